@@ -0,0 +1,319 @@
+//! NIP-42 auth policy: whether, and as whom, to answer relay AUTH challenges.
+//!
+//! `AuthPolicy` is a cheap-to-clone handle over one shared rule set, like
+//! `RelayPolicy`. A `RelayConnection` consults it when a relay sends a
+//! challenge, before anything is signed, so a relay only learns the user's
+//! pubkey when the policy says so:
+//!
+//! - a remembered per-relay answer always wins;
+//! - otherwise `AuthMode` decides: always, never, only allow-listed hosts, or
+//!   ask the app. Asking parks the challenge here, the connection reports an
+//!   `auth-pending` status, and the app answers with `AuthPolicy::resolve`.
+//!   Unanswered challenges are denied after `ask_timeout_ms`.
+//!
+//! An allowed challenge is signed with the relay's `AuthIdentity`: the user's
+//! signer, a throwaway key the crypto worker generates once per relay, or an
+//! explicit secret key. A denied challenge moves the connection's `AuthState`
+//! to `Failed`; the relay keeps serving whatever it serves without auth.
+
+use crate::relay_policy::host_matches;
+use crate::utils::normalize_relay_url;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// How to answer challenges from relays without a remembered decision.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuthMode {
+    #[default]
+    Always,
+    Never,
+    /// Only relays whose host matches `AuthPolicyRules::allow`.
+    AllowList,
+    /// Park the challenge until the app resolves it.
+    Ask,
+}
+
+/// Key a relay's AUTH event is signed with.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AuthIdentity {
+    /// The active signer.
+    #[default]
+    User,
+    /// A key the crypto worker generates for this relay, kept until the
+    /// engine stops.
+    Ephemeral,
+    /// A caller-supplied secret key (hex).
+    Key { secret_key: String },
+}
+
+/// Answer to one relay's challenge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthDecision {
+    Allow(AuthIdentity),
+    Deny,
+}
+
+/// Policy rules. Relay-keyed maps use normalized URLs; `allow` holds host
+/// patterns with the same syntax as `RelayPolicyRules::allow`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthPolicyRules {
+    pub mode: AuthMode,
+    pub allow: Vec<String>,
+    /// Identity per relay; unlisted relays are signed for by the user.
+    pub identities: HashMap<String, AuthIdentity>,
+    /// Answers remembered per relay, checked before `mode`.
+    pub remembered: HashMap<String, AuthDecision>,
+    /// How long `AuthMode::Ask` waits for the app before denying.
+    pub ask_timeout_ms: u64,
+}
+
+impl Default for AuthPolicyRules {
+    fn default() -> Self {
+        Self {
+            mode: AuthMode::Always,
+            allow: Vec::new(),
+            identities: HashMap::new(),
+            remembered: HashMap::new(),
+            ask_timeout_ms: 30_000,
+        }
+    }
+}
+
+impl AuthPolicyRules {
+    pub fn identity(&self, url: &str) -> AuthIdentity {
+        self.identities
+            .get(&normalize_relay_url(url))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The answer for `url`, or `None` when the app has to be asked.
+    pub fn decide(&self, url: &str) -> Option<AuthDecision> {
+        if let Some(decision) = self.remembered.get(&normalize_relay_url(url)) {
+            return Some(decision.clone());
+        }
+        let allow = AuthDecision::Allow(self.identity(url));
+        match self.mode {
+            AuthMode::Always => Some(allow),
+            AuthMode::Never => Some(AuthDecision::Deny),
+            AuthMode::AllowList => {
+                let host = crate::proxy::host_of(&url.trim().to_ascii_lowercase());
+                let listed = host.is_some_and(|host| {
+                    self.allow
+                        .iter()
+                        .any(|pattern| host_matches(pattern, &host))
+                });
+                Some(if listed { allow } else { AuthDecision::Deny })
+            }
+            AuthMode::Ask => None,
+        }
+    }
+}
+
+/// A challenge waiting for the app.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingAuth {
+    pub relay: String,
+    pub challenge: String,
+    pub requested_at_ms: u64,
+}
+
+#[derive(Default)]
+struct AuthPolicyState {
+    rules: AuthPolicyRules,
+    pending: HashMap<String, (PendingAuth, Option<AuthDecision>)>,
+}
+
+/// Shared, runtime-updatable auth policy.
+#[derive(Clone, Default)]
+pub struct AuthPolicy {
+    state: Arc<RwLock<AuthPolicyState>>,
+}
+
+impl AuthPolicy {
+    pub fn new(rules: AuthPolicyRules) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(AuthPolicyState {
+                rules,
+                pending: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Replace the rules; every holder of this handle sees the change.
+    /// Pending challenges stay parked.
+    pub fn set(&self, rules: AuthPolicyRules) {
+        self.state.write().unwrap().rules = rules;
+    }
+
+    pub fn rules(&self) -> AuthPolicyRules {
+        self.state.read().unwrap().rules.clone()
+    }
+
+    pub fn decide(&self, url: &str) -> Option<AuthDecision> {
+        self.state.read().unwrap().rules.decide(url)
+    }
+
+    /// Sign for `url` with `identity` from now on.
+    pub fn set_identity(&self, url: &str, identity: AuthIdentity) {
+        let key = normalize_relay_url(url);
+        let mut state = self.state.write().unwrap();
+        if identity == AuthIdentity::User {
+            state.rules.identities.remove(&key);
+        } else {
+            state.rules.identities.insert(key, identity);
+        }
+    }
+
+    /// Drops the remembered answer for `url`; its next challenge follows the mode.
+    pub fn forget(&self, url: &str) {
+        self.state
+            .write()
+            .unwrap()
+            .rules
+            .remembered
+            .remove(&normalize_relay_url(url));
+    }
+
+    /// Parks a challenge from `url` for the app, replacing an older one.
+    pub fn ask(&self, url: &str, challenge: &str, now_ms: u64) {
+        let pending = PendingAuth {
+            relay: url.to_string(),
+            challenge: challenge.to_string(),
+            requested_at_ms: now_ms,
+        };
+        self.state
+            .write()
+            .unwrap()
+            .pending
+            .insert(normalize_relay_url(url), (pending, None));
+    }
+
+    /// Challenges the app has not answered yet, oldest first.
+    pub fn pending(&self) -> Vec<PendingAuth> {
+        let state = self.state.read().unwrap();
+        let mut pending: Vec<PendingAuth> = state
+            .pending
+            .values()
+            .filter(|(_, answer)| answer.is_none())
+            .map(|(pending, _)| pending.clone())
+            .collect();
+        pending.sort_by_key(|p| p.requested_at_ms);
+        pending
+    }
+
+    /// Answers the pending challenge from `url`. With `remember`, the answer
+    /// also applies to every later challenge from that relay. Returns whether
+    /// a challenge was waiting.
+    pub fn resolve(&self, url: &str, decision: AuthDecision, remember: bool) -> bool {
+        let key = normalize_relay_url(url);
+        let mut state = self.state.write().unwrap();
+        if remember {
+            state.rules.remembered.insert(key.clone(), decision.clone());
+        }
+        match state.pending.get_mut(&key) {
+            Some((_, answer)) if answer.is_none() => {
+                *answer = Some(decision);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Takes the app's answer for `url`, or `Deny` once the challenge has
+    /// waited longer than the ask timeout. `None` while still waiting or when
+    /// nothing is parked.
+    pub fn poll(&self, url: &str, now_ms: u64) -> Option<AuthDecision> {
+        let key = normalize_relay_url(url);
+        let mut state = self.state.write().unwrap();
+        let timeout = state.rules.ask_timeout_ms;
+        let (pending, answer) = state.pending.get(&key)?;
+        let decision = match answer {
+            Some(decision) => decision.clone(),
+            None if now_ms.saturating_sub(pending.requested_at_ms) >= timeout => AuthDecision::Deny,
+            None => return None,
+        };
+        state.pending.remove(&key);
+        Some(decision)
+    }
+
+    /// Drops the parked challenge from `url` (its connection went away).
+    pub fn cancel(&self, url: &str) {
+        self.state
+            .write()
+            .unwrap()
+            .pending
+            .remove(&normalize_relay_url(url));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modes_allow_list_and_remembered_answers() {
+        let policy = AuthPolicy::default();
+        assert_eq!(
+            policy.decide("wss://relay.example.com"),
+            Some(AuthDecision::Allow(AuthIdentity::User))
+        );
+
+        policy.set(AuthPolicyRules {
+            mode: AuthMode::AllowList,
+            allow: vec!["*.example.com".to_string()],
+            identities: HashMap::from([(
+                "wss://paid.example.com".to_string(),
+                AuthIdentity::Ephemeral,
+            )]),
+            ..Default::default()
+        });
+        assert_eq!(
+            policy.decide("wss://Paid.example.com/"),
+            Some(AuthDecision::Allow(AuthIdentity::Ephemeral))
+        );
+        assert_eq!(policy.decide("wss://nos.lol"), Some(AuthDecision::Deny));
+
+        policy.set(AuthPolicyRules {
+            mode: AuthMode::Ask,
+            ..Default::default()
+        });
+        assert_eq!(policy.decide("wss://nos.lol"), None);
+        assert!(!policy.resolve("wss://nos.lol", AuthDecision::Deny, true));
+        assert_eq!(policy.decide("wss://nos.lol"), Some(AuthDecision::Deny));
+        policy.forget("wss://nos.lol");
+        assert_eq!(policy.decide("wss://nos.lol"), None);
+    }
+
+    #[test]
+    fn asked_challenges_resolve_or_time_out() {
+        let policy = AuthPolicy::new(AuthPolicyRules {
+            mode: AuthMode::Ask,
+            ask_timeout_ms: 1_000,
+            ..Default::default()
+        });
+        policy.ask("wss://a.example", "c1", 100);
+        policy.ask("wss://b.example", "c2", 200);
+        assert_eq!(policy.pending().len(), 2);
+        assert_eq!(policy.poll("wss://a.example", 500), None);
+
+        let throwaway = AuthDecision::Allow(AuthIdentity::Ephemeral);
+        assert!(policy.resolve("wss://a.example", throwaway.clone(), false));
+        assert_eq!(policy.pending()[0].challenge, "c2");
+        assert_eq!(policy.poll("wss://a.example", 500), Some(throwaway));
+        assert_eq!(policy.poll("wss://a.example", 500), None);
+        assert_eq!(policy.decide("wss://a.example"), None);
+
+        assert_eq!(policy.poll("wss://b.example", 1_199), None);
+        assert_eq!(
+            policy.poll("wss://b.example", 1_200),
+            Some(AuthDecision::Deny)
+        );
+        assert!(policy.pending().is_empty());
+    }
+}
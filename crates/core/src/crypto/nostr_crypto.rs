@@ -6,7 +6,7 @@
 #![cfg(feature = "crypto")]
 
 use crate::types::{Event, EventId, PublicKey, SecretKey, TypesError};
-use k256::schnorr::signature::{Signer, Verifier};
+use k256::schnorr::signature::hazmat::{PrehashSigner, PrehashVerifier};
 use k256::schnorr::{Signature, SigningKey, VerifyingKey};
 use sha2::{Digest, Sha256};
 
@@ -57,8 +57,9 @@ pub fn verify_event_signature(event: &Event) -> Result<()> {
     let signature = Signature::try_from(signature_bytes.as_slice())
         .map_err(|_| TypesError::InvalidFormat("Invalid signature format".to_string()))?;
 
+    // BIP-340 signs the 32-byte id itself; `Verifier::verify` would hash it again.
     verifying_key
-        .verify(&event.id.0, &signature)
+        .verify_prehash(&event.id.0, &signature)
         .map_err(|_| TypesError::InvalidFormat("Signature verification failed".to_string()))?;
 
     Ok(())
@@ -68,7 +69,9 @@ pub fn verify_event_signature(event: &Event) -> Result<()> {
 pub fn sign_event(secret_key: &SecretKey, event_id: &EventId) -> Result<String> {
     let signing_key = SigningKey::from_bytes(&secret_key.0)
         .map_err(|_| TypesError::InvalidFormat("Invalid secret key".to_string()))?;
-    let signature = signing_key.sign(&event_id.0);
+    let signature = signing_key
+        .sign_prehash(&event_id.0)
+        .map_err(|_| TypesError::InvalidFormat("Signing failed".to_string()))?;
     Ok(hex::encode(signature.to_bytes()))
 }
 
@@ -34,7 +34,7 @@ impl PrivateKeySigner {
     /// - Accepts hex (64 hex chars) or bech32 nsec starting with "nsec".
     /// - This skeleton does NOT derive or validate the actual key bytes yet.
     pub fn new(private_key_hex: &str) -> Result<Self> {
-        let secret_key = SecretKey::from_hex(private_key_hex)
+        let secret_key = SecretKey::parse(private_key_hex)
             .map_err(|e| SignerError::Other(format!("Invalid private key: {}", e)))?;
         let keys = Keys::new(secret_key);
         let pubkey_hex = keys.public_key().to_hex();
@@ -49,7 +49,7 @@ impl PrivateKeySigner {
 
     /// Replace the secret.
     pub fn set_secret(&mut self, secret: &str) -> Result<()> {
-        let secret_key = SecretKey::from_hex(secret)
+        let secret_key = SecretKey::parse(secret)
             .map_err(|e| SignerError::Other(format!("Invalid private key: {}", e)))?;
         self.keys = Keys::new(secret_key);
         self.pubkey_hex = self.keys.public_key().to_hex();
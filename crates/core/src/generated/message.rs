@@ -1429,10 +1429,10 @@ impl flatbuffers::SimpleToVerifyInSlice for MuteTarget {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_PIPE_CONFIG: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_PIPE_CONFIG: u8 = 9;
+pub const ENUM_MAX_PIPE_CONFIG: u8 = 10;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_PIPE_CONFIG: [PipeConfig; 10] = [
+pub const ENUM_VALUES_PIPE_CONFIG: [PipeConfig; 11] = [
   PipeConfig::NONE,
   PipeConfig::ParsePipeConfig,
   PipeConfig::KindFilterPipeConfig,
@@ -1443,6 +1443,7 @@ pub const ENUM_VALUES_PIPE_CONFIG: [PipeConfig; 10] = [
   PipeConfig::SerializeEventsPipeConfig,
   PipeConfig::ProofVerificationPipeConfig,
   PipeConfig::MuteFilterPipeConfig,
+  PipeConfig::ZapValidationPipeConfig,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -1460,9 +1461,10 @@ impl PipeConfig {
   pub const SerializeEventsPipeConfig: Self = Self(7);
   pub const ProofVerificationPipeConfig: Self = Self(8);
   pub const MuteFilterPipeConfig: Self = Self(9);
+  pub const ZapValidationPipeConfig: Self = Self(10);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 9;
+  pub const ENUM_MAX: u8 = 10;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::ParsePipeConfig,
@@ -1474,6 +1476,7 @@ impl PipeConfig {
     Self::SerializeEventsPipeConfig,
     Self::ProofVerificationPipeConfig,
     Self::MuteFilterPipeConfig,
+    Self::ZapValidationPipeConfig,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -1488,6 +1491,7 @@ impl PipeConfig {
       Self::SerializeEventsPipeConfig => Some("SerializeEventsPipeConfig"),
       Self::ProofVerificationPipeConfig => Some("ProofVerificationPipeConfig"),
       Self::MuteFilterPipeConfig => Some("MuteFilterPipeConfig"),
+      Self::ZapValidationPipeConfig => Some("ZapValidationPipeConfig"),
       _ => None,
     }
   }
@@ -1559,6 +1563,7 @@ pub enum PipeConfigT {
   SerializeEventsPipeConfig(Box<SerializeEventsPipeConfigT>),
   ProofVerificationPipeConfig(Box<ProofVerificationPipeConfigT>),
   MuteFilterPipeConfig(Box<MuteFilterPipeConfigT>),
+  ZapValidationPipeConfig(Box<ZapValidationPipeConfigT>),
 }
 impl Default for PipeConfigT {
   fn default() -> Self {
@@ -1578,6 +1583,7 @@ impl PipeConfigT {
       Self::SerializeEventsPipeConfig(_) => PipeConfig::SerializeEventsPipeConfig,
       Self::ProofVerificationPipeConfig(_) => PipeConfig::ProofVerificationPipeConfig,
       Self::MuteFilterPipeConfig(_) => PipeConfig::MuteFilterPipeConfig,
+      Self::ZapValidationPipeConfig(_) => PipeConfig::ZapValidationPipeConfig,
     }
   }
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(&self, fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>) -> Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>> {
@@ -1592,6 +1598,7 @@ impl PipeConfigT {
       Self::SerializeEventsPipeConfig(v) => Some(v.pack(fbb).as_union_value()),
       Self::ProofVerificationPipeConfig(v) => Some(v.pack(fbb).as_union_value()),
       Self::MuteFilterPipeConfig(v) => Some(v.pack(fbb).as_union_value()),
+      Self::ZapValidationPipeConfig(v) => Some(v.pack(fbb).as_union_value()),
     }
   }
   /// If the union variant matches, return the owned ParsePipeConfigT, setting the union to NONE.
@@ -1783,6 +1790,27 @@ impl PipeConfigT {
   pub fn as_mute_filter_pipe_config_mut(&mut self) -> Option<&mut MuteFilterPipeConfigT> {
     if let Self::MuteFilterPipeConfig(v) = self { Some(v.as_mut()) } else { None }
   }
+  /// If the union variant matches, return the owned ZapValidationPipeConfigT, setting the union to NONE.
+  pub fn take_zap_validation_pipe_config(&mut self) -> Option<Box<ZapValidationPipeConfigT>> {
+    if let Self::ZapValidationPipeConfig(_) = self {
+      let v = core::mem::replace(self, Self::NONE);
+      if let Self::ZapValidationPipeConfig(w) = v {
+        Some(w)
+      } else {
+        unreachable!()
+      }
+    } else {
+      None
+    }
+  }
+  /// If the union variant matches, return a reference to the ZapValidationPipeConfigT.
+  pub fn as_zap_validation_pipe_config(&self) -> Option<&ZapValidationPipeConfigT> {
+    if let Self::ZapValidationPipeConfig(v) = self { Some(v.as_ref()) } else { None }
+  }
+  /// If the union variant matches, return a mutable reference to the ZapValidationPipeConfigT.
+  pub fn as_zap_validation_pipe_config_mut(&mut self) -> Option<&mut ZapValidationPipeConfigT> {
+    if let Self::ZapValidationPipeConfig(v) = self { Some(v.as_mut()) } else { None }
+  }
 }
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_MAIN_CONTENT: u8 = 0;
@@ -2146,10 +2174,10 @@ impl flatbuffers::SimpleToVerifyInSlice for PollType {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_MESSAGE_TYPE: u32 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_MESSAGE_TYPE: u32 = 11;
+pub const ENUM_MAX_MESSAGE_TYPE: u32 = 14;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_MESSAGE_TYPE: [MessageType; 12] = [
+pub const ENUM_VALUES_MESSAGE_TYPE: [MessageType; 15] = [
   MessageType::ParsedNostrEvent,
   MessageType::ConnectionStatus,
   MessageType::CountResponse,
@@ -2162,6 +2190,9 @@ pub const ENUM_VALUES_MESSAGE_TYPE: [MessageType; 12] = [
   MessageType::NostrEvent,
   MessageType::SetSignerResponse,
   MessageType::AuthUrl,
+  MessageType::LinkPreview,
+  MessageType::ThreadTree,
+  MessageType::Mention,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -2181,9 +2212,12 @@ impl MessageType {
   pub const NostrEvent: Self = Self(9);
   pub const SetSignerResponse: Self = Self(10);
   pub const AuthUrl: Self = Self(11);
+  pub const LinkPreview: Self = Self(12);
+  pub const ThreadTree: Self = Self(13);
+  pub const Mention: Self = Self(14);
 
   pub const ENUM_MIN: u32 = 0;
-  pub const ENUM_MAX: u32 = 11;
+  pub const ENUM_MAX: u32 = 14;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::ParsedNostrEvent,
     Self::ConnectionStatus,
@@ -2197,6 +2231,9 @@ impl MessageType {
     Self::NostrEvent,
     Self::SetSignerResponse,
     Self::AuthUrl,
+    Self::LinkPreview,
+    Self::ThreadTree,
+    Self::Mention,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -2213,6 +2250,9 @@ impl MessageType {
       Self::NostrEvent => Some("NostrEvent"),
       Self::SetSignerResponse => Some("SetSignerResponse"),
       Self::AuthUrl => Some("AuthUrl"),
+      Self::LinkPreview => Some("LinkPreview"),
+      Self::ThreadTree => Some("ThreadTree"),
+      Self::Mention => Some("Mention"),
       _ => None,
     }
   }
@@ -2271,10 +2311,10 @@ impl flatbuffers::SimpleToVerifyInSlice for MessageType {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_PARSED_DATA_UNION: u32 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_PARSED_DATA_UNION: u32 = 52000;
+pub const ENUM_MAX_PARSED_DATA_UNION: u32 = 60000;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_PARSED_DATA_UNION: [ParsedDataUnion; 26] = [
+pub const ENUM_VALUES_PARSED_DATA_UNION: [ParsedDataUnion; 28] = [
   ParsedDataUnion::Kind0Parsed,
   ParsedDataUnion::Kind1Parsed,
   ParsedDataUnion::Kind3Parsed,
@@ -2282,6 +2322,7 @@ pub const ENUM_VALUES_PARSED_DATA_UNION: [ParsedDataUnion; 26] = [
   ParsedDataUnion::Kind6Parsed,
   ParsedDataUnion::Kind7Parsed,
   ParsedDataUnion::Kind8Parsed,
+  ParsedDataUnion::Kind14Parsed,
   ParsedDataUnion::Kind17Parsed,
   ParsedDataUnion::Kind20Parsed,
   ParsedDataUnion::Kind22Parsed,
@@ -2301,6 +2342,7 @@ pub const ENUM_VALUES_PARSED_DATA_UNION: [ParsedDataUnion; 26] = [
   ParsedDataUnion::Kind39089Parsed,
   ParsedDataUnion::ListParsed,
   ParsedDataUnion::PreGenericParsed,
+  ParsedDataUnion::CustomParsed,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -2315,6 +2357,7 @@ impl ParsedDataUnion {
   pub const Kind6Parsed: Self = Self(6);
   pub const Kind7Parsed: Self = Self(7);
   pub const Kind8Parsed: Self = Self(8);
+  pub const Kind14Parsed: Self = Self(14);
   pub const Kind17Parsed: Self = Self(17);
   pub const Kind20Parsed: Self = Self(20);
   pub const Kind22Parsed: Self = Self(22);
@@ -2334,9 +2377,10 @@ impl ParsedDataUnion {
   pub const Kind39089Parsed: Self = Self(39089);
   pub const ListParsed: Self = Self(51000);
   pub const PreGenericParsed: Self = Self(52000);
+  pub const CustomParsed: Self = Self(60000);
 
   pub const ENUM_MIN: u32 = 0;
-  pub const ENUM_MAX: u32 = 52000;
+  pub const ENUM_MAX: u32 = 60000;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::Kind0Parsed,
     Self::Kind1Parsed,
@@ -2345,6 +2389,7 @@ impl ParsedDataUnion {
     Self::Kind6Parsed,
     Self::Kind7Parsed,
     Self::Kind8Parsed,
+    Self::Kind14Parsed,
     Self::Kind17Parsed,
     Self::Kind20Parsed,
     Self::Kind22Parsed,
@@ -2364,6 +2409,7 @@ impl ParsedDataUnion {
     Self::Kind39089Parsed,
     Self::ListParsed,
     Self::PreGenericParsed,
+    Self::CustomParsed,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -2375,6 +2421,7 @@ impl ParsedDataUnion {
       Self::Kind6Parsed => Some("Kind6Parsed"),
       Self::Kind7Parsed => Some("Kind7Parsed"),
       Self::Kind8Parsed => Some("Kind8Parsed"),
+      Self::Kind14Parsed => Some("Kind14Parsed"),
       Self::Kind17Parsed => Some("Kind17Parsed"),
       Self::Kind20Parsed => Some("Kind20Parsed"),
       Self::Kind22Parsed => Some("Kind22Parsed"),
@@ -2394,6 +2441,7 @@ impl ParsedDataUnion {
       Self::Kind39089Parsed => Some("Kind39089Parsed"),
       Self::ListParsed => Some("ListParsed"),
       Self::PreGenericParsed => Some("PreGenericParsed"),
+      Self::CustomParsed => Some("CustomParsed"),
       _ => None,
     }
   }
@@ -2452,10 +2500,10 @@ impl flatbuffers::SimpleToVerifyInSlice for ParsedDataUnion {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_PARSED_DATA: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_PARSED_DATA: u8 = 25;
+pub const ENUM_MAX_PARSED_DATA: u8 = 27;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_PARSED_DATA: [ParsedData; 26] = [
+pub const ENUM_VALUES_PARSED_DATA: [ParsedData; 28] = [
   ParsedData::NONE,
   ParsedData::Kind0Parsed,
   ParsedData::Kind1Parsed,
@@ -2482,6 +2530,8 @@ pub const ENUM_VALUES_PARSED_DATA: [ParsedData; 26] = [
   ParsedData::Kind30023Parsed,
   ParsedData::ListParsed,
   ParsedData::PreGenericParsed,
+  ParsedData::Kind14Parsed,
+  ParsedData::CustomParsed,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -2515,9 +2565,11 @@ impl ParsedData {
   pub const Kind30023Parsed: Self = Self(23);
   pub const ListParsed: Self = Self(24);
   pub const PreGenericParsed: Self = Self(25);
+  pub const Kind14Parsed: Self = Self(26);
+  pub const CustomParsed: Self = Self(27);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 25;
+  pub const ENUM_MAX: u8 = 27;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::Kind0Parsed,
@@ -2545,6 +2597,8 @@ impl ParsedData {
     Self::Kind30023Parsed,
     Self::ListParsed,
     Self::PreGenericParsed,
+    Self::Kind14Parsed,
+    Self::CustomParsed,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -2575,6 +2629,8 @@ impl ParsedData {
       Self::Kind30023Parsed => Some("Kind30023Parsed"),
       Self::ListParsed => Some("ListParsed"),
       Self::PreGenericParsed => Some("PreGenericParsed"),
+      Self::Kind14Parsed => Some("Kind14Parsed"),
+      Self::CustomParsed => Some("CustomParsed"),
       _ => None,
     }
   }
@@ -2662,6 +2718,8 @@ pub enum ParsedDataT {
   Kind30023Parsed(Box<Kind30023ParsedT>),
   ListParsed(Box<ListParsedT>),
   PreGenericParsed(Box<PreGenericParsedT>),
+  Kind14Parsed(Box<Kind14ParsedT>),
+  CustomParsed(Box<CustomParsedT>),
 }
 impl Default for ParsedDataT {
   fn default() -> Self {
@@ -2697,6 +2755,8 @@ impl ParsedDataT {
       Self::Kind30023Parsed(_) => ParsedData::Kind30023Parsed,
       Self::ListParsed(_) => ParsedData::ListParsed,
       Self::PreGenericParsed(_) => ParsedData::PreGenericParsed,
+      Self::Kind14Parsed(_) => ParsedData::Kind14Parsed,
+      Self::CustomParsed(_) => ParsedData::CustomParsed,
     }
   }
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(&self, fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>) -> Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>> {
@@ -2727,6 +2787,8 @@ impl ParsedDataT {
       Self::Kind30023Parsed(v) => Some(v.pack(fbb).as_union_value()),
       Self::ListParsed(v) => Some(v.pack(fbb).as_union_value()),
       Self::PreGenericParsed(v) => Some(v.pack(fbb).as_union_value()),
+      Self::Kind14Parsed(v) => Some(v.pack(fbb).as_union_value()),
+      Self::CustomParsed(v) => Some(v.pack(fbb).as_union_value()),
     }
   }
   /// If the union variant matches, return the owned Kind0ParsedT, setting the union to NONE.
@@ -3254,14 +3316,56 @@ impl ParsedDataT {
   pub fn as_pre_generic_parsed_mut(&mut self) -> Option<&mut PreGenericParsedT> {
     if let Self::PreGenericParsed(v) = self { Some(v.as_mut()) } else { None }
   }
+  /// If the union variant matches, return the owned Kind14ParsedT, setting the union to NONE.
+  pub fn take_kind_14_parsed(&mut self) -> Option<Box<Kind14ParsedT>> {
+    if let Self::Kind14Parsed(_) = self {
+      let v = core::mem::replace(self, Self::NONE);
+      if let Self::Kind14Parsed(w) = v {
+        Some(w)
+      } else {
+        unreachable!()
+      }
+    } else {
+      None
+    }
+  }
+  /// If the union variant matches, return a reference to the Kind14ParsedT.
+  pub fn as_kind_14_parsed(&self) -> Option<&Kind14ParsedT> {
+    if let Self::Kind14Parsed(v) = self { Some(v.as_ref()) } else { None }
+  }
+  /// If the union variant matches, return a mutable reference to the Kind14ParsedT.
+  pub fn as_kind_14_parsed_mut(&mut self) -> Option<&mut Kind14ParsedT> {
+    if let Self::Kind14Parsed(v) = self { Some(v.as_mut()) } else { None }
+  }
+  /// If the union variant matches, return the owned CustomParsedT, setting the union to NONE.
+  pub fn take_custom_parsed(&mut self) -> Option<Box<CustomParsedT>> {
+    if let Self::CustomParsed(_) = self {
+      let v = core::mem::replace(self, Self::NONE);
+      if let Self::CustomParsed(w) = v {
+        Some(w)
+      } else {
+        unreachable!()
+      }
+    } else {
+      None
+    }
+  }
+  /// If the union variant matches, return a reference to the CustomParsedT.
+  pub fn as_custom_parsed(&self) -> Option<&CustomParsedT> {
+    if let Self::CustomParsed(v) = self { Some(v.as_ref()) } else { None }
+  }
+  /// If the union variant matches, return a mutable reference to the CustomParsedT.
+  pub fn as_custom_parsed_mut(&mut self) -> Option<&mut CustomParsedT> {
+    if let Self::CustomParsed(v) = self { Some(v.as_mut()) } else { None }
+  }
 }
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_MESSAGE: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_MESSAGE: u8 = 12;
+pub const ENUM_MAX_MESSAGE: u8 = 15;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_MESSAGE: [Message; 13] = [
+pub const ENUM_VALUES_MESSAGE: [Message; 16] = [
   Message::NONE,
   Message::ParsedEvent,
   Message::NostrEvent,
@@ -3275,6 +3379,9 @@ pub const ENUM_VALUES_MESSAGE: [Message; 13] = [
   Message::Raw,
   Message::SetSignerResponse,
   Message::AuthUrl,
+  Message::LinkPreviewData,
+  Message::ThreadTree,
+  Message::MentionData,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -3295,9 +3402,12 @@ impl Message {
   pub const Raw: Self = Self(10);
   pub const SetSignerResponse: Self = Self(11);
   pub const AuthUrl: Self = Self(12);
+  pub const LinkPreviewData: Self = Self(13);
+  pub const ThreadTree: Self = Self(14);
+  pub const MentionData: Self = Self(15);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 12;
+  pub const ENUM_MAX: u8 = 15;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::ParsedEvent,
@@ -3312,6 +3422,9 @@ impl Message {
     Self::Raw,
     Self::SetSignerResponse,
     Self::AuthUrl,
+    Self::LinkPreviewData,
+    Self::ThreadTree,
+    Self::MentionData,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -3329,6 +3442,9 @@ impl Message {
       Self::Raw => Some("Raw"),
       Self::SetSignerResponse => Some("SetSignerResponse"),
       Self::AuthUrl => Some("AuthUrl"),
+      Self::LinkPreviewData => Some("LinkPreviewData"),
+      Self::ThreadTree => Some("ThreadTree"),
+      Self::MentionData => Some("MentionData"),
       _ => None,
     }
   }
@@ -3403,6 +3519,9 @@ pub enum MessageT {
   Raw(Box<RawT>),
   SetSignerResponse(Box<SetSignerResponseT>),
   AuthUrl(Box<AuthUrlT>),
+  LinkPreviewData(Box<LinkPreviewDataT>),
+  ThreadTree(Box<ThreadTreeT>),
+  MentionData(Box<MentionDataT>),
 }
 impl Default for MessageT {
   fn default() -> Self {
@@ -3425,6 +3544,9 @@ impl MessageT {
       Self::Raw(_) => Message::Raw,
       Self::SetSignerResponse(_) => Message::SetSignerResponse,
       Self::AuthUrl(_) => Message::AuthUrl,
+      Self::LinkPreviewData(_) => Message::LinkPreviewData,
+      Self::ThreadTree(_) => Message::ThreadTree,
+      Self::MentionData(_) => Message::MentionData,
     }
   }
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(&self, fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>) -> Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>> {
@@ -3442,6 +3564,9 @@ impl MessageT {
       Self::Raw(v) => Some(v.pack(fbb).as_union_value()),
       Self::SetSignerResponse(v) => Some(v.pack(fbb).as_union_value()),
       Self::AuthUrl(v) => Some(v.pack(fbb).as_union_value()),
+      Self::LinkPreviewData(v) => Some(v.pack(fbb).as_union_value()),
+      Self::ThreadTree(v) => Some(v.pack(fbb).as_union_value()),
+      Self::MentionData(v) => Some(v.pack(fbb).as_union_value()),
     }
   }
   /// If the union variant matches, return the owned ParsedEventT, setting the union to NONE.
@@ -3696,6 +3821,69 @@ impl MessageT {
   pub fn as_auth_url_mut(&mut self) -> Option<&mut AuthUrlT> {
     if let Self::AuthUrl(v) = self { Some(v.as_mut()) } else { None }
   }
+  /// If the union variant matches, return the owned LinkPreviewDataT, setting the union to NONE.
+  pub fn take_link_preview_data(&mut self) -> Option<Box<LinkPreviewDataT>> {
+    if let Self::LinkPreviewData(_) = self {
+      let v = core::mem::replace(self, Self::NONE);
+      if let Self::LinkPreviewData(w) = v {
+        Some(w)
+      } else {
+        unreachable!()
+      }
+    } else {
+      None
+    }
+  }
+  /// If the union variant matches, return a reference to the LinkPreviewDataT.
+  pub fn as_link_preview_data(&self) -> Option<&LinkPreviewDataT> {
+    if let Self::LinkPreviewData(v) = self { Some(v.as_ref()) } else { None }
+  }
+  /// If the union variant matches, return a mutable reference to the LinkPreviewDataT.
+  pub fn as_link_preview_data_mut(&mut self) -> Option<&mut LinkPreviewDataT> {
+    if let Self::LinkPreviewData(v) = self { Some(v.as_mut()) } else { None }
+  }
+  /// If the union variant matches, return the owned ThreadTreeT, setting the union to NONE.
+  pub fn take_thread_tree(&mut self) -> Option<Box<ThreadTreeT>> {
+    if let Self::ThreadTree(_) = self {
+      let v = core::mem::replace(self, Self::NONE);
+      if let Self::ThreadTree(w) = v {
+        Some(w)
+      } else {
+        unreachable!()
+      }
+    } else {
+      None
+    }
+  }
+  /// If the union variant matches, return a reference to the ThreadTreeT.
+  pub fn as_thread_tree(&self) -> Option<&ThreadTreeT> {
+    if let Self::ThreadTree(v) = self { Some(v.as_ref()) } else { None }
+  }
+  /// If the union variant matches, return a mutable reference to the ThreadTreeT.
+  pub fn as_thread_tree_mut(&mut self) -> Option<&mut ThreadTreeT> {
+    if let Self::ThreadTree(v) = self { Some(v.as_mut()) } else { None }
+  }
+  /// If the union variant matches, return the owned MentionDataT, setting the union to NONE.
+  pub fn take_mention_data(&mut self) -> Option<Box<MentionDataT>> {
+    if let Self::MentionData(_) = self {
+      let v = core::mem::replace(self, Self::NONE);
+      if let Self::MentionData(w) = v {
+        Some(w)
+      } else {
+        unreachable!()
+      }
+    } else {
+      None
+    }
+  }
+  /// If the union variant matches, return a reference to the MentionDataT.
+  pub fn as_mention_data(&self) -> Option<&MentionDataT> {
+    if let Self::MentionData(v) = self { Some(v.as_ref()) } else { None }
+  }
+  /// If the union variant matches, return a mutable reference to the MentionDataT.
+  pub fn as_mention_data_mut(&mut self) -> Option<&mut MentionDataT> {
+    if let Self::MentionData(v) = self { Some(v.as_mut()) } else { None }
+  }
 }
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_SIGNER_OP: u32 = 0;
@@ -4314,6 +4502,11 @@ impl<'a> flatbuffers::Follow<'a> for CashuData<'a> {
 
 impl<'a> CashuData<'a> {
   pub const VT_TOKEN: flatbuffers::VOffsetT = 4;
+  pub const VT_MINT: flatbuffers::VOffsetT = 6;
+  pub const VT_UNIT: flatbuffers::VOffsetT = 8;
+  pub const VT_MEMO: flatbuffers::VOffsetT = 10;
+  pub const VT_AMOUNT: flatbuffers::VOffsetT = 12;
+  pub const VT_PROOFS: flatbuffers::VOffsetT = 14;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -4325,6 +4518,11 @@ impl<'a> CashuData<'a> {
     args: &'args CashuDataArgs<'args>
   ) -> flatbuffers::WIPOffset<CashuData<'bldr>> {
     let mut builder = CashuDataBuilder::new(_fbb);
+    builder.add_amount(args.amount);
+    if let Some(x) = args.proofs { builder.add_proofs(x); }
+    if let Some(x) = args.memo { builder.add_memo(x); }
+    if let Some(x) = args.unit { builder.add_unit(x); }
+    if let Some(x) = args.mint { builder.add_mint(x); }
     if let Some(x) = args.token { builder.add_token(x); }
     builder.finish()
   }
@@ -4334,8 +4532,26 @@ impl<'a> CashuData<'a> {
       let x = self.token();
       x.to_string()
     };
+    let mint = self.mint().map(|x| {
+      x.to_string()
+    });
+    let unit = self.unit().map(|x| {
+      x.to_string()
+    });
+    let memo = self.memo().map(|x| {
+      x.to_string()
+    });
+    let amount = self.amount();
+    let proofs = self.proofs().map(|x| {
+      x.iter().map(|t| t.unpack()).collect()
+    });
     CashuDataT {
       token,
+      mint,
+      unit,
+      memo,
+      amount,
+      proofs,
     }
   }
 
@@ -4346,6 +4562,41 @@ impl<'a> CashuData<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CashuData::VT_TOKEN, None).unwrap()}
   }
+  #[inline]
+  pub fn mint(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CashuData::VT_MINT, None)}
+  }
+  #[inline]
+  pub fn unit(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CashuData::VT_UNIT, None)}
+  }
+  #[inline]
+  pub fn memo(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CashuData::VT_MEMO, None)}
+  }
+  #[inline]
+  pub fn amount(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(CashuData::VT_AMOUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn proofs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Proof<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Proof>>>>(CashuData::VT_PROOFS, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for CashuData<'_> {
@@ -4356,18 +4607,33 @@ impl flatbuffers::Verifiable for CashuData<'_> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("token", Self::VT_TOKEN, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("mint", Self::VT_MINT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("unit", Self::VT_UNIT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("memo", Self::VT_MEMO, false)?
+     .visit_field::<u64>("amount", Self::VT_AMOUNT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Proof>>>>("proofs", Self::VT_PROOFS, false)?
      .finish();
     Ok(())
   }
 }
 pub struct CashuDataArgs<'a> {
     pub token: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub mint: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub unit: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub memo: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub amount: u64,
+    pub proofs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Proof<'a>>>>>,
 }
 impl<'a> Default for CashuDataArgs<'a> {
   #[inline]
   fn default() -> Self {
     CashuDataArgs {
       token: None, // required field
+      mint: None,
+      unit: None,
+      memo: None,
+      amount: 0,
+      proofs: None,
     }
   }
 }
@@ -4382,6 +4648,26 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CashuDataBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CashuData::VT_TOKEN, token);
   }
   #[inline]
+  pub fn add_mint(&mut self, mint: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CashuData::VT_MINT, mint);
+  }
+  #[inline]
+  pub fn add_unit(&mut self, unit: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CashuData::VT_UNIT, unit);
+  }
+  #[inline]
+  pub fn add_memo(&mut self, memo: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CashuData::VT_MEMO, memo);
+  }
+  #[inline]
+  pub fn add_amount(&mut self, amount: u64) {
+    self.fbb_.push_slot::<u64>(CashuData::VT_AMOUNT, amount, 0);
+  }
+  #[inline]
+  pub fn add_proofs(&mut self, proofs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Proof<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CashuData::VT_PROOFS, proofs);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CashuDataBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     CashuDataBuilder {
@@ -4401,6 +4687,11 @@ impl core::fmt::Debug for CashuData<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     let mut ds = f.debug_struct("CashuData");
       ds.field("token", &self.token());
+      ds.field("mint", &self.mint());
+      ds.field("unit", &self.unit());
+      ds.field("memo", &self.memo());
+      ds.field("amount", &self.amount());
+      ds.field("proofs", &self.proofs());
       ds.finish()
   }
 }
@@ -4408,11 +4699,21 @@ impl core::fmt::Debug for CashuData<'_> {
 #[derive(Debug, Clone, PartialEq)]
 pub struct CashuDataT {
   pub token: String,
+  pub mint: Option<String>,
+  pub unit: Option<String>,
+  pub memo: Option<String>,
+  pub amount: u64,
+  pub proofs: Option<Vec<ProofT>>,
 }
 impl Default for CashuDataT {
   fn default() -> Self {
     Self {
       token: "".to_string(),
+      mint: None,
+      unit: None,
+      memo: None,
+      amount: 0,
+      proofs: None,
     }
   }
 }
@@ -4425,8 +4726,26 @@ impl CashuDataT {
       let x = &self.token;
       _fbb.create_string(x)
     });
+    let mint = self.mint.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let unit = self.unit.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let memo = self.memo.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let amount = self.amount;
+    let proofs = self.proofs.as_ref().map(|x|{
+      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+    });
     CashuData::create(_fbb, &CashuDataArgs{
       token,
+      mint,
+      unit,
+      memo,
+      amount,
+      proofs,
     })
   }
 }
@@ -4447,6 +4766,14 @@ impl<'a> flatbuffers::Follow<'a> for LightningData<'a> {
 
 impl<'a> LightningData<'a> {
   pub const VT_INVOICE: flatbuffers::VOffsetT = 4;
+  pub const VT_NETWORK: flatbuffers::VOffsetT = 6;
+  pub const VT_AMOUNT_MSATS: flatbuffers::VOffsetT = 8;
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 10;
+  pub const VT_EXPIRY: flatbuffers::VOffsetT = 12;
+  pub const VT_DESCRIPTION: flatbuffers::VOffsetT = 14;
+  pub const VT_DESCRIPTION_HASH: flatbuffers::VOffsetT = 16;
+  pub const VT_PAYMENT_HASH: flatbuffers::VOffsetT = 18;
+  pub const VT_PAYEE: flatbuffers::VOffsetT = 20;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -4458,6 +4785,14 @@ impl<'a> LightningData<'a> {
     args: &'args LightningDataArgs<'args>
   ) -> flatbuffers::WIPOffset<LightningData<'bldr>> {
     let mut builder = LightningDataBuilder::new(_fbb);
+    builder.add_expiry(args.expiry);
+    builder.add_timestamp(args.timestamp);
+    builder.add_amount_msats(args.amount_msats);
+    if let Some(x) = args.payee { builder.add_payee(x); }
+    if let Some(x) = args.payment_hash { builder.add_payment_hash(x); }
+    if let Some(x) = args.description_hash { builder.add_description_hash(x); }
+    if let Some(x) = args.description { builder.add_description(x); }
+    if let Some(x) = args.network { builder.add_network(x); }
     if let Some(x) = args.invoice { builder.add_invoice(x); }
     builder.finish()
   }
@@ -4467,8 +4802,34 @@ impl<'a> LightningData<'a> {
       let x = self.invoice();
       x.to_string()
     };
+    let network = self.network().map(|x| {
+      x.to_string()
+    });
+    let amount_msats = self.amount_msats();
+    let timestamp = self.timestamp();
+    let expiry = self.expiry();
+    let description = self.description().map(|x| {
+      x.to_string()
+    });
+    let description_hash = self.description_hash().map(|x| {
+      x.to_string()
+    });
+    let payment_hash = self.payment_hash().map(|x| {
+      x.to_string()
+    });
+    let payee = self.payee().map(|x| {
+      x.to_string()
+    });
     LightningDataT {
       invoice,
+      network,
+      amount_msats,
+      timestamp,
+      expiry,
+      description,
+      description_hash,
+      payment_hash,
+      payee,
     }
   }
 
@@ -4479,6 +4840,62 @@ impl<'a> LightningData<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LightningData::VT_INVOICE, None).unwrap()}
   }
+  #[inline]
+  pub fn network(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LightningData::VT_NETWORK, None)}
+  }
+  #[inline]
+  pub fn amount_msats(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(LightningData::VT_AMOUNT_MSATS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn timestamp(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(LightningData::VT_TIMESTAMP, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn expiry(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(LightningData::VT_EXPIRY, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn description(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LightningData::VT_DESCRIPTION, None)}
+  }
+  #[inline]
+  pub fn description_hash(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LightningData::VT_DESCRIPTION_HASH, None)}
+  }
+  #[inline]
+  pub fn payment_hash(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LightningData::VT_PAYMENT_HASH, None)}
+  }
+  #[inline]
+  pub fn payee(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LightningData::VT_PAYEE, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for LightningData<'_> {
@@ -4489,18 +4906,42 @@ impl flatbuffers::Verifiable for LightningData<'_> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("invoice", Self::VT_INVOICE, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("network", Self::VT_NETWORK, false)?
+     .visit_field::<u64>("amount_msats", Self::VT_AMOUNT_MSATS, false)?
+     .visit_field::<u64>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_field::<u64>("expiry", Self::VT_EXPIRY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("description", Self::VT_DESCRIPTION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("description_hash", Self::VT_DESCRIPTION_HASH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("payment_hash", Self::VT_PAYMENT_HASH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("payee", Self::VT_PAYEE, false)?
      .finish();
     Ok(())
   }
 }
 pub struct LightningDataArgs<'a> {
     pub invoice: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub network: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub amount_msats: u64,
+    pub timestamp: u64,
+    pub expiry: u64,
+    pub description: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub description_hash: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub payment_hash: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub payee: Option<flatbuffers::WIPOffset<&'a str>>,
 }
 impl<'a> Default for LightningDataArgs<'a> {
   #[inline]
   fn default() -> Self {
     LightningDataArgs {
       invoice: None, // required field
+      network: None,
+      amount_msats: 0,
+      timestamp: 0,
+      expiry: 0,
+      description: None,
+      description_hash: None,
+      payment_hash: None,
+      payee: None,
     }
   }
 }
@@ -4515,6 +4956,38 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LightningDataBuilder<'a, 'b, A>
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LightningData::VT_INVOICE, invoice);
   }
   #[inline]
+  pub fn add_network(&mut self, network: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LightningData::VT_NETWORK, network);
+  }
+  #[inline]
+  pub fn add_amount_msats(&mut self, amount_msats: u64) {
+    self.fbb_.push_slot::<u64>(LightningData::VT_AMOUNT_MSATS, amount_msats, 0);
+  }
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: u64) {
+    self.fbb_.push_slot::<u64>(LightningData::VT_TIMESTAMP, timestamp, 0);
+  }
+  #[inline]
+  pub fn add_expiry(&mut self, expiry: u64) {
+    self.fbb_.push_slot::<u64>(LightningData::VT_EXPIRY, expiry, 0);
+  }
+  #[inline]
+  pub fn add_description(&mut self, description: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LightningData::VT_DESCRIPTION, description);
+  }
+  #[inline]
+  pub fn add_description_hash(&mut self, description_hash: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LightningData::VT_DESCRIPTION_HASH, description_hash);
+  }
+  #[inline]
+  pub fn add_payment_hash(&mut self, payment_hash: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LightningData::VT_PAYMENT_HASH, payment_hash);
+  }
+  #[inline]
+  pub fn add_payee(&mut self, payee: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LightningData::VT_PAYEE, payee);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LightningDataBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     LightningDataBuilder {
@@ -4534,6 +5007,14 @@ impl core::fmt::Debug for LightningData<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     let mut ds = f.debug_struct("LightningData");
       ds.field("invoice", &self.invoice());
+      ds.field("network", &self.network());
+      ds.field("amount_msats", &self.amount_msats());
+      ds.field("timestamp", &self.timestamp());
+      ds.field("expiry", &self.expiry());
+      ds.field("description", &self.description());
+      ds.field("description_hash", &self.description_hash());
+      ds.field("payment_hash", &self.payment_hash());
+      ds.field("payee", &self.payee());
       ds.finish()
   }
 }
@@ -4541,11 +5022,27 @@ impl core::fmt::Debug for LightningData<'_> {
 #[derive(Debug, Clone, PartialEq)]
 pub struct LightningDataT {
   pub invoice: String,
+  pub network: Option<String>,
+  pub amount_msats: u64,
+  pub timestamp: u64,
+  pub expiry: u64,
+  pub description: Option<String>,
+  pub description_hash: Option<String>,
+  pub payment_hash: Option<String>,
+  pub payee: Option<String>,
 }
 impl Default for LightningDataT {
   fn default() -> Self {
     Self {
       invoice: "".to_string(),
+      network: None,
+      amount_msats: 0,
+      timestamp: 0,
+      expiry: 0,
+      description: None,
+      description_hash: None,
+      payment_hash: None,
+      payee: None,
     }
   }
 }
@@ -4558,17 +5055,43 @@ impl LightningDataT {
       let x = &self.invoice;
       _fbb.create_string(x)
     });
-    LightningData::create(_fbb, &LightningDataArgs{
-      invoice,
-    })
-  }
-}
-pub enum ImageDataOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct ImageData<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
+    let network = self.network.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let amount_msats = self.amount_msats;
+    let timestamp = self.timestamp;
+    let expiry = self.expiry;
+    let description = self.description.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let description_hash = self.description_hash.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let payment_hash = self.payment_hash.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let payee = self.payee.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    LightningData::create(_fbb, &LightningDataArgs{
+      invoice,
+      network,
+      amount_msats,
+      timestamp,
+      expiry,
+      description,
+      description_hash,
+      payment_hash,
+      payee,
+    })
+  }
+}
+pub enum ImageDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ImageData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
 
 impl<'a> flatbuffers::Follow<'a> for ImageData<'a> {
   type Inner = ImageData<'a>;
@@ -9913,6 +10436,8 @@ impl<'a> Request<'a> {
   pub const VT_MAX_RELAYS: flatbuffers::VOffsetT = 28;
   pub const VT_CACHE_ONLY: flatbuffers::VOffsetT = 30;
   pub const VT_MESH_ONLY: flatbuffers::VOffsetT = 32;
+  pub const VT_NEGENTROPY: flatbuffers::VOffsetT = 34;
+  pub const VT_BACKGROUND: flatbuffers::VOffsetT = 36;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -9934,6 +10459,8 @@ impl<'a> Request<'a> {
     if let Some(x) = args.authors { builder.add_authors(x); }
     if let Some(x) = args.ids { builder.add_ids(x); }
     builder.add_max_relays(args.max_relays);
+    builder.add_background(args.background);
+    builder.add_negentropy(args.negentropy);
     builder.add_mesh_only(args.mesh_only);
     builder.add_cache_only(args.cache_only);
     builder.add_no_cache(args.no_cache);
@@ -9968,6 +10495,8 @@ impl<'a> Request<'a> {
     let max_relays = self.max_relays();
     let cache_only = self.cache_only();
     let mesh_only = self.mesh_only();
+    let negentropy = self.negentropy();
+    let background = self.background();
     RequestT {
       ids,
       authors,
@@ -9983,6 +10512,8 @@ impl<'a> Request<'a> {
       max_relays,
       cache_only,
       mesh_only,
+      negentropy,
+      background,
     }
   }
 
@@ -10084,6 +10615,20 @@ impl<'a> Request<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<bool>(Request::VT_MESH_ONLY, Some(false)).unwrap()}
   }
+  #[inline]
+  pub fn negentropy(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_NEGENTROPY, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn background(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Request::VT_BACKGROUND, Some(false)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for Request<'_> {
@@ -10107,6 +10652,8 @@ impl flatbuffers::Verifiable for Request<'_> {
      .visit_field::<u16>("max_relays", Self::VT_MAX_RELAYS, false)?
      .visit_field::<bool>("cache_only", Self::VT_CACHE_ONLY, false)?
      .visit_field::<bool>("mesh_only", Self::VT_MESH_ONLY, false)?
+     .visit_field::<bool>("negentropy", Self::VT_NEGENTROPY, false)?
+     .visit_field::<bool>("background", Self::VT_BACKGROUND, false)?
      .finish();
     Ok(())
   }
@@ -10126,6 +10673,8 @@ pub struct RequestArgs<'a> {
     pub max_relays: u16,
     pub cache_only: bool,
     pub mesh_only: bool,
+    pub negentropy: bool,
+    pub background: bool,
 }
 impl<'a> Default for RequestArgs<'a> {
   #[inline]
@@ -10145,6 +10694,8 @@ impl<'a> Default for RequestArgs<'a> {
       max_relays: 0,
       cache_only: false,
       mesh_only: false,
+      negentropy: false,
+      background: false,
     }
   }
 }
@@ -10211,6 +10762,14 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RequestBuilder<'a, 'b, A> {
     self.fbb_.push_slot::<bool>(Request::VT_MESH_ONLY, mesh_only, false);
   }
   #[inline]
+  pub fn add_negentropy(&mut self, negentropy: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_NEGENTROPY, negentropy, false);
+  }
+  #[inline]
+  pub fn add_background(&mut self, background: bool) {
+    self.fbb_.push_slot::<bool>(Request::VT_BACKGROUND, background, false);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RequestBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     RequestBuilder {
@@ -10242,6 +10801,8 @@ impl core::fmt::Debug for Request<'_> {
       ds.field("max_relays", &self.max_relays());
       ds.field("cache_only", &self.cache_only());
       ds.field("mesh_only", &self.mesh_only());
+      ds.field("negentropy", &self.negentropy());
+      ds.field("background", &self.background());
       ds.finish()
   }
 }
@@ -10262,6 +10823,8 @@ pub struct RequestT {
   pub max_relays: u16,
   pub cache_only: bool,
   pub mesh_only: bool,
+  pub negentropy: bool,
+  pub background: bool,
 }
 impl Default for RequestT {
   fn default() -> Self {
@@ -10280,6 +10843,8 @@ impl Default for RequestT {
       max_relays: 0,
       cache_only: false,
       mesh_only: false,
+      negentropy: false,
+      background: false,
     }
   }
 }
@@ -10314,6 +10879,8 @@ impl RequestT {
     let max_relays = self.max_relays;
     let cache_only = self.cache_only;
     let mesh_only = self.mesh_only;
+    let negentropy = self.negentropy;
+    let background = self.background;
     Request::create(_fbb, &RequestArgs{
       ids,
       authors,
@@ -10329,6 +10896,8 @@ impl RequestT {
       max_relays,
       cache_only,
       mesh_only,
+      negentropy,
+      background,
     })
   }
 }
@@ -12580,6 +13149,132 @@ impl MuteFilterPipeConfigT {
     })
   }
 }
+pub enum ZapValidationPipeConfigOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ZapValidationPipeConfig<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ZapValidationPipeConfig<'a> {
+  type Inner = ZapValidationPipeConfig<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ZapValidationPipeConfig<'a> {
+  pub const VT_DROP_INVALID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ZapValidationPipeConfig { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ZapValidationPipeConfigArgs
+  ) -> flatbuffers::WIPOffset<ZapValidationPipeConfig<'bldr>> {
+    let mut builder = ZapValidationPipeConfigBuilder::new(_fbb);
+    builder.add_drop_invalid(args.drop_invalid);
+    builder.finish()
+  }
+
+  pub fn unpack(&self) -> ZapValidationPipeConfigT {
+    let drop_invalid = self.drop_invalid();
+    ZapValidationPipeConfigT {
+      drop_invalid,
+    }
+  }
+
+  #[inline]
+  pub fn drop_invalid(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ZapValidationPipeConfig::VT_DROP_INVALID, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ZapValidationPipeConfig<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("drop_invalid", Self::VT_DROP_INVALID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ZapValidationPipeConfigArgs {
+    pub drop_invalid: bool,
+}
+impl<'a> Default for ZapValidationPipeConfigArgs {
+  #[inline]
+  fn default() -> Self {
+    ZapValidationPipeConfigArgs {
+      drop_invalid: false,
+    }
+  }
+}
+
+pub struct ZapValidationPipeConfigBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ZapValidationPipeConfigBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_drop_invalid(&mut self, drop_invalid: bool) {
+    self.fbb_.push_slot::<bool>(ZapValidationPipeConfig::VT_DROP_INVALID, drop_invalid, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ZapValidationPipeConfigBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ZapValidationPipeConfigBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ZapValidationPipeConfig<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ZapValidationPipeConfig<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ZapValidationPipeConfig");
+      ds.field("drop_invalid", &self.drop_invalid());
+      ds.finish()
+  }
+}
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZapValidationPipeConfigT {
+  pub drop_invalid: bool,
+}
+impl Default for ZapValidationPipeConfigT {
+  fn default() -> Self {
+    Self {
+      drop_invalid: false,
+    }
+  }
+}
+impl ZapValidationPipeConfigT {
+  pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+    &self,
+    _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
+  ) -> flatbuffers::WIPOffset<ZapValidationPipeConfig<'b>> {
+    let drop_invalid = self.drop_invalid;
+    ZapValidationPipeConfig::create(_fbb, &ZapValidationPipeConfigArgs{
+      drop_invalid,
+    })
+  }
+}
 pub enum PipeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -12662,6 +13357,11 @@ impl<'a> Pipe<'a> {
             .expect("Invalid union table, expected `PipeConfig::MuteFilterPipeConfig`.")
             .unpack()
       )),
+      PipeConfig::ZapValidationPipeConfig => PipeConfigT::ZapValidationPipeConfig(Box::new(
+        self.config_as_zap_validation_pipe_config()
+            .expect("Invalid union table, expected `PipeConfig::ZapValidationPipeConfig`.")
+            .unpack()
+      )),
       _ => PipeConfigT::NONE,
     };
     PipeT {
@@ -12809,6 +13509,20 @@ impl<'a> Pipe<'a> {
     }
   }
 
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn config_as_zap_validation_pipe_config(&self) -> Option<ZapValidationPipeConfig<'a>> {
+    if self.config_type() == PipeConfig::ZapValidationPipeConfig {
+      let u = self.config();
+      // Safety:
+      // Created from a valid Table for this object
+      // Which contains a valid union in this slot
+      Some(unsafe { ZapValidationPipeConfig::init_from_table(u) })
+    } else {
+      None
+    }
+  }
+
 }
 
 impl flatbuffers::Verifiable for Pipe<'_> {
@@ -12829,6 +13543,7 @@ impl flatbuffers::Verifiable for Pipe<'_> {
           PipeConfig::SerializeEventsPipeConfig => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SerializeEventsPipeConfig>>("PipeConfig::SerializeEventsPipeConfig", pos),
           PipeConfig::ProofVerificationPipeConfig => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ProofVerificationPipeConfig>>("PipeConfig::ProofVerificationPipeConfig", pos),
           PipeConfig::MuteFilterPipeConfig => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MuteFilterPipeConfig>>("PipeConfig::MuteFilterPipeConfig", pos),
+          PipeConfig::ZapValidationPipeConfig => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ZapValidationPipeConfig>>("PipeConfig::ZapValidationPipeConfig", pos),
           _ => Ok(()),
         }
      })?
@@ -12947,6 +13662,13 @@ impl core::fmt::Debug for Pipe<'_> {
             ds.field("config", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        PipeConfig::ZapValidationPipeConfig => {
+          if let Some(x) = self.config_as_zap_validation_pipe_config() {
+            ds.field("config", &x)
+          } else {
+            ds.field("config", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("config", &x)
@@ -13349,6 +14071,13 @@ impl<'a> SubscriptionConfig<'a> {
   pub const VT_IS_SLOW: flatbuffers::VOffsetT = 20;
   pub const VT_PAGINATION: flatbuffers::VOffsetT = 22;
   pub const VT_CACHE_ONLY: flatbuffers::VOffsetT = 24;
+  pub const VT_MERGED_WINDOW: flatbuffers::VOffsetT = 26;
+  pub const VT_LINK_PREVIEWS: flatbuffers::VOffsetT = 28;
+  pub const VT_THREAD: flatbuffers::VOffsetT = 30;
+  pub const VT_RESOLVE_MENTIONS: flatbuffers::VOffsetT = 32;
+  pub const VT_BUDGET_BYTES: flatbuffers::VOffsetT = 34;
+  pub const VT_BUDGET_EVENTS: flatbuffers::VOffsetT = 36;
+  pub const VT_VERIFY_NIP05: flatbuffers::VOffsetT = 38;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -13360,11 +14089,18 @@ impl<'a> SubscriptionConfig<'a> {
     args: &'args SubscriptionConfigArgs<'args>
   ) -> flatbuffers::WIPOffset<SubscriptionConfig<'bldr>> {
     let mut builder = SubscriptionConfigBuilder::new(_fbb);
+    builder.add_budget_events(args.budget_events);
+    builder.add_budget_bytes(args.budget_bytes);
     builder.add_timeout_ms(args.timeout_ms);
+    if let Some(x) = args.thread { builder.add_thread(x); }
     if let Some(x) = args.pagination { builder.add_pagination(x); }
     builder.add_bytes_per_event(args.bytes_per_event);
     builder.add_max_events(args.max_events);
     if let Some(x) = args.pipeline { builder.add_pipeline(x); }
+    builder.add_verify_nip05(args.verify_nip05);
+    builder.add_resolve_mentions(args.resolve_mentions);
+    builder.add_link_previews(args.link_previews);
+    builder.add_merged_window(args.merged_window);
     builder.add_cache_only(args.cache_only);
     builder.add_is_slow(args.is_slow);
     builder.add_force(args.force);
@@ -13390,6 +14126,15 @@ impl<'a> SubscriptionConfig<'a> {
       x.to_string()
     });
     let cache_only = self.cache_only();
+    let merged_window = self.merged_window();
+    let link_previews = self.link_previews();
+    let thread = self.thread().map(|x| {
+      x.to_string()
+    });
+    let resolve_mentions = self.resolve_mentions();
+    let budget_bytes = self.budget_bytes();
+    let budget_events = self.budget_events();
+    let verify_nip05 = self.verify_nip05();
     SubscriptionConfigT {
       pipeline,
       close_on_eose,
@@ -13402,6 +14147,13 @@ impl<'a> SubscriptionConfig<'a> {
       is_slow,
       pagination,
       cache_only,
+      merged_window,
+      link_previews,
+      thread,
+      resolve_mentions,
+      budget_bytes,
+      budget_events,
+      verify_nip05,
     }
   }
 
@@ -13482,6 +14234,55 @@ impl<'a> SubscriptionConfig<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<bool>(SubscriptionConfig::VT_CACHE_ONLY, Some(false)).unwrap()}
   }
+  #[inline]
+  pub fn merged_window(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SubscriptionConfig::VT_MERGED_WINDOW, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn link_previews(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SubscriptionConfig::VT_LINK_PREVIEWS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn thread(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SubscriptionConfig::VT_THREAD, None)}
+  }
+  #[inline]
+  pub fn resolve_mentions(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SubscriptionConfig::VT_RESOLVE_MENTIONS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn budget_bytes(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(SubscriptionConfig::VT_BUDGET_BYTES, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn budget_events(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(SubscriptionConfig::VT_BUDGET_EVENTS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn verify_nip05(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SubscriptionConfig::VT_VERIFY_NIP05, Some(false)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for SubscriptionConfig<'_> {
@@ -13502,6 +14303,13 @@ impl flatbuffers::Verifiable for SubscriptionConfig<'_> {
      .visit_field::<bool>("is_slow", Self::VT_IS_SLOW, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("pagination", Self::VT_PAGINATION, false)?
      .visit_field::<bool>("cache_only", Self::VT_CACHE_ONLY, false)?
+     .visit_field::<bool>("merged_window", Self::VT_MERGED_WINDOW, false)?
+     .visit_field::<bool>("link_previews", Self::VT_LINK_PREVIEWS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("thread", Self::VT_THREAD, false)?
+     .visit_field::<bool>("resolve_mentions", Self::VT_RESOLVE_MENTIONS, false)?
+     .visit_field::<u64>("budget_bytes", Self::VT_BUDGET_BYTES, false)?
+     .visit_field::<u64>("budget_events", Self::VT_BUDGET_EVENTS, false)?
+     .visit_field::<bool>("verify_nip05", Self::VT_VERIFY_NIP05, false)?
      .finish();
     Ok(())
   }
@@ -13518,6 +14326,13 @@ pub struct SubscriptionConfigArgs<'a> {
     pub is_slow: bool,
     pub pagination: Option<flatbuffers::WIPOffset<&'a str>>,
     pub cache_only: bool,
+    pub merged_window: bool,
+    pub link_previews: bool,
+    pub thread: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub resolve_mentions: bool,
+    pub budget_bytes: u64,
+    pub budget_events: u64,
+    pub verify_nip05: bool,
 }
 impl<'a> Default for SubscriptionConfigArgs<'a> {
   #[inline]
@@ -13534,6 +14349,13 @@ impl<'a> Default for SubscriptionConfigArgs<'a> {
       is_slow: false,
       pagination: None,
       cache_only: false,
+      merged_window: false,
+      link_previews: false,
+      thread: None,
+      resolve_mentions: false,
+      budget_bytes: 0,
+      budget_events: 0,
+      verify_nip05: false,
     }
   }
 }
@@ -13588,6 +14410,34 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SubscriptionConfigBuilder<'a, '
     self.fbb_.push_slot::<bool>(SubscriptionConfig::VT_CACHE_ONLY, cache_only, false);
   }
   #[inline]
+  pub fn add_merged_window(&mut self, merged_window: bool) {
+    self.fbb_.push_slot::<bool>(SubscriptionConfig::VT_MERGED_WINDOW, merged_window, false);
+  }
+  #[inline]
+  pub fn add_link_previews(&mut self, link_previews: bool) {
+    self.fbb_.push_slot::<bool>(SubscriptionConfig::VT_LINK_PREVIEWS, link_previews, false);
+  }
+  #[inline]
+  pub fn add_thread(&mut self, thread: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SubscriptionConfig::VT_THREAD, thread);
+  }
+  #[inline]
+  pub fn add_resolve_mentions(&mut self, resolve_mentions: bool) {
+    self.fbb_.push_slot::<bool>(SubscriptionConfig::VT_RESOLVE_MENTIONS, resolve_mentions, false);
+  }
+  #[inline]
+  pub fn add_budget_bytes(&mut self, budget_bytes: u64) {
+    self.fbb_.push_slot::<u64>(SubscriptionConfig::VT_BUDGET_BYTES, budget_bytes, 0);
+  }
+  #[inline]
+  pub fn add_budget_events(&mut self, budget_events: u64) {
+    self.fbb_.push_slot::<u64>(SubscriptionConfig::VT_BUDGET_EVENTS, budget_events, 0);
+  }
+  #[inline]
+  pub fn add_verify_nip05(&mut self, verify_nip05: bool) {
+    self.fbb_.push_slot::<bool>(SubscriptionConfig::VT_VERIFY_NIP05, verify_nip05, false);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SubscriptionConfigBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     SubscriptionConfigBuilder {
@@ -13616,6 +14466,13 @@ impl core::fmt::Debug for SubscriptionConfig<'_> {
       ds.field("is_slow", &self.is_slow());
       ds.field("pagination", &self.pagination());
       ds.field("cache_only", &self.cache_only());
+      ds.field("merged_window", &self.merged_window());
+      ds.field("link_previews", &self.link_previews());
+      ds.field("thread", &self.thread());
+      ds.field("resolve_mentions", &self.resolve_mentions());
+      ds.field("budget_bytes", &self.budget_bytes());
+      ds.field("budget_events", &self.budget_events());
+      ds.field("verify_nip05", &self.verify_nip05());
       ds.finish()
   }
 }
@@ -13633,6 +14490,13 @@ pub struct SubscriptionConfigT {
   pub is_slow: bool,
   pub pagination: Option<String>,
   pub cache_only: bool,
+  pub merged_window: bool,
+  pub link_previews: bool,
+  pub thread: Option<String>,
+  pub resolve_mentions: bool,
+  pub budget_bytes: u64,
+  pub budget_events: u64,
+  pub verify_nip05: bool,
 }
 impl Default for SubscriptionConfigT {
   fn default() -> Self {
@@ -13648,6 +14512,13 @@ impl Default for SubscriptionConfigT {
       is_slow: false,
       pagination: None,
       cache_only: false,
+      merged_window: false,
+      link_previews: false,
+      thread: None,
+      resolve_mentions: false,
+      budget_bytes: 0,
+      budget_events: 0,
+      verify_nip05: false,
     }
   }
 }
@@ -13671,6 +14542,15 @@ impl SubscriptionConfigT {
       _fbb.create_string(x)
     });
     let cache_only = self.cache_only;
+    let merged_window = self.merged_window;
+    let link_previews = self.link_previews;
+    let thread = self.thread.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let resolve_mentions = self.resolve_mentions;
+    let budget_bytes = self.budget_bytes;
+    let budget_events = self.budget_events;
+    let verify_nip05 = self.verify_nip05;
     SubscriptionConfig::create(_fbb, &SubscriptionConfigArgs{
       pipeline,
       close_on_eose,
@@ -13683,6 +14563,13 @@ impl SubscriptionConfigT {
       is_slow,
       pagination,
       cache_only,
+      merged_window,
+      link_previews,
+      thread,
+      resolve_mentions,
+      budget_bytes,
+      budget_events,
+      verify_nip05,
     })
   }
 }
@@ -15110,6 +15997,7 @@ impl<'a> Kind0Parsed<'a> {
   pub const VT_IMAGE: flatbuffers::VOffsetT = 38;
   pub const VT_AVATAR: flatbuffers::VOffsetT = 40;
   pub const VT_BACKGROUND: flatbuffers::VOffsetT = 42;
+  pub const VT_NIP05_VERIFIED: flatbuffers::VOffsetT = 44;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -15141,6 +16029,7 @@ impl<'a> Kind0Parsed<'a> {
     if let Some(x) = args.display_name { builder.add_display_name(x); }
     if let Some(x) = args.name { builder.add_name(x); }
     if let Some(x) = args.pubkey { builder.add_pubkey(x); }
+    builder.add_nip05_verified(args.nip05_verified);
     builder.finish()
   }
 
@@ -15205,6 +16094,7 @@ impl<'a> Kind0Parsed<'a> {
     let background = self.background().map(|x| {
       x.to_string()
     });
+    let nip05_verified = self.nip05_verified();
     Kind0ParsedT {
       pubkey,
       name,
@@ -15226,6 +16116,7 @@ impl<'a> Kind0Parsed<'a> {
       image,
       avatar,
       background,
+      nip05_verified,
     }
   }
 
@@ -15369,6 +16260,13 @@ impl<'a> Kind0Parsed<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind0Parsed::VT_BACKGROUND, None)}
   }
+  #[inline]
+  pub fn nip05_verified(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Kind0Parsed::VT_NIP05_VERIFIED, Some(false)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for Kind0Parsed<'_> {
@@ -15398,6 +16296,7 @@ impl flatbuffers::Verifiable for Kind0Parsed<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("image", Self::VT_IMAGE, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("avatar", Self::VT_AVATAR, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("background", Self::VT_BACKGROUND, false)?
+     .visit_field::<bool>("nip05_verified", Self::VT_NIP05_VERIFIED, false)?
      .finish();
     Ok(())
   }
@@ -15423,6 +16322,7 @@ pub struct Kind0ParsedArgs<'a> {
     pub image: Option<flatbuffers::WIPOffset<&'a str>>,
     pub avatar: Option<flatbuffers::WIPOffset<&'a str>>,
     pub background: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub nip05_verified: bool,
 }
 impl<'a> Default for Kind0ParsedArgs<'a> {
   #[inline]
@@ -15448,6 +16348,7 @@ impl<'a> Default for Kind0ParsedArgs<'a> {
       image: None,
       avatar: None,
       background: None,
+      nip05_verified: false,
     }
   }
 }
@@ -15538,6 +16439,10 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> Kind0ParsedBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind0Parsed::VT_BACKGROUND, background);
   }
   #[inline]
+  pub fn add_nip05_verified(&mut self, nip05_verified: bool) {
+    self.fbb_.push_slot::<bool>(Kind0Parsed::VT_NIP05_VERIFIED, nip05_verified, false);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> Kind0ParsedBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     Kind0ParsedBuilder {
@@ -15575,6 +16480,7 @@ impl core::fmt::Debug for Kind0Parsed<'_> {
       ds.field("image", &self.image());
       ds.field("avatar", &self.avatar());
       ds.field("background", &self.background());
+      ds.field("nip05_verified", &self.nip05_verified());
       ds.finish()
   }
 }
@@ -15601,6 +16507,7 @@ pub struct Kind0ParsedT {
   pub image: Option<String>,
   pub avatar: Option<String>,
   pub background: Option<String>,
+  pub nip05_verified: bool,
 }
 impl Default for Kind0ParsedT {
   fn default() -> Self {
@@ -15625,6 +16532,7 @@ impl Default for Kind0ParsedT {
       image: None,
       avatar: None,
       background: None,
+      nip05_verified: false,
     }
   }
 }
@@ -15693,6 +16601,7 @@ impl Kind0ParsedT {
     let background = self.background.as_ref().map(|x|{
       _fbb.create_string(x)
     });
+    let nip05_verified = self.nip05_verified;
     Kind0Parsed::create(_fbb, &Kind0ParsedArgs{
       pubkey,
       name,
@@ -15714,6 +16623,7 @@ impl Kind0ParsedT {
       image,
       avatar,
       background,
+      nip05_verified,
     })
   }
 }
@@ -17132,461 +18042,865 @@ impl Kind8ParsedT {
     })
   }
 }
-pub enum Kind17ParsedOffset {}
+pub enum Kind14ParsedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Kind17Parsed<'a> {
+pub struct Kind14Parsed<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Kind17Parsed<'a> {
-  type Inner = Kind17Parsed<'a>;
+impl<'a> flatbuffers::Follow<'a> for Kind14Parsed<'a> {
+  type Inner = Kind14Parsed<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> Kind17Parsed<'a> {
-  pub const VT_REACTION_TYPE: flatbuffers::VOffsetT = 4;
-  pub const VT_EVENT_ID: flatbuffers::VOffsetT = 6;
-  pub const VT_PUBKEY: flatbuffers::VOffsetT = 8;
-  pub const VT_EVENT_KIND: flatbuffers::VOffsetT = 10;
-  pub const VT_EMOJI: flatbuffers::VOffsetT = 12;
-  pub const VT_TARGET_COORDINATES: flatbuffers::VOffsetT = 14;
+impl<'a> Kind14Parsed<'a> {
+  pub const VT_KIND: flatbuffers::VOffsetT = 4;
+  pub const VT_RUMOR_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_SENDER: flatbuffers::VOffsetT = 8;
+  pub const VT_CREATED_AT: flatbuffers::VOffsetT = 10;
+  pub const VT_PARTICIPANTS: flatbuffers::VOffsetT = 12;
+  pub const VT_CHAT_ID: flatbuffers::VOffsetT = 14;
+  pub const VT_CONTENT: flatbuffers::VOffsetT = 16;
+  pub const VT_PARSED_CONTENT: flatbuffers::VOffsetT = 18;
+  pub const VT_SUBJECT: flatbuffers::VOffsetT = 20;
+  pub const VT_REPLY_TO: flatbuffers::VOffsetT = 22;
+  pub const VT_TAGS: flatbuffers::VOffsetT = 24;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Kind17Parsed { _tab: table }
+    Kind14Parsed { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args Kind17ParsedArgs<'args>
-  ) -> flatbuffers::WIPOffset<Kind17Parsed<'bldr>> {
-    let mut builder = Kind17ParsedBuilder::new(_fbb);
-    builder.add_event_kind(args.event_kind);
-    if let Some(x) = args.target_coordinates { builder.add_target_coordinates(x); }
-    if let Some(x) = args.emoji { builder.add_emoji(x); }
-    if let Some(x) = args.pubkey { builder.add_pubkey(x); }
-    if let Some(x) = args.event_id { builder.add_event_id(x); }
-    builder.add_reaction_type(args.reaction_type);
+    args: &'args Kind14ParsedArgs<'args>
+  ) -> flatbuffers::WIPOffset<Kind14Parsed<'bldr>> {
+    let mut builder = Kind14ParsedBuilder::new(_fbb);
+    if let Some(x) = args.tags { builder.add_tags(x); }
+    if let Some(x) = args.reply_to { builder.add_reply_to(x); }
+    if let Some(x) = args.subject { builder.add_subject(x); }
+    if let Some(x) = args.parsed_content { builder.add_parsed_content(x); }
+    if let Some(x) = args.content { builder.add_content(x); }
+    if let Some(x) = args.chat_id { builder.add_chat_id(x); }
+    if let Some(x) = args.participants { builder.add_participants(x); }
+    builder.add_created_at(args.created_at);
+    if let Some(x) = args.sender { builder.add_sender(x); }
+    if let Some(x) = args.rumor_id { builder.add_rumor_id(x); }
+    builder.add_kind(args.kind);
     builder.finish()
   }
 
-  pub fn unpack(&self) -> Kind17ParsedT {
-    let reaction_type = self.reaction_type();
-    let event_id = {
-      let x = self.event_id();
+  pub fn unpack(&self) -> Kind14ParsedT {
+    let kind = self.kind();
+    let rumor_id = {
+      let x = self.rumor_id();
       x.to_string()
     };
-    let pubkey = {
-      let x = self.pubkey();
+    let sender = {
+      let x = self.sender();
       x.to_string()
     };
-    let event_kind = self.event_kind();
-    let emoji = self.emoji().map(|x| {
-      Box::new(x.unpack())
-    });
-    let target_coordinates = self.target_coordinates().map(|x| {
+    let created_at = self.created_at();
+    let participants = {
+      let x = self.participants();
+      x.iter().map(|s| s.to_string()).collect()
+    };
+    let chat_id = {
+      let x = self.chat_id();
+      x.to_string()
+    };
+    let content = self.content().map(|x| {
       x.to_string()
     });
-    Kind17ParsedT {
-      reaction_type,
-      event_id,
-      pubkey,
-      event_kind,
-      emoji,
-      target_coordinates,
+    let parsed_content = self.parsed_content().map(|x| {
+      x.iter().map(|t| t.unpack()).collect()
+    });
+    let subject = self.subject().map(|x| {
+      x.to_string()
+    });
+    let reply_to = self.reply_to().map(|x| {
+      x.to_string()
+    });
+    let tags = self.tags().map(|x| {
+      x.iter().map(|t| t.unpack()).collect()
+    });
+    Kind14ParsedT {
+      kind,
+      rumor_id,
+      sender,
+      created_at,
+      participants,
+      chat_id,
+      content,
+      parsed_content,
+      subject,
+      reply_to,
+      tags,
     }
   }
 
   #[inline]
-  pub fn reaction_type(&self) -> ReactionType {
+  pub fn kind(&self) -> u16 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<ReactionType>(Kind17Parsed::VT_REACTION_TYPE, Some(ReactionType::Like)).unwrap()}
+    unsafe { self._tab.get::<u16>(Kind14Parsed::VT_KIND, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn event_id(&self) -> &'a str {
+  pub fn rumor_id(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind17Parsed::VT_EVENT_ID, None).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind14Parsed::VT_RUMOR_ID, None).unwrap()}
   }
   #[inline]
-  pub fn pubkey(&self) -> &'a str {
+  pub fn sender(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind17Parsed::VT_PUBKEY, None).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind14Parsed::VT_SENDER, None).unwrap()}
   }
   #[inline]
-  pub fn event_kind(&self) -> u64 {
+  pub fn created_at(&self) -> u32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(Kind17Parsed::VT_EVENT_KIND, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u32>(Kind14Parsed::VT_CREATED_AT, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn emoji(&self) -> Option<Emoji<'a>> {
+  pub fn participants(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Emoji>>(Kind17Parsed::VT_EMOJI, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Kind14Parsed::VT_PARTICIPANTS, None).unwrap()}
   }
   #[inline]
-  pub fn target_coordinates(&self) -> Option<&'a str> {
+  pub fn chat_id(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind17Parsed::VT_TARGET_COORDINATES, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind14Parsed::VT_CHAT_ID, None).unwrap()}
+  }
+  #[inline]
+  pub fn content(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind14Parsed::VT_CONTENT, None)}
+  }
+  #[inline]
+  pub fn parsed_content(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ContentBlock<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ContentBlock>>>>(Kind14Parsed::VT_PARSED_CONTENT, None)}
+  }
+  #[inline]
+  pub fn subject(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind14Parsed::VT_SUBJECT, None)}
+  }
+  #[inline]
+  pub fn reply_to(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind14Parsed::VT_REPLY_TO, None)}
+  }
+  #[inline]
+  pub fn tags(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StringVec<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StringVec>>>>(Kind14Parsed::VT_TAGS, None)}
   }
 }
 
-impl flatbuffers::Verifiable for Kind17Parsed<'_> {
+impl flatbuffers::Verifiable for Kind14Parsed<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<ReactionType>("reaction_type", Self::VT_REACTION_TYPE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("event_id", Self::VT_EVENT_ID, true)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("pubkey", Self::VT_PUBKEY, true)?
-     .visit_field::<u64>("event_kind", Self::VT_EVENT_KIND, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<Emoji>>("emoji", Self::VT_EMOJI, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("target_coordinates", Self::VT_TARGET_COORDINATES, false)?
+     .visit_field::<u16>("kind", Self::VT_KIND, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("rumor_id", Self::VT_RUMOR_ID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sender", Self::VT_SENDER, true)?
+     .visit_field::<u32>("created_at", Self::VT_CREATED_AT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("participants", Self::VT_PARTICIPANTS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("chat_id", Self::VT_CHAT_ID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("content", Self::VT_CONTENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ContentBlock>>>>("parsed_content", Self::VT_PARSED_CONTENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("subject", Self::VT_SUBJECT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reply_to", Self::VT_REPLY_TO, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StringVec>>>>("tags", Self::VT_TAGS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct Kind17ParsedArgs<'a> {
-    pub reaction_type: ReactionType,
-    pub event_id: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub pubkey: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub event_kind: u64,
-    pub emoji: Option<flatbuffers::WIPOffset<Emoji<'a>>>,
-    pub target_coordinates: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct Kind14ParsedArgs<'a> {
+    pub kind: u16,
+    pub rumor_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub sender: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub created_at: u32,
+    pub participants: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub chat_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub content: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub parsed_content: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ContentBlock<'a>>>>>,
+    pub subject: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub reply_to: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub tags: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StringVec<'a>>>>>,
 }
-impl<'a> Default for Kind17ParsedArgs<'a> {
+impl<'a> Default for Kind14ParsedArgs<'a> {
   #[inline]
   fn default() -> Self {
-    Kind17ParsedArgs {
-      reaction_type: ReactionType::Like,
-      event_id: None, // required field
-      pubkey: None, // required field
-      event_kind: 0,
-      emoji: None,
-      target_coordinates: None,
+    Kind14ParsedArgs {
+      kind: 0,
+      rumor_id: None, // required field
+      sender: None, // required field
+      created_at: 0,
+      participants: None, // required field
+      chat_id: None, // required field
+      content: None,
+      parsed_content: None,
+      subject: None,
+      reply_to: None,
+      tags: None,
     }
   }
 }
 
-pub struct Kind17ParsedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct Kind14ParsedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> Kind17ParsedBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> Kind14ParsedBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_reaction_type(&mut self, reaction_type: ReactionType) {
-    self.fbb_.push_slot::<ReactionType>(Kind17Parsed::VT_REACTION_TYPE, reaction_type, ReactionType::Like);
+  pub fn add_kind(&mut self, kind: u16) {
+    self.fbb_.push_slot::<u16>(Kind14Parsed::VT_KIND, kind, 0);
   }
   #[inline]
-  pub fn add_event_id(&mut self, event_id: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind17Parsed::VT_EVENT_ID, event_id);
+  pub fn add_rumor_id(&mut self, rumor_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind14Parsed::VT_RUMOR_ID, rumor_id);
   }
   #[inline]
-  pub fn add_pubkey(&mut self, pubkey: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind17Parsed::VT_PUBKEY, pubkey);
+  pub fn add_sender(&mut self, sender: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind14Parsed::VT_SENDER, sender);
   }
   #[inline]
-  pub fn add_event_kind(&mut self, event_kind: u64) {
-    self.fbb_.push_slot::<u64>(Kind17Parsed::VT_EVENT_KIND, event_kind, 0);
+  pub fn add_created_at(&mut self, created_at: u32) {
+    self.fbb_.push_slot::<u32>(Kind14Parsed::VT_CREATED_AT, created_at, 0);
   }
   #[inline]
-  pub fn add_emoji(&mut self, emoji: flatbuffers::WIPOffset<Emoji<'b >>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Emoji>>(Kind17Parsed::VT_EMOJI, emoji);
+  pub fn add_participants(&mut self, participants: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind14Parsed::VT_PARTICIPANTS, participants);
   }
   #[inline]
-  pub fn add_target_coordinates(&mut self, target_coordinates: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind17Parsed::VT_TARGET_COORDINATES, target_coordinates);
+  pub fn add_chat_id(&mut self, chat_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind14Parsed::VT_CHAT_ID, chat_id);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> Kind17ParsedBuilder<'a, 'b, A> {
+  pub fn add_content(&mut self, content: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind14Parsed::VT_CONTENT, content);
+  }
+  #[inline]
+  pub fn add_parsed_content(&mut self, parsed_content: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ContentBlock<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind14Parsed::VT_PARSED_CONTENT, parsed_content);
+  }
+  #[inline]
+  pub fn add_subject(&mut self, subject: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind14Parsed::VT_SUBJECT, subject);
+  }
+  #[inline]
+  pub fn add_reply_to(&mut self, reply_to: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind14Parsed::VT_REPLY_TO, reply_to);
+  }
+  #[inline]
+  pub fn add_tags(&mut self, tags: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<StringVec<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind14Parsed::VT_TAGS, tags);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> Kind14ParsedBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    Kind17ParsedBuilder {
+    Kind14ParsedBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Kind17Parsed<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<Kind14Parsed<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, Kind17Parsed::VT_EVENT_ID,"event_id");
-    self.fbb_.required(o, Kind17Parsed::VT_PUBKEY,"pubkey");
+    self.fbb_.required(o, Kind14Parsed::VT_RUMOR_ID,"rumor_id");
+    self.fbb_.required(o, Kind14Parsed::VT_SENDER,"sender");
+    self.fbb_.required(o, Kind14Parsed::VT_PARTICIPANTS,"participants");
+    self.fbb_.required(o, Kind14Parsed::VT_CHAT_ID,"chat_id");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Kind17Parsed<'_> {
+impl core::fmt::Debug for Kind14Parsed<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Kind17Parsed");
-      ds.field("reaction_type", &self.reaction_type());
-      ds.field("event_id", &self.event_id());
-      ds.field("pubkey", &self.pubkey());
-      ds.field("event_kind", &self.event_kind());
-      ds.field("emoji", &self.emoji());
-      ds.field("target_coordinates", &self.target_coordinates());
+    let mut ds = f.debug_struct("Kind14Parsed");
+      ds.field("kind", &self.kind());
+      ds.field("rumor_id", &self.rumor_id());
+      ds.field("sender", &self.sender());
+      ds.field("created_at", &self.created_at());
+      ds.field("participants", &self.participants());
+      ds.field("chat_id", &self.chat_id());
+      ds.field("content", &self.content());
+      ds.field("parsed_content", &self.parsed_content());
+      ds.field("subject", &self.subject());
+      ds.field("reply_to", &self.reply_to());
+      ds.field("tags", &self.tags());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct Kind17ParsedT {
-  pub reaction_type: ReactionType,
-  pub event_id: String,
-  pub pubkey: String,
-  pub event_kind: u64,
-  pub emoji: Option<Box<EmojiT>>,
-  pub target_coordinates: Option<String>,
+pub struct Kind14ParsedT {
+  pub kind: u16,
+  pub rumor_id: String,
+  pub sender: String,
+  pub created_at: u32,
+  pub participants: Vec<String>,
+  pub chat_id: String,
+  pub content: Option<String>,
+  pub parsed_content: Option<Vec<ContentBlockT>>,
+  pub subject: Option<String>,
+  pub reply_to: Option<String>,
+  pub tags: Option<Vec<StringVecT>>,
 }
-impl Default for Kind17ParsedT {
+impl Default for Kind14ParsedT {
   fn default() -> Self {
     Self {
-      reaction_type: ReactionType::Like,
-      event_id: "".to_string(),
-      pubkey: "".to_string(),
-      event_kind: 0,
-      emoji: None,
-      target_coordinates: None,
+      kind: 0,
+      rumor_id: "".to_string(),
+      sender: "".to_string(),
+      created_at: 0,
+      participants: Default::default(),
+      chat_id: "".to_string(),
+      content: None,
+      parsed_content: None,
+      subject: None,
+      reply_to: None,
+      tags: None,
     }
   }
 }
-impl Kind17ParsedT {
+impl Kind14ParsedT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<Kind17Parsed<'b>> {
-    let reaction_type = self.reaction_type;
-    let event_id = Some({
-      let x = &self.event_id;
+  ) -> flatbuffers::WIPOffset<Kind14Parsed<'b>> {
+    let kind = self.kind;
+    let rumor_id = Some({
+      let x = &self.rumor_id;
       _fbb.create_string(x)
     });
-    let pubkey = Some({
-      let x = &self.pubkey;
+    let sender = Some({
+      let x = &self.sender;
       _fbb.create_string(x)
     });
-    let event_kind = self.event_kind;
-    let emoji = self.emoji.as_ref().map(|x|{
-      x.pack(_fbb)
+    let created_at = self.created_at;
+    let participants = Some({
+      let x = &self.participants;
+      let w: Vec<_> = x.iter().map(|s| _fbb.create_string(s)).collect();_fbb.create_vector(&w)
     });
-    let target_coordinates = self.target_coordinates.as_ref().map(|x|{
+    let chat_id = Some({
+      let x = &self.chat_id;
       _fbb.create_string(x)
     });
-    Kind17Parsed::create(_fbb, &Kind17ParsedArgs{
-      reaction_type,
-      event_id,
-      pubkey,
-      event_kind,
-      emoji,
-      target_coordinates,
+    let content = self.content.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let parsed_content = self.parsed_content.as_ref().map(|x|{
+      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+    });
+    let subject = self.subject.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let reply_to = self.reply_to.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let tags = self.tags.as_ref().map(|x|{
+      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+    });
+    Kind14Parsed::create(_fbb, &Kind14ParsedArgs{
+      kind,
+      rumor_id,
+      sender,
+      created_at,
+      participants,
+      chat_id,
+      content,
+      parsed_content,
+      subject,
+      reply_to,
+      tags,
     })
   }
 }
-pub enum Kind10002ParsedOffset {}
+pub enum Kind17ParsedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Kind10002Parsed<'a> {
+pub struct Kind17Parsed<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Kind10002Parsed<'a> {
-  type Inner = Kind10002Parsed<'a>;
+impl<'a> flatbuffers::Follow<'a> for Kind17Parsed<'a> {
+  type Inner = Kind17Parsed<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> Kind10002Parsed<'a> {
-  pub const VT_RELAYS: flatbuffers::VOffsetT = 4;
+impl<'a> Kind17Parsed<'a> {
+  pub const VT_REACTION_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_EVENT_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_PUBKEY: flatbuffers::VOffsetT = 8;
+  pub const VT_EVENT_KIND: flatbuffers::VOffsetT = 10;
+  pub const VT_EMOJI: flatbuffers::VOffsetT = 12;
+  pub const VT_TARGET_COORDINATES: flatbuffers::VOffsetT = 14;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Kind10002Parsed { _tab: table }
+    Kind17Parsed { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args Kind10002ParsedArgs<'args>
-  ) -> flatbuffers::WIPOffset<Kind10002Parsed<'bldr>> {
-    let mut builder = Kind10002ParsedBuilder::new(_fbb);
-    if let Some(x) = args.relays { builder.add_relays(x); }
+    args: &'args Kind17ParsedArgs<'args>
+  ) -> flatbuffers::WIPOffset<Kind17Parsed<'bldr>> {
+    let mut builder = Kind17ParsedBuilder::new(_fbb);
+    builder.add_event_kind(args.event_kind);
+    if let Some(x) = args.target_coordinates { builder.add_target_coordinates(x); }
+    if let Some(x) = args.emoji { builder.add_emoji(x); }
+    if let Some(x) = args.pubkey { builder.add_pubkey(x); }
+    if let Some(x) = args.event_id { builder.add_event_id(x); }
+    builder.add_reaction_type(args.reaction_type);
     builder.finish()
   }
 
-  pub fn unpack(&self) -> Kind10002ParsedT {
-    let relays = {
-      let x = self.relays();
-      x.iter().map(|t| t.unpack()).collect()
+  pub fn unpack(&self) -> Kind17ParsedT {
+    let reaction_type = self.reaction_type();
+    let event_id = {
+      let x = self.event_id();
+      x.to_string()
     };
-    Kind10002ParsedT {
-      relays,
+    let pubkey = {
+      let x = self.pubkey();
+      x.to_string()
+    };
+    let event_kind = self.event_kind();
+    let emoji = self.emoji().map(|x| {
+      Box::new(x.unpack())
+    });
+    let target_coordinates = self.target_coordinates().map(|x| {
+      x.to_string()
+    });
+    Kind17ParsedT {
+      reaction_type,
+      event_id,
+      pubkey,
+      event_kind,
+      emoji,
+      target_coordinates,
     }
   }
 
   #[inline]
-  pub fn relays(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RelayInfo<'a>>> {
+  pub fn reaction_type(&self) -> ReactionType {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RelayInfo>>>>(Kind10002Parsed::VT_RELAYS, None).unwrap()}
+    unsafe { self._tab.get::<ReactionType>(Kind17Parsed::VT_REACTION_TYPE, Some(ReactionType::Like)).unwrap()}
+  }
+  #[inline]
+  pub fn event_id(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind17Parsed::VT_EVENT_ID, None).unwrap()}
+  }
+  #[inline]
+  pub fn pubkey(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind17Parsed::VT_PUBKEY, None).unwrap()}
+  }
+  #[inline]
+  pub fn event_kind(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Kind17Parsed::VT_EVENT_KIND, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn emoji(&self) -> Option<Emoji<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Emoji>>(Kind17Parsed::VT_EMOJI, None)}
+  }
+  #[inline]
+  pub fn target_coordinates(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind17Parsed::VT_TARGET_COORDINATES, None)}
   }
 }
 
-impl flatbuffers::Verifiable for Kind10002Parsed<'_> {
+impl flatbuffers::Verifiable for Kind17Parsed<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RelayInfo>>>>("relays", Self::VT_RELAYS, true)?
+     .visit_field::<ReactionType>("reaction_type", Self::VT_REACTION_TYPE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("event_id", Self::VT_EVENT_ID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("pubkey", Self::VT_PUBKEY, true)?
+     .visit_field::<u64>("event_kind", Self::VT_EVENT_KIND, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Emoji>>("emoji", Self::VT_EMOJI, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("target_coordinates", Self::VT_TARGET_COORDINATES, false)?
      .finish();
     Ok(())
   }
 }
-pub struct Kind10002ParsedArgs<'a> {
-    pub relays: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RelayInfo<'a>>>>>,
+pub struct Kind17ParsedArgs<'a> {
+    pub reaction_type: ReactionType,
+    pub event_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub pubkey: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub event_kind: u64,
+    pub emoji: Option<flatbuffers::WIPOffset<Emoji<'a>>>,
+    pub target_coordinates: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for Kind10002ParsedArgs<'a> {
+impl<'a> Default for Kind17ParsedArgs<'a> {
   #[inline]
   fn default() -> Self {
-    Kind10002ParsedArgs {
-      relays: None, // required field
+    Kind17ParsedArgs {
+      reaction_type: ReactionType::Like,
+      event_id: None, // required field
+      pubkey: None, // required field
+      event_kind: 0,
+      emoji: None,
+      target_coordinates: None,
     }
   }
 }
 
-pub struct Kind10002ParsedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct Kind17ParsedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> Kind10002ParsedBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> Kind17ParsedBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_relays(&mut self, relays: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<RelayInfo<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind10002Parsed::VT_RELAYS, relays);
+  pub fn add_reaction_type(&mut self, reaction_type: ReactionType) {
+    self.fbb_.push_slot::<ReactionType>(Kind17Parsed::VT_REACTION_TYPE, reaction_type, ReactionType::Like);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> Kind10002ParsedBuilder<'a, 'b, A> {
+  pub fn add_event_id(&mut self, event_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind17Parsed::VT_EVENT_ID, event_id);
+  }
+  #[inline]
+  pub fn add_pubkey(&mut self, pubkey: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind17Parsed::VT_PUBKEY, pubkey);
+  }
+  #[inline]
+  pub fn add_event_kind(&mut self, event_kind: u64) {
+    self.fbb_.push_slot::<u64>(Kind17Parsed::VT_EVENT_KIND, event_kind, 0);
+  }
+  #[inline]
+  pub fn add_emoji(&mut self, emoji: flatbuffers::WIPOffset<Emoji<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Emoji>>(Kind17Parsed::VT_EMOJI, emoji);
+  }
+  #[inline]
+  pub fn add_target_coordinates(&mut self, target_coordinates: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind17Parsed::VT_TARGET_COORDINATES, target_coordinates);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> Kind17ParsedBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    Kind10002ParsedBuilder {
+    Kind17ParsedBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Kind10002Parsed<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<Kind17Parsed<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, Kind10002Parsed::VT_RELAYS,"relays");
+    self.fbb_.required(o, Kind17Parsed::VT_EVENT_ID,"event_id");
+    self.fbb_.required(o, Kind17Parsed::VT_PUBKEY,"pubkey");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Kind10002Parsed<'_> {
+impl core::fmt::Debug for Kind17Parsed<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Kind10002Parsed");
-      ds.field("relays", &self.relays());
+    let mut ds = f.debug_struct("Kind17Parsed");
+      ds.field("reaction_type", &self.reaction_type());
+      ds.field("event_id", &self.event_id());
+      ds.field("pubkey", &self.pubkey());
+      ds.field("event_kind", &self.event_kind());
+      ds.field("emoji", &self.emoji());
+      ds.field("target_coordinates", &self.target_coordinates());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct Kind10002ParsedT {
-  pub relays: Vec<RelayInfoT>,
+pub struct Kind17ParsedT {
+  pub reaction_type: ReactionType,
+  pub event_id: String,
+  pub pubkey: String,
+  pub event_kind: u64,
+  pub emoji: Option<Box<EmojiT>>,
+  pub target_coordinates: Option<String>,
 }
-impl Default for Kind10002ParsedT {
+impl Default for Kind17ParsedT {
   fn default() -> Self {
     Self {
-      relays: Default::default(),
+      reaction_type: ReactionType::Like,
+      event_id: "".to_string(),
+      pubkey: "".to_string(),
+      event_kind: 0,
+      emoji: None,
+      target_coordinates: None,
     }
   }
 }
-impl Kind10002ParsedT {
+impl Kind17ParsedT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<Kind10002Parsed<'b>> {
-    let relays = Some({
-      let x = &self.relays;
-      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+  ) -> flatbuffers::WIPOffset<Kind17Parsed<'b>> {
+    let reaction_type = self.reaction_type;
+    let event_id = Some({
+      let x = &self.event_id;
+      _fbb.create_string(x)
     });
-    Kind10002Parsed::create(_fbb, &Kind10002ParsedArgs{
-      relays,
+    let pubkey = Some({
+      let x = &self.pubkey;
+      _fbb.create_string(x)
+    });
+    let event_kind = self.event_kind;
+    let emoji = self.emoji.as_ref().map(|x|{
+      x.pack(_fbb)
+    });
+    let target_coordinates = self.target_coordinates.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    Kind17Parsed::create(_fbb, &Kind17ParsedArgs{
+      reaction_type,
+      event_id,
+      pubkey,
+      event_kind,
+      emoji,
+      target_coordinates,
     })
   }
 }
-pub enum Kind10019ParsedOffset {}
+pub enum Kind10002ParsedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Kind10019Parsed<'a> {
+pub struct Kind10002Parsed<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Kind10019Parsed<'a> {
-  type Inner = Kind10019Parsed<'a>;
+impl<'a> flatbuffers::Follow<'a> for Kind10002Parsed<'a> {
+  type Inner = Kind10002Parsed<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> Kind10019Parsed<'a> {
-  pub const VT_TRUSTED_MINTS: flatbuffers::VOffsetT = 4;
-  pub const VT_P2PK_PUBKEY: flatbuffers::VOffsetT = 6;
-  pub const VT_READ_RELAYS: flatbuffers::VOffsetT = 8;
+impl<'a> Kind10002Parsed<'a> {
+  pub const VT_RELAYS: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Kind10019Parsed { _tab: table }
+    Kind10002Parsed { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args Kind10019ParsedArgs<'args>
-  ) -> flatbuffers::WIPOffset<Kind10019Parsed<'bldr>> {
-    let mut builder = Kind10019ParsedBuilder::new(_fbb);
-    if let Some(x) = args.read_relays { builder.add_read_relays(x); }
-    if let Some(x) = args.p2pk_pubkey { builder.add_p2pk_pubkey(x); }
-    if let Some(x) = args.trusted_mints { builder.add_trusted_mints(x); }
+    args: &'args Kind10002ParsedArgs<'args>
+  ) -> flatbuffers::WIPOffset<Kind10002Parsed<'bldr>> {
+    let mut builder = Kind10002ParsedBuilder::new(_fbb);
+    if let Some(x) = args.relays { builder.add_relays(x); }
     builder.finish()
   }
 
-  pub fn unpack(&self) -> Kind10019ParsedT {
-    let trusted_mints = self.trusted_mints().map(|x| {
+  pub fn unpack(&self) -> Kind10002ParsedT {
+    let relays = {
+      let x = self.relays();
       x.iter().map(|t| t.unpack()).collect()
-    });
-    let p2pk_pubkey = self.p2pk_pubkey().map(|x| {
-      x.to_string()
-    });
-    let read_relays = self.read_relays().map(|x| {
-      x.iter().map(|s| s.to_string()).collect()
-    });
-    Kind10019ParsedT {
-      trusted_mints,
-      p2pk_pubkey,
-      read_relays,
+    };
+    Kind10002ParsedT {
+      relays,
     }
   }
 
   #[inline]
-  pub fn trusted_mints(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MintInfo<'a>>>> {
+  pub fn relays(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RelayInfo<'a>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MintInfo>>>>(Kind10019Parsed::VT_TRUSTED_MINTS, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RelayInfo>>>>(Kind10002Parsed::VT_RELAYS, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Kind10002Parsed<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RelayInfo>>>>("relays", Self::VT_RELAYS, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct Kind10002ParsedArgs<'a> {
+    pub relays: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RelayInfo<'a>>>>>,
+}
+impl<'a> Default for Kind10002ParsedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    Kind10002ParsedArgs {
+      relays: None, // required field
+    }
+  }
+}
+
+pub struct Kind10002ParsedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> Kind10002ParsedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_relays(&mut self, relays: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<RelayInfo<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind10002Parsed::VT_RELAYS, relays);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> Kind10002ParsedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    Kind10002ParsedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Kind10002Parsed<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Kind10002Parsed::VT_RELAYS,"relays");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Kind10002Parsed<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Kind10002Parsed");
+      ds.field("relays", &self.relays());
+      ds.finish()
+  }
+}
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kind10002ParsedT {
+  pub relays: Vec<RelayInfoT>,
+}
+impl Default for Kind10002ParsedT {
+  fn default() -> Self {
+    Self {
+      relays: Default::default(),
+    }
+  }
+}
+impl Kind10002ParsedT {
+  pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+    &self,
+    _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
+  ) -> flatbuffers::WIPOffset<Kind10002Parsed<'b>> {
+    let relays = Some({
+      let x = &self.relays;
+      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+    });
+    Kind10002Parsed::create(_fbb, &Kind10002ParsedArgs{
+      relays,
+    })
+  }
+}
+pub enum Kind10019ParsedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Kind10019Parsed<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Kind10019Parsed<'a> {
+  type Inner = Kind10019Parsed<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Kind10019Parsed<'a> {
+  pub const VT_TRUSTED_MINTS: flatbuffers::VOffsetT = 4;
+  pub const VT_P2PK_PUBKEY: flatbuffers::VOffsetT = 6;
+  pub const VT_READ_RELAYS: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Kind10019Parsed { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args Kind10019ParsedArgs<'args>
+  ) -> flatbuffers::WIPOffset<Kind10019Parsed<'bldr>> {
+    let mut builder = Kind10019ParsedBuilder::new(_fbb);
+    if let Some(x) = args.read_relays { builder.add_read_relays(x); }
+    if let Some(x) = args.p2pk_pubkey { builder.add_p2pk_pubkey(x); }
+    if let Some(x) = args.trusted_mints { builder.add_trusted_mints(x); }
+    builder.finish()
+  }
+
+  pub fn unpack(&self) -> Kind10019ParsedT {
+    let trusted_mints = self.trusted_mints().map(|x| {
+      x.iter().map(|t| t.unpack()).collect()
+    });
+    let p2pk_pubkey = self.p2pk_pubkey().map(|x| {
+      x.to_string()
+    });
+    let read_relays = self.read_relays().map(|x| {
+      x.iter().map(|s| s.to_string()).collect()
+    });
+    Kind10019ParsedT {
+      trusted_mints,
+      p2pk_pubkey,
+      read_relays,
+    }
+  }
+
+  #[inline]
+  pub fn trusted_mints(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MintInfo<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MintInfo>>>>(Kind10019Parsed::VT_TRUSTED_MINTS, None)}
   }
   #[inline]
   pub fn p2pk_pubkey(&self) -> Option<&'a str> {
@@ -19039,6 +20353,8 @@ impl<'a> Kind9735Parsed<'a> {
   pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 22;
   pub const VT_VALID: flatbuffers::VOffsetT = 24;
   pub const VT_DESCRIPTION: flatbuffers::VOffsetT = 26;
+  pub const VT_INVALID_REASON: flatbuffers::VOffsetT = 28;
+  pub const VT_PROVIDER_VERIFIED: flatbuffers::VOffsetT = 30;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -19051,6 +20367,7 @@ impl<'a> Kind9735Parsed<'a> {
   ) -> flatbuffers::WIPOffset<Kind9735Parsed<'bldr>> {
     let mut builder = Kind9735ParsedBuilder::new(_fbb);
     builder.add_timestamp(args.timestamp);
+    if let Some(x) = args.invalid_reason { builder.add_invalid_reason(x); }
     if let Some(x) = args.description { builder.add_description(x); }
     if let Some(x) = args.event_coordinate { builder.add_event_coordinate(x); }
     if let Some(x) = args.event { builder.add_event(x); }
@@ -19061,6 +20378,7 @@ impl<'a> Kind9735Parsed<'a> {
     if let Some(x) = args.content { builder.add_content(x); }
     builder.add_amount(args.amount);
     if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_provider_verified(args.provider_verified);
     builder.add_valid(args.valid);
     builder.finish()
   }
@@ -19102,6 +20420,10 @@ impl<'a> Kind9735Parsed<'a> {
       let x = self.description();
       Box::new(x.unpack())
     };
+    let invalid_reason = self.invalid_reason().map(|x| {
+      x.to_string()
+    });
+    let provider_verified = self.provider_verified();
     Kind9735ParsedT {
       id,
       amount,
@@ -19115,6 +20437,8 @@ impl<'a> Kind9735Parsed<'a> {
       timestamp,
       valid,
       description,
+      invalid_reason,
+      provider_verified,
     }
   }
 
@@ -19202,6 +20526,20 @@ impl<'a> Kind9735Parsed<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<ZapRequest>>(Kind9735Parsed::VT_DESCRIPTION, None).unwrap()}
   }
+  #[inline]
+  pub fn invalid_reason(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Kind9735Parsed::VT_INVALID_REASON, None)}
+  }
+  #[inline]
+  pub fn provider_verified(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Kind9735Parsed::VT_PROVIDER_VERIFIED, Some(false)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for Kind9735Parsed<'_> {
@@ -19223,6 +20561,8 @@ impl flatbuffers::Verifiable for Kind9735Parsed<'_> {
      .visit_field::<u64>("timestamp", Self::VT_TIMESTAMP, false)?
      .visit_field::<bool>("valid", Self::VT_VALID, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<ZapRequest>>("description", Self::VT_DESCRIPTION, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("invalid_reason", Self::VT_INVALID_REASON, false)?
+     .visit_field::<bool>("provider_verified", Self::VT_PROVIDER_VERIFIED, false)?
      .finish();
     Ok(())
   }
@@ -19240,6 +20580,8 @@ pub struct Kind9735ParsedArgs<'a> {
     pub timestamp: u64,
     pub valid: bool,
     pub description: Option<flatbuffers::WIPOffset<ZapRequest<'a>>>,
+    pub invalid_reason: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub provider_verified: bool,
 }
 impl<'a> Default for Kind9735ParsedArgs<'a> {
   #[inline]
@@ -19257,6 +20599,8 @@ impl<'a> Default for Kind9735ParsedArgs<'a> {
       timestamp: 0,
       valid: false,
       description: None, // required field
+      invalid_reason: None,
+      provider_verified: false,
     }
   }
 }
@@ -19315,6 +20659,14 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> Kind9735ParsedBuilder<'a, 'b, A
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<ZapRequest>>(Kind9735Parsed::VT_DESCRIPTION, description);
   }
   #[inline]
+  pub fn add_invalid_reason(&mut self, invalid_reason: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Kind9735Parsed::VT_INVALID_REASON, invalid_reason);
+  }
+  #[inline]
+  pub fn add_provider_verified(&mut self, provider_verified: bool) {
+    self.fbb_.push_slot::<bool>(Kind9735Parsed::VT_PROVIDER_VERIFIED, provider_verified, false);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> Kind9735ParsedBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     Kind9735ParsedBuilder {
@@ -19350,6 +20702,8 @@ impl core::fmt::Debug for Kind9735Parsed<'_> {
       ds.field("timestamp", &self.timestamp());
       ds.field("valid", &self.valid());
       ds.field("description", &self.description());
+      ds.field("invalid_reason", &self.invalid_reason());
+      ds.field("provider_verified", &self.provider_verified());
       ds.finish()
   }
 }
@@ -19368,6 +20722,8 @@ pub struct Kind9735ParsedT {
   pub timestamp: u64,
   pub valid: bool,
   pub description: Box<ZapRequestT>,
+  pub invalid_reason: Option<String>,
+  pub provider_verified: bool,
 }
 impl Default for Kind9735ParsedT {
   fn default() -> Self {
@@ -19384,6 +20740,8 @@ impl Default for Kind9735ParsedT {
       timestamp: 0,
       valid: false,
       description: Default::default(),
+      invalid_reason: None,
+      provider_verified: false,
     }
   }
 }
@@ -19428,6 +20786,10 @@ impl Kind9735ParsedT {
       let x = &self.description;
       x.pack(_fbb)
     });
+    let invalid_reason = self.invalid_reason.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let provider_verified = self.provider_verified;
     Kind9735Parsed::create(_fbb, &Kind9735ParsedArgs{
       id,
       amount,
@@ -19441,6 +20803,8 @@ impl Kind9735ParsedT {
       timestamp,
       valid,
       description,
+      invalid_reason,
+      provider_verified,
     })
   }
 }
@@ -25056,120 +26420,284 @@ impl Kind1018ParsedT {
     })
   }
 }
-pub enum ParsedEventOffset {}
+pub enum CustomParsedOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ParsedEvent<'a> {
+pub struct CustomParsed<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ParsedEvent<'a> {
-  type Inner = ParsedEvent<'a>;
+impl<'a> flatbuffers::Follow<'a> for CustomParsed<'a> {
+  type Inner = CustomParsed<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> ParsedEvent<'a> {
-  pub const VT_ID: flatbuffers::VOffsetT = 4;
-  pub const VT_PUBKEY: flatbuffers::VOffsetT = 6;
-  pub const VT_KIND: flatbuffers::VOffsetT = 8;
-  pub const VT_CREATED_AT: flatbuffers::VOffsetT = 10;
-  pub const VT_PARSED_TYPE: flatbuffers::VOffsetT = 12;
-  pub const VT_PARSED: flatbuffers::VOffsetT = 14;
-  pub const VT_REQUESTS: flatbuffers::VOffsetT = 16;
-  pub const VT_RELAYS: flatbuffers::VOffsetT = 18;
-  pub const VT_TAGS: flatbuffers::VOffsetT = 20;
+impl<'a> CustomParsed<'a> {
+  pub const VT_SCHEMA_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_PAYLOAD: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    ParsedEvent { _tab: table }
+    CustomParsed { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ParsedEventArgs<'args>
-  ) -> flatbuffers::WIPOffset<ParsedEvent<'bldr>> {
-    let mut builder = ParsedEventBuilder::new(_fbb);
-    if let Some(x) = args.tags { builder.add_tags(x); }
-    if let Some(x) = args.relays { builder.add_relays(x); }
-    if let Some(x) = args.requests { builder.add_requests(x); }
-    if let Some(x) = args.parsed { builder.add_parsed(x); }
-    builder.add_created_at(args.created_at);
-    if let Some(x) = args.pubkey { builder.add_pubkey(x); }
-    if let Some(x) = args.id { builder.add_id(x); }
-    builder.add_kind(args.kind);
-    builder.add_parsed_type(args.parsed_type);
+    args: &'args CustomParsedArgs<'args>
+  ) -> flatbuffers::WIPOffset<CustomParsed<'bldr>> {
+    let mut builder = CustomParsedBuilder::new(_fbb);
+    if let Some(x) = args.payload { builder.add_payload(x); }
+    if let Some(x) = args.schema_id { builder.add_schema_id(x); }
     builder.finish()
   }
 
-  pub fn unpack(&self) -> ParsedEventT {
-    let id = {
-      let x = self.id();
-      x.to_string()
-    };
-    let pubkey = {
-      let x = self.pubkey();
+  pub fn unpack(&self) -> CustomParsedT {
+    let schema_id = {
+      let x = self.schema_id();
       x.to_string()
     };
-    let kind = self.kind();
-    let created_at = self.created_at();
-    let parsed = match self.parsed_type() {
-      ParsedData::NONE => ParsedDataT::NONE,
-      ParsedData::Kind0Parsed => ParsedDataT::Kind0Parsed(Box::new(
-        self.parsed_as_kind_0_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind0Parsed`.")
-            .unpack()
-      )),
-      ParsedData::Kind1Parsed => ParsedDataT::Kind1Parsed(Box::new(
-        self.parsed_as_kind_1_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind1Parsed`.")
-            .unpack()
-      )),
-      ParsedData::Kind3Parsed => ParsedDataT::Kind3Parsed(Box::new(
-        self.parsed_as_kind_3_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind3Parsed`.")
-            .unpack()
-      )),
-      ParsedData::Kind4Parsed => ParsedDataT::Kind4Parsed(Box::new(
-        self.parsed_as_kind_4_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind4Parsed`.")
-            .unpack()
-      )),
-      ParsedData::Kind6Parsed => ParsedDataT::Kind6Parsed(Box::new(
-        self.parsed_as_kind_6_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind6Parsed`.")
-            .unpack()
-      )),
-      ParsedData::Kind7Parsed => ParsedDataT::Kind7Parsed(Box::new(
-        self.parsed_as_kind_7_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind7Parsed`.")
-            .unpack()
-      )),
-      ParsedData::Kind8Parsed => ParsedDataT::Kind8Parsed(Box::new(
-        self.parsed_as_kind_8_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind8Parsed`.")
-            .unpack()
-      )),
-      ParsedData::Kind17Parsed => ParsedDataT::Kind17Parsed(Box::new(
-        self.parsed_as_kind_17_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind17Parsed`.")
-            .unpack()
-      )),
-      ParsedData::Kind20Parsed => ParsedDataT::Kind20Parsed(Box::new(
-        self.parsed_as_kind_20_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind20Parsed`.")
-            .unpack()
-      )),
-      ParsedData::Kind22Parsed => ParsedDataT::Kind22Parsed(Box::new(
-        self.parsed_as_kind_22_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind22Parsed`.")
-            .unpack()
-      )),
-      ParsedData::Kind1111Parsed => ParsedDataT::Kind1111Parsed(Box::new(
-        self.parsed_as_kind_1111_parsed()
-            .expect("Invalid union table, expected `ParsedData::Kind1111Parsed`.")
+    let payload = self.payload().map(|x| {
+      x.into_iter().collect()
+    });
+    CustomParsedT {
+      schema_id,
+      payload,
+    }
+  }
+
+  #[inline]
+  pub fn schema_id(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CustomParsed::VT_SCHEMA_ID, None).unwrap()}
+  }
+  #[inline]
+  pub fn payload(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(CustomParsed::VT_PAYLOAD, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CustomParsed<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("schema_id", Self::VT_SCHEMA_ID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("payload", Self::VT_PAYLOAD, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CustomParsedArgs<'a> {
+    pub schema_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub payload: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for CustomParsedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CustomParsedArgs {
+      schema_id: None, // required field
+      payload: None,
+    }
+  }
+}
+
+pub struct CustomParsedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CustomParsedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_schema_id(&mut self, schema_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CustomParsed::VT_SCHEMA_ID, schema_id);
+  }
+  #[inline]
+  pub fn add_payload(&mut self, payload: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CustomParsed::VT_PAYLOAD, payload);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CustomParsedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    CustomParsedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CustomParsed<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, CustomParsed::VT_SCHEMA_ID,"schema_id");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CustomParsed<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CustomParsed");
+      ds.field("schema_id", &self.schema_id());
+      ds.field("payload", &self.payload());
+      ds.finish()
+  }
+}
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomParsedT {
+  pub schema_id: String,
+  pub payload: Option<Vec<u8>>,
+}
+impl Default for CustomParsedT {
+  fn default() -> Self {
+    Self {
+      schema_id: "".to_string(),
+      payload: None,
+    }
+  }
+}
+impl CustomParsedT {
+  pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+    &self,
+    _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
+  ) -> flatbuffers::WIPOffset<CustomParsed<'b>> {
+    let schema_id = Some({
+      let x = &self.schema_id;
+      _fbb.create_string(x)
+    });
+    let payload = self.payload.as_ref().map(|x|{
+      _fbb.create_vector(x)
+    });
+    CustomParsed::create(_fbb, &CustomParsedArgs{
+      schema_id,
+      payload,
+    })
+  }
+}
+pub enum ParsedEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ParsedEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ParsedEvent<'a> {
+  type Inner = ParsedEvent<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ParsedEvent<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_PUBKEY: flatbuffers::VOffsetT = 6;
+  pub const VT_KIND: flatbuffers::VOffsetT = 8;
+  pub const VT_CREATED_AT: flatbuffers::VOffsetT = 10;
+  pub const VT_PARSED_TYPE: flatbuffers::VOffsetT = 12;
+  pub const VT_PARSED: flatbuffers::VOffsetT = 14;
+  pub const VT_REQUESTS: flatbuffers::VOffsetT = 16;
+  pub const VT_RELAYS: flatbuffers::VOffsetT = 18;
+  pub const VT_TAGS: flatbuffers::VOffsetT = 20;
+  pub const VT_NEVENT: flatbuffers::VOffsetT = 22;
+  pub const VT_NADDR: flatbuffers::VOffsetT = 24;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ParsedEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ParsedEventArgs<'args>
+  ) -> flatbuffers::WIPOffset<ParsedEvent<'bldr>> {
+    let mut builder = ParsedEventBuilder::new(_fbb);
+    if let Some(x) = args.naddr { builder.add_naddr(x); }
+    if let Some(x) = args.nevent { builder.add_nevent(x); }
+    if let Some(x) = args.tags { builder.add_tags(x); }
+    if let Some(x) = args.relays { builder.add_relays(x); }
+    if let Some(x) = args.requests { builder.add_requests(x); }
+    if let Some(x) = args.parsed { builder.add_parsed(x); }
+    builder.add_created_at(args.created_at);
+    if let Some(x) = args.pubkey { builder.add_pubkey(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_kind(args.kind);
+    builder.add_parsed_type(args.parsed_type);
+    builder.finish()
+  }
+
+  pub fn unpack(&self) -> ParsedEventT {
+    let id = {
+      let x = self.id();
+      x.to_string()
+    };
+    let pubkey = {
+      let x = self.pubkey();
+      x.to_string()
+    };
+    let kind = self.kind();
+    let created_at = self.created_at();
+    let parsed = match self.parsed_type() {
+      ParsedData::NONE => ParsedDataT::NONE,
+      ParsedData::Kind0Parsed => ParsedDataT::Kind0Parsed(Box::new(
+        self.parsed_as_kind_0_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind0Parsed`.")
+            .unpack()
+      )),
+      ParsedData::Kind1Parsed => ParsedDataT::Kind1Parsed(Box::new(
+        self.parsed_as_kind_1_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind1Parsed`.")
+            .unpack()
+      )),
+      ParsedData::Kind3Parsed => ParsedDataT::Kind3Parsed(Box::new(
+        self.parsed_as_kind_3_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind3Parsed`.")
+            .unpack()
+      )),
+      ParsedData::Kind4Parsed => ParsedDataT::Kind4Parsed(Box::new(
+        self.parsed_as_kind_4_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind4Parsed`.")
+            .unpack()
+      )),
+      ParsedData::Kind6Parsed => ParsedDataT::Kind6Parsed(Box::new(
+        self.parsed_as_kind_6_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind6Parsed`.")
+            .unpack()
+      )),
+      ParsedData::Kind7Parsed => ParsedDataT::Kind7Parsed(Box::new(
+        self.parsed_as_kind_7_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind7Parsed`.")
+            .unpack()
+      )),
+      ParsedData::Kind8Parsed => ParsedDataT::Kind8Parsed(Box::new(
+        self.parsed_as_kind_8_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind8Parsed`.")
+            .unpack()
+      )),
+      ParsedData::Kind17Parsed => ParsedDataT::Kind17Parsed(Box::new(
+        self.parsed_as_kind_17_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind17Parsed`.")
+            .unpack()
+      )),
+      ParsedData::Kind20Parsed => ParsedDataT::Kind20Parsed(Box::new(
+        self.parsed_as_kind_20_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind20Parsed`.")
+            .unpack()
+      )),
+      ParsedData::Kind22Parsed => ParsedDataT::Kind22Parsed(Box::new(
+        self.parsed_as_kind_22_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind22Parsed`.")
+            .unpack()
+      )),
+      ParsedData::Kind1111Parsed => ParsedDataT::Kind1111Parsed(Box::new(
+        self.parsed_as_kind_1111_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind1111Parsed`.")
             .unpack()
       )),
       ParsedData::Kind1311Parsed => ParsedDataT::Kind1311Parsed(Box::new(
@@ -25242,6 +26770,16 @@ impl<'a> ParsedEvent<'a> {
             .expect("Invalid union table, expected `ParsedData::PreGenericParsed`.")
             .unpack()
       )),
+      ParsedData::Kind14Parsed => ParsedDataT::Kind14Parsed(Box::new(
+        self.parsed_as_kind_14_parsed()
+            .expect("Invalid union table, expected `ParsedData::Kind14Parsed`.")
+            .unpack()
+      )),
+      ParsedData::CustomParsed => ParsedDataT::CustomParsed(Box::new(
+        self.parsed_as_custom_parsed()
+            .expect("Invalid union table, expected `ParsedData::CustomParsed`.")
+            .unpack()
+      )),
       _ => ParsedDataT::NONE,
     };
     let requests = self.requests().map(|x| {
@@ -25254,6 +26792,12 @@ impl<'a> ParsedEvent<'a> {
       let x = self.tags();
       x.iter().map(|t| t.unpack()).collect()
     };
+    let nevent = self.nevent().map(|x| {
+      x.to_string()
+    });
+    let naddr = self.naddr().map(|x| {
+      x.to_string()
+    });
     ParsedEventT {
       id,
       pubkey,
@@ -25263,6 +26807,8 @@ impl<'a> ParsedEvent<'a> {
       requests,
       relays,
       tags,
+      nevent,
+      naddr,
     }
   }
 
@@ -25330,6 +26876,20 @@ impl<'a> ParsedEvent<'a> {
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StringVec>>>>(ParsedEvent::VT_TAGS, None).unwrap()}
   }
   #[inline]
+  pub fn nevent(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ParsedEvent::VT_NEVENT, None)}
+  }
+  #[inline]
+  pub fn naddr(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ParsedEvent::VT_NADDR, None)}
+  }
+  #[inline]
   #[allow(non_snake_case)]
   pub fn parsed_as_kind_0_parsed(&self) -> Option<Kind0Parsed<'a>> {
     if self.parsed_type() == ParsedData::Kind0Parsed {
@@ -25704,6 +27264,36 @@ impl<'a> ParsedEvent<'a> {
     }
   }
 
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn parsed_as_kind_14_parsed(&self) -> Option<Kind14Parsed<'a>> {
+    if self.parsed_type() == ParsedData::Kind14Parsed {
+      self.parsed().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Kind14Parsed::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn parsed_as_custom_parsed(&self) -> Option<CustomParsed<'a>> {
+    if self.parsed_type() == ParsedData::CustomParsed {
+      self.parsed().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { CustomParsed::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
 }
 
 impl flatbuffers::Verifiable for ParsedEvent<'_> {
@@ -25744,12 +27334,16 @@ impl flatbuffers::Verifiable for ParsedEvent<'_> {
           ParsedData::Kind30023Parsed => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Kind30023Parsed>>("ParsedData::Kind30023Parsed", pos),
           ParsedData::ListParsed => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ListParsed>>("ParsedData::ListParsed", pos),
           ParsedData::PreGenericParsed => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PreGenericParsed>>("ParsedData::PreGenericParsed", pos),
+          ParsedData::Kind14Parsed => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Kind14Parsed>>("ParsedData::Kind14Parsed", pos),
+          ParsedData::CustomParsed => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CustomParsed>>("ParsedData::CustomParsed", pos),
           _ => Ok(()),
         }
      })?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Request>>>>("requests", Self::VT_REQUESTS, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("relays", Self::VT_RELAYS, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StringVec>>>>("tags", Self::VT_TAGS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("nevent", Self::VT_NEVENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("naddr", Self::VT_NADDR, false)?
      .finish();
     Ok(())
   }
@@ -25764,6 +27358,8 @@ pub struct ParsedEventArgs<'a> {
     pub requests: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Request<'a>>>>>,
     pub relays: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
     pub tags: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StringVec<'a>>>>>,
+    pub nevent: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub naddr: Option<flatbuffers::WIPOffset<&'a str>>,
 }
 impl<'a> Default for ParsedEventArgs<'a> {
   #[inline]
@@ -25778,6 +27374,8 @@ impl<'a> Default for ParsedEventArgs<'a> {
       requests: None,
       relays: None,
       tags: None, // required field
+      nevent: None,
+      naddr: None,
     }
   }
 }
@@ -25824,6 +27422,14 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ParsedEventBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ParsedEvent::VT_TAGS, tags);
   }
   #[inline]
+  pub fn add_nevent(&mut self, nevent: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ParsedEvent::VT_NEVENT, nevent);
+  }
+  #[inline]
+  pub fn add_naddr(&mut self, naddr: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ParsedEvent::VT_NADDR, naddr);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ParsedEventBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     ParsedEventBuilder {
@@ -26025,7 +27631,21 @@ impl core::fmt::Debug for ParsedEvent<'_> {
             ds.field("parsed", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
-        _ => {
+        ParsedData::Kind14Parsed => {
+          if let Some(x) = self.parsed_as_kind_14_parsed() {
+            ds.field("parsed", &x)
+          } else {
+            ds.field("parsed", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ParsedData::CustomParsed => {
+          if let Some(x) = self.parsed_as_custom_parsed() {
+            ds.field("parsed", &x)
+          } else {
+            ds.field("parsed", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
           let x: Option<()> = None;
           ds.field("parsed", &x)
         },
@@ -26033,6 +27653,8 @@ impl core::fmt::Debug for ParsedEvent<'_> {
       ds.field("requests", &self.requests());
       ds.field("relays", &self.relays());
       ds.field("tags", &self.tags());
+      ds.field("nevent", &self.nevent());
+      ds.field("naddr", &self.naddr());
       ds.finish()
   }
 }
@@ -26047,6 +27669,8 @@ pub struct ParsedEventT {
   pub requests: Option<Vec<RequestT>>,
   pub relays: Option<Vec<String>>,
   pub tags: Vec<StringVecT>,
+  pub nevent: Option<String>,
+  pub naddr: Option<String>,
 }
 impl Default for ParsedEventT {
   fn default() -> Self {
@@ -26059,6 +27683,8 @@ impl Default for ParsedEventT {
       requests: None,
       relays: None,
       tags: Default::default(),
+      nevent: None,
+      naddr: None,
     }
   }
 }
@@ -26066,1773 +27692,2440 @@ impl ParsedEventT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<ParsedEvent<'b>> {
-    let id = Some({
-      let x = &self.id;
-      _fbb.create_string(x)
-    });
-    let pubkey = Some({
-      let x = &self.pubkey;
+  ) -> flatbuffers::WIPOffset<ParsedEvent<'b>> {
+    let id = Some({
+      let x = &self.id;
+      _fbb.create_string(x)
+    });
+    let pubkey = Some({
+      let x = &self.pubkey;
+      _fbb.create_string(x)
+    });
+    let kind = self.kind;
+    let created_at = self.created_at;
+    let parsed_type = self.parsed.parsed_data_type();
+    let parsed = self.parsed.pack(_fbb);
+    let requests = self.requests.as_ref().map(|x|{
+      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+    });
+    let relays = self.relays.as_ref().map(|x|{
+      let w: Vec<_> = x.iter().map(|s| _fbb.create_string(s)).collect();_fbb.create_vector(&w)
+    });
+    let tags = Some({
+      let x = &self.tags;
+      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+    });
+    let nevent = self.nevent.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let naddr = self.naddr.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    ParsedEvent::create(_fbb, &ParsedEventArgs{
+      id,
+      pubkey,
+      kind,
+      created_at,
+      parsed_type,
+      parsed,
+      requests,
+      relays,
+      tags,
+      nevent,
+      naddr,
+    })
+  }
+}
+pub enum ConnectionStatusOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ConnectionStatus<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ConnectionStatus<'a> {
+  type Inner = ConnectionStatus<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ConnectionStatus<'a> {
+  pub const VT_RELAY_URL: flatbuffers::VOffsetT = 4;
+  pub const VT_STATUS: flatbuffers::VOffsetT = 6;
+  pub const VT_MESSAGE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ConnectionStatus { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ConnectionStatusArgs<'args>
+  ) -> flatbuffers::WIPOffset<ConnectionStatus<'bldr>> {
+    let mut builder = ConnectionStatusBuilder::new(_fbb);
+    if let Some(x) = args.message { builder.add_message(x); }
+    if let Some(x) = args.status { builder.add_status(x); }
+    if let Some(x) = args.relay_url { builder.add_relay_url(x); }
+    builder.finish()
+  }
+
+  pub fn unpack(&self) -> ConnectionStatusT {
+    let relay_url = {
+      let x = self.relay_url();
+      x.to_string()
+    };
+    let status = {
+      let x = self.status();
+      x.to_string()
+    };
+    let message = self.message().map(|x| {
+      x.to_string()
+    });
+    ConnectionStatusT {
+      relay_url,
+      status,
+      message,
+    }
+  }
+
+  #[inline]
+  pub fn relay_url(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ConnectionStatus::VT_RELAY_URL, None).unwrap()}
+  }
+  #[inline]
+  pub fn status(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ConnectionStatus::VT_STATUS, None).unwrap()}
+  }
+  #[inline]
+  pub fn message(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ConnectionStatus::VT_MESSAGE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ConnectionStatus<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("relay_url", Self::VT_RELAY_URL, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("status", Self::VT_STATUS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ConnectionStatusArgs<'a> {
+    pub relay_url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub status: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ConnectionStatusArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ConnectionStatusArgs {
+      relay_url: None, // required field
+      status: None, // required field
+      message: None,
+    }
+  }
+}
+
+pub struct ConnectionStatusBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConnectionStatusBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_relay_url(&mut self, relay_url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ConnectionStatus::VT_RELAY_URL, relay_url);
+  }
+  #[inline]
+  pub fn add_status(&mut self, status: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ConnectionStatus::VT_STATUS, status);
+  }
+  #[inline]
+  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ConnectionStatus::VT_MESSAGE, message);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ConnectionStatusBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ConnectionStatusBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ConnectionStatus<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ConnectionStatus::VT_RELAY_URL,"relay_url");
+    self.fbb_.required(o, ConnectionStatus::VT_STATUS,"status");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ConnectionStatus<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ConnectionStatus");
+      ds.field("relay_url", &self.relay_url());
+      ds.field("status", &self.status());
+      ds.field("message", &self.message());
+      ds.finish()
+  }
+}
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionStatusT {
+  pub relay_url: String,
+  pub status: String,
+  pub message: Option<String>,
+}
+impl Default for ConnectionStatusT {
+  fn default() -> Self {
+    Self {
+      relay_url: "".to_string(),
+      status: "".to_string(),
+      message: None,
+    }
+  }
+}
+impl ConnectionStatusT {
+  pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+    &self,
+    _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
+  ) -> flatbuffers::WIPOffset<ConnectionStatus<'b>> {
+    let relay_url = Some({
+      let x = &self.relay_url;
+      _fbb.create_string(x)
+    });
+    let status = Some({
+      let x = &self.status;
+      _fbb.create_string(x)
+    });
+    let message = self.message.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    ConnectionStatus::create(_fbb, &ConnectionStatusArgs{
+      relay_url,
+      status,
+      message,
+    })
+  }
+}
+pub enum CountResponseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CountResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CountResponse<'a> {
+  type Inner = CountResponse<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CountResponse<'a> {
+  pub const VT_COUNT: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND: flatbuffers::VOffsetT = 6;
+  pub const VT_YOU: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CountResponse { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args CountResponseArgs
+  ) -> flatbuffers::WIPOffset<CountResponse<'bldr>> {
+    let mut builder = CountResponseBuilder::new(_fbb);
+    builder.add_count(args.count);
+    builder.add_kind(args.kind);
+    builder.add_you(args.you);
+    builder.finish()
+  }
+
+  pub fn unpack(&self) -> CountResponseT {
+    let count = self.count();
+    let kind = self.kind();
+    let you = self.you();
+    CountResponseT {
+      count,
+      kind,
+      you,
+    }
+  }
+
+  #[inline]
+  pub fn count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(CountResponse::VT_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn kind(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(CountResponse::VT_KIND, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn you(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(CountResponse::VT_YOU, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for CountResponse<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("count", Self::VT_COUNT, false)?
+     .visit_field::<u16>("kind", Self::VT_KIND, false)?
+     .visit_field::<bool>("you", Self::VT_YOU, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CountResponseArgs {
+    pub count: u32,
+    pub kind: u16,
+    pub you: bool,
+}
+impl<'a> Default for CountResponseArgs {
+  #[inline]
+  fn default() -> Self {
+    CountResponseArgs {
+      count: 0,
+      kind: 0,
+      you: false,
+    }
+  }
+}
+
+pub struct CountResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CountResponseBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_count(&mut self, count: u32) {
+    self.fbb_.push_slot::<u32>(CountResponse::VT_COUNT, count, 0);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: u16) {
+    self.fbb_.push_slot::<u16>(CountResponse::VT_KIND, kind, 0);
+  }
+  #[inline]
+  pub fn add_you(&mut self, you: bool) {
+    self.fbb_.push_slot::<bool>(CountResponse::VT_YOU, you, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CountResponseBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    CountResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CountResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CountResponse<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CountResponse");
+      ds.field("count", &self.count());
+      ds.field("kind", &self.kind());
+      ds.field("you", &self.you());
+      ds.finish()
+  }
+}
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CountResponseT {
+  pub count: u32,
+  pub kind: u16,
+  pub you: bool,
+}
+impl Default for CountResponseT {
+  fn default() -> Self {
+    Self {
+      count: 0,
+      kind: 0,
+      you: false,
+    }
+  }
+}
+impl CountResponseT {
+  pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+    &self,
+    _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
+  ) -> flatbuffers::WIPOffset<CountResponse<'b>> {
+    let count = self.count;
+    let kind = self.kind;
+    let you = self.you;
+    CountResponse::create(_fbb, &CountResponseArgs{
+      count,
+      kind,
+      you,
+    })
+  }
+}
+pub enum EoceOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Eoce<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Eoce<'a> {
+  type Inner = Eoce<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Eoce<'a> {
+  pub const VT_SUBSCRIPTION_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Eoce { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EoceArgs<'args>
+  ) -> flatbuffers::WIPOffset<Eoce<'bldr>> {
+    let mut builder = EoceBuilder::new(_fbb);
+    if let Some(x) = args.subscription_id { builder.add_subscription_id(x); }
+    builder.finish()
+  }
+
+  pub fn unpack(&self) -> EoceT {
+    let subscription_id = {
+      let x = self.subscription_id();
+      x.to_string()
+    };
+    EoceT {
+      subscription_id,
+    }
+  }
+
+  #[inline]
+  pub fn subscription_id(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Eoce::VT_SUBSCRIPTION_ID, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Eoce<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("subscription_id", Self::VT_SUBSCRIPTION_ID, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EoceArgs<'a> {
+    pub subscription_id: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for EoceArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EoceArgs {
+      subscription_id: None, // required field
+    }
+  }
+}
+
+pub struct EoceBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EoceBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_subscription_id(&mut self, subscription_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Eoce::VT_SUBSCRIPTION_ID, subscription_id);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EoceBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EoceBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Eoce<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Eoce::VT_SUBSCRIPTION_ID,"subscription_id");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Eoce<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Eoce");
+      ds.field("subscription_id", &self.subscription_id());
+      ds.finish()
+  }
+}
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EoceT {
+  pub subscription_id: String,
+}
+impl Default for EoceT {
+  fn default() -> Self {
+    Self {
+      subscription_id: "".to_string(),
+    }
+  }
+}
+impl EoceT {
+  pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+    &self,
+    _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
+  ) -> flatbuffers::WIPOffset<Eoce<'b>> {
+    let subscription_id = Some({
+      let x = &self.subscription_id;
       _fbb.create_string(x)
     });
-    let kind = self.kind;
-    let created_at = self.created_at;
-    let parsed_type = self.parsed.parsed_data_type();
-    let parsed = self.parsed.pack(_fbb);
-    let requests = self.requests.as_ref().map(|x|{
-      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
-    });
-    let relays = self.relays.as_ref().map(|x|{
-      let w: Vec<_> = x.iter().map(|s| _fbb.create_string(s)).collect();_fbb.create_vector(&w)
-    });
-    let tags = Some({
-      let x = &self.tags;
-      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
-    });
-    ParsedEvent::create(_fbb, &ParsedEventArgs{
-      id,
-      pubkey,
-      kind,
-      created_at,
-      parsed_type,
-      parsed,
-      requests,
-      relays,
-      tags,
+    Eoce::create(_fbb, &EoceArgs{
+      subscription_id,
     })
   }
 }
-pub enum ConnectionStatusOffset {}
+pub enum BufferFullOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConnectionStatus<'a> {
+pub struct BufferFull<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConnectionStatus<'a> {
-  type Inner = ConnectionStatus<'a>;
+impl<'a> flatbuffers::Follow<'a> for BufferFull<'a> {
+  type Inner = BufferFull<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> ConnectionStatus<'a> {
-  pub const VT_RELAY_URL: flatbuffers::VOffsetT = 4;
-  pub const VT_STATUS: flatbuffers::VOffsetT = 6;
-  pub const VT_MESSAGE: flatbuffers::VOffsetT = 8;
+impl<'a> BufferFull<'a> {
+  pub const VT_DROPPED_EVENTS: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    ConnectionStatus { _tab: table }
+    BufferFull { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ConnectionStatusArgs<'args>
-  ) -> flatbuffers::WIPOffset<ConnectionStatus<'bldr>> {
-    let mut builder = ConnectionStatusBuilder::new(_fbb);
-    if let Some(x) = args.message { builder.add_message(x); }
-    if let Some(x) = args.status { builder.add_status(x); }
-    if let Some(x) = args.relay_url { builder.add_relay_url(x); }
+    args: &'args BufferFullArgs
+  ) -> flatbuffers::WIPOffset<BufferFull<'bldr>> {
+    let mut builder = BufferFullBuilder::new(_fbb);
+    builder.add_dropped_events(args.dropped_events);
     builder.finish()
   }
 
-  pub fn unpack(&self) -> ConnectionStatusT {
-    let relay_url = {
-      let x = self.relay_url();
-      x.to_string()
-    };
-    let status = {
-      let x = self.status();
-      x.to_string()
-    };
-    let message = self.message().map(|x| {
-      x.to_string()
-    });
-    ConnectionStatusT {
-      relay_url,
-      status,
-      message,
+  pub fn unpack(&self) -> BufferFullT {
+    let dropped_events = self.dropped_events();
+    BufferFullT {
+      dropped_events,
     }
   }
 
   #[inline]
-  pub fn relay_url(&self) -> &'a str {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ConnectionStatus::VT_RELAY_URL, None).unwrap()}
-  }
-  #[inline]
-  pub fn status(&self) -> &'a str {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ConnectionStatus::VT_STATUS, None).unwrap()}
-  }
-  #[inline]
-  pub fn message(&self) -> Option<&'a str> {
+  pub fn dropped_events(&self) -> u16 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ConnectionStatus::VT_MESSAGE, None)}
+    unsafe { self._tab.get::<u16>(BufferFull::VT_DROPPED_EVENTS, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for ConnectionStatus<'_> {
+impl flatbuffers::Verifiable for BufferFull<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("relay_url", Self::VT_RELAY_URL, true)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("status", Self::VT_STATUS, true)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
+     .visit_field::<u16>("dropped_events", Self::VT_DROPPED_EVENTS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ConnectionStatusArgs<'a> {
-    pub relay_url: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub status: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct BufferFullArgs {
+    pub dropped_events: u16,
 }
-impl<'a> Default for ConnectionStatusArgs<'a> {
+impl<'a> Default for BufferFullArgs {
   #[inline]
   fn default() -> Self {
-    ConnectionStatusArgs {
-      relay_url: None, // required field
-      status: None, // required field
-      message: None,
+    BufferFullArgs {
+      dropped_events: 0,
     }
   }
 }
 
-pub struct ConnectionStatusBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct BufferFullBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConnectionStatusBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_relay_url(&mut self, relay_url: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ConnectionStatus::VT_RELAY_URL, relay_url);
-  }
-  #[inline]
-  pub fn add_status(&mut self, status: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ConnectionStatus::VT_STATUS, status);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BufferFullBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ConnectionStatus::VT_MESSAGE, message);
+  pub fn add_dropped_events(&mut self, dropped_events: u16) {
+    self.fbb_.push_slot::<u16>(BufferFull::VT_DROPPED_EVENTS, dropped_events, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ConnectionStatusBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> BufferFullBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ConnectionStatusBuilder {
+    BufferFullBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<ConnectionStatus<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<BufferFull<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, ConnectionStatus::VT_RELAY_URL,"relay_url");
-    self.fbb_.required(o, ConnectionStatus::VT_STATUS,"status");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for ConnectionStatus<'_> {
+impl core::fmt::Debug for BufferFull<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("ConnectionStatus");
-      ds.field("relay_url", &self.relay_url());
-      ds.field("status", &self.status());
-      ds.field("message", &self.message());
+    let mut ds = f.debug_struct("BufferFull");
+      ds.field("dropped_events", &self.dropped_events());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct ConnectionStatusT {
-  pub relay_url: String,
-  pub status: String,
-  pub message: Option<String>,
+pub struct BufferFullT {
+  pub dropped_events: u16,
 }
-impl Default for ConnectionStatusT {
+impl Default for BufferFullT {
   fn default() -> Self {
     Self {
-      relay_url: "".to_string(),
-      status: "".to_string(),
-      message: None,
+      dropped_events: 0,
     }
   }
 }
-impl ConnectionStatusT {
+impl BufferFullT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<ConnectionStatus<'b>> {
-    let relay_url = Some({
-      let x = &self.relay_url;
-      _fbb.create_string(x)
-    });
-    let status = Some({
-      let x = &self.status;
-      _fbb.create_string(x)
-    });
-    let message = self.message.as_ref().map(|x|{
-      _fbb.create_string(x)
-    });
-    ConnectionStatus::create(_fbb, &ConnectionStatusArgs{
-      relay_url,
-      status,
-      message,
+  ) -> flatbuffers::WIPOffset<BufferFull<'b>> {
+    let dropped_events = self.dropped_events;
+    BufferFull::create(_fbb, &BufferFullArgs{
+      dropped_events,
     })
   }
 }
-pub enum CountResponseOffset {}
+pub enum ValidProofsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct CountResponse<'a> {
+pub struct ValidProofs<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for CountResponse<'a> {
-  type Inner = CountResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for ValidProofs<'a> {
+  type Inner = ValidProofs<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> CountResponse<'a> {
-  pub const VT_COUNT: flatbuffers::VOffsetT = 4;
-  pub const VT_KIND: flatbuffers::VOffsetT = 6;
-  pub const VT_YOU: flatbuffers::VOffsetT = 8;
+impl<'a> ValidProofs<'a> {
+  pub const VT_PROOFS: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    CountResponse { _tab: table }
+    ValidProofs { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args CountResponseArgs
-  ) -> flatbuffers::WIPOffset<CountResponse<'bldr>> {
-    let mut builder = CountResponseBuilder::new(_fbb);
-    builder.add_count(args.count);
-    builder.add_kind(args.kind);
-    builder.add_you(args.you);
+    args: &'args ValidProofsArgs<'args>
+  ) -> flatbuffers::WIPOffset<ValidProofs<'bldr>> {
+    let mut builder = ValidProofsBuilder::new(_fbb);
+    if let Some(x) = args.proofs { builder.add_proofs(x); }
     builder.finish()
   }
 
-  pub fn unpack(&self) -> CountResponseT {
-    let count = self.count();
-    let kind = self.kind();
-    let you = self.you();
-    CountResponseT {
-      count,
-      kind,
-      you,
+  pub fn unpack(&self) -> ValidProofsT {
+    let proofs = {
+      let x = self.proofs();
+      x.iter().map(|t| t.unpack()).collect()
+    };
+    ValidProofsT {
+      proofs,
     }
   }
 
   #[inline]
-  pub fn count(&self) -> u32 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(CountResponse::VT_COUNT, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn kind(&self) -> u16 {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<u16>(CountResponse::VT_KIND, Some(0)).unwrap()}
-  }
-  #[inline]
-  pub fn you(&self) -> bool {
+  pub fn proofs(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MintProofs<'a>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<bool>(CountResponse::VT_YOU, Some(false)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MintProofs>>>>(ValidProofs::VT_PROOFS, None).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for CountResponse<'_> {
+impl flatbuffers::Verifiable for ValidProofs<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u32>("count", Self::VT_COUNT, false)?
-     .visit_field::<u16>("kind", Self::VT_KIND, false)?
-     .visit_field::<bool>("you", Self::VT_YOU, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<MintProofs>>>>("proofs", Self::VT_PROOFS, true)?
      .finish();
     Ok(())
   }
 }
-pub struct CountResponseArgs {
-    pub count: u32,
-    pub kind: u16,
-    pub you: bool,
+pub struct ValidProofsArgs<'a> {
+    pub proofs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MintProofs<'a>>>>>,
 }
-impl<'a> Default for CountResponseArgs {
+impl<'a> Default for ValidProofsArgs<'a> {
   #[inline]
   fn default() -> Self {
-    CountResponseArgs {
-      count: 0,
-      kind: 0,
-      you: false,
+    ValidProofsArgs {
+      proofs: None, // required field
     }
   }
 }
 
-pub struct CountResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ValidProofsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CountResponseBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_count(&mut self, count: u32) {
-    self.fbb_.push_slot::<u32>(CountResponse::VT_COUNT, count, 0);
-  }
-  #[inline]
-  pub fn add_kind(&mut self, kind: u16) {
-    self.fbb_.push_slot::<u16>(CountResponse::VT_KIND, kind, 0);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ValidProofsBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_you(&mut self, you: bool) {
-    self.fbb_.push_slot::<bool>(CountResponse::VT_YOU, you, false);
+  pub fn add_proofs(&mut self, proofs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<MintProofs<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ValidProofs::VT_PROOFS, proofs);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CountResponseBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ValidProofsBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    CountResponseBuilder {
+    ValidProofsBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<CountResponse<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ValidProofs<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ValidProofs::VT_PROOFS,"proofs");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for CountResponse<'_> {
+impl core::fmt::Debug for ValidProofs<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("CountResponse");
-      ds.field("count", &self.count());
-      ds.field("kind", &self.kind());
-      ds.field("you", &self.you());
+    let mut ds = f.debug_struct("ValidProofs");
+      ds.field("proofs", &self.proofs());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct CountResponseT {
-  pub count: u32,
-  pub kind: u16,
-  pub you: bool,
+pub struct ValidProofsT {
+  pub proofs: Vec<MintProofsT>,
 }
-impl Default for CountResponseT {
+impl Default for ValidProofsT {
   fn default() -> Self {
     Self {
-      count: 0,
-      kind: 0,
-      you: false,
+      proofs: Default::default(),
     }
   }
 }
-impl CountResponseT {
+impl ValidProofsT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<CountResponse<'b>> {
-    let count = self.count;
-    let kind = self.kind;
-    let you = self.you;
-    CountResponse::create(_fbb, &CountResponseArgs{
-      count,
-      kind,
-      you,
+  ) -> flatbuffers::WIPOffset<ValidProofs<'b>> {
+    let proofs = Some({
+      let x = &self.proofs;
+      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+    });
+    ValidProofs::create(_fbb, &ValidProofsArgs{
+      proofs,
     })
   }
 }
-pub enum EoceOffset {}
+pub enum MintProofsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Eoce<'a> {
+pub struct MintProofs<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Eoce<'a> {
-  type Inner = Eoce<'a>;
+impl<'a> flatbuffers::Follow<'a> for MintProofs<'a> {
+  type Inner = MintProofs<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> Eoce<'a> {
-  pub const VT_SUBSCRIPTION_ID: flatbuffers::VOffsetT = 4;
+impl<'a> MintProofs<'a> {
+  pub const VT_MINT: flatbuffers::VOffsetT = 4;
+  pub const VT_PROOFS: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Eoce { _tab: table }
+    MintProofs { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args EoceArgs<'args>
-  ) -> flatbuffers::WIPOffset<Eoce<'bldr>> {
-    let mut builder = EoceBuilder::new(_fbb);
-    if let Some(x) = args.subscription_id { builder.add_subscription_id(x); }
+    args: &'args MintProofsArgs<'args>
+  ) -> flatbuffers::WIPOffset<MintProofs<'bldr>> {
+    let mut builder = MintProofsBuilder::new(_fbb);
+    if let Some(x) = args.proofs { builder.add_proofs(x); }
+    if let Some(x) = args.mint { builder.add_mint(x); }
     builder.finish()
   }
 
-  pub fn unpack(&self) -> EoceT {
-    let subscription_id = {
-      let x = self.subscription_id();
+  pub fn unpack(&self) -> MintProofsT {
+    let mint = {
+      let x = self.mint();
       x.to_string()
     };
-    EoceT {
-      subscription_id,
+    let proofs = {
+      let x = self.proofs();
+      x.iter().map(|t| t.unpack()).collect()
+    };
+    MintProofsT {
+      mint,
+      proofs,
     }
   }
 
   #[inline]
-  pub fn subscription_id(&self) -> &'a str {
+  pub fn mint(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Eoce::VT_SUBSCRIPTION_ID, None).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(MintProofs::VT_MINT, None).unwrap()}
+  }
+  #[inline]
+  pub fn proofs(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Proof<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Proof>>>>(MintProofs::VT_PROOFS, None).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for Eoce<'_> {
+impl flatbuffers::Verifiable for MintProofs<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("subscription_id", Self::VT_SUBSCRIPTION_ID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("mint", Self::VT_MINT, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Proof>>>>("proofs", Self::VT_PROOFS, true)?
      .finish();
     Ok(())
   }
 }
-pub struct EoceArgs<'a> {
-    pub subscription_id: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct MintProofsArgs<'a> {
+    pub mint: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub proofs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Proof<'a>>>>>,
 }
-impl<'a> Default for EoceArgs<'a> {
+impl<'a> Default for MintProofsArgs<'a> {
   #[inline]
   fn default() -> Self {
-    EoceArgs {
-      subscription_id: None, // required field
+    MintProofsArgs {
+      mint: None, // required field
+      proofs: None, // required field
     }
   }
 }
 
-pub struct EoceBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct MintProofsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EoceBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MintProofsBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_subscription_id(&mut self, subscription_id: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Eoce::VT_SUBSCRIPTION_ID, subscription_id);
+  pub fn add_mint(&mut self, mint: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MintProofs::VT_MINT, mint);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EoceBuilder<'a, 'b, A> {
+  pub fn add_proofs(&mut self, proofs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Proof<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MintProofs::VT_PROOFS, proofs);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MintProofsBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    EoceBuilder {
+    MintProofsBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Eoce<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<MintProofs<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, Eoce::VT_SUBSCRIPTION_ID,"subscription_id");
+    self.fbb_.required(o, MintProofs::VT_MINT,"mint");
+    self.fbb_.required(o, MintProofs::VT_PROOFS,"proofs");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Eoce<'_> {
+impl core::fmt::Debug for MintProofs<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Eoce");
-      ds.field("subscription_id", &self.subscription_id());
+    let mut ds = f.debug_struct("MintProofs");
+      ds.field("mint", &self.mint());
+      ds.field("proofs", &self.proofs());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct EoceT {
-  pub subscription_id: String,
+pub struct MintProofsT {
+  pub mint: String,
+  pub proofs: Vec<ProofT>,
 }
-impl Default for EoceT {
+impl Default for MintProofsT {
   fn default() -> Self {
     Self {
-      subscription_id: "".to_string(),
+      mint: "".to_string(),
+      proofs: Default::default(),
     }
   }
 }
-impl EoceT {
+impl MintProofsT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<Eoce<'b>> {
-    let subscription_id = Some({
-      let x = &self.subscription_id;
+  ) -> flatbuffers::WIPOffset<MintProofs<'b>> {
+    let mint = Some({
+      let x = &self.mint;
       _fbb.create_string(x)
     });
-    Eoce::create(_fbb, &EoceArgs{
-      subscription_id,
+    let proofs = Some({
+      let x = &self.proofs;
+      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+    });
+    MintProofs::create(_fbb, &MintProofsArgs{
+      mint,
+      proofs,
     })
   }
 }
-pub enum BufferFullOffset {}
+pub enum PubkeyOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct BufferFull<'a> {
+pub struct Pubkey<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for BufferFull<'a> {
-  type Inner = BufferFull<'a>;
+impl<'a> flatbuffers::Follow<'a> for Pubkey<'a> {
+  type Inner = Pubkey<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> BufferFull<'a> {
-  pub const VT_DROPPED_EVENTS: flatbuffers::VOffsetT = 4;
+impl<'a> Pubkey<'a> {
+  pub const VT_PUBKEY: flatbuffers::VOffsetT = 4;
+  pub const VT_ERROR: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    BufferFull { _tab: table }
+    Pubkey { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args BufferFullArgs
-  ) -> flatbuffers::WIPOffset<BufferFull<'bldr>> {
-    let mut builder = BufferFullBuilder::new(_fbb);
-    builder.add_dropped_events(args.dropped_events);
+    args: &'args PubkeyArgs<'args>
+  ) -> flatbuffers::WIPOffset<Pubkey<'bldr>> {
+    let mut builder = PubkeyBuilder::new(_fbb);
+    if let Some(x) = args.error { builder.add_error(x); }
+    if let Some(x) = args.pubkey { builder.add_pubkey(x); }
     builder.finish()
   }
 
-  pub fn unpack(&self) -> BufferFullT {
-    let dropped_events = self.dropped_events();
-    BufferFullT {
-      dropped_events,
+  pub fn unpack(&self) -> PubkeyT {
+    let pubkey = {
+      let x = self.pubkey();
+      x.to_string()
+    };
+    let error = self.error().map(|x| {
+      x.to_string()
+    });
+    PubkeyT {
+      pubkey,
+      error,
     }
   }
 
   #[inline]
-  pub fn dropped_events(&self) -> u16 {
+  pub fn pubkey(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u16>(BufferFull::VT_DROPPED_EVENTS, Some(0)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Pubkey::VT_PUBKEY, None).unwrap()}
+  }
+  #[inline]
+  pub fn error(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Pubkey::VT_ERROR, None)}
   }
 }
 
-impl flatbuffers::Verifiable for BufferFull<'_> {
+impl flatbuffers::Verifiable for Pubkey<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<u16>("dropped_events", Self::VT_DROPPED_EVENTS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("pubkey", Self::VT_PUBKEY, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("error", Self::VT_ERROR, false)?
      .finish();
     Ok(())
   }
 }
-pub struct BufferFullArgs {
-    pub dropped_events: u16,
+pub struct PubkeyArgs<'a> {
+    pub pubkey: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub error: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for BufferFullArgs {
+impl<'a> Default for PubkeyArgs<'a> {
   #[inline]
   fn default() -> Self {
-    BufferFullArgs {
-      dropped_events: 0,
+    PubkeyArgs {
+      pubkey: None, // required field
+      error: None,
     }
   }
 }
 
-pub struct BufferFullBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct PubkeyBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BufferFullBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PubkeyBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_dropped_events(&mut self, dropped_events: u16) {
-    self.fbb_.push_slot::<u16>(BufferFull::VT_DROPPED_EVENTS, dropped_events, 0);
+  pub fn add_pubkey(&mut self, pubkey: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Pubkey::VT_PUBKEY, pubkey);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> BufferFullBuilder<'a, 'b, A> {
+  pub fn add_error(&mut self, error: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Pubkey::VT_ERROR, error);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PubkeyBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    BufferFullBuilder {
+    PubkeyBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<BufferFull<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<Pubkey<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Pubkey::VT_PUBKEY,"pubkey");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for BufferFull<'_> {
+impl core::fmt::Debug for Pubkey<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("BufferFull");
-      ds.field("dropped_events", &self.dropped_events());
+    let mut ds = f.debug_struct("Pubkey");
+      ds.field("pubkey", &self.pubkey());
+      ds.field("error", &self.error());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct BufferFullT {
-  pub dropped_events: u16,
+pub struct PubkeyT {
+  pub pubkey: String,
+  pub error: Option<String>,
 }
-impl Default for BufferFullT {
+impl Default for PubkeyT {
   fn default() -> Self {
     Self {
-      dropped_events: 0,
+      pubkey: "".to_string(),
+      error: None,
     }
   }
 }
-impl BufferFullT {
+impl PubkeyT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<BufferFull<'b>> {
-    let dropped_events = self.dropped_events;
-    BufferFull::create(_fbb, &BufferFullArgs{
-      dropped_events,
+  ) -> flatbuffers::WIPOffset<Pubkey<'b>> {
+    let pubkey = Some({
+      let x = &self.pubkey;
+      _fbb.create_string(x)
+    });
+    let error = self.error.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    Pubkey::create(_fbb, &PubkeyArgs{
+      pubkey,
+      error,
     })
   }
 }
-pub enum ValidProofsOffset {}
+pub enum SignedEventOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ValidProofs<'a> {
+pub struct SignedEvent<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ValidProofs<'a> {
-  type Inner = ValidProofs<'a>;
+impl<'a> flatbuffers::Follow<'a> for SignedEvent<'a> {
+  type Inner = SignedEvent<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> ValidProofs<'a> {
-  pub const VT_PROOFS: flatbuffers::VOffsetT = 4;
+impl<'a> SignedEvent<'a> {
+  pub const VT_EVENT: flatbuffers::VOffsetT = 4;
+  pub const VT_REQUEST_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_ERROR: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    ValidProofs { _tab: table }
+    SignedEvent { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ValidProofsArgs<'args>
-  ) -> flatbuffers::WIPOffset<ValidProofs<'bldr>> {
-    let mut builder = ValidProofsBuilder::new(_fbb);
-    if let Some(x) = args.proofs { builder.add_proofs(x); }
+    args: &'args SignedEventArgs<'args>
+  ) -> flatbuffers::WIPOffset<SignedEvent<'bldr>> {
+    let mut builder = SignedEventBuilder::new(_fbb);
+    if let Some(x) = args.error { builder.add_error(x); }
+    builder.add_request_id(args.request_id);
+    if let Some(x) = args.event { builder.add_event(x); }
     builder.finish()
   }
 
-  pub fn unpack(&self) -> ValidProofsT {
-    let proofs = {
-      let x = self.proofs();
-      x.iter().map(|t| t.unpack()).collect()
-    };
-    ValidProofsT {
-      proofs,
+  pub fn unpack(&self) -> SignedEventT {
+    let event = self.event().map(|x| {
+      Box::new(x.unpack())
+    });
+    let request_id = self.request_id();
+    let error = self.error().map(|x| {
+      x.to_string()
+    });
+    SignedEventT {
+      event,
+      request_id,
+      error,
     }
   }
 
   #[inline]
-  pub fn proofs(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MintProofs<'a>>> {
+  pub fn event(&self) -> Option<NostrEvent<'a>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MintProofs>>>>(ValidProofs::VT_PROOFS, None).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<NostrEvent>>(SignedEvent::VT_EVENT, None)}
+  }
+  #[inline]
+  pub fn request_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(SignedEvent::VT_REQUEST_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn error(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SignedEvent::VT_ERROR, None)}
   }
 }
 
-impl flatbuffers::Verifiable for ValidProofs<'_> {
+impl flatbuffers::Verifiable for SignedEvent<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<MintProofs>>>>("proofs", Self::VT_PROOFS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<NostrEvent>>("event", Self::VT_EVENT, false)?
+     .visit_field::<u32>("request_id", Self::VT_REQUEST_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("error", Self::VT_ERROR, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ValidProofsArgs<'a> {
-    pub proofs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MintProofs<'a>>>>>,
+pub struct SignedEventArgs<'a> {
+    pub event: Option<flatbuffers::WIPOffset<NostrEvent<'a>>>,
+    pub request_id: u32,
+    pub error: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for ValidProofsArgs<'a> {
+impl<'a> Default for SignedEventArgs<'a> {
   #[inline]
   fn default() -> Self {
-    ValidProofsArgs {
-      proofs: None, // required field
+    SignedEventArgs {
+      event: None,
+      request_id: 0,
+      error: None,
     }
   }
 }
 
-pub struct ValidProofsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SignedEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ValidProofsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SignedEventBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_proofs(&mut self, proofs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<MintProofs<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ValidProofs::VT_PROOFS, proofs);
+  pub fn add_event(&mut self, event: flatbuffers::WIPOffset<NostrEvent<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<NostrEvent>>(SignedEvent::VT_EVENT, event);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ValidProofsBuilder<'a, 'b, A> {
+  pub fn add_request_id(&mut self, request_id: u32) {
+    self.fbb_.push_slot::<u32>(SignedEvent::VT_REQUEST_ID, request_id, 0);
+  }
+  #[inline]
+  pub fn add_error(&mut self, error: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SignedEvent::VT_ERROR, error);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SignedEventBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ValidProofsBuilder {
+    SignedEventBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<ValidProofs<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SignedEvent<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, ValidProofs::VT_PROOFS,"proofs");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for ValidProofs<'_> {
+impl core::fmt::Debug for SignedEvent<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("ValidProofs");
-      ds.field("proofs", &self.proofs());
+    let mut ds = f.debug_struct("SignedEvent");
+      ds.field("event", &self.event());
+      ds.field("request_id", &self.request_id());
+      ds.field("error", &self.error());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct ValidProofsT {
-  pub proofs: Vec<MintProofsT>,
+pub struct SignedEventT {
+  pub event: Option<Box<NostrEventT>>,
+  pub request_id: u32,
+  pub error: Option<String>,
 }
-impl Default for ValidProofsT {
+impl Default for SignedEventT {
   fn default() -> Self {
     Self {
-      proofs: Default::default(),
+      event: None,
+      request_id: 0,
+      error: None,
     }
   }
 }
-impl ValidProofsT {
+impl SignedEventT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<ValidProofs<'b>> {
-    let proofs = Some({
-      let x = &self.proofs;
-      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+  ) -> flatbuffers::WIPOffset<SignedEvent<'b>> {
+    let event = self.event.as_ref().map(|x|{
+      x.pack(_fbb)
     });
-    ValidProofs::create(_fbb, &ValidProofsArgs{
-      proofs,
+    let request_id = self.request_id;
+    let error = self.error.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    SignedEvent::create(_fbb, &SignedEventArgs{
+      event,
+      request_id,
+      error,
     })
   }
 }
-pub enum MintProofsOffset {}
+pub enum SetSignerResponseOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MintProofs<'a> {
+pub struct SetSignerResponse<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for MintProofs<'a> {
-  type Inner = MintProofs<'a>;
+impl<'a> flatbuffers::Follow<'a> for SetSignerResponse<'a> {
+  type Inner = SetSignerResponse<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> MintProofs<'a> {
-  pub const VT_MINT: flatbuffers::VOffsetT = 4;
-  pub const VT_PROOFS: flatbuffers::VOffsetT = 6;
+impl<'a> SetSignerResponse<'a> {
+  pub const VT_PUBKEY: flatbuffers::VOffsetT = 4;
+  pub const VT_BUNKER_URL: flatbuffers::VOffsetT = 6;
+  pub const VT_ERROR: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    MintProofs { _tab: table }
+    SetSignerResponse { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args MintProofsArgs<'args>
-  ) -> flatbuffers::WIPOffset<MintProofs<'bldr>> {
-    let mut builder = MintProofsBuilder::new(_fbb);
-    if let Some(x) = args.proofs { builder.add_proofs(x); }
-    if let Some(x) = args.mint { builder.add_mint(x); }
+    args: &'args SetSignerResponseArgs<'args>
+  ) -> flatbuffers::WIPOffset<SetSignerResponse<'bldr>> {
+    let mut builder = SetSignerResponseBuilder::new(_fbb);
+    if let Some(x) = args.error { builder.add_error(x); }
+    if let Some(x) = args.bunker_url { builder.add_bunker_url(x); }
+    if let Some(x) = args.pubkey { builder.add_pubkey(x); }
     builder.finish()
   }
 
-  pub fn unpack(&self) -> MintProofsT {
-    let mint = {
-      let x = self.mint();
+  pub fn unpack(&self) -> SetSignerResponseT {
+    let pubkey = self.pubkey().map(|x| {
       x.to_string()
-    };
-    let proofs = {
-      let x = self.proofs();
-      x.iter().map(|t| t.unpack()).collect()
-    };
-    MintProofsT {
-      mint,
-      proofs,
+    });
+    let bunker_url = self.bunker_url().map(|x| {
+      x.to_string()
+    });
+    let error = self.error().map(|x| {
+      x.to_string()
+    });
+    SetSignerResponseT {
+      pubkey,
+      bunker_url,
+      error,
     }
   }
-
+
+  #[inline]
+  pub fn pubkey(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SetSignerResponse::VT_PUBKEY, None)}
+  }
   #[inline]
-  pub fn mint(&self) -> &'a str {
+  pub fn bunker_url(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(MintProofs::VT_MINT, None).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SetSignerResponse::VT_BUNKER_URL, None)}
   }
   #[inline]
-  pub fn proofs(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Proof<'a>>> {
+  pub fn error(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Proof>>>>(MintProofs::VT_PROOFS, None).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SetSignerResponse::VT_ERROR, None)}
   }
 }
 
-impl flatbuffers::Verifiable for MintProofs<'_> {
+impl flatbuffers::Verifiable for SetSignerResponse<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("mint", Self::VT_MINT, true)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Proof>>>>("proofs", Self::VT_PROOFS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("pubkey", Self::VT_PUBKEY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("bunker_url", Self::VT_BUNKER_URL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("error", Self::VT_ERROR, false)?
      .finish();
     Ok(())
   }
 }
-pub struct MintProofsArgs<'a> {
-    pub mint: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub proofs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Proof<'a>>>>>,
+pub struct SetSignerResponseArgs<'a> {
+    pub pubkey: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub bunker_url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub error: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for MintProofsArgs<'a> {
+impl<'a> Default for SetSignerResponseArgs<'a> {
   #[inline]
   fn default() -> Self {
-    MintProofsArgs {
-      mint: None, // required field
-      proofs: None, // required field
+    SetSignerResponseArgs {
+      pubkey: None,
+      bunker_url: None,
+      error: None,
     }
   }
 }
 
-pub struct MintProofsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SetSignerResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MintProofsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetSignerResponseBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_mint(&mut self, mint: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MintProofs::VT_MINT, mint);
+  pub fn add_pubkey(&mut self, pubkey: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SetSignerResponse::VT_PUBKEY, pubkey);
   }
   #[inline]
-  pub fn add_proofs(&mut self, proofs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Proof<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MintProofs::VT_PROOFS, proofs);
+  pub fn add_bunker_url(&mut self, bunker_url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SetSignerResponse::VT_BUNKER_URL, bunker_url);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MintProofsBuilder<'a, 'b, A> {
+  pub fn add_error(&mut self, error: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SetSignerResponse::VT_ERROR, error);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetSignerResponseBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    MintProofsBuilder {
+    SetSignerResponseBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<MintProofs<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetSignerResponse<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, MintProofs::VT_MINT,"mint");
-    self.fbb_.required(o, MintProofs::VT_PROOFS,"proofs");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for MintProofs<'_> {
+impl core::fmt::Debug for SetSignerResponse<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("MintProofs");
-      ds.field("mint", &self.mint());
-      ds.field("proofs", &self.proofs());
+    let mut ds = f.debug_struct("SetSignerResponse");
+      ds.field("pubkey", &self.pubkey());
+      ds.field("bunker_url", &self.bunker_url());
+      ds.field("error", &self.error());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct MintProofsT {
-  pub mint: String,
-  pub proofs: Vec<ProofT>,
+pub struct SetSignerResponseT {
+  pub pubkey: Option<String>,
+  pub bunker_url: Option<String>,
+  pub error: Option<String>,
 }
-impl Default for MintProofsT {
+impl Default for SetSignerResponseT {
   fn default() -> Self {
     Self {
-      mint: "".to_string(),
-      proofs: Default::default(),
+      pubkey: None,
+      bunker_url: None,
+      error: None,
     }
   }
 }
-impl MintProofsT {
+impl SetSignerResponseT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<MintProofs<'b>> {
-    let mint = Some({
-      let x = &self.mint;
+  ) -> flatbuffers::WIPOffset<SetSignerResponse<'b>> {
+    let pubkey = self.pubkey.as_ref().map(|x|{
       _fbb.create_string(x)
     });
-    let proofs = Some({
-      let x = &self.proofs;
-      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+    let bunker_url = self.bunker_url.as_ref().map(|x|{
+      _fbb.create_string(x)
     });
-    MintProofs::create(_fbb, &MintProofsArgs{
-      mint,
-      proofs,
+    let error = self.error.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    SetSignerResponse::create(_fbb, &SetSignerResponseArgs{
+      pubkey,
+      bunker_url,
+      error,
     })
   }
 }
-pub enum PubkeyOffset {}
+pub enum RawOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Pubkey<'a> {
+pub struct Raw<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Pubkey<'a> {
-  type Inner = Pubkey<'a>;
+impl<'a> flatbuffers::Follow<'a> for Raw<'a> {
+  type Inner = Raw<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> Pubkey<'a> {
-  pub const VT_PUBKEY: flatbuffers::VOffsetT = 4;
-  pub const VT_ERROR: flatbuffers::VOffsetT = 6;
+impl<'a> Raw<'a> {
+  pub const VT_RAW: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Pubkey { _tab: table }
+    Raw { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args PubkeyArgs<'args>
-  ) -> flatbuffers::WIPOffset<Pubkey<'bldr>> {
-    let mut builder = PubkeyBuilder::new(_fbb);
-    if let Some(x) = args.error { builder.add_error(x); }
-    if let Some(x) = args.pubkey { builder.add_pubkey(x); }
+    args: &'args RawArgs<'args>
+  ) -> flatbuffers::WIPOffset<Raw<'bldr>> {
+    let mut builder = RawBuilder::new(_fbb);
+    if let Some(x) = args.raw { builder.add_raw(x); }
     builder.finish()
   }
 
-  pub fn unpack(&self) -> PubkeyT {
-    let pubkey = {
-      let x = self.pubkey();
+  pub fn unpack(&self) -> RawT {
+    let raw = {
+      let x = self.raw();
       x.to_string()
     };
-    let error = self.error().map(|x| {
-      x.to_string()
-    });
-    PubkeyT {
-      pubkey,
-      error,
+    RawT {
+      raw,
     }
   }
 
   #[inline]
-  pub fn pubkey(&self) -> &'a str {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Pubkey::VT_PUBKEY, None).unwrap()}
-  }
-  #[inline]
-  pub fn error(&self) -> Option<&'a str> {
+  pub fn raw(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Pubkey::VT_ERROR, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Raw::VT_RAW, None).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for Pubkey<'_> {
+impl flatbuffers::Verifiable for Raw<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("pubkey", Self::VT_PUBKEY, true)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("error", Self::VT_ERROR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("raw", Self::VT_RAW, true)?
      .finish();
     Ok(())
   }
 }
-pub struct PubkeyArgs<'a> {
-    pub pubkey: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub error: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct RawArgs<'a> {
+    pub raw: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for PubkeyArgs<'a> {
+impl<'a> Default for RawArgs<'a> {
   #[inline]
   fn default() -> Self {
-    PubkeyArgs {
-      pubkey: None, // required field
-      error: None,
+    RawArgs {
+      raw: None, // required field
     }
   }
 }
 
-pub struct PubkeyBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct RawBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PubkeyBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_pubkey(&mut self, pubkey: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Pubkey::VT_PUBKEY, pubkey);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RawBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_error(&mut self, error: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Pubkey::VT_ERROR, error);
+  pub fn add_raw(&mut self, raw: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Raw::VT_RAW, raw);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PubkeyBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RawBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    PubkeyBuilder {
+    RawBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Pubkey<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<Raw<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, Pubkey::VT_PUBKEY,"pubkey");
+    self.fbb_.required(o, Raw::VT_RAW,"raw");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Pubkey<'_> {
+impl core::fmt::Debug for Raw<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Pubkey");
-      ds.field("pubkey", &self.pubkey());
-      ds.field("error", &self.error());
+    let mut ds = f.debug_struct("Raw");
+      ds.field("raw", &self.raw());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct PubkeyT {
-  pub pubkey: String,
-  pub error: Option<String>,
+pub struct RawT {
+  pub raw: String,
 }
-impl Default for PubkeyT {
+impl Default for RawT {
   fn default() -> Self {
     Self {
-      pubkey: "".to_string(),
-      error: None,
+      raw: "".to_string(),
     }
   }
 }
-impl PubkeyT {
+impl RawT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<Pubkey<'b>> {
-    let pubkey = Some({
-      let x = &self.pubkey;
-      _fbb.create_string(x)
-    });
-    let error = self.error.as_ref().map(|x|{
+  ) -> flatbuffers::WIPOffset<Raw<'b>> {
+    let raw = Some({
+      let x = &self.raw;
       _fbb.create_string(x)
     });
-    Pubkey::create(_fbb, &PubkeyArgs{
-      pubkey,
-      error,
+    Raw::create(_fbb, &RawArgs{
+      raw,
     })
   }
 }
-pub enum SignedEventOffset {}
+pub enum AuthUrlOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SignedEvent<'a> {
+pub struct AuthUrl<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SignedEvent<'a> {
-  type Inner = SignedEvent<'a>;
+impl<'a> flatbuffers::Follow<'a> for AuthUrl<'a> {
+  type Inner = AuthUrl<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> SignedEvent<'a> {
-  pub const VT_EVENT: flatbuffers::VOffsetT = 4;
+impl<'a> AuthUrl<'a> {
+  pub const VT_URL: flatbuffers::VOffsetT = 4;
   pub const VT_REQUEST_ID: flatbuffers::VOffsetT = 6;
-  pub const VT_ERROR: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    SignedEvent { _tab: table }
+    AuthUrl { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SignedEventArgs<'args>
-  ) -> flatbuffers::WIPOffset<SignedEvent<'bldr>> {
-    let mut builder = SignedEventBuilder::new(_fbb);
-    if let Some(x) = args.error { builder.add_error(x); }
-    builder.add_request_id(args.request_id);
-    if let Some(x) = args.event { builder.add_event(x); }
+    args: &'args AuthUrlArgs<'args>
+  ) -> flatbuffers::WIPOffset<AuthUrl<'bldr>> {
+    let mut builder = AuthUrlBuilder::new(_fbb);
+    if let Some(x) = args.request_id { builder.add_request_id(x); }
+    if let Some(x) = args.url { builder.add_url(x); }
     builder.finish()
   }
 
-  pub fn unpack(&self) -> SignedEventT {
-    let event = self.event().map(|x| {
-      Box::new(x.unpack())
-    });
-    let request_id = self.request_id();
-    let error = self.error().map(|x| {
+  pub fn unpack(&self) -> AuthUrlT {
+    let url = {
+      let x = self.url();
+      x.to_string()
+    };
+    let request_id = self.request_id().map(|x| {
       x.to_string()
     });
-    SignedEventT {
-      event,
+    AuthUrlT {
+      url,
       request_id,
-      error,
     }
   }
 
   #[inline]
-  pub fn event(&self) -> Option<NostrEvent<'a>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<NostrEvent>>(SignedEvent::VT_EVENT, None)}
-  }
-  #[inline]
-  pub fn request_id(&self) -> u32 {
+  pub fn url(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(SignedEvent::VT_REQUEST_ID, Some(0)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AuthUrl::VT_URL, None).unwrap()}
   }
   #[inline]
-  pub fn error(&self) -> Option<&'a str> {
+  pub fn request_id(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SignedEvent::VT_ERROR, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AuthUrl::VT_REQUEST_ID, None)}
   }
 }
 
-impl flatbuffers::Verifiable for SignedEvent<'_> {
+impl flatbuffers::Verifiable for AuthUrl<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<NostrEvent>>("event", Self::VT_EVENT, false)?
-     .visit_field::<u32>("request_id", Self::VT_REQUEST_ID, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("error", Self::VT_ERROR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("request_id", Self::VT_REQUEST_ID, false)?
      .finish();
     Ok(())
   }
 }
-pub struct SignedEventArgs<'a> {
-    pub event: Option<flatbuffers::WIPOffset<NostrEvent<'a>>>,
-    pub request_id: u32,
-    pub error: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct AuthUrlArgs<'a> {
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub request_id: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for SignedEventArgs<'a> {
+impl<'a> Default for AuthUrlArgs<'a> {
   #[inline]
   fn default() -> Self {
-    SignedEventArgs {
-      event: None,
-      request_id: 0,
-      error: None,
+    AuthUrlArgs {
+      url: None, // required field
+      request_id: None,
     }
   }
 }
 
-pub struct SignedEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct AuthUrlBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SignedEventBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_event(&mut self, event: flatbuffers::WIPOffset<NostrEvent<'b >>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<NostrEvent>>(SignedEvent::VT_EVENT, event);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuthUrlBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_request_id(&mut self, request_id: u32) {
-    self.fbb_.push_slot::<u32>(SignedEvent::VT_REQUEST_ID, request_id, 0);
+  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AuthUrl::VT_URL, url);
   }
   #[inline]
-  pub fn add_error(&mut self, error: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SignedEvent::VT_ERROR, error);
+  pub fn add_request_id(&mut self, request_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AuthUrl::VT_REQUEST_ID, request_id);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SignedEventBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AuthUrlBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    SignedEventBuilder {
+    AuthUrlBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<SignedEvent<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<AuthUrl<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, AuthUrl::VT_URL,"url");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for SignedEvent<'_> {
+impl core::fmt::Debug for AuthUrl<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("SignedEvent");
-      ds.field("event", &self.event());
+    let mut ds = f.debug_struct("AuthUrl");
+      ds.field("url", &self.url());
       ds.field("request_id", &self.request_id());
-      ds.field("error", &self.error());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct SignedEventT {
-  pub event: Option<Box<NostrEventT>>,
-  pub request_id: u32,
-  pub error: Option<String>,
+pub struct AuthUrlT {
+  pub url: String,
+  pub request_id: Option<String>,
 }
-impl Default for SignedEventT {
+impl Default for AuthUrlT {
   fn default() -> Self {
     Self {
-      event: None,
-      request_id: 0,
-      error: None,
+      url: "".to_string(),
+      request_id: None,
     }
   }
 }
-impl SignedEventT {
+impl AuthUrlT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<SignedEvent<'b>> {
-    let event = self.event.as_ref().map(|x|{
-      x.pack(_fbb)
+  ) -> flatbuffers::WIPOffset<AuthUrl<'b>> {
+    let url = Some({
+      let x = &self.url;
+      _fbb.create_string(x)
     });
-    let request_id = self.request_id;
-    let error = self.error.as_ref().map(|x|{
+    let request_id = self.request_id.as_ref().map(|x|{
       _fbb.create_string(x)
     });
-    SignedEvent::create(_fbb, &SignedEventArgs{
-      event,
+    AuthUrl::create(_fbb, &AuthUrlArgs{
+      url,
       request_id,
-      error,
     })
   }
 }
-pub enum SetSignerResponseOffset {}
+pub enum ThreadNodeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SetSignerResponse<'a> {
+pub struct ThreadNode<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SetSignerResponse<'a> {
-  type Inner = SetSignerResponse<'a>;
+impl<'a> flatbuffers::Follow<'a> for ThreadNode<'a> {
+  type Inner = ThreadNode<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> SetSignerResponse<'a> {
-  pub const VT_PUBKEY: flatbuffers::VOffsetT = 4;
-  pub const VT_BUNKER_URL: flatbuffers::VOffsetT = 6;
-  pub const VT_ERROR: flatbuffers::VOffsetT = 8;
+impl<'a> ThreadNode<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_PARENT: flatbuffers::VOffsetT = 6;
+  pub const VT_DEPTH: flatbuffers::VOffsetT = 8;
+  pub const VT_MISSING: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    SetSignerResponse { _tab: table }
+    ThreadNode { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SetSignerResponseArgs<'args>
-  ) -> flatbuffers::WIPOffset<SetSignerResponse<'bldr>> {
-    let mut builder = SetSignerResponseBuilder::new(_fbb);
-    if let Some(x) = args.error { builder.add_error(x); }
-    if let Some(x) = args.bunker_url { builder.add_bunker_url(x); }
-    if let Some(x) = args.pubkey { builder.add_pubkey(x); }
+    args: &'args ThreadNodeArgs<'args>
+  ) -> flatbuffers::WIPOffset<ThreadNode<'bldr>> {
+    let mut builder = ThreadNodeBuilder::new(_fbb);
+    if let Some(x) = args.parent { builder.add_parent(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_depth(args.depth);
+    builder.add_missing(args.missing);
     builder.finish()
   }
 
-  pub fn unpack(&self) -> SetSignerResponseT {
-    let pubkey = self.pubkey().map(|x| {
-      x.to_string()
-    });
-    let bunker_url = self.bunker_url().map(|x| {
+  pub fn unpack(&self) -> ThreadNodeT {
+    let id = {
+      let x = self.id();
       x.to_string()
-    });
-    let error = self.error().map(|x| {
+    };
+    let parent = self.parent().map(|x| {
       x.to_string()
     });
-    SetSignerResponseT {
-      pubkey,
-      bunker_url,
-      error,
+    let depth = self.depth();
+    let missing = self.missing();
+    ThreadNodeT {
+      id,
+      parent,
+      depth,
+      missing,
     }
   }
 
   #[inline]
-  pub fn pubkey(&self) -> Option<&'a str> {
+  pub fn id(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SetSignerResponse::VT_PUBKEY, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ThreadNode::VT_ID, None).unwrap()}
   }
   #[inline]
-  pub fn bunker_url(&self) -> Option<&'a str> {
+  pub fn parent(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SetSignerResponse::VT_BUNKER_URL, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ThreadNode::VT_PARENT, None)}
   }
   #[inline]
-  pub fn error(&self) -> Option<&'a str> {
+  pub fn depth(&self) -> u16 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SetSignerResponse::VT_ERROR, None)}
+    unsafe { self._tab.get::<u16>(ThreadNode::VT_DEPTH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn missing(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ThreadNode::VT_MISSING, Some(false)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for SetSignerResponse<'_> {
+impl flatbuffers::Verifiable for ThreadNode<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("pubkey", Self::VT_PUBKEY, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("bunker_url", Self::VT_BUNKER_URL, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("error", Self::VT_ERROR, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("parent", Self::VT_PARENT, false)?
+     .visit_field::<u16>("depth", Self::VT_DEPTH, false)?
+     .visit_field::<bool>("missing", Self::VT_MISSING, false)?
      .finish();
     Ok(())
   }
 }
-pub struct SetSignerResponseArgs<'a> {
-    pub pubkey: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub bunker_url: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub error: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct ThreadNodeArgs<'a> {
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub parent: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub depth: u16,
+    pub missing: bool,
 }
-impl<'a> Default for SetSignerResponseArgs<'a> {
+impl<'a> Default for ThreadNodeArgs<'a> {
   #[inline]
   fn default() -> Self {
-    SetSignerResponseArgs {
-      pubkey: None,
-      bunker_url: None,
-      error: None,
+    ThreadNodeArgs {
+      id: None, // required field
+      parent: None,
+      depth: 0,
+      missing: false,
     }
   }
 }
 
-pub struct SetSignerResponseBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ThreadNodeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SetSignerResponseBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ThreadNodeBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_pubkey(&mut self, pubkey: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SetSignerResponse::VT_PUBKEY, pubkey);
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ThreadNode::VT_ID, id);
   }
   #[inline]
-  pub fn add_bunker_url(&mut self, bunker_url: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SetSignerResponse::VT_BUNKER_URL, bunker_url);
+  pub fn add_parent(&mut self, parent: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ThreadNode::VT_PARENT, parent);
   }
   #[inline]
-  pub fn add_error(&mut self, error: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SetSignerResponse::VT_ERROR, error);
+  pub fn add_depth(&mut self, depth: u16) {
+    self.fbb_.push_slot::<u16>(ThreadNode::VT_DEPTH, depth, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SetSignerResponseBuilder<'a, 'b, A> {
+  pub fn add_missing(&mut self, missing: bool) {
+    self.fbb_.push_slot::<bool>(ThreadNode::VT_MISSING, missing, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ThreadNodeBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    SetSignerResponseBuilder {
+    ThreadNodeBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<SetSignerResponse<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ThreadNode<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ThreadNode::VT_ID,"id");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for SetSignerResponse<'_> {
+impl core::fmt::Debug for ThreadNode<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("SetSignerResponse");
-      ds.field("pubkey", &self.pubkey());
-      ds.field("bunker_url", &self.bunker_url());
-      ds.field("error", &self.error());
+    let mut ds = f.debug_struct("ThreadNode");
+      ds.field("id", &self.id());
+      ds.field("parent", &self.parent());
+      ds.field("depth", &self.depth());
+      ds.field("missing", &self.missing());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct SetSignerResponseT {
-  pub pubkey: Option<String>,
-  pub bunker_url: Option<String>,
-  pub error: Option<String>,
+pub struct ThreadNodeT {
+  pub id: String,
+  pub parent: Option<String>,
+  pub depth: u16,
+  pub missing: bool,
 }
-impl Default for SetSignerResponseT {
+impl Default for ThreadNodeT {
   fn default() -> Self {
     Self {
-      pubkey: None,
-      bunker_url: None,
-      error: None,
+      id: "".to_string(),
+      parent: None,
+      depth: 0,
+      missing: false,
     }
   }
 }
-impl SetSignerResponseT {
+impl ThreadNodeT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<SetSignerResponse<'b>> {
-    let pubkey = self.pubkey.as_ref().map(|x|{
-      _fbb.create_string(x)
-    });
-    let bunker_url = self.bunker_url.as_ref().map(|x|{
+  ) -> flatbuffers::WIPOffset<ThreadNode<'b>> {
+    let id = Some({
+      let x = &self.id;
       _fbb.create_string(x)
     });
-    let error = self.error.as_ref().map(|x|{
+    let parent = self.parent.as_ref().map(|x|{
       _fbb.create_string(x)
     });
-    SetSignerResponse::create(_fbb, &SetSignerResponseArgs{
-      pubkey,
-      bunker_url,
-      error,
+    let depth = self.depth;
+    let missing = self.missing;
+    ThreadNode::create(_fbb, &ThreadNodeArgs{
+      id,
+      parent,
+      depth,
+      missing,
     })
   }
 }
-pub enum RawOffset {}
+pub enum ThreadTreeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Raw<'a> {
+pub struct ThreadTree<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Raw<'a> {
-  type Inner = Raw<'a>;
+impl<'a> flatbuffers::Follow<'a> for ThreadTree<'a> {
+  type Inner = ThreadTree<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> Raw<'a> {
-  pub const VT_RAW: flatbuffers::VOffsetT = 4;
+impl<'a> ThreadTree<'a> {
+  pub const VT_FOCUS: flatbuffers::VOffsetT = 4;
+  pub const VT_ROOT: flatbuffers::VOffsetT = 6;
+  pub const VT_NODES: flatbuffers::VOffsetT = 8;
+  pub const VT_COMPLETE: flatbuffers::VOffsetT = 10;
+  pub const VT_DELTA: flatbuffers::VOffsetT = 12;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    Raw { _tab: table }
+    ThreadTree { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args RawArgs<'args>
-  ) -> flatbuffers::WIPOffset<Raw<'bldr>> {
-    let mut builder = RawBuilder::new(_fbb);
-    if let Some(x) = args.raw { builder.add_raw(x); }
+    args: &'args ThreadTreeArgs<'args>
+  ) -> flatbuffers::WIPOffset<ThreadTree<'bldr>> {
+    let mut builder = ThreadTreeBuilder::new(_fbb);
+    if let Some(x) = args.nodes { builder.add_nodes(x); }
+    if let Some(x) = args.root { builder.add_root(x); }
+    if let Some(x) = args.focus { builder.add_focus(x); }
+    builder.add_delta(args.delta);
+    builder.add_complete(args.complete);
     builder.finish()
   }
-
-  pub fn unpack(&self) -> RawT {
-    let raw = {
-      let x = self.raw();
-      x.to_string()
-    };
-    RawT {
-      raw,
-    }
+
+  pub fn unpack(&self) -> ThreadTreeT {
+    let focus = {
+      let x = self.focus();
+      x.to_string()
+    };
+    let root = {
+      let x = self.root();
+      x.to_string()
+    };
+    let nodes = {
+      let x = self.nodes();
+      x.iter().map(|t| t.unpack()).collect()
+    };
+    let complete = self.complete();
+    let delta = self.delta();
+    ThreadTreeT {
+      focus,
+      root,
+      nodes,
+      complete,
+      delta,
+    }
+  }
+
+  #[inline]
+  pub fn focus(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ThreadTree::VT_FOCUS, None).unwrap()}
+  }
+  #[inline]
+  pub fn root(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ThreadTree::VT_ROOT, None).unwrap()}
+  }
+  #[inline]
+  pub fn nodes(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ThreadNode<'a>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ThreadNode>>>>(ThreadTree::VT_NODES, None).unwrap()}
+  }
+  #[inline]
+  pub fn complete(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ThreadTree::VT_COMPLETE, Some(false)).unwrap()}
   }
-
   #[inline]
-  pub fn raw(&self) -> &'a str {
+  pub fn delta(&self) -> bool {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Raw::VT_RAW, None).unwrap()}
+    unsafe { self._tab.get::<bool>(ThreadTree::VT_DELTA, Some(false)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for Raw<'_> {
+impl flatbuffers::Verifiable for ThreadTree<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("raw", Self::VT_RAW, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("focus", Self::VT_FOCUS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("root", Self::VT_ROOT, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ThreadNode>>>>("nodes", Self::VT_NODES, true)?
+     .visit_field::<bool>("complete", Self::VT_COMPLETE, false)?
+     .visit_field::<bool>("delta", Self::VT_DELTA, false)?
      .finish();
     Ok(())
   }
 }
-pub struct RawArgs<'a> {
-    pub raw: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct ThreadTreeArgs<'a> {
+    pub focus: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub root: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub nodes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ThreadNode<'a>>>>>,
+    pub complete: bool,
+    pub delta: bool,
 }
-impl<'a> Default for RawArgs<'a> {
+impl<'a> Default for ThreadTreeArgs<'a> {
   #[inline]
   fn default() -> Self {
-    RawArgs {
-      raw: None, // required field
+    ThreadTreeArgs {
+      focus: None, // required field
+      root: None, // required field
+      nodes: None, // required field
+      complete: false,
+      delta: false,
     }
   }
 }
 
-pub struct RawBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ThreadTreeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RawBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ThreadTreeBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_raw(&mut self, raw: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Raw::VT_RAW, raw);
+  pub fn add_focus(&mut self, focus: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ThreadTree::VT_FOCUS, focus);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RawBuilder<'a, 'b, A> {
+  pub fn add_root(&mut self, root: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ThreadTree::VT_ROOT, root);
+  }
+  #[inline]
+  pub fn add_nodes(&mut self, nodes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ThreadNode<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ThreadTree::VT_NODES, nodes);
+  }
+  #[inline]
+  pub fn add_complete(&mut self, complete: bool) {
+    self.fbb_.push_slot::<bool>(ThreadTree::VT_COMPLETE, complete, false);
+  }
+  #[inline]
+  pub fn add_delta(&mut self, delta: bool) {
+    self.fbb_.push_slot::<bool>(ThreadTree::VT_DELTA, delta, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ThreadTreeBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    RawBuilder {
+    ThreadTreeBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<Raw<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ThreadTree<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, Raw::VT_RAW,"raw");
+    self.fbb_.required(o, ThreadTree::VT_FOCUS,"focus");
+    self.fbb_.required(o, ThreadTree::VT_ROOT,"root");
+    self.fbb_.required(o, ThreadTree::VT_NODES,"nodes");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for Raw<'_> {
+impl core::fmt::Debug for ThreadTree<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("Raw");
-      ds.field("raw", &self.raw());
+    let mut ds = f.debug_struct("ThreadTree");
+      ds.field("focus", &self.focus());
+      ds.field("root", &self.root());
+      ds.field("nodes", &self.nodes());
+      ds.field("complete", &self.complete());
+      ds.field("delta", &self.delta());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct RawT {
-  pub raw: String,
-}
-impl Default for RawT {
+pub struct ThreadTreeT {
+  pub focus: String,
+  pub root: String,
+  pub nodes: Vec<ThreadNodeT>,
+  pub complete: bool,
+  pub delta: bool,
+}
+impl Default for ThreadTreeT {
   fn default() -> Self {
     Self {
-      raw: "".to_string(),
+      focus: "".to_string(),
+      root: "".to_string(),
+      nodes: Default::default(),
+      complete: false,
+      delta: false,
     }
   }
 }
-impl RawT {
+impl ThreadTreeT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<Raw<'b>> {
-    let raw = Some({
-      let x = &self.raw;
+  ) -> flatbuffers::WIPOffset<ThreadTree<'b>> {
+    let focus = Some({
+      let x = &self.focus;
       _fbb.create_string(x)
     });
-    Raw::create(_fbb, &RawArgs{
-      raw,
+    let root = Some({
+      let x = &self.root;
+      _fbb.create_string(x)
+    });
+    let nodes = Some({
+      let x = &self.nodes;
+      let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();_fbb.create_vector(&w)
+    });
+    let complete = self.complete;
+    let delta = self.delta;
+    ThreadTree::create(_fbb, &ThreadTreeArgs{
+      focus,
+      root,
+      nodes,
+      complete,
+      delta,
     })
   }
 }
-pub enum AuthUrlOffset {}
+pub enum MentionDataOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AuthUrl<'a> {
+pub struct MentionData<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for AuthUrl<'a> {
-  type Inner = AuthUrl<'a>;
+impl<'a> flatbuffers::Follow<'a> for MentionData<'a> {
+  type Inner = MentionData<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> AuthUrl<'a> {
-  pub const VT_URL: flatbuffers::VOffsetT = 4;
-  pub const VT_REQUEST_ID: flatbuffers::VOffsetT = 6;
+impl<'a> MentionData<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_PICTURE: flatbuffers::VOffsetT = 8;
+  pub const VT_EVENT: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    AuthUrl { _tab: table }
+    MentionData { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args AuthUrlArgs<'args>
-  ) -> flatbuffers::WIPOffset<AuthUrl<'bldr>> {
-    let mut builder = AuthUrlBuilder::new(_fbb);
-    if let Some(x) = args.request_id { builder.add_request_id(x); }
-    if let Some(x) = args.url { builder.add_url(x); }
+    args: &'args MentionDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<MentionData<'bldr>> {
+    let mut builder = MentionDataBuilder::new(_fbb);
+    if let Some(x) = args.event { builder.add_event(x); }
+    if let Some(x) = args.picture { builder.add_picture(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
     builder.finish()
   }
 
-  pub fn unpack(&self) -> AuthUrlT {
-    let url = {
-      let x = self.url();
+  pub fn unpack(&self) -> MentionDataT {
+    let id = {
+      let x = self.id();
       x.to_string()
     };
-    let request_id = self.request_id().map(|x| {
+    let name = self.name().map(|x| {
       x.to_string()
     });
-    AuthUrlT {
-      url,
-      request_id,
+    let picture = self.picture().map(|x| {
+      x.to_string()
+    });
+    let event = self.event().map(|x| {
+      Box::new(x.unpack())
+    });
+    MentionDataT {
+      id,
+      name,
+      picture,
+      event,
     }
   }
 
   #[inline]
-  pub fn url(&self) -> &'a str {
+  pub fn id(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AuthUrl::VT_URL, None).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(MentionData::VT_ID, None).unwrap()}
   }
   #[inline]
-  pub fn request_id(&self) -> Option<&'a str> {
+  pub fn name(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AuthUrl::VT_REQUEST_ID, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(MentionData::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn picture(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(MentionData::VT_PICTURE, None)}
+  }
+  #[inline]
+  pub fn event(&self) -> Option<ParsedEvent<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<ParsedEvent>>(MentionData::VT_EVENT, None)}
   }
 }
 
-impl flatbuffers::Verifiable for AuthUrl<'_> {
+impl flatbuffers::Verifiable for MentionData<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, true)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("request_id", Self::VT_REQUEST_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("picture", Self::VT_PICTURE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<ParsedEvent>>("event", Self::VT_EVENT, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AuthUrlArgs<'a> {
-    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub request_id: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct MentionDataArgs<'a> {
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub picture: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub event: Option<flatbuffers::WIPOffset<ParsedEvent<'a>>>,
 }
-impl<'a> Default for AuthUrlArgs<'a> {
+impl<'a> Default for MentionDataArgs<'a> {
   #[inline]
   fn default() -> Self {
-    AuthUrlArgs {
-      url: None, // required field
-      request_id: None,
+    MentionDataArgs {
+      id: None, // required field
+      name: None,
+      picture: None,
+      event: None,
     }
   }
 }
 
-pub struct AuthUrlBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct MentionDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuthUrlBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MentionDataBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AuthUrl::VT_URL, url);
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MentionData::VT_ID, id);
   }
   #[inline]
-  pub fn add_request_id(&mut self, request_id: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AuthUrl::VT_REQUEST_ID, request_id);
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MentionData::VT_NAME, name);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AuthUrlBuilder<'a, 'b, A> {
+  pub fn add_picture(&mut self, picture: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MentionData::VT_PICTURE, picture);
+  }
+  #[inline]
+  pub fn add_event(&mut self, event: flatbuffers::WIPOffset<ParsedEvent<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<ParsedEvent>>(MentionData::VT_EVENT, event);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MentionDataBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    AuthUrlBuilder {
+    MentionDataBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<AuthUrl<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<MentionData<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, AuthUrl::VT_URL,"url");
+    self.fbb_.required(o, MentionData::VT_ID,"id");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for AuthUrl<'_> {
+impl core::fmt::Debug for MentionData<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("AuthUrl");
-      ds.field("url", &self.url());
-      ds.field("request_id", &self.request_id());
+    let mut ds = f.debug_struct("MentionData");
+      ds.field("id", &self.id());
+      ds.field("name", &self.name());
+      ds.field("picture", &self.picture());
+      ds.field("event", &self.event());
       ds.finish()
   }
 }
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
-pub struct AuthUrlT {
-  pub url: String,
-  pub request_id: Option<String>,
+pub struct MentionDataT {
+  pub id: String,
+  pub name: Option<String>,
+  pub picture: Option<String>,
+  pub event: Option<Box<ParsedEventT>>,
 }
-impl Default for AuthUrlT {
+impl Default for MentionDataT {
   fn default() -> Self {
     Self {
-      url: "".to_string(),
-      request_id: None,
+      id: "".to_string(),
+      name: None,
+      picture: None,
+      event: None,
     }
   }
 }
-impl AuthUrlT {
+impl MentionDataT {
   pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
     &self,
     _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>
-  ) -> flatbuffers::WIPOffset<AuthUrl<'b>> {
-    let url = Some({
-      let x = &self.url;
+  ) -> flatbuffers::WIPOffset<MentionData<'b>> {
+    let id = Some({
+      let x = &self.id;
       _fbb.create_string(x)
     });
-    let request_id = self.request_id.as_ref().map(|x|{
+    let name = self.name.as_ref().map(|x|{
       _fbb.create_string(x)
     });
-    AuthUrl::create(_fbb, &AuthUrlArgs{
-      url,
-      request_id,
+    let picture = self.picture.as_ref().map(|x|{
+      _fbb.create_string(x)
+    });
+    let event = self.event.as_ref().map(|x|{
+      x.pack(_fbb)
+    });
+    MentionData::create(_fbb, &MentionDataArgs{
+      id,
+      name,
+      picture,
+      event,
     })
   }
 }
@@ -27946,6 +30239,21 @@ impl<'a> WorkerMessage<'a> {
             .expect("Invalid union table, expected `Message::AuthUrl`.")
             .unpack()
       )),
+      Message::LinkPreviewData => MessageT::LinkPreviewData(Box::new(
+        self.content_as_link_preview_data()
+            .expect("Invalid union table, expected `Message::LinkPreviewData`.")
+            .unpack()
+      )),
+      Message::ThreadTree => MessageT::ThreadTree(Box::new(
+        self.content_as_thread_tree()
+            .expect("Invalid union table, expected `Message::ThreadTree`.")
+            .unpack()
+      )),
+      Message::MentionData => MessageT::MentionData(Box::new(
+        self.content_as_mention_data()
+            .expect("Invalid union table, expected `Message::MentionData`.")
+            .unpack()
+      )),
       _ => MessageT::NONE,
     };
     WorkerMessageT {
@@ -28171,6 +30479,51 @@ impl<'a> WorkerMessage<'a> {
     }
   }
 
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_link_preview_data(&self) -> Option<LinkPreviewData<'a>> {
+    if self.content_type() == Message::LinkPreviewData {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { LinkPreviewData::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_thread_tree(&self) -> Option<ThreadTree<'a>> {
+    if self.content_type() == Message::ThreadTree {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ThreadTree::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_mention_data(&self) -> Option<MentionData<'a>> {
+    if self.content_type() == Message::MentionData {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { MentionData::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
 }
 
 impl flatbuffers::Verifiable for WorkerMessage<'_> {
@@ -28197,6 +30550,9 @@ impl flatbuffers::Verifiable for WorkerMessage<'_> {
           Message::Raw => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Raw>>("Message::Raw", pos),
           Message::SetSignerResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetSignerResponse>>("Message::SetSignerResponse", pos),
           Message::AuthUrl => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AuthUrl>>("Message::AuthUrl", pos),
+          Message::LinkPreviewData => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LinkPreviewData>>("Message::LinkPreviewData", pos),
+          Message::ThreadTree => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ThreadTree>>("Message::ThreadTree", pos),
+          Message::MentionData => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MentionData>>("Message::MentionData", pos),
           _ => Ok(()),
         }
      })?
@@ -28356,6 +30712,27 @@ impl core::fmt::Debug for WorkerMessage<'_> {
             ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        Message::LinkPreviewData => {
+          if let Some(x) = self.content_as_link_preview_data() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::ThreadTree => {
+          if let Some(x) = self.content_as_thread_tree() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Message::MentionData => {
+          if let Some(x) = self.content_as_mention_data() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("content", &x)
@@ -1,11 +1,20 @@
 extern crate alloc;
 
+pub mod auth_policy;
 pub mod cache_input;
 pub mod channel;
 pub mod generated;
+pub mod link_preview;
+pub mod lnurl;
+pub mod nip05;
 pub mod platform;
+pub mod outbox;
+pub mod proxy;
+pub mod relay_health;
+pub mod relay_policy;
 pub mod service;
 pub mod spawn;
+pub mod traffic;
 pub mod traits;
 pub mod types;
 pub mod utils;
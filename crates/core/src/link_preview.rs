@@ -0,0 +1,790 @@
+//! OpenGraph link previews for URLs found in parsed content.
+//!
+//! `LinkPreviews::fetch` downloads the head of a page (bounded in size and
+//! time), reads its OpenGraph tags, falling back to Twitter-card tags, the
+//! page `<title>`/description and finally the page's oEmbed endpoint, and
+//! caches the result for `PREVIEW_TTL_MS`. The content parser leaves link
+//! blocks bare; the parser worker owns the engine's `LinkPreviews`, sends
+//! cached previews and fetches the misses for subscriptions opened with
+//! `link_previews`, and persists the cache through the cache worker as an
+//! opaque snapshot (see `CACHE_SUB_ID`).
+//!
+//! Page and oEmbed URLs come from untrusted events, so fetches only go to
+//! public addresses: IP literals are checked on every hop, hostnames are
+//! checked when they resolve, and redirects are followed by hand (at most
+//! `MAX_REDIRECTS`) so each target is checked again.
+
+use crate::generated::nostr::fb;
+use crate::platform;
+use crate::proxy::{self, HttpClients};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// Reserved sub_id carrying the preview cache snapshot between the parser
+/// worker and the cache worker.
+pub const CACHE_SUB_ID: &str = "link_previews";
+
+/// Bytes of a page read at most; metadata lives in `<head>`, so a truncated
+/// body still yields a preview.
+const MAX_BODY_BYTES: usize = 512 * 1024;
+const FETCH_TIMEOUT_MS: u64 = 8_000;
+const MAX_REDIRECTS: usize = 5;
+const PREVIEW_TTL_MS: u64 = 24 * 60 * 60 * 1000;
+const FAILURE_BACKOFF_MS: u64 = 10 * 60 * 1000;
+const MAX_PREVIEWS: usize = 5_000;
+const MAX_TITLE_CHARS: usize = 300;
+const MAX_DESCRIPTION_CHARS: usize = 1_000;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinkPreview {
+    pub url: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+}
+
+impl LinkPreview {
+    /// A preview of `url` with nothing fetched yet.
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the page offered nothing to show besides its URL.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.description.is_none() && self.image.is_none()
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedPreview {
+    fetched_at: u64,
+    preview: LinkPreview,
+}
+
+#[derive(Default)]
+struct PreviewState {
+    /// Page URL -> preview, including pages without any metadata.
+    previews: FxHashMap<String, CachedPreview>,
+    /// Page URL -> retry_after_ms after a failed fetch.
+    failures: FxHashMap<String, u64>,
+    /// Set when a fetched preview has not been persisted yet.
+    dirty: bool,
+}
+
+impl PreviewState {
+    fn insert(&mut self, preview: LinkPreview, fetched_at: u64) {
+        let previews = &mut self.previews;
+        if previews.len() >= MAX_PREVIEWS && !previews.contains_key(&preview.url) {
+            let now_ms = platform::now_millis();
+            previews.retain(|_, entry| now_ms.saturating_sub(entry.fetched_at) < PREVIEW_TTL_MS);
+            if previews.len() >= MAX_PREVIEWS {
+                let oldest = previews
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.fetched_at)
+                    .map(|(url, _)| url.clone());
+                if let Some(oldest) = oldest {
+                    previews.remove(&oldest);
+                }
+            }
+        }
+        previews.insert(
+            preview.url.clone(),
+            CachedPreview {
+                fetched_at,
+                preview,
+            },
+        );
+    }
+}
+
+/// The link preview cache of one engine. Clones share the same cache.
+#[derive(Clone, Default)]
+pub struct LinkPreviews {
+    state: Arc<Mutex<PreviewState>>,
+    /// Lets tests fetch from servers on loopback.
+    allow_private_hosts: bool,
+}
+
+impl LinkPreviews {
+    #[cfg(test)]
+    fn allowing_private_hosts(mut self) -> Self {
+        self.allow_private_hosts = true;
+        self
+    }
+
+    /// The preview for `url` if one was fetched within the TTL.
+    pub fn cached(&self, url: &str) -> Option<LinkPreview> {
+        let now_ms = platform::now_millis();
+        self.state
+            .lock()
+            .unwrap()
+            .previews
+            .get(url)
+            .filter(|entry| now_ms.saturating_sub(entry.fetched_at) < PREVIEW_TTL_MS)
+            .map(|entry| entry.preview.clone())
+    }
+
+    /// The preview for `url`, from the cache or the network. Errors when the
+    /// page cannot be fetched; a page without metadata yields an empty
+    /// preview.
+    pub async fn fetch(&self, http: &HttpClients, url: &str) -> Result<LinkPreview, String> {
+        if let Some(preview) = self.cached(url) {
+            return Ok(preview);
+        }
+        if !is_http_url(url) {
+            return Err(format!("not an http(s) URL: {}", url));
+        }
+
+        if let Some(retry_after_ms) = self.state.lock().unwrap().failures.get(url) {
+            let now_ms = platform::now_millis();
+            if now_ms < *retry_after_ms {
+                return Err(format!(
+                    "link preview recently failed; retrying in {}ms",
+                    retry_after_ms.saturating_sub(now_ms)
+                ));
+            }
+        }
+
+        let result = self.load_preview(http, url).await;
+        let mut state = self.state.lock().unwrap();
+        match result {
+            Ok(preview) => {
+                state.failures.remove(url);
+                state.insert(preview.clone(), platform::now_millis());
+                state.dirty = true;
+                Ok(preview)
+            }
+            Err(e) => {
+                state
+                    .failures
+                    .insert(url.to_string(), platform::now_millis() + FAILURE_BACKOFF_MS);
+                Err(e)
+            }
+        }
+    }
+
+    async fn load_preview(&self, http: &HttpClients, url: &str) -> Result<LinkPreview, String> {
+        let (content_type, body) = self
+            .http_get(http, url, "text/html,application/xhtml+xml")
+            .await?;
+        let content_type = content_type.unwrap_or_default().to_ascii_lowercase();
+        if content_type.starts_with("image/") {
+            return Ok(LinkPreview {
+                url: url.to_string(),
+                image: Some(url.to_string()),
+                ..Default::default()
+            });
+        }
+        if !content_type.is_empty() && !content_type.contains("html") {
+            return Ok(LinkPreview::new(url));
+        }
+
+        let (mut preview, oembed) = parse_html(url, &String::from_utf8_lossy(&body));
+        if preview.title.is_none() || preview.image.is_none() {
+            if let Some(endpoint) = oembed {
+                // oEmbed only fills gaps; a broken endpoint keeps the page preview.
+                if let Ok((_, body)) = self.http_get(http, &endpoint, "application/json").await {
+                    apply_oembed(&mut preview, &endpoint, &body);
+                }
+            }
+        }
+        Ok(preview)
+    }
+
+    /// See `proxy::check_public_host`.
+    fn check_host(&self, url: &str) -> Result<(), String> {
+        if self.allow_private_hosts {
+            return Ok(());
+        }
+        proxy::check_public_host(url)
+    }
+
+    /// Serialized cache (unexpired entries) for persistence.
+    pub fn snapshot(&self) -> Vec<u8> {
+        let now_ms = platform::now_millis();
+        let state = self.state.lock().unwrap();
+        let fresh: Vec<&CachedPreview> = state
+            .previews
+            .values()
+            .filter(|entry| now_ms.saturating_sub(entry.fetched_at) < PREVIEW_TTL_MS)
+            .collect();
+        serde_json::to_vec(&fresh).unwrap_or_default()
+    }
+
+    /// Loads a persisted snapshot. Previews fetched in this session win over
+    /// older persisted ones.
+    pub fn restore(&self, bytes: &[u8]) {
+        let Ok(saved) = serde_json::from_slice::<Vec<CachedPreview>>(bytes) else {
+            tracing::warn!("[LinkPreview] ignoring malformed persisted previews");
+            return;
+        };
+        let now_ms = platform::now_millis();
+        let mut state = self.state.lock().unwrap();
+        for entry in saved {
+            if now_ms.saturating_sub(entry.fetched_at) >= PREVIEW_TTL_MS {
+                continue;
+            }
+            let newer = state
+                .previews
+                .get(&entry.preview.url)
+                .is_some_and(|current| current.fetched_at >= entry.fetched_at);
+            if !newer {
+                state.insert(entry.preview, entry.fetched_at);
+            }
+        }
+    }
+
+    /// Returns whether previews were fetched since the last call.
+    pub fn take_dirty(&self) -> bool {
+        std::mem::take(&mut self.state.lock().unwrap().dirty)
+    }
+}
+
+/// URLs of link blocks without preview data in a serialized ParsedEvent
+/// WorkerMessage, in order of appearance.
+pub fn unresolved_urls(worker_message: &[u8]) -> Vec<String> {
+    let Some(parsed) = flatbuffers::root::<fb::WorkerMessage>(worker_message)
+        .ok()
+        .and_then(|msg| msg.content_as_parsed_event())
+    else {
+        return Vec::new();
+    };
+
+    let mut seen = FxHashSet::default();
+    let mut urls = Vec::new();
+    for block in crate::utils::content_blocks(&parsed) {
+        let Some(link) = block.data_as_link_preview_data() else {
+            continue;
+        };
+        let unresolved =
+            link.title().is_none() && link.description().is_none() && link.image().is_none();
+        if unresolved && is_http_url(link.url()) && seen.insert(link.url()) {
+            urls.push(link.url().to_string());
+        }
+    }
+    urls
+}
+
+pub(crate) fn is_http_url(url: &str) -> bool {
+    let lower = url.get(..8).unwrap_or(url).to_ascii_lowercase();
+    lower.starts_with("https://") || lower.starts_with("http://")
+}
+
+/// Reads the preview fields and the oEmbed endpoint from a page's `<head>`.
+fn parse_html(page_url: &str, html: &str) -> (LinkPreview, Option<String>) {
+    let lower = html.to_ascii_lowercase();
+    let mut meta: FxHashMap<String, String> = FxHashMap::default();
+    let mut title_tag = None;
+    let mut oembed = None;
+
+    let mut pos = 0;
+    while let Some(found) = lower[pos..].find('<') {
+        let start = pos + found;
+        let rest = &lower[start + 1..];
+        if rest.starts_with("!--") {
+            pos = match rest.find("-->") {
+                Some(end) => start + 1 + end + 3,
+                None => break,
+            };
+            continue;
+        }
+        let Some(end) = tag_end(html, start) else {
+            break;
+        };
+        let name_len = rest
+            .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+            .unwrap_or(rest.len());
+        let name = &rest[..name_len];
+        let attrs_src = &html[start + 1 + name_len..end];
+        pos = end + 1;
+
+        match name {
+            "meta" => {
+                let attrs = parse_attrs(attrs_src);
+                let key = attr(&attrs, "property").or_else(|| attr(&attrs, "name"));
+                if let (Some(key), Some(content)) = (key, attr(&attrs, "content")) {
+                    meta.entry(key.to_ascii_lowercase())
+                        .or_insert_with(|| content.to_string());
+                }
+            }
+            "link" if oembed.is_none() => {
+                let attrs = parse_attrs(attrs_src);
+                let is_oembed = attr(&attrs, "type")
+                    .is_some_and(|t| t.eq_ignore_ascii_case("application/json+oembed"));
+                if is_oembed {
+                    oembed = attr(&attrs, "href").and_then(|href| resolve_url(page_url, href));
+                }
+            }
+            "title" if title_tag.is_none() => {
+                let close = lower[pos..]
+                    .find("</title")
+                    .map_or(lower.len(), |i| pos + i);
+                title_tag = Some(decode_entities(&html[pos..close]));
+                pos = close;
+            }
+            "script" | "style" => {
+                let closing = format!("</{}", name);
+                pos = lower[pos..].find(&closing).map_or(lower.len(), |i| pos + i);
+            }
+            "/head" | "body" => break,
+            _ => {}
+        }
+    }
+
+    let pick = |keys: &[&str]| {
+        keys.iter()
+            .find_map(|key| meta.get(*key).map(String::as_str))
+    };
+    let preview = LinkPreview {
+        url: page_url.to_string(),
+        title: clean(
+            pick(&["og:title", "twitter:title"]).or(title_tag.as_deref()),
+            MAX_TITLE_CHARS,
+        ),
+        description: clean(
+            pick(&["og:description", "twitter:description", "description"]),
+            MAX_DESCRIPTION_CHARS,
+        ),
+        image: pick(&[
+            "og:image",
+            "og:image:url",
+            "og:image:secure_url",
+            "twitter:image",
+            "twitter:image:src",
+        ])
+        .and_then(|src| resolve_url(page_url, src)),
+    };
+    (preview, oembed)
+}
+
+/// Fills the preview's missing title and image from an oEmbed response.
+fn apply_oembed(preview: &mut LinkPreview, endpoint: &str, body: &[u8]) {
+    let Ok(json) = serde_json::from_slice::<serde_json::Value>(body) else {
+        return;
+    };
+    if preview.title.is_none() {
+        preview.title = clean(json.get("title").and_then(|v| v.as_str()), MAX_TITLE_CHARS);
+    }
+    if preview.image.is_none() {
+        preview.image = json
+            .get("thumbnail_url")
+            .and_then(|v| v.as_str())
+            .and_then(|src| resolve_url(endpoint, src));
+    }
+}
+
+/// Index of the `>` closing the tag opened at `start`, skipping quoted values.
+fn tag_end(html: &str, start: usize) -> Option<usize> {
+    let mut quote = None;
+    for (i, b) in html.bytes().enumerate().skip(start + 1) {
+        match (quote, b) {
+            (Some(q), _) if b == q => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(b),
+            (None, b'>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Attributes of a tag body as (lowercased name, entity-decoded value).
+fn parse_attrs(src: &str) -> Vec<(String, String)> {
+    let bytes = src.as_bytes();
+    let mut attrs = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_whitespace() || bytes[i] == b'/' {
+            i += 1;
+            continue;
+        }
+        let name_start = i;
+        while i < bytes.len() && !bytes[i].is_ascii_whitespace() && !matches!(bytes[i], b'=' | b'/')
+        {
+            i += 1;
+        }
+        if i == name_start {
+            i += 1;
+            continue;
+        }
+        let name = src[name_start..i].to_ascii_lowercase();
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let mut value = "";
+        if i < bytes.len() && bytes[i] == b'=' {
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            if i < bytes.len() && matches!(bytes[i], b'"' | b'\'') {
+                let quote = bytes[i];
+                let value_start = i + 1;
+                i = value_start;
+                while i < bytes.len() && bytes[i] != quote {
+                    i += 1;
+                }
+                value = &src[value_start..i];
+                i += 1;
+            } else {
+                let value_start = i;
+                while i < bytes.len() && !bytes[i].is_ascii_whitespace() {
+                    i += 1;
+                }
+                value = &src[value_start..i];
+            }
+        }
+        attrs.push((name, decode_entities(value)));
+    }
+    attrs
+}
+
+fn attr<'a>(attrs: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attrs
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Decodes the character references that show up in titles and descriptions.
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|semi| *semi <= 10)
+            .and_then(|semi| {
+                let entity = &rest[1..1 + semi];
+                let c = match entity {
+                    "amp" => Some('&'),
+                    "lt" => Some('<'),
+                    "gt" => Some('>'),
+                    "quot" => Some('"'),
+                    "apos" => Some('\''),
+                    "nbsp" => Some(' '),
+                    _ => {
+                        let code = match entity.strip_prefix('#') {
+                            Some(hex) if hex.starts_with(['x', 'X']) => {
+                                u32::from_str_radix(&hex[1..], 16).ok()
+                            }
+                            Some(dec) => dec.parse().ok(),
+                            None => None,
+                        };
+                        code.and_then(char::from_u32)
+                    }
+                };
+                c.map(|c| (c, semi + 2))
+            });
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Collapses whitespace and caps the length; `None` when nothing is left.
+fn clean(text: Option<&str>, max_chars: usize) -> Option<String> {
+    let text = text?.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    Some(match text.char_indices().nth(max_chars) {
+        Some((cut, _)) => format!("{}…", text[..cut].trim_end()),
+        None => text,
+    })
+}
+
+/// Resolves an absolute, scheme-relative, root-relative or relative `href`
+/// against `base`.
+pub(crate) fn resolve_url(base: &str, href: &str) -> Option<String> {
+    let href = href.trim();
+    if href.is_empty() {
+        return None;
+    }
+    if is_http_url(href) {
+        return Some(href.to_string());
+    }
+    let (scheme, rest) = base.split_once("://")?;
+    if let Some(authority) = href.strip_prefix("//") {
+        return Some(format!("{}://{}", scheme, authority));
+    }
+    if href.contains(':') && !href.starts_with('/') && !href.starts_with('.') {
+        // Another scheme (data:, javascript:, ...).
+        return None;
+    }
+    let host_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let origin = &base[..scheme.len() + 3 + host_end];
+    if href.starts_with('/') {
+        return Some(format!("{}{}", origin, href));
+    }
+    let path = rest[host_end..].split(['?', '#']).next().unwrap_or("");
+    let dir = path.rfind('/').map_or("/", |i| &path[..=i]);
+    Some(format!("{}{}{}", origin, dir, href))
+}
+
+#[cfg(target_arch = "wasm32")]
+impl LinkPreviews {
+    /// GETs `url` and returns its content type and at most `MAX_BODY_BYTES`
+    /// of the body. Redirects are refused: the browser cannot hand them back
+    /// for checking.
+    async fn http_get(
+        &self,
+        _http: &HttpClients,
+        url: &str,
+        accept: &str,
+    ) -> Result<(Option<String>, Vec<u8>), String> {
+        use futures::future::{select, Either};
+
+        self.check_host(url)?;
+        let request = Box::pin(async {
+            let response = gloo_net::http::Request::get(url)
+                .header("Accept", accept)
+                .redirect(web_sys::RequestRedirect::Error)
+                .send()
+                .await
+                .map_err(|e| format!("HTTP request failed: {:?}", e))?;
+            if !response.ok() {
+                return Err(format!("{} returned status: {}", url, response.status()));
+            }
+            let content_type = response.headers().get("content-type");
+            // fetch() cannot stop a download midway; the body is capped after
+            // the fact and the timeout still bounds the transfer.
+            let mut body = response
+                .binary()
+                .await
+                .map_err(|e| format!("Failed to read response: {:?}", e))?;
+            body.truncate(MAX_BODY_BYTES);
+            Ok((content_type, body))
+        });
+        let timeout = Box::pin(platform::sleep(FETCH_TIMEOUT_MS));
+        match select(request, timeout).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(format!("{} timed out", url)),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl LinkPreviews {
+    /// GETs `url` and returns its content type and at most `MAX_BODY_BYTES`
+    /// of the body, following up to `MAX_REDIRECTS` redirects after checking
+    /// each target.
+    async fn http_get(
+        &self,
+        http: &HttpClients,
+        url: &str,
+        accept: &str,
+    ) -> Result<(Option<String>, Vec<u8>), String> {
+        let mut url = url.to_string();
+        for _ in 0..=MAX_REDIRECTS {
+            self.check_host(&url)?;
+            let client = if self.allow_private_hosts {
+                http.client_without_redirects(&url)?
+            } else {
+                http.public_client(&url)?
+            };
+            let mut response = client
+                .get(&url)
+                .header(reqwest::header::ACCEPT, accept)
+                .timeout(std::time::Duration::from_millis(FETCH_TIMEOUT_MS))
+                .send()
+                .await
+                .map_err(|e| format!("HTTP request failed: {}", e))?;
+            if response.status().is_redirection() {
+                let location = response
+                    .headers()
+                    .get(reqwest::header::LOCATION)
+                    .and_then(|v| v.to_str().ok())
+                    .ok_or_else(|| format!("{} redirected without a location", url))?;
+                url = resolve_url(&url, location)
+                    .filter(|next| is_http_url(next))
+                    .ok_or_else(|| format!("{} redirected to {}", url, location))?;
+                continue;
+            }
+            if !response.status().is_success() {
+                return Err(format!("{} returned status: {}", url, response.status()));
+            }
+            let content_type = response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let mut body = Vec::new();
+            while let Some(chunk) = response
+                .chunk()
+                .await
+                .map_err(|e| format!("Failed to read response: {}", e))?
+            {
+                let room = MAX_BODY_BYTES - body.len();
+                body.extend_from_slice(&chunk[..chunk.len().min(room)]);
+                if body.len() >= MAX_BODY_BYTES {
+                    break;
+                }
+            }
+            return Ok((content_type, body));
+        }
+        Err(format!("{} redirected too many times", url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_open_graph_before_twitter_and_title() {
+        let html = r#"<html><head>
+            <title>Page &amp; title</title>
+            <meta name="twitter:title" content="Twitter title">
+            <meta property="og:title" content="OG &quot;title&quot;" />
+            <meta name="description" content="Plain description">
+            <meta name="twitter:image" content="/img/card.png">
+            </head><body><meta property="og:description" content="ignored"></body></html>"#;
+        let (preview, oembed) = parse_html("https://example.com/a/b", html);
+        assert_eq!(preview.title.as_deref(), Some("OG \"title\""));
+        assert_eq!(preview.description.as_deref(), Some("Plain description"));
+        assert_eq!(
+            preview.image.as_deref(),
+            Some("https://example.com/img/card.png")
+        );
+        assert_eq!(oembed, None);
+    }
+
+    #[test]
+    fn falls_back_to_title_and_finds_oembed() {
+        let html = r#"<head><script>var s = "<meta property='og:title' content='no'>";</script>
+            <title> Only   a title </title>
+            <link rel="alternate" type="application/json+oembed" href="oembed?url=x"></head>"#;
+        let (preview, oembed) = parse_html("https://example.com/post/1", html);
+        assert_eq!(preview.title.as_deref(), Some("Only a title"));
+        assert!(preview.image.is_none());
+        assert_eq!(
+            oembed.as_deref(),
+            Some("https://example.com/post/oembed?url=x")
+        );
+
+        let mut preview = preview;
+        apply_oembed(
+            &mut preview,
+            "https://example.com/oembed",
+            br#"{"title":"oEmbed title","thumbnail_url":"https://cdn.example.com/t.jpg"}"#,
+        );
+        assert_eq!(preview.title.as_deref(), Some("Only a title"));
+        assert_eq!(
+            preview.image.as_deref(),
+            Some("https://cdn.example.com/t.jpg")
+        );
+    }
+
+    #[test]
+    fn snapshot_round_trips() {
+        let url = "https://snapshot.example.com/";
+        let previews = LinkPreviews::default();
+        previews.state.lock().unwrap().insert(
+            LinkPreview {
+                url: url.to_string(),
+                title: Some("Snapshot".to_string()),
+                ..Default::default()
+            },
+            platform::now_millis(),
+        );
+        let bytes = previews.snapshot();
+
+        let restored = LinkPreviews::default();
+        assert!(restored.cached(url).is_none());
+        restored.restore(&bytes);
+        assert_eq!(restored.cached(url).unwrap().title.as_deref(), Some("Snapshot"));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn follows_redirects_and_caches() {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let padding = "x".repeat(MAX_BODY_BYTES * 2);
+        let page = format!(
+            "<html><head><meta property=\"og:title\" content=\"Served\">\
+             <meta property=\"og:image\" content=\"/cover.jpg\"></head><body>{}</body></html>",
+            padding
+        );
+        let server = std::thread::spawn(move || {
+            // A redirect, then the page; the second fetch must come from the cache.
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            let _ = stream.write_all(
+                b"HTTP/1.1 302 Found\r\nLocation: /article\r\n\
+                  Content-Length: 0\r\nConnection: close\r\n\r\n",
+            );
+            drop(stream);
+
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.read(&mut buf);
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                page.len()
+            );
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(page.as_bytes());
+        });
+
+        let previews = LinkPreviews::default().allowing_private_hosts();
+        let http = HttpClients::default();
+        let url = format!("http://{}/short", addr);
+        let preview = previews.fetch(&http, &url).await.unwrap();
+        assert_eq!(preview.title.as_deref(), Some("Served"));
+        assert_eq!(
+            preview.image.as_deref(),
+            Some(format!("http://{}/cover.jpg", addr).as_str())
+        );
+        server.join().unwrap();
+
+        assert_eq!(previews.fetch(&http, &url).await.unwrap(), preview);
+        assert!(previews.take_dirty());
+        assert!(!previews.take_dirty());
+    }
+
+    #[tokio::test]
+    async fn refuses_private_hosts() {
+        let previews = LinkPreviews::default();
+        let http = HttpClients::default();
+        for url in [
+            "http://127.0.0.1:8080/",
+            "http://169.254.169.254/latest/meta-data/",
+            "http://[::1]/",
+            "http://10.0.0.1/admin",
+            "http://localhost./",
+        ] {
+            let err = previews.fetch(&http, url).await.unwrap_err();
+            assert!(err.contains("not a public host"), "{}: {}", url, err);
+        }
+        assert!(previews.check_host("https://example.com/a").is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_non_http_urls() {
+        let previews = LinkPreviews::default();
+        assert!(previews
+            .fetch(&HttpClients::default(), "ftp://example.com/file")
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,473 @@
+//! LNURL-pay provider lookups for NIP-57 zap validation.
+//!
+//! A zap receipt is only genuine when it is signed by the `nostrPubkey`
+//! advertised by the recipient's LNURL-pay endpoint. The endpoint comes
+//! from the recipient's kind 0 `lud16` (lightning address) or `lud06`
+//! (bech32 LNURL); the parser records those in its `ZapProviders` as
+//! profiles go by, and `ZapProviders::lookup` resolves the endpoint's key
+//! in the background. Only resolved keys are cached; failed lookups and
+//! endpoints without zap support are retried after
+//! `PROVIDER_FAILURE_BACKOFF_MS`.
+//!
+//! Addresses come from untrusted profiles, so endpoints on `localhost`,
+//! non-public IP addresses or ports are refused, every redirect target is
+//! checked again, and each fetch is bounded by `FETCH_TIMEOUT_MS` and
+//! `MAX_BODY_BYTES`.
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::link_preview::{is_http_url, resolve_url};
+use crate::nip05::is_public_domain;
+use crate::platform;
+use crate::proxy::{self, HttpClients};
+use crate::spawn::spawn_worker;
+use crate::types::nostr::nips::nip19;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::sync::{Arc, Mutex};
+use tracing::debug;
+
+const PROVIDER_FAILURE_BACKOFF_MS: u64 = 60_000;
+const MAX_ADDRESSES: usize = 50_000;
+const MAX_PROVIDERS: usize = 10_000;
+const FETCH_TIMEOUT_MS: u64 = 8_000;
+const MAX_BODY_BYTES: usize = 64 * 1024;
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_REDIRECTS: usize = 5;
+
+/// Where a provider lookup stands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProviderLookup {
+    /// The provider's `nostrPubkey`.
+    Known(String),
+    /// Not resolved yet: the lookup is in flight or backing off after a
+    /// network error.
+    Pending,
+    /// The address is malformed or its endpoint does not support zaps.
+    Unsupported(String),
+}
+
+#[derive(Default)]
+struct ProviderState {
+    /// Lightning address (lud16, else lud06) per profile pubkey, newest profile wins.
+    addresses: FxHashMap<String, (u64, String)>,
+    /// Resolved provider `nostrPubkey` per pay endpoint URL.
+    providers: FxHashMap<String, String>,
+    /// Pay endpoint URL -> (retry_after_ms, reason when the endpoint answered
+    /// without zap support) after a failed lookup.
+    failures: FxHashMap<String, (u64, Option<String>)>,
+    /// Pay endpoint URLs with a lookup in flight.
+    in_flight: FxHashSet<String>,
+}
+
+impl ProviderState {
+    /// Records the outcome of fetching the pay endpoint at `url`.
+    fn record(&mut self, url: String, result: Result<String, String>) -> ProviderLookup {
+        let retry_after_ms = platform::now_millis() + PROVIDER_FAILURE_BACKOFF_MS;
+        match result.map(|body| parse_pay_response(&body)) {
+            Ok(Some(provider)) => {
+                self.failures.remove(&url);
+                if self.providers.len() >= MAX_PROVIDERS {
+                    self.providers.clear();
+                }
+                self.providers.insert(url, provider.clone());
+                ProviderLookup::Known(provider)
+            }
+            Ok(None) => {
+                let reason = format!("{} does not support zaps", url);
+                self.failures
+                    .insert(url, (retry_after_ms, Some(reason.clone())));
+                ProviderLookup::Unsupported(reason)
+            }
+            Err(e) => {
+                debug!("LNURL lookup for {} failed: {}", url, e);
+                self.failures.insert(url, (retry_after_ms, None));
+                ProviderLookup::Pending
+            }
+        }
+    }
+}
+
+/// Lightning addresses and LNURL providers known to one engine. Clones
+/// share the same state.
+#[derive(Clone, Default)]
+pub struct ZapProviders {
+    state: Arc<Mutex<ProviderState>>,
+    /// Lets tests fetch from servers on loopback.
+    allow_private_hosts: bool,
+}
+
+impl ZapProviders {
+    #[cfg(test)]
+    fn allowing_private_hosts(mut self) -> Self {
+        self.allow_private_hosts = true;
+        self
+    }
+
+    /// Records the lightning address from a kind 0 profile created at `created_at`.
+    pub fn remember_address(&self, pubkey: &str, created_at: u64, address: Option<&str>) {
+        let Some(address) = address.map(str::trim).filter(|a| !a.is_empty()) else {
+            return;
+        };
+        let addresses = &mut self.state.lock().unwrap().addresses;
+        if let Some((seen_at, _)) = addresses.get(pubkey) {
+            if *seen_at > created_at {
+                return;
+            }
+        } else if addresses.len() >= MAX_ADDRESSES {
+            addresses.clear();
+        }
+        addresses.insert(pubkey.to_string(), (created_at, address.to_string()));
+    }
+
+    /// The lightning address last recorded for `pubkey`.
+    pub fn address_for(&self, pubkey: &str) -> Option<String> {
+        self.state
+            .lock()
+            .unwrap()
+            .addresses
+            .get(pubkey)
+            .map(|(_, address)| address.clone())
+    }
+
+    /// The provider behind `address`, from cache only. A miss starts a
+    /// background lookup through `http` and reports `Pending`; ask again
+    /// once it has finished.
+    pub fn lookup(&self, http: &HttpClients, address: &str) -> ProviderLookup {
+        let Some(url) = pay_endpoint(address) else {
+            return ProviderLookup::Unsupported(format!("invalid lightning address {}", address));
+        };
+        {
+            let mut state = self.state.lock().unwrap();
+            if let Some(provider) = state.providers.get(&url) {
+                return ProviderLookup::Known(provider.clone());
+            }
+            if let Some((retry_after_ms, reason)) = state.failures.get(&url) {
+                if platform::now_millis() < *retry_after_ms {
+                    return reason
+                        .clone()
+                        .map_or(ProviderLookup::Pending, ProviderLookup::Unsupported);
+                }
+            }
+            if !state.in_flight.insert(url.clone()) {
+                return ProviderLookup::Pending;
+            }
+        }
+
+        let providers = self.clone();
+        let http = http.clone();
+        spawn_worker(async move {
+            providers.resolve(&http, url).await;
+        });
+        ProviderLookup::Pending
+    }
+
+    /// Caches `provider` as the key behind `address`, as a lookup would.
+    #[cfg(test)]
+    pub(crate) fn remember_provider(&self, address: &str, provider: &str) {
+        let url = pay_endpoint(address).unwrap();
+        let mut state = self.state.lock().unwrap();
+        state.providers.insert(url, provider.to_string());
+    }
+
+    /// Fetches the pay endpoint at `url` and records the outcome.
+    async fn resolve(&self, http: &HttpClients, url: String) {
+        let result = fetch(http, &url, self.allow_private_hosts, FETCH_TIMEOUT_MS).await;
+        let mut state = self.state.lock().unwrap();
+        state.in_flight.remove(&url);
+        state.record(url, result);
+    }
+}
+
+/// LNURL-pay endpoint for a lud16 `name@domain` or a bech32 lud06 `lnurl1...`.
+/// Only https endpoints (http for `.onion`) on public hosts qualify.
+pub fn pay_endpoint(address: &str) -> Option<String> {
+    let address = address.trim();
+    if let Some((name, domain)) = address.rsplit_once('@') {
+        let domain = domain.to_ascii_lowercase();
+        if name.is_empty() || !is_public_domain(&domain) {
+            return None;
+        }
+        let scheme = if domain.ends_with(".onion") {
+            "http"
+        } else {
+            "https"
+        };
+        return Some(format!(
+            "{}://{}/.well-known/lnurlp/{}",
+            scheme,
+            domain,
+            percent_encode(&name.to_ascii_lowercase())
+        ));
+    }
+
+    let (hrp, bytes) = nip19::decode(address).ok()?;
+    if hrp != "lnurl" {
+        return None;
+    }
+    let url = String::from_utf8(bytes).ok()?;
+    let onion = proxy::host_of(&url).is_some_and(|host| host.ends_with(".onion"));
+    let scheme_ok = url.starts_with("https://") || (onion && url.starts_with("http://"));
+    (scheme_ok && proxy::check_public_host(&url).is_ok()).then_some(url)
+}
+
+/// `segment` with everything but RFC 3986 unreserved characters
+/// percent-encoded.
+fn percent_encode(segment: &str) -> String {
+    let mut out = String::with_capacity(segment.len());
+    for b in segment.bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{:02X}", b));
+        }
+    }
+    out
+}
+
+/// Extracts `nostrPubkey` from an LNURL-pay response that sets `allowsNostr`.
+fn parse_pay_response(body: &str) -> Option<String> {
+    let json: serde_json::Value = serde_json::from_str(body).ok()?;
+    if json.get("allowsNostr").and_then(|v| v.as_bool()) != Some(true) {
+        return None;
+    }
+    json.get("nostrPubkey")
+        .and_then(|v| v.as_str())
+        .filter(|key| key.len() == 64 && key.bytes().all(|b| b.is_ascii_hexdigit()))
+        .map(|key| key.to_ascii_lowercase())
+}
+
+/// GETs the pay endpoint at `url`. Redirects are refused: the browser
+/// cannot hand them back for checking.
+#[cfg(target_arch = "wasm32")]
+async fn fetch(
+    _http: &HttpClients,
+    url: &str,
+    allow_private_hosts: bool,
+    timeout_ms: u64,
+) -> Result<String, String> {
+    use futures::future::{select, Either};
+
+    if !allow_private_hosts {
+        proxy::check_public_host(url)?;
+    }
+    let request = Box::pin(async {
+        let response = gloo_net::http::Request::get(url)
+            .header("Accept", "application/json")
+            .redirect(web_sys::RequestRedirect::Error)
+            .send()
+            .await
+            .map_err(|e| format!("HTTP request failed: {:?}", e))?;
+        if !response.ok() {
+            return Err(format!(
+                "LNURL endpoint returned status: {}",
+                response.status()
+            ));
+        }
+        let body = response
+            .binary()
+            .await
+            .map_err(|e| format!("Failed to read response: {:?}", e))?;
+        if body.len() > MAX_BODY_BYTES {
+            return Err(format!("LNURL response from {} is too large", url));
+        }
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    });
+    let timeout = Box::pin(platform::sleep(timeout_ms));
+    match select(request, timeout).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(format!("{} timed out", url)),
+    }
+}
+
+/// GETs the pay endpoint at `url`, following up to `MAX_REDIRECTS`
+/// redirects after checking each target.
+#[cfg(not(target_arch = "wasm32"))]
+async fn fetch(
+    http: &HttpClients,
+    url: &str,
+    allow_private_hosts: bool,
+    timeout_ms: u64,
+) -> Result<String, String> {
+    let mut url = url.to_string();
+    for _ in 0..=MAX_REDIRECTS {
+        let client = if allow_private_hosts {
+            http.client_without_redirects(&url)?
+        } else {
+            proxy::check_public_host(&url)?;
+            http.public_client(&url)?
+        };
+        let mut response = client
+            .get(&url)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .timeout(std::time::Duration::from_millis(timeout_ms))
+            .send()
+            .await
+            .map_err(|e| format!("HTTP request failed: {}", e))?;
+        if response.status().is_redirection() {
+            let location = response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .ok_or_else(|| format!("{} redirected without a location", url))?;
+            url = resolve_url(&url, location)
+                .filter(|next| is_http_url(next))
+                .ok_or_else(|| format!("{} redirected to {}", url, location))?;
+            continue;
+        }
+        if !response.status().is_success() {
+            return Err(format!(
+                "LNURL endpoint returned status: {}",
+                response.status()
+            ));
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| format!("Failed to read response: {}", e))?
+        {
+            if body.len() + chunk.len() > MAX_BODY_BYTES {
+                return Err(format!("LNURL response from {} is too large", url));
+            }
+            body.extend_from_slice(&chunk);
+        }
+        return Ok(String::from_utf8_lossy(&body).into_owned());
+    }
+    Err(format!("{} redirected too many times", url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_pay_endpoints() {
+        assert_eq!(
+            pay_endpoint("Alice@Wallet.Example").as_deref(),
+            Some("https://wallet.example/.well-known/lnurlp/alice")
+        );
+        let lud06 = nip19::encode("lnurl", b"https://wallet.example/lnurlp/bob")
+            .unwrap()
+            .to_uppercase();
+        assert_eq!(
+            pay_endpoint(&lud06).as_deref(),
+            Some("https://wallet.example/lnurlp/bob")
+        );
+        assert!(pay_endpoint("not an address").is_none());
+        assert_eq!(
+            pay_endpoint("a/b?c@wallet.example").as_deref(),
+            Some("https://wallet.example/.well-known/lnurlp/a%2Fb%3Fc")
+        );
+    }
+
+    #[test]
+    fn refuses_private_and_odd_hosts() {
+        for address in [
+            "alice@127.0.0.1",
+            "alice@localhost",
+            "alice@wallet.localhost",
+            "alice@[::1]",
+            "alice@wallet.example:8080",
+            "alice@wallet.example/admin",
+            "alice@wallet.example?x=1",
+        ] {
+            assert!(pay_endpoint(address).is_none(), "{}", address);
+        }
+        for url in [
+            "http://wallet.example/lnurlp/bob",
+            "https://10.0.0.1/lnurlp/bob",
+            "https://localhost/lnurlp/bob",
+        ] {
+            let lud06 = nip19::encode("lnurl", url.as_bytes()).unwrap();
+            assert!(pay_endpoint(&lud06).is_none(), "{}", url);
+        }
+        let onion = nip19::encode("lnurl", b"http://wallet.onion/lnurlp/bob").unwrap();
+        assert!(pay_endpoint(&onion).is_some());
+        assert!(matches!(
+            ZapProviders::default().lookup(&HttpClients::default(), "alice@127.0.0.1"),
+            ProviderLookup::Unsupported(_)
+        ));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn fetch_refuses_private_hosts() {
+        let err = fetch(
+            &HttpClients::default(),
+            "https://127.0.0.1/.well-known/lnurlp/alice",
+            false,
+            FETCH_TIMEOUT_MS,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.contains("not a public host"), "{}", err);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn lookups_give_up_on_hanging_servers() {
+        use std::io::Read;
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/.well-known/lnurlp/alice", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            // Reads the request and never answers.
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            std::thread::sleep(std::time::Duration::from_millis(1_000));
+        });
+
+        let providers = ZapProviders::default().allowing_private_hosts();
+        let err = fetch(&HttpClients::default(), &url, providers.allow_private_hosts, 200)
+            .await
+            .unwrap_err();
+        assert!(err.contains("HTTP request failed"), "{}", err);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn requires_allows_nostr() {
+        let key = "9630f464cca6a5147aa8a35f0bcdd3ce485324e732fd39e09233b1d848238f31";
+        let body = format!(r#"{{"allowsNostr":true,"nostrPubkey":"{}"}}"#, key);
+        assert_eq!(parse_pay_response(&body).as_deref(), Some(key));
+        let body = format!(r#"{{"allowsNostr":false,"nostrPubkey":"{}"}}"#, key);
+        assert_eq!(parse_pay_response(&body), None);
+    }
+
+    #[test]
+    fn newest_profile_wins() {
+        let providers = ZapProviders::default();
+        let pubkey = "lnurl-test-pubkey";
+        providers.remember_address(pubkey, 20, Some("new@wallet.example"));
+        providers.remember_address(pubkey, 10, Some("old@wallet.example"));
+        assert_eq!(
+            providers.address_for(pubkey).as_deref(),
+            Some("new@wallet.example")
+        );
+    }
+
+    #[test]
+    fn failed_lookups_are_not_cached() {
+        let providers = ZapProviders::default();
+        let url = pay_endpoint("alice@wallet.example").unwrap();
+        let mut state = providers.state.lock().unwrap();
+
+        let lookup = state.record(url.clone(), Err("timed out".to_string()));
+        assert_eq!(lookup, ProviderLookup::Pending);
+        let body = r#"{"allowsNostr":false}"#.to_string();
+        let lookup = state.record(url.clone(), Ok(body));
+        assert!(matches!(lookup, ProviderLookup::Unsupported(_)));
+        assert!(state.providers.is_empty());
+
+        // Once the backoff has passed the endpoint is asked again; here the
+        // lookup joins the one already in flight
+        state.failures.get_mut(&url).unwrap().0 = 0;
+        state.in_flight.insert(url.clone());
+        drop(state);
+        assert_eq!(
+            providers.lookup(&HttpClients::default(), "alice@wallet.example"),
+            ProviderLookup::Pending
+        );
+    }
+}
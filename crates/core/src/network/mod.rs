@@ -145,7 +145,7 @@ impl NetworkManager {
         template: &Template,
         _relays: &Vec<String>,
         _optimistic_subids: Vec<String>,
-    ) -> NostrResult<Event> {
+    ) -> NostrResult<Vec<Event>> {
         info!("[NetworkManager] Publishing event {}", publish_id);
         self.inner
             .publish_manager
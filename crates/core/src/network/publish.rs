@@ -17,16 +17,22 @@ impl PublishManager {
         Self { parser }
     }
 
-    pub async fn publish_event(&self, publish_id: String, template: &Template) -> Result<Event> {
+    /// Signs the template into the events to send: usually one, one gift
+    /// wrap per recipient for NIP-17 messages.
+    pub async fn publish_event(
+        &self,
+        publish_id: String,
+        template: &Template,
+    ) -> Result<Vec<Event>> {
         info!("[publish_manager] Publishing event with ID {}", publish_id);
 
-        // Prepare the event using parser
-        let event = match self.parser.prepare(template).await {
-            Ok(parsed) => parsed,
+        // Prepare the events using parser
+        let events = match self.parser.prepare_all(template).await {
+            Ok(events) => events,
             Err(e) => return Err(NostrError::Other(format!("failed to prepare event: {}", e))),
         };
 
-        Ok(event)
+        Ok(events)
     }
 
     // async fn determine_target_relays(&self, event: &Event) -> Result<Vec<String>> {
@@ -196,7 +196,10 @@ impl SubscriptionManager {
                             // max_proofs: usize, check_interval_secs: u64
                             let max_proofs = config.max_proofs() as usize;
 
-                            PipeType::ProofVerification(ProofVerificationPipe::new(max_proofs))
+                            PipeType::ProofVerification(
+                                ProofVerificationPipe::new(max_proofs)
+                                    .with_http(self.parser.http()),
+                            )
                         }
                         fb::PipeConfig::CounterPipeConfig => {
                             let config = pipe_config.config_as_counter_pipe_config().unwrap();
@@ -268,6 +271,15 @@ impl SubscriptionManager {
                                 pubkeys, hashtags, words, event_ids, target,
                             )))
                         }
+                        fb::PipeConfig::ZapValidationPipeConfig => {
+                            let config =
+                                pipe_config.config_as_zap_validation_pipe_config().unwrap();
+                            PipeType::ZapValidation(
+                                ZapValidationPipe::new(config.drop_invalid())
+                                    .with_http(self.parser.http())
+                                    .with_providers(self.parser.zap_providers()),
+                            )
+                        }
                         _ => {
                             return Err(NostrError::Other(format!(
                                 "Unknown pipe config type: {:?}",
@@ -0,0 +1,835 @@
+//! NIP-05 identifier lookups and profile verification.
+//!
+//! `name@domain` resolves through `https://domain/.well-known/nostr.json?name=name`
+//! to a pubkey and, optionally, the relays the domain lists for it. A kind 0
+//! profile's `nip05` is verified when its identifier resolves to the
+//! profile's own pubkey. Results are cached per identifier for
+//! `RESULT_TTL_MS` (`MISS_TTL_MS` when the domain does not list the name) in
+//! the engine's `Nip05Lookups`, and persisted through the cache worker as an
+//! opaque snapshot (see `CACHE_SUB_ID`), like link previews.
+//!
+//! Lookups never follow redirects, as NIP-05 requires, and requests to one
+//! domain are spaced `DOMAIN_INTERVAL_MS` apart; a 429 pauses the domain for
+//! its `Retry-After`. Listed relays double as outbox hints for authors
+//! without a kind 10002 list (`relays_for`), but only once a kind 0 signed
+//! by the listed pubkey named the identifier: a domain can list any pubkey.
+//! Domains come from untrusted profiles, so names resolving to non-public
+//! addresses are refused (`HttpClients::public_client`).
+
+use crate::generated::nostr::fb;
+use crate::platform;
+use crate::proxy::HttpClients;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// Reserved sub_id carrying the lookup cache snapshot between the parser
+/// worker and the cache worker.
+pub const CACHE_SUB_ID: &str = "nip05";
+
+const RESULT_TTL_MS: u64 = 24 * 60 * 60 * 1000;
+const MISS_TTL_MS: u64 = 60 * 60 * 1000;
+const FAILURE_BACKOFF_MS: u64 = 5 * 60 * 1000;
+const DOMAIN_INTERVAL_MS: u64 = 1_000;
+const DEFAULT_RETRY_AFTER_MS: u64 = 60_000;
+const MAX_RETRY_AFTER_MS: u64 = 60 * 60 * 1000;
+const FETCH_TIMEOUT_MS: u64 = 10_000;
+/// Bytes of `nostr.json` read at most; domains that ignore `?name=` may
+/// serve every user.
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+const MAX_RESULTS: usize = 20_000;
+const MAX_RELAYS: usize = 8;
+
+/// Body of `/.well-known/nostr.json`.
+#[derive(Debug, Default, Deserialize)]
+pub struct Nip05Response {
+    #[serde(default)]
+    pub names: FxHashMap<String, String>,
+    #[serde(default)]
+    pub relays: Option<FxHashMap<String, Vec<String>>>,
+}
+
+/// What a NIP-05 identifier resolves to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Nip05Profile {
+    pub pubkey: String,
+    pub relays: Vec<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedLookup {
+    identifier: String,
+    checked_at: u64,
+    /// None when the domain does not list the name.
+    profile: Option<Nip05Profile>,
+    /// A kind 0 signed by the listed pubkey names this identifier.
+    #[serde(default)]
+    verified: bool,
+}
+
+impl CachedLookup {
+    fn is_fresh(&self, now_ms: u64) -> bool {
+        let ttl = if self.profile.is_some() {
+            RESULT_TTL_MS
+        } else {
+            MISS_TTL_MS
+        };
+        now_ms.saturating_sub(self.checked_at) < ttl
+    }
+}
+
+#[derive(Default)]
+struct DomainSlots {
+    /// Earliest time the next request to the domain may start.
+    next_request_at: u64,
+    /// Set by a 429; requests fail fast until then.
+    paused_until: u64,
+}
+
+#[derive(Default)]
+struct LookupState {
+    /// Normalized identifier -> last lookup.
+    results: FxHashMap<String, CachedLookup>,
+    /// Pubkey -> verified identifier that resolved to it, for `relays_for`.
+    by_pubkey: FxHashMap<String, String>,
+    /// Normalized identifier -> retry_after_ms after a failed lookup.
+    failures: FxHashMap<String, u64>,
+    domains: FxHashMap<String, DomainSlots>,
+    /// Set when a lookup result has not been persisted yet.
+    dirty: bool,
+}
+
+impl LookupState {
+    fn fresh(&self, identifier: &str, now_ms: u64) -> Option<&CachedLookup> {
+        self.results
+            .get(identifier)
+            .filter(|entry| entry.is_fresh(now_ms))
+    }
+
+    fn insert(&mut self, entry: CachedLookup) {
+        if self.results.len() >= MAX_RESULTS && !self.results.contains_key(&entry.identifier) {
+            let now_ms = platform::now_millis();
+            self.results.retain(|_, cached| cached.is_fresh(now_ms));
+            if self.results.len() >= MAX_RESULTS {
+                self.results.clear();
+                self.by_pubkey.clear();
+            }
+        }
+        if let Some(profile) = entry.profile.as_ref().filter(|_| entry.verified) {
+            self.by_pubkey
+                .insert(profile.pubkey.clone(), entry.identifier.clone());
+        }
+        self.results.insert(entry.identifier.clone(), entry);
+    }
+
+    /// Whether the fresh lookup of `identifier` lists `pubkey`, whose own
+    /// profile names it. A match binds the identifier to the pubkey for
+    /// `relays_for`.
+    fn confirm(&mut self, identifier: &str, pubkey: &str, now_ms: u64) -> Option<bool> {
+        let entry = self
+            .results
+            .get_mut(identifier)
+            .filter(|entry| entry.is_fresh(now_ms))?;
+        let Some(listed) = entry
+            .profile
+            .as_ref()
+            .filter(|profile| profile.pubkey.eq_ignore_ascii_case(pubkey))
+            .map(|profile| profile.pubkey.clone())
+        else {
+            return Some(false);
+        };
+        if !entry.verified {
+            entry.verified = true;
+            self.by_pubkey.insert(listed, identifier.to_string());
+            self.dirty = true;
+        }
+        Some(true)
+    }
+}
+
+/// Splits `name@domain` (a bare `domain` means `_@domain`) into its
+/// lowercase parts, or None when it is not a NIP-05 identifier. The domain
+/// must be a DNS name: IP addresses and ports are refused.
+pub fn parse_identifier(identifier: &str) -> Option<(String, String)> {
+    let identifier = identifier.trim().to_ascii_lowercase();
+    let (name, domain) = match identifier.rsplit_once('@') {
+        Some((name, domain)) => (name.to_string(), domain.to_string()),
+        None => ("_".to_string(), identifier),
+    };
+    let name_ok = !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'));
+    (name_ok && is_public_domain(&domain)).then_some((name, domain))
+}
+
+/// Whether lowercase `domain` is a plain DNS name: no port, path, IP
+/// literal or `localhost`.
+pub(crate) fn is_public_domain(domain: &str) -> bool {
+    // A numeric last label would make the host an IPv4 address.
+    domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with(".localhost")
+        && domain
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.'))
+        && domain
+            .rsplit('.')
+            .next()
+            .is_some_and(|tld| tld.bytes().any(|b| b.is_ascii_alphabetic()))
+}
+
+/// The `nostr.json` URL that resolves `name` on `domain`.
+pub fn well_known_url(name: &str, domain: &str) -> String {
+    let scheme = if domain.ends_with(".onion") {
+        "http"
+    } else {
+        "https"
+    };
+    format!(
+        "{}://{}/.well-known/nostr.json?name={}",
+        scheme, domain, name
+    )
+}
+
+/// NIP-05 lookups made by one engine, with per-domain rate limits. Clones
+/// share the same state.
+#[derive(Clone, Default)]
+pub struct Nip05Lookups {
+    state: Arc<Mutex<LookupState>>,
+}
+
+impl Nip05Lookups {
+    /// Whether `identifier` resolved to `pubkey`, from a lookup within the
+    /// TTL. None until the identifier has been looked up. Only ask for the
+    /// `nip05` of a kind 0 signed by `pubkey`: a match makes the domain's
+    /// relays outbox hints for it.
+    pub fn verified(&self, pubkey: &str, identifier: &str) -> Option<bool> {
+        let (name, domain) = parse_identifier(identifier)?;
+        let now_ms = platform::now_millis();
+        self.state
+            .lock()
+            .unwrap()
+            .confirm(&format!("{}@{}", name, domain), pubkey, now_ms)
+    }
+
+    /// Relays listed for `pubkey` by the NIP-05 domain it verified against.
+    pub fn relays_for(&self, pubkey: &str) -> Vec<String> {
+        let state = self.state.lock().unwrap();
+        let Some(identifier) = state.by_pubkey.get(pubkey) else {
+            return Vec::new();
+        };
+        state
+            .fresh(identifier, platform::now_millis())
+            .filter(|entry| entry.verified)
+            .and_then(|entry| entry.profile.as_ref())
+            .filter(|profile| profile.pubkey == pubkey)
+            .map(|profile| profile.relays.clone())
+            .unwrap_or_default()
+    }
+
+    /// Resolves `identifier` to a pubkey and relays, from the cache or the
+    /// network. Errors when the domain does not list the name or cannot be
+    /// reached.
+    pub async fn lookup(
+        &self,
+        http: &HttpClients,
+        identifier: &str,
+    ) -> Result<Nip05Profile, String> {
+        let (name, domain) = parse_identifier(identifier)
+            .ok_or_else(|| format!("invalid NIP-05 identifier {}", identifier))?;
+        let url = well_known_url(&name, &domain);
+        self.lookup_at(http, &name, &domain, &url)
+            .await?
+            .ok_or_else(|| format!("{} is not listed by {}", name, domain))
+    }
+
+    /// Whether `identifier` resolves to `pubkey`. Errors only when the
+    /// lookup itself fails. Like `verified`, only for the `nip05` of a
+    /// kind 0 signed by `pubkey`.
+    pub async fn verify(
+        &self,
+        http: &HttpClients,
+        pubkey: &str,
+        identifier: &str,
+    ) -> Result<bool, String> {
+        let (name, domain) = parse_identifier(identifier)
+            .ok_or_else(|| format!("invalid NIP-05 identifier {}", identifier))?;
+        let url = well_known_url(&name, &domain);
+        let profile = self.lookup_at(http, &name, &domain, &url).await?;
+        let key = format!("{}@{}", name, domain);
+        let confirmed = self.state.lock().unwrap().confirm(&key, pubkey, platform::now_millis());
+        Ok(confirmed
+            .unwrap_or_else(|| profile.is_some_and(|p| p.pubkey.eq_ignore_ascii_case(pubkey))))
+    }
+
+    async fn lookup_at(
+        &self,
+        http: &HttpClients,
+        name: &str,
+        domain: &str,
+        url: &str,
+    ) -> Result<Option<Nip05Profile>, String> {
+        let key = format!("{}@{}", name, domain);
+        {
+            let now_ms = platform::now_millis();
+            let state = self.state.lock().unwrap();
+            if let Some(entry) = state.fresh(&key, now_ms) {
+                return Ok(entry.profile.clone());
+            }
+            if let Some(retry_after_ms) = state.failures.get(&key) {
+                if now_ms < *retry_after_ms {
+                    return Err(format!(
+                        "NIP-05 lookup recently failed; retrying in {}ms",
+                        retry_after_ms.saturating_sub(now_ms)
+                    ));
+                }
+            }
+        }
+
+        let result = self.fetch_profile(http, name, domain, url).await;
+        let mut state = self.state.lock().unwrap();
+        let profile = match result {
+            Ok(profile) => profile,
+            Err(e) => {
+                state
+                    .failures
+                    .insert(key, platform::now_millis() + FAILURE_BACKOFF_MS);
+                return Err(e);
+            }
+        };
+        state.failures.remove(&key);
+        // A refreshed lookup listing the same pubkey stays bound to it.
+        let listed = |lookup: &Option<Nip05Profile>| lookup.as_ref().map(|p| p.pubkey.clone());
+        let verified = state
+            .results
+            .get(&key)
+            .is_some_and(|old| old.verified && listed(&old.profile) == listed(&profile));
+        state.insert(CachedLookup {
+            identifier: key,
+            checked_at: platform::now_millis(),
+            profile: profile.clone(),
+            verified,
+        });
+        state.dirty = true;
+        Ok(profile)
+    }
+
+    async fn fetch_profile(
+        &self,
+        http: &HttpClients,
+        name: &str,
+        domain: &str,
+        url: &str,
+    ) -> Result<Option<Nip05Profile>, String> {
+        self.take_domain_slot(domain).await?;
+        let response = http_get(http, url).await?;
+        match response.status {
+            200..=299 => {}
+            404 => return Ok(None),
+            429 => {
+                let pause_ms = response
+                    .retry_after
+                    .as_deref()
+                    .and_then(|v| v.trim().parse::<u64>().ok())
+                    .map(|secs| secs.saturating_mul(1000))
+                    .unwrap_or(DEFAULT_RETRY_AFTER_MS)
+                    .min(MAX_RETRY_AFTER_MS);
+                let mut state = self.state.lock().unwrap();
+                state.domains.entry(domain.to_string()).or_default().paused_until =
+                    platform::now_millis() + pause_ms;
+                return Err(format!("{} is rate limiting NIP-05 lookups", domain));
+            }
+            300..=399 => {
+                return Err(format!(
+                    "{} redirected; NIP-05 lookups do not follow redirects",
+                    url
+                ))
+            }
+            status => return Err(format!("{} returned status: {}", url, status)),
+        }
+        let body: Nip05Response = serde_json::from_slice(&response.body)
+            .map_err(|e| format!("invalid nostr.json from {}: {}", domain, e))?;
+        Ok(profile_from_response(name, &body))
+    }
+
+    /// Waits for the domain's next request slot; errors while a 429 pause
+    /// lasts.
+    async fn take_domain_slot(&self, domain: &str) -> Result<(), String> {
+        loop {
+            let wait_ms = {
+                let now_ms = platform::now_millis();
+                let mut state = self.state.lock().unwrap();
+                let slots = state.domains.entry(domain.to_string()).or_default();
+                if now_ms < slots.paused_until {
+                    return Err(format!(
+                        "{} is rate limiting NIP-05 lookups; retrying in {}ms",
+                        domain,
+                        slots.paused_until - now_ms
+                    ));
+                }
+                if now_ms >= slots.next_request_at {
+                    slots.next_request_at = now_ms + DOMAIN_INTERVAL_MS;
+                    return Ok(());
+                }
+                slots.next_request_at - now_ms
+            };
+            platform::sleep(wait_ms).await;
+        }
+    }
+
+    /// Serialized cache (unexpired entries) for persistence.
+    pub fn snapshot(&self) -> Vec<u8> {
+        let now_ms = platform::now_millis();
+        let state = self.state.lock().unwrap();
+        let fresh: Vec<&CachedLookup> = state
+            .results
+            .values()
+            .filter(|entry| entry.is_fresh(now_ms))
+            .collect();
+        serde_json::to_vec(&fresh).unwrap_or_default()
+    }
+
+    /// Loads a persisted snapshot. Lookups made in this session win over
+    /// older persisted ones.
+    pub fn restore(&self, bytes: &[u8]) {
+        let Ok(saved) = serde_json::from_slice::<Vec<CachedLookup>>(bytes) else {
+            tracing::warn!("[Nip05] ignoring malformed persisted lookups");
+            return;
+        };
+        let now_ms = platform::now_millis();
+        let mut state = self.state.lock().unwrap();
+        for entry in saved {
+            if !entry.is_fresh(now_ms) {
+                continue;
+            }
+            let newer = state
+                .results
+                .get(&entry.identifier)
+                .is_some_and(|current| current.checked_at >= entry.checked_at);
+            if !newer {
+                state.insert(entry);
+            }
+        }
+    }
+
+    /// Returns whether lookups finished since the last call.
+    pub fn take_dirty(&self) -> bool {
+        std::mem::take(&mut self.state.lock().unwrap().dirty)
+    }
+
+    /// `(pubkey, nip05)` of a serialized kind 0 ParsedEvent WorkerMessage
+    /// whose identifier has no lookup within the TTL yet.
+    pub fn unchecked_identifier(&self, worker_message: &[u8]) -> Option<(String, String)> {
+        let parsed = flatbuffers::root::<fb::WorkerMessage>(worker_message)
+            .ok()?
+            .content_as_parsed_event()?;
+        let profile = parsed.parsed_as_kind_0_parsed()?;
+        let identifier = profile.nip05()?;
+        parse_identifier(identifier)?;
+        let pubkey = parsed.pubkey();
+        self.verified(pubkey, identifier)
+            .is_none()
+            .then(|| (pubkey.to_string(), identifier.to_string()))
+    }
+
+    /// Rebuilds a serialized kind 0 ParsedEvent WorkerMessage when the
+    /// cached verdict for its `nip05` differs from its `nip05_verified` flag.
+    pub fn refresh_verified(&self, worker_message: &[u8]) -> Option<Vec<u8>> {
+        let message = flatbuffers::root::<fb::WorkerMessage>(worker_message).ok()?;
+        let parsed = message.content_as_parsed_event()?;
+        let profile = parsed.parsed_as_kind_0_parsed()?;
+        let now_verified = self.verified(parsed.pubkey(), profile.nip05()?)?;
+        if now_verified == profile.nip05_verified() {
+            return None;
+        }
+
+        let mut unpacked = message.unpack();
+        if let fb::MessageT::ParsedEvent(event) = &mut unpacked.content {
+            if let fb::ParsedDataT::Kind0Parsed(profile) = &mut event.parsed {
+                profile.nip05_verified = now_verified;
+            }
+        }
+        let mut builder = flatbuffers::FlatBufferBuilder::new();
+        let root = unpacked.pack(&mut builder);
+        builder.finish(root, None);
+        Some(builder.finished_data().to_vec())
+    }
+}
+
+/// The profile `body` lists for `name`, with its relays limited to
+/// websocket URLs.
+fn profile_from_response(name: &str, body: &Nip05Response) -> Option<Nip05Profile> {
+    let pubkey = body
+        .names
+        .get(name)
+        .or_else(|| {
+            body.names
+                .iter()
+                .find(|(listed, _)| listed.eq_ignore_ascii_case(name))
+                .map(|(_, pubkey)| pubkey)
+        })?
+        .to_ascii_lowercase();
+    if pubkey.len() != 64 || !pubkey.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let relays = body
+        .relays
+        .as_ref()
+        .and_then(|relays| relays.get(&pubkey))
+        .map(|relays| {
+            relays
+                .iter()
+                .map(|relay| relay.trim())
+                .filter(|relay| relay.starts_with("wss://") || relay.starts_with("ws://"))
+                .take(MAX_RELAYS)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(Nip05Profile { pubkey, relays })
+}
+
+struct HttpResponse {
+    status: u16,
+    retry_after: Option<String>,
+    body: Vec<u8>,
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn http_get(_http: &HttpClients, url: &str) -> Result<HttpResponse, String> {
+    use futures::future::{select, Either};
+
+    let request = Box::pin(async {
+        // An opaque redirect surfaces as a network error, never followed.
+        let response = gloo_net::http::Request::get(url)
+            .header("Accept", "application/json")
+            .redirect(web_sys::RequestRedirect::Error)
+            .send()
+            .await
+            .map_err(|e| format!("HTTP request failed: {:?}", e))?;
+        let status = response.status();
+        let retry_after = response.headers().get("retry-after");
+        let body = if (200..300).contains(&status) {
+            response
+                .binary()
+                .await
+                .map_err(|e| format!("Failed to read response: {:?}", e))?
+        } else {
+            Vec::new()
+        };
+        if body.len() > MAX_BODY_BYTES {
+            return Err(format!("nostr.json from {} is too large", url));
+        }
+        Ok(HttpResponse {
+            status,
+            retry_after,
+            body,
+        })
+    });
+    let timeout = Box::pin(platform::sleep(FETCH_TIMEOUT_MS));
+    match select(request, timeout).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(format!("{} timed out", url)),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn http_get(http: &HttpClients, url: &str) -> Result<HttpResponse, String> {
+    let client = http.public_client(url)?;
+    let mut response = client
+        .get(url)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .timeout(std::time::Duration::from_millis(FETCH_TIMEOUT_MS))
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {}", e))?;
+    let status = response.status().as_u16();
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let mut body = Vec::new();
+    if response.status().is_success() {
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| format!("Failed to read response: {}", e))?
+        {
+            if body.len() + chunk.len() > MAX_BODY_BYTES {
+                return Err(format!("nostr.json from {} is too large", url));
+            }
+            body.extend_from_slice(&chunk);
+        }
+    }
+    Ok(HttpResponse {
+        status,
+        retry_after,
+        body,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBKEY: &str = "b0635d6a9851d3aed0cd6c495b282167acf761729078d975fc341b22650b07b9";
+
+    fn cache(lookups: &Nip05Lookups, identifier: &str, pubkey: Option<&str>) {
+        lookups.state.lock().unwrap().insert(CachedLookup {
+            identifier: identifier.to_string(),
+            checked_at: platform::now_millis(),
+            profile: pubkey.map(|pubkey| Nip05Profile {
+                pubkey: pubkey.to_string(),
+                relays: vec!["wss://relay.example.com".to_string()],
+            }),
+            verified: false,
+        });
+    }
+
+    fn kind0_message(pubkey: &str, nip05: &str) -> Vec<u8> {
+        let message = fb::WorkerMessageT {
+            sub_id: Some("profiles".to_string()),
+            type_: fb::MessageType::ParsedNostrEvent,
+            content: fb::MessageT::ParsedEvent(Box::new(fb::ParsedEventT {
+                id: "00".repeat(32),
+                pubkey: pubkey.to_string(),
+                kind: 0,
+                parsed: fb::ParsedDataT::Kind0Parsed(Box::new(fb::Kind0ParsedT {
+                    pubkey: Some(pubkey.to_string()),
+                    name: Some("bob".to_string()),
+                    nip05: Some(nip05.to_string()),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let mut builder = flatbuffers::FlatBufferBuilder::new();
+        let root = message.pack(&mut builder);
+        builder.finish(root, None);
+        builder.finished_data().to_vec()
+    }
+
+    #[test]
+    fn parses_identifiers() {
+        assert_eq!(
+            parse_identifier(" Bob@Example.COM "),
+            Some(("bob".to_string(), "example.com".to_string()))
+        );
+        assert_eq!(
+            parse_identifier("example.com"),
+            Some(("_".to_string(), "example.com".to_string()))
+        );
+        assert!(parse_identifier("bob@localhost").is_none());
+        assert!(parse_identifier("bob@relay.localhost").is_none());
+        assert!(parse_identifier("bob@127.0.0.1").is_none());
+        assert!(parse_identifier("bob@example.com:8080").is_none());
+        assert!(parse_identifier("bob@[::1]").is_none());
+        assert!(parse_identifier("bob smith@example.com").is_none());
+        assert!(parse_identifier("bob@example.com/path").is_none());
+        assert_eq!(
+            well_known_url("bob", "example.com"),
+            "https://example.com/.well-known/nostr.json?name=bob"
+        );
+    }
+
+    #[test]
+    fn reads_pubkey_and_websocket_relays() {
+        let body: Nip05Response = serde_json::from_str(&format!(
+            r#"{{"names":{{"Bob":"{}"}},"relays":{{"{}":["wss://relay.example.com","https://not.a.relay"]}}}}"#,
+            PUBKEY.to_uppercase(),
+            PUBKEY
+        ))
+        .unwrap();
+        let profile = profile_from_response("bob", &body).unwrap();
+        assert_eq!(profile.pubkey, PUBKEY);
+        assert_eq!(profile.relays, vec!["wss://relay.example.com".to_string()]);
+
+        let body: Nip05Response = serde_json::from_str(r#"{"names":{"bob":"npub1"}}"#).unwrap();
+        assert!(profile_from_response("bob", &body).is_none());
+        assert!(profile_from_response("alice", &Nip05Response::default()).is_none());
+    }
+
+    #[test]
+    fn verdicts_come_from_the_cache() {
+        let lookups = Nip05Lookups::default();
+        assert_eq!(lookups.verified(PUBKEY, "carol@verdict.example"), None);
+        cache(&lookups, "carol@verdict.example", Some(PUBKEY));
+        cache(&lookups, "dave@verdict.example", None);
+        assert_eq!(lookups.verified(PUBKEY, "Carol@Verdict.Example"), Some(true));
+        assert_eq!(
+            lookups.verified(&"11".repeat(32), "carol@verdict.example"),
+            Some(false)
+        );
+        assert_eq!(lookups.verified(PUBKEY, "dave@verdict.example"), Some(false));
+        assert_eq!(
+            lookups.relays_for(PUBKEY),
+            vec!["wss://relay.example.com".to_string()]
+        );
+        assert!(Nip05Lookups::default().relays_for(PUBKEY).is_empty());
+    }
+
+    #[test]
+    fn relays_need_the_listed_pubkey_to_name_the_identifier() {
+        // An attacker's profile names x@evil.example, which lists the victim.
+        let lookups = Nip05Lookups::default();
+        let attacker = "33".repeat(32);
+        cache(&lookups, "x@evil.example", Some(PUBKEY));
+        assert_eq!(lookups.verified(&attacker, "x@evil.example"), Some(false));
+        assert!(lookups.relays_for(PUBKEY).is_empty());
+        assert!(lookups.relays_for(&attacker).is_empty());
+
+        // The victim's own profile naming it is what binds the relays.
+        assert_eq!(lookups.verified(PUBKEY, "x@evil.example"), Some(true));
+        assert_eq!(
+            lookups.relays_for(PUBKEY),
+            vec!["wss://relay.example.com".to_string()]
+        );
+        let restored = Nip05Lookups::default();
+        restored.restore(&lookups.snapshot());
+        assert_eq!(
+            restored.relays_for(PUBKEY),
+            vec!["wss://relay.example.com".to_string()]
+        );
+    }
+
+    #[test]
+    fn re_emits_profiles_once_verified() {
+        let lookups = Nip05Lookups::default();
+        let pubkey = "22".repeat(32);
+        let message = kind0_message(&pubkey, "erin@refresh.example");
+        assert_eq!(
+            lookups.unchecked_identifier(&message),
+            Some((pubkey.clone(), "erin@refresh.example".to_string()))
+        );
+        assert!(lookups.refresh_verified(&message).is_none());
+
+        cache(&lookups, "erin@refresh.example", Some(&pubkey));
+        assert!(lookups.unchecked_identifier(&message).is_none());
+        let updated = lookups.refresh_verified(&message).unwrap();
+        let parsed = flatbuffers::root::<fb::WorkerMessage>(&updated)
+            .unwrap()
+            .content_as_parsed_event()
+            .unwrap();
+        let profile = parsed.parsed_as_kind_0_parsed().unwrap();
+        assert!(profile.nip05_verified());
+        assert_eq!(profile.name(), Some("bob"));
+        assert!(lookups.refresh_verified(&updated).is_none());
+    }
+
+    #[test]
+    fn snapshot_round_trips() {
+        let pubkey = "33".repeat(32);
+        let lookups = Nip05Lookups::default();
+        cache(&lookups, "frank@snapshot.example", Some(&pubkey));
+        let bytes = lookups.snapshot();
+
+        let restored = Nip05Lookups::default();
+        assert_eq!(restored.verified(&pubkey, "frank@snapshot.example"), None);
+        restored.restore(&bytes);
+        assert_eq!(restored.verified(&pubkey, "frank@snapshot.example"), Some(true));
+    }
+
+    /// Serves each canned response to one connection, in order.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn serve(responses: Vec<String>) -> (String, std::thread::JoinHandle<()>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let server = std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        (addr, server)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn http_response(status: &str, headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            headers,
+            body.len(),
+            body
+        )
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn looks_up_from_http_server_and_caches() {
+        let body = format!(
+            r#"{{"names":{{"grace":"{}"}},"relays":{{"{}":["wss://grace.relay"]}}}}"#,
+            PUBKEY, PUBKEY
+        );
+        let (addr, server) = serve(vec![http_response(
+            "200 OK",
+            "Content-Type: application/json\r\n",
+            &body,
+        )]);
+        let url = format!("http://{}/.well-known/nostr.json?name=grace", addr);
+        let lookups = Nip05Lookups::default();
+        let http = HttpClients::default();
+
+        let profile = lookups
+            .lookup_at(&http, "grace", "lookup.example", &url)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(profile.pubkey, PUBKEY);
+        assert_eq!(profile.relays, vec!["wss://grace.relay".to_string()]);
+        server.join().unwrap();
+
+        // Served from the cache: the server is gone.
+        assert_eq!(
+            lookups
+                .lookup_at(&http, "grace", "lookup.example", &url)
+                .await
+                .unwrap(),
+            Some(profile)
+        );
+        assert_eq!(lookups.verified(PUBKEY, "grace@lookup.example"), Some(true));
+        assert!(lookups.take_dirty());
+        assert!(!lookups.take_dirty());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn refuses_redirects_and_honours_retry_after() {
+        let (addr, server) = serve(vec![
+            http_response("302 Found", "Location: https://elsewhere.example/\r\n", ""),
+            http_response("429 Too Many Requests", "Retry-After: 120\r\n", ""),
+        ]);
+        let url = format!("http://{}/.well-known/nostr.json?name=heidi", addr);
+        let lookups = Nip05Lookups::default();
+        let http = HttpClients::default();
+
+        let err = lookups
+            .lookup_at(&http, "heidi", "redirect.example", &url)
+            .await
+            .unwrap_err();
+        assert!(err.contains("redirect"), "{}", err);
+
+        let err = lookups
+            .lookup_at(&http, "ivan", "limited.example", &url)
+            .await
+            .unwrap_err();
+        assert!(err.contains("rate limiting"), "{}", err);
+        server.join().unwrap();
+        let paused_until = lookups.state.lock().unwrap().domains["limited.example"].paused_until;
+        assert!(paused_until >= platform::now_millis() + 100_000);
+
+        // Other names on the paused domain fail fast without a request.
+        let err = lookups
+            .lookup_at(&http, "judy", "limited.example", &url)
+            .await
+            .unwrap_err();
+        assert!(err.contains("retrying"), "{}", err);
+    }
+}
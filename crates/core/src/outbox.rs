@@ -0,0 +1,318 @@
+//! Outbox relay planning for multi-author subscriptions.
+//!
+//! Given each author's write relays (from their kind 10002 list), the planner
+//! picks a small set of relays with a greedy set cover so that every author is
+//! reached through up to `coverage` of their own relays, then assigns each
+//! selected relay only the authors that publish there. Authors no selected
+//! relay covers (including those without a relay list) are reported back and
+//! routed to fallback relays.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Relays each author should be reachable through.
+pub const OUTBOX_COVERAGE_TARGET: usize = 2;
+
+/// Relay cap used when the request does not set `max_relays`.
+pub const OUTBOX_MAX_RELAYS: usize = 15;
+
+/// One relay of a plan and the authors it should be asked for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayAssignment {
+    pub relay: String,
+    pub authors: Vec<String>,
+}
+
+/// Result of [`plan_outbox`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutboxPlan {
+    /// Selected relays in pick order, each with the authors it covers.
+    pub assignments: Vec<RelayAssignment>,
+    /// Authors without any selected relay; already merged into the fallback
+    /// relays' assignments, listed here for reporting.
+    pub uncovered: Vec<String>,
+}
+
+impl OutboxPlan {
+    pub fn relays(&self) -> Vec<String> {
+        self.assignments.iter().map(|a| a.relay.clone()).collect()
+    }
+}
+
+/// Plans relays for `author_relays` (author → write relays).
+///
+/// Each greedy step picks the relay that reaches the most authors with no
+/// relay yet, then the most authors still below `coverage`; remaining ties go
+/// to the higher `rank` (e.g. relay health), then the URL. Putting first
+/// coverage ahead of redundancy keeps a tight `max_relays` from being spent
+/// on popular relays while some author has none.
+/// Picking stops once every author is satisfied, no relay adds coverage, or
+/// `max_relays` relays are selected (`0` means [`OUTBOX_MAX_RELAYS`]).
+/// Uncovered authors are assigned to the relays in `fallback_relays`; the
+/// cap covers those too, and the last pick gives way when no fallback would
+/// fit otherwise. Relays failing `usable` (dead, blocked) are never picked;
+/// fallbacks are only filtered while at least one usable one remains.
+pub fn plan_outbox(
+    author_relays: &HashMap<String, Vec<String>>,
+    coverage: usize,
+    max_relays: usize,
+    fallback_relays: &[String],
+    rank: &dyn Fn(&str) -> f64,
+    usable: &dyn Fn(&str) -> bool,
+) -> OutboxPlan {
+    let coverage = coverage.max(1);
+    let max_relays = if max_relays == 0 {
+        OUTBOX_MAX_RELAYS
+    } else {
+        max_relays
+    };
+
+    // relay → authors listing it, ordered for deterministic plans.
+    let mut relay_authors: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for (author, relays) in author_relays {
+        for relay in relays {
+            if !relay.is_empty() && usable(relay) {
+                relay_authors
+                    .entry(relay.as_str())
+                    .or_default()
+                    .insert(author.as_str());
+            }
+        }
+    }
+
+    let mut need: HashMap<&str, usize> = author_relays
+        .iter()
+        .map(|(author, relays)| {
+            let distinct: BTreeSet<&String> = relays
+                .iter()
+                .filter(|r| !r.is_empty() && usable(r))
+                .collect();
+            (author.as_str(), coverage.min(distinct.len()))
+        })
+        .collect();
+
+    let mut covered: HashMap<&str, usize> = HashMap::new();
+    let mut selected: Vec<&str> = Vec::new();
+    while selected.len() < max_relays {
+        let best = relay_authors
+            .iter()
+            .map(|(relay, authors)| {
+                let needing = authors
+                    .iter()
+                    .filter(|a| need.get(*a).copied().unwrap_or(0) > 0);
+                let first = needing
+                    .clone()
+                    .filter(|a| !covered.contains_key(*a))
+                    .count();
+                (*relay, (first, needing.count()))
+            })
+            .filter(|(_, gain)| gain.1 > 0)
+            .max_by(|a, b| {
+                a.1.cmp(&b.1)
+                    .then_with(|| rank(a.0).total_cmp(&rank(b.0)))
+                    .then_with(|| b.0.cmp(a.0))
+            });
+        let Some((relay, _)) = best else {
+            break;
+        };
+        if let Some(authors) = relay_authors.remove(relay) {
+            for author in authors {
+                if let Some(n) = need.get_mut(author) {
+                    *n = n.saturating_sub(1);
+                }
+                *covered.entry(author).or_default() += 1;
+            }
+        }
+        selected.push(relay);
+    }
+
+    let fallback_relays: Vec<&String> = {
+        let live: Vec<&String> = fallback_relays.iter().filter(|r| usable(r)).collect();
+        if live.is_empty() {
+            fallback_relays.iter().collect()
+        } else {
+            live
+        }
+    };
+    let reaches_all = |selected: &[&str]| {
+        author_relays
+            .values()
+            .all(|relays| relays.iter().any(|r| selected.contains(&r.as_str())))
+    };
+    if !fallback_relays.is_empty()
+        && selected.len() >= max_relays
+        && !reaches_all(&selected)
+        && !fallback_relays.iter().any(|r| selected.contains(&r.as_str()))
+    {
+        selected.pop();
+    }
+
+    let mut assignments: Vec<RelayAssignment> = selected
+        .iter()
+        .map(|relay| {
+            let mut authors: Vec<String> = author_relays
+                .iter()
+                .filter(|(_, relays)| relays.iter().any(|r| r == relay))
+                .map(|(author, _)| author.clone())
+                .collect();
+            authors.sort();
+            RelayAssignment {
+                relay: relay.to_string(),
+                authors,
+            }
+        })
+        .collect();
+
+    let mut uncovered: Vec<String> = author_relays
+        .iter()
+        .filter(|(_, relays)| !relays.iter().any(|r| selected.contains(&r.as_str())))
+        .map(|(author, _)| author.clone())
+        .collect();
+    uncovered.sort();
+
+    if !uncovered.is_empty() {
+        for relay in fallback_relays {
+            let room = assignments.len() < max_relays;
+            match assignments.iter_mut().find(|a| &a.relay == relay) {
+                Some(existing) => {
+                    existing.authors.extend(uncovered.iter().cloned());
+                    existing.authors.sort();
+                    existing.authors.dedup();
+                }
+                None if room => assignments.push(RelayAssignment {
+                    relay: relay.clone(),
+                    authors: uncovered.clone(),
+                }),
+                None => {}
+            }
+        }
+    }
+
+    OutboxPlan {
+        assignments,
+        uncovered,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lists(entries: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+        entries
+            .iter()
+            .map(|(author, relays)| {
+                (
+                    author.to_string(),
+                    relays.iter().map(|r| r.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn no_rank(_: &str) -> f64 {
+        0.0
+    }
+
+    fn all_usable(_: &str) -> bool {
+        true
+    }
+
+    #[test]
+    fn covers_every_author_with_fewest_relays() {
+        let authors = lists(&[
+            ("alice", &["wss://a", "wss://shared"]),
+            ("bob", &["wss://b", "wss://shared"]),
+            ("carol", &["wss://c", "wss://shared"]),
+        ]);
+        let plan = plan_outbox(&authors, 1, 0, &[], &no_rank, &all_usable);
+        assert_eq!(plan.relays(), vec!["wss://shared".to_string()]);
+        assert_eq!(
+            plan.assignments[0].authors,
+            vec!["alice".to_string(), "bob".to_string(), "carol".to_string()]
+        );
+        assert!(plan.uncovered.is_empty());
+    }
+
+    #[test]
+    fn coverage_target_adds_second_relay_per_author() {
+        let authors = lists(&[
+            ("alice", &["wss://a", "wss://shared"]),
+            ("bob", &["wss://b", "wss://shared"]),
+        ]);
+        let plan = plan_outbox(&authors, 2, 0, &[], &no_rank, &all_usable);
+        assert_eq!(plan.assignments.len(), 3);
+        assert_eq!(plan.assignments[0].relay, "wss://shared");
+        let a = plan.assignments.iter().find(|a| a.relay == "wss://a").unwrap();
+        assert_eq!(a.authors, vec!["alice".to_string()]);
+    }
+
+    #[test]
+    fn max_relays_keeps_every_author_reachable_when_possible() {
+        // Truncating by popularity would pick the two popular relays and
+        // leave dave with nothing.
+        let authors = lists(&[
+            ("alice", &["wss://pop1", "wss://pop2"]),
+            ("bob", &["wss://pop1", "wss://pop2"]),
+            ("carol", &["wss://pop1", "wss://pop2"]),
+            ("dave", &["wss://niche"]),
+        ]);
+        let plan = plan_outbox(&authors, 2, 2, &[], &no_rank, &all_usable);
+        assert_eq!(plan.assignments.len(), 2);
+        assert!(plan.relays().contains(&"wss://niche".to_string()));
+        assert!(plan.uncovered.is_empty());
+    }
+
+    #[test]
+    fn uncovered_authors_go_to_fallback_relays() {
+        let authors = lists(&[("alice", &["wss://a"]), ("nolist", &[])]);
+        let fallback = vec!["wss://indexer".to_string(), "wss://a".to_string()];
+        let plan = plan_outbox(&authors, 2, 0, &fallback, &no_rank, &all_usable);
+        assert_eq!(plan.uncovered, vec!["nolist".to_string()]);
+        let a = plan.assignments.iter().find(|a| a.relay == "wss://a").unwrap();
+        assert_eq!(a.authors, vec!["alice".to_string(), "nolist".to_string()]);
+        let indexer = plan
+            .assignments
+            .iter()
+            .find(|a| a.relay == "wss://indexer")
+            .unwrap();
+        assert_eq!(indexer.authors, vec!["nolist".to_string()]);
+    }
+
+    #[test]
+    fn rank_breaks_ties() {
+        let authors = lists(&[("alice", &["wss://x", "wss://y"])]);
+        let rank = |relay: &str| if relay == "wss://y" { 1.0 } else { 0.0 };
+        let plan = plan_outbox(&authors, 1, 0, &[], &rank, &all_usable);
+        assert_eq!(plan.relays(), vec!["wss://y".to_string()]);
+    }
+
+    #[test]
+    fn unusable_relays_are_skipped() {
+        let authors = lists(&[("alice", &["wss://dead", "wss://a"]), ("bob", &["wss://dead"])]);
+        let fallback = vec!["wss://dead".to_string(), "wss://indexer".to_string()];
+        let usable = |relay: &str| relay != "wss://dead";
+        let plan = plan_outbox(&authors, 1, 0, &fallback, &no_rank, &usable);
+        assert_eq!(
+            plan.relays(),
+            vec!["wss://a".to_string(), "wss://indexer".to_string()]
+        );
+        assert_eq!(plan.uncovered, vec!["bob".to_string()]);
+    }
+
+    #[test]
+    fn max_relays_counts_fallback_relays() {
+        let authors = lists(&[
+            ("alice", &["wss://a"]),
+            ("bob", &["wss://b"]),
+            ("nolist", &[]),
+        ]);
+        let fallback = vec!["wss://f1".to_string(), "wss://f2".to_string()];
+        let plan = plan_outbox(&authors, 1, 2, &fallback, &no_rank, &all_usable);
+        assert_eq!(plan.assignments.len(), 2);
+        // The second pick gave way so the fallback reaches the rest.
+        assert_eq!(plan.assignments[1].relay, "wss://f1");
+        assert_eq!(plan.uncovered.len(), 2);
+        assert!(plan.uncovered.contains(&"nolist".to_string()));
+    }
+}
@@ -0,0 +1,343 @@
+//! BOLT11 lightning invoice decoding.
+//!
+//! Decodes the fields a client shows or checks before paying: amount,
+//! creation time and expiry, description or description hash, payment hash,
+//! network and payee node id. Decoding does not authorize a payment; wallets
+//! still apply their own feature and policy checks.
+
+use crate::types::nostr::nips::nip19;
+
+/// Expiry in seconds when an invoice has no `x` field.
+const DEFAULT_EXPIRY: u64 = 3600;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bolt11Invoice {
+    /// "bitcoin", "testnet", "signet" or "regtest".
+    pub network: String,
+    /// None when the payer chooses the amount.
+    pub amount_msats: Option<u64>,
+    /// Creation time, unix seconds.
+    pub timestamp: u64,
+    /// Seconds after `timestamp` until the invoice expires.
+    pub expiry: u64,
+    pub description: Option<String>,
+    /// Hex SHA-256 of the out-of-band description.
+    pub description_hash: Option<String>,
+    /// Hex.
+    pub payment_hash: String,
+    /// Hex compressed node id, from the `n` field or recovered from the
+    /// signature. None without the `crypto` feature and no `n` field.
+    pub payee: Option<String>,
+}
+
+impl Bolt11Invoice {
+    /// Decodes and validates `invoice`, with or without a `lightning:` prefix.
+    ///
+    /// Checks uniform case, the checksum, tagged-field framing, exactly one
+    /// payment hash and one description or description hash, and the
+    /// signature. With the `crypto` feature the payee is recovered from the
+    /// signature and must match the `n` field when present.
+    pub fn decode(invoice: &str) -> Option<Self> {
+        let invoice = match invoice.get(..10) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("lightning:") => &invoice[10..],
+            _ => invoice,
+        };
+        if !invoice.is_ascii() {
+            return None;
+        }
+
+        let (hrp, words) = nip19::decode_words(invoice).ok()?;
+        let (network, amount_msats) = parse_hrp(&hrp)?;
+
+        // 7 timestamp words, tagged fields, then 104 signature words.
+        let fields_end = words.len().checked_sub(104)?;
+        if fields_end < 7 {
+            return None;
+        }
+        let timestamp = words_to_u64(&words[..7])?;
+
+        let mut expiry = DEFAULT_EXPIRY;
+        let mut description = None;
+        let mut description_hash = None;
+        let mut payment_hash = None;
+        let mut payee = None;
+        let mut descriptions = 0u8;
+        let mut payment_hashes = 0u8;
+        let mut index = 7;
+        while index < fields_end {
+            let tag = words[index];
+            let len =
+                usize::from(*words.get(index + 1)?) << 5 | usize::from(*words.get(index + 2)?);
+            index += 3;
+            let field = words
+                .get(index..index + len)
+                .filter(|_| index + len <= fields_end)?;
+            index += len;
+
+            match tag {
+                // p
+                1 => {
+                    payment_hash = Some(hex::encode(hash_field(field)?));
+                    payment_hashes = payment_hashes.saturating_add(1);
+                }
+                // d
+                13 => {
+                    let bytes = nip19::convert_bits(field, 5, 8, false)?;
+                    description = Some(String::from_utf8(bytes).ok()?);
+                    descriptions = descriptions.saturating_add(1);
+                }
+                // h
+                23 => {
+                    description_hash = Some(hex::encode(hash_field(field)?));
+                    descriptions = descriptions.saturating_add(1);
+                }
+                // x
+                6 => expiry = words_to_u64(field)?,
+                // n
+                19 => {
+                    if len != 53 {
+                        return None;
+                    }
+                    let node = nip19::convert_bits(field, 5, 8, false)?;
+                    payee = Some(hex::encode(node));
+                }
+                _ => {}
+            }
+        }
+        if payment_hashes != 1 || descriptions != 1 {
+            return None;
+        }
+
+        let signature = nip19::convert_bits(&words[fields_end..], 5, 8, false)?;
+        if signature.len() != 65
+            || signature[64] > 3
+            || !is_valid_secp256k1_scalar(&signature[..32])
+            || !is_valid_secp256k1_scalar(&signature[32..64])
+        {
+            return None;
+        }
+
+        #[cfg(feature = "crypto")]
+        {
+            let recovered = recover_payee(&signing_hash(&hrp, &words[..fields_end]), &signature)?;
+            match payee {
+                Some(ref node) if *node != recovered => return None,
+                _ => payee = Some(recovered),
+            }
+        }
+
+        Some(Self {
+            network: network.to_string(),
+            amount_msats,
+            timestamp,
+            expiry,
+            description,
+            description_hash,
+            payment_hash: payment_hash?,
+            payee,
+        })
+    }
+
+    /// Unix seconds at which the invoice stops being payable.
+    pub fn expires_at(&self) -> u64 {
+        self.timestamp.saturating_add(self.expiry)
+    }
+
+    pub fn is_expired(&self, now_secs: u64) -> bool {
+        now_secs >= self.expires_at()
+    }
+}
+
+/// Network and amount (msats) from an invoice HRP such as `lnbc2500u`.
+fn parse_hrp(hrp: &str) -> Option<(&'static str, Option<u64>)> {
+    let rest = hrp.strip_prefix("ln")?;
+    let (network, amount) = if let Some(amount) = rest.strip_prefix("bcrt") {
+        ("regtest", amount)
+    } else if let Some(amount) = rest.strip_prefix("tbs") {
+        ("signet", amount)
+    } else if let Some(amount) = rest.strip_prefix("tb") {
+        ("testnet", amount)
+    } else if let Some(amount) = rest.strip_prefix("bc") {
+        ("bitcoin", amount)
+    } else {
+        return None;
+    };
+    if amount.is_empty() {
+        return Some((network, None));
+    }
+
+    let (digits, pico_per_unit) = match amount.as_bytes()[amount.len() - 1] {
+        b'm' => (&amount[..amount.len() - 1], 1_000_000_000u64),
+        b'u' => (&amount[..amount.len() - 1], 1_000_000u64),
+        b'n' => (&amount[..amount.len() - 1], 1_000u64),
+        b'p' => (&amount[..amount.len() - 1], 1u64),
+        _ => (amount, 1_000_000_000_000u64),
+    };
+    if digits.is_empty() || digits.starts_with('0') || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // One msat is ten pico-bitcoin; finer amounts are not payable
+    let pico = digits.parse::<u64>().ok()?.checked_mul(pico_per_unit)?;
+    if pico % 10 != 0 {
+        return None;
+    }
+    Some((network, Some(pico / 10)))
+}
+
+/// Big-endian integer from 5-bit words.
+fn words_to_u64(words: &[u8]) -> Option<u64> {
+    if words.len() > 12 {
+        return None;
+    }
+    Some(words.iter().fold(0u64, |acc, &w| acc << 5 | u64::from(w)))
+}
+
+/// A 256-bit `p` or `h` field: 52 words whose final padding bits are zero.
+fn hash_field(field: &[u8]) -> Option<Vec<u8>> {
+    if field.len() != 52 {
+        return None;
+    }
+    nip19::convert_bits(field, 5, 8, false)
+}
+
+fn is_valid_secp256k1_scalar(scalar: &[u8]) -> bool {
+    const CURVE_ORDER: [u8; 32] = [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xfe, 0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36,
+        0x41, 0x41,
+    ];
+
+    scalar.iter().any(|&byte| byte != 0) && scalar < CURVE_ORDER.as_slice()
+}
+
+/// SHA-256 of the HRP bytes followed by the timestamp and tagged-field words
+/// packed into bytes; this is what the payee signs.
+#[cfg(feature = "crypto")]
+pub(crate) fn signing_hash(hrp: &str, words: &[u8]) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(hrp.as_bytes());
+    hasher.update(nip19::convert_bits(words, 5, 8, true).unwrap_or_default());
+    hasher.finalize().into()
+}
+
+#[cfg(feature = "crypto")]
+fn recover_payee(hash: &[u8; 32], signature: &[u8]) -> Option<String> {
+    use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+    let sig = Signature::from_slice(&signature[..64]).ok()?;
+    let recovery_id = RecoveryId::from_byte(signature[64])?;
+    let key = VerifyingKey::recover_from_prehash(hash, &sig, recovery_id).ok()?;
+    Some(hex::encode(key.to_encoded_point(true).as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // BOLT11 specification test vectors
+    const DONATION: &str = concat!(
+        "lnbc1pvjluezsp5zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygspp5",
+        "qqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqypqdpl2pkx2ctnv5sxx",
+        "mmwwd5kgetjypeh2ursdae8g6twvus8g6rfwvs8qun0dfjkxaq9qrsgq357wnc5r2ueh7c",
+        "k6q93dj32dlqnls087fxdwk8qakdyafkq3yap9us6v52vjjsrvywa6rt52cm9r9zqt8r2",
+        "t7mlcwspyetp5h2tztugp9lfyql",
+    );
+    const DESCRIPTION_HASH: &str = concat!(
+        "lnbc20m1pvjluezsp5zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygspp5qqq",
+        "syqcyq5rqwzqfqqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqypqhp58yjmdan79s6qqdhdzgynm4zw",
+        "qd5d7xmw5fk98klysy043l2ahrqs9qrsgq7ea976txfraylvgzuxs8kgcw23ezlrszfnh8r6qtfp",
+        "r6cxga50aj6txm9rxrydzd06dfeawfk6swupvz4erwnyutnjq7x39ymw6j38gp7ynn44",
+    );
+
+    #[test]
+    fn decodes_spec_invoices() {
+        let donation = Bolt11Invoice::decode(DONATION).unwrap();
+        assert_eq!(donation.network, "bitcoin");
+        assert_eq!(donation.amount_msats, None);
+        assert_eq!(donation.timestamp, 1_496_314_658);
+        assert_eq!(donation.expiry, 3600);
+        assert_eq!(
+            donation.description.as_deref(),
+            Some("Please consider supporting this project")
+        );
+        assert_eq!(
+            donation.payment_hash,
+            "0001020304050607080900010203040506070809000102030405060708090102"
+        );
+
+        let hashed = Bolt11Invoice::decode(DESCRIPTION_HASH).unwrap();
+        assert_eq!(hashed.amount_msats, Some(2_000_000_000));
+        assert_eq!(hashed.description, None);
+        assert_eq!(
+            hashed.description_hash.as_deref(),
+            Some("3925b6f67e2c340036ed12093dd44e0368df1b6ea26c53dbe4811f58fd5db8c1")
+        );
+    }
+
+    /// Signs timestamp and tagged-field `words` under `hrp` with node key 0x03..03.
+    #[cfg(feature = "crypto")]
+    fn signed_invoice(hrp: &str, mut words: Vec<u8>) -> String {
+        let node = k256::ecdsa::SigningKey::from_slice(&[3; 32]).unwrap();
+        let (sig, recovery_id) = node
+            .sign_prehash_recoverable(&signing_hash(hrp, &words))
+            .unwrap();
+        let mut sig = sig.to_bytes().to_vec();
+        sig.push(recovery_id.to_byte());
+        words.extend(nip19::convert_bits(&sig, 8, 5, true).unwrap());
+        nip19::encode_words(hrp, &words)
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn reads_expiry_and_amount() {
+        // timestamp 1_700_000_000, p, d "coffee", x 60
+        let mut words = vec![1, 18, 21, 7, 28, 8, 0];
+        words.extend([1, 1, 20]);
+        words.extend(nip19::convert_bits(&[7; 32], 8, 5, true).unwrap());
+        words.extend([13, 0, 10]);
+        words.extend(nip19::convert_bits(b"coffee", 8, 5, true).unwrap());
+        words.extend([6, 0, 2, 1, 28]);
+        let invoice = signed_invoice("lntb2500u", words);
+
+        let decoded =
+            Bolt11Invoice::decode(&format!("LIGHTNING:{}", invoice.to_uppercase())).unwrap();
+        assert_eq!(decoded.network, "testnet");
+        assert_eq!(decoded.amount_msats, Some(250_000_000));
+        assert_eq!(decoded.timestamp, 1_700_000_000);
+        assert_eq!(decoded.expiry, 60);
+        assert!(decoded.is_expired(1_700_000_060));
+        assert!(!decoded.is_expired(1_700_000_059));
+        assert_eq!(decoded.description.as_deref(), Some("coffee"));
+        assert_eq!(decoded.payment_hash, hex::encode([7; 32]));
+
+        let node = k256::ecdsa::SigningKey::from_slice(&[3; 32]).unwrap();
+        assert_eq!(
+            decoded.payee,
+            Some(hex::encode(
+                node.verifying_key().to_encoded_point(true).as_bytes()
+            ))
+        );
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn recovers_payee() {
+        for invoice in [DONATION, DESCRIPTION_HASH] {
+            assert_eq!(
+                Bolt11Invoice::decode(invoice).unwrap().payee.as_deref(),
+                Some("03e7156ae33b0a208d0744199163177e909e80176e55d97a2f221ede0f934dd9ad")
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_invoices() {
+        let corrupted = format!("{}q", &DONATION[..DONATION.len() - 1]);
+        assert!(Bolt11Invoice::decode(&corrupted).is_none());
+        assert!(Bolt11Invoice::decode(&DONATION.replacen('l', "L", 1)).is_none());
+        assert!(Bolt11Invoice::decode(&format!("lnurl1{}", "q".repeat(117))).is_none());
+    }
+}
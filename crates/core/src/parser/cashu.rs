@@ -0,0 +1,421 @@
+//! Cashu ecash token decoding (NUT-00).
+//!
+//! Decodes V3 `cashuA` tokens (base64url JSON) and V4 `cashuB` tokens
+//! (base64url CBOR) into the mint, unit, memo and proofs a client shows
+//! before redeeming. Decoding says nothing about whether the proofs are
+//! still unspent; that needs the mint.
+
+use crate::types::proof::{DleqProof, Proof, Witness};
+#[cfg(feature = "crypto")]
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+/// Unit of tokens that do not name one.
+const DEFAULT_UNIT: &str = "sat";
+const MAX_CBOR_DEPTH: usize = 16;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CashuToken {
+    pub mint: String,
+    pub unit: String,
+    pub memo: Option<String>,
+    pub proofs: Vec<Proof>,
+}
+
+impl CashuToken {
+    /// Decodes a `cashuA` or `cashuB` token, with or without a `cashu:`
+    /// prefix. Tokens without proofs and V3 tokens spanning several mints
+    /// are rejected.
+    pub fn decode(token: &str) -> Option<Self> {
+        let token = match token.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("cashu:") => &token[6..],
+            _ => token,
+        };
+        let decoded = match token.get(..6)? {
+            "cashuA" => decode_v3(&base64_decode(&token[6..])?)?,
+            "cashuB" => decode_v4(&base64_decode(&token[6..])?)?,
+            _ => return None,
+        };
+        if decoded.mint.is_empty() || decoded.proofs.is_empty() {
+            return None;
+        }
+        Some(decoded)
+    }
+
+    /// Total value of the proofs, in `unit`.
+    pub fn amount(&self) -> u64 {
+        self.proofs
+            .iter()
+            .fold(0u64, |sum, proof| sum.saturating_add(proof.amount))
+    }
+
+    /// Checks the NUT-12 DLEQ proof of every proof offline. `keysets` maps a
+    /// keyset id to that keyset's amount -> hex public key map, as served by
+    /// the mint's `/v1/keys`. Proofs without a DLEQ proof fail.
+    #[cfg(feature = "crypto")]
+    pub fn verify_dleq(&self, keysets: &FxHashMap<String, FxHashMap<u64, String>>) -> bool {
+        self.proofs.iter().all(|proof| {
+            proof
+                .id
+                .as_ref()
+                .and_then(|id| keysets.get(id))
+                .is_some_and(|keys| crate::crypto::utils::verify_proof_dleq_with_keys(proof, keys))
+        })
+    }
+}
+
+/// V3: `{"token":[{"mint","proofs":[...]}],"unit","memo"}`.
+fn decode_v3(bytes: &[u8]) -> Option<CashuToken> {
+    let json: Value = serde_json::from_slice(bytes).ok()?;
+    let [entry] = json.get("token")?.as_array()?.as_slice() else {
+        return None;
+    };
+    let proofs = entry
+        .get("proofs")?
+        .as_array()?
+        .iter()
+        .map(proof_from_json)
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(CashuToken {
+        mint: entry.get("mint")?.as_str()?.to_string(),
+        unit: json
+            .get("unit")
+            .and_then(Value::as_str)
+            .unwrap_or(DEFAULT_UNIT)
+            .to_string(),
+        memo: json.get("memo").and_then(Value::as_str).map(str::to_string),
+        proofs,
+    })
+}
+
+fn proof_from_json(value: &Value) -> Option<Proof> {
+    let text = |key: &str| value.get(key)?.as_str().map(str::to_string);
+    let dleq = match value.get("dleq") {
+        None | Some(Value::Null) => None,
+        Some(dleq) => Some(DleqProof {
+            e: dleq.get("e")?.as_str()?.to_string(),
+            s: dleq.get("s")?.as_str()?.to_string(),
+            r: dleq.get("r").and_then(Value::as_str).map(str::to_string),
+        }),
+    };
+    // NUT-00 carries witnesses as serialized JSON strings
+    let witness = match value.get("witness") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(witness)) => Some(Witness::String(witness.clone())),
+        Some(_) => return None,
+    };
+
+    Some(Proof {
+        amount: value.get("amount")?.as_u64()?,
+        secret: text("secret")?,
+        c: text("C")?,
+        id: Some(text("id")?),
+        version: None,
+        dleq,
+        witness,
+    })
+}
+
+/// V4: `{"m": mint, "u": unit, "d": memo, "t": [{"i": keyset id, "p": [...]}]}`
+/// with keyset ids, `C` and DLEQ values as raw bytes.
+fn decode_v4(bytes: &[u8]) -> Option<CashuToken> {
+    let mut reader = CborReader { bytes, pos: 0 };
+    let token = reader.read(0)?;
+    if reader.pos != bytes.len() {
+        return None;
+    }
+
+    let mut proofs = Vec::new();
+    for entry in token.get("t")?.as_array()? {
+        let id = hex::encode(entry.get("i")?.as_bytes()?);
+        for proof in entry.get("p")?.as_array()? {
+            let dleq = match proof.get("d") {
+                None => None,
+                Some(dleq) => Some(DleqProof {
+                    e: hex::encode(dleq.get("e")?.as_bytes()?),
+                    s: hex::encode(dleq.get("s")?.as_bytes()?),
+                    r: dleq.get("r").and_then(Cbor::as_bytes).map(hex::encode),
+                }),
+            };
+            proofs.push(Proof {
+                amount: proof.get("a")?.as_u64()?,
+                secret: proof.get("s")?.as_text()?.to_string(),
+                c: hex::encode(proof.get("c")?.as_bytes()?),
+                id: Some(id.clone()),
+                version: None,
+                dleq,
+                witness: proof
+                    .get("w")
+                    .and_then(Cbor::as_text)
+                    .map(|w| Witness::String(w.to_string())),
+            });
+        }
+    }
+
+    Some(CashuToken {
+        mint: token.get("m")?.as_text()?.to_string(),
+        unit: token
+            .get("u")
+            .and_then(Cbor::as_text)
+            .unwrap_or(DEFAULT_UNIT)
+            .to_string(),
+        memo: token.get("d").and_then(Cbor::as_text).map(str::to_string),
+        proofs,
+    })
+}
+
+/// The CBOR data model subset V4 tokens use.
+enum Cbor {
+    Uint(u64),
+    Bytes(Vec<u8>),
+    Text(String),
+    Array(Vec<Cbor>),
+    Map(Vec<(Cbor, Cbor)>),
+    Simple,
+}
+
+impl Cbor {
+    /// Value under a text key of a map.
+    fn get(&self, key: &str) -> Option<&Cbor> {
+        match self {
+            Cbor::Map(entries) => entries
+                .iter()
+                .find(|(k, _)| k.as_text() == Some(key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_u64(&self) -> Option<u64> {
+        match self {
+            Cbor::Uint(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Cbor::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    fn as_text(&self) -> Option<&str> {
+        match self {
+            Cbor::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Cbor]> {
+        match self {
+            Cbor::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// Reader for definite-length CBOR (RFC 8949). Negative integers, floats and
+/// indefinite lengths do not occur in tokens and are rejected; tags are
+/// skipped.
+struct CborReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl CborReader<'_> {
+    fn take(&mut self, len: usize) -> Option<&[u8]> {
+        let end = self.pos.checked_add(len)?;
+        let slice = self.bytes.get(self.pos..end)?;
+        self.pos = end;
+        Some(slice)
+    }
+
+    /// Reads an item header: major type and argument.
+    fn header(&mut self) -> Option<(u8, u64)> {
+        let initial = *self.take(1)?.first()?;
+        let argument = match initial & 0x1f {
+            n @ 0..=23 => u64::from(n),
+            24 => u64::from(self.take(1)?[0]),
+            25 => u64::from(u16::from_be_bytes(self.take(2)?.try_into().ok()?)),
+            26 => u64::from(u32::from_be_bytes(self.take(4)?.try_into().ok()?)),
+            27 => u64::from_be_bytes(self.take(8)?.try_into().ok()?),
+            _ => return None,
+        };
+        Some((initial >> 5, argument))
+    }
+
+    /// Length argument, bounded by the remaining input so a hostile header
+    /// cannot force a huge allocation.
+    fn length(&self, argument: u64) -> Option<usize> {
+        usize::try_from(argument)
+            .ok()
+            .filter(|len| *len <= self.bytes.len() - self.pos)
+    }
+
+    fn read(&mut self, depth: usize) -> Option<Cbor> {
+        if depth > MAX_CBOR_DEPTH {
+            return None;
+        }
+        let (major, argument) = self.header()?;
+        match major {
+            0 => Some(Cbor::Uint(argument)),
+            2 => {
+                let len = self.length(argument)?;
+                Some(Cbor::Bytes(self.take(len)?.to_vec()))
+            }
+            3 => {
+                let len = self.length(argument)?;
+                let text = std::str::from_utf8(self.take(len)?).ok()?;
+                Some(Cbor::Text(text.to_string()))
+            }
+            4 => {
+                let len = self.length(argument)?;
+                let items = (0..len)
+                    .map(|_| self.read(depth + 1))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Cbor::Array(items))
+            }
+            5 => {
+                let len = self.length(argument)?;
+                let entries = (0..len)
+                    .map(|_| Some((self.read(depth + 1)?, self.read(depth + 1)?)))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Cbor::Map(entries))
+            }
+            6 => self.read(depth + 1),
+            // false, true, null, undefined
+            7 if (20..=23).contains(&argument) => Some(Cbor::Simple),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes base64 in either the standard or URL-safe alphabet, with or
+/// without padding.
+fn base64_decode(data: &str) -> Option<Vec<u8>> {
+    let data = data.trim_end_matches('=');
+    let mut out = Vec::with_capacity(data.len() * 3 / 4);
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    for byte in data.bytes() {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None,
+        };
+        acc = (acc << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    // A single trailing character carries fewer than 8 bits
+    (bits < 6).then_some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // NUT-00 test vectors
+    const V3: &str = concat!(
+        "cashuAeyJ0b2tlbiI6W3sibWludCI6Imh0dHBzOi8vODMzMy5zcGFjZTozMzM4IiwicHJvb2ZzIjpbeyJh",
+        "bW91bnQiOjIsImlkIjoiMDA5YTFmMjkzMjUzZTQxZSIsInNlY3JldCI6IjQwNzkxNWJjMjEyYmU2MWE3",
+        "N2UzZTZkMmFlYjRjNzI3OTgwYmRhNTFjZDA2YTZhZmMyOWUyODYxNzY4YTc4MzciLCJDIjoiMDJiYzkw",
+        "OTc5OTdkODFhZmIyY2M3MzQ2YjVlNDM0NWE5MzQ2YmQyYTUwNmViNzk1ODU5OGE3MmYwY2Y4NTE2M2Vh",
+        "In0seyJhbW91bnQiOjgsImlkIjoiMDA5YTFmMjkzMjUzZTQxZSIsInNlY3JldCI6ImZlMTUxMDkzMTRl",
+        "NjFkNzc1NmIwZjhlZTBmMjNhNjI0YWNhYTNmNGUwNDJmNjE0MzNjNzI4YzcwNTdiOTMxYmUiLCJDIjoi",
+        "MDI5ZThlNTA1MGI4OTBhN2Q2YzA5NjhkYjE2YmMxZDVkNWZhMDQwZWExZGUyODRmNmVjNjlkNjEyOTlm",
+        "NjcxMDU5In1dfV0sInVuaXQiOiJzYXQiLCJtZW1vIjoiVGhhbmsgeW91IHZlcnkgbXVjaC4ifQ",
+    );
+    const V4: &str = concat!(
+        "cashuBpGF0gaJhaUgArSaMTR9YJmFwgaNhYQFhc3hAOWE2ZGJiODQ3YmQyMzJiYTc2ZGIwZGYxOTcyMTZi",
+        "MjlkM2I4Y2MxNDU1M2NkMjc4MjdmYzFjYzk0MmZlZGI0ZWFjWCEDhhhUP_trhpXfStS6vN6So0qWvc2X3O4N",
+        "fM-Y1HISZ5JhZGlUaGFuayB5b3VhbXVodHRwOi8vbG9jYWxob3N0OjMzMzhhdWNzYXQ=",
+    );
+    // One proof with the NUT-12 test vector DLEQ proof
+    #[cfg(feature = "crypto")]
+    const DLEQ_V3: &str = concat!(
+        "cashuAeyJ0b2tlbiI6W3sibWludCI6Imh0dHBzOi8vbWludC5leGFtcGxlIiwicHJvb2ZzIjpbeyJhbW",
+        "91bnQiOjEsImlkIjoiMDA4ODI3NjBiZmEyZWI0MSIsInNlY3JldCI6ImRhZjRkZDAwYTJiNjhhMDg1OG",
+        "E4MDQ1MGY1MmM4YTdkMmNjZjg3ZDM3NWU0M2UyMTZlMGM1NzFmMDg5ZjYzZTkiLCJDIjoiMDI0MzY5ZD",
+        "JkMjJhODBlY2Y3OGYzOTM3ZGE5ZDVmMzBjMWI5Zjc0ZjBjMzI2ODRkNTgzY2NhMGZhNmE2MWNkY2ZjIi",
+        "wiZGxlcSI6eyJlIjoiYjMxZTU4YWM2NTI3ZjM0OTc1ZmZhYjEzZTcwYTQ4YjZkMmIwZDM1YWJjNGIwM2",
+        "YwMTUxZjA5ZWUxYTk3NjNkNCIsInMiOiI4ZmJhZTAwNGM1OWU3NTRkNzFkZjY3ZTM5MmI2YWU0ZTI5Mj",
+        "kzMTEzZGRjMmVjODY1OTJhMDQzMWQxNjMwNmQ4IiwiciI6ImE2ZDEzZmNkN2ExODQ0MmU2MDc2ZjVlMW",
+        "U3Yzg4N2FkNWRlNDBhMDE5ODI0YmRmYTlmZTc0MGQzMDJlOGQ4NjEifX1dfV19",
+    );
+
+    #[test]
+    fn decodes_v3_tokens() {
+        let token = CashuToken::decode(V3).unwrap();
+        assert_eq!(token.mint, "https://8333.space:3338");
+        assert_eq!(token.unit, "sat");
+        assert_eq!(token.memo.as_deref(), Some("Thank you very much."));
+        assert_eq!(token.proofs.len(), 2);
+        assert_eq!(token.amount(), 10);
+        assert_eq!(token.proofs[0].id.as_deref(), Some("009a1f293253e41e"));
+        assert_eq!(
+            token.proofs[1].c,
+            "029e8e5050b890a7d6c0968db16bc1d5d5fa040ea1de284f6ec69d61299f671059"
+        );
+    }
+
+    #[test]
+    fn decodes_v4_tokens() {
+        let token = CashuToken::decode(V4).unwrap();
+        assert_eq!(token.mint, "http://localhost:3338");
+        assert_eq!(token.unit, "sat");
+        assert_eq!(token.memo.as_deref(), Some("Thank you"));
+        assert_eq!(token.amount(), 1);
+        let proof = &token.proofs[0];
+        assert_eq!(proof.id.as_deref(), Some("00ad268c4d1f5826"));
+        assert_eq!(
+            proof.secret,
+            "9a6dbb847bd232ba76db0df197216b29d3b8cc14553cd27827fc1cc942fedb4e"
+        );
+        assert_eq!(
+            proof.c,
+            "038618543ffb6b8695df4ad4babcde92a34a96bdcd97dcee0d7ccf98d472126792"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_tokens() {
+        assert!(CashuToken::decode("cashuA").is_none());
+        assert!(CashuToken::decode("cashuAe30").is_none()); // {}
+        assert!(CashuToken::decode(&V3[..V3.len() - 8]).is_none());
+        assert!(CashuToken::decode(&V4[..V4.len() - 8]).is_none());
+        assert!(CashuToken::decode("cashuCabcd").is_none());
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn verifies_dleq_proofs() {
+        let token = CashuToken::decode(DLEQ_V3).unwrap();
+        assert_eq!(token.unit, "sat");
+        assert_eq!(token.memo, None);
+
+        let mint_key = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let mut keysets = FxHashMap::default();
+        keysets.insert(
+            "00882760bfa2eb41".to_string(),
+            FxHashMap::from_iter([(1, mint_key.to_string())]),
+        );
+        assert!(token.verify_dleq(&keysets));
+
+        keysets.insert(
+            "00882760bfa2eb41".to_string(),
+            FxHashMap::from_iter([(2, mint_key.to_string())]),
+        );
+        assert!(!token.verify_dleq(&keysets));
+        assert!(!CashuToken::decode(V3).unwrap().verify_dleq(&keysets));
+    }
+}
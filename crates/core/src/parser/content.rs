@@ -1,6 +1,9 @@
+use crate::parser::bolt11::Bolt11Invoice;
+use crate::parser::cashu::CashuToken;
 use crate::parser::Result;
 use crate::types::nostr::nips::nip19::{self, Nip19};
 use crate::types::ParserError;
+pub use crate::link_preview::LinkPreview;
 use regex::Regex;
 use std::sync::LazyLock;
 
@@ -55,13 +58,17 @@ pub enum ContentData {
         author: Option<String>,
         kind: Option<u64>,
     },
+    /// A decoded V3 or V4 Cashu token. Whether its proofs are unspent is
+    /// only known to the mint.
     Cashu {
         token: String,
+        decoded: CashuToken,
     },
-    /// A structurally well-formed BOLT11 candidate. Payment code must still
-    /// authenticate its signature and validate expiry, features, and policy.
+    /// A decoded BOLT11 invoice. Payment code must still check expiry,
+    /// features, and policy.
     Lightning {
         invoice: String,
+        decoded: Bolt11Invoice,
     },
     Emoji {
         shortcode: String,
@@ -174,7 +181,7 @@ fn contains_any_ascii_case_insensitive(text: &str, needles: &[&str]) -> bool {
 // Static content patterns, compiled once instead of per event
 static CODE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"```([\s\S]*?)```").unwrap());
 static CASHU_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"(cashuA[A-Za-z0-9_-]+)").unwrap());
+    LazyLock::new(|| Regex::new(r"(cashu[AB][A-Za-z0-9_-]+={0,2})").unwrap());
 static LIGHTNING_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
         r"(?i-u:\b(?:lightning:)?ln(?:bcrt|tbs|tb|bc)(?:[1-9][0-9]*[munp]?)?1[023456789ac-hj-np-z]{117,}\b)",
@@ -209,7 +216,7 @@ static PATTERNS: &[Pattern] = &[
         name: "cashu",
         regex: &CASHU_RE,
         processor: process_cashu,
-        required: &["cashuA"],
+        required: &["cashuA", "cashuB"],
         ignore_case: false,
     },
     Pattern {
@@ -789,232 +796,26 @@ fn process_code(text: &str, caps: &regex::Captures) -> Result<ContentBlock> {
 }
 
 fn process_cashu(text: &str, _caps: &regex::Captures) -> Result<ContentBlock> {
+    let Some(decoded) = CashuToken::decode(text) else {
+        return Err(ParserError::InvalidContent(
+            "invalid Cashu token".to_string(),
+        ));
+    };
+
     Ok(
         ContentBlock::new("cashu".to_string(), text.to_string()).with_data(ContentData::Cashu {
             token: text.to_string(),
+            decoded,
         }),
     )
 }
 
-const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
-
-fn bech32_value(byte: u8) -> Option<u8> {
-    BECH32_CHARSET
-        .iter()
-        .position(|&candidate| candidate == byte.to_ascii_lowercase())
-        .map(|index| index as u8)
-}
-
-fn bech32_polymod_step(mut checksum: u32, value: u8) -> u32 {
-    const GENERATORS: [u32; 5] = [
-        0x3b6a_57b2,
-        0x2650_8e6d,
-        0x1ea1_19fa,
-        0x3d42_33dd,
-        0x2a14_62b3,
-    ];
-
-    let top = checksum >> 25;
-    checksum = ((checksum & 0x01ff_ffff) << 5) ^ u32::from(value);
-    for (bit, generator) in GENERATORS.iter().enumerate() {
-        if (top >> bit) & 1 != 0 {
-            checksum ^= generator;
-        }
-    }
-    checksum
-}
-
-fn is_valid_bolt11_hrp(hrp: &[u8]) -> bool {
-    let normalized: Vec<u8> = hrp.iter().map(u8::to_ascii_lowercase).collect();
-    let Some(network_and_amount) = normalized.strip_prefix(b"ln") else {
-        return false;
-    };
-    let amount = if let Some(amount) = network_and_amount.strip_prefix(b"bcrt") {
-        amount
-    } else if let Some(amount) = network_and_amount.strip_prefix(b"tbs") {
-        amount
-    } else if let Some(amount) = network_and_amount.strip_prefix(b"tb") {
-        amount
-    } else if let Some(amount) = network_and_amount.strip_prefix(b"bc") {
-        amount
-    } else {
-        return false;
-    };
-
-    if amount.is_empty() {
-        return true;
-    }
-
-    let (digits, multiplier) = match amount.last() {
-        Some(b'm') => (&amount[..amount.len() - 1], 1_000_000_000u64),
-        Some(b'u') => (&amount[..amount.len() - 1], 1_000_000u64),
-        Some(b'n') => (&amount[..amount.len() - 1], 1_000u64),
-        Some(b'p') => (&amount[..amount.len() - 1], 1u64),
-        _ => (amount, 1_000_000_000_000u64),
-    };
-    if digits.is_empty() || digits[0] == b'0' || !digits.iter().all(u8::is_ascii_digit) {
-        return false;
-    }
-
-    let Ok(raw_amount) = std::str::from_utf8(digits).unwrap_or_default().parse::<u64>() else {
-        return false;
-    };
-    raw_amount
-        .checked_mul(multiplier)
-        .is_some_and(|pico_btc| pico_btc % 10 == 0)
-}
-
-fn is_valid_secp256k1_scalar(scalar: &[u8]) -> bool {
-    const CURVE_ORDER: [u8; 32] = [
-        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-        0xff, 0xfe, 0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c,
-        0xd0, 0x36, 0x41, 0x41,
-    ];
-
-    scalar.iter().any(|&byte| byte != 0) && scalar < CURVE_ORDER.as_slice()
-}
-
-/// Validate the BOLT11 envelope without doing payment-time policy checks.
-///
-/// This verifies uniform Bech32 case, checksum, tagged-field framing, the
-/// mandatory payment hash and description/hash field, and the 65-byte
-/// signature shape. A wallet must still verify the signature, features,
-/// expiry, and payment policy before attempting payment.
-fn is_well_formed_bolt11(invoice: &str) -> bool {
-    let bytes = invoice.as_bytes();
-    if !bytes.is_ascii() {
-        return false;
-    }
-
-    let has_lowercase = bytes.iter().any(u8::is_ascii_lowercase);
-    let has_uppercase = bytes.iter().any(u8::is_ascii_uppercase);
-    if has_lowercase && has_uppercase {
-        return false;
-    }
-
-    let Some(separator) = bytes.iter().rposition(|&byte| byte == b'1') else {
-        return false;
-    };
-    if separator == 0 || separator + 1 >= bytes.len() {
-        return false;
-    }
-
-    let (hrp, separator_and_data) = bytes.split_at(separator);
-    let data = &separator_and_data[1..];
-    if !is_valid_bolt11_hrp(hrp) {
-        return false;
-    }
-    // 7 timestamp words + 104 signature words + 6 checksum words.
-    if data.len() < 117 {
-        return false;
-    }
-
-    let mut checksum = 1u32;
-    for &byte in hrp {
-        checksum = bech32_polymod_step(checksum, byte.to_ascii_lowercase() >> 5);
-    }
-    checksum = bech32_polymod_step(checksum, 0);
-    for &byte in hrp {
-        checksum = bech32_polymod_step(checksum, byte.to_ascii_lowercase() & 0x1f);
-    }
-    for &byte in data {
-        let Some(value) = bech32_value(byte) else {
-            return false;
-        };
-        checksum = bech32_polymod_step(checksum, value);
-    }
-    if checksum != 1 {
-        return false;
-    }
-
-    let tagged_end = data.len() - 6 - 104;
-    if tagged_end < 7 {
-        return false;
-    }
-
-    let mut index = 7;
-    let mut payment_hashes = 0u8;
-    let mut descriptions = 0u8;
-    while index < tagged_end {
-        if index + 3 > tagged_end {
-            return false;
-        }
-        let Some(tag) = bech32_value(data[index]) else {
-            return false;
-        };
-        let (Some(length_high), Some(length_low)) =
-            (bech32_value(data[index + 1]), bech32_value(data[index + 2]))
-        else {
-            return false;
-        };
-        let field_length = (usize::from(length_high) << 5) | usize::from(length_low);
-        index += 3;
-        let Some(field_end) = index.checked_add(field_length) else {
-            return false;
-        };
-        if field_end > tagged_end {
-            return false;
-        }
-
-        match tag {
-            1 => {
-                if field_length != 52
-                    || bech32_value(data[field_end - 1]).map_or(true, |value| value & 0x0f != 0)
-                {
-                    return false;
-                }
-                payment_hashes = payment_hashes.saturating_add(1);
-            }
-            13 => descriptions = descriptions.saturating_add(1),
-            23 => {
-                if field_length != 52
-                    || bech32_value(data[field_end - 1]).map_or(true, |value| value & 0x0f != 0)
-                {
-                    return false;
-                }
-                descriptions = descriptions.saturating_add(1);
-            }
-            _ => {}
-        }
-        index = field_end;
-    }
-    if payment_hashes != 1 || descriptions != 1 {
-        return false;
-    }
-
-    // The final 104 data words before the checksum encode a 65-byte compact
-    // signature. Its last byte is the recovery id and must be 0..=3.
-    let signature = &data[tagged_end..data.len() - 6];
-    let mut accumulator = 0u32;
-    let mut bits = 0u8;
-    let mut signature_bytes = [0u8; 65];
-    let mut byte_count = 0usize;
-    for &encoded in signature {
-        let Some(value) = bech32_value(encoded) else {
-            return false;
-        };
-        accumulator = (accumulator << 5) | u32::from(value);
-        bits += 5;
-        if bits >= 8 {
-            bits -= 8;
-            let decoded = ((accumulator >> bits) & 0xff) as u8;
-            if byte_count >= signature_bytes.len() {
-                return false;
-            }
-            signature_bytes[byte_count] = decoded;
-            byte_count += 1;
-            accumulator &= (1u32 << bits) - 1;
-        }
-    }
-
-    byte_count == 65
-        && bits == 0
-        && signature_bytes[64] <= 3
-        && is_valid_secp256k1_scalar(&signature_bytes[..32])
-        && is_valid_secp256k1_scalar(&signature_bytes[32..64])
-}
-
 fn process_lightning(text: &str, _caps: &regex::Captures) -> Result<ContentBlock> {
+    let Some(decoded) = Bolt11Invoice::decode(text) else {
+        return Err(ParserError::InvalidContent(
+            "invalid BOLT11 invoice".to_string(),
+        ));
+    };
     let invoice = if text
         .get(..10)
         .is_some_and(|prefix| prefix.eq_ignore_ascii_case("lightning:"))
@@ -1024,16 +825,11 @@ fn process_lightning(text: &str, _caps: &regex::Captures) -> Result<ContentBlock
         text
     };
 
-    if !is_well_formed_bolt11(invoice) {
-        return Err(ParserError::InvalidContent(
-            "invalid BOLT11 invoice".to_string(),
-        ));
-    }
-
     Ok(
         ContentBlock::new("lightning".to_string(), text.to_string()).with_data(
             ContentData::Lightning {
                 invoice: invoice.to_string(),
+                decoded,
             },
         ),
     )
@@ -1089,65 +885,67 @@ fn process_nostr(text: &str, _caps: &regex::Captures) -> Result<ContentBlock> {
         text
     };
 
-    // Try to decode the identifier
-    match nip19::FromBech32::from_bech32(entity_str) {
-        Ok(decoded) => {
-            let (prefix, id, relays, author, kind) = match decoded {
-                Nip19::Pubkey(pk) => (
-                    "npub",
-                    pk.to_string(),
-                    Vec::new(),
-                    Some(pk.to_string()),
-                    None,
-                ),
-                Nip19::EventId(note) => ("note", note.to_string(), Vec::new(), None, None),
-                Nip19::Profile(profile) => (
-                    "nprofile",
-                    profile.public_key.to_string(),
-                    profile.relays.into_iter().map(|r| r.to_string()).collect(),
-                    Some(profile.public_key.to_string()),
-                    None,
-                ),
-                Nip19::Event(event) => (
-                    "nevent",
-                    event.event_id.to_string(),
-                    event.relays.into_iter().map(|r| r.to_string()).collect(),
-                    event.author.map(|pk| pk.to_string()),
-                    None,
-                ),
-                Nip19::Coordinate(coord) => (
-                    "naddr",
-                    format!(
-                        "{}:{}:{}",
-                        coord.kind,
-                        coord.public_key.to_string(),
-                        coord.identifier
-                    ),
-                    coord.relays.into_iter().map(|r| r.to_string()).collect(),
-                    Some(coord.public_key.to_string()),
-                    Some(coord.kind as u64),
-                ),
-            };
-
-            Ok(
-                ContentBlock::new(prefix.to_string(), text.to_string()).with_data(
-                    ContentData::Nostr {
-                        entity: entity_str.to_string(),
-                        data: Some(id),
-                        relays,
-                        author,
-                        kind,
-                    },
-                ),
-            )
-        }
-        Err(_) => {
-            // If we can't decode, treat as text
-            Ok(ContentBlock::new("text".to_string(), text.to_string()))
+    match decode_nostr_entity(entity_str) {
+        Some((prefix, data)) => {
+            Ok(ContentBlock::new(prefix.to_string(), text.to_string()).with_data(data))
         }
+        // If we can't decode, treat as text
+        None => Ok(ContentBlock::new("text".to_string(), text.to_string())),
     }
 }
 
+/// Decodes a bare NIP-19 entity (no `nostr:` prefix) into its block type
+/// and `ContentData::Nostr`.
+pub fn decode_nostr_entity(entity_str: &str) -> Option<(&'static str, ContentData)> {
+    let decoded = nip19::FromBech32::from_bech32(entity_str).ok()?;
+    let (prefix, id, relays, author, kind) = match decoded {
+        Nip19::Pubkey(pk) => (
+            "npub",
+            pk.to_string(),
+            Vec::new(),
+            Some(pk.to_string()),
+            None,
+        ),
+        Nip19::EventId(note) => ("note", note.to_string(), Vec::new(), None, None),
+        Nip19::Profile(profile) => (
+            "nprofile",
+            profile.public_key.to_string(),
+            profile.relays.into_iter().map(|r| r.to_string()).collect(),
+            Some(profile.public_key.to_string()),
+            None,
+        ),
+        Nip19::Event(event) => (
+            "nevent",
+            event.event_id.to_string(),
+            event.relays.into_iter().map(|r| r.to_string()).collect(),
+            event.author.map(|pk| pk.to_string()),
+            None,
+        ),
+        Nip19::Coordinate(coord) => (
+            "naddr",
+            format!(
+                "{}:{}:{}",
+                coord.kind,
+                coord.public_key.to_string(),
+                coord.identifier
+            ),
+            coord.relays.into_iter().map(|r| r.to_string()).collect(),
+            Some(coord.public_key.to_string()),
+            Some(coord.kind as u64),
+        ),
+    };
+    Some((
+        prefix,
+        ContentData::Nostr {
+            entity: entity_str.to_string(),
+            data: Some(id),
+            relays,
+            author,
+            kind,
+        },
+    ))
+}
+
 fn process_link(text: &str, _caps: &regex::Captures) -> Result<ContentBlock> {
     let url = if text
         .get(..4)
@@ -1158,39 +956,21 @@ fn process_link(text: &str, _caps: &regex::Captures) -> Result<ContentBlock> {
         format!("https://{}", text)
     };
 
-    // Placeholder preview fields (a real preview is not implemented); the
-    // proxy URL that LinkPreview::new builds is never read, so skip it.
+    // Only the URL here; the parser worker sends previews as separate
+    // messages to `link_previews` subscriptions.
+    let preview = LinkPreview::new(&url);
     Ok(
         ContentBlock::new("link".to_string(), text.to_string()).with_data(
             ContentData::LinkPreview {
-                url,
-                title: Some("Link Preview".to_string()),
-                description: Some("Link preview not implemented".to_string()),
-                image: None,
+                url: preview.url,
+                title: preview.title,
+                description: preview.description,
+                image: preview.image,
             },
         ),
     )
 }
 
-#[derive(Debug, Clone)]
-pub struct LinkPreview {
-    pub url: String,
-    pub title: Option<String>,
-    pub description: Option<String>,
-    pub image: Option<String>,
-}
-
-impl LinkPreview {
-    pub fn new(url: &str) -> Self {
-        Self {
-            url: format!("https://proxy.nuts.cash/?url={}", url),
-            title: Some("Link Preview".to_string()),
-            description: Some("Link preview not implemented".to_string()),
-            image: None,
-        }
-    }
-}
-
 fn process_emoji_placeholder(_text: &str, _caps: &regex::Captures) -> Result<ContentBlock> {
     // Placeholder - actual processing happens in parse_content_with_emojis
     // This should never be called directly
@@ -1398,22 +1178,55 @@ pub fn serialize_content_data<'a, A: flatbuffers::Allocator + 'a>(
             );
             (fb::ContentData::NostrData, Some(nostr_fb.as_union_value()))
         }
-        ContentData::Cashu { token } => {
+        ContentData::Cashu { token, decoded } => {
             let token_off = builder.create_string(token);
+            let mint_off = builder.create_string(&decoded.mint);
+            let unit_off = builder.create_string(&decoded.unit);
+            let memo_off = decoded.memo.as_ref().map(|m| builder.create_string(m));
+            let proof_offs: Vec<_> = decoded
+                .proofs
+                .iter()
+                .map(|proof| proof.to_offset(builder))
+                .collect();
+            let proofs_off = builder.create_vector(&proof_offs);
             let cashu_fb = fb::CashuData::create(
                 builder,
                 &fb::CashuDataArgs {
                     token: Some(token_off),
+                    mint: Some(mint_off),
+                    unit: Some(unit_off),
+                    memo: memo_off,
+                    amount: decoded.amount(),
+                    proofs: Some(proofs_off),
                 },
             );
             (fb::ContentData::CashuData, Some(cashu_fb.as_union_value()))
         }
-        ContentData::Lightning { invoice } => {
+        ContentData::Lightning { invoice, decoded } => {
             let invoice_off = builder.create_string(invoice);
+            let network_off = builder.create_string(&decoded.network);
+            let description_off = decoded
+                .description
+                .as_ref()
+                .map(|d| builder.create_string(d));
+            let description_hash_off = decoded
+                .description_hash
+                .as_ref()
+                .map(|h| builder.create_string(h));
+            let payment_hash_off = builder.create_string(&decoded.payment_hash);
+            let payee_off = decoded.payee.as_ref().map(|p| builder.create_string(p));
             let lightning_fb = fb::LightningData::create(
                 builder,
                 &fb::LightningDataArgs {
                     invoice: Some(invoice_off),
+                    network: Some(network_off),
+                    amount_msats: decoded.amount_msats.unwrap_or(0),
+                    timestamp: decoded.timestamp,
+                    expiry: decoded.expiry,
+                    description: description_off,
+                    description_hash: description_hash_off,
+                    payment_hash: Some(payment_hash_off),
+                    payee: payee_off,
                 },
             );
             (
@@ -1625,6 +1438,7 @@ mod tests {
             block.data,
             Some(ContentData::Lightning {
                 invoice: VALID_BOLT11.to_string(),
+                decoded: Bolt11Invoice::decode(VALID_BOLT11).unwrap(),
             })
         );
     }
@@ -1638,7 +1452,11 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].block_type, "lightning");
         assert_eq!(result[0].text, uri);
-        assert_eq!(result[0].data, Some(ContentData::Lightning { invoice }));
+        let decoded = Bolt11Invoice::decode(&invoice).unwrap();
+        assert_eq!(
+            result[0].data,
+            Some(ContentData::Lightning { invoice, decoded })
+        );
     }
 
     #[test]
@@ -1651,6 +1469,7 @@ mod tests {
             result[0].data,
             Some(ContentData::Lightning {
                 invoice: VALID_BOLT11_DESCRIPTION_HASH.to_string(),
+                decoded: Bolt11Invoice::decode(VALID_BOLT11_DESCRIPTION_HASH).unwrap(),
             })
         );
     }
@@ -1669,12 +1488,54 @@ mod tests {
         let view = flatbuffers::root::<fb::ContentBlock>(builder.finished_data())
             .expect("valid serialized ContentBlock");
         assert_eq!(view.data_type(), fb::ContentData::LightningData);
-        assert_eq!(
-            view.data_as_lightning_data()
-                .expect("LightningData payload")
-                .invoice(),
-            VALID_BOLT11
+        let lightning = view
+            .data_as_lightning_data()
+            .expect("LightningData payload");
+        assert_eq!(lightning.invoice(), VALID_BOLT11);
+        assert_eq!(lightning.network(), Some("bitcoin"));
+        assert_eq!(lightning.amount_msats(), 0);
+        assert_eq!(lightning.timestamp(), 1_496_314_658);
+        assert_eq!(lightning.expiry(), 3600);
+    }
+
+    #[test]
+    fn test_cashu_parse_serialize_flatbuffer_round_trip() {
+        const TOKEN: &str = concat!(
+            "cashuBpGF0gaJhaUgArSaMTR9YJmFwgaNhYQFhc3hAOWE2ZGJiODQ3YmQyMzJiYTc2ZGIwZGYxOTcyMTZi",
+            "MjlkM2I4Y2MxNDU1M2NkMjc4MjdmYzFjYzk0MmZlZGI0ZWFjWCEDhhhUP_trhpXfStS6vN6So0qWvc2X3O4N",
+            "fM-Y1HISZ5JhZGlUaGFuayB5b3VhbXVodHRwOi8vbG9jYWxob3N0OjMzMzhhdWNzYXQ=",
         );
+        let content = format!("Here you go: {TOKEN} enjoy");
+        let result = parse_content(&content, &[]).unwrap();
+        let cashu = result
+            .iter()
+            .find(|block| block.block_type == "cashu")
+            .expect("parsed Cashu block");
+        assert_eq!(cashu.text, TOKEN);
+
+        let mut builder = FlatBufferBuilder::new();
+        let block = serialize_content_block(&mut builder, cashu);
+        builder.finish(block, None);
+
+        let view = flatbuffers::root::<fb::ContentBlock>(builder.finished_data())
+            .expect("valid serialized ContentBlock");
+        let cashu = view.data_as_cashu_data().expect("CashuData payload");
+        assert_eq!(cashu.token(), TOKEN);
+        assert_eq!(cashu.mint(), Some("http://localhost:3338"));
+        assert_eq!(cashu.unit(), Some("sat"));
+        assert_eq!(cashu.memo(), Some("Thank you"));
+        assert_eq!(cashu.amount(), 1);
+        let proofs = cashu.proofs().expect("proofs");
+        assert_eq!(proofs.len(), 1);
+        assert_eq!(proofs.get(0).id(), "00ad268c4d1f5826");
+    }
+
+    #[test]
+    fn test_does_not_parse_malformed_cashu_token() {
+        let result = parse_content("cashuAnotatoken", &[]).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].block_type, "text");
     }
 
     #[test]
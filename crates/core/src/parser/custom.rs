@@ -0,0 +1,241 @@
+//! Parsers for app-specific kinds, registered by the embedding crate.
+//!
+//! A `KindParser` turns an event into a FlatBuffer payload built against
+//! the app's own schema. The payload travels as `CustomParsed` in the
+//! `ParsedData` union, tagged with the parser's `schema_id`, so it is cached
+//! by `SaveToDbPipe` and served back from the cache without the engine
+//! knowing its layout. Registered parsers are consulted before the built-in
+//! ones, so they can also take over a kind the engine already parses.
+
+use crate::generated::nostr::fb;
+use crate::parser::Result;
+use crate::types::{network::Request, nostr::Kind, Event};
+use std::ops::RangeInclusive;
+use std::sync::{Arc, RwLock};
+
+pub trait KindParser: Send + Sync {
+    /// Names the payload's schema for readers, e.g. `"com.example.recipe/1"`.
+    fn schema_id(&self) -> &str;
+
+    /// A finished FlatBuffer for `event`, plus any follow-up requests.
+    /// An error drops the event, as it does for built-in kinds.
+    fn parse(&self, event: &Event) -> Result<(Vec<u8>, Option<Vec<Request>>)>;
+}
+
+type Registration = (RangeInclusive<Kind>, Arc<dyn KindParser>);
+
+/// Output of a `KindParser`.
+pub struct CustomParsed {
+    pub schema_id: String,
+    pub payload: Vec<u8>,
+}
+
+/// Cheap-to-clone handle over the registered parsers, shared by every
+/// `Parser` built with it. Registrations apply to events parsed afterwards.
+#[derive(Clone, Default)]
+pub struct CustomParsers {
+    entries: Arc<RwLock<Vec<Registration>>>,
+}
+
+impl CustomParsers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse `kinds` with `parser`. Later registrations win where ranges
+    /// overlap.
+    pub fn register(&self, kinds: RangeInclusive<Kind>, parser: Arc<dyn KindParser>) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.push((kinds, parser));
+        }
+    }
+
+    pub fn register_kind(&self, kind: Kind, parser: Arc<dyn KindParser>) {
+        self.register(kind..=kind, parser);
+    }
+
+    /// Drops every registration covering `kind`.
+    pub fn unregister(&self, kind: Kind) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.retain(|(kinds, _)| !kinds.contains(&kind));
+        }
+    }
+
+    pub fn get(&self, kind: Kind) -> Option<Arc<dyn KindParser>> {
+        let entries = self.entries.read().ok()?;
+        entries
+            .iter()
+            .rev()
+            .find(|(kinds, _)| kinds.contains(&kind))
+            .map(|(_, parser)| parser.clone())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().map(|e| e.is_empty()).unwrap_or(true)
+    }
+}
+
+pub fn build_flatbuffer<'a, A: flatbuffers::Allocator + 'a>(
+    parsed: &CustomParsed,
+    builder: &mut flatbuffers::FlatBufferBuilder<'a, A>,
+) -> Result<flatbuffers::WIPOffset<fb::CustomParsed<'a>>> {
+    let schema_id = builder.create_string(&parsed.schema_id);
+    let payload = builder.create_vector(&parsed.payload);
+    Ok(fb::CustomParsed::create(
+        builder,
+        &fb::CustomParsedArgs {
+            schema_id: Some(schema_id),
+            payload: Some(payload),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use crate::parser_types::parsed_event::ParsedData;
+    use crate::types::nostr::{EventId, PublicKey};
+    use crate::types::ParserError;
+
+    /// Stands in for an app schema: the event's "t" tags as a StringVec.
+    struct Topics;
+
+    impl KindParser for Topics {
+        fn schema_id(&self) -> &str {
+            "test.topics/1"
+        }
+
+        fn parse(&self, event: &Event) -> Result<(Vec<u8>, Option<Vec<Request>>)> {
+            let mut builder = flatbuffers::FlatBufferBuilder::new();
+            let topics: Vec<_> = event
+                .tags
+                .iter()
+                .filter(|tag| tag.len() >= 2 && tag[0] == "t")
+                .map(|tag| builder.create_string(&tag[1]))
+                .collect();
+            if topics.is_empty() {
+                return Err(ParserError::MissingField("t".to_string()));
+            }
+            let items = builder.create_vector(&topics);
+            let root =
+                fb::StringVec::create(&mut builder, &fb::StringVecArgs { items: Some(items) });
+            builder.finish(root, None);
+            Ok((builder.finished_data().to_vec(), None))
+        }
+    }
+
+    struct Named(&'static str);
+
+    impl KindParser for Named {
+        fn schema_id(&self) -> &str {
+            self.0
+        }
+
+        fn parse(&self, _event: &Event) -> Result<(Vec<u8>, Option<Vec<Request>>)> {
+            Ok((Vec::new(), None))
+        }
+    }
+
+    fn event(kind: Kind, tags: Vec<Vec<String>>) -> Event {
+        Event {
+            id: EventId([1; 32]),
+            pubkey: PublicKey([2; 32]),
+            created_at: 1_700_000_000,
+            kind,
+            tags,
+            content: String::new(),
+            sig: hex::encode([4; 64]),
+        }
+    }
+
+    fn topics(payload: &[u8]) -> Vec<String> {
+        let items = flatbuffers::root::<fb::StringVec>(payload)
+            .unwrap()
+            .items()
+            .unwrap();
+        items.iter().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn later_registrations_win_where_ranges_overlap() {
+        let parsers = CustomParsers::new();
+        assert!(parsers.is_empty());
+        parsers.register(30500..=30599, Arc::new(Named("range")));
+        parsers.register_kind(30555, Arc::new(Named("single")));
+
+        assert_eq!(parsers.get(30500).unwrap().schema_id(), "range");
+        assert_eq!(parsers.get(30555).unwrap().schema_id(), "single");
+        assert!(parsers.get(30600).is_none());
+
+        parsers.unregister(30555);
+        assert!(parsers.get(30501).is_none());
+        assert!(parsers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn parser_uses_registered_parser() {
+        let parsers = CustomParsers::new();
+        let parser = Parser::new(None).with_custom_parsers(parsers.clone());
+        // Registered after the parser was built: the handle is shared.
+        parsers.register_kind(31337, Arc::new(Topics));
+
+        let tags = vec![
+            vec!["t".to_string(), "rust".to_string()],
+            vec!["t".to_string(), "nostr".to_string()],
+        ];
+        let parsed = parser.parse(event(31337, tags)).await.unwrap();
+        match parsed.parsed {
+            Some(ParsedData::Custom(custom)) => {
+                assert_eq!(custom.schema_id, "test.topics/1");
+                assert_eq!(topics(&custom.payload), vec!["rust", "nostr"]);
+            }
+            _ => panic!("kind 31337 should use the registered parser"),
+        }
+
+        // Parser errors surface as for built-in kinds, so ParsePipe drops
+        // the event.
+        assert!(parser.parse(event(31337, Vec::new())).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn overrides_built_in_kinds() {
+        let parser = Parser::new(None);
+        parser
+            .custom_parsers()
+            .register_kind(1, Arc::new(Named("notes")));
+        let parsed = parser.parse(event(1, Vec::new())).await.unwrap();
+        assert!(matches!(parsed.parsed, Some(ParsedData::Custom(ref c)) if c.schema_id == "notes"));
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn payload_round_trips_through_cache_rebuild() {
+        let parser = Parser::new(None);
+        parser
+            .custom_parsers()
+            .register_kind(31337, Arc::new(Topics));
+        let parsed = parser
+            .parse(event(
+                31337,
+                vec![vec!["t".to_string(), "zaps".to_string()]],
+            ))
+            .await
+            .unwrap();
+
+        // What the cache stores, then rebuilds when serving a subscription.
+        let mut builder = flatbuffers::FlatBufferBuilder::new();
+        let root = parsed.build_flatbuffer(&mut builder).unwrap();
+        builder.finish(root, None);
+        let served =
+            crate::storage::utils::wrap_event_with_worker_message("sub", builder.finished_data())
+                .unwrap();
+
+        let message = flatbuffers::root::<fb::WorkerMessage>(&served).unwrap();
+        let event = message.content_as_parsed_event().unwrap();
+        assert_eq!(event.kind(), 31337);
+        let custom = event.parsed_as_custom_parsed().unwrap();
+        assert_eq!(custom.schema_id(), "test.topics/1");
+        assert_eq!(topics(custom.payload().unwrap().bytes()), vec!["zaps"]);
+    }
+}
@@ -4,7 +4,6 @@ use crate::parser::{ParserError, Result};
 use crate::parser_utils::json::BaseJsonParser;
 use crate::types::network::Request;
 use crate::types::nostr::Event;
-use rustc_hash::FxHashMap;
 
 pub struct Kind0Parsed {
     pub pubkey: String,
@@ -29,6 +28,9 @@ pub struct Kind0Parsed {
     pub image: Option<String>,
     pub avatar: Option<String>,
     pub background: Option<String>,
+
+    /// `nip05` resolved to `pubkey` in a lookup within the cache TTL
+    pub nip05_verified: bool,
 }
 
 impl Kind0Parsed {
@@ -112,6 +114,7 @@ impl<'a> ProfileJsonParser<'a> {
             image: None,
             avatar: None,
             background: None,
+            nip05_verified: false,
         };
 
         while parser.pos < parser.bytes.len() {
@@ -211,10 +214,7 @@ pub struct ProfilePointer {
     pub relays: Vec<String>,
 }
 
-pub struct Nip05Response {
-    pub names: FxHashMap<String, String>,
-    pub relays: Option<FxHashMap<String, Vec<String>>>,
-}
+pub use crate::nip05::Nip05Response;
 
 impl Parser {
     pub fn parse_kind_0(&self, event: &Event) -> Result<(Kind0Parsed, Option<Vec<Request>>)> {
@@ -243,6 +243,7 @@ impl Parser {
             image: None,
             avatar: None,
             background: None,
+            nip05_verified: false,
         };
 
         // Parse the content JSON
@@ -270,9 +271,23 @@ impl Parser {
             }
         }
         profile.pubkey = event.pubkey.to_hex();
-        // Note: NIP-05 verification is commented out in the original Go code
-        // because synchronous HTTP requests can cause deadlocks
-        // We would need to implement async verification separately
+        // Zap receipts for this pubkey are checked against this address's provider
+        self.zap_providers.remember_address(
+            &profile.pubkey,
+            event.created_at,
+            profile
+                .lud16
+                .as_deref()
+                .filter(|a| !a.trim().is_empty())
+                .or(profile.lud06.as_deref()),
+        );
+        // Only cached lookups count here; the parser worker resolves
+        // unchecked identifiers and re-emits the profile once verified.
+        profile.nip05_verified = profile
+            .nip05
+            .as_deref()
+            .and_then(|nip05| self.nip05.verified(&profile.pubkey, nip05))
+            .unwrap_or(false);
 
         Ok((profile, None))
     }
@@ -334,6 +349,7 @@ pub fn build_flatbuffer<'a, A: flatbuffers::Allocator + 'a>(
         image,
         avatar,
         background,
+        nip05_verified: parsed.nip05_verified,
     };
 
     let offset = fb::Kind0Parsed::create(builder, &args);
@@ -0,0 +1,523 @@
+//! NIP-17 private direct messages carried in NIP-59 gift wraps.
+//!
+//! A kind 1059 wrap is signed by a throwaway key and NIP-44 encrypted to the
+//! recipient; inside is a kind 13 seal signed by the real author, and inside
+//! that the unsigned kind 14 (chat) or kind 15 (file) rumor. Parsing keeps the
+//! wrap as the `ParsedEvent`'s event, so cache queries on kind 1059 and `#p`
+//! keep working, and puts the unwrapped rumor in `Kind14Parsed`.
+
+use crate::parser::content::serialize_content_data;
+use crate::parser::ContentBlock;
+use crate::parser::{content::parse_content, Parser};
+use crate::parser::{ParserError, Result};
+use crate::parser_utils::request_deduplication::RequestDeduplicator;
+
+use crate::types::network::Request;
+use crate::types::nostr::Template;
+use crate::types::Event;
+use tracing::warn;
+
+use crate::generated::nostr::*;
+
+pub struct Kind14Parsed {
+    /// 14 for chat messages, 15 for file messages.
+    pub kind: u16,
+    pub rumor_id: String,
+    /// Rumor author; always equal to the seal's signer.
+    pub sender: String,
+    pub created_at: u64,
+    /// Sender and every `p` tag of the rumor, sorted and deduplicated.
+    pub participants: Vec<String>,
+    /// Participants joined with `_`; stable for a conversation.
+    pub chat_id: String,
+    pub content: String,
+    pub parsed_content: Vec<ContentBlock>,
+    pub subject: Option<String>,
+    pub reply_to: Option<String>,
+    pub tags: Vec<Vec<String>>,
+}
+
+fn first_tag_value(tags: &[Vec<String>], name: &str) -> Option<String> {
+    tags.iter()
+        .find(|tag| tag.len() >= 2 && tag[0] == name)
+        .map(|tag| tag[1].clone())
+}
+
+impl Parser {
+    /// Unwraps a gift wrap addressed to the active signer. Wraps for other
+    /// accounts, or parsed without a signer, yield `None`; a seal with a bad
+    /// signature, or whose author differs from the rumor's, is rejected.
+    pub async fn parse_kind_1059(
+        &self,
+        event: &Event,
+    ) -> Result<(Option<Kind14Parsed>, Option<Vec<Request>>)> {
+        if event.kind != 1059 {
+            return Err(ParserError::Other("event is not kind 1059".to_string()));
+        }
+
+        let recipient = first_tag_value(&event.tags, "p")
+            .ok_or_else(|| ParserError::Other("no recipient found in gift wrap".to_string()))?;
+
+        let Some(signer) = &self.signer else {
+            warn!("No signer configured; skipping gift wrap unwrapping");
+            return Ok((None, None));
+        };
+
+        let seal_json = match signer
+            .nip44_decrypt_between(&event.pubkey.to_hex(), &recipient, &event.content)
+            .await
+        {
+            Ok(json) => json,
+            Err(e) => {
+                let error = e.to_string();
+                if error.contains("signer pubkey is not a participant") {
+                    // Wrap for another account in the same subscription.
+                    return Ok((None, None));
+                }
+                // Transient signer failures must not cache an unreadable
+                // message forever; see parse_kind_4.
+                return Err(ParserError::Crypto(format!(
+                    "Failed to unwrap gift wrap: {}",
+                    error
+                )));
+            }
+        };
+
+        let seal = Event::from_json(&seal_json)?;
+        if seal.kind != 13 {
+            return Err(ParserError::Other(format!(
+                "gift wrap contains kind {} instead of a seal",
+                seal.kind
+            )));
+        }
+        // The rumor is unsigned, so the seal's signature is what ties it to
+        // its author; check it before trusting anything inside.
+        #[cfg(feature = "crypto")]
+        {
+            use crate::crypto::nostr_crypto::{compute_event_id, verify_event_signature};
+            let expected = compute_event_id(
+                &seal.pubkey,
+                seal.created_at,
+                seal.kind,
+                &seal.tags,
+                &seal.content,
+            );
+            if expected != seal.id.to_hex() || verify_event_signature(&seal).is_err() {
+                return Err(ParserError::Other("seal signature is invalid".to_string()));
+            }
+        }
+
+        let rumor_json = signer
+            .nip44_decrypt(&seal.pubkey.to_hex(), &seal.content)
+            .await
+            .map_err(|e| ParserError::Crypto(format!("Failed to open seal: {}", e)))?;
+        let rumor = Event::from_json(&rumor_json)?;
+        if rumor.kind != 14 && rumor.kind != 15 {
+            return Err(ParserError::Other(format!(
+                "seal contains kind {} instead of a private message",
+                rumor.kind
+            )));
+        }
+        // The seal signature is the only proof of authorship: a rumor
+        // claiming another pubkey is an impersonation attempt.
+        if rumor.pubkey != seal.pubkey {
+            return Err(ParserError::Other(
+                "rumor author does not match seal author".to_string(),
+            ));
+        }
+        #[cfg(feature = "crypto")]
+        {
+            let expected = crate::crypto::nostr_crypto::compute_event_id(
+                &rumor.pubkey,
+                rumor.created_at,
+                rumor.kind,
+                &rumor.tags,
+                &rumor.content,
+            );
+            if expected != rumor.id.to_hex() {
+                return Err(ParserError::Other("rumor id does not match".to_string()));
+            }
+        }
+
+        let sender = rumor.pubkey.to_hex();
+        let mut participants = vec![sender.clone()];
+        participants.extend(
+            rumor
+                .tags
+                .iter()
+                .filter(|tag| tag.len() >= 2 && tag[0] == "p")
+                .map(|tag| tag[1].clone()),
+        );
+        participants.sort();
+        participants.dedup();
+        let chat_id = participants.join("_");
+
+        // Emoji tags of the rumor are only visible after unwrapping.
+        let parsed_content = parse_content(&rumor.content, &rumor.tags).unwrap_or_else(|_| {
+            vec![ContentBlock {
+                block_type: "text".to_string(),
+                text: rumor.content.clone(),
+                data: None,
+            }]
+        });
+
+        // Profiles, plus DM inbox relay lists so replies can be sent to the
+        // participants' kind 10050 relays.
+        let requests: Vec<Request> = participants
+            .iter()
+            .map(|pubkey| Request {
+                authors: vec![pubkey.clone()],
+                kinds: vec![0, 10050],
+                relays: vec![],
+                cache_first: true,
+                ..Default::default()
+            })
+            .collect();
+
+        let parsed = Kind14Parsed {
+            kind: rumor.kind,
+            rumor_id: rumor.id.to_hex(),
+            sender,
+            created_at: rumor.created_at,
+            participants,
+            chat_id,
+            subject: first_tag_value(&rumor.tags, "subject"),
+            reply_to: first_tag_value(&rumor.tags, "e"),
+            content: rumor.content,
+            parsed_content,
+            tags: rumor.tags,
+        };
+
+        Ok((
+            Some(parsed),
+            Some(RequestDeduplicator::deduplicate_requests(&requests)),
+        ))
+    }
+
+    /// Builds one gift wrap per `p` recipient plus one for the sender, so
+    /// their other devices see the message too. The sender's copy comes
+    /// first. Seals and wraps get timestamps up to two days in the past so
+    /// relays cannot correlate them with the rumor.
+    #[cfg(feature = "crypto")]
+    pub async fn prepare_kind_14(&self, template: &Template) -> Result<Vec<Event>> {
+        use crate::crypto::nostr_crypto::compute_event_id;
+        use crate::crypto::signers::PrivateKeySigner;
+        use crate::types::{Keys, PublicKey};
+
+        if template.kind != 14 && template.kind != 15 {
+            return Err(ParserError::Other("event is not kind 14 or 15".to_string()));
+        }
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            ParserError::Crypto("encryption not available in parser; signer not configured".into())
+        })?;
+        let sender = signer
+            .get_public_key()
+            .await
+            .map_err(|e| ParserError::Crypto(format!("Signer error: {}", e)))?;
+
+        let mut recipients = vec![sender.clone()];
+        for tag in &template.tags {
+            if tag.len() >= 2 && tag[0] == "p" && !recipients.contains(&tag[1]) {
+                recipients.push(tag[1].clone());
+            }
+        }
+        if recipients.len() < 2 {
+            return Err(ParserError::Other(
+                "no recipient found in p tag".to_string(),
+            ));
+        }
+
+        let sender_key = PublicKey::from_hex(&sender)?;
+        let rumor_created_at = crate::types::nostr::timestamp_now();
+        let rumor_id = compute_event_id(
+            &sender_key,
+            rumor_created_at,
+            template.kind,
+            &template.tags,
+            &template.content,
+        );
+        let rumor_json = serde_json::json!({
+            "id": rumor_id,
+            "pubkey": sender,
+            "created_at": rumor_created_at,
+            "kind": template.kind,
+            "tags": template.tags,
+            "content": template.content,
+        })
+        .to_string();
+
+        let mut wraps = Vec::with_capacity(recipients.len());
+        for recipient in &recipients {
+            let sealed = signer
+                .nip44_encrypt(recipient, &rumor_json)
+                .await
+                .map_err(|e| ParserError::Crypto(format!("NIP-44 encrypt error: {}", e)))?;
+            let seal = self
+                .sign_template(&Template {
+                    kind: 13,
+                    content: sealed,
+                    tags: Vec::new(),
+                    created_at: randomized_timestamp()?,
+                })
+                .await?;
+
+            let keys = Keys::generate();
+            let ephemeral = PrivateKeySigner::new(&keys.secret_key.to_hex())
+                .map_err(|e| ParserError::Crypto(format!("ephemeral key error: {}", e)))?;
+            let wrapped = ephemeral
+                .nip44_encrypt(recipient, &seal.to_json())
+                .map_err(|e| ParserError::Crypto(format!("NIP-44 encrypt error: {}", e)))?;
+            let wrap = Template {
+                kind: 1059,
+                content: wrapped,
+                tags: vec![vec!["p".to_string(), recipient.clone()]],
+                created_at: randomized_timestamp()?,
+            };
+            let wrap_json = ephemeral
+                .sign_event(&wrap.to_json())
+                .await
+                .map_err(|e| ParserError::Crypto(format!("Signer error: {}", e)))?;
+            wraps.push(Event::from_json(&wrap_json)?);
+        }
+
+        Ok(wraps)
+    }
+
+    #[cfg(not(feature = "crypto"))]
+    pub async fn prepare_kind_14(&self, _template: &Template) -> Result<Vec<Event>> {
+        Err(ParserError::Crypto(
+            "gift wrapping requires the crypto feature".to_string(),
+        ))
+    }
+}
+
+/// Now minus a random offset of up to two days (NIP-59).
+#[cfg(feature = "crypto")]
+fn randomized_timestamp() -> Result<u64> {
+    const TWO_DAYS: u64 = 2 * 24 * 60 * 60;
+    let mut bytes = [0u8; 8];
+    getrandom::getrandom(&mut bytes)
+        .map_err(|e| ParserError::Crypto(format!("random source unavailable: {}", e)))?;
+    let offset = u64::from_le_bytes(bytes) % TWO_DAYS;
+    Ok(crate::types::nostr::timestamp_now().saturating_sub(offset))
+}
+
+pub fn build_flatbuffer<'a, A: flatbuffers::Allocator + 'a>(
+    parsed: &Kind14Parsed,
+    builder: &mut flatbuffers::FlatBufferBuilder<'a, A>,
+) -> Result<flatbuffers::WIPOffset<fb::Kind14Parsed<'a>>> {
+    let mut parsed_content_offsets = Vec::new();
+    for block in &parsed.parsed_content {
+        let block_type = builder.create_string(&block.block_type);
+        let text = builder.create_string(&block.text);
+        let (data_type, data) = match &block.data {
+            Some(d) => serialize_content_data(builder, d),
+            None => (fb::ContentData::NONE, None),
+        };
+
+        let content_block_args = fb::ContentBlockArgs {
+            type_: Some(block_type),
+            text: Some(text),
+            data_type,
+            data,
+        };
+        parsed_content_offsets.push(fb::ContentBlock::create(builder, &content_block_args));
+    }
+    let parsed_content = builder.create_vector(&parsed_content_offsets);
+
+    let participant_offsets: Vec<_> = parsed
+        .participants
+        .iter()
+        .map(|p| builder.create_string(p))
+        .collect();
+    let participants = builder.create_vector(&participant_offsets);
+
+    let mut tag_offsets = Vec::new();
+    for tag in &parsed.tags {
+        let items: Vec<_> = tag.iter().map(|s| builder.create_string(s)).collect();
+        let items = builder.create_vector(&items);
+        tag_offsets.push(fb::StringVec::create(
+            builder,
+            &fb::StringVecArgs { items: Some(items) },
+        ));
+    }
+    let tags = builder.create_vector(&tag_offsets);
+
+    let rumor_id = builder.create_string(&parsed.rumor_id);
+    let sender = builder.create_string(&parsed.sender);
+    let chat_id = builder.create_string(&parsed.chat_id);
+    let content = builder.create_string(&parsed.content);
+    let subject = parsed.subject.as_ref().map(|s| builder.create_string(s));
+    let reply_to = parsed.reply_to.as_ref().map(|s| builder.create_string(s));
+
+    let args = fb::Kind14ParsedArgs {
+        kind: parsed.kind,
+        rumor_id: Some(rumor_id),
+        sender: Some(sender),
+        created_at: parsed.created_at as u32,
+        participants: Some(participants),
+        chat_id: Some(chat_id),
+        content: Some(content),
+        parsed_content: Some(parsed_content),
+        subject,
+        reply_to,
+        tags: Some(tags),
+    };
+
+    Ok(fb::Kind14Parsed::create(builder, &args))
+}
+
+#[cfg(all(test, feature = "crypto"))]
+mod tests {
+    use crate::crypto::signers::PrivateKeySigner;
+    use crate::parser::Parser;
+    use crate::parser_types::parsed_event::ParsedData;
+    use crate::types::nostr::Template;
+    use crate::types::{Event, Keys};
+    use std::sync::Arc;
+
+    fn account() -> (PrivateKeySigner, String) {
+        let keys = Keys::generate();
+        let signer = PrivateKeySigner::new(&keys.secret_key.to_hex()).unwrap();
+        let pubkey = keys.public_key().to_hex();
+        (signer, pubkey)
+    }
+
+    fn parser(signer: PrivateKeySigner) -> Parser {
+        Parser::new(Some(Arc::new(signer)))
+    }
+
+    #[tokio::test]
+    async fn gift_wraps_round_trip_to_each_recipient() {
+        let (alice, alice_pk) = account();
+        let (bob, bob_pk) = account();
+        let (carol, _) = account();
+
+        let template = Template::new(
+            14,
+            "hi bob".to_string(),
+            vec![
+                vec!["p".to_string(), bob_pk.clone()],
+                vec!["subject".to_string(), "lunch".to_string()],
+            ],
+        );
+        assert!(parser(alice.clone()).prepare(&template).await.is_err());
+        let wraps = parser(alice).prepare_all(&template).await.unwrap();
+        assert_eq!(wraps.len(), 2);
+        assert!(wraps.iter().all(|w| w.kind == 1059));
+        assert_eq!(wraps[0].tags[0][1], alice_pk);
+        assert_ne!(wraps[0].pubkey.to_hex(), alice_pk);
+
+        let to_bob = wraps
+            .iter()
+            .find(|w| w.tags[0][1] == bob_pk)
+            .unwrap()
+            .clone();
+        let parsed = parser(bob).parse(to_bob.clone()).await.unwrap();
+        assert_eq!(parsed.event.kind, 1059);
+        let Some(ParsedData::Kind14(message)) = parsed.parsed else {
+            panic!("expected kind 14 payload");
+        };
+        assert_eq!(message.content, "hi bob");
+        assert_eq!(message.sender, alice_pk);
+        assert_eq!(message.subject.as_deref(), Some("lunch"));
+        let mut expected = vec![alice_pk, bob_pk];
+        expected.sort();
+        assert_eq!(message.chat_id, expected.join("_"));
+        assert_eq!(message.participants, expected);
+
+        let parsed = parser(carol).parse(to_bob).await.unwrap();
+        assert!(parsed.parsed.is_none());
+    }
+
+    #[tokio::test]
+    async fn rejects_rumor_not_authored_by_seal_signer() {
+        let (_, alice_pk) = account();
+        let (mallory, _) = account();
+        let (bob, bob_pk) = account();
+
+        let rumor = serde_json::json!({
+            "id": "0".repeat(64),
+            "pubkey": alice_pk,
+            "created_at": 1,
+            "kind": 14,
+            "tags": [["p", bob_pk]],
+            "content": "forged",
+        })
+        .to_string();
+        let seal = Template {
+            kind: 13,
+            content: mallory.nip44_encrypt(&bob_pk, &rumor).unwrap(),
+            tags: Vec::new(),
+            created_at: 1,
+        };
+        let seal = mallory.sign_event(&seal.to_json()).await.unwrap();
+
+        let (wrapper, _) = account();
+        let wrap = Template {
+            kind: 1059,
+            content: wrapper.nip44_encrypt(&bob_pk, &seal).unwrap(),
+            tags: vec![vec!["p".to_string(), bob_pk]],
+            created_at: 1,
+        };
+        let wrap = wrapper.sign_event(&wrap.to_json()).await.unwrap();
+
+        let result = parser(bob).parse(Event::from_json(&wrap).unwrap()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn rejects_seal_with_bad_signature() {
+        let (alice, alice_pk) = account();
+        let (bob, bob_pk) = account();
+
+        let rumor = Template::new(
+            14,
+            "hi".to_string(),
+            vec![vec!["p".to_string(), bob_pk.clone()]],
+        );
+        let rumor_id = crate::crypto::nostr_crypto::compute_event_id(
+            &crate::types::PublicKey::from_hex(&alice_pk).unwrap(),
+            rumor.created_at,
+            rumor.kind,
+            &rumor.tags,
+            &rumor.content,
+        );
+        let rumor = serde_json::json!({
+            "id": rumor_id,
+            "pubkey": alice_pk,
+            "created_at": rumor.created_at,
+            "kind": 14,
+            "tags": rumor.tags,
+            "content": rumor.content,
+        })
+        .to_string();
+        let seal = Template {
+            kind: 13,
+            content: alice.nip44_encrypt(&bob_pk, &rumor).unwrap(),
+            tags: Vec::new(),
+            created_at: 1,
+        };
+        let mut seal: serde_json::Value =
+            serde_json::from_str(&alice.sign_event(&seal.to_json()).await.unwrap()).unwrap();
+        let sig = seal["sig"].as_str().unwrap();
+        let flipped = if sig.starts_with('0') { "1" } else { "0" };
+        seal["sig"] = format!("{}{}", flipped, &sig[1..]).into();
+
+        let (wrapper, _) = account();
+        let wrap = Template {
+            kind: 1059,
+            content: wrapper.nip44_encrypt(&bob_pk, &seal.to_string()).unwrap(),
+            tags: vec![vec!["p".to_string(), bob_pk]],
+            created_at: 1,
+        };
+        let wrap = wrapper.sign_event(&wrap.to_json()).await.unwrap();
+
+        let error = parser(bob)
+            .parse(Event::from_json(&wrap).unwrap())
+            .await
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("seal signature"), "{}", error);
+    }
+}
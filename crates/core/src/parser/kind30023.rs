@@ -1,3 +1,4 @@
+use crate::parser::content::{decode_nostr_entity, ContentData};
 use crate::parser::{Parser, ParserError, Result};
 use crate::{
     generated::nostr::*,
@@ -98,10 +99,10 @@ impl Parser {
         let published_at =
             tag_value(&event.tags, "published_at").and_then(|s| s.parse::<u64>().ok());
 
-        // PRE "a" tuple string for convenience (not bech32 encoding)
+        // PRE "a" tuple string; the bech32 naddr is on the ParsedEvent
         let naddr = slug
             .as_ref()
-            .map(|d| format!("30023:{}:{}", event.pubkey.to_hex(), d));
+            .map(|d| crate::parser::compute_a_pointer(30023, &event.pubkey.to_hex(), d));
         let article_blocks = parse_article_markdown(&event.content);
 
         let parsed = Kind30023Parsed {
@@ -403,13 +404,7 @@ fn parse_text_inlines(text: &str) -> Vec<ArticleInline> {
             let entity = entity_match.as_str();
             let mut inline = article_inline(fb::ArticleInlineType::NostrEntity);
             inline.text = Some(entity.to_string());
-            inline.entity = Some(ArticleEntity {
-                entity: entity.to_string(),
-                id: None,
-                relays: Vec::new(),
-                author: None,
-                kind: None,
-            });
+            inline.entity = Some(article_entity(entity));
             inlines.push(inline);
         } else if let Some(tag_match) = captures.get(4) {
             let tag = tag_match.as_str().trim_start_matches('#');
@@ -440,6 +435,35 @@ fn push_text_inline(inlines: &mut Vec<ArticleInline>, text: &str) {
     inlines.push(inline);
 }
 
+/// Decodes the entity so its id, relay hints and author travel with it.
+fn article_entity(entity: &str) -> ArticleEntity {
+    match decode_nostr_entity(entity) {
+        Some((
+            _,
+            ContentData::Nostr {
+                data,
+                relays,
+                author,
+                kind,
+                ..
+            },
+        )) => ArticleEntity {
+            entity: entity.to_string(),
+            id: data,
+            relays,
+            author,
+            kind,
+        },
+        _ => ArticleEntity {
+            entity: entity.to_string(),
+            id: None,
+            relays: Vec::new(),
+            author: None,
+            kind: None,
+        },
+    }
+}
+
 fn build_article_entity<'a, A: flatbuffers::Allocator + 'a>(
     entity: &ArticleEntity,
     builder: &mut flatbuffers::FlatBufferBuilder<'a, A>,
@@ -579,3 +603,34 @@ fn tag_values(tags: &[Vec<String>], key: &str) -> Vec<String> {
         .filter_map(|t| (t.len() >= 2 && t[0] == key).then(|| t[1].clone()))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::Parser;
+    use crate::parser_types::parsed_event::ParsedEvent;
+    use crate::types::Event;
+
+    #[test]
+    fn naddr_field_is_the_a_pointer_and_share_link_is_bech32() {
+        let pubkey = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let event = Event::from_json(
+            &serde_json::json!({
+                "id": "1".repeat(64),
+                "pubkey": pubkey,
+                "created_at": 1,
+                "kind": 30023,
+                "tags": [["d", "intro"], ["title", "Intro"]],
+                "content": "# Hello",
+                "sig": "2".repeat(128),
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let (parsed, _) = Parser::new(None).parse_kind_30023(&event).unwrap();
+        assert_eq!(parsed.naddr, Some(format!("30023:{}:intro", pubkey)));
+
+        let (_, naddr) = ParsedEvent::new(event).nip19_links();
+        assert!(naddr.unwrap().starts_with("naddr1"));
+    }
+}
@@ -181,6 +181,10 @@ fn build_nested_parsed_event<'a, A: flatbuffers::Allocator + 'a>(
         return Err(ParserError::Other("No parsed data".to_string()));
     };
 
+    let (nevent, naddr) = event.nip19_links();
+    let nevent = nevent.map(|s| builder.create_string(&s));
+    let naddr = naddr.map(|s| builder.create_string(&s));
+
     let args = fb::ParsedEventArgs {
         id: Some(id_offset),
         pubkey: Some(pubkey_offset),
@@ -191,6 +195,8 @@ fn build_nested_parsed_event<'a, A: flatbuffers::Allocator + 'a>(
         requests: requests_offset,
         relays: relays_offset,
         tags: Some(tags_offset),
+        nevent,
+        naddr,
     };
 
     Ok(fb::ParsedEvent::create(builder, &args))
@@ -1,3 +1,4 @@
+use crate::parser::bolt11::Bolt11Invoice;
 use crate::parser::Parser;
 use crate::parser::{ParserError, Result};
 
@@ -19,6 +20,8 @@ pub struct ZapRequest {
     pub content: String,
     pub tags: NostrTags,
     pub signature: Option<String>,
+    pub id: Option<String>,
+    pub created_at: u64,
 }
 
 impl ZapRequest {
@@ -144,6 +147,8 @@ impl<'a> ZapRequestParser<'a> {
         let mut has_content = false;
         let mut tags = NostrTags(Vec::new());
         let mut signature = None;
+        let mut id = None;
+        let mut created_at = 0u64;
 
         while parser.pos < parser.bytes.len() {
             parser.skip_whitespace();
@@ -165,7 +170,9 @@ impl<'a> ZapRequestParser<'a> {
                     has_content = true;
                 }
                 "tags" => tags = NostrTags::from_json(parser.parse_raw_json_value()?)?,
-                "signature" => signature = Some(parser.parse_string_unescaped()?),
+                "sig" | "signature" => signature = Some(parser.parse_string_unescaped()?),
+                "id" => id = Some(parser.parse_string_unescaped()?),
+                "created_at" => created_at = parser.parse_u64()?,
                 _ => parser.skip_value()?,
             }
 
@@ -184,10 +191,22 @@ impl<'a> ZapRequestParser<'a> {
             content,
             tags,
             signature,
+            id,
+            created_at,
         })
     }
 }
 
+/// Whether a zap receipt is signed by the recipient's LNURL provider.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ZapProviderCheck {
+    /// Not checked, or the provider lookup has not finished yet.
+    #[default]
+    Pending,
+    Verified,
+    Failed(String),
+}
+
 pub struct Kind9735Parsed {
     pub id: String,
     pub amount: i32,
@@ -201,67 +220,12 @@ pub struct Kind9735Parsed {
     pub timestamp: u64,
     pub valid: bool,
     pub description: ZapRequest,
-}
-
-fn sats_from_bolt11_hrp(invoice: &str) -> Option<i64> {
-    // Normalize to lowercase for HRP parsing
-    let lower = invoice.to_ascii_lowercase();
-    if !lower.starts_with("ln") {
-        return None;
-    }
-    // Split HRP and data part
-    let (hrp, _) = lower.split_once('1')?;
-    if hrp.len() <= 2 {
-        return None; // no currency nor amount
-    }
-    // Remove "ln"
-    let hrp = &hrp[2..];
-
-    // Find where amount digits start (after currency code, e.g., "bc", "tb", "bcrt")
-    let start = hrp.find(|c: char| c.is_ascii_digit())?;
-    let amount_part = &hrp[start..];
-    if amount_part.is_empty() {
-        return None;
-    }
-
-    // Split into numeric part and optional suffix unit
-    let (digits_str, unit_opt) = {
-        let last = amount_part.chars().last().unwrap();
-        if last.is_ascii_alphabetic() {
-            (&amount_part[..amount_part.len() - 1], Some(last))
-        } else {
-            (amount_part, None)
-        }
-    };
-
-    if digits_str.is_empty() {
-        return None;
-    }
-
-    // Use wide integers to avoid overflow during conversions
-    let value = digits_str.parse::<u128>().ok()?;
-
-    let msat: u128 = match unit_opt {
-        None => value.saturating_mul(1000),             // sats
-        Some('m') => value.saturating_mul(100_000_000), // milli-BTC
-        Some('u') => value.saturating_mul(100_000),     // micro-BTC
-        Some('n') => value.saturating_mul(100),         // nano-BTC
-        Some('p') => {
-            // pico-BTC = 0.1 msat per unit; must be multiple of 10 to land on whole msats
-            if value % 10 != 0 {
-                return None;
-            }
-            value / 10
-        }
-        _ => return None,
-    };
-
-    // Always return sats (integer). Sub-sat totals become 0 and are treated as None.
-    let sats_u128 = msat / 1000;
-    if sats_u128 == 0 {
-        return None;
-    }
-    i64::try_from(sats_u128).ok()
+    /// Why `valid` is false.
+    pub invalid_reason: Option<String>,
+    /// Set by `ZapValidationPipe`. It comes from the network, so it is
+    /// never persisted; `valid` and `invalid_reason` only hold the offline
+    /// checks.
+    pub provider: ZapProviderCheck,
 }
 
 impl Parser {
@@ -324,25 +288,17 @@ impl Parser {
             return Err(ParserError::Other("invalid zap request".to_string()));
         }
 
-        // Extract amount from bolt11 invoice or zap request
-        let mut amount = 0i32;
-
-        // First check if there's an amount tag in the zap request
-        if let Some(amount_tag) = find_tag_in_vec(&zap_request.tags.0, "amount") {
-            if amount_tag.len() >= 2 {
-                if let Ok(amt_int) = amount_tag[1].parse::<i64>() {
-                    amount = (amt_int / 1000) as i32; // Convert from millisats to sats
-                }
-            }
-        }
-        // Fallback: If no amount tag or 0 sats, decode BOLT11 HRP to get sats
-        if amount == 0 {
-            if let Some(sats) = sats_from_bolt11_hrp(&bolt11) {
-                if let Ok(sats_i32) = i32::try_from(sats) {
-                    amount = sats_i32; // always sats
-                }
-            }
-        }
+        // The invoice amount is what was actually paid; the zap request's
+        // `amount` tag (msats) only fills in for invoices without one.
+        let invoice = Bolt11Invoice::decode(&bolt11);
+        let invoice_msats = invoice.as_ref().and_then(|i| i.amount_msats);
+        let request_msats = find_tag_in_vec(&zap_request.tags.0, "amount")
+            .and_then(|tag| tag.get(1))
+            .and_then(|amount| amount.parse::<u64>().ok());
+        let amount = invoice_msats
+            .or(request_msats)
+            .and_then(|msats| i32::try_from(msats / 1000).ok())
+            .unwrap_or(0);
 
         // Determine sender
         let sender = if let Some(sender_tag) = sender_tag {
@@ -359,9 +315,10 @@ impl Parser {
             }
         }
 
-        // Try to find the zapper profile
+        // The recipient profile names the zap provider (lud16/lud06)
+        // that the receipt signer is checked against
         requests.push(Request {
-            authors: vec![event.pubkey.to_hex()],
+            authors: vec![recipient.clone()],
             kinds: vec![0],
             limit: Some(1),
             relays: zapper_relay_hints,
@@ -378,43 +335,22 @@ impl Parser {
             sender,
             recipient: recipient.clone(),
             timestamp: event.created_at,
-            valid: true, // We'll validate below
+            valid: true,
             description: zap_request,
             preimage: preimage_tag.map(|tag| tag[1].clone()),
             event: e_tag.map(|tag| tag[1].clone()),
             event_coordinate: a_tag.map(|tag| tag[1].clone()),
+            invalid_reason: None,
+            provider: ZapProviderCheck::Pending,
         };
 
-        // Perform basic validation
-        // 1. The zap request should have the same recipient as the receipt
-        if let Some(request_p_tag) = find_tag_in_vec(&receipt.description.tags.0, "p") {
-            if request_p_tag.len() >= 2 && request_p_tag[1] != receipt.recipient {
-                receipt.valid = false;
-            }
-        } else {
+        // The provider signature (receipt pubkey) is checked by
+        // ZapValidationPipe, which can resolve the recipient's LNURL.
+        if let Err(reason) =
+            check_zap_receipt(&receipt, &description_str, invoice.as_ref(), request_msats)
+        {
             receipt.valid = false;
-        }
-
-        // 2. If the receipt has an event ID, the request should also have it
-        if let Some(ref event_id) = receipt.event {
-            if let Some(request_e_tag) = find_tag_in_vec(&receipt.description.tags.0, "e") {
-                if request_e_tag.len() < 2 || request_e_tag[1] != *event_id {
-                    receipt.valid = false;
-                }
-            } else {
-                receipt.valid = false;
-            }
-        }
-
-        // 3. If the receipt has an event coordinate, the request should also have it
-        if let Some(ref event_coordinate) = receipt.event_coordinate {
-            if let Some(request_a_tag) = find_tag_in_vec(&receipt.description.tags.0, "a") {
-                if request_a_tag.len() < 2 || request_a_tag[1] != *event_coordinate {
-                    receipt.valid = false;
-                }
-            } else {
-                receipt.valid = false;
-            }
+            receipt.invalid_reason = Some(reason);
         }
 
         // Deduplicate requests using the utility
@@ -499,6 +435,16 @@ pub fn build_flatbuffer<'a, A: flatbuffers::Allocator + 'a>(
         .event_coordinate
         .as_ref()
         .map(|ec| builder.create_string(ec));
+    // A provider mismatch invalidates the receipt on the wire
+    let provider_failure = match &parsed.provider {
+        ZapProviderCheck::Failed(reason) if parsed.valid => Some(reason),
+        _ => None,
+    };
+    let invalid_reason = parsed
+        .invalid_reason
+        .as_ref()
+        .or(provider_failure)
+        .map(|r| builder.create_string(r));
 
     // Build ZapRequest
     let description_content = builder.create_string(&parsed.description.content);
@@ -552,8 +498,10 @@ pub fn build_flatbuffer<'a, A: flatbuffers::Allocator + 'a>(
         event,
         event_coordinate,
         timestamp: parsed.timestamp,
-        valid: parsed.valid,
+        valid: parsed.valid && provider_failure.is_none(),
         description: Some(zap_request_offset),
+        invalid_reason,
+        provider_verified: parsed.valid && parsed.provider == ZapProviderCheck::Verified,
     };
 
     let offset = fb::Kind9735Parsed::create(builder, &args);
@@ -561,6 +509,97 @@ pub fn build_flatbuffer<'a, A: flatbuffers::Allocator + 'a>(
     Ok(offset)
 }
 
+/// Offline NIP-57 checks: the zap request must target what the receipt says,
+/// be signed by its author, and be committed to by the paid invoice.
+fn check_zap_receipt(
+    receipt: &Kind9735Parsed,
+    description: &str,
+    invoice: Option<&Bolt11Invoice>,
+    request_msats: Option<u64>,
+) -> std::result::Result<(), String> {
+    let request = &receipt.description;
+
+    // 1. The zap request should have the same recipient as the receipt
+    match find_tag_in_vec(&request.tags.0, "p") {
+        Some(tag) if tag.len() >= 2 && tag[1] == receipt.recipient => {}
+        _ => return Err("zap request recipient does not match receipt".to_string()),
+    }
+
+    // 2. If the receipt has an event ID, the request should also have it
+    if let Some(ref event_id) = receipt.event {
+        match find_tag_in_vec(&request.tags.0, "e") {
+            Some(tag) if tag.len() >= 2 && tag[1] == *event_id => {}
+            _ => return Err("zap request event does not match receipt".to_string()),
+        }
+    }
+
+    // 3. If the receipt has an event coordinate, the request should also have it
+    if let Some(ref event_coordinate) = receipt.event_coordinate {
+        match find_tag_in_vec(&request.tags.0, "a") {
+            Some(tag) if tag.len() >= 2 && tag[1] == *event_coordinate => {}
+            _ => return Err("zap request coordinate does not match receipt".to_string()),
+        }
+    }
+
+    // 4. The invoice must decode and be for the amount that was requested
+    let Some(invoice) = invoice else {
+        return Err("receipt bolt11 is not a valid invoice".to_string());
+    };
+    if let (Some(paid), Some(requested)) = (invoice.amount_msats, request_msats) {
+        if paid != requested {
+            return Err(format!(
+                "invoice amount {} msats does not match requested {} msats",
+                paid, requested
+            ));
+        }
+    }
+
+    #[cfg(feature = "crypto")]
+    {
+        use sha2::{Digest, Sha256};
+
+        // 5. The invoice description hash commits to this exact zap request
+        let Some(ref hash) = invoice.description_hash else {
+            return Err("invoice has no description hash".to_string());
+        };
+        if *hash != hex::encode(Sha256::digest(description.as_bytes())) {
+            return Err("invoice description hash does not match zap request".to_string());
+        }
+
+        // 6. The zap request is signed by its author
+        let pubkey = PublicKey::from_hex(&request.pubkey)
+            .map_err(|_| "zap request pubkey is invalid".to_string())?;
+        let id = crate::crypto::nostr_crypto::compute_event_id(
+            &pubkey,
+            request.created_at,
+            request.kind,
+            &request.tags.0,
+            &request.content,
+        );
+        if request.id.as_deref().is_some_and(|claimed| claimed != id) {
+            return Err("zap request id does not match its content".to_string());
+        }
+        let Some(sig) = request.signature.clone() else {
+            return Err("zap request is not signed".to_string());
+        };
+        let signed = Event {
+            id: EventId::from_hex(&id).map_err(|e| e.to_string())?,
+            pubkey,
+            created_at: request.created_at,
+            kind: request.kind,
+            tags: request.tags.0.clone(),
+            content: request.content.clone(),
+            sig,
+        };
+        crate::crypto::nostr_crypto::verify_event_signature(&signed)
+            .map_err(|_| "zap request signature is invalid".to_string())?;
+    }
+    #[cfg(not(feature = "crypto"))]
+    let _ = description;
+
+    Ok(())
+}
+
 // Helper function to find a tag by name in a vec of vec of strings
 fn find_tag_in_vec<'a>(tags: &'a [Vec<String>], name: &str) -> Option<&'a Vec<String>> {
     tags.iter().find(|tag| !tag.is_empty() && tag[0] == name)
@@ -568,7 +607,7 @@ fn find_tag_in_vec<'a>(tags: &'a [Vec<String>], name: &str) -> Option<&'a Vec<St
 
 #[cfg(test)]
 mod tests {
-    use super::ZapRequest;
+    use super::*;
 
     #[test]
     fn zap_request_allows_empty_content() {
@@ -587,4 +626,103 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "crypto")]
+    mod receipts {
+        use super::*;
+        use crate::crypto::nostr_crypto::{compute_event_id, derive_public_key, sign_event};
+        use crate::parser::bolt11::signing_hash;
+        use crate::types::nostr::nips::nip19;
+        use crate::types::SecretKey;
+        use sha2::{Digest, Sha256};
+
+        const RECIPIENT: &str = "32e1827635450ebb3c5a7d12c1f8e7b2b514439ac10a67eef3d9fd9c5c68e245";
+
+        fn zap_request(content: &str, amount_msats: u64) -> String {
+            let secret = SecretKey::from_hex(&"01".repeat(32)).unwrap();
+            let pubkey = derive_public_key(&secret);
+            let tags = vec![
+                vec!["p".to_string(), RECIPIENT.to_string()],
+                vec!["amount".to_string(), amount_msats.to_string()],
+                vec!["relays".to_string(), "wss://relay.example".to_string()],
+            ];
+            let id = compute_event_id(&pubkey, 1_700_000_000, 9734, &tags, content);
+            let sig = sign_event(&secret, &EventId::from_hex(&id).unwrap()).unwrap();
+            format!(
+                r#"{{"id":"{}","pubkey":"{}","created_at":1700000000,"kind":9734,"tags":{},"content":"{}","sig":"{}"}}"#,
+                id,
+                pubkey.to_hex(),
+                NostrTags(tags).to_json(),
+                content,
+                sig
+            )
+        }
+
+        /// A 10u (1000 sat) invoice whose `h` field commits to `description`.
+        fn invoice(description: &str) -> String {
+            let hash = Sha256::digest(description.as_bytes());
+            let mut words = vec![0u8; 7];
+            words.extend([1, 1, 20]);
+            words.extend(nip19::convert_bits(&[7; 32], 8, 5, true).unwrap());
+            words.extend([23, 1, 20]);
+            words.extend(nip19::convert_bits(&hash, 8, 5, true).unwrap());
+
+            let node = k256::ecdsa::SigningKey::from_slice(&[3; 32]).unwrap();
+            let (sig, recovery_id) = node
+                .sign_prehash_recoverable(&signing_hash("lnbc10u", &words))
+                .unwrap();
+            let mut sig = sig.to_bytes().to_vec();
+            sig.push(recovery_id.to_byte());
+            words.extend(nip19::convert_bits(&sig, 8, 5, true).unwrap());
+            nip19::encode_words("lnbc10u", &words)
+        }
+
+        fn receipt(bolt11: &str, description: &str) -> Kind9735Parsed {
+            let event = Event {
+                id: EventId([9; 32]),
+                pubkey: PublicKey::from_hex(RECIPIENT).unwrap(),
+                created_at: 1_700_000_001,
+                kind: 9735,
+                tags: vec![
+                    vec!["p".to_string(), RECIPIENT.to_string()],
+                    vec!["bolt11".to_string(), bolt11.to_string()],
+                    vec!["description".to_string(), description.to_string()],
+                ],
+                content: String::new(),
+                sig: String::new(),
+            };
+            Parser::new(None).parse_kind_9735(&event).unwrap().0
+        }
+
+        #[test]
+        fn accepts_committed_signed_request() {
+            let request = zap_request("great post", 1_000_000);
+            let parsed = receipt(&invoice(&request), &request);
+
+            assert!(parsed.valid, "{:?}", parsed.invalid_reason);
+            assert_eq!(parsed.amount, 1000);
+        }
+
+        #[test]
+        fn flags_forged_receipts() {
+            let request = zap_request("great post", 1_000_000);
+            let other = zap_request("another post", 1_000_000);
+            let parsed = receipt(&invoice(&other), &request);
+            assert!(!parsed.valid);
+            assert!(parsed.invalid_reason.unwrap().contains("description hash"));
+
+            let inflated = zap_request("great post", 21_000_000);
+            let parsed = receipt(&invoice(&inflated), &inflated);
+            assert!(!parsed.valid);
+            assert!(parsed
+                .invalid_reason
+                .unwrap()
+                .contains("does not match requested"));
+
+            let tampered = request.replace("great post", "edited post");
+            let parsed = receipt(&invoice(&tampered), &tampered);
+            assert!(!parsed.valid);
+            assert!(parsed.invalid_reason.unwrap().contains("zap request id"));
+        }
+    }
 }
@@ -1,5 +1,8 @@
 pub mod kind1018;
 pub mod kind1068;
+use crate::lnurl::ZapProviders;
+use crate::nip05::Nip05Lookups;
+use crate::proxy::HttpClients;
 use crate::traits::Signer;
 use crate::types::nostr::{EventId, PublicKey};
 use crate::types::{nostr::Template, Event, ParserError};
@@ -8,13 +11,17 @@ use std::sync::Arc;
 pub type Result<T> = std::result::Result<T, ParserError>;
 
 // Declare all parser modules
+pub mod bolt11;
+pub mod cashu;
 pub mod content;
+pub mod custom;
 pub mod kind0;
 pub mod kind1;
 pub mod kind10002;
 pub mod kind10019;
 pub mod kind1111;
 pub mod kind1311;
+pub mod kind14;
 pub mod kind17;
 pub mod kind17375;
 pub mod kind20;
@@ -35,7 +42,10 @@ pub mod pre_adapters;
 pub mod pre_generic;
 
 // Re-export commonly used types
+pub use bolt11::Bolt11Invoice;
+pub use cashu::CashuToken;
 pub use content::{parse_content, ContentBlock, ContentParser};
+pub use custom::{CustomParsed, CustomParsers, KindParser};
 pub use kind0::{Kind0Parsed, Nip05Response, ProfilePointer};
 pub use kind1::{EventPointer, Kind1Parsed, ProfilePointer as Kind1ProfilePointer};
 pub use kind10002::{Kind10002Parsed, RelayInfo};
@@ -44,6 +54,7 @@ pub use kind1018::Kind1018Parsed;
 pub use kind1068::{Kind1068Parsed, PollOption, PollType};
 pub use kind1111::Kind1111Parsed;
 pub use kind1311::Kind1311Parsed;
+pub use kind14::Kind14Parsed;
 pub use kind17::Kind17Parsed;
 pub use kind17375::Kind17375Parsed;
 pub use kind20::Kind20Parsed;
@@ -58,10 +69,10 @@ pub use kind7375::Kind7375Parsed;
 pub use kind7376::{HistoryTag, Kind7376Parsed};
 pub use kind8::{BadgeAwardRecipient, Kind8Parsed};
 pub use kind9321::Kind9321Parsed;
-pub use kind9735::{Kind9735Parsed, ZapRequest};
+pub use kind9735::{Kind9735Parsed, ZapProviderCheck, ZapRequest};
 pub use nip51::{Coordinate, ListParsed};
 pub use pre_adapters::{
-    compute_a_pointer, compute_naddr_like, try_compute_naddr, BadgeDefinition, Calendar,
+    compute_a_pointer, compute_naddr, try_compute_naddr, BadgeDefinition, Calendar,
     CalendarEvent, LiveActivity, LiveSession, LiveSpace, ProfileBadges, WikiArticle, WikiRedirect,
 };
 
@@ -69,11 +80,59 @@ use crate::parser_types::parsed_event::{ParsedData, ParsedEvent};
 
 pub struct Parser {
     signer: Option<Arc<dyn Signer>>,
+    custom: CustomParsers,
+    http: HttpClients,
+    zap_providers: ZapProviders,
+    nip05: Nip05Lookups,
 }
 
 impl Parser {
     pub fn new(signer: Option<Arc<dyn Signer>>) -> Self {
-        Self { signer }
+        Self {
+            signer,
+            custom: CustomParsers::default(),
+            http: HttpClients::default(),
+            zap_providers: ZapProviders::default(),
+            nip05: Nip05Lookups::default(),
+        }
+    }
+
+    /// Share `custom` with this parser; later registrations on it apply too.
+    pub fn with_custom_parsers(mut self, custom: CustomParsers) -> Self {
+        self.custom = custom;
+        self
+    }
+
+    pub fn custom_parsers(&self) -> CustomParsers {
+        self.custom.clone()
+    }
+
+    /// Share the engine's HTTP clients (and proxy) with this parser and the
+    /// pipes and lookups built from it.
+    pub fn with_http(mut self, http: HttpClients) -> Self {
+        self.http = http;
+        self
+    }
+
+    pub fn http(&self) -> HttpClients {
+        self.http.clone()
+    }
+
+    /// Share the engine's NIP-05 lookups, whose verdicts set
+    /// `nip05_verified` on parsed profiles.
+    pub fn with_nip05(mut self, nip05: Nip05Lookups) -> Self {
+        self.nip05 = nip05;
+        self
+    }
+
+    pub fn nip05(&self) -> Nip05Lookups {
+        self.nip05.clone()
+    }
+
+    /// Lightning addresses seen in profiles and their resolved LNURL
+    /// providers, shared with the `ZapValidationPipe`s built from this parser.
+    pub fn zap_providers(&self) -> ZapProviders {
+        self.zap_providers.clone()
     }
 
     async fn sign_template(&self, template: &Template) -> Result<Event> {
@@ -94,6 +153,20 @@ impl Parser {
     pub async fn parse(&self, event: Event) -> Result<ParsedEvent> {
         let kind = event.kind;
 
+        if let Some(custom) = self.custom.get(kind) {
+            let (payload, requests) = custom.parse(&event)?;
+            let parsed = CustomParsed {
+                schema_id: custom.schema_id().to_string(),
+                payload,
+            };
+            return Ok(ParsedEvent {
+                event,
+                parsed: Some(ParsedData::Custom(parsed)),
+                requests,
+                relays: Vec::new(),
+            });
+        }
+
         let (parsed, requests) = match kind {
             0 => {
                 let (parsed, requests) = self.parse_kind_0(&event)?;
@@ -123,6 +196,10 @@ impl Parser {
                 let (parsed, requests) = self.parse_kind_8(&event)?;
                 (Some(ParsedData::Kind8(parsed)), requests)
             }
+            1059 => match self.parse_kind_1059(&event).await? {
+                (Some(parsed), requests) => (Some(ParsedData::Kind14(parsed)), requests),
+                (None, requests) => (None, requests),
+            },
             17 => {
                 let (parsed, requests) = self.parse_kind_17(&event)?;
                 (Some(ParsedData::Kind17(parsed)), requests)
@@ -241,6 +318,9 @@ impl Parser {
 
         match kind {
             4 => self.prepare_kind_4(template).await,
+            14 | 15 => Err(ParserError::Other(
+                "private messages publish as several gift wraps; use prepare_all".to_string(),
+            )),
             7374 => self.prepare_kind_7374(template).await,
             7375 => self.prepare_kind_7375(template).await,
             7376 => self.prepare_kind_7376(template).await,
@@ -254,6 +334,15 @@ impl Parser {
             _ => self.sign_template(template).await,
         }
     }
+
+    /// Like `prepare`, but for templates that publish as several events:
+    /// NIP-17 messages become one gift wrap per recipient.
+    pub async fn prepare_all(&self, template: &Template) -> Result<Vec<Event>> {
+        match template.kind {
+            14 | 15 => self.prepare_kind_14(template).await,
+            _ => Ok(vec![self.prepare(template).await?]),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,37 +1,35 @@
 use crate::parser::pre_generic::{PreGenericParsed, PreParticipant, PreRefEvent};
 use crate::parser::Coordinate;
+use crate::types::nostr::nips::nip19::{Nip19Coordinate, ToBech32};
+use crate::types::nostr::PublicKey;
 
 /// Convenience: build the NIP-33 address ("a" pointer) string "kind:pubkey_hex:d"
 pub fn compute_a_pointer(kind: u16, pubkey_hex: &str, d: &str) -> String {
     format!("{}:{}:{}", kind, pubkey_hex, d)
 }
 
-/// Convenience: best-effort naddr-like string for UI/debug in absence of a bech32 encoder.
-/// This is NOT a proper bech32-encoded naddr, but it contains the same tuple information.
-/// If you later wire a NIP-19 encoder, replace callers with `try_compute_naddr`.
-pub fn compute_naddr_like(kind: u16, pubkey_hex: &str, d: &str) -> String {
-    // Keep a stable, recognizable prefix to make it clear to consumers this is not bech32.
-    format!("naddr-like:{}", compute_a_pointer(kind, pubkey_hex, d))
+/// NIP-19 `naddr` for the address, without relay hints. Falls back to the
+/// plain "kind:pubkey:d" pointer when `pubkey_hex` is not a valid key.
+pub fn compute_naddr(kind: u16, pubkey_hex: &str, d: &str) -> String {
+    try_compute_naddr(kind, pubkey_hex, d, &[])
+        .unwrap_or_else(|| compute_a_pointer(kind, pubkey_hex, d))
 }
 
-/// Attempt to compute a proper bech32 naddr (if/when a NIP-19 encoder is available).
-/// Currently returns None as a placeholder to avoid hard coupling here.
-#[allow(unused_variables)]
+/// NIP-19 `naddr` with relay hints, or None when `pubkey_hex` is invalid.
 pub fn try_compute_naddr(
     kind: u16,
     pubkey_hex: &str,
     d: &str,
     relays: &[String],
 ) -> Option<String> {
-    // TODO: integrate with your NIP-19 bech32 encoder (e.g., nip19::to_naddr)
-    // Example shape (pseudo):
-    // nip19::Naddr {
-    //   kind,
-    //   pubkey: pubkey_hex,
-    //   d,
-    //   relays: relays.to_vec(),
-    // }.to_bech32().ok()
-    None
+    Nip19Coordinate {
+        identifier: d.to_string(),
+        public_key: PublicKey::from_hex(pubkey_hex).ok()?,
+        kind,
+        relays: relays.to_vec(),
+    }
+    .to_bech32()
+    .ok()
 }
 
 /* -------------------- NIP-53 Live Activities (30311/30312/30313) -------------------- */
@@ -69,8 +67,8 @@ pub struct LiveActivity {
     pub ends: Option<u64>,
     pub topics: Vec<String>,
     pub participants: Vec<LiveParticipant>,
-    pub a_pointer: String,  // "30311:<pubkey>:<d>"
-    pub naddr_like: String, // best-effort naddr-like string
+    pub a_pointer: String, // "30311:<pubkey>:<d>"
+    pub naddr: String,
 }
 
 pub fn adapt_live_activity(
@@ -82,7 +80,7 @@ pub fn adapt_live_activity(
     }
     let d = pre.d.clone()?;
     let a = compute_a_pointer(pre.kind, author_pubkey_hex, &d);
-    let naddr_like = compute_naddr_like(pre.kind, author_pubkey_hex, &d);
+    let naddr = compute_naddr(pre.kind, author_pubkey_hex, &d);
 
     Some(LiveActivity {
         kind: pre.kind,
@@ -98,7 +96,7 @@ pub fn adapt_live_activity(
         topics: pre.topics.clone(),
         participants: pre.participants.iter().map(LiveParticipant::from).collect(),
         a_pointer: a,
-        naddr_like,
+        naddr,
     })
 }
 
@@ -117,7 +115,7 @@ pub struct LiveSpace {
     pub participants: Vec<LiveParticipant>,
     pub relays: Vec<String>,
     pub a_pointer: String,
-    pub naddr_like: String,
+    pub naddr: String,
 }
 
 pub fn adapt_live_space(pre: &PreGenericParsed, author_pubkey_hex: &str) -> Option<LiveSpace> {
@@ -126,7 +124,7 @@ pub fn adapt_live_space(pre: &PreGenericParsed, author_pubkey_hex: &str) -> Opti
     }
     let d = pre.d.clone()?;
     let a = compute_a_pointer(pre.kind, author_pubkey_hex, &d);
-    let naddr_like = compute_naddr_like(pre.kind, author_pubkey_hex, &d);
+    let naddr = compute_naddr(pre.kind, author_pubkey_hex, &d);
 
     Some(LiveSpace {
         kind: pre.kind,
@@ -142,7 +140,7 @@ pub fn adapt_live_space(pre: &PreGenericParsed, author_pubkey_hex: &str) -> Opti
         participants: pre.participants.iter().map(LiveParticipant::from).collect(),
         relays: pre.relays.clone(),
         a_pointer: a,
-        naddr_like,
+        naddr,
     })
 }
 
@@ -159,7 +157,7 @@ pub struct LiveSession {
     pub topics: Vec<String>,
     pub participants: Vec<LiveParticipant>,
     pub a_pointer: String,
-    pub naddr_like: String,
+    pub naddr: String,
 }
 
 pub fn adapt_live_session(pre: &PreGenericParsed, author_pubkey_hex: &str) -> Option<LiveSession> {
@@ -168,7 +166,7 @@ pub fn adapt_live_session(pre: &PreGenericParsed, author_pubkey_hex: &str) -> Op
     }
     let d = pre.d.clone()?;
     let a = compute_a_pointer(pre.kind, author_pubkey_hex, &d);
-    let naddr_like = compute_naddr_like(pre.kind, author_pubkey_hex, &d);
+    let naddr = compute_naddr(pre.kind, author_pubkey_hex, &d);
 
     Some(LiveSession {
         kind: pre.kind,
@@ -182,7 +180,7 @@ pub fn adapt_live_session(pre: &PreGenericParsed, author_pubkey_hex: &str) -> Op
         topics: pre.topics.clone(),
         participants: pre.participants.iter().map(LiveParticipant::from).collect(),
         a_pointer: a,
-        naddr_like,
+        naddr,
     })
 }
 
@@ -196,7 +194,7 @@ pub struct BadgeDefinition {
     pub description: Option<String>,
     pub image: Option<String>,
     pub a_pointer: String,
-    pub naddr_like: String,
+    pub naddr: String,
 }
 
 pub fn adapt_badge_definition(
@@ -208,7 +206,7 @@ pub fn adapt_badge_definition(
     }
     let d = pre.d.clone()?;
     let a = compute_a_pointer(pre.kind, issuer_pubkey_hex, &d);
-    let naddr_like = compute_naddr_like(pre.kind, issuer_pubkey_hex, &d);
+    let naddr = compute_naddr(pre.kind, issuer_pubkey_hex, &d);
 
     Some(BadgeDefinition {
         kind: pre.kind,
@@ -217,7 +215,7 @@ pub fn adapt_badge_definition(
         description: pre.description.clone(),
         image: pre.image.clone(),
         a_pointer: a,
-        naddr_like,
+        naddr,
     })
 }
 
@@ -228,7 +226,7 @@ pub struct ProfileBadges {
     pub addresses: Vec<Coordinate>, // references to definitions (a)
     pub awards: Vec<PreRefEvent>,   // references to award events (e)
     pub a_pointer: String,
-    pub naddr_like: String,
+    pub naddr: String,
 }
 
 pub fn adapt_profile_badges(
@@ -240,7 +238,7 @@ pub fn adapt_profile_badges(
     }
     let d = pre.d.clone()?;
     let a = compute_a_pointer(pre.kind, owner_pubkey_hex, &d);
-    let naddr_like = compute_naddr_like(pre.kind, owner_pubkey_hex, &d);
+    let naddr = compute_naddr(pre.kind, owner_pubkey_hex, &d);
 
     Some(ProfileBadges {
         kind: pre.kind,
@@ -248,7 +246,7 @@ pub fn adapt_profile_badges(
         addresses: pre.addresses.clone(),
         awards: pre.events.clone(),
         a_pointer: a,
-        naddr_like,
+        naddr,
     })
 }
 
@@ -267,7 +265,7 @@ pub struct CalendarEvent {
     pub links: Vec<String>,
     pub participants: Vec<LiveParticipant>, // reuse shape (pubkey/relay/role)
     pub a_pointer: String,
-    pub naddr_like: String,
+    pub naddr: String,
 }
 
 pub fn adapt_calendar_event(
@@ -279,7 +277,7 @@ pub fn adapt_calendar_event(
     }
     let d = pre.d.clone()?;
     let a = compute_a_pointer(pre.kind, author_pubkey_hex, &d);
-    let naddr_like = compute_naddr_like(pre.kind, author_pubkey_hex, &d);
+    let naddr = compute_naddr(pre.kind, author_pubkey_hex, &d);
 
     Some(CalendarEvent {
         kind: pre.kind,
@@ -293,7 +291,7 @@ pub fn adapt_calendar_event(
         links: pre.links.clone(),
         participants: pre.participants.iter().map(LiveParticipant::from).collect(),
         a_pointer: a,
-        naddr_like,
+        naddr,
     })
 }
 
@@ -304,7 +302,7 @@ pub struct Calendar {
     pub title: Option<String>,
     pub references: Vec<Coordinate>, // "a" to 31922/31923
     pub a_pointer: String,
-    pub naddr_like: String,
+    pub naddr: String,
 }
 
 pub fn adapt_calendar(pre: &PreGenericParsed, owner_pubkey_hex: &str) -> Option<Calendar> {
@@ -313,7 +311,7 @@ pub fn adapt_calendar(pre: &PreGenericParsed, owner_pubkey_hex: &str) -> Option<
     }
     let d = pre.d.clone()?;
     let a = compute_a_pointer(pre.kind, owner_pubkey_hex, &d);
-    let naddr_like = compute_naddr_like(pre.kind, owner_pubkey_hex, &d);
+    let naddr = compute_naddr(pre.kind, owner_pubkey_hex, &d);
 
     Some(Calendar {
         kind: pre.kind,
@@ -321,7 +319,7 @@ pub fn adapt_calendar(pre: &PreGenericParsed, owner_pubkey_hex: &str) -> Option<
         title: pre.title.clone(),
         references: pre.addresses.clone(),
         a_pointer: a,
-        naddr_like,
+        naddr,
     })
 }
 
@@ -335,7 +333,7 @@ pub struct WikiArticle {
     pub summary: Option<String>, // some clients use "summary"
     pub description: Option<String>,
     pub a_pointer: String,
-    pub naddr_like: String,
+    pub naddr: String,
 }
 
 pub fn adapt_wiki_article(pre: &PreGenericParsed, author_pubkey_hex: &str) -> Option<WikiArticle> {
@@ -344,7 +342,7 @@ pub fn adapt_wiki_article(pre: &PreGenericParsed, author_pubkey_hex: &str) -> Op
     }
     let d = pre.d.clone()?;
     let a = compute_a_pointer(pre.kind, author_pubkey_hex, &d);
-    let naddr_like = compute_naddr_like(pre.kind, author_pubkey_hex, &d);
+    let naddr = compute_naddr(pre.kind, author_pubkey_hex, &d);
 
     Some(WikiArticle {
         kind: pre.kind,
@@ -354,7 +352,7 @@ pub fn adapt_wiki_article(pre: &PreGenericParsed, author_pubkey_hex: &str) -> Op
         summary: None,
         description: pre.description.clone(),
         a_pointer: a,
-        naddr_like,
+        naddr,
     })
 }
 
@@ -364,7 +362,7 @@ pub struct WikiRedirect {
     pub d: String,                // source
     pub targets: Vec<Coordinate>, // target "a" references (as available)
     pub a_pointer: String,
-    pub naddr_like: String,
+    pub naddr: String,
 }
 
 pub fn adapt_wiki_redirect(
@@ -376,14 +374,14 @@ pub fn adapt_wiki_redirect(
     }
     let d = pre.d.clone()?;
     let a = compute_a_pointer(pre.kind, author_pubkey_hex, &d);
-    let naddr_like = compute_naddr_like(pre.kind, author_pubkey_hex, &d);
+    let naddr = compute_naddr(pre.kind, author_pubkey_hex, &d);
 
     Some(WikiRedirect {
         kind: pre.kind,
         d,
         targets: pre.addresses.clone(),
         a_pointer: a,
-        naddr_like,
+        naddr,
     })
 }
 
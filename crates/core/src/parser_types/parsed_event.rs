@@ -1,14 +1,17 @@
 use crate::parser::nip51::ListParsed;
 use crate::parser::pre_generic::PreGenericParsed;
 use crate::types::network::Request;
+use crate::parser::try_compute_naddr;
+use crate::types::nostr::nips::nip19::{Nip19Event, ToBech32};
 use crate::types::nostr::Event;
 use crate::{generated::nostr::fb, types::TypesError};
 
 use crate::parser::{
-    Kind0Parsed, Kind10002Parsed, Kind10019Parsed, Kind1018Parsed, Kind1068Parsed, Kind1111Parsed,
-    Kind1311Parsed, Kind17375Parsed, Kind17Parsed, Kind1Parsed, Kind20Parsed, Kind22Parsed,
-    Kind30023Parsed, Kind3Parsed, Kind4Parsed, Kind6Parsed, Kind7374Parsed, Kind7375Parsed,
-    Kind7376Parsed, Kind7Parsed, Kind8Parsed, Kind9321Parsed, Kind9735Parsed,
+    CustomParsed, Kind0Parsed, Kind10002Parsed, Kind10019Parsed, Kind1018Parsed, Kind1068Parsed,
+    Kind1111Parsed, Kind1311Parsed, Kind14Parsed, Kind17375Parsed, Kind17Parsed, Kind1Parsed,
+    Kind20Parsed, Kind22Parsed, Kind30023Parsed, Kind3Parsed, Kind4Parsed, Kind6Parsed,
+    Kind7374Parsed, Kind7375Parsed, Kind7376Parsed, Kind7Parsed, Kind8Parsed, Kind9321Parsed,
+    Kind9735Parsed,
 };
 
 /// Strongly typed parsed data for different event kinds
@@ -20,6 +23,7 @@ pub enum ParsedData {
     Kind6(Kind6Parsed),
     Kind7(Kind7Parsed),
     Kind8(Kind8Parsed),
+    Kind14(Kind14Parsed),
     Kind17(Kind17Parsed),
     Kind20(Kind20Parsed),
     Kind22(Kind22Parsed),
@@ -38,6 +42,8 @@ pub enum ParsedData {
     Kind30023(Kind30023Parsed),
     List(ListParsed),
     PreGeneric(PreGenericParsed),
+    /// Produced by a `KindParser` the app registered.
+    Custom(CustomParsed),
 }
 
 impl ParsedData {
@@ -83,6 +89,10 @@ impl ParsedData {
                 let offset = crate::parser::kind8::build_flatbuffer(data, builder)?;
                 Ok((fb::ParsedData::Kind8Parsed, offset.as_union_value()))
             }
+            ParsedData::Kind14(data) => {
+                let offset = crate::parser::kind14::build_flatbuffer(data, builder)?;
+                Ok((fb::ParsedData::Kind14Parsed, offset.as_union_value()))
+            }
             ParsedData::Kind17(data) => {
                 let offset = crate::parser::kind17::build_flatbuffer(data, builder)?;
                 Ok((fb::ParsedData::Kind17Parsed, offset.as_union_value()))
@@ -155,6 +165,10 @@ impl ParsedData {
                 let offset = crate::parser::pre_generic::build_flatbuffer(data, builder)?;
                 Ok((fb::ParsedData::PreGenericParsed, offset.as_union_value()))
             }
+            ParsedData::Custom(data) => {
+                let offset = crate::parser::custom::build_flatbuffer(data, builder)?;
+                Ok((fb::ParsedData::CustomParsed, offset.as_union_value()))
+            }
         }
     }
 }
@@ -195,6 +209,37 @@ impl ParsedEvent {
         self
     }
 
+    /// NIP-19 share links: an `nevent`, plus an `naddr` for addressable
+    /// kinds with a `d` tag. Both carry up to two relay hints.
+    pub fn nip19_links(&self) -> (Option<String>, Option<String>) {
+        let relays: Vec<String> = self.relays.iter().take(2).cloned().collect();
+        let nevent = Nip19Event {
+            event_id: self.event.id,
+            author: Some(self.event.pubkey.clone()),
+            kind: Some(self.event.kind),
+            relays: relays.clone(),
+        }
+        .to_bech32()
+        .ok();
+        let naddr = if (30000..40000).contains(&self.event.kind) {
+            self.event
+                .tags
+                .iter()
+                .find(|tag| tag.len() >= 2 && tag[0] == "d")
+                .and_then(|tag| {
+                    try_compute_naddr(
+                        self.event.kind,
+                        &self.event.pubkey.to_hex(),
+                        &tag[1],
+                        &relays,
+                    )
+                })
+        } else {
+            None
+        };
+        (nevent, naddr)
+    }
+
     pub fn build_flatbuffer<'a>(
         &self,
         fbb: &mut flatbuffers::FlatBufferBuilder<'a>,
@@ -254,6 +299,10 @@ impl ParsedEvent {
         }
         let tags_offset = fbb.create_vector(&string_vec_offsets);
 
+        let (nevent, naddr) = self.nip19_links();
+        let nevent = nevent.map(|s| fbb.create_string(&s));
+        let naddr = naddr.map(|s| fbb.create_string(&s));
+
         // Build ParsedEvent with the union
         let parsed_event_args = fb::ParsedEventArgs {
             id: Some(id_offset),
@@ -265,6 +314,8 @@ impl ParsedEvent {
             requests: requests_offset,
             relays: relays_offset,
             tags: Some(tags_offset),
+            nevent,
+            naddr,
         };
 
         Ok(fb::ParsedEvent::create(fbb, &parsed_event_args))
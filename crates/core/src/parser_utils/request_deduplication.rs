@@ -56,6 +56,8 @@ impl RequestDeduplicator {
                     max_relays: request.max_relays,
                     cache_only: request.cache_only,
                     mesh_only: request.mesh_only,
+                    negentropy: request.negentropy,
+                    background: request.background,
                     relays: {
                         let mut relays: Vec<String> = relay_set.into_iter().collect();
                         relays.sort();
@@ -150,6 +152,8 @@ impl RequestDeduplicator {
         // Add other filter-relevant fields
         key_parts.push(format!("cache_first:{}", request.cache_first));
         key_parts.push(format!("mesh_only:{}", request.mesh_only));
+        key_parts.push(format!("negentropy:{}", request.negentropy));
+        key_parts.push(format!("background:{}", request.background));
 
         key_parts.join("|")
     }
@@ -106,6 +106,7 @@ pub enum PipeType {
     KindFilter(KindFilterPipe),
     NpubLimiter(NpubLimiterPipe),
     MuteFilter(MuteFilterPipe),
+    ZapValidation(ZapValidationPipe),
 }
 
 impl PipeType {
@@ -120,6 +121,7 @@ impl PipeType {
             PipeType::KindFilter(pipe) => pipe.process(event).await,
             PipeType::NpubLimiter(pipe) => pipe.process(event).await,
             PipeType::MuteFilter(pipe) => pipe.process(event).await,
+            PipeType::ZapValidation(pipe) => pipe.process(event).await,
         }
     }
 
@@ -152,6 +154,9 @@ impl PipeType {
             PipeType::MuteFilter(pipe) => {
                 <MuteFilterPipe as Pipe>::process_cached_batch(pipe, messages).await
             }
+            PipeType::ZapValidation(pipe) => {
+                <ZapValidationPipe as Pipe>::process_cached_batch(pipe, messages).await
+            }
         }
     }
 
@@ -166,6 +171,7 @@ impl PipeType {
             PipeType::KindFilter(pipe) => pipe.name(),
             PipeType::NpubLimiter(pipe) => pipe.name(),
             PipeType::MuteFilter(pipe) => pipe.name(),
+            PipeType::ZapValidation(pipe) => pipe.name(),
         }
     }
 
@@ -180,6 +186,7 @@ impl PipeType {
             PipeType::KindFilter(pipe) => pipe.can_direct_output(),
             PipeType::NpubLimiter(pipe) => pipe.can_direct_output(),
             PipeType::MuteFilter(pipe) => pipe.can_direct_output(),
+            PipeType::ZapValidation(pipe) => pipe.can_direct_output(),
         }
     }
 
@@ -194,6 +201,7 @@ impl PipeType {
             PipeType::KindFilter(pipe) => pipe.run_for_cached_events(),
             PipeType::NpubLimiter(pipe) => pipe.run_for_cached_events(),
             PipeType::MuteFilter(pipe) => pipe.run_for_cached_events(),
+            PipeType::ZapValidation(pipe) => pipe.run_for_cached_events(),
         }
     }
 
@@ -208,6 +216,7 @@ impl PipeType {
             PipeType::KindFilter(pipe) => pipe.flush(),
             PipeType::NpubLimiter(pipe) => pipe.flush(),
             PipeType::MuteFilter(pipe) => pipe.flush(),
+            PipeType::ZapValidation(pipe) => pipe.flush(),
         }
     }
 
@@ -222,6 +231,7 @@ impl PipeType {
             PipeType::KindFilter(pipe) => pipe.on_eose(),
             PipeType::NpubLimiter(pipe) => pipe.on_eose(),
             PipeType::MuteFilter(pipe) => pipe.on_eose(),
+            PipeType::ZapValidation(pipe) => pipe.on_eose(),
         }
     }
 }
@@ -283,8 +293,10 @@ impl Pipeline {
         Self::new(
             vec![
                 PipeType::KindFilter(KindFilterPipe::new(vec![9321, 7375])), // Only process cashu events
-                PipeType::Parse(ParsePipe::new(parser)),
-                PipeType::ProofVerification(ProofVerificationPipe::new(max_proofs)),
+                PipeType::Parse(ParsePipe::new(parser.clone())),
+                PipeType::ProofVerification(
+                    ProofVerificationPipe::new(max_proofs).with_http(parser.http()),
+                ),
             ],
             subscription_id,
         )
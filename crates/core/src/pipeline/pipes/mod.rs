@@ -7,6 +7,7 @@ mod parse;
 mod proof_verification;
 mod save_to_db;
 mod serialize_events;
+mod zap_validation;
 
 pub use chat_limiter::ChatLimiterPipe;
 pub use counter::CounterPipe;
@@ -17,3 +18,4 @@ pub use parse::ParsePipe;
 pub use proof_verification::ProofVerificationPipe;
 pub use save_to_db::SaveToDbPipe;
 pub use serialize_events::SerializeEventsPipe;
+pub use zap_validation::ZapValidationPipe;
@@ -3,6 +3,7 @@ use crate::parser_types::parsed_event::ParsedData;
 use crate::{
     generated::nostr::fb::{self},
     platform,
+    proxy::HttpClients,
     types::Proof,
 };
 use std::sync::{Arc, Mutex};
@@ -35,6 +36,7 @@ pub struct ProofVerificationPipe {
     verification_running: bool,
     mint_keys_cache: Arc<Mutex<FxHashMap<String, CachedKeys>>>, // cached mint keys
     mint_key_failures: Arc<Mutex<FxHashMap<String, u64>>>,      // mint_url -> retry_after_ms
+    http: HttpClients,
 }
 
 impl ProofVerificationPipe {
@@ -48,9 +50,16 @@ impl ProofVerificationPipe {
             verification_running: false,
             mint_keys_cache: Arc::new(Mutex::new(FxHashMap::default())),
             mint_key_failures: Arc::new(Mutex::new(FxHashMap::default())),
+            http: HttpClients::default(),
         }
     }
 
+    /// Mint key fetches go through `http` (the engine's proxy routing).
+    pub fn with_http(mut self, http: HttpClients) -> Self {
+        self.http = http;
+        self
+    }
+
     async fn add_proofs(&mut self, proofs: Vec<Proof>, mint_url: String) -> Result<()> {
         if proofs.is_empty() {
             return Ok(());
@@ -439,7 +448,10 @@ impl ProofVerificationPipe {
     #[cfg(not(target_arch = "wasm32"))]
     async fn fetch_mint_keys_from_network(&self, mint_url: &str) -> Result<String> {
         let url = format!("{}/v1/keys", mint_url.trim_end_matches('/'));
-        let response = reqwest::get(&url)
+        let client = self.http.client(&url).map_err(NostrError::Other)?;
+        let response = client
+            .get(&url)
+            .send()
             .await
             .map_err(|e| NostrError::Other(format!("HTTP request failed: {}", e)))?;
 
@@ -1,4 +1,6 @@
 use super::super::*;
+use crate::parser::ZapProviderCheck;
+use crate::parser_types::parsed_event::ParsedData;
 use crate::{cache_input, channel::MessageSender, generated::nostr::fb};
 use flatbuffers::FlatBufferBuilder;
 use std::sync::Arc;
@@ -82,8 +84,22 @@ impl SaveToDbPipe {
     }
 }
 
+/// Takes the provider verdict off a zap receipt, leaving it pending. The
+/// verdict comes from LNURL lookups, so storage only keeps the parser's
+/// checks and cached receipts are checked again when loaded.
+fn take_zap_provider_check(event: &mut PipelineEvent) -> Option<ZapProviderCheck> {
+    match event.parsed.as_mut()?.parsed.as_mut()? {
+        ParsedData::Kind9735(zap) if zap.provider != ZapProviderCheck::Pending => {
+            Some(std::mem::take(&mut zap.provider))
+        }
+        _ => None,
+    }
+}
+
 impl Pipe for SaveToDbPipe {
     async fn process(&mut self, mut event: PipelineEvent) -> Result<PipeOutput> {
+        let provider_check = take_zap_provider_check(&mut event);
+
         // Send event as WorkerMessage (ParsedEvent if available, else NostrEvent)
         if let Some((bytes, is_parsed)) = self.build_message(&event) {
             // Frame with the cache-input tagged header so the cache worker can
@@ -98,11 +114,19 @@ impl Pipe for SaveToDbPipe {
             // The inner sub_id ("save_to_db") is ignored downstream: main reads
             // the sub id from the outer tagged framing, and the cache persist
             // path accepts any sub_id since the header-tag framing change.
-            if is_parsed {
+            if is_parsed && provider_check.is_none() {
                 event.serialized = Some(bytes);
             }
         }
 
+        if let Some(check) = provider_check {
+            if let Some(ParsedData::Kind9735(zap)) =
+                event.parsed.as_mut().and_then(|p| p.parsed.as_mut())
+            {
+                zap.provider = check;
+            }
+        }
+
         // Always pass the event through (with the stash attached when present)
         Ok(PipeOutput::Event(event))
     }
@@ -0,0 +1,287 @@
+use super::super::*;
+use crate::generated::nostr::fb;
+use crate::lnurl::{ProviderLookup, ZapProviders};
+use crate::parser::ZapProviderCheck;
+use crate::parser_types::parsed_event::ParsedData;
+use crate::proxy::HttpClients;
+use tracing::debug;
+
+/// Pipe that finishes NIP-57 validation of parsed zap receipts (kind 9735).
+/// The parser already checks the zap request signature, the invoice
+/// description hash and amounts; this pipe adds the check that needs the
+/// network: the receipt must be signed by the `nostrPubkey` of the
+/// recipient's LNURL provider, found through their kind 0 lud16/lud06.
+///
+/// Providers are resolved in the background and never awaited here: until
+/// the recipient's profile and provider are known a receipt stays pending
+/// (`valid` without `provider_verified`). A provider that did not sign the
+/// receipt makes it invalid. The verdict only goes to the output, never to
+/// storage. Must run after `ParsePipe`.
+pub struct ZapValidationPipe {
+    drop_invalid: bool,
+    http: HttpClients,
+    providers: ZapProviders,
+    name: String,
+}
+
+impl ZapValidationPipe {
+    pub fn new(drop_invalid: bool) -> Self {
+        Self {
+            drop_invalid,
+            http: HttpClients::default(),
+            providers: ZapProviders::default(),
+            name: format!("ZapValidation(drop_invalid:{})", drop_invalid),
+        }
+    }
+
+    /// LNURL lookups go through `http` (the engine's proxy routing).
+    pub fn with_http(mut self, http: HttpClients) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Share the engine's lightning addresses and resolved providers.
+    pub fn with_providers(mut self, providers: ZapProviders) -> Self {
+        self.providers = providers;
+        self
+    }
+
+    /// Checks that `receipt_pubkey` is the recipient's zap provider, from
+    /// cached lookups only.
+    fn check_provider(&self, recipient: &str, receipt_pubkey: &str) -> ZapProviderCheck {
+        let Some(address) = self.providers.address_for(recipient) else {
+            return ZapProviderCheck::Pending;
+        };
+        match self.providers.lookup(&self.http, &address) {
+            ProviderLookup::Known(provider) if provider == receipt_pubkey => {
+                ZapProviderCheck::Verified
+            }
+            ProviderLookup::Known(_) => ZapProviderCheck::Failed(
+                "receipt not signed by the recipient's zap provider".to_string(),
+            ),
+            ProviderLookup::Pending => ZapProviderCheck::Pending,
+            ProviderLookup::Unsupported(reason) => ZapProviderCheck::Failed(reason),
+        }
+    }
+
+    /// Applies the provider check to a cached receipt. Returns None to drop
+    /// it, else the bytes to emit (rebuilt when the verdict changes them).
+    fn check_cached(&self, bytes: &[u8]) -> Option<Vec<u8>> {
+        let Some(parsed) = parsed_event(bytes) else {
+            return Some(bytes.to_vec());
+        };
+        let Some(zap) = parsed.parsed_as_kind_9735_parsed() else {
+            return Some(bytes.to_vec());
+        };
+        if !zap.valid() {
+            return (!self.drop_invalid).then(|| bytes.to_vec());
+        }
+
+        let (verified, failure) = match self.check_provider(zap.recipient(), parsed.pubkey()) {
+            ZapProviderCheck::Pending => (false, None),
+            ZapProviderCheck::Verified => (true, None),
+            ZapProviderCheck::Failed(_) if self.drop_invalid => return None,
+            ZapProviderCheck::Failed(reason) => (false, Some(reason)),
+        };
+        if verified == zap.provider_verified() && failure.is_none() {
+            return Some(bytes.to_vec());
+        }
+
+        let mut unpacked = flatbuffers::root::<fb::WorkerMessage>(bytes).ok()?.unpack();
+        if let fb::MessageT::ParsedEvent(event) = &mut unpacked.content {
+            if let fb::ParsedDataT::Kind9735Parsed(zap) = &mut event.parsed {
+                zap.provider_verified = verified;
+                if failure.is_some() {
+                    zap.valid = false;
+                    zap.invalid_reason = failure;
+                }
+            }
+        }
+        let mut builder = flatbuffers::FlatBufferBuilder::new();
+        let root = unpacked.pack(&mut builder);
+        builder.finish(root, None);
+        Some(builder.finished_data().to_vec())
+    }
+}
+
+fn parsed_event(bytes: &[u8]) -> Option<fb::ParsedEvent<'_>> {
+    flatbuffers::root::<fb::WorkerMessage>(bytes)
+        .ok()
+        .and_then(|msg| msg.content_as_parsed_event())
+}
+
+impl Pipe for ZapValidationPipe {
+    async fn process(&mut self, mut event: PipelineEvent) -> Result<PipeOutput> {
+        let valid = match event.parsed.as_mut() {
+            Some(parsed_event) if parsed_event.event.kind == 9735 => {
+                let receipt_pubkey = parsed_event.event.pubkey.to_hex();
+                match parsed_event.parsed.as_mut() {
+                    Some(ParsedData::Kind9735(zap)) => {
+                        if zap.valid {
+                            zap.provider = self.check_provider(&zap.recipient, &receipt_pubkey);
+                            // Bytes stashed upstream do not carry the verdict
+                            event.serialized = None;
+                        }
+                        zap.valid && !matches!(zap.provider, ZapProviderCheck::Failed(_))
+                    }
+                    _ => false,
+                }
+            }
+            _ => true,
+        };
+
+        if !valid && self.drop_invalid {
+            debug!("Dropping invalid zap receipt {}", hex::encode(event.id));
+            return Ok(PipeOutput::Drop);
+        }
+        Ok(PipeOutput::Event(event))
+    }
+
+    async fn process_cached_batch(&mut self, messages: &[Vec<u8>]) -> Result<Vec<Vec<u8>>> {
+        // Cached profiles name the providers for cached receipts
+        for parsed in messages.iter().filter_map(|bytes| parsed_event(bytes)) {
+            if let Some(profile) = parsed.parsed_as_kind_0_parsed() {
+                self.providers.remember_address(
+                    parsed.pubkey(),
+                    parsed.created_at() as u64,
+                    profile
+                        .lud16()
+                        .filter(|a| !a.trim().is_empty())
+                        .or(profile.lud06()),
+                );
+            }
+        }
+
+        Ok(messages
+            .iter()
+            .filter_map(|bytes| self.check_cached(bytes))
+            .collect())
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache_input;
+    use crate::channel::MessageSender;
+    use crate::parser::{Kind9735Parsed, ZapRequest};
+    use crate::parser_types::parsed_event::ParsedEvent;
+    use crate::pipeline::pipes::save_to_db::SaveToDbPipe;
+    use crate::types::nostr::{Event, EventId, NostrTags, PublicKey};
+    use futures::StreamExt;
+    use std::sync::Arc;
+
+    const PROVIDER: [u8; 32] = [7; 32];
+    const RECIPIENT: &str = "b0635d6a9851d3aed0cd6c495b282167acf761729078d975fc341b22650b07b9";
+
+    /// A receipt that passed the parser's offline checks, signed by `signer`.
+    fn receipt(signer: [u8; 32]) -> PipelineEvent {
+        let event = Event {
+            id: EventId([9; 32]),
+            pubkey: PublicKey(signer),
+            created_at: 1_700_000_001,
+            kind: 9735,
+            tags: vec![vec!["p".to_string(), RECIPIENT.to_string()]],
+            content: String::new(),
+            sig: hex::encode([4; 64]),
+        };
+        let zap = Kind9735Parsed {
+            id: event.id.to_hex(),
+            amount: 1000,
+            content: String::new(),
+            bolt11: "lnbc10u1".to_string(),
+            preimage: None,
+            sender: hex::encode([5; 32]),
+            recipient: RECIPIENT.to_string(),
+            event: None,
+            event_coordinate: None,
+            timestamp: event.created_at,
+            valid: true,
+            description: ZapRequest {
+                kind: 9734,
+                pubkey: hex::encode([5; 32]),
+                content: String::new(),
+                tags: NostrTags(Vec::new()),
+                signature: None,
+                id: None,
+                created_at: 1_700_000_000,
+            },
+            invalid_reason: None,
+            provider: ZapProviderCheck::Pending,
+        };
+        PipelineEvent::from_parsed(ParsedEvent::new(event).with_parsed(ParsedData::Kind9735(zap)))
+    }
+
+    fn pipe(drop_invalid: bool) -> ZapValidationPipe {
+        let providers = ZapProviders::default();
+        providers.remember_address(RECIPIENT, 1, Some("alice@wallet.example"));
+        providers.remember_provider("alice@wallet.example", &hex::encode(PROVIDER));
+        ZapValidationPipe::new(drop_invalid).with_providers(providers)
+    }
+
+    fn provider_check(output: &PipeOutput) -> &ZapProviderCheck {
+        match output {
+            PipeOutput::Event(event) => match event.parsed.as_ref().unwrap().parsed.as_ref() {
+                Some(ParsedData::Kind9735(zap)) => &zap.provider,
+                _ => panic!("expected a zap receipt"),
+            },
+            _ => panic!("expected the receipt to pass"),
+        }
+    }
+
+    #[tokio::test]
+    async fn receipts_stay_pending_until_the_provider_is_known() {
+        let mut pipe = ZapValidationPipe::new(true);
+        let output = pipe.process(receipt(PROVIDER)).await.unwrap();
+        assert_eq!(provider_check(&output), &ZapProviderCheck::Pending);
+    }
+
+    #[tokio::test]
+    async fn checks_the_signer_against_the_cached_provider() {
+        let output = pipe(true).process(receipt(PROVIDER)).await.unwrap();
+        assert_eq!(provider_check(&output), &ZapProviderCheck::Verified);
+
+        let output = pipe(true).process(receipt([8; 32])).await.unwrap();
+        assert!(matches!(output, PipeOutput::Drop));
+
+        let output = pipe(false).process(receipt([8; 32])).await.unwrap();
+        assert!(matches!(
+            provider_check(&output),
+            ZapProviderCheck::Failed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn verdicts_are_not_persisted_and_are_redone_for_cached_receipts() {
+        let (tx, mut rx) = futures::channel::mpsc::unbounded::<Vec<u8>>();
+        let to_cache: Arc<dyn MessageSender> = Arc::new(tx);
+        let mut save_pipe = SaveToDbPipe::new(to_cache);
+
+        let mut pipe = pipe(false);
+        let output = pipe.process(receipt(PROVIDER)).await.unwrap();
+        let PipeOutput::Event(event) = output else {
+            panic!("expected the receipt to pass");
+        };
+        let output = save_pipe.process(event).await.unwrap();
+        assert_eq!(provider_check(&output), &ZapProviderCheck::Verified);
+
+        let framed = rx.next().await.unwrap();
+        let (_, stored) = cache_input::split(&framed).unwrap();
+        let zap = parsed_event(stored)
+            .and_then(|p| p.parsed_as_kind_9735_parsed())
+            .unwrap();
+        assert!(zap.valid());
+        assert!(!zap.provider_verified());
+
+        let loaded = pipe.process_cached_batch(&[stored.to_vec()]).await.unwrap();
+        let zap = parsed_event(&loaded[0])
+            .and_then(|p| p.parsed_as_kind_9735_parsed())
+            .unwrap();
+        assert!(zap.valid());
+        assert!(zap.provider_verified());
+    }
+}
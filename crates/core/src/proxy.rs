@@ -0,0 +1,409 @@
+//! SOCKS5 proxy routing for relay sockets and HTTP lookups.
+//!
+//! A `ProxyConfig` names a SOCKS5 endpoint (typically a local Tor daemon)
+//! and which relays go through it. Each engine carries its own: transports
+//! in `RelayConfig::proxy`, and NIP-05/LNURL/mint/link-preview HTTP calls in
+//! the engine's `HttpClients`.
+//!
+//! Hostnames are always handed to the proxy unresolved (`socks5h`), so DNS
+//! never leaks. HTTP calls fail closed: when a proxy applies but the HTTP
+//! client cannot use it, the request errors instead of going out directly.
+
+use std::net::IpAddr;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::OnceLock;
+use std::sync::{Arc, RwLock};
+
+/// Which relay URLs are routed through the proxy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProxyScope {
+    /// Every relay and HTTP request.
+    All,
+    /// Only `.onion` hosts; everything else dials directly.
+    Onion,
+    /// Only these relay URLs (normalized), plus `.onion` hosts, which can
+    /// never be reached directly.
+    Relays(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyConfig {
+    /// Proxy host (name or IP literal).
+    pub host: String,
+    pub port: u16,
+    pub scope: ProxyScope,
+}
+
+impl ProxyConfig {
+    /// Parses `socks5://host:port`, `socks5h://host:port` or bare `host:port`.
+    pub fn parse(addr: &str, scope: ProxyScope) -> Option<Self> {
+        let addr = addr.trim();
+        let rest = addr
+            .strip_prefix("socks5h://")
+            .or_else(|| addr.strip_prefix("socks5://"))
+            .unwrap_or(addr)
+            .trim_end_matches('/');
+        let (host, port) = rest.rsplit_once(':')?;
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        if host.is_empty() {
+            return None;
+        }
+        Some(Self {
+            host: host.to_string(),
+            port: port.parse().ok()?,
+            scope,
+        })
+    }
+
+    /// Tor's default SOCKS port on localhost.
+    pub fn tor(scope: ProxyScope) -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 9050,
+            scope,
+        }
+    }
+
+    /// The proxy as a `socks5h://` URL (remote DNS).
+    pub fn url(&self) -> String {
+        if self.host.contains(':') {
+            format!("socks5h://[{}]:{}", self.host, self.port)
+        } else {
+            format!("socks5h://{}:{}", self.host, self.port)
+        }
+    }
+
+    /// Whether connections to `url` (a relay or HTTP URL) use the proxy.
+    pub fn applies_to(&self, url: &str) -> bool {
+        let onion = host_of(url).is_some_and(|host| host.ends_with(".onion"));
+        match &self.scope {
+            ProxyScope::All => true,
+            ProxyScope::Onion => onion,
+            ProxyScope::Relays(relays) => {
+                let url = url.trim_end_matches('/');
+                onion || relays.iter().any(|relay| relay.trim_end_matches('/') == url)
+            }
+        }
+    }
+}
+
+pub(crate) fn host_of(url: &str) -> Option<String> {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let authority = authority.rsplit_once('@').map_or(authority, |(_, host)| host);
+    let host = if let Some(v6) = authority.strip_prefix('[') {
+        v6.split(']').next()?
+    } else {
+        authority.split(':').next()?
+    };
+    Some(host.to_ascii_lowercase())
+}
+
+/// Refuses `url` when its host is `localhost` or a non-public IP literal.
+/// Hostnames are checked again when they resolve (`public_client`).
+pub(crate) fn check_public_host(url: &str) -> Result<(), String> {
+    let host = host_of(url).ok_or_else(|| format!("no host in {}", url))?;
+    let host = host.trim_end_matches('.');
+    let private = match host.parse() {
+        Ok(ip) => !is_public_ip(ip),
+        Err(_) => host == "localhost" || host.ends_with(".localhost"),
+    };
+    if private {
+        return Err(format!("refusing to fetch {}: not a public host", url));
+    }
+    Ok(())
+}
+
+/// Whether `ip` is a public unicast address, i.e. not loopback, private,
+/// link-local, shared (CGNAT), multicast, documentation or reserved.
+pub(crate) fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, ..] = v4.octets();
+            !(v4.is_unspecified()
+                || v4.is_loopback()
+                || v4.is_private()
+                || v4.is_link_local()
+                || v4.is_broadcast()
+                || v4.is_multicast()
+                || v4.is_documentation()
+                || a == 0
+                || a >= 240
+                || (a == 100 && (64..128).contains(&b))
+                || (a == 198 && (18..20).contains(&b)))
+        }
+        IpAddr::V6(v6) => {
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return is_public_ip(IpAddr::V4(v4));
+            }
+            let [first, second, ..] = v6.segments();
+            !(v6.is_unspecified()
+                || v6.is_loopback()
+                || v6.is_multicast()
+                || (first & 0xfe00) == 0xfc00
+                || (first & 0xffc0) == 0xfe80
+                || (first & 0xffc0) == 0xfec0
+                || (first == 0x2001 && second == 0x0db8)
+                || (first == 0x0064 && second == 0xff9b))
+        }
+    }
+}
+
+/// Resolves hostnames like the system resolver but fails when any address
+/// is not public, so lookups for untrusted URLs cannot reach the local
+/// network, whatever the name pointed to when it was first checked.
+#[cfg(not(target_arch = "wasm32"))]
+struct PublicResolver;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl reqwest::dns::Resolve for PublicResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs: Vec<std::net::SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            if let Some(addr) = addrs.iter().find(|addr| !is_public_ip(addr.ip())) {
+                return Err(format!("{} resolves to non-public address {}", host, addr.ip()).into());
+            }
+            let addrs: reqwest::dns::Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+/// Per-engine HTTP clients for NIP-05, LNURL, mint and link-preview
+/// lookups. One `reqwest::Client` (and connection pool) is built per routing
+/// and redirect policy on first use, then shared by every request until
+/// `set_proxy` replaces them. Clones share the same clients.
+#[derive(Clone, Default)]
+pub struct HttpClients {
+    inner: Arc<RwLock<Arc<ClientSet>>>,
+}
+
+#[derive(Default)]
+struct ClientSet {
+    proxy: Option<ProxyConfig>,
+    #[cfg(not(target_arch = "wasm32"))]
+    built: [OnceLock<Result<reqwest::Client, String>>; 4],
+    /// Direct client of `public_client`.
+    #[cfg(not(target_arch = "wasm32"))]
+    public: OnceLock<Result<reqwest::Client, String>>,
+}
+
+impl HttpClients {
+    pub fn new(proxy: Option<ProxyConfig>) -> Self {
+        let clients = Self::default();
+        clients.set_proxy(proxy);
+        clients
+    }
+
+    /// Routes later requests through `proxy` (or directly when `None`).
+    pub fn set_proxy(&self, proxy: Option<ProxyConfig>) {
+        if let Ok(mut slot) = self.inner.write() {
+            *slot = Arc::new(ClientSet {
+                proxy,
+                ..Default::default()
+            });
+        }
+    }
+
+    pub fn proxy(&self) -> Option<ProxyConfig> {
+        self.current().proxy.clone()
+    }
+
+    fn current(&self) -> Arc<ClientSet> {
+        self.inner
+            .read()
+            .map(|slot| slot.clone())
+            .unwrap_or_default()
+    }
+
+    /// Client for `url`, following redirects.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn client(&self, url: &str) -> Result<reqwest::Client, String> {
+        self.current().client(url, true)
+    }
+
+    /// Client for `url` that returns redirects instead of following them.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn client_without_redirects(&self, url: &str) -> Result<reqwest::Client, String> {
+        self.current().client(url, false)
+    }
+
+    /// Client for untrusted `url`s: returns redirects instead of following
+    /// them, and refuses hostnames that resolve to non-public addresses.
+    /// Behind the proxy the proxy resolves names, so callers still have to
+    /// check IP literals themselves (see `is_public_ip`).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn public_client(&self, url: &str) -> Result<reqwest::Client, String> {
+        let current = self.current();
+        if current.proxy.as_ref().is_some_and(|proxy| proxy.applies_to(url)) {
+            return current.client(url, false);
+        }
+        current
+            .public
+            .get_or_init(|| {
+                reqwest::Client::builder()
+                    .redirect(reqwest::redirect::Policy::none())
+                    .dns_resolver(Arc::new(PublicResolver))
+                    .build()
+                    .map_err(|e| e.to_string())
+            })
+            .clone()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ClientSet {
+    fn client(&self, url: &str, follow_redirects: bool) -> Result<reqwest::Client, String> {
+        let proxy = self.proxy.as_ref().filter(|proxy| proxy.applies_to(url));
+        let slot = usize::from(proxy.is_some()) * 2 + usize::from(follow_redirects);
+        self.built[slot]
+            .get_or_init(|| {
+                let mut builder = reqwest::Client::builder();
+                if !follow_redirects {
+                    builder = builder.redirect(reqwest::redirect::Policy::none());
+                }
+                if let Some(proxy) = proxy {
+                    let proxy = reqwest::Proxy::all(proxy.url())
+                        .map_err(|e| format!("proxy {} unavailable for HTTP: {}", proxy.url(), e))?;
+                    builder = builder.proxy(proxy);
+                }
+                builder.build().map_err(|e| e.to_string())
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_proxy_addresses() {
+        let proxy = ProxyConfig::parse("socks5h://127.0.0.1:9050", ProxyScope::All).unwrap();
+        assert_eq!((proxy.host.as_str(), proxy.port), ("127.0.0.1", 9050));
+        assert_eq!(proxy.url(), "socks5h://127.0.0.1:9050");
+
+        let proxy = ProxyConfig::parse("[::1]:1080", ProxyScope::All).unwrap();
+        assert_eq!(proxy.url(), "socks5h://[::1]:1080");
+
+        assert!(ProxyConfig::parse("localhost", ProxyScope::All).is_none());
+        assert!(ProxyConfig::parse(":9050", ProxyScope::All).is_none());
+    }
+
+    #[test]
+    fn only_public_addresses_pass() {
+        for ip in ["93.184.216.34", "2606:2800:220:1::1"] {
+            assert!(is_public_ip(ip.parse().unwrap()), "{}", ip);
+        }
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public_ip(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[test]
+    fn scope_selects_routed_relays() {
+        let onion = ProxyConfig::tor(ProxyScope::Onion);
+        assert!(onion.applies_to("ws://abcdef.onion"));
+        assert!(onion.applies_to("wss://user@Relay.ONION:443/path"));
+        assert!(!onion.applies_to("wss://relay.damus.io"));
+
+        let listed = ProxyConfig::tor(ProxyScope::Relays(vec!["wss://nos.lol".to_string()]));
+        assert!(listed.applies_to("wss://nos.lol/"));
+        assert!(listed.applies_to("ws://abcdef.onion"));
+        assert!(!listed.applies_to("wss://relay.damus.io"));
+
+        assert!(ProxyConfig::tor(ProxyScope::All).applies_to("https://mint.example"));
+    }
+
+    /// A one-shot SOCKS5 proxy that accepts a CONNECT by hostname, splices
+    /// it to `target` whatever the name, and reports the requested host/port.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn socks_stand_in(
+        target: std::net::SocketAddr,
+    ) -> (ProxyConfig, std::thread::JoinHandle<(String, u16)>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let proxy = std::thread::spawn(move || {
+            let (mut client, _) = listener.accept().unwrap();
+            let mut greeting = [0u8; 2];
+            client.read_exact(&mut greeting).unwrap();
+            let mut methods = vec![0u8; greeting[1] as usize];
+            client.read_exact(&mut methods).unwrap();
+            client.write_all(&[5, 0]).unwrap();
+
+            let mut request = [0u8; 5];
+            client.read_exact(&mut request).unwrap();
+            assert_eq!(request[3], 3, "hostname must reach the proxy unresolved");
+            let mut host = vec![0u8; request[4] as usize];
+            client.read_exact(&mut host).unwrap();
+            let mut port = [0u8; 2];
+            client.read_exact(&mut port).unwrap();
+            client.write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0]).unwrap();
+
+            let mut upstream = std::net::TcpStream::connect(target).unwrap();
+            let mut client_read = client.try_clone().unwrap();
+            let mut upstream_write = upstream.try_clone().unwrap();
+            std::thread::spawn(move || std::io::copy(&mut client_read, &mut upstream_write));
+            let _ = std::io::copy(&mut upstream, &mut client);
+            (String::from_utf8(host).unwrap(), u16::from_be_bytes(port))
+        });
+        (ProxyConfig::parse(&addr, ProxyScope::All).unwrap(), proxy)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn http_requests_go_through_the_proxy() {
+        use std::io::{Read, Write};
+
+        let server = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let target = server.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (mut stream, _) = server.accept().unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            let _ = stream.write_all(
+                b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+            );
+        });
+        let (proxy, requested) = socks_stand_in(target);
+        let http = HttpClients::new(Some(proxy.clone()));
+        assert_eq!(http.proxy(), Some(proxy));
+
+        // `.onion` names never resolve locally, so this only works via the proxy.
+        let url = "http://mint.example.onion/v1/keys";
+        let body = http
+            .client(url)
+            .unwrap()
+            .get(url)
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert_eq!(body, "ok");
+        assert_eq!(
+            requested.join().unwrap(),
+            ("mint.example.onion".to_string(), 80)
+        );
+
+        http.set_proxy(None);
+        assert_eq!(http.proxy(), None);
+    }
+}
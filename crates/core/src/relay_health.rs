@@ -0,0 +1,483 @@
+//! Per-relay health metrics and scoring.
+//!
+//! `RelayHealth` is a cheap-to-clone handle over one shared table of
+//! `RelayHealthStats`, keyed by relay URL. Relay connections record connect
+//! latency, time-to-EOSE, event throughput, OK acceptance and disconnects; the
+//! connections worker adds cross-relay duplicate counts from `SubDedup`. The
+//! cache worker ranks discovered relays by score before `max_relays` is
+//! applied and persists the table through `Storage::save_relay_health` so
+//! scores survive restarts. Connections also scale their reconnect backoff by
+//! the relay's score, and the engine exposes the table for settings screens.
+//!
+//! The handle is `Send + Sync`: on native the workers run on separate threads.
+//! EVENT and duplicate counts are per-relay atomics, so the per-frame hot path
+//! only takes the table's read lock; everything else goes through a per-relay
+//! mutex and the table is write-locked only when a new relay shows up.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Score assigned to relays without any recorded connection attempts, so new
+/// relays are tried before known-bad ones but after known-good ones.
+pub const UNKNOWN_RELAY_SCORE: f64 = 0.5;
+
+/// Relays scoring below this (with enough samples) are treated as dead.
+pub const DEAD_RELAY_SCORE: f64 = 0.15;
+
+/// Connection attempts needed before a relay can be flagged dead.
+const MIN_ATTEMPTS_FOR_DEAD: u32 = 3;
+
+/// Latency (ms) above which a relay is flagged slow.
+const SLOW_RELAY_LATENCY_MS: f64 = 5_000.0;
+
+/// Weight of the newest sample in the latency moving averages.
+const LATENCY_EWMA_ALPHA: f64 = 0.3;
+
+/// Counters are halved once connect attempts pass this, so old history fades
+/// and a relay that recovers can climb back up.
+const MAX_CONNECT_ATTEMPTS: u32 = 200;
+
+/// Persisted metrics for one relay.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelayHealthStats {
+    pub connect_attempts: u32,
+    pub connect_failures: u32,
+    /// Unexpected transport failures/closes after a successful connect.
+    pub disconnects: u32,
+    /// Moving average of websocket open latency (ms); 0 when never connected.
+    pub connect_latency_ms: f64,
+    /// Moving average of REQ → EOSE latency (ms); 0 when never measured.
+    pub eose_latency_ms: f64,
+    pub events_received: u64,
+    /// EVENTs already delivered for the same subscription by another relay.
+    pub duplicate_events: u64,
+    pub ok_accepted: u32,
+    pub ok_rejected: u32,
+    pub last_connected_at: u64,
+    pub last_failure_at: u64,
+}
+
+impl RelayHealthStats {
+    /// Health score in `0.0..=1.0`; higher is better.
+    pub fn score(&self) -> f64 {
+        if self.connect_attempts == 0 {
+            return UNKNOWN_RELAY_SCORE;
+        }
+        let successes = self.connect_attempts.saturating_sub(self.connect_failures) as f64;
+        let availability = (successes + 1.0) / (self.connect_attempts as f64 + 2.0);
+        let stability = (successes + 1.0) / (successes + self.disconnects as f64 + 1.0);
+        let latency = latency_factor(self.connect_latency_ms) * latency_factor(self.eose_latency_ms);
+        let acceptance = (self.ok_accepted as f64 + 1.0)
+            / (self.ok_accepted as f64 + self.ok_rejected as f64 + 2.0);
+        let unique = (self.events_received.saturating_sub(self.duplicate_events) as f64 + 1.0)
+            / (self.events_received as f64 + 1.0);
+
+        availability
+            * stability
+            * (0.5 + 0.5 * latency)
+            * (0.75 + 0.25 * acceptance)
+            * (0.8 + 0.2 * unique)
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.connect_attempts >= MIN_ATTEMPTS_FOR_DEAD && self.score() < DEAD_RELAY_SCORE
+    }
+
+    pub fn is_slow(&self) -> bool {
+        self.connect_latency_ms > SLOW_RELAY_LATENCY_MS || self.eose_latency_ms > SLOW_RELAY_LATENCY_MS
+    }
+
+    fn decay(&mut self) {
+        if self.connect_attempts <= MAX_CONNECT_ATTEMPTS {
+            return;
+        }
+        self.connect_attempts /= 2;
+        self.connect_failures /= 2;
+        self.disconnects /= 2;
+        self.events_received /= 2;
+        self.duplicate_events /= 2;
+        self.ok_accepted /= 2;
+        self.ok_rejected /= 2;
+    }
+}
+
+/// 1.0 for unknown or instant, approaching 0 as latency grows (0.5 at 2s).
+fn latency_factor(latency_ms: f64) -> f64 {
+    if latency_ms <= 0.0 {
+        1.0
+    } else {
+        1.0 / (1.0 + latency_ms / 2_000.0)
+    }
+}
+
+fn ewma(current: f64, sample: f64) -> f64 {
+    if current <= 0.0 {
+        sample
+    } else {
+        current + LATENCY_EWMA_ALPHA * (sample - current)
+    }
+}
+
+/// Score snapshot for one relay, as returned by [`RelayHealth::scores`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelayScore {
+    pub url: String,
+    pub score: f64,
+    pub dead: bool,
+    pub slow: bool,
+    pub stats: RelayHealthStats,
+}
+
+/// One relay's row in the table. Per-EVENT counters accumulate in atomics
+/// and are folded into `stats` whenever the row is read or updated.
+#[derive(Default)]
+struct RelayEntry {
+    stats: Mutex<RelayHealthStats>,
+    events_received: AtomicU64,
+    duplicate_events: AtomicU64,
+}
+
+impl RelayEntry {
+    fn with_stats(stats: RelayHealthStats) -> Self {
+        Self {
+            stats: Mutex::new(stats),
+            ..Default::default()
+        }
+    }
+
+    fn fold(&self, stats: &mut RelayHealthStats) {
+        let events = self.events_received.swap(0, Ordering::AcqRel);
+        let duplicates = self.duplicate_events.swap(0, Ordering::AcqRel);
+        stats.events_received = stats.events_received.saturating_add(events);
+        stats.duplicate_events = stats.duplicate_events.saturating_add(duplicates);
+    }
+
+    fn snapshot(&self) -> RelayHealthStats {
+        let mut stats = self.stats.lock().unwrap();
+        self.fold(&mut stats);
+        stats.clone()
+    }
+}
+
+/// Shared relay health table.
+#[derive(Clone, Default)]
+pub struct RelayHealth {
+    relays: Arc<RwLock<HashMap<String, Arc<RelayEntry>>>>,
+    dirty: Arc<AtomicBool>,
+}
+
+impl RelayHealth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn entry(&self, url: &str) -> Arc<RelayEntry> {
+        if let Some(entry) = self.relays.read().unwrap().get(url) {
+            return entry.clone();
+        }
+        self.relays
+            .write()
+            .unwrap()
+            .entry(url.to_string())
+            .or_default()
+            .clone()
+    }
+
+    fn mark_dirty(&self) {
+        if !self.dirty.load(Ordering::Relaxed) {
+            self.dirty.store(true, Ordering::Release);
+        }
+    }
+
+    fn update(&self, url: &str, f: impl FnOnce(&mut RelayHealthStats)) {
+        let entry = self.entry(url);
+        let mut stats = entry.stats.lock().unwrap();
+        entry.fold(&mut stats);
+        f(&mut stats);
+        stats.decay();
+        self.mark_dirty();
+    }
+
+    pub fn record_connected(&self, url: &str, latency_ms: u64, now_ms: u64) {
+        self.update(url, |s| {
+            s.connect_attempts = s.connect_attempts.saturating_add(1);
+            s.connect_latency_ms = ewma(s.connect_latency_ms, latency_ms as f64);
+            s.last_connected_at = now_ms;
+        });
+    }
+
+    pub fn record_connect_failed(&self, url: &str, now_ms: u64) {
+        self.update(url, |s| {
+            s.connect_attempts = s.connect_attempts.saturating_add(1);
+            s.connect_failures = s.connect_failures.saturating_add(1);
+            s.last_failure_at = now_ms;
+        });
+    }
+
+    pub fn record_disconnect(&self, url: &str, now_ms: u64) {
+        self.update(url, |s| {
+            s.disconnects = s.disconnects.saturating_add(1);
+            s.last_failure_at = now_ms;
+        });
+    }
+
+    pub fn record_eose(&self, url: &str, latency_ms: u64) {
+        self.update(url, |s| {
+            s.eose_latency_ms = ewma(s.eose_latency_ms, latency_ms as f64);
+        });
+    }
+
+    pub fn record_event(&self, url: &str) {
+        self.entry(url).events_received.fetch_add(1, Ordering::Relaxed);
+        self.mark_dirty();
+    }
+
+    pub fn record_duplicate(&self, url: &str) {
+        self.entry(url).duplicate_events.fetch_add(1, Ordering::Relaxed);
+        self.mark_dirty();
+    }
+
+    pub fn record_ok(&self, url: &str, accepted: bool) {
+        self.update(url, |s| {
+            if accepted {
+                s.ok_accepted = s.ok_accepted.saturating_add(1);
+            } else {
+                s.ok_rejected = s.ok_rejected.saturating_add(1);
+            }
+        });
+    }
+
+    pub fn stats(&self, url: &str) -> Option<RelayHealthStats> {
+        self.relays.read().unwrap().get(url).map(|entry| entry.snapshot())
+    }
+
+    pub fn is_dead(&self, url: &str) -> bool {
+        self.stats(url).is_some_and(|stats| stats.is_dead())
+    }
+
+    pub fn score(&self, url: &str) -> f64 {
+        self.relays
+            .read()
+            .unwrap()
+            .get(url)
+            .map_or(UNKNOWN_RELAY_SCORE, |entry| entry.snapshot().score())
+    }
+
+    /// All known relays, best score first.
+    pub fn scores(&self) -> Vec<RelayScore> {
+        let mut scores: Vec<RelayScore> = self
+            .relays
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(url, entry)| {
+                let stats = entry.snapshot();
+                RelayScore {
+                    url: url.clone(),
+                    score: stats.score(),
+                    dead: stats.is_dead(),
+                    slow: stats.is_slow(),
+                    stats,
+                }
+            })
+            .collect();
+        scores.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.url.cmp(&b.url)));
+        scores
+    }
+
+    /// Multiplier for the reconnect backoff: healthy relays keep the base
+    /// schedule, degraded ones wait twice as long and dead ones four times.
+    /// Relays with too few attempts to judge keep the base schedule.
+    pub fn backoff_factor(&self, url: &str) -> u64 {
+        let Some(stats) = self.stats(url) else {
+            return 1;
+        };
+        if stats.connect_attempts < MIN_ATTEMPTS_FOR_DEAD {
+            1
+        } else if stats.is_dead() {
+            4
+        } else if stats.score() < UNKNOWN_RELAY_SCORE {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Orders a candidate relay list for a REQ and applies `max_relays`.
+    ///
+    /// The incoming order (e.g. outbox coverage from `Storage::get_relays`)
+    /// and the health score are weighted equally. Dead relays are dropped as
+    /// long as at least one live candidate remains.
+    pub fn select(&self, relays: Vec<String>, max_relays: usize) -> Vec<String> {
+        let relays_map = self.relays.read().unwrap();
+        let n = relays.len().max(1) as f64;
+        let mut ranked: Vec<(f64, bool, String)> = relays
+            .into_iter()
+            .enumerate()
+            .map(|(i, url)| {
+                let (score, dead) = relays_map
+                    .get(&url)
+                    .map(|entry| entry.snapshot())
+                    .map_or((UNKNOWN_RELAY_SCORE, false), |s| (s.score(), s.is_dead()));
+                let prior = 1.0 - i as f64 / n;
+                (0.5 * prior + 0.5 * score, dead, url)
+            })
+            .collect();
+        drop(relays_map);
+
+        if ranked.iter().any(|(_, dead, _)| !dead) {
+            ranked.retain(|(_, dead, _)| !dead);
+        }
+        ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+        let mut selected: Vec<String> = ranked.into_iter().map(|(_, _, url)| url).collect();
+        if max_relays > 0 {
+            selected.truncate(max_relays);
+        }
+        selected
+    }
+
+    /// Serialized table for `Storage::save_relay_health`.
+    pub fn snapshot(&self) -> Vec<u8> {
+        let table: HashMap<String, RelayHealthStats> = self
+            .relays
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(url, entry)| (url.clone(), entry.snapshot()))
+            .collect();
+        serde_json::to_vec(&table).unwrap_or_default()
+    }
+
+    /// Loads a persisted table. Relays already observed in this session keep
+    /// their live stats.
+    pub fn restore(&self, bytes: &[u8]) {
+        let Ok(saved) = serde_json::from_slice::<HashMap<String, RelayHealthStats>>(bytes) else {
+            tracing::warn!("[RelayHealth] ignoring malformed persisted relay health");
+            return;
+        };
+        let mut relays = self.relays.write().unwrap();
+        for (url, stats) in saved {
+            relays
+                .entry(url)
+                .or_insert_with(|| Arc::new(RelayEntry::with_stats(stats)));
+        }
+    }
+
+    /// Returns whether anything changed since the last call.
+    pub fn take_dirty(&self) -> bool {
+        self.dirty.swap(false, Ordering::AcqRel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_relays_get_neutral_score() {
+        let health = RelayHealth::new();
+        assert_eq!(health.score("wss://new"), UNKNOWN_RELAY_SCORE);
+        assert_eq!(health.backoff_factor("wss://new"), 1);
+    }
+
+    #[test]
+    fn failing_relay_is_dead_and_backs_off_longer() {
+        let health = RelayHealth::new();
+        for _ in 0..5 {
+            health.record_connect_failed("wss://dead", 1);
+            health.record_connected("wss://good", 100, 1);
+        }
+        assert!(health.stats("wss://dead").unwrap().is_dead());
+        assert!(health.score("wss://good") > UNKNOWN_RELAY_SCORE);
+        assert_eq!(health.backoff_factor("wss://dead"), 4);
+        assert_eq!(health.backoff_factor("wss://good"), 1);
+
+        let scores = health.scores();
+        assert_eq!(scores[0].url, "wss://good");
+        assert!(scores[1].dead);
+    }
+
+    #[test]
+    fn slow_eose_lowers_score_and_flags_slow() {
+        let health = RelayHealth::new();
+        for _ in 0..3 {
+            health.record_connected("wss://fast", 100, 1);
+            health.record_eose("wss://fast", 200);
+            health.record_connected("wss://slow", 100, 1);
+            health.record_eose("wss://slow", 9_000);
+        }
+        assert!(health.score("wss://fast") > health.score("wss://slow"));
+        assert!(health.stats("wss://slow").unwrap().is_slow());
+        assert!(!health.stats("wss://fast").unwrap().is_slow());
+    }
+
+    #[test]
+    fn select_drops_dead_relays_and_applies_max() {
+        let health = RelayHealth::new();
+        for _ in 0..5 {
+            health.record_connect_failed("wss://a", 1);
+            health.record_connected("wss://c", 50, 1);
+        }
+        let relays = vec!["wss://a".to_string(), "wss://b".to_string(), "wss://c".to_string()];
+        assert_eq!(
+            health.select(relays.clone(), 0),
+            vec!["wss://b".to_string(), "wss://c".to_string()]
+        );
+        assert_eq!(health.select(relays, 1), vec!["wss://b".to_string()]);
+        // A proven relay overtakes an untried one at the same position.
+        assert_eq!(
+            health.select(vec!["wss://c".to_string(), "wss://b".to_string()], 1),
+            vec!["wss://c".to_string()]
+        );
+        assert_eq!(
+            health.select(vec!["wss://a".to_string()], 0),
+            vec!["wss://a".to_string()]
+        );
+    }
+
+    #[test]
+    fn event_counters_fold_into_stats_across_threads() {
+        let health = RelayHealth::new();
+        health.record_connected("wss://r", 100, 1);
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let health = health.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..250 {
+                        health.record_event("wss://r");
+                    }
+                    health.record_duplicate("wss://r");
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        let stats = health.stats("wss://r").unwrap();
+        assert_eq!(stats.events_received, 1_000);
+        assert_eq!(stats.duplicate_events, 4);
+        assert!(health.take_dirty());
+
+        health.record_event("wss://r");
+        health.record_ok("wss://r", true);
+        assert_eq!(health.stats("wss://r").unwrap().events_received, 1_001);
+    }
+
+    #[test]
+    fn snapshot_round_trips_and_keeps_live_stats() {
+        let health = RelayHealth::new();
+        health.record_connected("wss://r", 120, 7);
+        health.record_ok("wss://r", false);
+        assert!(health.take_dirty());
+        assert!(!health.take_dirty());
+
+        let restored = RelayHealth::new();
+        restored.record_connect_failed("wss://live", 1);
+        restored.restore(&health.snapshot());
+        assert_eq!(restored.stats("wss://r"), health.stats("wss://r"));
+        assert_eq!(restored.stats("wss://live").unwrap().connect_failures, 1);
+    }
+}
@@ -0,0 +1,249 @@
+//! Relay policy: which relays the engine may talk to, and how.
+//!
+//! `RelayPolicy` is a cheap-to-clone handle over one shared
+//! `RelayPolicyRules`, like `RelayHealth`. The engine hands the same handle
+//! to the cache worker, which filters routed, outbox-planned (NIP-65) and
+//! publish relay sets, and to the connections worker, which refuses frames
+//! the policy forbids, caps concurrent connections by evicting the least
+//! recently used idle relay, and closes relays a later update denies.
+//! Rules can be replaced at runtime with `RelayPolicy::set`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, RwLock};
+
+/// Relays that are denied unless a caller replaces the default rules.
+const DEFAULT_DENIED_HOSTS: &[&str] = &["wheat.happytavern.co"];
+
+/// What the engine may do on one relay.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RelayMode {
+    #[default]
+    ReadWrite,
+    /// Subscriptions only; publishes are not sent here.
+    Read,
+    /// Publishes only; subscriptions are not opened here.
+    Write,
+}
+
+impl RelayMode {
+    pub fn can_read(self) -> bool {
+        self != RelayMode::Write
+    }
+
+    pub fn can_write(self) -> bool {
+        self != RelayMode::Read
+    }
+}
+
+/// Policy rules. Host patterns are exact hosts or `*.example.com`, which
+/// matches `example.com` and all of its subdomains.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RelayPolicyRules {
+    /// When non-empty, only relays whose host matches are used.
+    pub allow: Vec<String>,
+    /// Relays whose host matches are never used (checked before `allow`).
+    pub deny: Vec<String>,
+    /// Reject relays addressed by IP literal.
+    pub block_ip_literals: bool,
+    /// Reject `localhost` and loopback relays.
+    pub block_localhost: bool,
+    /// Reject plain `ws://` relays.
+    pub require_tls: bool,
+    /// Cap on open relay connections; 0 means unlimited.
+    pub max_connections: usize,
+    /// Per-relay modes keyed by normalized URL; unlisted relays are read-write.
+    pub modes: HashMap<String, RelayMode>,
+}
+
+impl Default for RelayPolicyRules {
+    fn default() -> Self {
+        Self {
+            allow: Vec::new(),
+            deny: DEFAULT_DENIED_HOSTS.iter().map(|h| h.to_string()).collect(),
+            block_ip_literals: false,
+            block_localhost: false,
+            require_tls: false,
+            max_connections: 0,
+            modes: HashMap::new(),
+        }
+    }
+}
+
+impl RelayPolicyRules {
+    /// Whether `url` may be used at all, with the reason when it may not.
+    pub fn check(&self, url: &str) -> Result<(), String> {
+        let url = url.trim();
+        let lower = url.to_ascii_lowercase();
+        let tls = lower.starts_with("wss://");
+        if !tls && !lower.starts_with("ws://") {
+            return Err(format!("relay URL must start with ws:// or wss://: {}", url));
+        }
+        let host = match crate::proxy::host_of(&lower) {
+            Some(host) if !host.is_empty() => host,
+            _ => return Err(format!("relay URL has no host: {}", url)),
+        };
+        if self.deny.iter().any(|pattern| host_matches(pattern, &host)) {
+            return Err(format!("relay is denied: {}", url));
+        }
+        if !self.allow.is_empty() && !self.allow.iter().any(|pattern| host_matches(pattern, &host)) {
+            return Err(format!("relay is not on the allow list: {}", url));
+        }
+        if self.require_tls && !tls {
+            return Err(format!("relay does not use TLS: {}", url));
+        }
+        let ip = host.parse::<IpAddr>().ok();
+        if self.block_localhost && (host == "localhost" || ip.is_some_and(|ip| ip.is_loopback())) {
+            return Err(format!("localhost relays are blocked: {}", url));
+        }
+        if self.block_ip_literals && ip.is_some() {
+            return Err(format!("IP-literal relays are blocked: {}", url));
+        }
+        Ok(())
+    }
+
+    pub fn mode(&self, url: &str) -> RelayMode {
+        self.modes
+            .get(&crate::utils::normalize_relay_url(url))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+pub(crate) fn host_matches(pattern: &str, host: &str) -> bool {
+    let pattern = pattern.trim().to_ascii_lowercase();
+    match pattern.strip_prefix("*.") {
+        Some(domain) => {
+            host == domain
+                || host
+                    .strip_suffix(domain)
+                    .is_some_and(|prefix| prefix.ends_with('.'))
+        }
+        None => host == pattern,
+    }
+}
+
+/// Shared, runtime-updatable relay policy.
+#[derive(Clone, Default)]
+pub struct RelayPolicy {
+    rules: Arc<RwLock<RelayPolicyRules>>,
+}
+
+impl RelayPolicy {
+    pub fn new(rules: RelayPolicyRules) -> Self {
+        Self {
+            rules: Arc::new(RwLock::new(rules)),
+        }
+    }
+
+    /// Replace the rules; every holder of this handle sees the change.
+    pub fn set(&self, rules: RelayPolicyRules) {
+        *self.rules.write().unwrap() = rules;
+    }
+
+    pub fn rules(&self) -> RelayPolicyRules {
+        self.rules.read().unwrap().clone()
+    }
+
+    pub fn allows(&self, url: &str) -> bool {
+        self.rules.read().unwrap().check(url).is_ok()
+    }
+
+    pub fn can_read(&self, url: &str) -> bool {
+        let rules = self.rules.read().unwrap();
+        rules.check(url).is_ok() && rules.mode(url).can_read()
+    }
+
+    pub fn can_write(&self, url: &str) -> bool {
+        let rules = self.rules.read().unwrap();
+        rules.check(url).is_ok() && rules.mode(url).can_write()
+    }
+
+    /// Keeps the relays subscriptions may be routed to, in order.
+    pub fn filter_read(&self, relays: Vec<String>) -> Vec<String> {
+        relays.into_iter().filter(|relay| self.can_read(relay)).collect()
+    }
+
+    /// Keeps the relays events may be published to, in order.
+    pub fn filter_write(&self, relays: Vec<String>) -> Vec<String> {
+        relays.into_iter().filter(|relay| self.can_write(relay)).collect()
+    }
+
+    pub fn max_connections(&self) -> usize {
+        self.rules.read().unwrap().max_connections
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_rules_keep_the_legacy_blacklist() {
+        let policy = RelayPolicy::default();
+        assert!(policy.allows("wss://relay.damus.io"));
+        assert!(policy.allows("ws://localhost:8080"));
+        assert!(!policy.allows("wss://wheat.happytavern.co"));
+        assert!(!policy.allows("https://relay.damus.io"));
+        assert!(!policy.allows(""));
+    }
+
+    #[test]
+    fn wildcard_allow_and_deny_lists() {
+        let policy = RelayPolicy::new(RelayPolicyRules {
+            allow: vec!["*.nostr.band".to_string(), "nos.lol".to_string()],
+            deny: vec!["spam.nostr.band".to_string()],
+            ..Default::default()
+        });
+        assert!(policy.allows("wss://relay.nostr.band"));
+        assert!(policy.allows("wss://nostr.band/"));
+        assert!(policy.allows("wss://NOS.LOL"));
+        assert!(!policy.allows("wss://spam.nostr.band"));
+        assert!(!policy.allows("wss://evilnostr.band"));
+        assert!(!policy.allows("wss://relay.damus.io"));
+    }
+
+    #[test]
+    fn blocks_ip_localhost_and_plaintext_relays() {
+        let policy = RelayPolicy::new(RelayPolicyRules {
+            block_ip_literals: true,
+            block_localhost: true,
+            require_tls: true,
+            ..Default::default()
+        });
+        assert!(policy.allows("wss://relay.damus.io"));
+        assert!(!policy.allows("ws://relay.damus.io"));
+        assert!(!policy.allows("wss://localhost:7777"));
+        assert!(!policy.allows("wss://127.0.0.1"));
+        assert!(!policy.allows("wss://[2001:db8::1]:443"));
+        assert!(!policy.allows("wss://203.0.113.9"));
+    }
+
+    #[test]
+    fn modes_split_read_and_write_relays() {
+        let policy = RelayPolicy::default();
+        policy.set(RelayPolicyRules {
+            modes: HashMap::from([
+                ("wss://read.example".to_string(), RelayMode::Read),
+                ("wss://write.example".to_string(), RelayMode::Write),
+            ]),
+            ..Default::default()
+        });
+        let relays = vec![
+            "wss://read.example/".to_string(),
+            "wss://write.example".to_string(),
+            "wss://both.example".to_string(),
+        ];
+        assert_eq!(
+            policy.filter_read(relays.clone()),
+            vec!["wss://read.example/", "wss://both.example"]
+        );
+        assert_eq!(
+            policy.filter_write(relays),
+            vec!["wss://write.example", "wss://both.example"]
+        );
+    }
+}
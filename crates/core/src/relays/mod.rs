@@ -21,10 +21,9 @@ pub use crate::types::nostr::{Event, EventId, Filter, PublicKey};
 
 /// Utility functions for the relay module
 pub mod utils {
+    use crate::relay_policy::RelayPolicyRules;
     use crate::relays::RelayError;
 
-    const BLACKLISTED_RELAYS: &[&str] = &["wheat.happytavern.co"];
-
     /// Parse a relay message from JSON string
     // pub fn parse_relay_message(json: &str) -> Result<RelayMessage, RelayError> {
     //     serde_json::from_str(json).map_err(RelayError::ParseError)
@@ -35,29 +34,15 @@ pub mod utils {
     //     serde_json::to_string(msg).map_err(RelayError::SerializeError)
     // }
 
-    /// Validate relay URL format
+    /// Validate relay URL format against the default relay policy
+    /// (see `crate::relay_policy` for configurable rules).
     pub fn validate_relay_url(url: &str) -> Result<(), RelayError> {
         if url.is_empty() {
             return Err(RelayError::InvalidUrl("URL cannot be empty".to_string()));
         }
-
-        let normalized_url = url.trim().to_lowercase();
-        for &blacklisted in BLACKLISTED_RELAYS {
-            if normalized_url.contains(blacklisted) {
-                return Err(RelayError::InvalidUrl(format!(
-                    "Relay URL is blacklisted: {}",
-                    url
-                )));
-            }
-        }
-
-        if !url.starts_with("ws://") && !url.starts_with("wss://") {
-            return Err(RelayError::InvalidUrl(
-                "URL must start with ws:// or wss://".to_string(),
-            ));
-        }
-
-        Ok(())
+        RelayPolicyRules::default()
+            .check(url)
+            .map_err(RelayError::InvalidUrl)
     }
 
     /// Normalize relay URL (remove trailing slash, convert to lowercase)
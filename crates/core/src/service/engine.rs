@@ -5,13 +5,19 @@ use tracing::info;
 #[cfg(not(target_arch = "wasm32"))]
 use std::sync::atomic::{AtomicBool, Ordering};
 
+use crate::auth_policy::{AuthPolicy, AuthPolicyRules};
 #[cfg(not(target_arch = "wasm32"))]
 use crate::channel::TokioWorkerChannel;
 use crate::channel::{FuturesWorkerChannel, MessageSender, WorkerChannel};
 use crate::generated::nostr::fb;
+use crate::nip05::{Nip05Lookups, Nip05Profile};
 use crate::nostr_error::{NostrError, NostrResult};
-use crate::parser::Parser;
+use crate::parser::{CustomParsers, Parser};
+use crate::proxy::HttpClients;
+use crate::relay_health::{RelayHealth, RelayScore};
+use crate::relay_policy::{RelayPolicy, RelayPolicyRules};
 use crate::spawn::spawn_worker;
+use crate::traffic::{TrafficBudget, TrafficStats};
 use crate::traits::{RelayTransport, Storage};
 use crate::types::network::Request;
 use crate::types::nostr::Template;
@@ -33,6 +39,13 @@ pub struct NostrEngine {
     crypto_tx: Box<dyn MessageSender>,
     crypto_clear_tx: mpsc::UnboundedSender<CryptoControl>,
     event_sink: mpsc::Sender<(String, Vec<u8>)>,
+    relay_health: RelayHealth,
+    relay_policy: RelayPolicy,
+    traffic: TrafficStats,
+    auth_policy: AuthPolicy,
+    custom_parsers: CustomParsers,
+    http: HttpClients,
+    nip05: Nip05Lookups,
     #[cfg(target_arch = "wasm32")]
     connections_handle: ConnectionsHandle,
     #[cfg(not(target_arch = "wasm32"))]
@@ -166,8 +179,16 @@ impl NostrEngine {
 
         let (to_main_ch, from_parser_ch) = FuturesWorkerChannel::new_pair();
 
+        let custom_parsers = CustomParsers::default();
+        let http = HttpClients::default();
+        let nip05 = Nip05Lookups::default();
         let crypto_client = crate::crypto_client::CryptoClient::new(Box::new(parser_crypto_ch));
-        let parser = Arc::new(Parser::new(Some(Arc::new(crypto_client))));
+        let parser = Arc::new(
+            Parser::new(Some(Arc::new(crypto_client)))
+                .with_custom_parsers(custom_parsers.clone())
+                .with_http(http.clone())
+                .with_nip05(nip05.clone()),
+        );
 
         let parser_worker = ParserWorker::new(
             parser.clone(),
@@ -181,7 +202,17 @@ impl NostrEngine {
             Box::new(parser_cache_ch),
         );
 
-        let connections_worker = ConnectionsWorker::new(transport);
+        // Shared by both workers: connections records, cache ranks + persists.
+        let relay_health = RelayHealth::new();
+        let relay_policy = RelayPolicy::default();
+        let traffic = TrafficStats::new();
+        let auth_policy = AuthPolicy::default();
+
+        let connections_worker = ConnectionsWorker::new(transport)
+            .with_relay_health(relay_health.clone())
+            .with_relay_policy(relay_policy.clone())
+            .with_traffic(traffic.clone())
+            .with_auth_policy(auth_policy.clone());
         let connections_handle = connections_worker.run(
             Box::new(parser_conn_ch),
             conn_parser_tx,
@@ -192,7 +223,9 @@ impl NostrEngine {
         #[cfg(not(target_arch = "wasm32"))]
         let _connections_handle = connections_handle;
 
-        let cache_worker = CacheWorker::new(storage);
+        let cache_worker = CacheWorker::new(storage)
+            .with_relay_health(relay_health.clone())
+            .with_relay_policy(relay_policy.clone());
         cache_worker.run(
             Box::new(cache_parser_ch),
             cache_parser_tx,
@@ -269,6 +302,13 @@ impl NostrEngine {
             crypto_tx: engine_crypto_tx,
             crypto_clear_tx,
             event_sink,
+            relay_health,
+            relay_policy,
+            traffic,
+            auth_policy,
+            custom_parsers,
+            http,
+            nip05,
             #[cfg(target_arch = "wasm32")]
             connections_handle,
             #[cfg(not(target_arch = "wasm32"))]
@@ -351,9 +391,20 @@ impl NostrEngine {
         let (crypto_clear_tx, mut crypto_clear_rx) = mpsc::unbounded::<CryptoControl>();
         let mut native_threads = Vec::with_capacity(4);
 
+        let custom_parsers = CustomParsers::default();
+        let parser_custom = custom_parsers.clone();
+        let http = HttpClients::default();
+        let parser_http = http.clone();
+        let nip05 = Nip05Lookups::default();
+        let parser_nip05 = nip05.clone();
         native_threads.push(spawn_native_local_thread("nipworker-parser", move || {
             let crypto_client = crate::crypto_client::CryptoClient::new(Box::new(parser_crypto_ch));
-            let parser = Arc::new(Parser::new(Some(Arc::new(crypto_client))));
+            let parser = Arc::new(
+                Parser::new(Some(Arc::new(crypto_client)))
+                    .with_custom_parsers(parser_custom)
+                    .with_http(parser_http)
+                    .with_nip05(parser_nip05),
+            );
             let parser_worker = ParserWorker::new(
                 parser,
                 Arc::from(parser_cache_ch.clone_sender()),
@@ -369,11 +420,25 @@ impl NostrEngine {
 
         let (connections_wake_tx, mut connections_wake_rx) =
             tokio::sync::mpsc::unbounded_channel::<()>();
+        let relay_health = RelayHealth::new();
+        let connections_health = relay_health.clone();
+        let cache_health = relay_health.clone();
+        let relay_policy = RelayPolicy::default();
+        let connections_policy = relay_policy.clone();
+        let cache_policy = relay_policy.clone();
+        let traffic = TrafficStats::new();
+        let connections_traffic = traffic.clone();
+        let auth_policy = AuthPolicy::default();
+        let connections_auth_policy = auth_policy.clone();
 
         native_threads.push(spawn_native_local_thread(
             "nipworker-connections",
             move || {
-                let connections_worker = ConnectionsWorker::new(transport_factory());
+                let connections_worker = ConnectionsWorker::new(transport_factory())
+                    .with_relay_health(connections_health)
+                    .with_relay_policy(connections_policy)
+                    .with_traffic(connections_traffic)
+                    .with_auth_policy(connections_auth_policy);
                 let connections_handle = connections_worker.run(
                     Box::new(parser_conn_ch),
                     conn_parser_tx,
@@ -393,7 +458,9 @@ impl NostrEngine {
         native_threads.push(spawn_native_local_thread("nipworker-cache", move || {
             if let Some(mesh_storage_factory) = mesh_storage_factory {
                 let cache_worker =
-                    CacheWorker::with_mesh_storage(storage_factory(), mesh_storage_factory());
+                    CacheWorker::with_mesh_storage(storage_factory(), mesh_storage_factory())
+                        .with_relay_health(cache_health)
+                        .with_relay_policy(cache_policy);
                 cache_worker.run_with_mesh(
                     Box::new(cache_parser_ch),
                     cache_parser_tx,
@@ -402,7 +469,9 @@ impl NostrEngine {
                     cache_mesh_results.clone_sender(),
                 );
             } else {
-                let cache_worker = CacheWorker::new(storage_factory());
+                let cache_worker = CacheWorker::new(storage_factory())
+                    .with_relay_health(cache_health)
+                    .with_relay_policy(cache_policy);
                 cache_worker.run(
                     Box::new(cache_parser_ch),
                     cache_parser_tx,
@@ -467,12 +536,78 @@ impl NostrEngine {
             crypto_tx: engine_crypto_tx,
             crypto_clear_tx,
             event_sink,
+            relay_health,
+            relay_policy,
+            traffic,
+            auth_policy,
+            custom_parsers,
+            http,
+            nip05,
             connections_wake_tx: Some(connections_wake_tx),
             native_threads,
         };
         (engine, mesh_endpoint)
     }
 
+    /// Live relay health table (connect latency, EOSE latency, rejections…).
+    pub fn relay_health(&self) -> RelayHealth {
+        self.relay_health.clone()
+    }
+
+    /// Per-relay scores, best first, for flagging dead or slow relays.
+    pub fn relay_scores(&self) -> Vec<RelayScore> {
+        self.relay_health.scores()
+    }
+
+    /// Resolves a NIP-05 `name@domain` to its pubkey and listed relays,
+    /// sharing the lookup cache used to verify profiles.
+    pub async fn lookup_nip05(&self, identifier: &str) -> Result<Nip05Profile, String> {
+        self.nip05.lookup(&self.http, identifier).await
+    }
+
+    /// HTTP clients for NIP-05, LNURL, mint and link-preview lookups.
+    /// `set_proxy` on it routes them through a SOCKS5 proxy; relay sockets
+    /// take theirs from the transport's `RelayConfig`.
+    pub fn http(&self) -> HttpClients {
+        self.http.clone()
+    }
+
+    /// Parsers for app-specific kinds. Register on it before subscribing;
+    /// events parsed earlier keep whatever payload they were stored with.
+    pub fn custom_parsers(&self) -> CustomParsers {
+        self.custom_parsers.clone()
+    }
+
+    /// Bytes, frames, events and duplicates per relay and per subscription.
+    /// `set_budget` on it caps a subscription; one over budget is closed.
+    pub fn traffic(&self) -> TrafficStats {
+        self.traffic.clone()
+    }
+
+    /// Start with `rules` instead of the default relay policy.
+    pub fn with_relay_policy(self, rules: RelayPolicyRules) -> Self {
+        self.relay_policy.set(rules);
+        self
+    }
+
+    /// Shared relay policy; `set` on it applies to all workers at runtime.
+    pub fn relay_policy(&self) -> RelayPolicy {
+        self.relay_policy.clone()
+    }
+
+    /// Start with `rules` instead of signing every relay's AUTH challenge
+    /// with the user's key.
+    pub fn with_auth_policy(self, rules: AuthPolicyRules) -> Self {
+        self.auth_policy.set(rules);
+        self
+    }
+
+    /// Shared NIP-42 auth policy. In `AuthMode::Ask` a challenge is reported
+    /// as an `auth-pending` relay status; answer it with `resolve`.
+    pub fn auth_policy(&self) -> AuthPolicy {
+        self.auth_policy.clone()
+    }
+
     pub fn wake(&self) {
         #[cfg(target_arch = "wasm32")]
         {
@@ -523,6 +658,10 @@ impl NostrEngine {
         let main_message = flatbuffers::root::<fb::MainMessage>(bytes)
             .map_err(|e| NostrError::Parse(format!("Failed to decode FlatBuffer: {:?}", e)))?;
 
+        if let Some(subscribe) = main_message.content_as_subscribe() {
+            self.apply_traffic_budget(&subscribe);
+        }
+
         match main_message.content_type() {
             fb::MainContent::Subscribe
             | fb::MainContent::Unsubscribe
@@ -547,6 +686,23 @@ impl NostrEngine {
         Ok(())
     }
 
+    /// Registers the `budget_bytes`/`budget_events` of a Subscribe's config
+    /// with the traffic table; zero means unlimited.
+    fn apply_traffic_budget(&self, subscribe: &fb::Subscribe) {
+        let config = subscribe.config();
+        let (bytes, events) = (config.budget_bytes(), config.budget_events());
+        if bytes == 0 && events == 0 {
+            return;
+        }
+        self.traffic.set_budget(
+            subscribe.subscription_id(),
+            TrafficBudget {
+                max_bytes: (bytes > 0).then_some(bytes),
+                max_events: (events > 0).then_some(events),
+            },
+        );
+    }
+
     pub async fn subscribe(
         &self,
         subscription_id: String,
@@ -956,6 +1112,122 @@ mod tests {
         panic!("timed out waiting for event for {sub_id}");
     }
 
+    // ============================================================================
+    // WebSocket transport for the threaded engine against `nipworker_testrelay`
+    // ============================================================================
+
+    type WsCallbacks<T> = std::rc::Rc<std::cell::RefCell<HashMap<String, std::rc::Rc<dyn Fn(T)>>>>;
+
+    /// Plain `ws://` transport; the threaded tests talk to a real relay
+    /// instead of scripted callbacks.
+    #[derive(Default)]
+    struct WsTransport {
+        sockets: std::rc::Rc<
+            std::cell::RefCell<HashMap<String, futures::channel::mpsc::UnboundedSender<String>>>,
+        >,
+        message_callbacks: WsCallbacks<String>,
+        status_callbacks: WsCallbacks<TransportStatus>,
+    }
+
+    #[async_trait(?Send)]
+    impl RelayTransport for WsTransport {
+        async fn connect(&self, url: &str) -> Result<(), TransportError> {
+            use futures::SinkExt;
+            use tokio_tungstenite::tungstenite::Message;
+
+            let (socket, _) = tokio_tungstenite::connect_async(url)
+                .await
+                .map_err(|e| TransportError::Other(e.to_string()))?;
+            let (mut write, mut read) = socket.split();
+            let (tx, mut rx) = futures::channel::mpsc::unbounded::<String>();
+            self.sockets.borrow_mut().insert(url.to_string(), tx);
+
+            tokio::task::spawn_local(async move {
+                while let Some(frame) = rx.next().await {
+                    if write.send(Message::Text(frame)).await.is_err() {
+                        break;
+                    }
+                }
+                let _ = write.close().await;
+            });
+            let relay = url.to_string();
+            let sockets = self.sockets.clone();
+            let message_callbacks = self.message_callbacks.clone();
+            let status_callbacks = self.status_callbacks.clone();
+            tokio::task::spawn_local(async move {
+                while let Some(Ok(msg)) = read.next().await {
+                    if let Message::Text(text) = msg {
+                        let callback = message_callbacks.borrow().get(&relay).cloned();
+                        if let Some(callback) = callback {
+                            callback(text);
+                        }
+                    }
+                }
+                sockets.borrow_mut().remove(&relay);
+                let callback = status_callbacks.borrow().get(&relay).cloned();
+                if let Some(callback) = callback {
+                    callback(TransportStatus::Closed { url: relay.clone() });
+                }
+            });
+
+            let callback = self.status_callbacks.borrow().get(url).cloned();
+            if let Some(callback) = callback {
+                callback(TransportStatus::Connected {
+                    url: url.to_string(),
+                });
+            }
+            Ok(())
+        }
+
+        fn disconnect(&self, url: &str) {
+            self.sockets.borrow_mut().remove(url);
+        }
+
+        async fn send(&self, url: &str, frame: String) -> Result<(), TransportError> {
+            self.sockets
+                .borrow()
+                .get(url)
+                .ok_or_else(|| TransportError::Other(format!("{} is not connected", url)))?
+                .unbounded_send(frame)
+                .map_err(|e| TransportError::Other(e.to_string()))
+        }
+
+        fn on_message(&self, url: &str, callback: Box<dyn Fn(String)>) {
+            self.message_callbacks
+                .borrow_mut()
+                .insert(url.to_string(), std::rc::Rc::from(callback));
+        }
+
+        fn on_status(&self, url: &str, callback: Box<dyn Fn(TransportStatus)>) {
+            self.status_callbacks
+                .borrow_mut()
+                .insert(url.to_string(), std::rc::Rc::from(callback));
+        }
+    }
+
+    // Transports are single-threaded by design; each engine thread builds its own.
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn ws_transport() -> Arc<dyn RelayTransport> {
+        Arc::new(WsTransport::default())
+    }
+
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn mock_transport() -> Arc<dyn RelayTransport> {
+        Arc::new(MockRelayTransport::new())
+    }
+
+    fn test_relay_event(id: char, kind: u16, content: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id.to_string().repeat(64),
+            "pubkey": "1".repeat(64),
+            "kind": kind,
+            "created_at": 10,
+            "tags": [],
+            "content": content,
+            "sig": "00".repeat(64),
+        })
+    }
+
     // ============================================================================
     // Test 1: Engine Wiring Valid
     // ============================================================================
@@ -968,7 +1240,7 @@ mod tests {
                 let (event_sink_tx, _event_sink_rx) =
                     futures::channel::mpsc::channel::<(String, Vec<u8>)>(8);
                 let engine = NostrEngine::new_threaded(
-                    || Arc::new(MockRelayTransport::new()),
+                    ws_transport,
                     || Arc::new(MockStorage::new()),
                     event_sink_tx,
                 );
@@ -993,7 +1265,7 @@ mod tests {
                     futures::channel::mpsc::channel::<(String, Vec<u8>)>(100);
 
                 let engine = NostrEngine::new_threaded(
-                    || Arc::new(MockRelayTransport::new()),
+                    ws_transport,
                     || Arc::new(MockStorage::new()),
                     event_sink_tx,
                 );
@@ -1090,8 +1362,66 @@ mod tests {
             .await;
     }
 
+    #[tokio::test]
+    async fn test_subscribe_config_sets_traffic_budget() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let storage = Arc::new(MockStorage::new());
+                let (event_sink_tx, _event_sink_rx) =
+                    futures::channel::mpsc::channel::<(String, Vec<u8>)>(100);
+                let engine = NostrEngine::new(mock_transport(), storage, event_sink_tx);
+
+                let mut builder = flatbuffers::FlatBufferBuilder::new();
+                let sub_id = builder.create_string("budgeted");
+                let requests = builder.create_vector::<flatbuffers::WIPOffset<fb::Request>>(&[]);
+                let config = fb::SubscriptionConfigT {
+                    budget_events: 5,
+                    ..Default::default()
+                }
+                .pack(&mut builder);
+                let subscribe = fb::Subscribe::create(
+                    &mut builder,
+                    &fb::SubscribeArgs {
+                        subscription_id: Some(sub_id),
+                        requests: Some(requests),
+                        config: Some(config),
+                    },
+                );
+                let main_msg = fb::MainMessage::create(
+                    &mut builder,
+                    &fb::MainMessageArgs {
+                        content_type: fb::MainContent::Subscribe,
+                        content: Some(subscribe.as_union_value()),
+                    },
+                );
+                builder.finish(main_msg, None);
+
+                engine
+                    .handle_message(builder.finished_data())
+                    .await
+                    .unwrap();
+                let budget = engine.traffic().subscription("budgeted").unwrap().budget;
+                assert_eq!(
+                    budget,
+                    Some(TrafficBudget {
+                        max_bytes: None,
+                        max_events: Some(5),
+                    })
+                );
+            })
+            .await;
+    }
+
     #[tokio::test]
     async fn test_threaded_engine_cache_only_roundtrip() {
+        let relay = nipworker_testrelay::TestRelay::start(Default::default())
+            .await
+            .unwrap();
+        relay
+            .publish(test_relay_event('a', 1, "from relay"))
+            .unwrap();
+        let url = relay.url();
         let local = LocalSet::new();
         local
             .run_until(async {
@@ -1103,7 +1433,7 @@ mod tests {
                     futures::channel::mpsc::channel::<(String, Vec<u8>)>(100);
 
                 let engine = NostrEngine::new_threaded(
-                    || Arc::new(MockRelayTransport::new()),
+                    ws_transport,
                     move || storage_for_worker,
                     event_sink_tx,
                 );
@@ -1113,7 +1443,7 @@ mod tests {
                         sub_id.to_string(),
                         vec![Request {
                             cache_only: true,
-                            relays: vec!["wss://r".to_string()],
+                            relays: vec![url],
                             ..Default::default()
                         }],
                     )
@@ -1128,6 +1458,115 @@ mod tests {
                 );
             })
             .await;
+        assert!(
+            relay.received().is_empty(),
+            "cache-only subscriptions must not reach the relay"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_threaded_engine_subscribes_against_test_relay() {
+        let relay = nipworker_testrelay::TestRelay::start(Default::default())
+            .await
+            .unwrap();
+        for (id, kind) in [('a', 1), ('b', 1), ('c', 7)] {
+            relay.publish(test_relay_event(id, kind, "")).unwrap();
+        }
+        let url = relay.url();
+
+        let ids = LocalSet::new()
+            .run_until(async move {
+                let (event_sink_tx, mut event_sink_rx) =
+                    futures::channel::mpsc::channel::<(String, Vec<u8>)>(100);
+                let engine = NostrEngine::new_threaded(
+                    ws_transport,
+                    || Arc::new(MockStorage::new()),
+                    event_sink_tx,
+                );
+                engine
+                    .subscribe(
+                        "relay-feed".to_string(),
+                        vec![Request {
+                            relays: vec![url],
+                            kinds: vec![1],
+                            ..Default::default()
+                        }],
+                    )
+                    .await
+                    .unwrap();
+
+                let mut ids = Vec::new();
+                loop {
+                    let bytes = next_event_for_sub(&mut event_sink_rx, "relay-feed").await;
+                    let wm = flatbuffers::root::<fb::WorkerMessage>(&bytes).unwrap();
+                    match wm.content_type() {
+                        fb::Message::ParsedEvent => {
+                            ids.push(wm.content_as_parsed_event().unwrap().id().to_string());
+                        }
+                        fb::Message::ConnectionStatus
+                            if wm.content_as_connection_status().unwrap().status() == "EOSE" =>
+                        {
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                ids.sort();
+                ids
+            })
+            .await;
+        assert_eq!(ids, vec!["a".repeat(64), "b".repeat(64)]);
+    }
+
+    #[tokio::test]
+    async fn test_threaded_engine_publishes_to_test_relay() {
+        const SECRET: &str = "f7e69dd87239da6a828fb9a2fbf481b5b9e147edb848497620e8dc6f5ec10a0a";
+
+        let relay = nipworker_testrelay::TestRelay::start(Default::default())
+            .await
+            .unwrap();
+        let url = relay.url();
+
+        LocalSet::new()
+            .run_until(async {
+                let (event_sink_tx, mut event_sink_rx) =
+                    futures::channel::mpsc::channel::<(String, Vec<u8>)>(100);
+                let engine = NostrEngine::new_threaded(
+                    ws_transport,
+                    || Arc::new(MockStorage::new()),
+                    event_sink_tx,
+                );
+                engine
+                    .handle_message(&build_set_private_key_message(SECRET))
+                    .await
+                    .unwrap();
+                next_crypto_worker_message(&mut event_sink_rx).await;
+
+                let template = Template {
+                    kind: 1,
+                    content: "hello relay".to_string(),
+                    tags: vec![],
+                    created_at: 0,
+                };
+                engine
+                    .publish("pub-relay".to_string(), &template, vec![url], vec![])
+                    .await
+                    .unwrap();
+
+                let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+                while relay.events().is_empty() {
+                    assert!(
+                        tokio::time::Instant::now() < deadline,
+                        "relay never stored the event; received {:?}",
+                        relay.received()
+                    );
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                }
+            })
+            .await;
+        let events = relay.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0]["content"], "hello relay");
     }
 
     // ============================================================================
@@ -159,6 +159,8 @@ async fn test_seen_ids_deduplication_bounded() {
                         requests: None,
                         relays: None,
                         tags: Some(tags_offset),
+                        nevent: None,
+                        naddr: None,
                     },
                 );
                 let wm = fb::WorkerMessage::create(
@@ -208,6 +210,8 @@ async fn test_seen_ids_deduplication_bounded() {
                         requests: None,
                         relays: None,
                         tags: Some(tags_offset),
+                        nevent: None,
+                        naddr: None,
                     },
                 );
                 let wm = fb::WorkerMessage::create(
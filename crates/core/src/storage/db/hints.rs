@@ -0,0 +1,299 @@
+//! Relay hints harvested from cached events.
+//!
+//! Tags (`e`/`E`/`q` for events, `p`/`P` for pubkeys, `a`/`A` for the
+//! address author) and decoded `nevent`/`nprofile`/`naddr` content entities
+//! carry the relay where the referenced event or profile can be found. The
+//! store keeps, per event id and per pubkey, the hinted relays with the best
+//! confidence seen and the newest hinting event's `created_at`, so lookups
+//! can prefer strong, recent hints. The author named next to an event
+//! reference (NIP-10 `e` tag pubkey, `nevent` author) is kept too, so
+//! lookups by id can fall back to the author's relays. Recording is
+//! idempotent, which lets the store be refilled when indexes are rebuilt
+//! from storage.
+
+use rustc_hash::FxHashMap;
+
+/// Confidence of a relay hint in an event tag.
+pub const TAG_HINT_CONFIDENCE: f32 = 0.6;
+/// Confidence of a relay in a decoded `nostr:` entity; authors pick these
+/// deliberately when sharing.
+pub const ENTITY_HINT_CONFIDENCE: f32 = 0.8;
+/// Confidence for a pubkey inferred from a hint about one of its events.
+pub const AUTHOR_HINT_CONFIDENCE: f32 = 0.4;
+
+/// Relays kept per event id or pubkey.
+const MAX_RELAYS_PER_KEY: usize = 8;
+/// Keys kept per table before the stalest quarter is dropped.
+const MAX_HINT_KEYS: usize = 50_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hint {
+    confidence: f32,
+    seen_at: u32,
+}
+
+type HintTable = FxHashMap<String, Vec<(String, Hint)>>;
+
+#[derive(Debug, Default)]
+pub struct RelayHints {
+    events: HintTable,
+    pubkeys: HintTable,
+    /// Author of a referenced event id, with the newest `seen_at`.
+    authors: FxHashMap<String, (String, u32)>,
+}
+
+impl RelayHints {
+    pub fn add_event_hint(&mut self, event_id: &str, relay: &str, confidence: f32, seen_at: u32) {
+        record(&mut self.events, event_id, relay, confidence, seen_at);
+    }
+
+    pub fn add_pubkey_hint(&mut self, pubkey: &str, relay: &str, confidence: f32, seen_at: u32) {
+        record(&mut self.pubkeys, pubkey, relay, confidence, seen_at);
+    }
+
+    /// Best relays for an event id, strongest and newest first.
+    pub fn event_relays(&self, event_id: &str, limit: usize) -> Vec<String> {
+        best(&self.events, event_id, limit)
+    }
+
+    /// Best relays for a pubkey, strongest and newest first.
+    pub fn pubkey_relays(&self, pubkey: &str, limit: usize) -> Vec<String> {
+        best(&self.pubkeys, pubkey, limit)
+    }
+
+    pub fn add_event_author(&mut self, event_id: &str, author: &str, seen_at: u32) {
+        if event_id.is_empty() || !is_hex_key(author) {
+            return;
+        }
+        if self.authors.len() >= MAX_HINT_KEYS && !self.authors.contains_key(event_id) {
+            let mut newest: Vec<u32> = self.authors.values().map(|(_, seen)| *seen).collect();
+            newest.sort_unstable();
+            let cutoff = newest[newest.len() / 4];
+            self.authors.retain(|_, (_, seen)| *seen > cutoff);
+        }
+        let entry = self
+            .authors
+            .entry(event_id.to_string())
+            .or_insert_with(|| (author.to_string(), seen_at));
+        if seen_at >= entry.1 {
+            *entry = (author.to_string(), seen_at);
+        }
+    }
+
+    /// Author hinted for an event id, if any reference named one.
+    pub fn event_author(&self, event_id: &str) -> Option<&str> {
+        self.authors.get(event_id).map(|(author, _)| author.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty() && self.pubkeys.is_empty() && self.authors.is_empty()
+    }
+
+    /// Harvests the relay hint of one event tag, if it has one.
+    pub fn add_tag(&mut self, items: &[&str], seen_at: u32) {
+        let (Some(name), Some(value)) = (items.first(), items.get(1)) else {
+            return;
+        };
+        let is_event = matches!(*name, "e" | "E" | "q");
+        // NIP-10 / NIP-22 carry the referenced author after the marker, even
+        // when the relay slot is empty.
+        let author = items.iter().skip(3).find(|item| is_hex_key(item));
+        if let (true, Some(author)) = (is_event, author) {
+            self.add_event_author(value, author, seen_at);
+        }
+        let Some(relay) = items.get(2) else {
+            return;
+        };
+        match *name {
+            "e" | "E" | "q" => {
+                self.add_event_hint(value, relay, TAG_HINT_CONFIDENCE, seen_at);
+                if let Some(author) = author {
+                    self.add_pubkey_hint(author, relay, AUTHOR_HINT_CONFIDENCE, seen_at);
+                }
+            }
+            "p" | "P" => self.add_pubkey_hint(value, relay, TAG_HINT_CONFIDENCE, seen_at),
+            "a" | "A" => {
+                if let Some(pubkey) = value.split(':').nth(1) {
+                    self.add_pubkey_hint(pubkey, relay, TAG_HINT_CONFIDENCE, seen_at);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Harvests a decoded `nevent`/`nprofile`/`naddr`/`note` entity.
+    pub fn add_entity(
+        &mut self,
+        entity: &str,
+        id: &str,
+        author: Option<&str>,
+        relays: &[&str],
+        seen_at: u32,
+    ) {
+        if let ("nevent", Some(author)) = (entity, author) {
+            self.add_event_author(id, author, seen_at);
+        }
+        for relay in relays {
+            match entity {
+                "nevent" | "note" => {
+                    self.add_event_hint(id, relay, ENTITY_HINT_CONFIDENCE, seen_at);
+                    if let Some(author) = author {
+                        self.add_pubkey_hint(author, relay, AUTHOR_HINT_CONFIDENCE, seen_at);
+                    }
+                }
+                "nprofile" | "npub" => {
+                    self.add_pubkey_hint(id, relay, ENTITY_HINT_CONFIDENCE, seen_at);
+                }
+                "naddr" => {
+                    if let Some(author) = author {
+                        self.add_pubkey_hint(author, relay, ENTITY_HINT_CONFIDENCE, seen_at);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn is_hex_key(value: &str) -> bool {
+    value.len() == 64 && value.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Trims a hinted relay URL to the form used as connection key, or `None`
+/// for values that are not websocket URLs.
+fn normalize_relay(relay: &str) -> Option<String> {
+    let relay = relay.trim();
+    let lower = relay.to_ascii_lowercase();
+    if !(lower.starts_with("wss://") || lower.starts_with("ws://")) {
+        return None;
+    }
+    let relay = relay.trim_end_matches('/');
+    if relay.len() <= "wss://".len() || relay.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(relay.to_string())
+}
+
+fn record(table: &mut HintTable, key: &str, relay: &str, confidence: f32, seen_at: u32) {
+    if key.is_empty() {
+        return;
+    }
+    let Some(relay) = normalize_relay(relay) else {
+        return;
+    };
+    if table.len() >= MAX_HINT_KEYS && !table.contains_key(key) {
+        evict_stalest(table);
+    }
+    let hints = table.entry(key.to_string()).or_default();
+    match hints.iter_mut().find(|(url, _)| *url == relay) {
+        Some((_, hint)) => {
+            hint.confidence = hint.confidence.max(confidence);
+            hint.seen_at = hint.seen_at.max(seen_at);
+        }
+        None => hints.push((
+            relay,
+            Hint {
+                confidence,
+                seen_at,
+            },
+        )),
+    }
+    hints.sort_by(|a, b| rank(&b.1, &a.1));
+    hints.truncate(MAX_RELAYS_PER_KEY);
+}
+
+fn rank(a: &Hint, b: &Hint) -> std::cmp::Ordering {
+    a.confidence
+        .total_cmp(&b.confidence)
+        .then_with(|| a.seen_at.cmp(&b.seen_at))
+}
+
+fn best(table: &HintTable, key: &str, limit: usize) -> Vec<String> {
+    table
+        .get(key)
+        .map(|hints| {
+            hints
+                .iter()
+                .take(limit)
+                .map(|(url, _)| url.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn evict_stalest(table: &mut HintTable) {
+    let mut newest: Vec<u32> = table
+        .values()
+        .map(|hints| hints.iter().map(|(_, h)| h.seen_at).max().unwrap_or(0))
+        .collect();
+    newest.sort_unstable();
+    let cutoff = newest[newest.len() / 4];
+    table.retain(|_, hints| hints.iter().any(|(_, h)| h.seen_at > cutoff));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+    const PK: &str = "00000000000000000000000000000000000000000000000000000000000000aa";
+
+    #[test]
+    fn tag_hints_cover_events_authors_and_addresses() {
+        let mut hints = RelayHints::default();
+        hints.add_tag(&["e", ID, "wss://niche.example/", "reply", PK], 10);
+        hints.add_tag(&["p", "bob", "wss://bob.example"], 10);
+        hints.add_tag(&["a", "30023:carol:post", "wss://carol.example"], 10);
+        hints.add_tag(&["e", "no-hint"], 10);
+        hints.add_tag(&["p", "dave", "not a relay"], 10);
+
+        assert_eq!(hints.event_relays(ID, 3), vec!["wss://niche.example"]);
+        assert_eq!(hints.pubkey_relays(PK, 3), vec!["wss://niche.example"]);
+        assert_eq!(hints.pubkey_relays("bob", 3), vec!["wss://bob.example"]);
+        assert_eq!(hints.pubkey_relays("carol", 3), vec!["wss://carol.example"]);
+        assert!(hints.event_relays("no-hint", 3).is_empty());
+        assert!(hints.pubkey_relays("dave", 3).is_empty());
+    }
+
+    #[test]
+    fn stronger_and_newer_hints_rank_first() {
+        let mut hints = RelayHints::default();
+        hints.add_tag(&["q", ID, "wss://old.example"], 10);
+        hints.add_tag(&["q", ID, "wss://new.example"], 20);
+        hints.add_entity("nevent", ID, None, &["wss://shared.example"], 5);
+        // Re-recording is idempotent and only refreshes recency.
+        hints.add_tag(&["q", ID, "wss://old.example"], 10);
+
+        assert_eq!(
+            hints.event_relays(ID, 3),
+            vec![
+                "wss://shared.example".to_string(),
+                "wss://new.example".to_string(),
+                "wss://old.example".to_string(),
+            ]
+        );
+        assert_eq!(hints.event_relays(ID, 1), vec!["wss://shared.example"]);
+    }
+
+    #[test]
+    fn event_references_remember_their_author() {
+        let mut hints = RelayHints::default();
+        hints.add_tag(&["e", ID, "", "root", PK], 10);
+        assert_eq!(hints.event_author(ID), Some(PK));
+        assert!(hints.event_relays(ID, 1).is_empty());
+
+        hints.add_entity("nevent", "other", Some(PK), &[], 10);
+        assert_eq!(hints.event_author("other"), Some(PK));
+        hints.add_entity("nevent", "anon", None, &["wss://a.example"], 10);
+        assert_eq!(hints.event_author("anon"), None);
+    }
+
+    #[test]
+    fn profile_and_address_entities_hint_pubkeys() {
+        let mut hints = RelayHints::default();
+        hints.add_entity("nprofile", PK, None, &["wss://p.example"], 1);
+        hints.add_entity("naddr", "post", Some("carol"), &["wss://a.example"], 1);
+        assert_eq!(hints.pubkey_relays(PK, 2), vec!["wss://p.example"]);
+        assert_eq!(hints.pubkey_relays("carol", 2), vec!["wss://a.example"]);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::generated::nostr::fb::{self, NostrEvent, ParsedEvent, Request, WorkerMessage};
+use crate::outbox::{plan_outbox, OutboxPlan, OUTBOX_COVERAGE_TARGET};
 use crate::platform::now_millis;
+use crate::storage::db::hints::RelayHints;
 use crate::storage::db::sharded_storage::ShardedRingBufferStorage;
 use crate::storage::db::types::{
     DatabaseConfig, DatabaseError, DatabaseIndexes, EventKey, EventRecord, EventStorage,
@@ -10,6 +12,9 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 type Result<T> = std::result::Result<T, DatabaseError>;
 
+/// Hinted relays consulted per referenced event id or pubkey.
+const HINT_RELAYS_PER_REF: usize = 2;
+
 /// A candidate event-key set for query evaluation. Single-value indexed fields
 /// borrow the index set directly (the common case avoids cloning the whole
 /// set); multi-value fields union into an owned set.
@@ -38,6 +43,7 @@ enum GatheredCandidates<'a> {
 }
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::future::Future;
 use std::rc::Rc;
 use std::sync::{Arc, RwLock};
@@ -53,6 +59,8 @@ pub struct NostrDB<S = ShardedRingBufferStorage> {
     is_initialized: Arc<RwLock<bool>>,
     /// NIP-09 deletion tombstones, resolved to index keys at kind-5 ingest
     tombstones: Rc<RefCell<Tombstones>>,
+    /// Relay hints from tags and `nostr:` entities of indexed events
+    hints: RefCell<RelayHints>,
     /// Default relays for nostr operations
     pub default_relays: Vec<String>,
     /// Indexer relays for nostr operations
@@ -78,6 +86,7 @@ impl NostrDB<ShardedRingBufferStorage> {
             storage,
             is_initialized: Arc::new(RwLock::new(false)),
             tombstones: Rc::new(RefCell::new(Tombstones::default())),
+            hints: RefCell::new(RelayHints::default()),
             default_relays,
             indexer_relays,
         }
@@ -830,6 +839,174 @@ impl<S: EventStorage> NostrDB<S> {
         None
     }
 
+    /// Relay hints in the third slot of `e`/`p`/`a`/`q` tags.
+    fn harvest_tag_hints(
+        &self,
+        tags: &flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<fb::StringVec<'_>>>,
+        created_at: u32,
+    ) {
+        let mut hints = self.hints.borrow_mut();
+        for tag in tags.iter() {
+            if let Some(items) = tag.items() {
+                if items.len() >= 3 {
+                    let items: Vec<&str> = items.iter().collect();
+                    hints.add_tag(&items, created_at);
+                }
+            }
+        }
+    }
+
+    /// Relay hints in `nostr:` entities the parser decoded into content blocks
+    /// or article inlines.
+    fn harvest_content_hints(&self, event: &ParsedEvent<'_>) {
+        if let Some(blocks) = event
+            .parsed_as_kind_30023_parsed()
+            .and_then(|k| k.article_blocks())
+        {
+            let mut hints = self.hints.borrow_mut();
+            for block in blocks.iter() {
+                Self::harvest_article_block(&mut hints, block, event.created_at());
+            }
+            return;
+        }
+        let blocks = event
+            .parsed_as_kind_1_parsed()
+            .map(|k| k.parsed_content())
+            .or_else(|| event.parsed_as_kind_4_parsed().and_then(|k| k.parsed_content()))
+            .or_else(|| event.parsed_as_kind_14_parsed().and_then(|k| k.parsed_content()))
+            .or_else(|| {
+                event
+                    .parsed_as_kind_1111_parsed()
+                    .and_then(|k| k.parsed_content())
+            })
+            .or_else(|| {
+                event
+                    .parsed_as_kind_1311_parsed()
+                    .and_then(|k| k.parsed_content())
+            })
+            .or_else(|| {
+                event
+                    .parsed_as_kind_1068_parsed()
+                    .and_then(|k| k.content_blocks())
+            });
+        let Some(blocks) = blocks else {
+            return;
+        };
+        let mut hints = self.hints.borrow_mut();
+        for block in blocks.iter() {
+            let Some(data) = block.data_as_nostr_data() else {
+                continue;
+            };
+            let Some(relays) = data.relays() else {
+                continue;
+            };
+            let relays: Vec<&str> = relays.iter().collect();
+            hints.add_entity(
+                block.type_(),
+                data.id(),
+                data.author(),
+                &relays,
+                event.created_at(),
+            );
+        }
+    }
+
+    /// Entities in an article block's inlines and nested blocks.
+    fn harvest_article_block(hints: &mut RelayHints, block: fb::ArticleBlock<'_>, seen_at: u32) {
+        fn inline_entities(hints: &mut RelayHints, inline: fb::ArticleInline<'_>, seen_at: u32) {
+            if let Some(entity) = inline.entity() {
+                let relays: Vec<&str> = entity.relays().into_iter().flatten().collect();
+                let kind = entity.entity().split('1').next().unwrap_or_default();
+                let id = entity.id().unwrap_or_default();
+                hints.add_entity(kind, id, entity.author(), &relays, seen_at);
+            }
+            for child in inline.children().into_iter().flatten() {
+                inline_entities(hints, child, seen_at);
+            }
+        }
+        for inline in block.inlines().into_iter().flatten() {
+            inline_entities(hints, inline, seen_at);
+        }
+        for child in block.children().into_iter().flatten() {
+            Self::harvest_article_block(hints, child, seen_at);
+        }
+    }
+
+    /// Hinted relays for the events and pubkeys a request references:
+    /// `ids`, `#e`/`#q` (event hints) and `#p`/`#a` (pubkey hints). Event
+    /// references with a hinted author add the author's kind 10002 write
+    /// relays after the direct hints.
+    fn hinted_relays(&self, fb_req: &Request<'_>) -> Vec<String> {
+        let hints = self.hints.borrow();
+        if hints.is_empty() {
+            return Vec::new();
+        }
+        let mut relays: Vec<String> = Vec::new();
+        let mut authors: Vec<String> = Vec::new();
+        let mut push = |found: Vec<String>| {
+            for relay in found {
+                if !relays.contains(&relay) {
+                    relays.push(relay);
+                }
+            }
+        };
+        let mut note_author = |id: &str| {
+            if let Some(author) = hints.event_author(id) {
+                if !authors.iter().any(|a| a == author) {
+                    authors.push(author.to_string());
+                }
+            }
+        };
+        if let Some(ids) = fb_req.ids() {
+            for id in ids {
+                push(hints.event_relays(id, HINT_RELAYS_PER_REF));
+                note_author(id);
+            }
+        }
+        if let Some(tags) = fb_req.tags() {
+            for tag in tags {
+                let Some(items) = tag.items() else {
+                    continue;
+                };
+                if items.len() < 2 {
+                    continue;
+                }
+                let name = items.get(0).trim_start_matches('#');
+                for value in items.iter().skip(1) {
+                    match name {
+                        "e" | "E" | "q" => {
+                            push(hints.event_relays(value, HINT_RELAYS_PER_REF));
+                            note_author(value);
+                        }
+                        "p" | "P" => push(hints.pubkey_relays(value, HINT_RELAYS_PER_REF)),
+                        "a" | "A" => {
+                            if let Some(pubkey) = value.split(':').nth(1) {
+                                push(hints.pubkey_relays(pubkey, HINT_RELAYS_PER_REF));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        drop(hints);
+        if !authors.is_empty() {
+            let mut lists = self.latest_relay_lists(authors.clone());
+            for author in authors {
+                let listed = lists.remove(&author).unwrap_or_default();
+                push(
+                    listed
+                        .into_iter()
+                        .filter(|(_, _, write)| *write)
+                        .map(|(url, _, _)| url)
+                        .take(HINT_RELAYS_PER_REF)
+                        .collect(),
+                );
+            }
+        }
+        relays
+    }
+
     fn index_parsed_event(&self, event: ParsedEvent<'_>, offset: u64) {
         let event_id = event.id();
         let event_key = self
@@ -926,6 +1103,9 @@ impl<S: EventStorage> NostrDB<S> {
             }
         }
 
+        self.harvest_tag_hints(&tags, event.created_at());
+        self.harvest_content_hints(&event);
+
         self.apply_tombstones_to_event(
             event_key,
             event_id,
@@ -1036,6 +1216,7 @@ impl<S: EventStorage> NostrDB<S> {
         }
 
         let created_at = event.created_at().max(0) as u32;
+        self.harvest_tag_hints(&tags, created_at);
         self.apply_tombstones_to_event(
             event_key,
             event_id,
@@ -1428,7 +1609,19 @@ impl<S: EventStorage> NostrDB<S> {
         }
     }
 
+    /// Relays for a request: relays hinted for the referenced events and
+    /// pubkeys first, then the relay-list (or fallback) relays.
     pub fn get_relays(&self, fb_req: &Request<'_>) -> Vec<String> {
+        let mut relays = self.hinted_relays(fb_req);
+        for relay in self.listed_relays(fb_req) {
+            if !relays.contains(&relay) {
+                relays.push(relay);
+            }
+        }
+        relays
+    }
+
+    fn listed_relays(&self, fb_req: &Request<'_>) -> Vec<String> {
         let mut relay_counts: FxHashMap<String, usize> = FxHashMap::default();
 
         // Collect all pubkeys we need to check relays for
@@ -1472,57 +1665,13 @@ impl<S: EventStorage> NostrDB<S> {
             return self.default_relays.clone();
         }
 
-        // Make a single query for all pubkeys' kind 10002 events
-        let mut filter = QueryFilter::new();
-        filter.kinds = Some(vec![10002]);
-        filter.authors = Some(pubkeys_to_check);
-        // No limit since we want the latest 10002 for each author
-
-        if let Ok(result) = self.query_events_with_filter(filter) {
-            // Group events by pubkey and keep only the latest one for each
-            let mut latest_events: FxHashMap<String, Vec<u8>> = FxHashMap::default();
-
-            for event_bytes in result.events {
-                if let Some(event) = Self::extract_parsed_event(&event_bytes) {
-                    let pubkey = event.pubkey().to_string();
-
-                    // Check if we already have an event for this pubkey
-                    if let Some(existing) = latest_events.get(&pubkey) {
-                        if let Some(existing_event) = Self::extract_parsed_event(existing) {
-                            // Keep the newer event
-                            if event.created_at() > existing_event.created_at() {
-                                latest_events.insert(pubkey, event_bytes);
-                            }
-                        }
-                    } else {
-                        latest_events.insert(pubkey, event_bytes);
-                    }
-                }
-            }
-
-            // Process the latest events to extract relays
-            for (pubkey, event_bytes) in latest_events {
-                if let Some(event) = Self::extract_parsed_event(&event_bytes) {
-                    if let Some(kind10002) = event.parsed_as_kind_10002_parsed() {
-                        // Determine if this pubkey needs read or write relays
-                        let is_author = authors_set.contains(&pubkey);
-
-                        for relay in kind10002.relays() {
-                            // If pubkey is in authors filter, we need write relays (they're posting)
-                            // Otherwise, we need read relays (we're reading their events)
-                            if is_author {
-                                if relay.write() {
-                                    let url = relay.url().to_string();
-                                    *relay_counts.entry(url).or_insert(0) += 1;
-                                }
-                            } else {
-                                if relay.read() {
-                                    let url = relay.url().to_string();
-                                    *relay_counts.entry(url).or_insert(0) += 1;
-                                }
-                            }
-                        }
-                    }
+        for (pubkey, relays) in self.latest_relay_lists(pubkeys_to_check) {
+            // If pubkey is in authors filter, we need write relays (they're posting)
+            // Otherwise, we need read relays (we're reading their events)
+            let is_author = authors_set.contains(&pubkey);
+            for (url, read, write) in relays {
+                if (is_author && write) || (!is_author && read) {
+                    *relay_counts.entry(url).or_insert(0) += 1;
                 }
             }
         }
@@ -1549,6 +1698,116 @@ impl<S: EventStorage> NostrDB<S> {
         }
     }
 
+    /// Latest kind 10002 relay list per pubkey as `(url, read, write)`.
+    /// Pubkeys without a cached list are absent from the map.
+    fn latest_relay_lists(
+        &self,
+        pubkeys: Vec<String>,
+    ) -> FxHashMap<String, Vec<(String, bool, bool)>> {
+        // Make a single query for all pubkeys' kind 10002 events
+        let mut filter = QueryFilter::new();
+        filter.kinds = Some(vec![10002]);
+        filter.authors = Some(pubkeys);
+        // No limit since we want the latest 10002 for each author
+
+        let mut lists = FxHashMap::default();
+        let Ok(result) = self.query_events_with_filter(filter) else {
+            return lists;
+        };
+
+        // Group events by pubkey and keep only the latest one for each
+        let mut latest_events: FxHashMap<String, Vec<u8>> = FxHashMap::default();
+        for event_bytes in result.events {
+            if let Some(event) = Self::extract_parsed_event(&event_bytes) {
+                let pubkey = event.pubkey().to_string();
+
+                // Check if we already have an event for this pubkey
+                if let Some(existing) = latest_events.get(&pubkey) {
+                    if let Some(existing_event) = Self::extract_parsed_event(existing) {
+                        // Keep the newer event
+                        if event.created_at() > existing_event.created_at() {
+                            latest_events.insert(pubkey, event_bytes);
+                        }
+                    }
+                } else {
+                    latest_events.insert(pubkey, event_bytes);
+                }
+            }
+        }
+
+        for (pubkey, event_bytes) in latest_events {
+            if let Some(event) = Self::extract_parsed_event(&event_bytes) {
+                if let Some(kind10002) = event.parsed_as_kind_10002_parsed() {
+                    let relays = kind10002
+                        .relays()
+                        .iter()
+                        .map(|relay| (relay.url().to_string(), relay.read(), relay.write()))
+                        .collect();
+                    lists.insert(pubkey, relays);
+                }
+            }
+        }
+        lists
+    }
+
+    /// Outbox plan for a request with `authors`: a set cover over the
+    /// authors' write relays (see `crate::outbox`). Authors without a cached
+    /// relay list fall back to the relays their NIP-05 domain lists
+    /// (`listed`), then to relays hinted for them; those with none count as uncovered and go to
+    /// the indexer relays (default relays when no indexers are configured).
+    /// Returns `None` for requests without authors.
+    pub fn plan_outbox(
+        &self,
+        fb_req: &Request<'_>,
+        max_relays: usize,
+        rank: &dyn Fn(&str) -> f64,
+        usable: &dyn Fn(&str) -> bool,
+        listed: &dyn Fn(&str) -> Vec<String>,
+    ) -> Option<OutboxPlan> {
+        let authors: Vec<String> = fb_req
+            .authors()
+            .map(|authors| authors.iter().map(|a| a.to_string()).collect())
+            .filter(|authors: &Vec<String>| !authors.is_empty())?;
+
+        let mut lists = self.latest_relay_lists(authors.clone());
+        let hints = self.hints.borrow();
+        let author_relays: HashMap<String, Vec<String>> = authors
+            .into_iter()
+            .map(|author| {
+                let write_relays = match lists.remove(&author) {
+                    Some(list) => list
+                        .into_iter()
+                        .filter(|(_, _, write)| *write)
+                        .map(|(url, _, _)| url)
+                        .collect(),
+                    None => {
+                        let listed = listed(&author);
+                        if listed.is_empty() {
+                            hints.pubkey_relays(&author, HINT_RELAYS_PER_REF)
+                        } else {
+                            listed
+                        }
+                    }
+                };
+                (author, write_relays)
+            })
+            .collect();
+
+        let fallback = if self.indexer_relays.is_empty() {
+            &self.default_relays
+        } else {
+            &self.indexer_relays
+        };
+        Some(plan_outbox(
+            &author_relays,
+            OUTBOX_COVERAGE_TARGET,
+            max_relays,
+            fallback,
+            rank,
+            usable,
+        ))
+    }
+
     pub fn get_read_relays(&self, pubkey: &str) -> Option<Vec<String>> {
         let mut filter = QueryFilter::new();
         filter.kinds = Some(vec![10002]);
@@ -1760,6 +2019,7 @@ mod tests {
             storage: CountingStorage::new(max_buffer_size),
             is_initialized: Arc::new(RwLock::new(false)),
             tombstones: Rc::new(RefCell::new(Tombstones::default())),
+            hints: RefCell::new(RelayHints::default()),
             default_relays: vec![],
             indexer_relays: vec![],
         }
@@ -2178,4 +2438,152 @@ mod tests {
         assert!(query_kind(&db, 1).events.is_empty());
         assert_eq!(db.deleted_count(), 1);
     }
+
+    fn build_request(ids: &[&str], tags: &[&[&str]]) -> Vec<u8> {
+        let mut builder = FlatBufferBuilder::new();
+        let ids: Vec<_> = ids.iter().map(|id| builder.create_string(id)).collect();
+        let ids = builder.create_vector(&ids);
+        let tag_offsets: Vec<_> = tags
+            .iter()
+            .map(|tag| {
+                let items: Vec<_> = tag.iter().map(|s| builder.create_string(s)).collect();
+                let items = builder.create_vector(&items);
+                fb::StringVec::create(&mut builder, &fb::StringVecArgs { items: Some(items) })
+            })
+            .collect();
+        let tags = builder.create_vector(&tag_offsets);
+        let request = fb::Request::create(
+            &mut builder,
+            &fb::RequestArgs {
+                ids: Some(ids),
+                tags: Some(tags),
+                ..Default::default()
+            },
+        );
+        builder.finish(request, None);
+        builder.finished_data().to_vec()
+    }
+
+    #[tokio::test]
+    async fn get_relays_prefers_harvested_hints() {
+        let mut db = new_test_db(1024 * 1024);
+        db.default_relays = vec!["wss://default.example".to_string()];
+        db.initialize().await.unwrap();
+
+        let reply = build_parsed_worker_message(
+            &event_id(1),
+            &pubkey_id(1),
+            1,
+            1000,
+            &[
+                &["e", &event_id(0), "wss://root.example/", "root"],
+                &["p", &pubkey_id(2), "wss://bob.example"],
+            ],
+        );
+        db.add_worker_message_bytes(&reply).await.unwrap();
+
+        let by_id = build_request(&[&event_id(0)], &[]);
+        let by_id = flatbuffers::root::<fb::Request>(&by_id).unwrap();
+        assert_eq!(
+            db.get_relays(&by_id),
+            vec!["wss://root.example", "wss://default.example"]
+        );
+
+        let mentions = build_request(&[], &[&["#p", &pubkey_id(2)]]);
+        let mentions = flatbuffers::root::<fb::Request>(&mentions).unwrap();
+        assert_eq!(db.get_relays(&mentions)[0], "wss://bob.example");
+
+        // Hints come back after indexes are rebuilt from storage.
+        db.hints.replace(RelayHints::default());
+        db.rebuild_indexes_from_storage().unwrap();
+        assert_eq!(db.get_relays(&by_id)[0], "wss://root.example");
+    }
+
+    /// Runs `json` through the parser and wraps it like the parser worker.
+    async fn parsed_message(json: serde_json::Value) -> Vec<u8> {
+        let event = crate::types::Event::from_json(&json.to_string()).unwrap();
+        let parsed = crate::parser::Parser::new(None).parse(event).await.unwrap();
+        let mut builder = FlatBufferBuilder::new();
+        let parsed = parsed.build_flatbuffer(&mut builder).unwrap();
+        let message = fb::WorkerMessage::create(
+            &mut builder,
+            &fb::WorkerMessageArgs {
+                content_type: fb::Message::ParsedEvent,
+                content: Some(parsed.as_union_value()),
+                ..Default::default()
+            },
+        );
+        builder.finish(message, None);
+        builder.finished_data().to_vec()
+    }
+
+    #[tokio::test]
+    async fn get_relays_follows_article_entities_and_hinted_authors() {
+        use crate::types::nostr::nips::nip19::{Nip19Event, ToBech32};
+        use crate::types::nostr::{EventId, PublicKey};
+
+        let author = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let mut db = new_test_db(1024 * 1024);
+        db.default_relays = vec!["wss://default.example".to_string()];
+        db.initialize().await.unwrap();
+
+        let nevent = Nip19Event {
+            event_id: EventId([7; 32]),
+            author: Some(PublicKey::from_hex(author).unwrap()),
+            kind: Some(1),
+            relays: vec!["wss://article.example".to_string()],
+        }
+        .to_bech32()
+        .unwrap();
+        let event = |id: &str, kind: u16, tags: serde_json::Value, content: &str| {
+            serde_json::json!({
+                "id": id.repeat(64),
+                "pubkey": author,
+                "created_at": 1000,
+                "kind": kind,
+                "tags": tags,
+                "content": content,
+                "sig": "2".repeat(128),
+            })
+        };
+        let article = event(
+            "a",
+            30023,
+            serde_json::json!([["d", "post"]]),
+            &format!("# Quoted\n\nSee nostr:{nevent} for context."),
+        );
+        let relay_list = event(
+            "b",
+            10002,
+            serde_json::json!([
+                ["r", "wss://outbox.example", "write"],
+                ["r", "wss://inbox.example", "read"]
+            ]),
+            "",
+        );
+        for json in [article, relay_list] {
+            db.add_worker_message_bytes(&parsed_message(json).await).await.unwrap();
+        }
+        // An e tag naming its author without a relay hint.
+        let reply = build_parsed_worker_message(
+            &event_id(1),
+            &pubkey_id(1),
+            1,
+            1000,
+            &[&["e", &event_id(0), "", "root", author]],
+        );
+        db.add_worker_message_bytes(&reply).await.unwrap();
+
+        let quoted = EventId([7; 32]).to_hex();
+        let by_id = build_request(&[&quoted], &[]);
+        let by_id = flatbuffers::root::<fb::Request>(&by_id).unwrap();
+        assert_eq!(
+            db.get_relays(&by_id),
+            vec!["wss://article.example", "wss://outbox.example", "wss://default.example"]
+        );
+
+        let replies = build_request(&[], &[&["#e", &event_id(0)]]);
+        let replies = flatbuffers::root::<fb::Request>(&replies).unwrap();
+        assert_eq!(db.get_relays(&replies)[0], "wss://outbox.example");
+    }
 }
@@ -1,3 +1,4 @@
+pub mod hints;
 pub mod index;
 pub mod nostr_db_storage;
 pub mod ring_buffer;
@@ -1,4 +1,5 @@
 use crate::generated::nostr::fb::Request;
+use crate::outbox::OutboxPlan;
 use crate::storage::db::index::NostrDB;
 use crate::storage::db::sharded_storage::ShardedRingBufferStorage;
 use crate::storage::db::types::{DatabaseError, QueryFilter};
@@ -257,6 +258,17 @@ impl Storage for NostrDbStorage {
     fn get_relays(&self, request: &Request<'_>) -> Option<Vec<String>> {
         Some(self.db.get_relays(request))
     }
+
+    fn plan_outbox(
+        &self,
+        request: &Request<'_>,
+        max_relays: usize,
+        rank: &dyn Fn(&str) -> f64,
+        usable: &dyn Fn(&str) -> bool,
+        listed: &dyn Fn(&str) -> Vec<String>,
+    ) -> Option<OutboxPlan> {
+        self.db.plan_outbox(request, max_relays, rank, usable, listed)
+    }
 }
 
 impl NostrDbStorage {
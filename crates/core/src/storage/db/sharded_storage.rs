@@ -26,7 +26,8 @@ impl ShardId {
         match kind {
             0 => ShardId::Profile,
             3 => ShardId::Replaceable,
-            4 => ShardId::Kind4,
+            // NIP-04 DMs and NIP-17 seals, messages and gift wraps.
+            4 | 13 | 14 | 15 | 1059 => ShardId::Kind4,
             7 => ShardId::Reaction,
             7375 => ShardId::Kind7375,
             1..=9999 => ShardId::Regular,
@@ -463,6 +464,7 @@ mod tests {
         assert_eq!(storage.shard_for_kind(1), ShardId::Regular);
         assert_eq!(storage.shard_for_kind(3), ShardId::Replaceable);
         assert_eq!(storage.shard_for_kind(4), ShardId::Kind4);
+        assert_eq!(storage.shard_for_kind(1059), ShardId::Kind4);
         assert_eq!(storage.shard_for_kind(6), ShardId::Regular);
         assert_eq!(storage.shard_for_kind(7), ShardId::Reaction);
         assert_eq!(storage.shard_for_kind(7375), ShardId::Kind7375);
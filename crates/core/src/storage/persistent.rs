@@ -1,4 +1,5 @@
 use crate::generated::nostr::fb::Request;
+use crate::outbox::OutboxPlan;
 use crate::platform::now_millis;
 use crate::storage::db::sharded_storage::ShardId;
 use crate::storage::NostrDbStorage;
@@ -20,6 +21,11 @@ const TOMBSTONES_KEY: &str = "tombstones";
 /// contain the ciphertext, so they cannot be repaired in place.
 const ENCRYPTED_PARSE_CACHE_VERSION_KEY: &str = "encrypted-parse-cache-version";
 const ENCRYPTED_PARSE_CACHE_VERSION: &[u8] = b"1";
+/// Blob key for the serialized `RelayHealth` table.
+const RELAY_HEALTH_KEY: &str = "relay-health";
+/// Blob key for the link preview cache snapshot.
+const LINK_PREVIEWS_KEY: &str = "link-previews";
+const NIP05_LOOKUPS_KEY: &str = "nip05-lookups";
 /// Maximum entries kept in the deletion WAL before oldest-first compaction.
 const MAX_WAL_ENTRIES: usize = 8192;
 
@@ -332,6 +338,41 @@ impl<B: BlobStore> Storage for PersistentNostrDbStorage<B> {
     fn get_relays(&self, request: &Request<'_>) -> Option<Vec<String>> {
         self.core.get_relays(request)
     }
+
+    fn plan_outbox(
+        &self,
+        request: &Request<'_>,
+        max_relays: usize,
+        rank: &dyn Fn(&str) -> f64,
+        usable: &dyn Fn(&str) -> bool,
+        listed: &dyn Fn(&str) -> Vec<String>,
+    ) -> Option<OutboxPlan> {
+        self.core.plan_outbox(request, max_relays, rank, usable, listed)
+    }
+
+    async fn load_relay_health(&self) -> Result<Option<Vec<u8>>, StorageError> {
+        self.blob_store.get(RELAY_HEALTH_KEY).await
+    }
+
+    async fn save_relay_health(&self, bytes: &[u8]) -> Result<(), StorageError> {
+        self.blob_store.put(RELAY_HEALTH_KEY, bytes).await
+    }
+
+    async fn load_link_previews(&self) -> Result<Option<Vec<u8>>, StorageError> {
+        self.blob_store.get(LINK_PREVIEWS_KEY).await
+    }
+
+    async fn save_link_previews(&self, bytes: &[u8]) -> Result<(), StorageError> {
+        self.blob_store.put(LINK_PREVIEWS_KEY, bytes).await
+    }
+
+    async fn load_nip05_lookups(&self) -> Result<Option<Vec<u8>>, StorageError> {
+        self.blob_store.get(NIP05_LOOKUPS_KEY).await
+    }
+
+    async fn save_nip05_lookups(&self, bytes: &[u8]) -> Result<(), StorageError> {
+        self.blob_store.put(NIP05_LOOKUPS_KEY, bytes).await
+    }
 }
 
 #[cfg(test)]
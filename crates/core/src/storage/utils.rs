@@ -356,6 +356,54 @@ fn build_image_data<'a>(
     )
 }
 
+fn build_cashu_data<'a>(
+    builder: &mut FlatBufferBuilder<'a>,
+    v: fb::CashuData<'_>,
+) -> WIPOffset<fb::CashuData<'a>> {
+    let token = builder.create_string(v.token());
+    let mint = v.mint().map(|s| builder.create_string(s));
+    let unit = v.unit().map(|s| builder.create_string(s));
+    let memo = v.memo().map(|s| builder.create_string(s));
+    let proofs = v.proofs().map(|p| build_proof_vector(builder, p));
+    fb::CashuData::create(
+        builder,
+        &fb::CashuDataArgs {
+            token: Some(token),
+            mint,
+            unit,
+            memo,
+            amount: v.amount(),
+            proofs,
+        },
+    )
+}
+
+fn build_lightning_data<'a>(
+    builder: &mut FlatBufferBuilder<'a>,
+    v: fb::LightningData<'_>,
+) -> WIPOffset<fb::LightningData<'a>> {
+    let invoice = builder.create_string(v.invoice());
+    let network = v.network().map(|s| builder.create_string(s));
+    let description = v.description().map(|s| builder.create_string(s));
+    let description_hash = v.description_hash().map(|s| builder.create_string(s));
+    let payment_hash = v.payment_hash().map(|s| builder.create_string(s));
+    let payee = v.payee().map(|s| builder.create_string(s));
+    fb::LightningData::create(
+        builder,
+        &fb::LightningDataArgs {
+            invoice: Some(invoice),
+            network,
+            amount_msats: v.amount_msats(),
+            timestamp: v.timestamp(),
+            expiry: v.expiry(),
+            description,
+            description_hash,
+            payment_hash,
+            payee,
+        },
+    )
+}
+
 fn build_video_data<'a>(
     builder: &mut FlatBufferBuilder<'a>,
     v: fb::VideoData<'_>,
@@ -439,11 +487,7 @@ fn build_content_block_vector<'a>(
                 }
                 fb::ContentData::CashuData => {
                     if let Some(v) = b.data_as_cashu_data() {
-                        let token = builder.create_string(v.token());
-                        let off = fb::CashuData::create(
-                            builder,
-                            &fb::CashuDataArgs { token: Some(token) },
-                        );
+                        let off = build_cashu_data(builder, v);
                         (fb::ContentData::CashuData, Some(off.as_union_value()))
                     } else {
                         (fb::ContentData::NONE, None)
@@ -451,13 +495,7 @@ fn build_content_block_vector<'a>(
                 }
                 fb::ContentData::LightningData => {
                     if let Some(v) = b.data_as_lightning_data() {
-                        let invoice = builder.create_string(v.invoice());
-                        let off = fb::LightningData::create(
-                            builder,
-                            &fb::LightningDataArgs {
-                                invoice: Some(invoice),
-                            },
-                        );
+                        let off = build_lightning_data(builder, v);
                         (fb::ContentData::LightningData, Some(off.as_union_value()))
                     } else {
                         (fb::ContentData::NONE, None)
@@ -608,6 +646,8 @@ fn build_requests_vector<'a>(
                 max_relays: r.max_relays(),
                 cache_only: r.cache_only(),
                 mesh_only: r.mesh_only(),
+                negentropy: r.negentropy(),
+                background: r.background(),
             },
         );
 
@@ -668,6 +708,7 @@ fn build_kind0<'a>(
             image,
             avatar,
             background,
+            nip05_verified: v.nip05_verified(),
         },
     )
 }
@@ -784,6 +825,39 @@ fn build_kind7<'a>(
     )
 }
 
+fn build_kind14<'a>(
+    builder: &mut FlatBufferBuilder<'a>,
+    v: fb::Kind14Parsed<'_>,
+) -> WIPOffset<fb::Kind14Parsed<'a>> {
+    let rumor_id = builder.create_string(v.rumor_id());
+    let sender = builder.create_string(v.sender());
+    let participants = build_string_vector(builder, v.participants());
+    let chat_id = builder.create_string(v.chat_id());
+    let content = v.content().map(|s| builder.create_string(s));
+    let parsed_content = v
+        .parsed_content()
+        .map(|pc| build_content_block_vector(builder, pc));
+    let subject = v.subject().map(|s| builder.create_string(s));
+    let reply_to = v.reply_to().map(|s| builder.create_string(s));
+    let tags = v.tags().map(|t| build_tags_vector(builder, t));
+    fb::Kind14Parsed::create(
+        builder,
+        &fb::Kind14ParsedArgs {
+            kind: v.kind(),
+            rumor_id: Some(rumor_id),
+            sender: Some(sender),
+            created_at: v.created_at(),
+            participants: Some(participants),
+            chat_id: Some(chat_id),
+            content,
+            parsed_content,
+            subject,
+            reply_to,
+            tags,
+        },
+    )
+}
+
 fn build_kind17<'a>(
     builder: &mut FlatBufferBuilder<'a>,
     v: fb::Kind17Parsed<'_>,
@@ -961,6 +1035,7 @@ fn build_kind9735<'a>(
     let event_coordinate = v.event_coordinate().map(|s| builder.create_string(s));
     let preimage = v.preimage().map(|s| builder.create_string(s));
     let description = build_zap_request(builder, v.description());
+    let invalid_reason = v.invalid_reason().map(|s| builder.create_string(s));
     fb::Kind9735Parsed::create(
         builder,
         &fb::Kind9735ParsedArgs {
@@ -976,6 +1051,8 @@ fn build_kind9735<'a>(
             timestamp: v.timestamp(),
             valid: v.valid(),
             description: Some(description),
+            invalid_reason,
+            provider_verified: v.provider_verified(),
         },
     )
 }
@@ -1020,6 +1097,22 @@ fn build_kind8<'a>(
     )
 }
 
+fn build_custom<'a>(
+    builder: &mut FlatBufferBuilder<'a>,
+    v: fb::CustomParsed<'_>,
+) -> WIPOffset<fb::CustomParsed<'a>> {
+    // Opaque to the engine: copied byte for byte.
+    let schema_id = builder.create_string(v.schema_id());
+    let payload = v.payload().map(|p| builder.create_vector(p.bytes()));
+    fb::CustomParsed::create(
+        builder,
+        &fb::CustomParsedArgs {
+            schema_id: Some(schema_id),
+            payload,
+        },
+    )
+}
+
 fn build_parsed_union<'a>(
     builder: &mut FlatBufferBuilder<'a>,
     pe: fb::ParsedEvent<'_>,
@@ -1063,6 +1156,11 @@ fn build_parsed_union<'a>(
             let off = build_kind8(builder, v);
             (fb::ParsedData::Kind8Parsed, Some(off.as_union_value()))
         }
+        fb::ParsedData::Kind14Parsed => {
+            let v = pe.parsed_as_kind_14_parsed().unwrap();
+            let off = build_kind14(builder, v);
+            (fb::ParsedData::Kind14Parsed, Some(off.as_union_value()))
+        }
         fb::ParsedData::Kind17Parsed => {
             let v = pe.parsed_as_kind_17_parsed().unwrap();
             let off = build_kind17(builder, v);
@@ -1108,6 +1206,11 @@ fn build_parsed_union<'a>(
             let off = build_kind9735(builder, v);
             (fb::ParsedData::Kind9735Parsed, Some(off.as_union_value()))
         }
+        fb::ParsedData::CustomParsed => {
+            let v = pe.parsed_as_custom_parsed().unwrap();
+            let off = build_custom(builder, v);
+            (fb::ParsedData::CustomParsed, Some(off.as_union_value()))
+        }
         fb::ParsedData::NONE => (fb::ParsedData::NONE, None),
         _ => (fb::ParsedData::NONE, None),
     }
@@ -1126,6 +1229,8 @@ fn rebuild_parsed_event<'a>(
     let tags_vec = build_tags_vector(builder, pe.tags());
     let requests_vec = pe.requests().map(|rv| build_requests_vector(builder, rv));
     let relays_vec = pe.relays().map(|rv| build_string_vector(builder, rv));
+    let nevent = pe.nevent().map(|s| builder.create_string(s));
+    let naddr = pe.naddr().map(|s| builder.create_string(s));
 
     let (parsed_type, parsed_union_opt) = build_parsed_union(builder, pe);
 
@@ -1141,6 +1246,8 @@ fn rebuild_parsed_event<'a>(
             requests: requests_vec,
             relays: relays_vec,
             tags: Some(tags_vec),
+            nevent,
+            naddr,
         },
     )
 }
@@ -1297,6 +1404,9 @@ mod tests {
             &mut source,
             &fb::LightningDataArgs {
                 invoice: Some(invoice),
+                amount_msats: 21_000,
+                expiry: 600,
+                ..Default::default()
             },
         );
         let block_type = source.create_string("lightning");
@@ -1344,5 +1454,63 @@ mod tests {
                 .invoice(),
             INVOICE
         );
+        let rebuilt_lightning = rebuilt_block.data_as_lightning_data().unwrap();
+        assert_eq!(rebuilt_lightning.amount_msats(), 21_000);
+        assert_eq!(rebuilt_lightning.expiry(), 600);
+    }
+
+    #[test]
+    fn parsed_union_rebuild_keeps_private_messages() {
+        let mut source = FlatBufferBuilder::new();
+        let rumor_id = source.create_string("rumor");
+        let sender = source.create_string("alice");
+        let alice = source.create_string("alice");
+        let bob = source.create_string("bob");
+        let participants = source.create_vector(&[alice, bob]);
+        let chat_id = source.create_string("alice_bob");
+        let content = source.create_string("hi bob");
+        let subject = source.create_string("lunch");
+        let kind14 = fb::Kind14Parsed::create(
+            &mut source,
+            &fb::Kind14ParsedArgs {
+                kind: 14,
+                rumor_id: Some(rumor_id),
+                sender: Some(sender),
+                created_at: 1,
+                participants: Some(participants),
+                chat_id: Some(chat_id),
+                content: Some(content),
+                subject: Some(subject),
+                ..Default::default()
+            },
+        );
+        let id = source.create_string("wrap");
+        let pubkey = source.create_string("ephemeral");
+        let tags = source.create_vector::<WIPOffset<fb::StringVec>>(&[]);
+        let event = fb::ParsedEvent::create(
+            &mut source,
+            &fb::ParsedEventArgs {
+                id: Some(id),
+                pubkey: Some(pubkey),
+                kind: 1059,
+                parsed_type: fb::ParsedData::Kind14Parsed,
+                parsed: Some(kind14.as_union_value()),
+                tags: Some(tags),
+                ..Default::default()
+            },
+        );
+        source.finish(event, None);
+
+        let source_view = flatbuffers::root::<fb::ParsedEvent>(source.finished_data()).unwrap();
+        let mut rebuilt = FlatBufferBuilder::new();
+        let rebuilt_event = rebuild_parsed_event(&mut rebuilt, source_view);
+        rebuilt.finish(rebuilt_event, None);
+
+        let rebuilt_view = flatbuffers::root::<fb::ParsedEvent>(rebuilt.finished_data()).unwrap();
+        let message = rebuilt_view.parsed_as_kind_14_parsed().expect("Kind14Parsed payload");
+        assert_eq!(message.content(), Some("hi bob"));
+        assert_eq!(message.chat_id(), "alice_bob");
+        assert_eq!(message.subject(), Some("lunch"));
+        assert_eq!(message.participants().len(), 2);
     }
 }
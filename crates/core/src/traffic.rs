@@ -0,0 +1,455 @@
+//! Bandwidth and frame accounting per relay and per subscription.
+//!
+//! `TrafficStats` is a cheap-to-clone handle over one shared table, like
+//! `RelayHealth`. Relay connections record wire bytes and frames in both
+//! directions and the REQs they send; the connections worker attributes
+//! incoming frames, EVENTs and `SubDedup` duplicates to app subscription ids
+//! and the REQ/CLOSE frames it sends on their behalf. A REQ multiplexed for
+//! several subscriptions is split evenly between them. An app CLOSE drops the
+//! subscription's entry; frames that arrive after it are counted towards the
+//! relay only. The engine exposes the table so a data-saver mode can show
+//! what each feed costs.
+//!
+//! A subscription can carry a `TrafficBudget`. The first time its counters
+//! exceed the budget it is marked exhausted: the connections worker stops
+//! forwarding its EVENTs, CLOSEs it on every relay and reports a
+//! `budget-exceeded:` CLOSED to the app.
+//!
+//! Byte counts are the length of the frame text, not including WebSocket or
+//! TLS framing. Frames sent as part of reconnect replays count towards the
+//! relay but not the subscription.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Reason prefix of the CLOSED reported for a subscription over its budget.
+pub const BUDGET_EXCEEDED_PREFIX: &str = "budget-exceeded:";
+
+/// Counters for one relay or one subscription.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrafficCounters {
+    pub bytes_in: u64,
+    pub bytes_out: u64,
+    pub frames_in: u64,
+    pub frames_out: u64,
+    /// EVENT frames received, duplicates included.
+    pub events: u64,
+    /// EVENTs dropped because another relay already delivered them.
+    pub duplicates: u64,
+    pub reqs_sent: u64,
+}
+
+impl TrafficCounters {
+    pub fn total_bytes(&self) -> u64 {
+        self.bytes_in.saturating_add(self.bytes_out)
+    }
+
+    fn add(&mut self, other: &TrafficCounters) {
+        self.bytes_in = self.bytes_in.saturating_add(other.bytes_in);
+        self.bytes_out = self.bytes_out.saturating_add(other.bytes_out);
+        self.frames_in = self.frames_in.saturating_add(other.frames_in);
+        self.frames_out = self.frames_out.saturating_add(other.frames_out);
+        self.events = self.events.saturating_add(other.events);
+        self.duplicates = self.duplicates.saturating_add(other.duplicates);
+        self.reqs_sent = self.reqs_sent.saturating_add(other.reqs_sent);
+    }
+}
+
+/// Limits for one subscription; `None` fields are unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrafficBudget {
+    /// Bytes in and out across all relays.
+    pub max_bytes: Option<u64>,
+    /// EVENT frames received across all relays, duplicates included.
+    pub max_events: Option<u64>,
+}
+
+impl TrafficBudget {
+    fn exceeded_by(&self, counters: &TrafficCounters) -> bool {
+        self.max_bytes.is_some_and(|max| counters.total_bytes() > max)
+            || self.max_events.is_some_and(|max| counters.events > max)
+    }
+}
+
+/// Snapshot for one subscription, as returned by [`TrafficStats::subscriptions`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionTraffic {
+    pub sub_id: String,
+    pub counters: TrafficCounters,
+    /// Per relay, for subscriptions spread over several relays.
+    pub relays: HashMap<String, TrafficCounters>,
+    pub budget: Option<TrafficBudget>,
+    pub exhausted: bool,
+}
+
+#[derive(Default)]
+struct SubEntry {
+    relays: HashMap<String, TrafficCounters>,
+    budget: Option<TrafficBudget>,
+    exhausted: bool,
+}
+
+impl SubEntry {
+    fn counters(&self) -> TrafficCounters {
+        let mut total = TrafficCounters::default();
+        for counters in self.relays.values() {
+            total.add(counters);
+        }
+        total
+    }
+}
+
+#[derive(Default)]
+struct Tables {
+    relays: HashMap<String, TrafficCounters>,
+    subs: HashMap<String, SubEntry>,
+    // Subscriptions that crossed their budget and still need closing.
+    newly_exhausted: Vec<String>,
+}
+
+/// Shared traffic table.
+#[derive(Clone, Default)]
+pub struct TrafficStats {
+    tables: Arc<RwLock<Tables>>,
+}
+
+impl TrafficStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn update_relay(&self, url: &str, f: impl FnOnce(&mut TrafficCounters)) {
+        let mut tables = self.tables.write().unwrap();
+        f(tables.relays.entry(url.to_string()).or_default());
+    }
+
+    /// Only frames we send open an entry; received frames for a subscription
+    /// that was already closed are not attributed.
+    fn update_sub(
+        &self,
+        sub_id: &str,
+        url: &str,
+        create: bool,
+        f: impl FnOnce(&mut TrafficCounters),
+    ) {
+        if sub_id.is_empty() {
+            return;
+        }
+        let mut tables = self.tables.write().unwrap();
+        let entry = if create {
+            tables.subs.entry(sub_id.to_string()).or_default()
+        } else {
+            match tables.subs.get_mut(sub_id) {
+                Some(entry) => entry,
+                None => return,
+            }
+        };
+        f(entry.relays.entry(url.to_string()).or_default());
+        let exceeded = !entry.exhausted
+            && entry
+                .budget
+                .is_some_and(|budget| budget.exceeded_by(&entry.counters()));
+        if exceeded {
+            entry.exhausted = true;
+            tables.newly_exhausted.push(sub_id.to_string());
+        }
+    }
+
+    /// A frame the relay connection put on the wire.
+    pub fn record_relay_sent(&self, url: &str, frame: &str) {
+        let is_req = frame_kind(frame) == Some("REQ");
+        self.update_relay(url, |c| {
+            c.bytes_out = c.bytes_out.saturating_add(frame.len() as u64);
+            c.frames_out = c.frames_out.saturating_add(1);
+            if is_req {
+                c.reqs_sent = c.reqs_sent.saturating_add(1);
+            }
+        });
+    }
+
+    /// A frame the relay connection received.
+    pub fn record_relay_received(&self, url: &str, frame: &str) {
+        let is_event = frame_kind(frame) == Some("EVENT");
+        self.update_relay(url, |c| {
+            c.bytes_in = c.bytes_in.saturating_add(frame.len() as u64);
+            c.frames_in = c.frames_in.saturating_add(1);
+            if is_event {
+                c.events = c.events.saturating_add(1);
+            }
+        });
+    }
+
+    /// A REQ/CLOSE/NEG-* frame sent to `url` on behalf of `sub_id`.
+    pub fn record_sub_sent(&self, sub_id: &str, url: &str, frame: &str) {
+        let is_req = frame_kind(frame) == Some("REQ");
+        self.update_sub(sub_id, url, true, |c| {
+            c.bytes_out = c.bytes_out.saturating_add(frame.len() as u64);
+            c.frames_out = c.frames_out.saturating_add(1);
+            if is_req {
+                c.reqs_sent = c.reqs_sent.saturating_add(1);
+            }
+        });
+    }
+
+    /// A relay frame routed to `sub_id`.
+    pub fn record_sub_received(&self, sub_id: &str, url: &str, frame: &str) {
+        let is_event = frame_kind(frame) == Some("EVENT");
+        self.update_sub(sub_id, url, false, |c| {
+            c.bytes_in = c.bytes_in.saturating_add(frame.len() as u64);
+            c.frames_in = c.frames_in.saturating_add(1);
+            if is_event {
+                c.events = c.events.saturating_add(1);
+            }
+        });
+    }
+
+    /// An EVENT for `sub_id` from `url` dropped by cross-relay dedup.
+    pub fn record_duplicate(&self, sub_id: &str, url: &str) {
+        self.update_relay(url, |c| c.duplicates = c.duplicates.saturating_add(1));
+        self.update_sub(sub_id, url, false, |c| {
+            c.duplicates = c.duplicates.saturating_add(1)
+        });
+    }
+
+    /// A REQ sent to `url` on behalf of every subscription in `members`, e.g.
+    /// a multiplexed one. Each member counts the frame and the REQ, and the
+    /// bytes are split evenly, the first members taking the remainder.
+    pub fn record_shared_sent(&self, members: &[String], url: &str, frame: &str) {
+        if members.is_empty() {
+            return;
+        }
+        let is_req = frame_kind(frame) == Some("REQ");
+        let len = frame.len() as u64;
+        let count = members.len() as u64;
+        for (i, sub_id) in members.iter().enumerate() {
+            let share = len / count + u64::from((i as u64) < len % count);
+            self.update_sub(sub_id, url, true, |c| {
+                c.bytes_out = c.bytes_out.saturating_add(share);
+                c.frames_out = c.frames_out.saturating_add(1);
+                if is_req {
+                    c.reqs_sent = c.reqs_sent.saturating_add(1);
+                }
+            });
+        }
+    }
+
+    /// Caps `sub_id`; a subscription already over the new budget is
+    /// exhausted on its next recorded frame.
+    pub fn set_budget(&self, sub_id: &str, budget: TrafficBudget) {
+        let mut tables = self.tables.write().unwrap();
+        let entry = tables.subs.entry(sub_id.to_string()).or_default();
+        entry.budget = Some(budget);
+    }
+
+    /// Removes the budget and lifts an exhaustion, e.g. before resubscribing.
+    pub fn clear_budget(&self, sub_id: &str) {
+        if let Some(entry) = self.tables.write().unwrap().subs.get_mut(sub_id) {
+            entry.budget = None;
+            entry.exhausted = false;
+        }
+    }
+
+    pub fn is_exhausted(&self, sub_id: &str) -> bool {
+        self.tables
+            .read()
+            .unwrap()
+            .subs
+            .get(sub_id)
+            .is_some_and(|entry| entry.exhausted)
+    }
+
+    /// Subscriptions that crossed their budget since the last call.
+    pub fn take_exhausted(&self) -> Vec<String> {
+        std::mem::take(&mut self.tables.write().unwrap().newly_exhausted)
+    }
+
+    pub fn relay(&self, url: &str) -> Option<TrafficCounters> {
+        self.tables.read().unwrap().relays.get(url).copied()
+    }
+
+    /// All relays, most bytes first.
+    pub fn relays(&self) -> Vec<(String, TrafficCounters)> {
+        let mut relays: Vec<(String, TrafficCounters)> = self
+            .tables
+            .read()
+            .unwrap()
+            .relays
+            .iter()
+            .map(|(url, counters)| (url.clone(), *counters))
+            .collect();
+        relays.sort_by(|a, b| {
+            b.1.total_bytes()
+                .cmp(&a.1.total_bytes())
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        relays
+    }
+
+    pub fn subscription(&self, sub_id: &str) -> Option<SubscriptionTraffic> {
+        self.tables
+            .read()
+            .unwrap()
+            .subs
+            .get(sub_id)
+            .map(|entry| snapshot(sub_id, entry))
+    }
+
+    /// All subscriptions, most bytes first.
+    pub fn subscriptions(&self) -> Vec<SubscriptionTraffic> {
+        let mut subs: Vec<SubscriptionTraffic> = self
+            .tables
+            .read()
+            .unwrap()
+            .subs
+            .iter()
+            .map(|(sub_id, entry)| snapshot(sub_id, entry))
+            .collect();
+        subs.sort_by(|a, b| {
+            b.counters
+                .total_bytes()
+                .cmp(&a.counters.total_bytes())
+                .then_with(|| a.sub_id.cmp(&b.sub_id))
+        });
+        subs
+    }
+
+    /// Sum over all relays.
+    pub fn totals(&self) -> TrafficCounters {
+        let mut total = TrafficCounters::default();
+        for counters in self.tables.read().unwrap().relays.values() {
+            total.add(counters);
+        }
+        total
+    }
+
+    /// Forgets `sub_id` entirely, counters and budget, once the app closed it.
+    pub fn remove_subscription(&self, sub_id: &str) {
+        let mut tables = self.tables.write().unwrap();
+        tables.subs.remove(sub_id);
+        tables.newly_exhausted.retain(|id| id != sub_id);
+    }
+
+    /// Drops the counters of `sub_id`; its budget is kept.
+    pub fn reset_subscription(&self, sub_id: &str) {
+        if let Some(entry) = self.tables.write().unwrap().subs.get_mut(sub_id) {
+            entry.relays.clear();
+        }
+    }
+
+    /// Zeroes every counter, e.g. at the start of a billing period. Budgets
+    /// and exhaustion are kept.
+    pub fn reset(&self) {
+        let mut tables = self.tables.write().unwrap();
+        tables.relays.clear();
+        for entry in tables.subs.values_mut() {
+            entry.relays.clear();
+        }
+    }
+}
+
+fn snapshot(sub_id: &str, entry: &SubEntry) -> SubscriptionTraffic {
+    SubscriptionTraffic {
+        sub_id: sub_id.to_string(),
+        counters: entry.counters(),
+        relays: entry.relays.clone(),
+        budget: entry.budget,
+        exhausted: entry.exhausted,
+    }
+}
+
+/// The verb of a relay frame, e.g. `EVENT`, without parsing the rest.
+fn frame_kind(frame: &str) -> Option<&str> {
+    frame
+        .trim_start()
+        .strip_prefix('[')
+        .and_then(|rest| rest.trim_start().strip_prefix('"'))
+        .and_then(|rest| rest.split('"').next())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relay_and_subscription_counters_are_separate() {
+        let traffic = TrafficStats::new();
+        traffic.record_relay_sent("wss://a", r#"["REQ","s",{}]"#);
+        traffic.record_relay_received("wss://a", r#"["EVENT","s",{}]"#);
+        traffic.record_sub_sent("s", "wss://a", r#"["REQ","s",{}]"#);
+        traffic.record_sub_received("s", "wss://a", r#"["EVENT","s",{}]"#);
+        traffic.record_sub_received("s", "wss://b", r#"["EOSE","s"]"#);
+        traffic.record_duplicate("s", "wss://b");
+
+        let relay = traffic.relay("wss://a").unwrap();
+        assert_eq!(relay.reqs_sent, 1);
+        assert_eq!(relay.events, 1);
+        assert_eq!(relay.bytes_out, 14);
+        assert_eq!(traffic.relay("wss://b").unwrap().duplicates, 1);
+
+        let sub = traffic.subscription("s").unwrap();
+        assert_eq!(sub.counters.frames_in, 2);
+        assert_eq!(sub.counters.events, 1);
+        assert_eq!(sub.counters.duplicates, 1);
+        assert_eq!(sub.relays["wss://b"].frames_in, 1);
+        assert_eq!(traffic.totals().bytes_in, 16);
+    }
+
+    #[test]
+    fn budget_exhausts_once() {
+        let traffic = TrafficStats::new();
+        traffic.set_budget(
+            "s",
+            TrafficBudget {
+                max_events: Some(1),
+                ..Default::default()
+            },
+        );
+        let event = r#"["EVENT","s",{}]"#;
+        traffic.record_sub_received("s", "wss://a", event);
+        assert!(!traffic.is_exhausted("s"));
+        traffic.record_sub_received("s", "wss://a", event);
+        traffic.record_sub_received("s", "wss://b", event);
+        assert!(traffic.is_exhausted("s"));
+        assert_eq!(traffic.take_exhausted(), vec!["s".to_string()]);
+        assert!(traffic.take_exhausted().is_empty());
+
+        traffic.clear_budget("s");
+        assert!(!traffic.is_exhausted("s"));
+    }
+
+    #[test]
+    fn shared_req_is_split_between_members() {
+        let traffic = TrafficStats::new();
+        let members = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        // 17 bytes: 6 + 6 + 5.
+        let frame = r#"["REQ","m",{},{}]"#;
+        assert_eq!(frame.len(), 17);
+        traffic.record_shared_sent(&members, "wss://r", frame);
+
+        let shares: Vec<u64> = members
+            .iter()
+            .map(|id| traffic.subscription(id).unwrap().counters.bytes_out)
+            .collect();
+        assert_eq!(shares, vec![6, 6, 5]);
+        for id in &members {
+            let counters = traffic.subscription(id).unwrap().counters;
+            assert_eq!(counters.reqs_sent, 1);
+            assert_eq!(counters.frames_out, 1);
+        }
+    }
+
+    #[test]
+    fn removed_subscription_is_not_recreated_by_late_frames() {
+        let traffic = TrafficStats::new();
+        traffic.record_sub_sent("s", "wss://a", r#"["REQ","s",{}]"#);
+        traffic.remove_subscription("s");
+        traffic.record_relay_received("wss://a", r#"["EVENT","s",{}]"#);
+        traffic.record_sub_received("s", "wss://a", r#"["EVENT","s",{}]"#);
+        traffic.record_duplicate("s", "wss://a");
+
+        assert!(traffic.subscription("s").is_none());
+        assert!(traffic.subscriptions().is_empty());
+        assert_eq!(traffic.relay("wss://a").unwrap().events, 1);
+    }
+}
@@ -50,6 +50,49 @@ pub trait Storage {
     ) -> Option<Vec<String>> {
         None
     }
+
+    /// Per-relay author split for requests with authors, when the backend
+    /// knows the authors' relay lists. `rank` orders otherwise equal relays;
+    /// relays failing `usable` are left out of the plan. `listed` gives the
+    /// relays an author's verified NIP-05 document lists, for authors
+    /// without a relay list.
+    fn plan_outbox(
+        &self,
+        _request: &crate::generated::nostr::fb::Request<'_>,
+        _max_relays: usize,
+        _rank: &dyn Fn(&str) -> f64,
+        _usable: &dyn Fn(&str) -> bool,
+        _listed: &dyn Fn(&str) -> Vec<String>,
+    ) -> Option<crate::outbox::OutboxPlan> {
+        None
+    }
+
+    /// Persisted `RelayHealth` table, if the backend keeps one.
+    async fn load_relay_health(&self) -> Result<Option<Vec<u8>>, StorageError> {
+        Ok(None)
+    }
+
+    async fn save_relay_health(&self, _bytes: &[u8]) -> Result<(), StorageError> {
+        Ok(())
+    }
+
+    /// Persisted link preview cache snapshot, if the backend keeps one.
+    async fn load_link_previews(&self) -> Result<Option<Vec<u8>>, StorageError> {
+        Ok(None)
+    }
+
+    async fn save_link_previews(&self, _bytes: &[u8]) -> Result<(), StorageError> {
+        Ok(())
+    }
+
+    /// Persisted NIP-05 lookup cache snapshot, if the backend keeps one.
+    async fn load_nip05_lookups(&self) -> Result<Option<Vec<u8>>, StorageError> {
+        Ok(None)
+    }
+
+    async fn save_nip05_lookups(&self, _bytes: &[u8]) -> Result<(), StorageError> {
+        Ok(())
+    }
 }
 
 #[async_trait(?Send)]
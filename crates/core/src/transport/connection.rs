@@ -7,19 +7,36 @@
 //! - If reconnect/retry fails, drop that frame, mark relay as unreliable for a cooldown window,
 //!   and avoid further reconnect attempts during that window.
 //! - Synthetic notifications are emitted on successful send: REQ => SUBSCRIBED, CLOSE => CLOSED.
+//! - A transport failure with live subscriptions schedules a `wake` (reconnect + REQ replay)
+//!   once the backoff passes; unused connected relays are closed by `close_if_idle`.
+//! - Replayed REQs resume where the subscription left off: each filter gets a `since` just
+//!   below the newest `created_at` delivered on this relay, capped at the current time so a
+//!   future-dated event cannot skip real ones. The overlap is dropped by the connections
+//!   worker's per-subscription `SubDedup`.
+//! - Wire bytes, frames and REQs are counted per relay in a shared `TrafficStats` table.
+//! - AUTH challenges are only signed when the shared `AuthPolicy` allows it, with the key it
+//!   names for this relay; challenges it defers wait for the app, checked by `poll_auth`.
 //!
 //! Notes:
 //! - Synchronous, non-blocking enqueue via bounded channel (cap: 50). `send_raw` never awaits network.
+//! - The drainer paces frames through per-relay token buckets and sends higher-priority frames
+//!   first (see `transport::rate_limit`).
 //! - Incoming messages are written to ring buffer via `out_writer`. Status changes via `status_writer`.
 
+use crate::auth_policy::{AuthDecision, AuthIdentity, AuthPolicy};
 use crate::platform::{now_millis, sleep};
+use crate::relay_health::RelayHealth;
 use crate::spawn::spawn_worker;
+use crate::traffic::TrafficStats;
 use crate::traits::{RelayTransport, TransportStatus};
 use crate::transport::frame_scan::scan_relay_frame;
+use crate::transport::rate_limit::{
+    throttled_classes, FramePriority, FrameQueue, NextFrame, RateLimiter, RateLimits,
+};
 use crate::transport::types::{AuthState, ConnectionStats, ConnectionStatus, RelayError};
 use crate::utils::{extract_first_three, validate_relay_url};
 
-use futures::channel::mpsc::{self, Receiver, Sender};
+use futures::channel::mpsc::{self, Receiver, Sender, TryRecvError};
 use futures::StreamExt;
 use serde_json::json;
 use std::cell::RefCell;
@@ -31,12 +48,22 @@ use tracing::warn;
 type OutWriter = Rc<dyn Fn(&str, &str, &str)>; // (url, sub_id, raw_text)
 type StatusWriter = Rc<dyn Fn(&str, &str)>; // (status, url)
 type CryptoSender = Rc<RefCell<dyn Fn(&[u8])>>;
+type QueuedFrame = (FramePriority, String);
 
 const RECONNECT_RETRY_BASE_DELAY_MS: u64 = 30_000;
 const RECONNECT_RETRY_MAX_DELAY_MS: u64 = 30 * 60_000;
 const HEALTHY_RETRY_DELAYS_MS: [u32; 3] = [200, 800, 2_000];
 const COLD_START_RETRY_DELAYS_MS: [u32; 1] = [200];
 const AUTH_REQUEST_ID_MASK: u64 = 0x8000_0000_0000_0000;
+/// Connected relays with no subscriptions, no publishes awaiting OK and no
+/// traffic for this long are closed (see `close_if_idle`).
+pub const IDLE_SHUTDOWN_MS: u64 = 60_000;
+/// Longest the drainer sleeps on a paced queue before looking for newly
+/// queued higher-priority frames.
+const MAX_PACING_SLEEP_MS: u64 = 250;
+/// How far before the newest delivered event a resumed REQ starts, to cover
+/// events that reached the relay late or carry slightly skewed timestamps.
+const RESUME_SKEW_SECS: u64 = 120;
 
 /// Parse incoming relay frame and return (kind, sub_id, content_for_auth).
 ///
@@ -48,7 +75,7 @@ fn parse_incoming_relay_text(text: &str) -> Option<(String, Option<String>, Opti
     let kind = scan.kind.to_string();
 
     let sub_id = match kind.as_str() {
-        "EVENT" | "EOSE" | "OK" | "CLOSED" => scan.args[0].and_then(|v| {
+        "EVENT" | "EOSE" | "OK" | "CLOSED" | "NEG-MSG" | "NEG-ERR" => scan.args[0].and_then(|v| {
             if v.is_string {
                 Some(v.inner().to_string())
             } else {
@@ -84,26 +111,95 @@ fn parse_incoming_relay_text(text: &str) -> Option<(String, Option<String>, Opti
     Some((kind, sub_id, content))
 }
 
+/// `created_at` of the event in an EVENT frame, found by a plain text scan.
+fn event_created_at(frame: &str) -> Option<u64> {
+    let mut rest = frame;
+    while let Some(pos) = rest.find("\"created_at\"") {
+        rest = &rest[pos + "\"created_at\"".len()..];
+        if let Some(value) = rest.trim_start().strip_prefix(':') {
+            let value = value.trim_start();
+            let end = value
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(value.len());
+            return value[..end].parse().ok();
+        }
+    }
+    None
+}
+
+/// Rewrites a REQ so each filter only asks for events from `since` on.
+/// Filters pinned to `ids` or bounded by `until` are left as they are, as is
+/// any `since` that is already later. Returns None when nothing changed.
+fn resume_req_frame(frame: &str, since: u64) -> Option<String> {
+    let serde_json::Value::Array(mut parts) = serde_json::from_str(frame).ok()? else {
+        return None;
+    };
+    if parts.first().and_then(|v| v.as_str()) != Some("REQ") {
+        return None;
+    }
+    let mut changed = false;
+    for filter in parts.iter_mut().skip(2) {
+        let Some(filter) = filter.as_object_mut() else {
+            continue;
+        };
+        if filter.contains_key("ids") || filter.contains_key("until") {
+            continue;
+        }
+        if filter
+            .get("since")
+            .and_then(|v| v.as_u64())
+            .is_some_and(|current| current >= since)
+        {
+            continue;
+        }
+        filter.insert("since".to_string(), json!(since));
+        changed = true;
+    }
+    changed.then(|| serde_json::Value::Array(parts).to_string())
+}
+
 pub struct RelayConnection {
     url: String,
     status: Arc<RwLock<ConnectionStatus>>,
     transport: Arc<dyn RelayTransport>,
 
     stats: Arc<RwLock<ConnectionStats>>,
+    // Cross-session relay metrics shared with the other workers.
+    health: RelayHealth,
+    // Wire bytes and frames, shared with the connections worker.
+    traffic: Arc<RwLock<TrafficStats>>,
+    // First REQ send time per subscription, for time-to-EOSE.
+    req_sent_at: Arc<RwLock<HashMap<String, u64>>>,
     active_subs: Arc<RwLock<HashSet<String>>>,
-    active_reqs: Arc<RwLock<HashMap<String, Vec<String>>>>,
+    // Latest REQ per subscription; relays replace a REQ that reuses its id.
+    active_reqs: Arc<RwLock<HashMap<String, String>>>,
+    // Newest event `created_at` delivered per subscription, for resuming.
+    newest_seen: Arc<RwLock<HashMap<String, u64>>>,
+    // Subscriptions whose REQ reached EOSE. Only these resume from
+    // `newest_seen`; before EOSE the stored events are still arriving
+    // newest-first, so a `since` would skip the older ones.
+    eosed_subs: Arc<RwLock<HashSet<String>>>,
+    // Since-rewritten replays of `active_reqs`, which must not be recorded
+    // as subscriptions of their own.
+    resumed_reqs: Arc<RwLock<HashMap<String, String>>>,
+    // Published event ids still waiting for the relay's OK.
+    pending_publishes: Arc<RwLock<HashSet<String>>>,
+    // Last frame sent or received, for idle shutdown.
+    last_activity_ms: Arc<RwLock<u64>>,
     backoff_attempts: Arc<RwLock<u32>>,
     next_retry_at_ms: Arc<RwLock<u64>>,
     connection_confirmed: Arc<RwLock<bool>>,
     wake_in_flight: Arc<RwLock<bool>>,
 
     // Channel created at construction time so callers can enqueue immediately.
-    queue_tx: Arc<RwLock<Option<Sender<String>>>>,
+    queue_tx: Arc<RwLock<Option<Sender<QueuedFrame>>>>,
     // Receiver is held until first successful connect, then consumed by the drainer.
-    queue_rx: Arc<RwLock<Option<Receiver<String>>>>,
+    queue_rx: Arc<RwLock<Option<Receiver<QueuedFrame>>>>,
     // Frames not yet picked up by the drainer. Used to avoid waking historical
     // relay objects that have neither active subscriptions nor queued work.
     pending_frames: Arc<RwLock<usize>>,
+    // Outgoing pacing, tightened when the relay reports rate limiting.
+    rate_limiter: Arc<RwLock<RateLimiter>>,
 
     out_writer: OutWriter,
     status_writer: StatusWriter,
@@ -116,6 +212,8 @@ pub struct RelayConnection {
     to_crypto: CryptoSender,
     // Counter for generating unique auth request IDs
     next_auth_id: Arc<RwLock<u64>>,
+    // Whether and as whom to answer challenges, shared with the engine.
+    auth_policy: Arc<RwLock<AuthPolicy>>,
 }
 
 impl RelayConnection {
@@ -125,17 +223,44 @@ impl RelayConnection {
         out_writer: OutWriter,
         status_writer: StatusWriter,
         to_crypto: CryptoSender,
+    ) -> Arc<Self> {
+        Self::with_health(
+            url,
+            transport,
+            out_writer,
+            status_writer,
+            to_crypto,
+            RelayHealth::new(),
+        )
+    }
+
+    /// Like `new`, recording metrics into a shared `RelayHealth` table.
+    pub fn with_health(
+        url: String,
+        transport: Arc<dyn RelayTransport>,
+        out_writer: OutWriter,
+        status_writer: StatusWriter,
+        to_crypto: CryptoSender,
+        health: RelayHealth,
     ) -> Arc<Self> {
         // Create the queue immediately so send_raw can enqueue even before connection.
-        let (tx, rx) = mpsc::channel::<String>(64);
+        let (tx, rx) = mpsc::channel::<QueuedFrame>(64);
 
         let conn = Arc::new(Self {
             url,
             status: Arc::new(RwLock::new(ConnectionStatus::Connecting)),
             transport,
             stats: Arc::new(RwLock::new(ConnectionStats::default())),
+            health,
+            traffic: Arc::new(RwLock::new(TrafficStats::new())),
+            req_sent_at: Arc::new(RwLock::new(HashMap::new())),
             active_subs: Arc::new(RwLock::new(HashSet::new())),
             active_reqs: Arc::new(RwLock::new(HashMap::new())),
+            newest_seen: Arc::new(RwLock::new(HashMap::new())),
+            eosed_subs: Arc::new(RwLock::new(HashSet::new())),
+            resumed_reqs: Arc::new(RwLock::new(HashMap::new())),
+            pending_publishes: Arc::new(RwLock::new(HashSet::new())),
+            last_activity_ms: Arc::new(RwLock::new(now_millis())),
             backoff_attempts: Arc::new(RwLock::new(0)),
             next_retry_at_ms: Arc::new(RwLock::new(0)),
             connection_confirmed: Arc::new(RwLock::new(false)),
@@ -143,12 +268,17 @@ impl RelayConnection {
             queue_tx: Arc::new(RwLock::new(Some(tx))),
             queue_rx: Arc::new(RwLock::new(Some(rx))),
             pending_frames: Arc::new(RwLock::new(0)),
+            rate_limiter: Arc::new(RwLock::new(RateLimiter::new(
+                RateLimits::default(),
+                now_millis(),
+            ))),
             out_writer,
             status_writer,
             auth_state: Arc::new(RwLock::new(AuthState::Unknown)),
             pre_auth_queue: Arc::new(RwLock::new(Vec::new())),
             to_crypto,
             next_auth_id: Arc::new(RwLock::new(1)),
+            auth_policy: Arc::new(RwLock::new(AuthPolicy::default())),
         });
 
         // Connect immediately
@@ -168,6 +298,35 @@ impl RelayConnection {
         &self.url
     }
 
+    /// Delivers `frame` for `sub_id` as if this relay had sent it.
+    pub fn notify(&self, sub_id: &str, frame: &str) {
+        (self.out_writer)(&self.url, sub_id, frame);
+    }
+
+    /// Counters for this connection since it was created.
+    pub fn stats(&self) -> ConnectionStats {
+        let mut stats = self.stats.read().unwrap().clone();
+        stats.active_subscriptions = self.active_subs.read().unwrap().len();
+        stats
+    }
+
+    /// Records wire traffic into a shared `TrafficStats` table instead of a
+    /// private one.
+    pub fn set_traffic(&self, traffic: TrafficStats) {
+        *self.traffic.write().unwrap() = traffic;
+    }
+
+    /// Answers AUTH challenges according to a shared `AuthPolicy` instead of
+    /// always signing with the user's key.
+    pub fn set_auth_policy(&self, policy: AuthPolicy) {
+        *self.auth_policy.write().unwrap() = policy;
+    }
+
+    /// Replaces the outgoing rate limits; buckets restart full.
+    pub fn set_rate_limits(&self, limits: RateLimits) {
+        *self.rate_limiter.write().unwrap() = RateLimiter::new(limits, now_millis());
+    }
+
     #[inline]
     fn clear_backoff(&self) {
         *self.backoff_attempts.write().unwrap() = 0;
@@ -194,8 +353,10 @@ impl RelayConnection {
             *attempts
         };
         let shift = attempt.saturating_sub(1).min(6);
+        // Relays with a poor health record wait longer between attempts.
         let delay_ms = RECONNECT_RETRY_BASE_DELAY_MS
             .saturating_mul(1_u64 << shift)
+            .saturating_mul(self.health.backoff_factor(&self.url))
             .min(RECONNECT_RETRY_MAX_DELAY_MS);
         let retry_at = now_millis() + delay_ms;
         *self.next_retry_at_ms.write().unwrap() = retry_at;
@@ -228,65 +389,159 @@ impl RelayConnection {
     // - If relay was previously healthy (Connected), try harder before cooldown.
     // - If all retries fail, drop that frame, mark relay unreliable (cooldown window), continue.
     // - While unreliable window is active, skip reconnect attempts and drop incoming queued frames quickly.
-    async fn queue_drainer(self: Arc<Self>, mut rx: Receiver<String>) {
-        while let Some(frame) = rx.next().await {
-            {
-                let mut pending = self.pending_frames.write().unwrap();
-                *pending = pending.saturating_sub(1);
+    // Frames are taken from a priority queue under the relay's rate limits; `deliver_frame`
+    // applies the policy above to each one.
+    async fn queue_drainer(self: Arc<Self>, mut rx: Receiver<QueuedFrame>) {
+        let mut queue = FrameQueue::new();
+        let mut open = true;
+        loop {
+            if queue.is_empty() {
+                match rx.next().await {
+                    Some((priority, frame)) => self.queue_frame(&mut queue, priority, frame),
+                    None => break,
+                }
             }
-            let now = now_millis();
-            let retry_at = *self.next_retry_at_ms.read().unwrap();
-
-            if now < retry_at {
-                let remaining = (retry_at - now) / 1000;
-                tracing::warn!(
-                    relay = %self.url,
-                    remaining_secs = remaining,
-                    frame_len = frame.len(),
-                    "[connections][drainer] relay unreliable during cooldown; dropping queued frame without reconnect"
-                );
-                continue;
+            while open {
+                match rx.try_recv() {
+                    Ok((priority, frame)) => self.queue_frame(&mut queue, priority, frame),
+                    Err(TryRecvError::Closed) => open = false,
+                    Err(TryRecvError::Empty) => break,
+                }
             }
+            let next = queue.pop(&mut self.rate_limiter.write().unwrap(), now_millis());
+            match next {
+                NextFrame::Ready(frame) => {
+                    {
+                        let mut pending = self.pending_frames.write().unwrap();
+                        *pending = pending.saturating_sub(1);
+                    }
+                    self.deliver_frame(frame).await;
+                }
+                NextFrame::Wait(ms) => sleep(ms.min(MAX_PACING_SLEEP_MS)).await,
+                NextFrame::Empty => {
+                    if !open {
+                        break;
+                    }
+                }
+            }
+        }
+        tracing::debug!(relay = %self.url, "Queue drainer exiting");
+    }
 
-            let was_previously_connected = *self.connection_confirmed.read().unwrap();
+    fn queue_frame(&self, queue: &mut FrameQueue, priority: FramePriority, frame: String) {
+        let dropped = queue.push(frame, priority);
+        if dropped > 0 {
+            tracing::debug!(relay = %self.url, dropped, "[connections][drainer] CLOSE superseded queued REQs");
+            let mut pending = self.pending_frames.write().unwrap();
+            *pending = pending.saturating_sub(dropped);
+        }
+    }
 
-            tracing::info!(
+    async fn deliver_frame(self: &Arc<Self>, frame: String) {
+        let now = now_millis();
+        let retry_at = *self.next_retry_at_ms.read().unwrap();
+
+        if now < retry_at {
+            let remaining = (retry_at - now) / 1000;
+            tracing::warn!(
                 relay = %self.url,
-                status = ?*self.status.read().unwrap(),
-                was_previously_connected,
+                remaining_secs = remaining,
                 frame_len = frame.len(),
-                "[connections][drainer] processing queued frame"
+                "[connections][drainer] relay unreliable during cooldown; dropping queued frame without reconnect"
             );
+            return;
+        }
 
-            // Attempt direct send first (covers the common connected case).
-            match self.send_raw_internal(&frame).await {
-                Ok(()) => {
-                    tracing::info!(relay = %self.url, "[connections][drainer] frame sent successfully (direct)");
-                    continue;
-                }
-                Err(e) => {
-                    tracing::warn!(
-                        relay = %self.url,
-                        error = ?e,
-                        "[connections][drainer] direct send failed; attempting immediate reconnect + single retry"
-                    );
+        let was_previously_connected = *self.connection_confirmed.read().unwrap();
+
+        tracing::info!(
+            relay = %self.url,
+            status = ?*self.status.read().unwrap(),
+            was_previously_connected,
+            frame_len = frame.len(),
+            "[connections][drainer] processing queued frame"
+        );
+
+        // Attempt direct send first (covers the common connected case).
+        match self.send_raw_internal(&frame).await {
+            Ok(()) => {
+                tracing::info!(relay = %self.url, "[connections][drainer] frame sent successfully (direct)");
+                return;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    relay = %self.url,
+                    error = ?e,
+                    "[connections][drainer] direct send failed; attempting immediate reconnect + single retry"
+                );
+            }
+        }
+
+        // Reconnect attempt #1 for this frame.
+        match self.connect().await {
+            Ok(()) => {
+                tracing::info!(relay = %self.url, "[connections][drainer] reconnect #1 succeeded; retrying same frame");
+                match self.send_raw_internal(&frame).await {
+                    Ok(()) => {
+                        tracing::info!(relay = %self.url, "[connections][drainer] frame sent successfully after reconnect #1 retry");
+                        return;
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            relay = %self.url,
+                            error = ?e,
+                            "[connections][drainer] retry send failed after reconnect #1; trying one forced reconnect retry"
+                        );
+                    }
                 }
             }
+            Err(e) => {
+                tracing::warn!(
+                    relay = %self.url,
+                    error = ?e,
+                    "[connections][drainer] reconnect #1 failed; trying one forced reconnect retry"
+                );
+            }
+        }
+
+        let delays: &[u32] = if was_previously_connected {
+            &HEALTHY_RETRY_DELAYS_MS
+        } else {
+            &COLD_START_RETRY_DELAYS_MS
+        };
+
+        let mut delivered = false;
+        for (idx, delay_ms) in delays.iter().copied().enumerate() {
+            // Bypass the current deadline for this bounded retry burst while preserving
+            // the consecutive-failure count used by the long-term backoff.
+            self.clear_retry_deadline();
+            sleep(delay_ms as u64).await;
 
-            // Reconnect attempt #1 for this frame.
+            let attempt_num = idx + 2; // #1 already happened above
             match self.connect().await {
                 Ok(()) => {
-                    tracing::info!(relay = %self.url, "[connections][drainer] reconnect #1 succeeded; retrying same frame");
+                    tracing::info!(
+                        relay = %self.url,
+                        attempt_num,
+                        delay_ms,
+                        "[connections][drainer] reconnect staged attempt succeeded; retrying same frame"
+                    );
                     match self.send_raw_internal(&frame).await {
                         Ok(()) => {
-                            tracing::info!(relay = %self.url, "[connections][drainer] frame sent successfully after reconnect #1 retry");
-                            continue;
+                            tracing::info!(
+                                relay = %self.url,
+                                attempt_num,
+                                "[connections][drainer] frame sent successfully after staged reconnect retry"
+                            );
+                            delivered = true;
+                            break;
                         }
                         Err(e) => {
                             tracing::warn!(
                                 relay = %self.url,
+                                attempt_num,
                                 error = ?e,
-                                "[connections][drainer] retry send failed after reconnect #1; trying one forced reconnect retry"
+                                "[connections][drainer] staged retry send failed"
                             );
                         }
                     }
@@ -294,81 +549,28 @@ impl RelayConnection {
                 Err(e) => {
                     tracing::warn!(
                         relay = %self.url,
+                        attempt_num,
                         error = ?e,
-                        "[connections][drainer] reconnect #1 failed; trying one forced reconnect retry"
+                        "[connections][drainer] staged reconnect failed"
                     );
                 }
             }
+        }
 
-            let delays: &[u32] = if was_previously_connected {
-                &HEALTHY_RETRY_DELAYS_MS
-            } else {
-                &COLD_START_RETRY_DELAYS_MS
-            };
-
-            let mut delivered = false;
-            for (idx, delay_ms) in delays.iter().copied().enumerate() {
-                // Bypass the current deadline for this bounded retry burst while preserving
-                // the consecutive-failure count used by the long-term backoff.
-                self.clear_retry_deadline();
-                sleep(delay_ms as u64).await;
-
-                let attempt_num = idx + 2; // #1 already happened above
-                match self.connect().await {
-                    Ok(()) => {
-                        tracing::info!(
-                            relay = %self.url,
-                            attempt_num,
-                            delay_ms,
-                            "[connections][drainer] reconnect staged attempt succeeded; retrying same frame"
-                        );
-                        match self.send_raw_internal(&frame).await {
-                            Ok(()) => {
-                                tracing::info!(
-                                    relay = %self.url,
-                                    attempt_num,
-                                    "[connections][drainer] frame sent successfully after staged reconnect retry"
-                                );
-                                delivered = true;
-                                break;
-                            }
-                            Err(e) => {
-                                tracing::warn!(
-                                    relay = %self.url,
-                                    attempt_num,
-                                    error = ?e,
-                                    "[connections][drainer] staged retry send failed"
-                                );
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            relay = %self.url,
-                            attempt_num,
-                            error = ?e,
-                            "[connections][drainer] staged reconnect failed"
-                        );
-                    }
-                }
-            }
-
-            if delivered {
-                continue;
-            }
+        if delivered {
+            return;
+        }
 
-            tracing::error!(
-                relay = %self.url,
-                was_previously_connected,
-                "[connections][drainer] all reconnect/send retries exhausted; dropping frame and marking unreliable"
-            );
-            if !self.should_delay_reconnect() {
-                self.schedule_reconnect_backoff();
-            }
-            let retry_at = *self.next_retry_at_ms.read().unwrap();
-            tracing::warn!(relay = %self.url, retry_at, "[connections][drainer] relay marked unreliable after retry exhaustion");
+        tracing::error!(
+            relay = %self.url,
+            was_previously_connected,
+            "[connections][drainer] all reconnect/send retries exhausted; dropping frame and marking unreliable"
+        );
+        if !self.should_delay_reconnect() {
+            self.schedule_reconnect_backoff();
         }
-        tracing::debug!(relay = %self.url, "Queue drainer exiting");
+        let retry_at = *self.next_retry_at_ms.read().unwrap();
+        tracing::warn!(relay = %self.url, retry_at, "[connections][drainer] relay marked unreliable after retry exhaustion");
     }
 
     async fn send_raw_internal(&self, text: &str) -> Result<(), RelayError> {
@@ -377,6 +579,8 @@ impl RelayConnection {
             .await
             .map_err(|e| RelayError::ConnectionError(e.to_string()))?;
         self.confirm_connection();
+        *self.last_activity_ms.write().unwrap() = now_millis();
+        self.traffic.read().unwrap().record_relay_sent(&self.url, text);
 
         // On successful send, adjust inflight and emit synthetic notifications when appropriate.
         if let Some(parts) = extract_first_three(text) {
@@ -393,7 +597,7 @@ impl RelayConnection {
                                     let _ = self.close();
                                 }
                             }
-                            self.active_reqs.write().unwrap().remove(&sub);
+                            self.forget_sub(&sub);
                             let raw_closed = format!(r#"["OK","{}","CLOSED"]"#, sub);
                             (self.out_writer)(&self.url, &sub, &raw_closed);
                         }
@@ -401,11 +605,32 @@ impl RelayConnection {
                     "REQ" => {
                         if let Some(sub_id) = parts[1].map(|s| s.trim_matches('"').to_string()) {
                             self.shadow_pre_auth_req(text);
-                            self.active_subs.write().unwrap().insert(sub_id.clone());
-                            let mut reqs = self.active_reqs.write().unwrap();
-                            let frames = reqs.entry(sub_id.clone()).or_default();
-                            if !frames.iter().any(|frame| frame == text) {
-                                frames.push(text.to_string());
+                            if self.active_subs.write().unwrap().insert(sub_id.clone()) {
+                                self.stats.write().unwrap().subscriptions_created += 1;
+                            }
+                            self.req_sent_at
+                                .write()
+                                .unwrap()
+                                .entry(sub_id.clone())
+                                .or_insert_with(now_millis);
+                            let resumed = self
+                                .resumed_reqs
+                                .read()
+                                .unwrap()
+                                .get(&sub_id)
+                                .is_some_and(|frame| frame == text);
+                            if !resumed {
+                                let previous = self
+                                    .active_reqs
+                                    .write()
+                                    .unwrap()
+                                    .insert(sub_id.clone(), text.to_string());
+                                if previous.as_deref() != Some(text) {
+                                    // New filters: their window starts over.
+                                    self.newest_seen.write().unwrap().remove(&sub_id);
+                                    self.eosed_subs.write().unwrap().remove(&sub_id);
+                                    self.resumed_reqs.write().unwrap().remove(&sub_id);
+                                }
                             }
                             // optional: keep the synthetic notification
                             let raw_subscribed = format!(r#"["OK","{}","SUBSCRIBED"]"#, sub_id);
@@ -433,6 +658,8 @@ impl RelayConnection {
                             }
 
                             if let Some(id) = event_id {
+                                self.stats.write().unwrap().events_published += 1;
+                                self.pending_publishes.write().unwrap().insert(id.clone());
                                 // Synthetic OK to indicate the publish has been sent
                                 // This routes by event_id (used as sub_id for publish tracking)
                                 let raw_sent = format!(r#"["OK","{}","SENT"]"#, id);
@@ -453,6 +680,16 @@ impl RelayConnection {
     // Important: we always enqueue (unless queue full), even if auth is currently Failed,
     // so stale transport state does not cause immediate caller-side frame loss.
     pub fn send_raw(self: &Arc<Self>, text: &str) -> Result<(), RelayError> {
+        self.send_raw_with_priority(text, FramePriority::of(text))
+    }
+
+    /// Like `send_raw`, queueing the frame in an explicit lane (e.g. `Low` for
+    /// background prefetch REQs).
+    pub fn send_raw_with_priority(
+        self: &Arc<Self>,
+        text: &str,
+        priority: FramePriority,
+    ) -> Result<(), RelayError> {
         if let Some(tx) = self.queue_tx.read().unwrap().as_ref() {
             tracing::info!(
                 relay = %self.url,
@@ -461,7 +698,7 @@ impl RelayConnection {
                 status = ?*self.status.read().unwrap(),
                 "[connections][enqueue] enqueueing frame"
             );
-            tx.clone().try_send(text.to_owned()).map_err(|e| {
+            tx.clone().try_send((priority, text.to_owned())).map_err(|e| {
 				if e.is_full() {
 					warn!(relay = %self.url, "Frame dropped: send queue full (64)");
 					RelayError::QueueFull
@@ -520,7 +757,10 @@ impl RelayConnection {
         validate_relay_url(&self.url).map_err(|e| RelayError::InvalidUrl(e.to_string()))?;
 
         // Open transport connection
+        let connect_started_at = now_millis();
+        self.stats.write().unwrap().reconnect_attempts += 1;
         self.transport.connect(&self.url).await.map_err(|e| {
+            self.health.record_connect_failed(&self.url, now_millis());
             let mut st = self.status.write().unwrap();
             *st = ConnectionStatus::Failed;
             (self.status_writer)("failed", &self.url);
@@ -558,8 +798,14 @@ impl RelayConnection {
             *st = ConnectionStatus::Connected;
         }
         {
+            let now = now_millis();
             let mut s = self.stats.write().unwrap();
-            s.connected_at = Some(now_millis());
+            s.connected_at = Some(now);
+            self.health.record_connected(
+                &self.url,
+                now.saturating_sub(connect_started_at),
+                now,
+            );
         }
 
         // Reset auth flow on each fresh transport connect.
@@ -580,15 +826,40 @@ impl RelayConnection {
 
     fn handle_incoming_message(&self, text: &str) {
         self.confirm_connection();
+        *self.last_activity_ms.write().unwrap() = now_millis();
+        self.traffic.read().unwrap().record_relay_received(&self.url, text);
         tracing::info!(relay = %self.url, "Raw incoming: {}", text);
 
         if let Some((kind, sub_id, content)) = parse_incoming_relay_text(text) {
             if kind == "CLOSED" {
                 if let Some(ref sub_id) = sub_id {
                     self.active_subs.write().unwrap().remove(sub_id);
-                    self.active_reqs.write().unwrap().remove(sub_id);
+                    self.forget_sub(sub_id);
                 }
             }
+            if kind == "EVENT" {
+                if let (Some(sub_id), Some(created_at)) = (sub_id.as_ref(), event_created_at(text)) {
+                    // A future-dated event must not push the resumed `since` past real events.
+                    let created_at = created_at.min(now_millis() / 1000);
+                    let mut newest = self.newest_seen.write().unwrap();
+                    let entry = newest.entry(sub_id.clone()).or_default();
+                    *entry = (*entry).max(created_at);
+                }
+            }
+            if kind == "OK" {
+                if let Some(ref event_id) = sub_id {
+                    self.pending_publishes.write().unwrap().remove(event_id);
+                }
+            }
+            self.record_incoming(&kind, sub_id.as_deref(), content.as_deref());
+            let throttled = throttled_classes(&kind, text);
+            if !throttled.is_empty() {
+                tracing::warn!(relay = %self.url, kind = %kind, "[connections] relay is rate limiting us; slowing down");
+                self.rate_limiter
+                    .write()
+                    .unwrap()
+                    .tighten(throttled, now_millis());
+            }
 
             // Handle NIP-42 authentication state machine on first response
             let content_for_auth = content.as_deref().unwrap_or("");
@@ -603,7 +874,37 @@ impl RelayConnection {
         }
     }
 
-    fn handle_transport_status(&self, status: TransportStatus) {
+    fn record_incoming(&self, kind: &str, sub_id: Option<&str>, content: Option<&str>) {
+        match kind {
+            "EVENT" => {
+                self.stats.write().unwrap().events_received += 1;
+                self.health.record_event(&self.url);
+            }
+            "EOSE" => {
+                if let Some(sub) = sub_id {
+                    if self.active_reqs.read().unwrap().contains_key(sub) {
+                        self.eosed_subs.write().unwrap().insert(sub.to_string());
+                    }
+                }
+                // Only the first EOSE per REQ counts; replays re-arm on send.
+                let sent_at = sub_id.and_then(|sub| self.req_sent_at.write().unwrap().remove(sub));
+                if let Some(sent_at) = sent_at {
+                    self.health
+                        .record_eose(&self.url, now_millis().saturating_sub(sent_at));
+                }
+            }
+            "OK" => {
+                // AUTH acknowledgements are part of the handshake, not publishes.
+                if !matches!(*self.auth_state.read().unwrap(), AuthState::Pending) {
+                    let accepted = content.is_some_and(|c| c.trim_start().starts_with("true"));
+                    self.health.record_ok(&self.url, accepted);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_transport_status(self: &Arc<Self>, status: TransportStatus) {
         match status {
             TransportStatus::Failed { url } => {
                 // During intentional close/reconnect replacement, old transport errors are expected.
@@ -623,6 +924,8 @@ impl RelayConnection {
                 }
 
                 tracing::error!(relay = %url, "Transport error");
+                self.health.record_disconnect(&self.url, now_millis());
+                self.req_sent_at.write().unwrap().clear();
                 {
                     let mut st = self.status.write().unwrap();
                     *st = ConnectionStatus::Failed;
@@ -645,7 +948,20 @@ impl RelayConnection {
                 }
                 // Set auth state to Failed until next successful reconnect path resets via first response
                 *self.auth_state.write().unwrap() = AuthState::Failed;
+                self.auth_policy.read().unwrap().cancel(&self.url);
                 (self.status_writer)("failed", &url);
+
+                // Live subscriptions would otherwise stay silent until the next
+                // send or app wake: reconnect and replay once the backoff passes.
+                if !self.active_reqs.read().unwrap().is_empty() {
+                    let conn = Arc::clone(self);
+                    spawn_worker(async move {
+                        sleep(delay_ms).await;
+                        if matches!(*conn.status.read().unwrap(), ConnectionStatus::Failed) {
+                            conn.wake();
+                        }
+                    });
+                }
             }
             TransportStatus::Closed { url } => {
                 let ignore_transition = {
@@ -663,6 +979,8 @@ impl RelayConnection {
                     return;
                 }
 
+                self.health.record_disconnect(&self.url, now_millis());
+                self.req_sent_at.write().unwrap().clear();
                 {
                     let mut st = self.status.write().unwrap();
                     *st = ConnectionStatus::Failed;
@@ -687,6 +1005,7 @@ impl RelayConnection {
                 }
                 // Reset auth state for next transport connect.
                 *self.auth_state.write().unwrap() = AuthState::Unknown;
+                self.auth_policy.read().unwrap().cancel(&self.url);
 
                 (self.status_writer)("failed", &url);
             }
@@ -714,12 +1033,12 @@ impl RelayConnection {
         if !present {
             return false;
         }
-        self.active_reqs.write().unwrap().remove(sub_id);
+        self.forget_sub(sub_id);
 
         // Enqueue CLOSE frame; drainer will send when connected
         let frame = format!(r#"["CLOSE","{}"]"#, sub_id);
         if let Some(tx) = self.queue_tx.read().unwrap().as_ref() {
-            match tx.clone().try_send(frame) {
+            match tx.clone().try_send((FramePriority::High, frame)) {
                 Ok(()) => *self.pending_frames.write().unwrap() += 1,
                 Err(e) => {
                     warn!(
@@ -760,6 +1079,7 @@ impl RelayConnection {
         }
         // Reset auth state for next transport connect.
         *self.auth_state.write().unwrap() = AuthState::Unknown;
+        self.auth_policy.read().unwrap().cancel(&self.url);
 
         (self.status_writer)("close", &self.url);
 
@@ -789,13 +1109,7 @@ impl RelayConnection {
 
         self.clear_backoff();
 
-        let replay_frames: Vec<String> = self
-            .active_reqs
-            .read()
-            .unwrap()
-            .values()
-            .flat_map(|frames| frames.iter().cloned())
-            .collect();
+        let replay_frames = self.resume_frames();
 
         let conn = Arc::clone(self);
         spawn_worker(async move {
@@ -822,6 +1136,65 @@ impl RelayConnection {
         });
     }
 
+    /// Closes the transport when the relay is connected but unused: no
+    /// subscriptions, no publishes awaiting OK, no queued frames and no
+    /// traffic for `IDLE_SHUTDOWN_MS`. The connection stays reusable; the
+    /// next frame reconnects. Returns true when it closed.
+    pub fn close_if_idle(&self, now_ms: u64) -> bool {
+        let idle = matches!(*self.status.read().unwrap(), ConnectionStatus::Connected)
+            && self
+                .idle_since()
+                .is_some_and(|since| now_ms.saturating_sub(since) >= IDLE_SHUTDOWN_MS);
+        if idle {
+            tracing::info!(relay = %self.url, "[connections][idle] closing unused relay");
+            let _ = self.close();
+        }
+        idle
+    }
+
+    /// Time of the last traffic when nothing depends on this connection (no
+    /// subscriptions, publishes awaiting OK or queued frames), else None.
+    pub fn idle_since(&self) -> Option<u64> {
+        let unused = self.active_subs.read().unwrap().is_empty()
+            && self.pending_publishes.read().unwrap().is_empty()
+            && *self.pending_frames.read().unwrap() == 0;
+        unused.then(|| *self.last_activity_ms.read().unwrap())
+    }
+
+    /// Active REQs to replay after a reconnect. Those that reached EOSE are
+    /// rewritten to resume from the newest event already delivered (see
+    /// `resume_req_frame`); the rest are replayed unchanged.
+    fn resume_frames(&self) -> Vec<String> {
+        let reqs = self.active_reqs.read().unwrap();
+        let newest = self.newest_seen.read().unwrap();
+        let eosed = self.eosed_subs.read().unwrap();
+        let mut resumed = self.resumed_reqs.write().unwrap();
+        resumed.clear();
+        let mut replay = Vec::with_capacity(reqs.len());
+        for (sub_id, frame) in reqs.iter() {
+            let since = newest
+                .get(sub_id)
+                .filter(|_| eosed.contains(sub_id))
+                .map(|created_at| created_at.saturating_sub(RESUME_SKEW_SECS));
+            match since.and_then(|since| resume_req_frame(frame, since)) {
+                Some(rewritten) => {
+                    resumed.insert(sub_id.clone(), rewritten.clone());
+                    replay.push(rewritten);
+                }
+                None => replay.push(frame.clone()),
+            }
+        }
+        replay
+    }
+
+    fn forget_sub(&self, sub_id: &str) {
+        self.active_reqs.write().unwrap().remove(sub_id);
+        self.req_sent_at.write().unwrap().remove(sub_id);
+        self.newest_seen.write().unwrap().remove(sub_id);
+        self.eosed_subs.write().unwrap().remove(sub_id);
+        self.resumed_reqs.write().unwrap().remove(sub_id);
+    }
+
     pub fn has_recovery_work(&self) -> bool {
         !self.active_reqs.read().unwrap().is_empty()
             || *self.pending_frames.read().unwrap() > 0
@@ -880,8 +1253,15 @@ impl RelayConnection {
                 };
                 tracing::debug!(relay = %self.url, "[connections][AUTH] State set to Required");
 
-                // Request signature from crypto
-                self.request_auth_signature(challenge);
+                let policy = self.auth_policy.read().unwrap().clone();
+                match policy.decide(&self.url) {
+                    Some(decision) => self.apply_auth_decision(challenge, decision),
+                    None => {
+                        tracing::info!(relay = %self.url, "[connections][AUTH] Waiting for the app to answer the challenge");
+                        policy.ask(&self.url, &challenge, now_millis());
+                        (self.status_writer)("auth-pending", &self.url);
+                    }
+                }
             }
             _ => {
                 // Any other response means auth is not required
@@ -891,8 +1271,40 @@ impl RelayConnection {
         }
     }
 
+    fn apply_auth_decision(&self, challenge: String, decision: AuthDecision) {
+        match decision {
+            AuthDecision::Allow(identity) => self.request_auth_signature(challenge, identity),
+            AuthDecision::Deny => {
+                // The relay keeps serving what it serves without auth; REQs it
+                // refused are reported by its own CLOSED frames.
+                let queue = std::mem::take(&mut *self.pre_auth_queue.write().unwrap());
+                *self.auth_state.write().unwrap() = AuthState::Failed;
+                tracing::info!(relay = %self.url, cleared_frames = queue.len(), "[connections][AUTH] Challenge declined by auth policy");
+                (self.status_writer)("auth-denied", &self.url);
+            }
+        }
+    }
+
+    /// Applies the app's answer to a challenge parked by `AuthMode::Ask`, or
+    /// declines it once the policy's timeout passes. Returns whether the
+    /// challenge was settled.
+    pub fn poll_auth(&self, now_ms: u64) -> bool {
+        let challenge = match &*self.auth_state.read().unwrap() {
+            AuthState::Required { challenge } => challenge.clone(),
+            _ => return false,
+        };
+        let decision = self.auth_policy.read().unwrap().poll(&self.url, now_ms);
+        match decision {
+            Some(decision) => {
+                self.apply_auth_decision(challenge, decision);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Send signing request to crypto worker for kind 22242 event
-    fn request_auth_signature(&self, challenge: String) {
+    fn request_auth_signature(&self, challenge: String, identity: AuthIdentity) {
         let request_id = {
             let mut id = self.next_auth_id.write().unwrap();
             let current = *id;
@@ -905,11 +1317,12 @@ impl RelayConnection {
         let payload = json!({
             "challenge": challenge,
             "relay": self.url,
-            "created_at": created_at
+            "created_at": created_at,
+            "identity": identity
         })
         .to_string();
 
-        tracing::info!(relay = %self.url, request_id, payload, "[connections][AUTH] Building SignerRequest for crypto");
+        tracing::info!(relay = %self.url, request_id, "[connections][AUTH] Building SignerRequest for crypto");
 
         // Build FlatBuffers SignerRequest
         let mut fbb = flatbuffers::FlatBufferBuilder::new();
@@ -947,7 +1360,7 @@ impl RelayConnection {
 
         // Send directly (bypass queue since this is internal)
         if let Some(tx) = self.queue_tx.read().unwrap().as_ref() {
-            match tx.clone().try_send(auth_frame) {
+            match tx.clone().try_send((FramePriority::High, auth_frame)) {
                 Ok(_) => {
                     *self.pending_frames.write().unwrap() += 1;
                     tracing::info!(relay = %self.url, "[connections][AUTH] Frame queued for relay");
@@ -983,7 +1396,7 @@ impl RelayConnection {
             );
             if let Some(tx) = self.queue_tx.read().unwrap().as_ref() {
                 for frame in shadow_frames {
-                    match tx.clone().try_send(frame) {
+                    match tx.clone().try_send((FramePriority::Normal, frame)) {
                         Ok(()) => *self.pending_frames.write().unwrap() += 1,
                         Err(e) => {
                             tracing::warn!(
@@ -1232,6 +1645,57 @@ mod tests {
             .await;
     }
 
+    #[tokio::test]
+    async fn test_drainer_sends_by_priority_and_slows_down_when_rate_limited() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let transport = Arc::new(MockRelayTransport::new());
+                transport.set_connect_result(Err(TransportError::Other("not yet".to_string())));
+                let (out_writer, status_writer, to_crypto, _out, _status, _crypto) = make_writers();
+                let conn = RelayConnection::new(
+                    "wss://r".to_string(),
+                    transport.clone(),
+                    out_writer,
+                    status_writer,
+                    to_crypto,
+                );
+                tokio::task::yield_now().await;
+                tokio::task::yield_now().await;
+
+                let prefetch = r#"["REQ","prefetch",{}]"#;
+                let feed = r#"["REQ","feed",{}]"#;
+                let publish = r#"["EVENT",{"id":"e1","kind":1}]"#;
+                conn.send_raw_with_priority(prefetch, FramePriority::Low).unwrap();
+                conn.send_raw(feed).unwrap();
+                conn.send_raw(publish).unwrap();
+
+                transport.set_connect_result(Ok(()));
+                conn.wake();
+                for _ in 0..8 {
+                    tokio::task::yield_now().await;
+                }
+
+                let sent: Vec<String> = transport
+                    .calls()
+                    .into_iter()
+                    .filter_map(|c| match c {
+                        Call::Send(_, frame) => Some(frame),
+                        _ => None,
+                    })
+                    .collect();
+                assert_eq!(sent, [publish, feed, prefetch]);
+
+                transport.invoke_message_callback(
+                    "wss://r",
+                    r#"["CLOSED","feed","rate-limited: slow down"]"#.to_string(),
+                );
+                let limiter = conn.rate_limiter.read().unwrap();
+                assert_eq!(limiter.rate(crate::transport::rate_limit::FrameClass::Req), Some(2.0));
+            })
+            .await;
+    }
+
     #[tokio::test]
     async fn test_synthetic_subscribed_notification() {
         let local = LocalSet::new();
@@ -1450,6 +1914,78 @@ mod tests {
 			.await;
     }
 
+    #[tokio::test]
+    async fn test_auth_policy_gates_challenges() {
+        use crate::auth_policy::{AuthMode, AuthPolicyRules};
+        use crate::generated::nostr::fb;
+
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let transport = Arc::new(MockRelayTransport::new());
+                let (out_writer, status_writer, to_crypto, _out, status, crypto) = make_writers();
+                let policy = AuthPolicy::new(AuthPolicyRules {
+                    mode: AuthMode::Ask,
+                    ask_timeout_ms: 60_000,
+                    ..Default::default()
+                });
+
+                let conn = RelayConnection::new(
+                    "wss://r".to_string(),
+                    transport.clone(),
+                    out_writer,
+                    status_writer,
+                    to_crypto,
+                );
+                conn.set_auth_policy(policy.clone());
+                tokio::task::yield_now().await;
+                tokio::task::yield_now().await;
+                tokio::task::yield_now().await;
+
+                // Ask: nothing is signed until the app answers.
+                transport.invoke_message_callback("wss://r", r#"["AUTH","c1"]"#.to_string());
+                tokio::task::yield_now().await;
+                assert!(status
+                    .lock()
+                    .unwrap()
+                    .contains(&("auth-pending".to_string(), "wss://r".to_string())));
+                assert_eq!(policy.pending()[0].challenge, "c1");
+                assert!(crypto.lock().unwrap().is_empty());
+                assert!(!conn.poll_auth(now_millis()));
+
+                policy.resolve(
+                    "wss://r",
+                    AuthDecision::Allow(AuthIdentity::Ephemeral),
+                    false,
+                );
+                assert!(conn.poll_auth(now_millis()));
+                let requests = crypto.lock().unwrap().clone();
+                assert_eq!(requests.len(), 1);
+                let req = flatbuffers::root::<fb::SignerRequest>(&requests[0]).unwrap();
+                let payload: serde_json::Value =
+                    serde_json::from_str(req.payload().unwrap()).unwrap();
+                assert_eq!(payload["challenge"], "c1");
+                assert_eq!(payload["identity"]["type"], "ephemeral");
+
+                // A remembered denial declines the next challenge outright.
+                policy.resolve("wss://r", AuthDecision::Deny, true);
+                conn.close().unwrap();
+                conn.wake();
+                tokio::task::yield_now().await;
+                tokio::task::yield_now().await;
+                tokio::task::yield_now().await;
+                transport.invoke_message_callback("wss://r", r#"["AUTH","c2"]"#.to_string());
+                tokio::task::yield_now().await;
+                assert!(matches!(*conn.auth_state.read().unwrap(), AuthState::Failed));
+                assert!(status
+                    .lock()
+                    .unwrap()
+                    .contains(&("auth-denied".to_string(), "wss://r".to_string())));
+                assert_eq!(crypto.lock().unwrap().len(), 1);
+            })
+            .await;
+    }
+
     #[tokio::test]
     async fn test_auth_success_replays_pre_auth_reqs() {
         let local = LocalSet::new();
@@ -2017,7 +2553,7 @@ mod tests {
                 assert!(conn.active_subs.read().unwrap().contains("s1"));
                 assert_eq!(
                     conn.active_reqs.read().unwrap().get("s1").cloned(),
-                    Some(vec![req_frame.to_string()])
+                    Some(req_frame.to_string())
                 );
 
                 conn.wake();
@@ -2042,6 +2578,184 @@ mod tests {
             .await;
     }
 
+    #[tokio::test]
+    async fn test_wake_resumes_replayed_reqs_from_newest_event() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let transport = Arc::new(MockRelayTransport::new());
+                let (out_writer, status_writer, to_crypto, _out, _status, _crypto) = make_writers();
+                let conn = RelayConnection::new(
+                    "wss://r".to_string(),
+                    transport.clone(),
+                    out_writer,
+                    status_writer,
+                    to_crypto,
+                );
+                for _ in 0..3 {
+                    tokio::task::yield_now().await;
+                }
+
+                let req_frame = r#"["REQ","s1",{"kinds":[1],"limit":50}]"#;
+                conn.send_raw(req_frame).unwrap();
+                for _ in 0..3 {
+                    tokio::task::yield_now().await;
+                }
+                for created_at in [1_000, 5_000, 3_000] {
+                    transport.invoke_message_callback(
+                        "wss://r",
+                        format!(
+                            r#"["EVENT","s1",{{"id":"e{0}","tags":[["t","created_at"]],"created_at":{0}}}]"#,
+                            created_at
+                        ),
+                    );
+                }
+                transport.invoke_message_callback("wss://r", r#"["EOSE","s1"]"#.to_string());
+
+                let sent = || -> Vec<String> {
+                    transport
+                        .calls()
+                        .into_iter()
+                        .filter_map(|c| match c {
+                            Call::Send(_, frame) => Some(frame),
+                            _ => None,
+                        })
+                        .collect()
+                };
+                let resumed = r#"["REQ","s1",{"kinds":[1],"limit":50,"since":4880}]"#;
+                for _ in 0..2 {
+                    conn.wake();
+                    for _ in 0..6 {
+                        tokio::task::yield_now().await;
+                    }
+                    assert_eq!(sent().last().map(String::as_str), Some(resumed));
+                }
+                // The original REQ stays the one to resume from.
+                assert_eq!(
+                    conn.active_reqs.read().unwrap().get("s1").cloned(),
+                    Some(req_frame.to_string())
+                );
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_wake_does_not_resume_past_now_for_future_dated_events() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let transport = Arc::new(MockRelayTransport::new());
+                let (out_writer, status_writer, to_crypto, _out, _status, _crypto) = make_writers();
+                let conn = RelayConnection::new(
+                    "wss://r".to_string(),
+                    transport.clone(),
+                    out_writer,
+                    status_writer,
+                    to_crypto,
+                );
+                for _ in 0..3 {
+                    tokio::task::yield_now().await;
+                }
+
+                conn.send_raw(r#"["REQ","s1",{"kinds":[1]}]"#).unwrap();
+                for _ in 0..3 {
+                    tokio::task::yield_now().await;
+                }
+                let now = now_millis() / 1000;
+                for created_at in [now - 60, now + 86_400] {
+                    transport.invoke_message_callback(
+                        "wss://r",
+                        format!(r#"["EVENT","s1",{{"id":"e{0}","created_at":{0}}}]"#, created_at),
+                    );
+                }
+                transport.invoke_message_callback("wss://r", r#"["EOSE","s1"]"#.to_string());
+
+                conn.wake();
+                for _ in 0..6 {
+                    tokio::task::yield_now().await;
+                }
+                let resumed = transport
+                    .calls()
+                    .into_iter()
+                    .rev()
+                    .find_map(|c| match c {
+                        Call::Send(_, frame) => Some(frame),
+                        _ => None,
+                    })
+                    .unwrap();
+                let resumed: serde_json::Value = serde_json::from_str(&resumed).unwrap();
+                let since = resumed[2]["since"].as_u64().unwrap();
+                assert!(since <= now_millis() / 1000 - RESUME_SKEW_SECS, "since {since}");
+                assert!(since <= now - 60, "the event a minute ago is asked for again");
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_wake_replays_reqs_before_eose_unchanged_and_keeps_only_the_latest() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let transport = Arc::new(MockRelayTransport::new());
+                let (out_writer, status_writer, to_crypto, _out, _status, _crypto) = make_writers();
+                let conn = RelayConnection::new(
+                    "wss://r".to_string(),
+                    transport.clone(),
+                    out_writer,
+                    status_writer,
+                    to_crypto,
+                );
+                for _ in 0..3 {
+                    tokio::task::yield_now().await;
+                }
+
+                conn.send_raw(r#"["REQ","s1",{"kinds":[0]}]"#).unwrap();
+                let req_frame = r#"["REQ","s1",{"kinds":[1],"limit":50}]"#;
+                conn.send_raw(req_frame).unwrap();
+                for _ in 0..3 {
+                    tokio::task::yield_now().await;
+                }
+                // Stored events still streaming in, newest first: no EOSE yet.
+                transport.invoke_message_callback(
+                    "wss://r",
+                    r#"["EVENT","s1",{"id":"e1","created_at":5000}]"#.to_string(),
+                );
+
+                let sends_before = transport
+                    .calls()
+                    .iter()
+                    .filter(|c| matches!(c, Call::Send(..)))
+                    .count();
+                conn.wake();
+                for _ in 0..6 {
+                    tokio::task::yield_now().await;
+                }
+                let replayed: Vec<String> = transport
+                    .calls()
+                    .into_iter()
+                    .filter_map(|c| match c {
+                        Call::Send(_, frame) => Some(frame),
+                        _ => None,
+                    })
+                    .skip(sends_before)
+                    .collect();
+                assert_eq!(replayed, vec![req_frame.to_string()]);
+            })
+            .await;
+    }
+
+    #[test]
+    fn resume_rewrites_only_open_ended_filters() {
+        assert_eq!(
+            resume_req_frame(r#"["REQ","s",{"ids":["a"]},{"until":5},{"since":900},{"since":10}]"#, 100)
+                .as_deref(),
+            Some(r#"["REQ","s",{"ids":["a"]},{"until":5},{"since":900},{"since":100}]"#)
+        );
+        assert_eq!(resume_req_frame(r#"["REQ","s",{"ids":["a"]}]"#, 100), None);
+        assert_eq!(resume_req_frame(r#"["CLOSE","s"]"#, 100), None);
+        assert_eq!(event_created_at(r#"["EVENT","s",{"content":"\"created_at\": 7","created_at" : 42}]"#), Some(42));
+    }
+
     #[tokio::test]
     async fn test_wake_coalesces_while_reconnect_is_in_flight() {
         let local = LocalSet::new();
@@ -2153,4 +2867,106 @@ mod tests {
             })
             .await;
     }
+
+    #[tokio::test]
+    async fn test_records_relay_health_and_stats() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let transport = Arc::new(MockRelayTransport::new());
+                let (out_writer, status_writer, to_crypto, _out, _status, _crypto) =
+                    make_writers();
+                let health = RelayHealth::new();
+
+                let conn = RelayConnection::with_health(
+                    "wss://r".to_string(),
+                    transport.clone(),
+                    out_writer,
+                    status_writer,
+                    to_crypto,
+                    health.clone(),
+                );
+                let traffic = TrafficStats::new();
+                conn.set_traffic(traffic.clone());
+
+                conn.send_raw(r#"["REQ","s1",{}]"#).unwrap();
+                tokio::task::yield_now().await;
+                tokio::task::yield_now().await;
+                tokio::task::yield_now().await;
+
+                transport.invoke_message_callback(
+                    "wss://r",
+                    r#"["EVENT","s1",{"id":"aa"}]"#.to_string(),
+                );
+                transport.invoke_message_callback("wss://r", r#"["EOSE","s1"]"#.to_string());
+                transport.invoke_message_callback("wss://r", r#"["EOSE","s1"]"#.to_string());
+                transport.invoke_message_callback(
+                    "wss://r",
+                    r#"["OK","aa",false,"blocked: spam"]"#.to_string(),
+                );
+                transport.invoke_status_callback(
+                    "wss://r",
+                    TransportStatus::Closed {
+                        url: "wss://r".to_string(),
+                    },
+                );
+
+                let stats = conn.stats();
+                assert_eq!(stats.events_received, 1);
+                assert_eq!(stats.subscriptions_created, 1);
+                assert!(stats.connected_at.is_some());
+
+                let recorded = health.stats("wss://r").expect("relay recorded");
+                assert_eq!(recorded.connect_attempts, 1);
+                assert_eq!(recorded.connect_failures, 0);
+                assert_eq!(recorded.events_received, 1);
+                assert_eq!(recorded.ok_rejected, 1);
+                assert_eq!(recorded.disconnects, 1);
+
+                let wire = traffic.relay("wss://r").expect("traffic recorded");
+                assert_eq!(wire.reqs_sent, 1);
+                assert_eq!(wire.bytes_out, r#"["REQ","s1",{}]"#.len() as u64);
+                assert_eq!(wire.frames_in, 4);
+                assert_eq!(wire.events, 1);
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_idle_relay_closes_only_without_subs_or_pending_publishes() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let transport = Arc::new(MockRelayTransport::new());
+                let (out_writer, status_writer, to_crypto, _out, _status, _crypto) =
+                    make_writers();
+                let conn = RelayConnection::new(
+                    "wss://r".to_string(),
+                    transport.clone(),
+                    out_writer,
+                    status_writer,
+                    to_crypto,
+                );
+
+                conn.send_raw(r#"["EVENT",{"id":"ee","kind":1}]"#).unwrap();
+                tokio::task::yield_now().await;
+                tokio::task::yield_now().await;
+                tokio::task::yield_now().await;
+
+                let later = now_millis() + IDLE_SHUTDOWN_MS;
+                assert!(!conn.close_if_idle(later), "publish still awaits its OK");
+
+                transport.invoke_message_callback("wss://r", r#"["OK","ee",true,""]"#.to_string());
+                assert!(
+                    !conn.close_if_idle(now_millis()),
+                    "recent traffic keeps the relay open"
+                );
+                assert!(conn.close_if_idle(now_millis() + IDLE_SHUTDOWN_MS));
+                assert!(transport
+                    .calls()
+                    .iter()
+                    .any(|c| matches!(c, Call::Disconnect(url) if url == "wss://r")));
+            })
+            .await;
+    }
 }
@@ -1,6 +1,10 @@
 pub mod connection;
 pub mod fb_utils;
 pub mod frame_scan;
+pub mod multiplexer;
+pub mod negentropy;
+pub mod rate_limit;
+pub mod recording;
 pub mod sub_dedup;
 pub mod types;
 
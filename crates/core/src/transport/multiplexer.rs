@@ -0,0 +1,828 @@
+//! Relay-level subscription multiplexing.
+//!
+//! Every app subscription used to become its own REQ on each relay. The
+//! multiplexer instead places app subscriptions ("members") into shared REQs
+//! per relay, identified by `mx:<n>` subscription ids:
+//!
+//! - A new member joins a pending shared REQ whose filters absorb its own
+//!   (compatible filters differ in a single list field, such as `authors`,
+//!   whose values are unioned), or opens a new pending one while the relay is
+//!   below `max_subs_per_relay`. At the limit it rides along as extra filters
+//!   of a pending REQ, or waits until a shared REQ closes.
+//! - Pending REQs go out on the next [`SubscriptionMultiplexer::flush`], so
+//!   subscriptions opened together share one REQ. A sent REQ never takes new
+//!   members: re-sending it would make the relay stream its stored events
+//!   again.
+//! - Incoming frames for a shared id are demultiplexed: EVENTs go to every
+//!   member whose own filters match (a filter's `limit` caps what it takes
+//!   before EOSE), EOSE and the synthetic `SUBSCRIBED` go once to each
+//!   member, CLOSED ends all members.
+//! - When a member leaves, the shared REQ is narrowed to the remaining
+//!   members' filters once they all reached EOSE; the narrowed REQ asks for
+//!   live events only (`limit: 0`). The last member leaving CLOSEs it.
+//!
+//! Subscriptions with a `limit` or `search` filter still share a REQ but are
+//! never coalesced into another filter, since both change the result set.
+//! Background members (prefetch) never share a REQ with foreground ones, so
+//! each shared REQ goes out in a single [`FramePriority`] lane.
+
+use crate::transport::frame_scan::scan_relay_frame;
+use crate::transport::rate_limit::FramePriority;
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, VecDeque};
+
+/// Prefix of shared subscription ids.
+pub const SHARED_SUB_PREFIX: &str = "mx:";
+
+/// Default cap on shared REQs open per relay.
+pub const MAX_SUBS_PER_RELAY: usize = 10;
+
+/// Values allowed in one coalesced list field before filters stay separate.
+const MAX_MERGED_VALUES: usize = 500;
+
+struct Member {
+	sub_id: String,
+	filters: Vec<Value>,
+	/// Stored events taken per filter before EOSE, checked against `limit`.
+	taken: Vec<u64>,
+	subscribed: bool,
+	eosed: bool,
+	/// Opened by a background envelope; sent in the `Low` lane.
+	background: bool,
+}
+
+impl Member {
+	fn new(sub_id: &str, filters: Vec<Value>, background: bool) -> Self {
+		Self {
+			sub_id: sub_id.to_string(),
+			taken: vec![0; filters.len()],
+			filters,
+			subscribed: false,
+			eosed: false,
+			background,
+		}
+	}
+
+	/// Whether `event` is routed to this member. Before EOSE it counts
+	/// against the first matching filter with `limit` headroom.
+	fn accepts(&mut self, event: &Value) -> bool {
+		if self.eosed {
+			return self.filters.iter().any(|f| filter_matches_event(f, event));
+		}
+		for (filter, taken) in self.filters.iter().zip(self.taken.iter_mut()) {
+			if !filter_matches_event(filter, event) {
+				continue;
+			}
+			match filter.get("limit").and_then(Value::as_u64) {
+				Some(limit) if *taken >= limit => continue,
+				_ => {
+					*taken += 1;
+					return true;
+				}
+			}
+		}
+		false
+	}
+}
+
+struct SharedReq {
+	id: String,
+	members: Vec<Member>,
+	filters: Vec<Value>,
+	/// Sent to the relay; closed to new members from then on.
+	sent: bool,
+	/// A member left after the REQ was sent; narrow once the rest reached EOSE.
+	narrow: bool,
+	/// Carries background members only.
+	background: bool,
+}
+
+impl SharedReq {
+	fn refresh(&mut self) {
+		self.filters = coalesce(self.members.iter().flat_map(|m| m.filters.iter()));
+	}
+
+	/// Whether `member` may still join: pending and in the same lane.
+	fn open_to(&self, member: &Member) -> bool {
+		!self.sent && self.background == member.background
+	}
+
+	fn absorbs(&self, member: &Member) -> bool {
+		coalesce(self.filters.iter().chain(member.filters.iter())).len() == self.filters.len()
+	}
+
+	fn req_frame(&self) -> String {
+		let mut frame = vec![json!("REQ"), json!(self.id)];
+		frame.extend(self.filters.iter().cloned());
+		Value::Array(frame).to_string()
+	}
+
+	/// REQ for the current filters without stored events.
+	fn live_req_frame(&self) -> String {
+		let mut frame = vec![json!("REQ"), json!(self.id)];
+		frame.extend(self.filters.iter().map(|filter| {
+			let mut live = filter.clone();
+			if let Some(obj) = live.as_object_mut() {
+				obj.insert("limit".to_string(), json!(0));
+			}
+			live
+		}));
+		Value::Array(frame).to_string()
+	}
+}
+
+#[derive(Default)]
+struct RelayReqs {
+	shared: Vec<SharedReq>,
+	/// Members waiting for a free slot at the cap, oldest first.
+	waiting: VecDeque<Member>,
+}
+
+impl RelayReqs {
+	fn is_empty(&self) -> bool {
+		self.shared.is_empty() && self.waiting.is_empty()
+	}
+
+	/// Places `member` into a pending shared REQ, opening one below `max_subs`,
+	/// or queues it.
+	fn place(&mut self, member: Member, max_subs: usize, next_id: &mut u64) {
+		let pending = self.shared.iter().position(|s| s.open_to(&member) && s.absorbs(&member));
+		let index = match pending {
+			Some(index) => index,
+			None if self.shared.len() < max_subs => {
+				*next_id += 1;
+				self.shared.push(SharedReq {
+					id: format!("{}{}", SHARED_SUB_PREFIX, next_id),
+					members: Vec::new(),
+					filters: Vec::new(),
+					sent: false,
+					narrow: false,
+					background: member.background,
+				});
+				self.shared.len() - 1
+			}
+			None => match self
+				.shared
+				.iter()
+				.enumerate()
+				.filter(|(_, s)| s.open_to(&member))
+				.min_by_key(|(_, s)| s.filters.len())
+			{
+				Some((index, _)) => index,
+				None => {
+					self.waiting.push_back(member);
+					return;
+				}
+			},
+		};
+		let target = &mut self.shared[index];
+		target.members.push(member);
+		target.refresh();
+	}
+
+	/// Moves waiting members into the slots freed by a closed shared REQ.
+	fn promote(&mut self, max_subs: usize, next_id: &mut u64) {
+		while self.shared.len() < max_subs {
+			let Some(member) = self.waiting.pop_front() else {
+				break;
+			};
+			self.place(member, max_subs, next_id);
+		}
+	}
+}
+
+/// Result of [`SubscriptionMultiplexer::unsubscribe`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unsubscribed {
+	/// The subscription was not multiplexed on this relay.
+	NotShared,
+	/// Removed; nothing to send right now.
+	Left,
+	/// Removed as the last member; send this CLOSE for the shared REQ.
+	Closed(String),
+}
+
+/// A shared REQ frame to send, from [`SubscriptionMultiplexer::flush`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedFrame {
+	pub relay: String,
+	pub frame: String,
+	/// App subscriptions carried by the frame.
+	pub members: Vec<String>,
+	/// Send lane: `Low` for shared REQs of background members.
+	pub priority: FramePriority,
+}
+
+/// Shared REQs per relay url.
+pub struct SubscriptionMultiplexer {
+	relays: HashMap<String, RelayReqs>,
+	max_subs_per_relay: usize,
+	next_id: u64,
+}
+
+impl Default for SubscriptionMultiplexer {
+	fn default() -> Self {
+		Self::new(MAX_SUBS_PER_RELAY)
+	}
+}
+
+impl SubscriptionMultiplexer {
+	pub fn new(max_subs_per_relay: usize) -> Self {
+		Self {
+			relays: HashMap::new(),
+			max_subs_per_relay: max_subs_per_relay.max(1),
+			next_id: 0,
+		}
+	}
+
+	pub fn is_shared_id(sub_id: &str) -> bool {
+		sub_id.starts_with(SHARED_SUB_PREFIX)
+	}
+
+	/// Shared REQs currently open or pending on `relay`.
+	pub fn shared_count(&self, relay: &str) -> usize {
+		self.relays.get(relay).map_or(0, |reqs| reqs.shared.len())
+	}
+
+	/// Members on `relay` waiting for a free shared REQ slot.
+	pub fn waiting_count(&self, relay: &str) -> usize {
+		self.relays.get(relay).map_or(0, |reqs| reqs.waiting.len())
+	}
+
+	/// Shared subscription id carrying `sub_id` on `relay`.
+	pub fn shared_id(&self, relay: &str, sub_id: &str) -> Option<&str> {
+		self.relays.get(relay)?.shared.iter().find_map(|shared| {
+			shared
+				.members
+				.iter()
+				.any(|m| m.sub_id == sub_id)
+				.then_some(shared.id.as_str())
+		})
+	}
+
+	/// Whether `sub_id` is carried (or waiting) on `relay`.
+	pub fn is_member(&self, relay: &str, sub_id: &str) -> bool {
+		self.shared_id(relay, sub_id).is_some()
+			|| self
+				.relays
+				.get(relay)
+				.is_some_and(|reqs| reqs.waiting.iter().any(|m| m.sub_id == sub_id))
+	}
+
+	/// Adds `sub_id`'s filters on `relay`; they go out with the next
+	/// [`flush`](Self::flush). A repeated REQ leaves the member's current
+	/// shared REQ first, and the CLOSE to send when that emptied it is
+	/// returned. `background` members only share REQs with each other.
+	pub fn subscribe(
+		&mut self,
+		relay: &str,
+		sub_id: &str,
+		filters: Vec<Value>,
+		background: bool,
+	) -> Option<String> {
+		let close = match self.unsubscribe(relay, sub_id) {
+			Unsubscribed::Closed(close) => Some(close),
+			_ => None,
+		};
+		let max_subs = self.max_subs_per_relay;
+		self.relays
+			.entry(relay.to_string())
+			.or_default()
+			.place(Member::new(sub_id, filters, background), max_subs, &mut self.next_id);
+		close
+	}
+
+	/// Drops `sub_id` from its shared REQ on `relay`.
+	pub fn unsubscribe(&mut self, relay: &str, sub_id: &str) -> Unsubscribed {
+		let max_subs = self.max_subs_per_relay;
+		let Some(reqs) = self.relays.get_mut(relay) else {
+			return Unsubscribed::NotShared;
+		};
+		if let Some(position) = reqs.waiting.iter().position(|m| m.sub_id == sub_id) {
+			reqs.waiting.remove(position);
+			if reqs.is_empty() {
+				self.relays.remove(relay);
+			}
+			return Unsubscribed::Left;
+		}
+		let Some(index) = reqs
+			.shared
+			.iter()
+			.position(|s| s.members.iter().any(|m| m.sub_id == sub_id))
+		else {
+			return Unsubscribed::NotShared;
+		};
+
+		let target = &mut reqs.shared[index];
+		target.members.retain(|m| m.sub_id != sub_id);
+		if !target.members.is_empty() {
+			let before = std::mem::take(&mut target.filters);
+			target.refresh();
+			if target.sent && target.filters != before {
+				target.narrow = true;
+			}
+			return Unsubscribed::Left;
+		}
+
+		let closed = reqs.shared.remove(index);
+		reqs.promote(max_subs, &mut self.next_id);
+		if reqs.is_empty() {
+			self.relays.remove(relay);
+		}
+		if closed.sent {
+			Unsubscribed::Closed(json!(["CLOSE", closed.id]).to_string())
+		} else {
+			Unsubscribed::Left
+		}
+	}
+
+	/// Forgets `relay`'s shared REQs once its connection is torn down.
+	pub fn remove_relay(&mut self, relay: &str) {
+		self.relays.remove(relay);
+	}
+
+	/// Frames to send now: pending shared REQs, and live-only narrowed REQs
+	/// for shared REQs whose remaining members all reached EOSE.
+	pub fn flush(&mut self) -> Vec<SharedFrame> {
+		let mut out = Vec::new();
+		for (relay, reqs) in &mut self.relays {
+			for shared in &mut reqs.shared {
+				let frame = if !shared.sent {
+					shared.sent = true;
+					shared.narrow = false;
+					shared.req_frame()
+				} else if shared.narrow && shared.members.iter().all(|m| m.eosed) {
+					shared.narrow = false;
+					shared.live_req_frame()
+				} else {
+					continue;
+				};
+				out.push(SharedFrame {
+					relay: relay.clone(),
+					frame,
+					members: shared.members.iter().map(|m| m.sub_id.clone()).collect(),
+					priority: if shared.background {
+						FramePriority::Low
+					} else {
+						FramePriority::Normal
+					},
+				});
+			}
+		}
+		out
+	}
+
+	/// Splits a relay frame for shared REQ `shared_id` into `(member sub id,
+	/// frame)` pairs, rewritten as if each member had its own REQ.
+	pub fn demux(&mut self, relay: &str, shared_id: &str, frame: &str) -> Vec<(String, String)> {
+		let max_subs = self.max_subs_per_relay;
+		let Some(reqs) = self.relays.get_mut(relay) else {
+			return Vec::new();
+		};
+		let Some(index) = reqs.shared.iter().position(|s| s.id == shared_id) else {
+			return Vec::new();
+		};
+		let Some(scan) = scan_relay_frame(frame) else {
+			return Vec::new();
+		};
+		let shared = &mut reqs.shared[index];
+
+		match scan.kind {
+			"EVENT" => {
+				let Some(raw) = scan.args[1].filter(|v| !v.is_string).map(|v| v.raw) else {
+					return Vec::new();
+				};
+				let Ok(event) = serde_json::from_str::<Value>(raw) else {
+					return Vec::new();
+				};
+				shared
+					.members
+					.iter_mut()
+					.filter_map(|m| {
+						m.accepts(&event).then(|| {
+							(
+								m.sub_id.clone(),
+								format!(r#"["EVENT",{},{}]"#, json!(m.sub_id), raw),
+							)
+						})
+					})
+					.collect()
+			}
+			"EOSE" => shared
+				.members
+				.iter_mut()
+				.filter_map(|m| {
+					(!std::mem::replace(&mut m.eosed, true))
+						.then(|| (m.sub_id.clone(), json!(["EOSE", m.sub_id]).to_string()))
+				})
+				.collect(),
+			"OK" => {
+				let status = scan.args[1].map(|v| v.inner()).unwrap_or_default();
+				if status != "SUBSCRIBED" {
+					return Vec::new();
+				}
+				shared
+					.members
+					.iter_mut()
+					.filter_map(|m| {
+						(!std::mem::replace(&mut m.subscribed, true)).then(|| {
+							let frame = json!(["OK", m.sub_id, "SUBSCRIBED"]).to_string();
+							(m.sub_id.clone(), frame)
+						})
+					})
+					.collect()
+			}
+			"CLOSED" => {
+				let reason = serde_json::from_str::<Value>(frame)
+					.ok()
+					.and_then(|v| v.get(2).and_then(Value::as_str).map(str::to_string))
+					.unwrap_or_default();
+				let routed = shared
+					.members
+					.iter()
+					.map(|m| (m.sub_id.clone(), json!(["CLOSED", m.sub_id, reason]).to_string()))
+					.collect();
+				reqs.shared.remove(index);
+				reqs.promote(max_subs, &mut self.next_id);
+				if reqs.is_empty() {
+					self.relays.remove(relay);
+				}
+				routed
+			}
+			_ => {
+				let Ok(Value::Array(mut arr)) = serde_json::from_str::<Value>(frame) else {
+					return Vec::new();
+				};
+				shared
+					.members
+					.iter()
+					.map(|m| {
+						if arr.len() > 1 {
+							arr[1] = json!(m.sub_id);
+						}
+						(m.sub_id.clone(), Value::Array(arr.clone()).to_string())
+					})
+					.collect()
+			}
+		}
+	}
+}
+
+/// Merges compatible filters, keeping the first occurrence's position.
+pub fn coalesce<'a>(filters: impl IntoIterator<Item = &'a Value>) -> Vec<Value> {
+	let mut out: Vec<Value> = Vec::new();
+	for filter in filters {
+		match out.iter().position(|f| merge_filters(f, filter).is_some()) {
+			Some(index) => {
+				if let Some(merged) = merge_filters(&out[index], filter) {
+					out[index] = merged;
+				}
+			}
+			None => out.push(filter.clone()),
+		}
+	}
+	out
+}
+
+/// Union of two filters that are equal except for one list field, or `None`
+/// when they cannot be expressed as a single filter.
+fn merge_filters(a: &Value, b: &Value) -> Option<Value> {
+	let (a, b) = (a.as_object()?, b.as_object()?);
+	if a == b {
+		return Some(Value::Object(a.clone()));
+	}
+	let restricted = |f: &Map<String, Value>| f.contains_key("limit") || f.contains_key("search");
+	if restricted(a) || restricted(b) || a.len() != b.len() {
+		return None;
+	}
+
+	let mut differing = None;
+	for (key, value) in a {
+		let other = b.get(key)?;
+		if value != other {
+			if differing.is_some() {
+				return None;
+			}
+			differing = Some(key);
+		}
+	}
+	let key = differing?;
+	let mut values = a.get(key)?.as_array()?.clone();
+	for value in b.get(key)?.as_array()? {
+		if !values.contains(value) {
+			values.push(value.clone());
+		}
+	}
+	if values.len() > MAX_MERGED_VALUES {
+		return None;
+	}
+	let mut merged = a.clone();
+	merged.insert(key.clone(), Value::Array(values));
+	Some(Value::Object(merged))
+}
+
+/// NIP-01 filter match of a JSON event, with the same prefix matching for
+/// `ids`/`authors` as the cache worker's live matcher.
+pub fn filter_matches_event(filter: &Value, event: &Value) -> bool {
+	let Some(filter) = filter.as_object() else {
+		return false;
+	};
+	let str_field = |name: &str| event.get(name).and_then(Value::as_str).unwrap_or("");
+	let prefix_list = |name: &str, value: &str| {
+		filter.get(name).and_then(Value::as_array).is_none_or(|wanted| {
+			wanted
+				.iter()
+				.filter_map(Value::as_str)
+				.any(|w| value.starts_with(w))
+		})
+	};
+	if !prefix_list("ids", str_field("id")) || !prefix_list("authors", str_field("pubkey")) {
+		return false;
+	}
+
+	let kind = event.get("kind").and_then(Value::as_u64);
+	if let Some(kinds) = filter.get("kinds").and_then(Value::as_array) {
+		if !kinds.iter().any(|k| k.as_u64().is_some() && k.as_u64() == kind) {
+			return false;
+		}
+	}
+
+	let created_at = event.get("created_at").and_then(Value::as_i64).unwrap_or(0);
+	if filter
+		.get("since")
+		.and_then(Value::as_i64)
+		.is_some_and(|since| created_at < since)
+		|| filter
+			.get("until")
+			.and_then(Value::as_i64)
+			.is_some_and(|until| created_at > until)
+	{
+		return false;
+	}
+
+	if let Some(search) = filter.get("search").and_then(Value::as_str) {
+		if !str_field("content")
+			.to_lowercase()
+			.contains(&search.to_lowercase())
+		{
+			return false;
+		}
+	}
+
+	let tags = event.get("tags").and_then(Value::as_array);
+	filter.iter().all(|(key, wanted)| {
+		let Some(name) = key.strip_prefix('#') else {
+			return true;
+		};
+		let Some(wanted) = wanted.as_array() else {
+			return true;
+		};
+		tags.into_iter().flatten().any(|tag| {
+			let Some(items) = tag.as_array() else {
+				return false;
+			};
+			items.first().and_then(Value::as_str) == Some(name)
+				&& items[1..].iter().any(|value| wanted.contains(value))
+		})
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const RELAY: &str = "wss://relay.example";
+
+	fn event(pubkey: &str, kind: u64) -> Value {
+		json!({
+			"id": "00".repeat(32),
+			"pubkey": pubkey,
+			"kind": kind,
+			"created_at": 10,
+			"content": "hello",
+			"tags": [["t", "nostr"]],
+			"sig": "",
+		})
+	}
+
+	fn event_frame(shared: &str, event: &Value) -> String {
+		json!(["EVENT", shared, event]).to_string()
+	}
+
+	fn flushed_frames(mux: &mut SubscriptionMultiplexer) -> Vec<Value> {
+		mux.flush()
+			.into_iter()
+			.map(|f| serde_json::from_str(&f.frame).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn compatible_filters_share_one_req() {
+		let mut mux = SubscriptionMultiplexer::default();
+		let alice = vec![json!({"kinds": [0], "authors": ["alice"]})];
+		assert!(mux.subscribe(RELAY, "a", alice, false).is_none());
+		mux.subscribe(RELAY, "b", vec![json!({"kinds": [0], "authors": ["bob"]})], false);
+
+		assert_eq!(mux.shared_count(RELAY), 1);
+		assert_eq!(mux.shared_id(RELAY, "a"), mux.shared_id(RELAY, "b"));
+		let frames = flushed_frames(&mut mux);
+		assert_eq!(frames.len(), 1);
+		assert_eq!(frames[0][2], json!({"kinds": [0], "authors": ["alice", "bob"]}));
+		assert!(frames[0].get(3).is_none());
+		assert!(mux.flush().is_empty());
+	}
+
+	#[test]
+	fn sent_reqs_take_no_new_members() {
+		let mut mux = SubscriptionMultiplexer::default();
+		mux.subscribe(RELAY, "a", vec![json!({"kinds": [0], "authors": ["alice"]})], false);
+		mux.flush();
+		mux.subscribe(RELAY, "b", vec![json!({"kinds": [0], "authors": ["bob"]})], false);
+
+		assert_ne!(mux.shared_id(RELAY, "a"), mux.shared_id(RELAY, "b"));
+		let frames = flushed_frames(&mut mux);
+		assert_eq!(frames.len(), 1);
+		assert_eq!(frames[0][1], json!(mux.shared_id(RELAY, "b").unwrap()));
+		assert_eq!(frames[0][2], json!({"kinds": [0], "authors": ["bob"]}));
+	}
+
+	#[test]
+	fn members_wait_at_the_limit() {
+		let mut mux = SubscriptionMultiplexer::new(2);
+		mux.subscribe(RELAY, "a", vec![json!({"kinds": [1], "limit": 10})], false);
+		mux.subscribe(RELAY, "b", vec![json!({"kinds": [7], "limit": 10})], false);
+		// Still pending: the member rides along as an extra filter.
+		mux.subscribe(RELAY, "c", vec![json!({"kinds": [6], "limit": 5})], false);
+		assert_eq!(mux.shared_count(RELAY), 2);
+		assert_eq!(mux.shared_id(RELAY, "a"), mux.shared_id(RELAY, "c"));
+		assert_eq!(flushed_frames(&mut mux).len(), 2);
+
+		// Both REQs are out; the next member waits for a slot.
+		mux.subscribe(RELAY, "d", vec![json!({"kinds": [9]})], false);
+		assert_eq!(mux.waiting_count(RELAY), 1);
+		assert!(mux.flush().is_empty());
+
+		let shared = mux.shared_id(RELAY, "b").unwrap().to_string();
+		assert_eq!(
+			mux.unsubscribe(RELAY, "b"),
+			Unsubscribed::Closed(json!(["CLOSE", shared]).to_string())
+		);
+		assert_eq!(mux.waiting_count(RELAY), 0);
+		let frames = flushed_frames(&mut mux);
+		assert_eq!(frames.len(), 1);
+		assert_eq!(frames[0][2], json!({"kinds": [9]}));
+	}
+
+	#[test]
+	fn events_reach_only_matching_members() {
+		let mut mux = SubscriptionMultiplexer::default();
+		mux.subscribe(RELAY, "a", vec![json!({"kinds": [0], "authors": ["alice"]})], false);
+		mux.subscribe(RELAY, "b", vec![json!({"kinds": [0], "authors": ["bob"]})], false);
+		mux.flush();
+		let shared = mux.shared_id(RELAY, "a").unwrap().to_string();
+
+		let routed = mux.demux(RELAY, &shared, &event_frame(&shared, &event("bob", 0)));
+		assert_eq!(routed.len(), 1);
+		assert_eq!(routed[0].0, "b");
+		assert!(routed[0].1.starts_with(r#"["EVENT","b",{"#));
+
+		assert!(mux
+			.demux(RELAY, &shared, &event_frame(&shared, &event("carol", 0)))
+			.is_empty());
+	}
+
+	#[test]
+	fn member_limit_caps_stored_events_until_eose() {
+		let mut mux = SubscriptionMultiplexer::new(1);
+		mux.subscribe(RELAY, "a", vec![json!({"kinds": [1], "limit": 1})], false);
+		mux.subscribe(RELAY, "b", vec![json!({"kinds": [1], "authors": ["alice"]})], false);
+		mux.flush();
+		let shared = mux.shared_id(RELAY, "a").unwrap().to_string();
+		let frame = event_frame(&shared, &event("alice", 1));
+
+		let members = |routed: Vec<(String, String)>| -> Vec<String> {
+			routed.into_iter().map(|(sub_id, _)| sub_id).collect()
+		};
+		assert_eq!(members(mux.demux(RELAY, &shared, &frame)), ["a", "b"]);
+		assert_eq!(members(mux.demux(RELAY, &shared, &frame)), ["b"]);
+
+		mux.demux(RELAY, &shared, &json!(["EOSE", shared]).to_string());
+		assert_eq!(members(mux.demux(RELAY, &shared, &frame)), ["a", "b"]);
+	}
+
+	#[test]
+	fn eose_goes_once_to_each_member() {
+		let mut mux = SubscriptionMultiplexer::default();
+		mux.subscribe(RELAY, "a", vec![json!({"kinds": [0], "authors": ["alice"]})], false);
+		mux.subscribe(RELAY, "b", vec![json!({"kinds": [0], "authors": ["bob"]})], false);
+		mux.flush();
+		let shared = mux.shared_id(RELAY, "a").unwrap().to_string();
+		let eose = json!(["EOSE", shared]).to_string();
+		assert_eq!(mux.demux(RELAY, &shared, &eose).len(), 2);
+		assert!(mux.demux(RELAY, &shared, &eose).is_empty());
+	}
+
+	#[test]
+	fn leaving_narrows_the_shared_req_to_live_events() {
+		let mut mux = SubscriptionMultiplexer::default();
+		mux.subscribe(RELAY, "a", vec![json!({"kinds": [0], "authors": ["alice"]})], false);
+		mux.subscribe(RELAY, "b", vec![json!({"kinds": [0], "authors": ["bob"]})], false);
+		mux.flush();
+		let shared = mux.shared_id(RELAY, "a").unwrap().to_string();
+
+		assert_eq!(mux.unsubscribe(RELAY, "a"), Unsubscribed::Left);
+		// "b" has not reached EOSE yet, so its stored events keep flowing.
+		assert!(mux.flush().is_empty());
+
+		mux.demux(RELAY, &shared, &json!(["EOSE", shared]).to_string());
+		let frames = flushed_frames(&mut mux);
+		assert_eq!(frames.len(), 1);
+		assert_eq!(frames[0], json!(["REQ", shared, {"kinds": [0], "authors": ["bob"], "limit": 0}]));
+		assert!(mux.flush().is_empty());
+	}
+
+	#[test]
+	fn last_member_closes_the_shared_req() {
+		let mut mux = SubscriptionMultiplexer::default();
+		mux.subscribe(RELAY, "a", vec![json!({"kinds": [0], "authors": ["alice"]})], false);
+		mux.subscribe(RELAY, "b", vec![json!({"kinds": [0], "authors": ["bob"]})], false);
+		mux.flush();
+		let shared = mux.shared_id(RELAY, "a").unwrap().to_string();
+
+		assert_eq!(mux.unsubscribe(RELAY, "a"), Unsubscribed::Left);
+		assert_eq!(
+			mux.unsubscribe(RELAY, "b"),
+			Unsubscribed::Closed(json!(["CLOSE", shared]).to_string())
+		);
+		assert_eq!(mux.unsubscribe(RELAY, "b"), Unsubscribed::NotShared);
+		assert_eq!(mux.shared_count(RELAY), 0);
+	}
+
+	#[test]
+	fn pending_reqs_close_without_a_frame() {
+		let mut mux = SubscriptionMultiplexer::default();
+		mux.subscribe(RELAY, "a", vec![json!({"kinds": [0]})], false);
+		assert_eq!(mux.unsubscribe(RELAY, "a"), Unsubscribed::Left);
+		assert!(mux.flush().is_empty());
+	}
+
+	#[test]
+	fn repeated_req_replaces_member_filters() {
+		let mut mux = SubscriptionMultiplexer::default();
+		mux.subscribe(RELAY, "a", vec![json!({"kinds": [1], "limit": 10})], false);
+		mux.flush();
+		let shared = mux.shared_id(RELAY, "a").unwrap().to_string();
+		let close = mux.subscribe(RELAY, "a", vec![json!({"kinds": [7], "limit": 10})], false);
+
+		assert_eq!(close, Some(json!(["CLOSE", shared]).to_string()));
+		let frames = flushed_frames(&mut mux);
+		assert_eq!(frames.len(), 1);
+		assert_eq!(frames[0][2], json!({"kinds": [7], "limit": 10}));
+		assert!(frames[0].get(3).is_none());
+	}
+
+	#[test]
+	fn relay_closed_ends_every_member() {
+		let mut mux = SubscriptionMultiplexer::default();
+		mux.subscribe(RELAY, "a", vec![json!({"kinds": [0], "authors": ["alice"]})], false);
+		mux.subscribe(RELAY, "b", vec![json!({"kinds": [0], "authors": ["bob"]})], false);
+		mux.flush();
+		let shared = mux.shared_id(RELAY, "a").unwrap().to_string();
+
+		let closed = json!(["CLOSED", shared, "auth-required: sign in"]).to_string();
+		let routed = mux.demux(RELAY, &shared, &closed);
+		assert_eq!(routed.len(), 2);
+		assert_eq!(routed[0].1, r#"["CLOSED","a","auth-required: sign in"]"#);
+		assert_eq!(mux.shared_count(RELAY), 0);
+	}
+
+	#[test]
+	fn background_members_get_their_own_low_priority_req() {
+		let mut mux = SubscriptionMultiplexer::new(2);
+		mux.subscribe(RELAY, "feed", vec![json!({"kinds": [0], "authors": ["alice"]})], false);
+		mux.subscribe(RELAY, "prefetch", vec![json!({"kinds": [0], "authors": ["bob"]})], true);
+		assert_ne!(mux.shared_id(RELAY, "feed"), mux.shared_id(RELAY, "prefetch"));
+
+		// At the cap a background member rides along with background REQs only.
+		mux.subscribe(RELAY, "warm", vec![json!({"kinds": [3], "limit": 1})], true);
+		assert_eq!(mux.shared_id(RELAY, "warm"), mux.shared_id(RELAY, "prefetch"));
+
+		let mut frames = mux.flush();
+		frames.sort_by(|a, b| a.members.cmp(&b.members));
+		assert_eq!(frames.len(), 2);
+		assert_eq!(frames[0].members, ["feed"]);
+		assert_eq!(frames[0].priority, FramePriority::Normal);
+		assert_eq!(frames[1].members, ["prefetch", "warm"]);
+		assert_eq!(frames[1].priority, FramePriority::Low);
+	}
+
+	#[test]
+	fn filter_matching_follows_nip01() {
+		let ev = event("alice", 1);
+		assert!(filter_matches_event(&json!({"kinds": [1], "#t": ["nostr"]}), &ev));
+		assert!(filter_matches_event(&json!({"authors": ["ali"]}), &ev));
+		assert!(!filter_matches_event(&json!({"#t": ["other"]}), &ev));
+		assert!(!filter_matches_event(&json!({"since": 11}), &ev));
+		assert!(!filter_matches_event(&json!({"kinds": [1], "search": "bye"}), &ev));
+	}
+}
@@ -0,0 +1,392 @@
+//! NIP-77 negentropy reconciliation against relays.
+//!
+//! A request flagged `negentropy` reaches the connections worker as a normal
+//! REQ envelope plus a `NegentropySpec`: the filter and the `(created_at, id)`
+//! pairs the local cache already holds for it. Instead of the REQ, each relay
+//! gets a `NEG-OPEN`; `NEG-MSG` rounds are answered here until the relay-side
+//! difference is known, then the session is closed and the relay is asked for
+//! the missing ids only, one REQ per `NEGENTROPY_IDS_PER_FILTER` chunk. Each
+//! chunk replaces the previous REQ once its EOSE arrives; the last one also
+//! carries the original filter with `limit: 0` so live events keep flowing
+//! under the same subscription id.
+//!
+//! Relays without NIP-77 answer `NEG-ERR` (or nothing at all). Those sessions
+//! fall back to the envelope's original REQ frames, either immediately or
+//! once `NEGENTROPY_IDLE_TIMEOUT_MS` passes without a reply.
+
+use negentropy::{Id, Negentropy, NegentropyStorageVector};
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+
+/// Frame size limit for outgoing NEG-MSG payloads (bytes, before hex).
+const NEGENTROPY_FRAME_SIZE_LIMIT: u64 = 60_000;
+
+/// Missing ids requested per REQ once reconciliation completes.
+const NEGENTROPY_IDS_PER_FILTER: usize = 500;
+
+/// Sessions without a relay reply for this long fall back to the plain REQ.
+pub const NEGENTROPY_IDLE_TIMEOUT_MS: u64 = 10_000;
+
+/// Reconciliation request attached to a cache→connections envelope.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct NegentropySpec {
+	pub sub_id: String,
+	/// Filter to reconcile, without `limit`.
+	pub filter: Value,
+	/// `(created_at, hex id)` of every cached event matching `filter`.
+	#[serde(default)]
+	pub items: Vec<(u64, String)>,
+}
+
+struct Session {
+	reconciler: Negentropy<'static, NegentropyStorageVector>,
+	filter: Value,
+	fallback: Vec<String>,
+	need: Vec<Id>,
+	last_activity_ms: u64,
+}
+
+/// In-flight reconciliations keyed by (relay url, full subscription id).
+#[derive(Default)]
+pub struct NegentropySessions {
+	sessions: HashMap<(String, String), Session>,
+	/// REQ chunks still to send after reconciliation, one per EOSE.
+	fetches: HashMap<(String, String), VecDeque<String>>,
+}
+
+impl NegentropySessions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn len(&self) -> usize {
+		self.sessions.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.sessions.is_empty()
+	}
+
+	/// Starts reconciling `spec` with `relay` and returns the frames to send:
+	/// the `NEG-OPEN`, or `fallback` when the local set cannot be encoded.
+	pub fn open(
+		&mut self,
+		relay: &str,
+		spec: &NegentropySpec,
+		fallback: &[String],
+		now_ms: u64,
+	) -> Vec<String> {
+		let mut storage = NegentropyStorageVector::with_capacity(spec.items.len());
+		for (created_at, id) in &spec.items {
+			let Some(id) = decode_id(id) else {
+				continue;
+			};
+			if storage.insert(*created_at, id).is_err() {
+				return fallback.to_vec();
+			}
+		}
+		if storage.seal().is_err() {
+			return fallback.to_vec();
+		}
+		let Ok(mut reconciler) = Negentropy::owned(storage, NEGENTROPY_FRAME_SIZE_LIMIT) else {
+			return fallback.to_vec();
+		};
+		let Ok(initial) = reconciler.initiate() else {
+			return fallback.to_vec();
+		};
+
+		self.sessions.insert(
+			(relay.to_string(), spec.sub_id.clone()),
+			Session {
+				reconciler,
+				filter: spec.filter.clone(),
+				fallback: fallback.to_vec(),
+				need: Vec::new(),
+				last_activity_ms: now_ms,
+			},
+		);
+		vec![json!(["NEG-OPEN", spec.sub_id, spec.filter, hex::encode(initial)]).to_string()]
+	}
+
+	/// Handles an inbound `NEG-MSG`/`NEG-ERR` frame for `(relay, sub_id)` and
+	/// returns the frames to send back. `None` when no session matches, in
+	/// which case the frame is stale and can be dropped.
+	pub fn handle(
+		&mut self,
+		relay: &str,
+		sub_id: &str,
+		frame: &str,
+		now_ms: u64,
+	) -> Option<Vec<String>> {
+		let key = (relay.to_string(), sub_id.to_string());
+		let session = self.sessions.get_mut(&key)?;
+		session.last_activity_ms = now_ms;
+
+		let value: Value = serde_json::from_str(frame).ok()?;
+		let arr = value.as_array()?;
+		let kind = arr.first().and_then(Value::as_str).unwrap_or("");
+
+		let next = if kind == "NEG-MSG" {
+			arr.get(2)
+				.and_then(Value::as_str)
+				.and_then(|payload| hex::decode(payload).ok())
+				.and_then(|query| {
+					let mut have = Vec::new();
+					let mut need = Vec::new();
+					let next = session
+						.reconciler
+						.reconcile_with_ids(&query, &mut have, &mut need)
+						.ok()?;
+					session.need.extend(need);
+					Some(next)
+				})
+		} else {
+			let reason = arr.get(2).and_then(|v| v.as_str()).unwrap_or("");
+			tracing::info!(
+				relay,
+				sub_id,
+				reason,
+				"[connections][negentropy] relay rejected NEG-OPEN, falling back to REQ"
+			);
+			None
+		};
+
+		match next {
+			Some(Some(msg)) => Some(vec![json!(["NEG-MSG", sub_id, hex::encode(msg)]).to_string()]),
+			Some(None) => {
+				let session = self.sessions.remove(&key)?;
+				tracing::info!(
+					relay,
+					sub_id,
+					missing = session.need.len(),
+					"[connections][negentropy] reconciliation complete"
+				);
+				let mut reqs = missing_ids_reqs(sub_id, &session.filter, &session.need);
+				let first = reqs.pop_front()?;
+				if !reqs.is_empty() {
+					self.fetches.insert(key, reqs);
+				}
+				Some(vec![json!(["NEG-CLOSE", sub_id]).to_string(), first])
+			}
+			None => self.sessions.remove(&key).map(|s| s.fallback),
+		}
+	}
+
+	/// Called on EOSE for `(relay, sub_id)`: returns the next missing-ids REQ
+	/// when a chunked fetch is still running, in which case the EOSE belongs
+	/// to an intermediate chunk and must not reach the app.
+	pub fn next_fetch(&mut self, relay: &str, sub_id: &str) -> Option<String> {
+		if self.fetches.is_empty() {
+			return None;
+		}
+		let key = (relay.to_string(), sub_id.to_string());
+		let queue = self.fetches.get_mut(&key)?;
+		let next = queue.pop_front();
+		if queue.is_empty() {
+			self.fetches.remove(&key);
+		}
+		next
+	}
+
+	/// Drops the sessions of `sub_id` (on `relay` only, or everywhere) and
+	/// returns the relays that still had one in flight, which should get a
+	/// `NEG-CLOSE`.
+	pub fn close(&mut self, relay: Option<&str>, sub_id: &str) -> Vec<String> {
+		self.fetches
+			.retain(|(r, s), _| s != sub_id || relay.is_some_and(|relay| relay != r));
+		let keys: Vec<(String, String)> = self
+			.sessions
+			.keys()
+			.filter(|(r, s)| s == sub_id && relay.is_none_or(|relay| relay == r))
+			.cloned()
+			.collect();
+		for key in &keys {
+			self.sessions.remove(key);
+		}
+		keys.into_iter().map(|(relay, _)| relay).collect()
+	}
+
+	/// Removes sessions idle for longer than `NEGENTROPY_IDLE_TIMEOUT_MS` and
+	/// returns `(relay, frames)` to send: a `NEG-CLOSE` plus the fallback REQ.
+	pub fn expire(&mut self, now_ms: u64) -> Vec<(String, Vec<String>)> {
+		if self.sessions.is_empty() {
+			return Vec::new();
+		}
+		let expired: Vec<(String, String)> = self
+			.sessions
+			.iter()
+			.filter(|(_, s)| now_ms.saturating_sub(s.last_activity_ms) >= NEGENTROPY_IDLE_TIMEOUT_MS)
+			.map(|(key, _)| key.clone())
+			.collect();
+		expired
+			.into_iter()
+			.filter_map(|key| {
+				let session = self.sessions.remove(&key)?;
+				tracing::info!(
+					relay = %key.0,
+					sub_id = %key.1,
+					"[connections][negentropy] no reply, falling back to REQ"
+				);
+				let mut frames = vec![json!(["NEG-CLOSE", key.1]).to_string()];
+				frames.extend(session.fallback);
+				Some((key.0, frames))
+			})
+			.collect()
+	}
+}
+
+fn decode_id(hex_id: &str) -> Option<Id> {
+	let bytes = hex::decode(hex_id).ok()?;
+	Id::from_slice(&bytes).ok()
+}
+
+/// REQs fetching `need` by id, one chunk each; the last one also carries
+/// `filter` with `limit: 0` for live events.
+fn missing_ids_reqs(sub_id: &str, filter: &Value, need: &[Id]) -> VecDeque<String> {
+	let mut live = filter.clone();
+	if let Some(obj) = live.as_object_mut() {
+		obj.insert("limit".to_string(), json!(0));
+	}
+	let mut frames: VecDeque<Vec<Value>> = need
+		.chunks(NEGENTROPY_IDS_PER_FILTER)
+		.map(|chunk| {
+			let ids: Vec<String> = chunk.iter().map(|id| hex::encode(id.as_bytes())).collect();
+			vec![json!("REQ"), json!(sub_id), json!({ "ids": ids })]
+		})
+		.collect();
+	match frames.back_mut() {
+		Some(last) => last.push(live),
+		None => frames.push_back(vec![json!("REQ"), json!(sub_id), live]),
+	}
+	frames.into_iter().map(|frame| Value::Array(frame).to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn id_hex(n: u8) -> String {
+		hex::encode([n; 32])
+	}
+
+	fn relay_reconciler(items: &[(u64, u8)]) -> Negentropy<'static, NegentropyStorageVector> {
+		let mut storage = NegentropyStorageVector::new();
+		for (created_at, n) in items {
+			storage.insert(*created_at, Id::from_byte_array([*n; 32])).unwrap();
+		}
+		storage.seal().unwrap();
+		Negentropy::owned(storage, 0).unwrap()
+	}
+
+	fn spec(items: &[(u64, u8)]) -> NegentropySpec {
+		NegentropySpec {
+			sub_id: "s1".to_string(),
+			filter: json!({ "kinds": [1] }),
+			items: items.iter().map(|(t, n)| (*t, id_hex(*n))).collect(),
+		}
+	}
+
+	fn payload(frame: &str) -> Vec<u8> {
+		let value: Value = serde_json::from_str(frame).unwrap();
+		let arr = value.as_array().unwrap();
+		hex::decode(arr.last().unwrap().as_str().unwrap()).unwrap()
+	}
+
+	#[test]
+	fn reconciles_and_requests_only_missing_ids() {
+		let mut sessions = NegentropySessions::new();
+		let fallback = vec![r#"["REQ","s1",{"kinds":[1],"limit":10}]"#.to_string()];
+		let local = [(10, 1), (20, 2)];
+		let remote = [(10, 1), (20, 2), (30, 3), (40, 4)];
+
+		let mut out = sessions.open("wss://r", &spec(&local), &fallback, 0);
+		assert_eq!(out.len(), 1);
+		assert!(out[0].starts_with(r#"["NEG-OPEN","s1",{"kinds":[1]}"#));
+
+		let mut relay = relay_reconciler(&remote);
+		let final_frames = loop {
+			let reply = relay.reconcile(&payload(&out[0])).unwrap();
+			let frame = json!(["NEG-MSG", "s1", hex::encode(reply)]).to_string();
+			out = sessions.handle("wss://r", "s1", &frame, 1).unwrap();
+			if out.len() == 2 {
+				break out;
+			}
+		};
+
+		assert_eq!(final_frames[0], r#"["NEG-CLOSE","s1"]"#);
+		let req: Value = serde_json::from_str(&final_frames[1]).unwrap();
+		let mut ids: Vec<String> = req[2]["ids"]
+			.as_array()
+			.unwrap()
+			.iter()
+			.map(|v| v.as_str().unwrap().to_string())
+			.collect();
+		ids.sort();
+		assert_eq!(ids, vec![id_hex(3), id_hex(4)]);
+		assert_eq!(req[3], json!({ "kinds": [1], "limit": 0 }));
+		assert!(sessions.is_empty());
+	}
+
+	#[test]
+	fn missing_ids_are_fetched_one_chunk_per_eose() {
+		let need: Vec<Id> = (0..NEGENTROPY_IDS_PER_FILTER + 1)
+			.map(|n| Id::from_byte_array([(n % 251) as u8; 32]))
+			.collect();
+		let reqs = missing_ids_reqs("s1", &json!({ "kinds": [1] }), &need);
+		assert_eq!(reqs.len(), 2);
+		let first: Value = serde_json::from_str(&reqs[0]).unwrap();
+		assert_eq!(first.as_array().unwrap().len(), 3);
+		assert_eq!(first[2]["ids"].as_array().unwrap().len(), NEGENTROPY_IDS_PER_FILTER);
+		let last: Value = serde_json::from_str(&reqs[1]).unwrap();
+		assert_eq!(last[2]["ids"].as_array().unwrap().len(), 1);
+		assert_eq!(last[3], json!({ "kinds": [1], "limit": 0 }));
+
+		let mut sessions = NegentropySessions::new();
+		sessions.fetches.insert(("wss://r".to_string(), "s1".to_string()), reqs.clone());
+		assert!(sessions.next_fetch("wss://other", "s1").is_none());
+		assert_eq!(sessions.next_fetch("wss://r", "s1").as_deref(), Some(reqs[0].as_str()));
+		assert_eq!(sessions.next_fetch("wss://r", "s1").as_deref(), Some(reqs[1].as_str()));
+		assert!(sessions.next_fetch("wss://r", "s1").is_none());
+	}
+
+	#[test]
+	fn neg_err_falls_back_to_original_req() {
+		let mut sessions = NegentropySessions::new();
+		let fallback = vec![r#"["REQ","s1",{"kinds":[1],"limit":10}]"#.to_string()];
+		sessions.open("wss://r", &spec(&[]), &fallback, 0);
+
+		let out = sessions
+			.handle("wss://r", "s1", r#"["NEG-ERR","s1","blocked: unsupported"]"#, 1)
+			.unwrap();
+		assert_eq!(out, fallback);
+		assert!(sessions.is_empty());
+		assert!(sessions.handle("wss://r", "s1", r#"["NEG-ERR","s1",""]"#, 2).is_none());
+	}
+
+	#[test]
+	fn idle_sessions_expire_to_fallback() {
+		let mut sessions = NegentropySessions::new();
+		let fallback = vec![r#"["REQ","s1",{}]"#.to_string()];
+		sessions.open("wss://r", &spec(&[(1, 1)]), &fallback, 100);
+
+		assert!(sessions.expire(100 + NEGENTROPY_IDLE_TIMEOUT_MS - 1).is_empty());
+		let expired = sessions.expire(100 + NEGENTROPY_IDLE_TIMEOUT_MS);
+		assert_eq!(expired.len(), 1);
+		assert_eq!(expired[0].0, "wss://r");
+		assert_eq!(
+			expired[0].1,
+			vec![r#"["NEG-CLOSE","s1"]"#.to_string(), fallback[0].clone()]
+		);
+	}
+
+	#[test]
+	fn close_reports_relays_with_sessions_in_flight() {
+		let mut sessions = NegentropySessions::new();
+		sessions.open("wss://a", &spec(&[]), &[], 0);
+		sessions.open("wss://b", &spec(&[]), &[], 0);
+
+		assert_eq!(sessions.close(Some("wss://a"), "s1"), vec!["wss://a".to_string()]);
+		assert_eq!(sessions.close(None, "s1"), vec!["wss://b".to_string()]);
+		assert!(sessions.close(None, "s1").is_empty());
+	}
+}
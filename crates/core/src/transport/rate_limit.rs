@@ -0,0 +1,420 @@
+//! Per-relay outgoing rate limiting and frame prioritisation.
+//!
+//! Every `RelayConnection` drains its send queue through a `FrameQueue`
+//! (three priority lanes) gated by a `RateLimiter` (one token bucket per
+//! frame class). Publishes, CLOSEs and AUTH go in the high lane, ordinary
+//! subscriptions in the normal lane and background prefetch REQs in the low
+//! lane; within a class a lower lane never overtakes a higher one, while
+//! classes are paced independently so a burst of REQs never delays a publish.
+//!
+//! When a relay answers `rate-limited:` (CLOSED/OK) or sends a NOTICE asking
+//! us to slow down, the affected buckets are halved (down to
+//! `RateLimits::min_per_sec`) and then doubled back toward the configured
+//! rate every `RateLimits::recovery_ms` without another complaint.
+
+use crate::transport::frame_scan::scan_relay_frame;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Frame classes with their own token bucket. `Other` (AUTH, NEG-MSG,
+/// NEG-CLOSE) is never paced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameClass {
+	/// REQ, COUNT and NEG-OPEN: everything that opens a subscription.
+	Req,
+	Event,
+	Close,
+	Other,
+}
+
+impl FrameClass {
+	pub fn of(kind: &str) -> Self {
+		match kind {
+			"REQ" | "COUNT" | "NEG-OPEN" => FrameClass::Req,
+			"EVENT" => FrameClass::Event,
+			"CLOSE" => FrameClass::Close,
+			_ => FrameClass::Other,
+		}
+	}
+}
+
+/// Send-queue lane. Lanes are drained in declaration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FramePriority {
+	/// User-initiated publishes, CLOSEs and AUTH.
+	High,
+	/// Foreground subscriptions.
+	Normal,
+	/// Background prefetch (`Request::background`).
+	Low,
+}
+
+impl FramePriority {
+	/// Default lane for a frame: subscriptions are normal, the rest high.
+	pub fn of(frame: &str) -> Self {
+		match scan_relay_frame(frame).map(|scan| FrameClass::of(scan.kind)) {
+			Some(FrameClass::Req) | None => FramePriority::Normal,
+			Some(_) => FramePriority::High,
+		}
+	}
+}
+
+/// One token bucket: `burst` frames at once, refilled at `per_sec`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BucketLimit {
+	pub burst: f64,
+	pub per_sec: f64,
+}
+
+/// Per-relay limits. Every relay gets its own buckets with these settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RateLimits {
+	pub req: BucketLimit,
+	pub event: BucketLimit,
+	pub close: BucketLimit,
+	/// Floor for automatic tightening, in frames per second.
+	pub min_per_sec: f64,
+	/// How long a tightened bucket stays at its rate before doubling back.
+	pub recovery_ms: u64,
+}
+
+impl Default for RateLimits {
+	fn default() -> Self {
+		Self {
+			req: BucketLimit {
+				burst: 8.0,
+				per_sec: 4.0,
+			},
+			event: BucketLimit {
+				burst: 10.0,
+				per_sec: 5.0,
+			},
+			close: BucketLimit {
+				burst: 20.0,
+				per_sec: 10.0,
+			},
+			min_per_sec: 0.25,
+			recovery_ms: 30_000,
+		}
+	}
+}
+
+struct TokenBucket {
+	limit: BucketLimit,
+	rate: f64,
+	tokens: f64,
+	last_ms: u64,
+	changed_ms: u64,
+}
+
+impl TokenBucket {
+	fn new(limit: BucketLimit, now_ms: u64) -> Self {
+		Self {
+			limit,
+			rate: limit.per_sec,
+			tokens: limit.burst,
+			last_ms: now_ms,
+			changed_ms: now_ms,
+		}
+	}
+
+	fn refill(&mut self, now_ms: u64, recovery_ms: u64) {
+		while self.rate < self.limit.per_sec && now_ms.saturating_sub(self.changed_ms) >= recovery_ms {
+			self.rate = (self.rate * 2.0).min(self.limit.per_sec);
+			self.changed_ms += recovery_ms;
+		}
+		let elapsed = now_ms.saturating_sub(self.last_ms) as f64 / 1000.0;
+		self.tokens = (self.tokens + elapsed * self.rate).min(self.limit.burst.max(1.0));
+		self.last_ms = now_ms;
+	}
+
+	fn wait_ms(&self) -> u64 {
+		if self.tokens >= 1.0 || self.rate <= 0.0 {
+			0
+		} else {
+			((1.0 - self.tokens) / self.rate * 1000.0).ceil() as u64
+		}
+	}
+
+	fn tighten(&mut self, min_per_sec: f64, now_ms: u64) {
+		self.rate = (self.rate / 2.0).max(min_per_sec.min(self.limit.per_sec));
+		self.tokens = 0.0;
+		self.changed_ms = now_ms;
+	}
+}
+
+/// Token buckets for one relay.
+pub struct RateLimiter {
+	limits: RateLimits,
+	req: TokenBucket,
+	event: TokenBucket,
+	close: TokenBucket,
+}
+
+impl RateLimiter {
+	pub fn new(limits: RateLimits, now_ms: u64) -> Self {
+		Self {
+			req: TokenBucket::new(limits.req, now_ms),
+			event: TokenBucket::new(limits.event, now_ms),
+			close: TokenBucket::new(limits.close, now_ms),
+			limits,
+		}
+	}
+
+	fn bucket(&mut self, class: FrameClass) -> Option<&mut TokenBucket> {
+		match class {
+			FrameClass::Req => Some(&mut self.req),
+			FrameClass::Event => Some(&mut self.event),
+			FrameClass::Close => Some(&mut self.close),
+			FrameClass::Other => None,
+		}
+	}
+
+	/// Milliseconds until a `class` frame may be sent (0 = now).
+	pub fn wait_ms(&mut self, class: FrameClass, now_ms: u64) -> u64 {
+		let recovery_ms = self.limits.recovery_ms;
+		match self.bucket(class) {
+			Some(bucket) => {
+				bucket.refill(now_ms, recovery_ms);
+				bucket.wait_ms()
+			}
+			None => 0,
+		}
+	}
+
+	fn take(&mut self, class: FrameClass) {
+		if let Some(bucket) = self.bucket(class) {
+			bucket.tokens = (bucket.tokens - 1.0).max(0.0);
+		}
+	}
+
+	/// Halves the rate of `classes` after the relay pushed back.
+	pub fn tighten(&mut self, classes: &[FrameClass], now_ms: u64) {
+		let min_per_sec = self.limits.min_per_sec;
+		for class in classes {
+			if let Some(bucket) = self.bucket(*class) {
+				bucket.tighten(min_per_sec, now_ms);
+			}
+		}
+	}
+
+	/// Current refill rate of `class`, in frames per second.
+	pub fn rate(&self, class: FrameClass) -> Option<f64> {
+		match class {
+			FrameClass::Req => Some(self.req.rate),
+			FrameClass::Event => Some(self.event.rate),
+			FrameClass::Close => Some(self.close.rate),
+			FrameClass::Other => None,
+		}
+	}
+}
+
+/// Classes to tighten for an incoming relay frame, if it signals rate
+/// limiting: `rate-limited:` on CLOSED (subscriptions) or OK (publishes), or
+/// a NOTICE asking to slow down (everything).
+pub fn throttled_classes(kind: &str, text: &str) -> &'static [FrameClass] {
+	match kind {
+		"CLOSED" if text.contains("rate-limited:") => &[FrameClass::Req],
+		"OK" if text.contains("rate-limited:") => &[FrameClass::Event],
+		"NOTICE" => {
+			let text = text.to_ascii_lowercase();
+			let throttled = ["rate-limit", "rate limit", "too many", "too fast", "slow down"]
+				.iter()
+				.any(|needle| text.contains(needle));
+			if throttled {
+				&[FrameClass::Req, FrameClass::Event, FrameClass::Close]
+			} else {
+				&[]
+			}
+		}
+		_ => &[],
+	}
+}
+
+/// Result of `FrameQueue::pop`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum NextFrame {
+	Ready(String),
+	/// Every queued frame is paced; retry after this many milliseconds.
+	Wait(u64),
+	Empty,
+}
+
+/// Prioritised send queue for one relay.
+#[derive(Default)]
+pub struct FrameQueue {
+	lanes: [VecDeque<(FrameClass, String)>; 3],
+}
+
+impl FrameQueue {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn len(&self) -> usize {
+		self.lanes.iter().map(VecDeque::len).sum()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.lanes.iter().all(VecDeque::is_empty)
+	}
+
+	/// Queues `frame` and returns how many queued frames it made redundant: a
+	/// CLOSE jumps ahead of its subscription's REQ, so a REQ that never left
+	/// the queue is dropped instead of opening a subscription after its CLOSE.
+	pub fn push(&mut self, frame: String, priority: FramePriority) -> usize {
+		let (class, sub_id) = match scan_relay_frame(&frame) {
+			Some(scan) => (
+				FrameClass::of(scan.kind),
+				scan.args[0].map(|v| v.inner().to_string()),
+			),
+			None => (FrameClass::Other, None),
+		};
+		let mut dropped = 0;
+		if let (FrameClass::Close, Some(sub_id)) = (class, sub_id.as_deref()) {
+			for lane in self.lanes.iter_mut() {
+				let before = lane.len();
+				lane.retain(|(queued_class, queued)| {
+					*queued_class != FrameClass::Req
+						|| scan_relay_frame(queued)
+							.and_then(|scan| scan.args[0])
+							.is_none_or(|v| v.inner() != sub_id)
+				});
+				dropped += before - lane.len();
+			}
+		}
+		self.lanes[priority as usize].push_back((class, frame));
+		dropped
+	}
+
+	/// Takes the highest-priority frame whose class has a token. A lane's
+	/// head that is paced blocks lower lanes of the same class only.
+	pub fn pop(&mut self, limiter: &mut RateLimiter, now_ms: u64) -> NextFrame {
+		let mut blocked: Vec<FrameClass> = Vec::new();
+		let mut wait: Option<u64> = None;
+		for lane in self.lanes.iter_mut() {
+			let Some((class, _)) = lane.front() else {
+				continue;
+			};
+			let class = *class;
+			if blocked.contains(&class) {
+				continue;
+			}
+			match limiter.wait_ms(class, now_ms) {
+				0 => {
+					limiter.take(class);
+					return NextFrame::Ready(lane.pop_front().map(|(_, frame)| frame).unwrap_or_default());
+				}
+				ms => {
+					blocked.push(class);
+					wait = Some(wait.map_or(ms, |w| w.min(ms)));
+				}
+			}
+		}
+		match wait {
+			Some(ms) => NextFrame::Wait(ms),
+			None => NextFrame::Empty,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn req(sub: &str) -> String {
+		format!(r#"["REQ","{}",{{"kinds":[1]}}]"#, sub)
+	}
+
+	#[test]
+	fn buckets_allow_a_burst_then_pace() {
+		let limits = RateLimits {
+			req: BucketLimit {
+				burst: 2.0,
+				per_sec: 2.0,
+			},
+			..Default::default()
+		};
+		let mut limiter = RateLimiter::new(limits, 0);
+		let mut queue = FrameQueue::new();
+		for sub in ["a", "b", "c"] {
+			queue.push(req(sub), FramePriority::Normal);
+		}
+		assert_eq!(queue.pop(&mut limiter, 0), NextFrame::Ready(req("a")));
+		assert_eq!(queue.pop(&mut limiter, 0), NextFrame::Ready(req("b")));
+		assert_eq!(queue.pop(&mut limiter, 0), NextFrame::Wait(500));
+		assert_eq!(queue.pop(&mut limiter, 500), NextFrame::Ready(req("c")));
+		assert_eq!(queue.pop(&mut limiter, 500), NextFrame::Empty);
+	}
+
+	#[test]
+	fn publishes_and_foreground_reqs_jump_ahead_of_prefetch() {
+		let mut limiter = RateLimiter::new(RateLimits::default(), 0);
+		let mut queue = FrameQueue::new();
+		let event = r#"["EVENT",{"id":"e1","kind":1}]"#.to_string();
+		queue.push(req("prefetch"), FramePriority::Low);
+		queue.push(req("feed"), FramePriority::of(&req("feed")));
+		queue.push(event.clone(), FramePriority::of(&event));
+
+		assert_eq!(queue.pop(&mut limiter, 0), NextFrame::Ready(event));
+		assert_eq!(queue.pop(&mut limiter, 0), NextFrame::Ready(req("feed")));
+		assert_eq!(queue.pop(&mut limiter, 0), NextFrame::Ready(req("prefetch")));
+	}
+
+	#[test]
+	fn paced_reqs_do_not_hold_back_other_classes() {
+		let limits = RateLimits {
+			req: BucketLimit {
+				burst: 1.0,
+				per_sec: 1.0,
+			},
+			..Default::default()
+		};
+		let mut limiter = RateLimiter::new(limits, 0);
+		let mut queue = FrameQueue::new();
+		queue.push(req("a"), FramePriority::Normal);
+		queue.push(req("b"), FramePriority::Normal);
+		queue.push(req("c"), FramePriority::Low);
+		assert_eq!(queue.pop(&mut limiter, 0), NextFrame::Ready(req("a")));
+
+		let close = r#"["CLOSE","x"]"#.to_string();
+		queue.push(close.clone(), FramePriority::of(&close));
+		// "b" is paced; "c" may not overtake it, but the CLOSE may.
+		assert_eq!(queue.pop(&mut limiter, 0), NextFrame::Ready(close));
+		assert_eq!(queue.pop(&mut limiter, 0), NextFrame::Wait(1000));
+		assert_eq!(queue.pop(&mut limiter, 1000), NextFrame::Ready(req("b")));
+	}
+
+	#[test]
+	fn close_drops_its_unsent_req() {
+		let mut queue = FrameQueue::new();
+		queue.push(req("a"), FramePriority::Low);
+		queue.push(req("b"), FramePriority::Normal);
+		assert_eq!(queue.push(r#"["CLOSE","a"]"#.to_string(), FramePriority::High), 1);
+		assert_eq!(queue.len(), 2);
+	}
+
+	#[test]
+	fn rate_limited_replies_tighten_then_recover() {
+		let mut limiter = RateLimiter::new(RateLimits::default(), 0);
+		let closed = r#"["CLOSED","s1","rate-limited: slow down"]"#;
+		limiter.tighten(throttled_classes("CLOSED", closed), 0);
+		limiter.tighten(throttled_classes("CLOSED", closed), 0);
+		assert_eq!(limiter.rate(FrameClass::Req), Some(1.0));
+		assert_eq!(limiter.rate(FrameClass::Event), Some(5.0));
+		assert!(limiter.wait_ms(FrameClass::Req, 0) > 0);
+
+		assert_eq!(limiter.wait_ms(FrameClass::Req, 30_000), 0);
+		assert_eq!(limiter.rate(FrameClass::Req), Some(2.0));
+		limiter.wait_ms(FrameClass::Req, 60_000);
+		assert_eq!(limiter.rate(FrameClass::Req), Some(4.0));
+
+		assert_eq!(
+			throttled_classes("NOTICE", "Too many concurrent REQs"),
+			&[FrameClass::Req, FrameClass::Event, FrameClass::Close]
+		);
+		assert!(throttled_classes("NOTICE", "welcome").is_empty());
+		assert!(throttled_classes("OK", r#"["OK","e1",true,""]"#).is_empty());
+	}
+}
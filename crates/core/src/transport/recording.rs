@@ -0,0 +1,882 @@
+//! Record-and-replay relay transport.
+//!
+//! `RecordingTransport` decorates any `RelayTransport` and logs every
+//! connect, disconnect, sent frame, received frame and status change with a
+//! timestamp (ms since the recording started). A `Recording` serializes to
+//! JSON lines, so a session captured in an app can be attached to a bug
+//! report and loaded back in `cargo test`.
+//!
+//! `ReplayTransport` serves a recording back without any network:
+//! - `connect` returns the recorded outcome for that relay (in order) and
+//!   delivers frames the relay sent before we sent anything (AUTH challenge,
+//!   NOTICE).
+//! - REQ/COUNT are matched against unconsumed recorded frames by normalized
+//!   filters: key order, array order and duplicates are ignored, as are
+//!   `since`/`until` which apps derive from the clock. The recorded replies
+//!   for that subscription are delivered with the live subscription id.
+//! - EVENT/AUTH are matched by event id, falling back to recording order
+//!   (signatures, and therefore ids, change between runs); the recorded `OK`
+//!   is delivered for the live id.
+//! - CLOSE only consumes the recorded CLOSE of the subscription its REQ was
+//!   matched to; anything else is kept in `unmatched()` so tests can assert
+//!   the replay covered the session.
+//!
+//! Replies are delivered in recorded order on a spawned task, never from
+//! inside `send`, so the caller observes the same ordering on every run.
+//! Each reply waits for its recorded delay (`at`) after the frame that
+//! triggered it, so timeouts and batching windows see the original pacing.
+
+use crate::platform::{now_millis, sleep};
+use crate::spawn::spawn_worker;
+use crate::traits::{RelayTransport, TransportError, TransportStatus};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+/// One recorded transport interaction. `at` is ms since the recording started.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RecordedEntry {
+    Connect {
+        at: u64,
+        url: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    Disconnect {
+        at: u64,
+        url: String,
+    },
+    Send {
+        at: u64,
+        url: String,
+        frame: String,
+    },
+    Recv {
+        at: u64,
+        url: String,
+        frame: String,
+    },
+    Status {
+        at: u64,
+        url: String,
+        status: String,
+    },
+}
+
+impl RecordedEntry {
+    pub fn at(&self) -> u64 {
+        match self {
+            RecordedEntry::Connect { at, .. }
+            | RecordedEntry::Disconnect { at, .. }
+            | RecordedEntry::Send { at, .. }
+            | RecordedEntry::Recv { at, .. }
+            | RecordedEntry::Status { at, .. } => *at,
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        match self {
+            RecordedEntry::Connect { url, .. }
+            | RecordedEntry::Disconnect { url, .. }
+            | RecordedEntry::Send { url, .. }
+            | RecordedEntry::Recv { url, .. }
+            | RecordedEntry::Status { url, .. } => url,
+        }
+    }
+}
+
+/// An ordered transport session, stored as JSON lines.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recording {
+    pub entries: Vec<RecordedEntry>,
+}
+
+impl Recording {
+    pub fn to_jsonl(&self) -> String {
+        let mut out = String::new();
+        for entry in &self.entries {
+            if let Ok(line) = serde_json::to_string(entry) {
+                out.push_str(&line);
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    pub fn from_jsonl(text: &str) -> serde_json::Result<Self> {
+        let entries = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<serde_json::Result<Vec<RecordedEntry>>>()?;
+        Ok(Self { entries })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Self::from_jsonl(&text).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_jsonl())
+    }
+}
+
+fn status_name(status: &TransportStatus) -> &'static str {
+    match status {
+        TransportStatus::Connected { .. } => "connected",
+        TransportStatus::Failed { .. } => "failed",
+        TransportStatus::Closed { .. } => "closed",
+    }
+}
+
+/// Shared sink of a `RecordingTransport`; callbacks registered on the inner
+/// transport hold a clone of it.
+struct Recorder {
+    started_at: u64,
+    entries: Mutex<Vec<RecordedEntry>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    file: Option<Mutex<std::fs::File>>,
+}
+
+impl Recorder {
+    fn at(&self) -> u64 {
+        now_millis().saturating_sub(self.started_at)
+    }
+
+    fn push(&self, entry: RecordedEntry) {
+        // Append line by line so a crashed session still leaves a usable file.
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(file) = &self.file {
+            use std::io::Write;
+            if let (Ok(mut file), Ok(line)) = (file.lock(), serde_json::to_string(&entry)) {
+                let _ = writeln!(file, "{}", line);
+            }
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.push(entry);
+        }
+    }
+}
+
+/// `RelayTransport` decorator that records every interaction with `inner`.
+pub struct RecordingTransport {
+    inner: Arc<dyn RelayTransport>,
+    recorder: Arc<Recorder>,
+}
+
+impl RecordingTransport {
+    pub fn new(inner: Arc<dyn RelayTransport>) -> Self {
+        Self {
+            inner,
+            recorder: Arc::new(Recorder {
+                started_at: now_millis(),
+                entries: Mutex::new(Vec::new()),
+                #[cfg(not(target_arch = "wasm32"))]
+                file: None,
+            }),
+        }
+    }
+
+    /// Record into memory and append every entry to `path` as it happens.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn to_file(
+        inner: Arc<dyn RelayTransport>,
+        path: impl AsRef<std::path::Path>,
+    ) -> std::io::Result<Self> {
+        let file = std::fs::File::create(path)?;
+        Ok(Self {
+            inner,
+            recorder: Arc::new(Recorder {
+                started_at: now_millis(),
+                entries: Mutex::new(Vec::new()),
+                file: Some(Mutex::new(file)),
+            }),
+        })
+    }
+
+    /// Snapshot of everything recorded so far.
+    pub fn recording(&self) -> Recording {
+        Recording {
+            entries: self
+                .recorder
+                .entries
+                .lock()
+                .map(|e| e.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl RelayTransport for RecordingTransport {
+    async fn connect(&self, url: &str) -> Result<(), TransportError> {
+        let result = self.inner.connect(url).await;
+        self.recorder.push(RecordedEntry::Connect {
+            at: self.recorder.at(),
+            url: url.to_string(),
+            error: result.as_ref().err().map(|e| e.to_string()),
+        });
+        result
+    }
+
+    fn disconnect(&self, url: &str) {
+        self.recorder.push(RecordedEntry::Disconnect {
+            at: self.recorder.at(),
+            url: url.to_string(),
+        });
+        self.inner.disconnect(url);
+    }
+
+    async fn send(&self, url: &str, frame: String) -> Result<(), TransportError> {
+        self.recorder.push(RecordedEntry::Send {
+            at: self.recorder.at(),
+            url: url.to_string(),
+            frame: frame.clone(),
+        });
+        self.inner.send(url, frame).await
+    }
+
+    fn on_message(&self, url: &str, callback: Box<dyn Fn(String)>) {
+        let recorder = self.recorder.clone();
+        let relay = url.to_string();
+        self.inner.on_message(
+            url,
+            Box::new(move |frame: String| {
+                recorder.push(RecordedEntry::Recv {
+                    at: recorder.at(),
+                    url: relay.clone(),
+                    frame: frame.clone(),
+                });
+                callback(frame);
+            }),
+        );
+    }
+
+    fn on_status(&self, url: &str, callback: Box<dyn Fn(TransportStatus)>) {
+        let recorder = self.recorder.clone();
+        let relay = url.to_string();
+        self.inner.on_status(
+            url,
+            Box::new(move |status: TransportStatus| {
+                recorder.push(RecordedEntry::Status {
+                    at: recorder.at(),
+                    url: relay.clone(),
+                    status: status_name(&status).to_string(),
+                });
+                callback(status);
+            }),
+        );
+    }
+}
+
+/// Canonical form of a JSON value: object keys sorted, scalar arrays sorted
+/// and deduplicated, `since`/`until` dropped.
+fn normalize_value(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let sorted: BTreeMap<&String, Value> = map
+                .iter()
+                .filter(|(k, _)| k.as_str() != "since" && k.as_str() != "until")
+                .map(|(k, v)| (k, normalize_value(v)))
+                .collect();
+            Value::Object(sorted.into_iter().map(|(k, v)| (k.clone(), v)).collect())
+        }
+        Value::Array(items) => {
+            let mut items: Vec<Value> = items.iter().map(normalize_value).collect();
+            if items.iter().all(|v| !v.is_object() && !v.is_array()) {
+                items.sort_by_key(|v| v.to_string());
+                items.dedup();
+            }
+            Value::Array(items)
+        }
+        other => other.clone(),
+    }
+}
+
+/// Normalized filter list of a REQ/COUNT frame, order-insensitive.
+fn normalized_filters(frame: &[Value]) -> Vec<String> {
+    let mut filters: Vec<String> = frame
+        .iter()
+        .skip(2)
+        .map(|f| normalize_value(f).to_string())
+        .collect();
+    filters.sort();
+    filters
+}
+
+fn parse_frame(frame: &str) -> Option<Vec<Value>> {
+    match serde_json::from_str::<Value>(frame).ok()? {
+        Value::Array(items) => Some(items),
+        _ => None,
+    }
+}
+
+fn frame_verb(frame: &[Value]) -> &str {
+    frame.first().and_then(Value::as_str).unwrap_or("")
+}
+
+/// Second element of a frame: the subscription id (REQ, EVENT from relay,
+/// EOSE, CLOSED, COUNT) or the event id (OK).
+fn frame_key(frame: &[Value]) -> Option<&str> {
+    frame.get(1).and_then(Value::as_str)
+}
+
+/// Event id of an outgoing EVENT/AUTH frame.
+fn sent_event_id(frame: &[Value]) -> Option<&str> {
+    frame.get(1)?.get("id")?.as_str()
+}
+
+/// Replace the second element of a relay frame (`from`) with `to`, leaving
+/// the rest of the frame text untouched.
+fn rewrite_key(frame: &str, from: &str, to: &str) -> String {
+    let (Ok(from), Ok(to)) = (serde_json::to_string(from), serde_json::to_string(to)) else {
+        return frame.to_string();
+    };
+    if let Some(comma) = frame.find(',') {
+        let rest = &frame[comma + 1..];
+        let trimmed = rest.trim_start();
+        if trimmed.starts_with(&from) {
+            let start = comma + 1 + (rest.len() - trimmed.len());
+            return format!("{}{}{}", &frame[..start], to, &frame[start + from.len()..]);
+        }
+    }
+    frame.to_string()
+}
+
+type MessageCallbacks = HashMap<String, Rc<dyn Fn(String)>>;
+type StatusCallbacks = HashMap<String, Rc<dyn Fn(TransportStatus)>>;
+
+#[derive(Default)]
+struct ReplayState {
+    /// Recorded entries already matched to a live connect or send.
+    consumed: Vec<bool>,
+    /// Live frames nothing in the recording answered.
+    unmatched: Vec<(String, String)>,
+    /// (url, live sub id) -> recorded sub id of the REQ/COUNT it was matched to.
+    subs: HashMap<(String, String), String>,
+}
+
+/// `RelayTransport` that answers from a `Recording` instead of the network.
+pub struct ReplayTransport {
+    entries: Vec<RecordedEntry>,
+    state: Mutex<ReplayState>,
+    message_callbacks: Rc<RefCell<MessageCallbacks>>,
+    status_callbacks: RefCell<StatusCallbacks>,
+}
+
+impl ReplayTransport {
+    pub fn new(recording: Recording) -> Self {
+        let consumed = vec![false; recording.entries.len()];
+        Self {
+            entries: recording.entries,
+            state: Mutex::new(ReplayState {
+                consumed,
+                ..Default::default()
+            }),
+            message_callbacks: Rc::new(RefCell::new(HashMap::new())),
+            status_callbacks: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Live frames (url, frame) that had no counterpart in the recording.
+    pub fn unmatched(&self) -> Vec<(String, String)> {
+        self.state
+            .lock()
+            .map(|s| s.unmatched.clone())
+            .unwrap_or_default()
+    }
+
+    /// Recorded frames that were never sent during the replay.
+    pub fn unreplayed_sends(&self) -> Vec<(String, String)> {
+        let Ok(state) = self.state.lock() else {
+            return Vec::new();
+        };
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !state.consumed[*i])
+            .filter_map(|(_, entry)| match entry {
+                RecordedEntry::Send { url, frame, .. } => Some((url.clone(), frame.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Deliver a transport status to the callback registered for `url`, e.g.
+    /// to replay a recorded disconnect at a chosen point of a test.
+    pub fn emit_status(&self, url: &str, status: TransportStatus) {
+        let callback = self.status_callbacks.borrow().get(url).cloned();
+        if let Some(callback) = callback {
+            callback(status);
+        }
+    }
+
+    /// Deliver `frames` (recorded `at`, frame) to `url`, each one after its
+    /// recorded delay relative to entry `from`.
+    fn deliver(&self, url: &str, from: usize, frames: Vec<(u64, String)>) {
+        if frames.is_empty() {
+            return;
+        }
+        let callbacks = self.message_callbacks.clone();
+        let url = url.to_string();
+        let mut last = self.entries[from].at();
+        spawn_worker(async move {
+            for (at, frame) in frames {
+                let delay = at.saturating_sub(last);
+                last = last.max(at);
+                if delay > 0 {
+                    sleep(delay).await;
+                }
+                let callback = callbacks.borrow().get(&url).cloned();
+                if let Some(callback) = callback {
+                    callback(frame);
+                }
+            }
+        });
+    }
+
+    /// Recorded frames received on `url` after entry `from`, up to (not
+    /// including) the next recorded send or connect on that relay.
+    fn unsolicited_after(&self, url: &str, from: usize) -> Vec<(u64, String)> {
+        self.entries[from + 1..]
+            .iter()
+            .filter(|e| e.url() == url)
+            .take_while(|e| matches!(e, RecordedEntry::Recv { .. } | RecordedEntry::Status { .. }))
+            .filter_map(|e| match e {
+                RecordedEntry::Recv { at, frame, .. } => Some((*at, frame.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Recorded replies for subscription `recorded_sub` after entry `from`,
+    /// rewritten to `live_sub`. Stops where the recorded id is reused.
+    fn sub_replies(
+        &self,
+        url: &str,
+        from: usize,
+        recorded_sub: &str,
+        live_sub: &str,
+    ) -> Vec<(u64, String)> {
+        let mut replies = Vec::new();
+        for entry in self.entries[from + 1..].iter().filter(|e| e.url() == url) {
+            match entry {
+                RecordedEntry::Recv { at, frame, .. } => {
+                    let Some(parsed) = parse_frame(frame) else {
+                        continue;
+                    };
+                    if frame_verb(&parsed) != "OK" && frame_key(&parsed) == Some(recorded_sub) {
+                        replies.push((*at, rewrite_key(frame, recorded_sub, live_sub)));
+                    }
+                }
+                RecordedEntry::Send { frame, .. } => {
+                    let reused = parse_frame(frame).is_some_and(|parsed| {
+                        matches!(frame_verb(&parsed), "REQ" | "COUNT" | "CLOSE")
+                            && frame_key(&parsed) == Some(recorded_sub)
+                    });
+                    if reused {
+                        break;
+                    }
+                }
+                RecordedEntry::Connect { .. } => break,
+                _ => {}
+            }
+        }
+        replies
+    }
+
+    /// First recorded `OK` for `recorded_id` after entry `from`, rewritten to
+    /// `live_id`.
+    fn ok_reply(
+        &self,
+        url: &str,
+        from: usize,
+        recorded_id: &str,
+        live_id: &str,
+    ) -> Vec<(u64, String)> {
+        self.entries[from + 1..]
+            .iter()
+            .filter(|e| e.url() == url)
+            .find_map(|e| match e {
+                RecordedEntry::Recv { at, frame, .. } => {
+                    let parsed = parse_frame(frame)?;
+                    (frame_verb(&parsed) == "OK" && frame_key(&parsed) == Some(recorded_id))
+                        .then(|| (*at, rewrite_key(frame, recorded_id, live_id)))
+                }
+                _ => None,
+            })
+            .into_iter()
+            .collect()
+    }
+
+    /// Index of the first unconsumed recorded send on `url` accepted by `matches`.
+    fn take_send(
+        &self,
+        url: &str,
+        matches: impl Fn(&[Value]) -> bool,
+    ) -> Option<(usize, Vec<Value>)> {
+        let mut state = self.state.lock().ok()?;
+        let (idx, parsed) = self.entries.iter().enumerate().find_map(|(i, e)| match e {
+            RecordedEntry::Send { url: u, frame, .. } if u == url && !state.consumed[i] => {
+                let parsed = parse_frame(frame)?;
+                matches(&parsed).then_some((i, parsed))
+            }
+            _ => None,
+        })?;
+        state.consumed[idx] = true;
+        Some((idx, parsed))
+    }
+
+    fn mark_unmatched(&self, url: &str, frame: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state.unmatched.push((url.to_string(), frame.to_string()));
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl RelayTransport for ReplayTransport {
+    async fn connect(&self, url: &str) -> Result<(), TransportError> {
+        let taken = {
+            let mut state = self
+                .state
+                .lock()
+                .map_err(|_| TransportError::Other("replay state poisoned".to_string()))?;
+            let next = self.entries.iter().enumerate().position(|(i, e)| {
+                matches!(e, RecordedEntry::Connect { url: u, .. } if u == url) && !state.consumed[i]
+            });
+            if let Some(idx) = next {
+                state.consumed[idx] = true;
+            }
+            // Reconnects beyond the recording repeat the last recorded outcome.
+            next.or_else(|| {
+                self.entries
+                    .iter()
+                    .rposition(|e| matches!(e, RecordedEntry::Connect { url: u, .. } if u == url))
+            })
+        };
+        let Some(idx) = taken else {
+            return Err(TransportError::Other(format!(
+                "{} is not in the recording",
+                url
+            )));
+        };
+        if let RecordedEntry::Connect {
+            error: Some(error), ..
+        } = &self.entries[idx]
+        {
+            return Err(TransportError::Other(error.clone()));
+        }
+        self.deliver(url, idx, self.unsolicited_after(url, idx));
+        Ok(())
+    }
+
+    fn disconnect(&self, _url: &str) {}
+
+    async fn send(&self, url: &str, frame: String) -> Result<(), TransportError> {
+        let Some(live) = parse_frame(&frame) else {
+            self.mark_unmatched(url, &frame);
+            return Ok(());
+        };
+        let verb = frame_verb(&live).to_string();
+        let replies = match verb.as_str() {
+            "REQ" | "COUNT" => {
+                let live_sub = frame_key(&live).unwrap_or("").to_string();
+                let filters = normalized_filters(&live);
+                self.take_send(url, |rec| {
+                    frame_verb(rec) == verb && normalized_filters(rec) == filters
+                })
+                .map(|(idx, rec)| {
+                    let recorded_sub = frame_key(&rec).unwrap_or("").to_string();
+                    if let Ok(mut state) = self.state.lock() {
+                        state
+                            .subs
+                            .insert((url.to_string(), live_sub.clone()), recorded_sub.clone());
+                    }
+                    (idx, self.sub_replies(url, idx, &recorded_sub, &live_sub))
+                })
+            }
+            "EVENT" | "AUTH" => {
+                let live_id = sent_event_id(&live).unwrap_or("").to_string();
+                self.take_send(url, |rec| {
+                    frame_verb(rec) == verb && sent_event_id(rec) == Some(&live_id)
+                })
+                .or_else(|| self.take_send(url, |rec| frame_verb(rec) == verb))
+                .map(|(idx, rec)| {
+                    let recorded_id = sent_event_id(&rec).unwrap_or("");
+                    (idx, self.ok_reply(url, idx, recorded_id, &live_id))
+                })
+            }
+            "CLOSE" => {
+                let live_sub = frame_key(&live).unwrap_or("").to_string();
+                let recorded_sub = self
+                    .state
+                    .lock()
+                    .ok()
+                    .and_then(|mut s| s.subs.remove(&(url.to_string(), live_sub.clone())))
+                    .unwrap_or(live_sub);
+                self.take_send(url, |rec| {
+                    frame_verb(rec) == "CLOSE" && frame_key(rec) == Some(recorded_sub.as_str())
+                })
+                .map(|(idx, _)| (idx, Vec::new()))
+            }
+            _ => self
+                .take_send(url, |rec| {
+                    serde_json::to_string(rec).ok() == serde_json::to_string(&live).ok()
+                })
+                .map(|(idx, _)| (idx, Vec::new())),
+        };
+        match replies {
+            Some((idx, replies)) => self.deliver(url, idx, replies),
+            None => self.mark_unmatched(url, &frame),
+        }
+        Ok(())
+    }
+
+    fn on_message(&self, url: &str, callback: Box<dyn Fn(String)>) {
+        self.message_callbacks
+            .borrow_mut()
+            .insert(url.to_string(), Rc::from(callback));
+    }
+
+    fn on_status(&self, url: &str, callback: Box<dyn Fn(TransportStatus)>) {
+        self.status_callbacks
+            .borrow_mut()
+            .insert(url.to_string(), Rc::from(callback));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn send(url: &str, frame: &str) -> RecordedEntry {
+        RecordedEntry::Send {
+            at: 0,
+            url: url.to_string(),
+            frame: frame.to_string(),
+        }
+    }
+
+    fn recv(url: &str, frame: &str) -> RecordedEntry {
+        RecordedEntry::Recv {
+            at: 0,
+            url: url.to_string(),
+            frame: frame.to_string(),
+        }
+    }
+
+    fn connect(url: &str) -> RecordedEntry {
+        RecordedEntry::Connect {
+            at: 0,
+            url: url.to_string(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn jsonl_roundtrip() {
+        let recording = Recording {
+            entries: vec![
+                connect("wss://r"),
+                send("wss://r", r#"["REQ","s",{"kinds":[1]}]"#),
+                recv("wss://r", r#"["EOSE","s"]"#),
+                RecordedEntry::Status {
+                    at: 5,
+                    url: "wss://r".to_string(),
+                    status: "closed".to_string(),
+                },
+            ],
+        };
+        let text = recording.to_jsonl();
+        assert_eq!(text.lines().count(), 4);
+        assert_eq!(Recording::from_jsonl(&text).unwrap(), recording);
+    }
+
+    #[test]
+    fn filters_normalize_order_duplicates_and_time_bounds() {
+        let a =
+            parse_frame(r#"["REQ","a",{"kinds":[1,6,1],"authors":["y","x"],"since":5}]"#).unwrap();
+        let b =
+            parse_frame(r#"["REQ","b",{"authors":["x","y"],"kinds":[6,1],"until":9}]"#).unwrap();
+        let c = parse_frame(r#"["REQ","c",{"authors":["x"],"kinds":[6,1]}]"#).unwrap();
+        assert_eq!(normalized_filters(&a), normalized_filters(&b));
+        assert_ne!(normalized_filters(&a), normalized_filters(&c));
+    }
+
+    #[test]
+    fn rewrite_key_only_touches_the_subscription_id() {
+        assert_eq!(
+            rewrite_key(r#"["EVENT","old",{"content":"old"}]"#, "old", "new"),
+            r#"["EVENT","new",{"content":"old"}]"#
+        );
+        assert_eq!(
+            rewrite_key(r#"["EOSE", "old"]"#, "old", "n"),
+            r#"["EOSE", "n"]"#
+        );
+        assert_eq!(
+            rewrite_key(r#"["NOTICE","hi"]"#, "old", "n"),
+            r#"["NOTICE","hi"]"#
+        );
+    }
+
+    #[tokio::test]
+    async fn replay_serves_recorded_replies_under_the_live_sub_id() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let url = "wss://r";
+                let replay = ReplayTransport::new(Recording {
+                    entries: vec![
+                        connect(url),
+                        recv(url, r#"["AUTH","challenge"]"#),
+                        send(url, r#"["REQ","rec",{"kinds":[1],"since":100}]"#),
+                        recv(url, r#"["EVENT","rec",{"id":"e1"}]"#),
+                        recv(url, r#"["EVENT","other",{"id":"e2"}]"#),
+                        recv(url, r#"["EOSE","rec"]"#),
+                        send(url, r#"["EVENT",{"id":"p1"}]"#),
+                        recv(url, r#"["OK","p1",true,""]"#),
+                    ],
+                });
+                let received = Rc::new(std::cell::RefCell::new(Vec::<String>::new()));
+                let sink = received.clone();
+
+                replay.connect(url).await.unwrap();
+                replay.on_message(url, Box::new(move |f| sink.borrow_mut().push(f)));
+                replay
+                    .send(
+                        url,
+                        r#"["REQ","live",{"kinds":[1],"since":900}]"#.to_string(),
+                    )
+                    .await
+                    .unwrap();
+                replay
+                    .send(url, r#"["EVENT",{"id":"p2"}]"#.to_string())
+                    .await
+                    .unwrap();
+                replay
+                    .send(url, r#"["REQ","x",{"kinds":[7]}]"#.to_string())
+                    .await
+                    .unwrap();
+                tokio::task::yield_now().await;
+
+                assert_eq!(
+                    *received.borrow(),
+                    vec![
+                        r#"["AUTH","challenge"]"#.to_string(),
+                        r#"["EVENT","live",{"id":"e1"}]"#.to_string(),
+                        r#"["EOSE","live"]"#.to_string(),
+                        r#"["OK","p2",true,""]"#.to_string(),
+                    ]
+                );
+                assert_eq!(
+                    replay.unmatched(),
+                    vec![(url.to_string(), r#"["REQ","x",{"kinds":[7]}]"#.to_string())]
+                );
+                assert!(replay.unreplayed_sends().is_empty());
+                assert!(replay.connect("wss://unknown").await.is_err());
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn close_consumes_only_the_close_of_its_own_subscription() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let url = "wss://r";
+                let replay = ReplayTransport::new(Recording {
+                    entries: vec![
+                        connect(url),
+                        send(url, r#"["REQ","a",{"kinds":[1]}]"#),
+                        send(url, r#"["REQ","b",{"kinds":[7]}]"#),
+                        send(url, r#"["CLOSE","b"]"#),
+                        send(url, r#"["CLOSE","a"]"#),
+                    ],
+                });
+                replay.connect(url).await.unwrap();
+                for frame in [
+                    r#"["REQ","live-a",{"kinds":[1]}]"#,
+                    r#"["REQ","live-b",{"kinds":[7]}]"#,
+                    r#"["CLOSE","live-a"]"#,
+                    r#"["CLOSE","stray"]"#,
+                ] {
+                    replay.send(url, frame.to_string()).await.unwrap();
+                }
+
+                assert_eq!(
+                    replay.unreplayed_sends(),
+                    vec![(url.to_string(), r#"["CLOSE","b"]"#.to_string())]
+                );
+                assert_eq!(
+                    replay.unmatched(),
+                    vec![(url.to_string(), r#"["CLOSE","stray"]"#.to_string())]
+                );
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn replies_keep_their_recorded_delay() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let url = "wss://r";
+                let at = |at: u64, entry: RecordedEntry| match entry {
+                    RecordedEntry::Send { url, frame, .. } => {
+                        RecordedEntry::Send { at, url, frame }
+                    }
+                    RecordedEntry::Recv { url, frame, .. } => {
+                        RecordedEntry::Recv { at, url, frame }
+                    }
+                    other => other,
+                };
+                let replay = ReplayTransport::new(Recording {
+                    entries: vec![
+                        connect(url),
+                        at(100, send(url, r#"["REQ","s",{"kinds":[1]}]"#)),
+                        at(120, recv(url, r#"["EVENT","s",{"id":"e1"}]"#)),
+                        at(400, recv(url, r#"["EOSE","s"]"#)),
+                    ],
+                });
+                let received = Rc::new(std::cell::RefCell::new(Vec::<String>::new()));
+                let sink = received.clone();
+                replay.connect(url).await.unwrap();
+                replay.on_message(url, Box::new(move |f| sink.borrow_mut().push(f)));
+                replay
+                    .send(url, r#"["REQ","s",{"kinds":[1]}]"#.to_string())
+                    .await
+                    .unwrap();
+
+                tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+                assert_eq!(
+                    *received.borrow(),
+                    vec![r#"["EVENT","s",{"id":"e1"}]"#.to_string()]
+                );
+                tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+                assert_eq!(received.borrow().len(), 2);
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn replay_repeats_recorded_connect_failures() {
+        let replay = ReplayTransport::new(Recording {
+            entries: vec![RecordedEntry::Connect {
+                at: 0,
+                url: "wss://down".to_string(),
+                error: Some("refused".to_string()),
+            }],
+        });
+        assert!(replay.connect("wss://down").await.is_err());
+        assert!(replay.connect("wss://down").await.is_err());
+    }
+}
@@ -140,6 +140,8 @@ pub struct RelayConfig {
     pub max_reconnect_attempts: usize,
     /// Delay between reconnection attempts
     pub reconnect_delay: std::time::Duration,
+    /// SOCKS5 proxy for relay sockets
+    pub proxy: Option<crate::proxy::ProxyConfig>,
 }
 
 impl Default for RelayConfig {
@@ -149,6 +151,7 @@ impl Default for RelayConfig {
             keepalive_timeout: std::time::Duration::from_secs(60),
             max_reconnect_attempts: 3,
             reconnect_delay: std::time::Duration::from_secs(2),
+            proxy: None,
         }
     }
 }
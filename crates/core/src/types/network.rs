@@ -33,6 +33,10 @@ pub struct Request {
     pub cache_only: bool,
 
     pub mesh_only: bool,
+
+    pub negentropy: bool,
+
+    pub background: bool,
 }
 
 impl Request {
@@ -95,6 +99,8 @@ impl Request {
             max_relays: fb_req.max_relays() as u32,
             cache_only: fb_req.cache_only(),
             mesh_only: fb_req.mesh_only(),
+            negentropy: fb_req.negentropy(),
+            background: fb_req.background(),
         }
     }
 
@@ -178,6 +184,8 @@ impl Request {
                 max_relays: self.max_relays as u16,
                 cache_only: self.cache_only,
                 mesh_only: self.mesh_only,
+                negentropy: self.negentropy,
+                background: self.background,
             },
         )
     }
@@ -73,6 +73,16 @@ impl SecretKey {
         Ok(SecretKey(arr))
     }
 
+    /// Accepts 64 hex characters or a bech32 `nsec1...` string.
+    pub fn parse(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("nsec1")) {
+            nips::nip19::FromBech32::from_bech32(s)
+        } else {
+            Self::from_hex(s)
+        }
+    }
+
     pub fn to_hex(&self) -> String {
         hex::encode(self.0)
     }
@@ -98,17 +108,9 @@ impl Keys {
         }
     }
 
+    /// Accepts a hex secret key or a bech32 `nsec`.
     pub fn parse(nsec: &str) -> Result<Self> {
-        // Check if it starts with "nsec1" for bech32 format
-        if nsec.starts_with("nsec1") {
-            // For now, return error since bech32 is not implemented
-            return Err(TypesError::InvalidFormat(
-                "Bech32 nsec parsing not implemented".to_string(),
-            ));
-        }
-
-        // Otherwise treat it as hex
-        let _secret_key = SecretKey::from_hex(nsec)?;
+        let _secret_key = SecretKey::parse(nsec)?;
         #[cfg(feature = "crypto")]
         {
             Ok(Self::new(_secret_key))
@@ -911,13 +913,15 @@ pub fn timestamp_now() -> Timestamp {
 }
 
 // ============================================================================
-// NIP-19 (Bech32) - Stubbed out since bech32 is not in dependencies
+// NIP-19 (Bech32)
 // ============================================================================
 
 pub mod nips {
     pub mod nip19 {
         use super::super::*;
 
+        const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
         pub enum Nip19 {
             Pubkey(PublicKey),
             EventId(EventId),
@@ -934,6 +938,7 @@ pub mod nips {
         pub struct Nip19Event {
             pub event_id: EventId,
             pub author: Option<PublicKey>,
+            pub kind: Option<u16>,
             pub relays: Vec<String>,
         }
 
@@ -950,179 +955,339 @@ pub mod nips {
                 Self: Sized;
         }
 
-        impl FromBech32 for Nip19 {
-            fn from_bech32(s: &str) -> Result<Self> {
-                // Bech32 character set
-                const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+        pub trait ToBech32 {
+            fn to_bech32(&self) -> Result<String>;
+        }
 
-                // Convert bech32 character to value
-                fn decode_char(c: u8) -> Option<u8> {
-                    CHARSET.iter().position(|&x| x == c).map(|p| p as u8)
+        fn polymod(values: impl IntoIterator<Item = u8>) -> u32 {
+            const GENERATORS: [u32; 5] = [
+                0x3b6a_57b2,
+                0x2650_8e6d,
+                0x1ea1_19fa,
+                0x3d42_33dd,
+                0x2a14_62b3,
+            ];
+            let mut checksum = 1u32;
+            for value in values {
+                let top = checksum >> 25;
+                checksum = ((checksum & 0x01ff_ffff) << 5) ^ u32::from(value);
+                for (bit, generator) in GENERATORS.iter().enumerate() {
+                    if (top >> bit) & 1 != 0 {
+                        checksum ^= generator;
+                    }
                 }
+            }
+            checksum
+        }
 
-                // Convert between bit groups
-                fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32) -> Option<Vec<u8>> {
-                    let mut acc = 0u32;
-                    let mut bits = 0u32;
-                    let mut ret = Vec::new();
-                    let maxv = (1 << to_bits) - 1;
+        fn hrp_expand(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+            hrp.bytes()
+                .map(|b| b >> 5)
+                .chain(std::iter::once(0))
+                .chain(hrp.bytes().map(|b| b & 0x1f))
+        }
 
-                    for &value in data {
-                        if (value as u32) >> from_bits != 0 {
-                            return None;
-                        }
-                        acc = (acc << from_bits) | (value as u32);
-                        bits += from_bits;
+        /// Regroups `data` from `from_bits`-bit to `to_bits`-bit values.
+        pub fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> Option<Vec<u8>> {
+            let mut acc = 0u32;
+            let mut bits = 0u32;
+            let mut ret = Vec::new();
+            let maxv = (1 << to_bits) - 1;
 
-                        while bits >= to_bits {
-                            bits -= to_bits;
-                            ret.push(((acc >> bits) & maxv) as u8);
-                        }
-                    }
+            for &value in data {
+                if (value as u32) >> from_bits != 0 {
+                    return None;
+                }
+                acc = (acc << from_bits) | (value as u32);
+                bits += from_bits;
 
-                    if bits >= from_bits || ((acc << (to_bits - bits)) & maxv) != 0 {
-                        return None;
-                    }
+                while bits >= to_bits {
+                    bits -= to_bits;
+                    ret.push(((acc >> bits) & maxv) as u8);
+                }
+            }
 
-                    Some(ret)
+            if pad {
+                if bits > 0 {
+                    ret.push(((acc << (to_bits - bits)) & maxv) as u8);
                 }
+            } else if bits >= from_bits || ((acc << (to_bits - bits)) & maxv) != 0 {
+                return None;
+            }
 
-                // Parse TLV data for nprofile and nevent
-                fn parse_tlv(
-                    mut data: Vec<u8>,
-                ) -> Result<(
-                    Option<Vec<u8>>,  // Special field (can be pubkey, event_id, or identifier)
-                    Option<[u8; 32]>, // Author field
-                    Option<u16>,      // Kind field
-                    Vec<String>,      // Relays
-                )> {
-                    let mut special = None;
-                    let mut author = None;
-                    let mut kind = None;
-                    let mut relays = Vec::new();
-
-                    while !data.is_empty() {
-                        if data.len() < 2 {
-                            break;
-                        }
+            Some(ret)
+        }
 
-                        let t = data[0];
-                        let l = data[1] as usize;
+        /// Encodes `bytes` as bech32 (BIP-173) under `hrp`. NIP-19 strings
+        /// may exceed the BIP-173 90-character limit, so none is applied.
+        pub fn encode(hrp: &str, bytes: &[u8]) -> Result<String> {
+            let data = convert_bits(bytes, 8, 5, true)
+                .ok_or_else(|| TypesError::InvalidFormat("Failed to convert bits".to_string()))?;
+            Ok(encode_words(hrp, &data))
+        }
 
-                        if data.len() < 2 + l {
-                            return Err(TypesError::InvalidFormat("Invalid TLV data".to_string()));
-                        }
+        /// Encodes raw 5-bit words as bech32 under `hrp`; the inverse of
+        /// `decode_words`.
+        pub fn encode_words(hrp: &str, data: &[u8]) -> String {
+            let checksum = polymod(hrp_expand(hrp).chain(data.iter().copied()).chain([0u8; 6])) ^ 1;
 
-                        let value = &data[2..2 + l];
+            let mut out = String::with_capacity(hrp.len() + 1 + data.len() + 6);
+            out.push_str(hrp);
+            out.push('1');
+            for value in data {
+                out.push(CHARSET[*value as usize] as char);
+            }
+            for i in 0..6 {
+                out.push(CHARSET[((checksum >> (5 * (5 - i))) & 0x1f) as usize] as char);
+            }
+            out
+        }
 
-                        match t {
-                            0 => {
-                                // Special field (can be 32 bytes or variable length for identifier)
-                                if special.is_none() {
-                                    special = Some(value.to_vec());
-                                }
-                            }
-                            1 => {
-                                // Relay
-                                if let Ok(relay) = String::from_utf8(value.to_vec()) {
-                                    relays.push(relay);
-                                }
-                            }
-                            2 => {
-                                // Author
-                                if l == 32 && author.is_none() {
-                                    let mut arr = [0u8; 32];
-                                    arr.copy_from_slice(value);
-                                    author = Some(arr);
-                                }
-                            }
-                            3 => {
-                                // Kind
-                                if l == 4 && kind.is_none() {
-                                    let bytes: [u8; 4] = value.try_into().map_err(|_| {
-                                        TypesError::InvalidFormat("Invalid kind".to_string())
-                                    })?;
-                                    kind = Some(u32::from_be_bytes(bytes) as u16);
-                                }
-                            }
-                            _ => {} // Skip unknown TLV types
-                        }
+        /// Decodes a bech32 string into its (lowercase) HRP and payload bytes,
+        /// verifying the checksum.
+        pub fn decode(s: &str) -> Result<(String, Vec<u8>)> {
+            let (hrp, data) = decode_words(s)?;
 
-                        data.drain(..2 + l);
-                    }
+            // Convert 5-bit groups to 8-bit bytes
+            let bytes = convert_bits(&data, 5, 8, false)
+                .ok_or_else(|| TypesError::InvalidFormat("Failed to convert bits".to_string()))?;
 
-                    Ok((special, author, kind, relays))
-                }
+            Ok((hrp, bytes))
+        }
 
-                // Convert to lowercase
-                let s = s.to_lowercase();
+        /// Decodes a bech32 string into its (lowercase) HRP and raw 5-bit
+        /// words, checksum removed. For formats such as BOLT11 whose fields
+        /// are not byte-aligned.
+        pub fn decode_words(s: &str) -> Result<(String, Vec<u8>)> {
+            if s.bytes().any(|b| b.is_ascii_lowercase())
+                && s.bytes().any(|b| b.is_ascii_uppercase())
+            {
+                return Err(TypesError::InvalidFormat(
+                    "Mixed-case bech32 string".to_string(),
+                ));
+            }
 
-                // Find separator '1'
-                let sep_pos = s.rfind('1').ok_or_else(|| {
-                    TypesError::InvalidFormat("Missing separator '1'".to_string())
-                })?;
+            // Convert to lowercase
+            let s = s.to_lowercase();
 
-                if sep_pos == 0 || sep_pos == s.len() - 1 {
-                    return Err(TypesError::InvalidFormat(
-                        "Invalid bech32 format".to_string(),
-                    ));
-                }
+            // Find separator '1'
+            let sep_pos = s
+                .rfind('1')
+                .ok_or_else(|| TypesError::InvalidFormat("Missing separator '1'".to_string()))?;
 
-                // Split HRP and data
-                let hrp = &s[..sep_pos];
-                let data_str = &s[sep_pos + 1..];
-
-                // Decode data characters
-                let mut data = Vec::new();
-                for c in data_str.bytes() {
-                    match decode_char(c) {
-                        Some(val) => data.push(val),
-                        None => {
-                            return Err(TypesError::InvalidFormat(format!(
-                                "Invalid bech32 character: {}",
-                                c as char
-                            )))
-                        }
+            if sep_pos == 0 || sep_pos == s.len() - 1 {
+                return Err(TypesError::InvalidFormat(
+                    "Invalid bech32 format".to_string(),
+                ));
+            }
+
+            // Split HRP and data
+            let hrp = &s[..sep_pos];
+            let data_str = &s[sep_pos + 1..];
+
+            // Decode data characters
+            let mut data = Vec::with_capacity(data_str.len());
+            for c in data_str.bytes() {
+                match CHARSET.iter().position(|&x| x == c) {
+                    Some(val) => data.push(val as u8),
+                    None => {
+                        return Err(TypesError::InvalidFormat(format!(
+                            "Invalid bech32 character: {}",
+                            c as char
+                        )))
                     }
                 }
+            }
+
+            // Verify minimum length for checksum
+            if data.len() < 6 {
+                return Err(TypesError::InvalidFormat("Data too short".to_string()));
+            }
+            if polymod(hrp_expand(hrp).chain(data.iter().copied())) != 1 {
+                return Err(TypesError::InvalidFormat(
+                    "Invalid bech32 checksum".to_string(),
+                ));
+            }
+            data.truncate(data.len() - 6);
+
+            Ok((hrp.to_string(), data))
+        }
+
+        fn bytes_32(bytes: Vec<u8>, hrp: &str) -> Result<[u8; 32]> {
+            bytes.try_into().map_err(|bytes: Vec<u8>| {
+                TypesError::InvalidFormat(format!("Invalid {} length: {}", hrp, bytes.len()))
+            })
+        }
+
+        fn push_tlv(out: &mut Vec<u8>, t: u8, value: &[u8]) -> Result<()> {
+            let len = u8::try_from(value.len())
+                .map_err(|_| TypesError::InvalidFormat("TLV value too long".to_string()))?;
+            out.push(t);
+            out.push(len);
+            out.extend_from_slice(value);
+            Ok(())
+        }
+
+        fn push_relays(out: &mut Vec<u8>, relays: &[String]) -> Result<()> {
+            for relay in relays {
+                push_tlv(out, 1, relay.as_bytes())?;
+            }
+            Ok(())
+        }
 
-                // Verify minimum length for checksum
-                if data.len() < 6 {
-                    return Err(TypesError::InvalidFormat("Data too short".to_string()));
+        // Parse TLV data for nprofile, nevent and naddr
+        fn parse_tlv(
+            mut data: Vec<u8>,
+        ) -> Result<(
+            Option<Vec<u8>>,  // Special field (can be pubkey, event_id, or identifier)
+            Option<[u8; 32]>, // Author field
+            Option<u16>,      // Kind field
+            Vec<String>,      // Relays
+        )> {
+            let mut special = None;
+            let mut author = None;
+            let mut kind = None;
+            let mut relays = Vec::new();
+
+            while !data.is_empty() {
+                if data.len() < 2 {
+                    break;
                 }
 
-                // Remove checksum (last 6 characters) - simplified, not verifying
-                data.truncate(data.len() - 6);
+                let t = data[0];
+                let l = data[1] as usize;
 
-                // Convert 5-bit groups to 8-bit bytes
-                let bytes = convert_bits(&data, 5, 8).ok_or_else(|| {
-                    TypesError::InvalidFormat("Failed to convert bits".to_string())
-                })?;
+                if data.len() < 2 + l {
+                    return Err(TypesError::InvalidFormat("Invalid TLV data".to_string()));
+                }
 
-                // Parse based on HRP
-                match hrp {
-                    "npub" => {
-                        if bytes.len() != 32 {
-                            return Err(TypesError::InvalidFormat(format!(
-                                "Invalid npub length: {}",
-                                bytes.len()
-                            )));
+                let value = &data[2..2 + l];
+
+                match t {
+                    0 => {
+                        // Special field (can be 32 bytes or variable length for identifier)
+                        if special.is_none() {
+                            special = Some(value.to_vec());
+                        }
+                    }
+                    1 => {
+                        // Relay
+                        if let Ok(relay) = String::from_utf8(value.to_vec()) {
+                            relays.push(relay);
                         }
-                        let mut arr = [0u8; 32];
-                        arr.copy_from_slice(&bytes);
-                        Ok(Nip19::Pubkey(PublicKey(arr)))
                     }
-                    "note" => {
-                        if bytes.len() != 32 {
-                            return Err(TypesError::InvalidFormat(format!(
-                                "Invalid note length: {}",
-                                bytes.len()
-                            )));
+                    2 => {
+                        // Author
+                        if l == 32 && author.is_none() {
+                            let mut arr = [0u8; 32];
+                            arr.copy_from_slice(value);
+                            author = Some(arr);
                         }
-                        let mut arr = [0u8; 32];
-                        arr.copy_from_slice(&bytes);
-                        Ok(Nip19::EventId(EventId(arr)))
                     }
+                    3 => {
+                        // Kind
+                        if l == 4 && kind.is_none() {
+                            let bytes: [u8; 4] = value.try_into().map_err(|_| {
+                                TypesError::InvalidFormat("Invalid kind".to_string())
+                            })?;
+                            kind = Some(u32::from_be_bytes(bytes) as u16);
+                        }
+                    }
+                    _ => {} // Skip unknown TLV types
+                }
+
+                data.drain(..2 + l);
+            }
+
+            Ok((special, author, kind, relays))
+        }
+
+        impl FromBech32 for SecretKey {
+            fn from_bech32(s: &str) -> Result<Self> {
+                let (hrp, bytes) = decode(s)?;
+                if hrp != "nsec" {
+                    return Err(TypesError::InvalidFormat(format!(
+                        "Expected nsec but got {}",
+                        hrp
+                    )));
+                }
+                bytes_32(bytes, "nsec").map(SecretKey)
+            }
+        }
+
+        impl ToBech32 for SecretKey {
+            fn to_bech32(&self) -> Result<String> {
+                encode("nsec", &self.0)
+            }
+        }
+
+        impl ToBech32 for PublicKey {
+            fn to_bech32(&self) -> Result<String> {
+                encode("npub", &self.0)
+            }
+        }
+
+        impl ToBech32 for EventId {
+            fn to_bech32(&self) -> Result<String> {
+                encode("note", &self.0)
+            }
+        }
+
+        impl ToBech32 for Nip19Profile {
+            fn to_bech32(&self) -> Result<String> {
+                let mut tlv = Vec::new();
+                push_tlv(&mut tlv, 0, &self.public_key.0)?;
+                push_relays(&mut tlv, &self.relays)?;
+                encode("nprofile", &tlv)
+            }
+        }
+
+        impl ToBech32 for Nip19Event {
+            fn to_bech32(&self) -> Result<String> {
+                let mut tlv = Vec::new();
+                push_tlv(&mut tlv, 0, &self.event_id.0)?;
+                push_relays(&mut tlv, &self.relays)?;
+                if let Some(author) = &self.author {
+                    push_tlv(&mut tlv, 2, &author.0)?;
+                }
+                if let Some(kind) = self.kind {
+                    push_tlv(&mut tlv, 3, &u32::from(kind).to_be_bytes())?;
+                }
+                encode("nevent", &tlv)
+            }
+        }
+
+        impl ToBech32 for Nip19Coordinate {
+            fn to_bech32(&self) -> Result<String> {
+                let mut tlv = Vec::new();
+                push_tlv(&mut tlv, 0, self.identifier.as_bytes())?;
+                push_relays(&mut tlv, &self.relays)?;
+                push_tlv(&mut tlv, 2, &self.public_key.0)?;
+                push_tlv(&mut tlv, 3, &u32::from(self.kind).to_be_bytes())?;
+                encode("naddr", &tlv)
+            }
+        }
+
+        impl ToBech32 for Nip19 {
+            fn to_bech32(&self) -> Result<String> {
+                match self {
+                    Nip19::Pubkey(public_key) => public_key.to_bech32(),
+                    Nip19::EventId(event_id) => event_id.to_bech32(),
+                    Nip19::Profile(profile) => profile.to_bech32(),
+                    Nip19::Event(event) => event.to_bech32(),
+                    Nip19::Coordinate(coordinate) => coordinate.to_bech32(),
+                }
+            }
+        }
+
+        impl FromBech32 for Nip19 {
+            fn from_bech32(s: &str) -> Result<Self> {
+                let (hrp, bytes) = decode(s)?;
+
+                // Parse based on HRP
+                match hrp.as_str() {
+                    "npub" => Ok(Nip19::Pubkey(PublicKey(bytes_32(bytes, "npub")?))),
+                    "note" => Ok(Nip19::EventId(EventId(bytes_32(bytes, "note")?))),
                     "nprofile" => {
                         let (special, _, _, relays) = parse_tlv(bytes)?;
 
@@ -1145,7 +1310,7 @@ pub mod nips {
                         }))
                     }
                     "nevent" => {
-                        let (special, author, _kind, relays) = parse_tlv(bytes)?;
+                        let (special, author, kind, relays) = parse_tlv(bytes)?;
 
                         let special_bytes = special.ok_or_else(|| {
                             TypesError::InvalidFormat("Missing event ID in nevent".to_string())
@@ -1163,6 +1328,7 @@ pub mod nips {
                         Ok(Nip19::Event(Nip19Event {
                             event_id: EventId(event_id),
                             author: author.map(PublicKey),
+                            kind,
                             relays,
                         }))
                     }
@@ -1403,3 +1569,86 @@ impl NostrTags {
 
 // Re-export for compatibility
 pub use nips::*;
+
+#[cfg(test)]
+mod tests {
+    use super::nips::nip19::*;
+    use super::*;
+
+    // Test vectors from NIP-19.
+    const NPUB: &str = "npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg";
+    const NPUB_HEX: &str = "7e7e9c42a91bfef19fa929e5fda1b72e0ebc1a4c1141673e2794234d86addf4e";
+    const NSEC: &str = "nsec1vl029mgpspedva04g90vltkh6fvh240zqtv9k0t9af8935ke9laqsnlfe5";
+    const NSEC_HEX: &str = "67dea2ed018072d675f5415ecfaed7d2597555e202d85b3d65ea4e58d2d92ffa";
+    const NPROFILE: &str = "nprofile1qqsrhuxx8l9ex335q7he0f09aej04zpazpl0ne2cgukyawd24mayt8gpp4mhxue69uhhytnc9e3k7mgpz4mhxue69uhkg6nzv9ejuumpv34kytnrdaksjlyr9p";
+
+    #[test]
+    fn encodes_nip19_test_vectors() {
+        assert_eq!(
+            PublicKey::from_hex(NPUB_HEX).unwrap().to_bech32().unwrap(),
+            NPUB
+        );
+        assert_eq!(
+            SecretKey::from_hex(NSEC_HEX).unwrap().to_bech32().unwrap(),
+            NSEC
+        );
+        assert_eq!(SecretKey::parse(NSEC).unwrap().to_hex(), NSEC_HEX);
+
+        let profile = Nip19Profile {
+            public_key: PublicKey::from_hex(
+                "3bf0c63fcb93463407af97a5e5ee64fa883d107ef9e558472c4eb9aaaefa459d",
+            )
+            .unwrap(),
+            relays: vec![
+                "wss://r.x.com".to_string(),
+                "wss://djbas.sadkb.com".to_string(),
+            ],
+        };
+        assert_eq!(profile.to_bech32().unwrap(), NPROFILE);
+    }
+
+    #[test]
+    fn tlv_entities_round_trip() {
+        let author = PublicKey::from_hex(NPUB_HEX).unwrap();
+        let naddr = Nip19Coordinate {
+            identifier: "my-article".to_string(),
+            public_key: author.clone(),
+            kind: 30023,
+            relays: vec!["wss://relay.example".to_string()],
+        }
+        .to_bech32()
+        .unwrap();
+        assert!(naddr.starts_with("naddr1"));
+        let Ok(Nip19::Coordinate(coordinate)) = Nip19::from_bech32(&naddr) else {
+            panic!("expected naddr");
+        };
+        assert_eq!(coordinate.identifier, "my-article");
+        assert_eq!(coordinate.kind, 30023);
+        assert_eq!(coordinate.public_key, author);
+        assert_eq!(coordinate.relays, vec!["wss://relay.example".to_string()]);
+
+        let nevent = Nip19Event {
+            event_id: EventId([7; 32]),
+            author: Some(author.clone()),
+            kind: Some(1),
+            relays: Vec::new(),
+        }
+        .to_bech32()
+        .unwrap();
+        let Ok(Nip19::Event(event)) = Nip19::from_bech32(&nevent) else {
+            panic!("expected nevent");
+        };
+        assert_eq!(event.event_id, EventId([7; 32]));
+        assert_eq!(event.author, Some(author));
+        assert_eq!(event.kind, Some(1));
+    }
+
+    #[test]
+    fn rejects_bad_checksums() {
+        let mut corrupted = NPUB.to_string();
+        corrupted.pop();
+        corrupted.push('q');
+        assert!(Nip19::from_bech32(&corrupted).is_err());
+        assert!(SecretKey::parse(NPUB).is_err());
+    }
+}
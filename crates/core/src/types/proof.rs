@@ -6,7 +6,7 @@ use crate::{generated::nostr::fb, types::ParserError, utils::BaseJsonParser};
 type Result<T> = std::result::Result<T, ParserError>;
 
 /// DLEQ (Discrete Log Equality) proof for offline signature validation (NUT-12)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DleqProof {
     pub e: String,         // Challenge
     pub s: String,         // Response
@@ -77,14 +77,14 @@ impl DleqProof {
 }
 
 /// P2PK witness
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct P2PKWitness {
     /// An array of signatures in hex format
     pub signatures: Option<Vec<String>>,
 }
 
 /// HTLC witness
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HTLCWitness {
     /// preimage
     pub preimage: String,
@@ -93,7 +93,7 @@ pub struct HTLCWitness {
 }
 
 /// Witness enum
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Witness {
     String(String),
     P2PK(P2PKWitness),
@@ -381,7 +381,7 @@ impl Witness {
 }
 
 /// Helper struct for creating proof test data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Proof {
     pub amount: u64,
     pub secret: String,
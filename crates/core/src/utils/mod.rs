@@ -1,3 +1,4 @@
+use crate::generated::nostr::fb;
 use crate::types::ParserError;
 
 #[cfg(feature = "crypto")]
@@ -537,3 +538,41 @@ pub fn normalize_relay_url(url: &str) -> String {
     }
     normalized
 }
+
+/// Content blocks of the parsed kinds that carry parsed content, in order of
+/// appearance; a kind 1's shortened content follows its full content.
+pub fn content_blocks<'a>(parsed: &fb::ParsedEvent<'a>) -> Vec<fb::ContentBlock<'a>> {
+    let block_lists = match parsed.parsed_type() {
+        fb::ParsedData::Kind1Parsed => parsed
+            .parsed_as_kind_1_parsed()
+            .map(|k| vec![Some(k.parsed_content()), k.shortened_content()])
+            .unwrap_or_default(),
+        fb::ParsedData::Kind4Parsed => {
+            vec![parsed
+                .parsed_as_kind_4_parsed()
+                .and_then(|k| k.parsed_content())]
+        }
+        fb::ParsedData::Kind14Parsed => {
+            vec![parsed
+                .parsed_as_kind_14_parsed()
+                .and_then(|k| k.parsed_content())]
+        }
+        fb::ParsedData::Kind1111Parsed => {
+            vec![parsed
+                .parsed_as_kind_1111_parsed()
+                .and_then(|k| k.parsed_content())]
+        }
+        fb::ParsedData::Kind1311Parsed => {
+            vec![parsed
+                .parsed_as_kind_1311_parsed()
+                .and_then(|k| k.parsed_content())]
+        }
+        fb::ParsedData::Kind1068Parsed => {
+            vec![parsed
+                .parsed_as_kind_1068_parsed()
+                .and_then(|k| k.content_blocks())]
+        }
+        _ => Vec::new(),
+    };
+    block_lists.into_iter().flatten().flatten().collect()
+}
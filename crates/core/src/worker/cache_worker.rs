@@ -1,6 +1,11 @@
 use crate::cache_input;
 use crate::channel::{MessageSender, WorkerChannel};
 use crate::generated::nostr::fb;
+use crate::link_preview;
+use crate::nip05::{self, Nip05Lookups};
+use crate::platform::sleep;
+use crate::relay_health::RelayHealth;
+use crate::relay_policy::RelayPolicy;
 use crate::spawn::spawn_worker;
 use crate::traits::Storage;
 use crate::types::network::Request;
@@ -25,9 +30,71 @@ fn resolve_publish_relays(requested_relays: Option<Vec<String>>) -> Vec<String>
             .collect(),
     }
 }
+
+/// Kind of a NIP-17 DM inbox relay list.
+const DM_INBOX_RELAYS_KIND: i32 = 10050;
+
+/// `relay` tags of the newest cached kind 10050 among `events`.
+fn latest_inbox_relays(events: &[Vec<u8>]) -> Vec<String> {
+    let mut newest: Option<(u32, Vec<String>)> = None;
+    for bytes in events {
+        let Ok(message) = flatbuffers::root::<fb::WorkerMessage>(bytes) else {
+            continue;
+        };
+        let (created_at, tags) = match message.content_type() {
+            fb::Message::NostrEvent => match message.content_as_nostr_event() {
+                Some(event) => (event.created_at() as u32, Some(event.tags())),
+                None => continue,
+            },
+            fb::Message::ParsedEvent => match message.content_as_parsed_event() {
+                Some(event) => (event.created_at(), Some(event.tags())),
+                None => continue,
+            },
+            _ => continue,
+        };
+        if newest.as_ref().is_some_and(|(at, _)| *at >= created_at) {
+            continue;
+        }
+        let relays = tags
+            .into_iter()
+            .flatten()
+            .filter_map(|tag| tag.items())
+            .filter(|items| items.len() >= 2 && items.get(0) == "relay")
+            .map(|items| items.get(1).to_string())
+            .filter(|relay| !relay.is_empty())
+            .collect();
+        newest = Some((created_at, relays));
+    }
+    newest.map(|(_, relays)| relays).unwrap_or_default()
+}
+
+/// Cached DM inbox relays of `recipient`; empty when no list is cached.
+async fn dm_inbox_relays(storage: &Arc<dyn Storage>, recipient: &str) -> Vec<String> {
+    let request = Request {
+        authors: vec![recipient.to_string()],
+        kinds: vec![DM_INBOX_RELAYS_KIND],
+        ..Default::default()
+    };
+    let Ok(filter) = request.to_filter() else {
+        return Vec::new();
+    };
+    match storage.query(vec![filter]).await {
+        Ok(events) => latest_inbox_relays(&events),
+        Err(e) => {
+            warn!("[CacheWorker] inbox relay lookup failed: {}", e);
+            Vec::new()
+        }
+    }
+}
+
 const MAX_MESH_WATCHES: usize = 128;
 const MAX_DELIVERED_IDS_PER_WATCH: usize = 4096;
 const MESH_EVENT_TTL: Duration = Duration::from_secs(10 * 60);
+/// Upper bound on the cached `(created_at, id)` pairs sent into a NIP-77
+/// reconciliation; anything older is simply fetched again.
+const NEGENTROPY_MAX_ITEMS: i32 = 50_000;
+/// How often changed relay health is written back to storage.
+const RELAY_HEALTH_FLUSH_MS: u64 = 30_000;
 const MESH_PIN_PROFILE_SUB_ID: &str = "mesh_pin_profile";
 const MESH_CLEAR_PROFILE_SUB_ID: &str = "mesh_clear_profile";
 
@@ -126,6 +193,8 @@ impl MeshWatch {
 pub struct CacheWorker {
     _storage: Arc<dyn Storage>,
     mesh_storage: Option<Arc<dyn Storage>>,
+    relay_health: RelayHealth,
+    relay_policy: RelayPolicy,
 }
 
 impl CacheWorker {
@@ -133,6 +202,8 @@ impl CacheWorker {
         Self {
             _storage: storage,
             mesh_storage: None,
+            relay_health: RelayHealth::new(),
+            relay_policy: RelayPolicy::default(),
         }
     }
 
@@ -140,9 +211,26 @@ impl CacheWorker {
         Self {
             _storage: storage,
             mesh_storage: Some(mesh_storage),
+            relay_health: RelayHealth::new(),
+            relay_policy: RelayPolicy::default(),
         }
     }
 
+    /// Rank relays for outgoing REQs with `health` (normally the table the
+    /// connections worker records into). The client endpoint also loads and
+    /// persists it through `Storage`.
+    pub fn with_relay_health(mut self, health: RelayHealth) -> Self {
+        self.relay_health = health;
+        self
+    }
+
+    /// Drop relays `policy` forbids from routed, outbox-planned and publish
+    /// relay sets (normally the handle shared with the connections worker).
+    pub fn with_relay_policy(mut self, policy: RelayPolicy) -> Self {
+        self.relay_policy = policy;
+        self
+    }
+
     pub fn run(
         self,
         from_parser: Box<dyn WorkerChannel>,
@@ -185,6 +273,8 @@ impl CacheWorker {
             None,
             Some(mesh_ttl.clone()),
             false,
+            Some(self.relay_health),
+            self.relay_policy,
         );
         spawn_cache_endpoint(
             "mesh",
@@ -198,6 +288,8 @@ impl CacheWorker {
             Some(parser_out),
             Some(mesh_ttl),
             true,
+            None,
+            RelayPolicy::default(),
         );
     }
 
@@ -219,10 +311,13 @@ impl CacheWorker {
             None,
             None,
             false,
+            Some(self.relay_health),
+            self.relay_policy,
         );
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_cache_endpoint(
     name: &'static str,
     storage: Arc<dyn Storage>,
@@ -235,6 +330,8 @@ fn spawn_cache_endpoint(
     mesh_notifications_out: Option<Arc<dyn MessageSender>>,
     mesh_ttl: Option<Arc<Mutex<MeshTtlIndex>>>,
     track_mesh_ingress: bool,
+    relay_health: Option<RelayHealth>,
+    relay_policy: RelayPolicy,
 ) {
     spawn_worker(async move {
         info!(endpoint = name, "[CacheWorker] endpoint started");
@@ -244,6 +341,44 @@ fn spawn_cache_endpoint(
                 "[CacheWorker] failed to initialize storage: {}", e
             );
         }
+        if let Some(health) = relay_health.as_ref() {
+            match storage.load_relay_health().await {
+                Ok(Some(bytes)) => health.restore(&bytes),
+                Ok(None) => {}
+                Err(e) => warn!("[CacheWorker] failed to load relay health: {}", e),
+            }
+            spawn_relay_health_flush(storage.clone(), health.clone());
+        }
+        // The parser worker owns the link preview cache; hand it the
+        // persisted snapshot (only the client endpoint feeds a parser).
+        if upstream_out.is_some() {
+            match storage.load_link_previews().await {
+                Ok(Some(bytes)) => {
+                    let resp = serialize_cache_response(link_preview::CACHE_SUB_ID, &bytes);
+                    if let Err(e) = results_out.send(&resp) {
+                        warn!("[CacheWorker] failed to send link previews: {}", e);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => warn!("[CacheWorker] failed to load link previews: {}", e),
+            }
+        }
+        // NIP-05 lookups are owned by the parser worker too; this endpoint
+        // keeps a copy for outbox hints.
+        let nip05_lookups = Nip05Lookups::default();
+        match storage.load_nip05_lookups().await {
+            Ok(Some(bytes)) => {
+                nip05_lookups.restore(&bytes);
+                if upstream_out.is_some() {
+                    let resp = serialize_cache_response(nip05::CACHE_SUB_ID, &bytes);
+                    if let Err(e) = results_out.send(&resp) {
+                        warn!("[CacheWorker] failed to send NIP-05 lookups: {}", e);
+                    }
+                }
+            }
+            Ok(None) => {}
+            Err(e) => warn!("[CacheWorker] failed to load NIP-05 lookups: {}", e),
+        }
 
         while let Ok(bytes) = requests_in.recv().await {
             let (tag, inner) = match cache_input::split(&bytes) {
@@ -269,6 +404,25 @@ fn spawn_cache_endpoint(
                 };
                 let sub_id = worker_msg.sub_id().map(str::to_string);
 
+                if sub_id.as_deref() == Some(link_preview::CACHE_SUB_ID) {
+                    if let Some(raw) = worker_msg.content_as_raw() {
+                        if let Err(e) = storage.save_link_previews(raw.raw().as_bytes()).await {
+                            warn!("[CacheWorker] failed to persist link previews: {}", e);
+                        }
+                    }
+                    continue;
+                }
+
+                if sub_id.as_deref() == Some(nip05::CACHE_SUB_ID) {
+                    if let Some(raw) = worker_msg.content_as_raw() {
+                        nip05_lookups.restore(raw.raw().as_bytes());
+                        if let Err(e) = storage.save_nip05_lookups(raw.raw().as_bytes()).await {
+                            warn!("[CacheWorker] failed to persist NIP-05 lookups: {}", e);
+                        }
+                    }
+                    continue;
+                }
+
                 if track_mesh_ingress && sub_id.as_deref() == Some(MESH_CLEAR_PROFILE_SUB_ID) {
                     if let Some(mesh_ttl) = mesh_ttl.as_ref() {
                         if let Ok(mut index) = mesh_ttl.lock() {
@@ -376,10 +530,24 @@ fn spawn_cache_endpoint(
                 let frame = json!(["EVENT", event_json]);
                 let frame_str = serde_json::to_string(&frame).unwrap_or_else(|_| "[]".to_string());
 
-                let requested_relays = cache_req
+                let mut requested_relays: Option<Vec<String>> = cache_req
                     .relays()
                     .map(|r| (0..r.len()).map(|i| r.get(i).to_string()).collect());
-                let relays = resolve_publish_relays(requested_relays);
+                // NIP-17: a gift wrap goes to its recipient's DM inbox relays
+                // when their kind 10050 list is cached.
+                if fb_event.kind() == 1059 {
+                    let recipient = tags_json.iter().find_map(|tag| match tag.as_array() {
+                        Some(items) if items.len() >= 2 && items[0] == "p" => items[1].as_str(),
+                        _ => None,
+                    });
+                    if let Some(recipient) = recipient {
+                        let inbox = dm_inbox_relays(&storage, recipient).await;
+                        if !inbox.is_empty() {
+                            requested_relays = Some(inbox);
+                        }
+                    }
+                }
+                let relays = relay_policy.filter_write(resolve_publish_relays(requested_relays));
 
                 info!(
                     event_id = fb_event.id(),
@@ -585,29 +753,105 @@ fn spawn_cache_endpoint(
                     }
                     let fb_req = reqs.get(i);
                     let filter_json = fb_request_to_json(&fb_req);
-                    let frame = json!(["REQ", &sub_id, filter_json]);
-                    let frame_str =
-                        serde_json::to_string(&frame).unwrap_or_else(|_| "[]".to_string());
-
-                    let mut relays: Vec<String> = fb_req
-                        .relays()
-                        .map(|r| (0..r.len()).map(|j| r.get(j).to_string()).collect())
-                        .filter(|v: &Vec<String>| !v.is_empty())
-                        .or_else(|| storage.get_relays(&fb_req))
-                        .filter(|v: &Vec<String>| !v.is_empty())
-                        .unwrap_or_else(|| DEFAULT_RELAYS.iter().map(|s| s.to_string()).collect());
                     let max_relays = fb_req.max_relays() as usize;
-                    if max_relays > 0 {
-                        relays.truncate(max_relays);
-                    }
+                    let explicit_relays = fb_req.relays().is_some_and(|r| !r.is_empty());
+                    let negentropy = if fb_req.negentropy() {
+                        Some(NegentropyCache::load(&storage, &sub_id, &fb_req).await)
+                    } else {
+                        None
+                    };
 
-                    let envelope = json!({ "relays": relays, "frames": [frame_str] });
-                    let env_str =
-                        serde_json::to_string(&envelope).unwrap_or_else(|_| "{}".to_string());
+                    // Multi-author REQs without pinned relays follow the
+                    // outbox plan: each relay only gets the authors it covers.
+                    let plan = if explicit_relays {
+                        None
+                    } else {
+                        let rank = |relay: &str| {
+                            relay_health.as_ref().map_or(0.0, |health| health.score(relay))
+                        };
+                        let usable = |relay: &str| {
+                            relay_policy.can_read(relay)
+                                && !relay_health.as_ref().is_some_and(|health| health.is_dead(relay))
+                        };
+                        let listed = |author: &str| nip05_lookups.relays_for(author);
+                        storage
+                            .plan_outbox(&fb_req, max_relays, &rank, &usable, &listed)
+                            .filter(|plan| !plan.assignments.is_empty())
+                    };
+                    let envelopes: Vec<Value> = match plan {
+                        Some(plan) => {
+                            if !plan.uncovered.is_empty() {
+                                debug!(
+                                    "[CacheWorker] sub_id={} req={} {} author(s) without outbox relays, using fallback",
+                                    sub_id,
+                                    i,
+                                    plan.uncovered.len()
+                                );
+                            }
+                            plan.assignments
+                                .into_iter()
+                                .map(|assignment| {
+                                    let mut filter = filter_json.clone();
+                                    filter["authors"] = json!(assignment.authors);
+                                    let frame = json!(["REQ", &sub_id, filter]);
+                                    let mut envelope = json!({
+                                        "relays": [assignment.relay],
+                                        "frames": [frame.to_string()],
+                                    });
+                                    if let Some(cache) = negentropy.as_ref() {
+                                        envelope["negentropy"] =
+                                            cache.spec(&sub_id, Some(&assignment.authors));
+                                    }
+                                    envelope
+                                })
+                                .collect()
+                        }
+                        None => {
+                            let frame = json!(["REQ", &sub_id, filter_json]);
+                            let frame_str = serde_json::to_string(&frame)
+                                .unwrap_or_else(|_| "[]".to_string());
+
+                            let mut relays: Vec<String> = fb_req
+                                .relays()
+                                .map(|r| (0..r.len()).map(|j| r.get(j).to_string()).collect())
+                                .filter(|v: &Vec<String>| !v.is_empty())
+                                .or_else(|| storage.get_relays(&fb_req))
+                                .filter(|v: &Vec<String>| !v.is_empty())
+                                .unwrap_or_else(|| {
+                                    DEFAULT_RELAYS.iter().map(|s| s.to_string()).collect()
+                                });
+                            relays = relay_policy.filter_read(relays);
+                            match relay_health.as_ref() {
+                                // Caller-pinned relays keep their order; only the
+                                // relays we picked ourselves are ranked by health.
+                                Some(health) if !explicit_relays => {
+                                    relays = health.select(relays, max_relays);
+                                }
+                                _ => {
+                                    if max_relays > 0 {
+                                        relays.truncate(max_relays);
+                                    }
+                                }
+                            }
+                            let mut envelope = json!({ "relays": relays, "frames": [frame_str] });
+                            if let Some(cache) = negentropy.as_ref() {
+                                envelope["negentropy"] = cache.spec(&sub_id, None);
+                            }
+                            vec![envelope]
+                        }
+                    };
 
-                    if let Some(upstream_out) = upstream_out.as_ref() {
-                        if let Err(e) = upstream_out.send(env_str.as_bytes()) {
-                            warn!("[CacheWorker] failed to send REQ envelope: {}", e);
+                    for mut envelope in envelopes {
+                        if fb_req.background() {
+                            envelope["background"] = json!(true);
+                        }
+                        let env_str = serde_json::to_string(&envelope)
+                            .unwrap_or_else(|_| "{}".to_string());
+
+                        if let Some(upstream_out) = upstream_out.as_ref() {
+                            if let Err(e) = upstream_out.send(env_str.as_bytes()) {
+                                warn!("[CacheWorker] failed to send REQ envelope: {}", e);
+                            }
                         }
                     }
                 }
@@ -655,6 +899,101 @@ fn worker_message_event_id(bytes: &[u8]) -> Option<String> {
     }
 }
 
+fn worker_message_negentropy_item(bytes: &[u8]) -> Option<NegentropyItem> {
+    let message = flatbuffers::root::<fb::WorkerMessage>(bytes).ok()?;
+    match message.content_type() {
+        fb::Message::NostrEvent => message.content_as_nostr_event().map(|event| NegentropyItem {
+            created_at: event.created_at() as u64,
+            id: event.id().to_string(),
+            pubkey: event.pubkey().to_string(),
+        }),
+        fb::Message::ParsedEvent => message.content_as_parsed_event().map(|event| NegentropyItem {
+            created_at: event.created_at() as u64,
+            id: event.id().to_string(),
+            pubkey: event.pubkey().to_string(),
+        }),
+        _ => None,
+    }
+}
+
+/// Periodically writes the relay health table back to storage so scores
+/// survive restarts. Skips the write when nothing changed.
+fn spawn_relay_health_flush(storage: Arc<dyn Storage>, health: RelayHealth) {
+    spawn_worker(async move {
+        loop {
+            sleep(RELAY_HEALTH_FLUSH_MS).await;
+            if !health.take_dirty() {
+                continue;
+            }
+            if let Err(e) = storage.save_relay_health(&health.snapshot()).await {
+                warn!("[CacheWorker] failed to persist relay health: {}", e);
+            }
+        }
+    });
+}
+
+struct NegentropyItem {
+    created_at: u64,
+    id: String,
+    pubkey: String,
+}
+
+/// Cached side of a NIP-77 reconciliation: the request filter without
+/// `limit`, plus every cached event matching it. Storage is queried with
+/// `NEGENTROPY_MAX_ITEMS` instead of the request's limit so the set covers
+/// the whole window.
+struct NegentropyCache {
+    filter: Value,
+    items: Vec<NegentropyItem>,
+}
+
+impl NegentropyCache {
+    async fn load(storage: &Arc<dyn Storage>, sub_id: &str, fb_req: &fb::Request<'_>) -> Self {
+        let mut filter = fb_request_to_json(fb_req);
+        if let Some(obj) = filter.as_object_mut() {
+            obj.remove("limit");
+        }
+
+        let mut request = Request::from_flatbuffer(fb_req);
+        request.limit = Some(NEGENTROPY_MAX_ITEMS);
+        let items = match request.to_filter() {
+            Ok(query) => match storage.query(vec![query]).await {
+                Ok(events) => events
+                    .iter()
+                    .filter_map(|event| worker_message_negentropy_item(event))
+                    .collect(),
+                Err(e) => {
+                    warn!(
+                        "[CacheWorker] sub_id={} negentropy storage query failed: {}",
+                        sub_id, e
+                    );
+                    Vec::new()
+                }
+            },
+            Err(_) => Vec::new(),
+        };
+        Self { filter, items }
+    }
+
+    /// Spec for one envelope. An outbox split passes its relay's `authors`,
+    /// which narrow both the filter and the cached set.
+    fn spec(&self, sub_id: &str, authors: Option<&[String]>) -> Value {
+        let mut filter = self.filter.clone();
+        if let Some(authors) = authors {
+            filter["authors"] = json!(authors);
+        }
+        let items: Vec<(u64, &str)> = self
+            .items
+            .iter()
+            .filter(|item| {
+                authors.is_none_or(|authors| authors.iter().any(|a| item.pubkey.starts_with(a)))
+            })
+            .map(|item| (item.created_at, item.id.as_str()))
+            .collect();
+        json!({ "sub_id": sub_id, "filter": filter, "items": items })
+    }
+}
+
 fn request_matches_event(request: &Request, event: &fb::NostrEvent<'_>) -> bool {
     if !request.ids.is_empty() && !request.ids.iter().any(|id| event.id().starts_with(id)) {
         return false;
@@ -962,13 +1301,34 @@ mod tests {
         created_at: i32,
         sig: &str,
         relays: &[&str],
+    ) -> Vec<u8> {
+        build_tagged_publish_request_bytes(id, pubkey, kind, content, created_at, sig, &[], relays)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_tagged_publish_request_bytes(
+        id: &str,
+        pubkey: &str,
+        kind: u16,
+        content: &str,
+        created_at: i32,
+        sig: &str,
+        tags: &[&[&str]],
+        relays: &[&str],
     ) -> Vec<u8> {
         let mut builder = flatbuffers::FlatBufferBuilder::new();
         let id_off = builder.create_string(id);
         let pubkey_off = builder.create_string(pubkey);
         let content_off = builder.create_string(content);
         let sig_off = builder.create_string(sig);
-        let tag_offsets: Vec<flatbuffers::WIPOffset<fb::StringVec>> = vec![];
+        let tag_offsets: Vec<_> = tags
+            .iter()
+            .map(|tag| {
+                let items: Vec<_> = tag.iter().map(|item| builder.create_string(item)).collect();
+                let items = builder.create_vector(&items);
+                fb::StringVec::create(&mut builder, &fb::StringVecArgs { items: Some(items) })
+            })
+            .collect();
         let tags_off = builder.create_vector(&tag_offsets);
         let event_fb = fb::NostrEvent::create(
             &mut builder,
@@ -1037,7 +1397,7 @@ mod tests {
             .run_until(async {
                 let storage = Arc::new(MockStorage::new());
                 let worker = CacheWorker::new(storage.clone());
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, _to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, _to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1072,7 +1432,7 @@ mod tests {
             .run_until(async {
                 let storage = Arc::new(MockStorage::new());
                 let worker = CacheWorker::new(storage.clone());
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, _to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1124,6 +1484,118 @@ mod tests {
             .await;
     }
 
+    #[test]
+    fn latest_inbox_relays_uses_the_newest_list() {
+        let older = build_nostr_worker_message_bytes(
+            &"1".repeat(64),
+            &"2".repeat(64),
+            10050,
+            100,
+            "",
+            &[&["relay", "wss://old.example"]],
+        );
+        let newer = build_nostr_worker_message_bytes(
+            &"3".repeat(64),
+            &"2".repeat(64),
+            10050,
+            200,
+            "",
+            &[&["relay", "wss://inbox.example"], &["relay", "wss://inbox2.example"]],
+        );
+        assert_eq!(
+            latest_inbox_relays(&[newer, older]),
+            vec!["wss://inbox.example", "wss://inbox2.example"]
+        );
+        assert!(latest_inbox_relays(&[]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_gift_wrap_is_published_to_recipient_inbox_relays() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let recipient = "4".repeat(64);
+                let inbox = build_nostr_worker_message_bytes(
+                    &"5".repeat(64),
+                    &recipient,
+                    10050,
+                    100,
+                    "",
+                    &[&["relay", "wss://inbox.example"]],
+                );
+                let storage = Arc::new(MockStorage::with_query_results(vec![Ok(vec![inbox])]));
+                let worker = CacheWorker::new(storage.clone());
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (to_parser_tx, _to_parser_rx) = TokioWorkerChannel::new_pair();
+                let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
+                worker.run(
+                    Box::new(from_parser_rx),
+                    to_parser_tx.clone_sender(),
+                    to_connections_tx.clone_sender(),
+                );
+
+                let bytes = build_tagged_publish_request_bytes(
+                    &"1".repeat(64),
+                    &"2".repeat(64),
+                    1059,
+                    "sealed",
+                    1234567890,
+                    &"3".repeat(128),
+                    &[&["p", &recipient]],
+                    &["wss://r"],
+                );
+                from_parser_tx.send(&frame_request(&bytes)).await.unwrap();
+
+                let env_bytes = to_connections_rx.recv().await.unwrap();
+                let envelope: Value = serde_json::from_slice(&env_bytes).unwrap();
+                assert_eq!(envelope["relays"], json!(["wss://inbox.example"]));
+                let calls = storage.query_calls.lock().unwrap();
+                assert_eq!(calls[0].filters[0].kinds, Some(vec![10050]));
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_publish_skips_relays_the_policy_forbids() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let policy = RelayPolicy::new(crate::relay_policy::RelayPolicyRules {
+                    require_tls: true,
+                    modes: HashMap::from([(
+                        "wss://read-only".to_string(),
+                        crate::relay_policy::RelayMode::Read,
+                    )]),
+                    ..Default::default()
+                });
+                let worker = CacheWorker::new(Arc::new(MockStorage::new())).with_relay_policy(policy);
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (to_parser_tx, _to_parser_rx) = TokioWorkerChannel::new_pair();
+                let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
+                worker.run(
+                    Box::new(from_parser_rx),
+                    to_parser_tx.clone_sender(),
+                    to_connections_tx.clone_sender(),
+                );
+
+                let bytes = build_publish_request_bytes(
+                    "0000000000000000000000000000000000000000000000000000000000000001",
+                    "0000000000000000000000000000000000000000000000000000000000000002",
+                    1,
+                    "hello",
+                    1234567890,
+                    "0000000000000000000000000000000000000000000000000000000000000003",
+                    &["wss://read-only", "ws://plain", "wss://ok"],
+                );
+                from_parser_tx.send(&frame_request(&bytes)).await.unwrap();
+
+                let env_bytes = to_connections_rx.recv().await.unwrap();
+                let envelope: Value = serde_json::from_slice(&env_bytes).unwrap();
+                assert_eq!(envelope["relays"], json!(["wss://ok"]));
+            })
+            .await;
+    }
+
     #[tokio::test]
     async fn test_query_returns_batched_cache_response_to_parser() {
         let local = tokio::task::LocalSet::new();
@@ -1134,7 +1606,7 @@ mod tests {
                     Ok(vec![]),
                 ]));
                 let worker = CacheWorker::new(storage.clone());
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, mut to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, _to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1207,7 +1679,7 @@ mod tests {
                     Ok(vec![]),
                 ]));
                 let worker = CacheWorker::new(storage.clone());
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, mut to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1252,6 +1724,63 @@ mod tests {
             .await;
     }
 
+    #[tokio::test]
+    async fn test_negentropy_query_attaches_cached_id_set() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let have_a = "a".repeat(64);
+                let have_b = "b".repeat(64);
+                let storage = Arc::new(MockStorage::with_query_results(vec![
+                    Ok(vec![build_nostr_worker_message_bytes(&have_a, "pk", 1, 20, "", &[])]),
+                    Ok(vec![
+                        build_nostr_worker_message_bytes(&have_a, "pk", 1, 20, "", &[]),
+                        build_nostr_worker_message_bytes(&have_b, "pk", 1, 10, "", &[]),
+                    ]),
+                ]));
+                let worker = CacheWorker::new(storage.clone());
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (to_parser_tx, _to_parser_rx) = TokioWorkerChannel::new_pair();
+                let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
+
+                worker.run(
+                    Box::new(from_parser_rx),
+                    to_parser_tx.clone_sender(),
+                    to_connections_tx.clone_sender(),
+                );
+
+                let requests = vec![Request {
+                    relays: vec!["wss://r".to_string()],
+                    kinds: vec![1],
+                    limit: Some(1),
+                    negentropy: true,
+                    ..Default::default()
+                }];
+                let bytes = build_query_request_bytes("s1", requests);
+                from_parser_tx
+                    .send(&frame_request(&bytes))
+                    .await
+                    .unwrap();
+
+                let env_bytes = to_connections_rx.recv().await.unwrap();
+                let envelope: Value = serde_json::from_slice(&env_bytes).unwrap();
+                let frame: Value =
+                    serde_json::from_str(envelope["frames"][0].as_str().unwrap()).unwrap();
+                assert_eq!(frame[2], json!({ "kinds": [1], "limit": 1 }));
+                assert_eq!(envelope["negentropy"]["sub_id"], "s1");
+                assert_eq!(envelope["negentropy"]["filter"], json!({ "kinds": [1] }));
+                assert_eq!(
+                    envelope["negentropy"]["items"],
+                    json!([[20, have_a], [10, have_b]])
+                );
+
+                let calls = storage.query_calls.lock().unwrap();
+                assert_eq!(calls.len(), 2);
+                assert_eq!(calls[1].filters[0].limit, Some(NEGENTROPY_MAX_ITEMS as u32));
+            })
+            .await;
+    }
+
     #[tokio::test]
     async fn test_query_honors_max_relays() {
         let local = tokio::task::LocalSet::new();
@@ -1259,7 +1788,7 @@ mod tests {
             .run_until(async {
                 let storage = Arc::new(MockStorage::new());
                 let worker = CacheWorker::new(storage);
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, _to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1289,6 +1818,148 @@ mod tests {
             .await;
     }
 
+    #[tokio::test]
+    async fn test_outbox_plan_splits_req_per_relay() {
+        const ALICE: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+        const BOB: &str = "2222222222222222222222222222222222222222222222222222222222222222";
+        const CAROL: &str = "3333333333333333333333333333333333333333333333333333333333333333";
+        struct OutboxStorage;
+
+        #[async_trait(?Send)]
+        impl Storage for OutboxStorage {
+            async fn query(&self, _filters: Vec<Filter>) -> Result<Vec<Vec<u8>>, StorageError> {
+                Ok(vec![
+                    build_nostr_worker_message_bytes(&"a".repeat(64), ALICE, 1, 10, "", &[]),
+                    build_nostr_worker_message_bytes(&"b".repeat(64), BOB, 1, 20, "", &[]),
+                ])
+            }
+
+            async fn persist(&self, _event_bytes: &[u8]) -> Result<(), StorageError> {
+                Ok(())
+            }
+
+            async fn initialize(&self) -> Result<(), StorageError> {
+                Ok(())
+            }
+
+            fn plan_outbox(
+                &self,
+                _request: &fb::Request<'_>,
+                _max_relays: usize,
+                _rank: &dyn Fn(&str) -> f64,
+                _usable: &dyn Fn(&str) -> bool,
+                _listed: &dyn Fn(&str) -> Vec<String>,
+            ) -> Option<crate::outbox::OutboxPlan> {
+                let author_relays = [
+                    (ALICE.to_string(), vec!["wss://a".to_string()]),
+                    (BOB.to_string(), vec!["wss://b".to_string()]),
+                    (CAROL.to_string(), Vec::new()),
+                ]
+                .into_iter()
+                .collect();
+                Some(crate::outbox::plan_outbox(
+                    &author_relays,
+                    1,
+                    0,
+                    &["wss://indexer".to_string()],
+                    &|_| 0.0,
+                    &|_| true,
+                ))
+            }
+        }
+
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let worker = CacheWorker::new(Arc::new(OutboxStorage));
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (to_parser_tx, _to_parser_rx) = TokioWorkerChannel::new_pair();
+                let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
+
+                worker.run(
+                    Box::new(from_parser_rx),
+                    to_parser_tx.clone_sender(),
+                    to_connections_tx.clone_sender(),
+                );
+
+                let requests = vec![Request {
+                    authors: vec![ALICE.to_string(), BOB.to_string(), CAROL.to_string()],
+                    kinds: vec![1],
+                    no_cache: true,
+                    negentropy: true,
+                    ..Default::default()
+                }];
+                let bytes = build_query_request_bytes("outbox", requests);
+                from_parser_tx.send(&frame_request(&bytes)).await.unwrap();
+
+                let mut routed = Vec::new();
+                for _ in 0..3 {
+                    let env_bytes = to_connections_rx.recv().await.unwrap();
+                    let envelope: Value = serde_json::from_slice(&env_bytes).unwrap();
+                    let frame: Value =
+                        serde_json::from_str(envelope["frames"][0].as_str().unwrap()).unwrap();
+                    routed.push((envelope["relays"].clone(), frame[2]["authors"].clone()));
+                    // Each split reconciles only its own authors.
+                    let spec = &envelope["negentropy"];
+                    assert_eq!(spec["filter"]["authors"], frame[2]["authors"]);
+                    let expected = match frame[2]["authors"][0].as_str().unwrap() {
+                        ALICE => json!([[10, "a".repeat(64)]]),
+                        BOB => json!([[20, "b".repeat(64)]]),
+                        _ => json!([]),
+                    };
+                    assert_eq!(spec["items"], expected);
+                }
+                assert_eq!(
+                    routed,
+                    vec![
+                        (json!(["wss://a"]), json!([ALICE])),
+                        (json!(["wss://b"]), json!([BOB])),
+                        (json!(["wss://indexer"]), json!([CAROL])),
+                    ]
+                );
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_discovered_relays_skip_dead_relays() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let health = RelayHealth::new();
+                for _ in 0..5 {
+                    health.record_connect_failed(DEFAULT_RELAYS[0], 1);
+                }
+                let storage = Arc::new(MockStorage::new());
+                let worker = CacheWorker::new(storage).with_relay_health(health);
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (to_parser_tx, _to_parser_rx) = TokioWorkerChannel::new_pair();
+                let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
+
+                worker.run(
+                    Box::new(from_parser_rx),
+                    to_parser_tx.clone_sender(),
+                    to_connections_tx.clone_sender(),
+                );
+
+                let requests = vec![Request {
+                    no_cache: true,
+                    max_relays: 2,
+                    ..Default::default()
+                }];
+                let bytes = build_query_request_bytes("ranked", requests);
+                from_parser_tx.send(&frame_request(&bytes)).await.unwrap();
+
+                let env_bytes = to_connections_rx.recv().await.unwrap();
+                let envelope: Value = serde_json::from_slice(&env_bytes).unwrap();
+                assert_eq!(
+                    envelope["relays"],
+                    json!([DEFAULT_RELAYS[1], DEFAULT_RELAYS[2]])
+                );
+            })
+            .await;
+    }
+
     #[tokio::test]
     async fn test_no_cache_skips_storage_query() {
         let local = tokio::task::LocalSet::new();
@@ -1296,7 +1967,7 @@ mod tests {
             .run_until(async {
                 let storage = Arc::new(MockStorage::new());
                 let worker = CacheWorker::new(storage.clone());
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, mut to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1349,7 +2020,7 @@ mod tests {
                     Ok(vec![]),
                 ]));
                 let worker = CacheWorker::new(storage.clone());
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, mut to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1418,7 +2089,7 @@ mod tests {
             .run_until(async {
                 let storage = Arc::new(FailingQueryStorage);
                 let worker = CacheWorker::new(storage.clone());
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, mut to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1479,7 +2150,7 @@ mod tests {
             .run_until(async {
                 let storage = Arc::new(FailingPersistStorage);
                 let worker = CacheWorker::new(storage.clone());
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, _to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, _to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1539,7 +2210,7 @@ mod tests {
             .run_until(async {
                 let storage = Arc::new(FailingInitStorage);
                 let worker = CacheWorker::new(storage.clone());
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, mut to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1613,7 +2284,7 @@ mod tests {
                     query_count: Mutex::new(0),
                 });
                 let worker = CacheWorker::new(storage.clone());
-                let (mut from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
+                let (from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, mut to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
 
@@ -1685,7 +2356,7 @@ mod tests {
                 let (_from_parser_tx, from_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_parser_tx, _to_parser_rx) = TokioWorkerChannel::new_pair();
                 let (to_connections_tx, mut to_connections_rx) = TokioWorkerChannel::new_pair();
-                let (mut from_mesh_tx, from_mesh_rx) = TokioWorkerChannel::new_pair();
+                let (from_mesh_tx, from_mesh_rx) = TokioWorkerChannel::new_pair();
                 let (to_mesh_tx, mut to_mesh_rx) = TokioWorkerChannel::new_pair();
 
                 worker.run_with_mesh(
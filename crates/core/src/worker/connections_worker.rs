@@ -1,10 +1,17 @@
+use crate::auth_policy::AuthPolicy;
 use crate::channel::{MessageSender, WorkerChannel};
 use crate::generated::nostr::fb;
+use crate::relay_health::RelayHealth;
+use crate::relay_policy::RelayPolicy;
 use crate::spawn::spawn_worker;
+use crate::traffic::{TrafficStats, BUDGET_EXCEEDED_PREFIX};
 use crate::traits::RelayTransport;
 use crate::transport::connection::RelayConnection;
 use crate::transport::fb_utils::{build_worker_message, serialize_connection_status};
-use crate::transport::frame_scan::scan_relay_frame;
+use crate::transport::frame_scan::{scan_relay_frame, ScannedFrame};
+use crate::transport::multiplexer::{SubscriptionMultiplexer, Unsubscribed, MAX_SUBS_PER_RELAY};
+use crate::transport::negentropy::{NegentropySessions, NegentropySpec};
+use crate::transport::rate_limit::{FramePriority, RateLimits};
 use crate::transport::sub_dedup::{scanned_event_id, SubDedup};
 use crate::worker::batch_buffer::{encode_raw_conn_batch, BatchBufferManager};
 use futures::StreamExt;
@@ -18,10 +25,33 @@ use tracing::{info, warn};
 /// for timeout flushes (same discipline as the parser→main sweeper).
 const CONN_BATCH_SWEEP_MS: u64 = 4;
 
+/// How often pending shared REQs are sent. Subscriptions opened within one
+/// tick share a REQ (see `transport::multiplexer`).
+const MULTIPLEX_SWEEP_MS: u64 = 4;
+
+/// How often idle NIP-77 sessions are checked for the REQ fallback.
+const NEGENTROPY_SWEEP_MS: u64 = 1_000;
+
+/// How often connected relays are checked for idle shutdown.
+const IDLE_SWEEP_MS: u64 = 10_000;
+
+/// How often subscriptions over their traffic budget are closed.
+const TRAFFIC_SWEEP_MS: u64 = 250;
+
+/// How often AUTH challenges waiting for the app are checked for an answer.
+const AUTH_SWEEP_MS: u64 = 250;
+
 #[derive(serde::Deserialize)]
 struct Envelope {
     relays: Vec<String>,
     frames: Vec<String>,
+    /// When present, `frames` is only the fallback: relays are reconciled
+    /// first and asked for the missing ids (see `transport::negentropy`).
+    #[serde(default)]
+    negentropy: Option<NegentropySpec>,
+    /// Prefetch REQs wait behind foreground traffic on each relay.
+    #[serde(default)]
+    background: bool,
 }
 
 fn relay_safe_sub_id(input: &str) -> String {
@@ -68,7 +98,7 @@ fn encode_relay_frame(
     let Some(kind) = arr.first().and_then(|v| v.as_str()) else {
         return frame.to_string();
     };
-    if kind != "REQ" && kind != "CLOSE" {
+    if !matches!(kind, "REQ" | "CLOSE" | "NEG-OPEN" | "NEG-MSG" | "NEG-CLOSE") {
         return frame.to_string();
     }
     let Some(full_sub_id) = arr.get(1).and_then(|v| v.as_str()).map(str::to_string) else {
@@ -112,6 +142,184 @@ fn relay_frame_state(frame: &str) -> Option<(String, String)> {
     Some((kind, sub_id))
 }
 
+/// Whether `policy` lets `frame` go to `relay`: EVENT needs write access,
+/// REQ/COUNT/NEG-OPEN need read access, anything else an allowed relay.
+fn policy_allows_frame(policy: &RelayPolicy, relay: &str, frame: &str) -> bool {
+    let kind = frame
+        .trim_start()
+        .strip_prefix('[')
+        .and_then(|rest| rest.trim_start().strip_prefix('"'))
+        .and_then(|rest| rest.split('"').next());
+    match kind {
+        Some("EVENT") => policy.can_write(relay),
+        Some("REQ" | "COUNT" | "NEG-OPEN") => policy.can_read(relay),
+        _ => policy.allows(relay),
+    }
+}
+
+/// Closes least recently used idle connections until at most `keep` remain.
+/// Relays with subscriptions or publishes in flight are never evicted, so
+/// the cap is soft while every open relay is busy.
+fn evict_idle_connections(
+    connections: &Arc<RwLock<HashMap<String, Arc<RelayConnection>>>>,
+    multiplexer: &Rc<RefCell<SubscriptionMultiplexer>>,
+    keep: usize,
+) {
+    let mut idle: Vec<(u64, String, Arc<RelayConnection>)> = {
+        let map = connections.read().unwrap();
+        if map.len() <= keep {
+            return;
+        }
+        map.iter()
+            .filter_map(|(url, conn)| conn.idle_since().map(|since| (since, url.clone(), conn.clone())))
+            .collect()
+    };
+    idle.sort_by_key(|(since, _, _)| *since);
+    let excess = connections.read().unwrap().len() - keep;
+    for (_, url, conn) in idle.into_iter().take(excess) {
+        info!(relay = %url, "[ConnectionsWorker] evicting idle relay over the connection cap");
+        let _ = conn.close();
+        connections.write().unwrap().remove(&url);
+        multiplexer.borrow_mut().remove_relay(&url);
+    }
+}
+
+/// Routes an app REQ or CLOSE for `relay` through the multiplexer and returns
+/// the frames to put on the wire now; shared REQs themselves go out with the
+/// multiplex sweeper. With multiplexing turned off for the worker, REQs and
+/// CLOSEs pass through like NIP-46 subscriptions and all other frames. REQs
+/// of a `background` envelope only share REQs with other background members.
+fn multiplex_frame(
+    multiplexer: &Rc<RefCell<SubscriptionMultiplexer>>,
+    relay: &str,
+    frame: &str,
+    conn: &RelayConnection,
+    enabled: bool,
+    background: bool,
+) -> Vec<String> {
+    let Ok(serde_json::Value::Array(arr)) = serde_json::from_str::<serde_json::Value>(frame) else {
+        return vec![frame.to_string()];
+    };
+    let (Some(kind), Some(sub_id)) = (
+        arr.first().and_then(|v| v.as_str()),
+        arr.get(1).and_then(|v| v.as_str()),
+    ) else {
+        return vec![frame.to_string()];
+    };
+    if !enabled || sub_id.starts_with("n46:") {
+        return vec![frame.to_string()];
+    }
+    match kind {
+        "REQ" => multiplexer
+            .borrow_mut()
+            .subscribe(relay, sub_id, arr[2..].to_vec(), background)
+            .into_iter()
+            .collect(),
+        "CLOSE" => {
+            let result = multiplexer.borrow_mut().unsubscribe(relay, sub_id);
+            if result != Unsubscribed::NotShared {
+                // The relay never sees this sub id, so acknowledge it here.
+                conn.notify(sub_id, &format!(r#"["OK","{}","CLOSED"]"#, sub_id));
+            }
+            match result {
+                Unsubscribed::NotShared => vec![frame.to_string()],
+                Unsubscribed::Left => Vec::new(),
+                Unsubscribed::Closed(close) => vec![close],
+            }
+        }
+        _ => vec![frame.to_string()],
+    }
+}
+
+/// Sends `NEG-CLOSE` for every in-flight reconciliation of `sub_id` (on
+/// `relay` only, or on all relays) ahead of the subscription's CLOSE.
+fn close_negentropy(
+    negentropy: &Rc<RefCell<NegentropySessions>>,
+    relay: Option<&str>,
+    sub_id: &str,
+    get_conn: &dyn Fn(&str) -> Arc<RelayConnection>,
+    full_to_relay: &Rc<RefCell<HashMap<String, String>>>,
+    relay_to_full: &Rc<RefCell<HashMap<String, String>>>,
+) {
+    let relays = negentropy.borrow_mut().close(relay, sub_id);
+    for relay in relays {
+        let frame = serde_json::json!(["NEG-CLOSE", sub_id]).to_string();
+        let relay_frame = encode_relay_frame(&frame, full_to_relay, relay_to_full);
+        let _ = get_conn(&relay).send_raw(&relay_frame);
+    }
+}
+
+/// Status frames the connection layer makes up (`["OK", sub, "SUBSCRIBED"]`,
+/// budget CLOSEDs…) rather than receives; they cost no bandwidth.
+fn is_synthetic_frame(scan: &ScannedFrame) -> bool {
+    match scan.kind {
+        "OK" => scan.args[1].is_some_and(|status| status.is_string),
+        "CLOSED" => scan.args[1]
+            .is_some_and(|reason| reason.inner().starts_with(BUDGET_EXCEEDED_PREFIX)),
+        _ => false,
+    }
+}
+
+/// Attributes a REQ/CLOSE put on the wire for `relay` to its subscription.
+fn record_sub_frame(traffic: &TrafficStats, relay: &str, frame: &str, wire: &str) {
+    if let Some((_, sub_id)) = relay_frame_state(frame) {
+        traffic.record_sub_sent(&sub_id, relay, wire);
+    }
+}
+
+/// Closes `sub_id` on every relay it was sent to and frees its dedup state.
+/// `close_frame` is the app-level `["CLOSE", sub_id]`.
+#[allow(clippy::too_many_arguments)]
+fn close_subscription(
+    sub_id: &str,
+    close_frame: &str,
+    get_conn: &dyn Fn(&str) -> Arc<RelayConnection>,
+    full_to_relay: &Rc<RefCell<HashMap<String, String>>>,
+    relay_to_full: &Rc<RefCell<HashMap<String, String>>>,
+    sub_relays: &Rc<RefCell<HashMap<String, HashSet<String>>>>,
+    sub_dedup: &Rc<RefCell<HashMap<String, SubDedup>>>,
+    negentropy: &Rc<RefCell<NegentropySessions>>,
+    multiplexer: &Rc<RefCell<SubscriptionMultiplexer>>,
+    multiplex: bool,
+    policy: &RelayPolicy,
+    traffic: &TrafficStats,
+) -> Vec<String> {
+    let relays = sub_relays.borrow_mut().remove(sub_id).unwrap_or_default();
+    // Free the cross-relay dedup state for this subscription.
+    sub_dedup.borrow_mut().remove(sub_id);
+    close_negentropy(
+        negentropy,
+        None,
+        sub_id,
+        get_conn,
+        full_to_relay,
+        relay_to_full,
+    );
+    let mut closed = Vec::new();
+    for relay in relays {
+        if !policy.allows(&relay) {
+            continue;
+        }
+        let conn = get_conn(&relay);
+        closed.push(relay.clone());
+        for wire in multiplex_frame(
+            multiplexer,
+            &relay,
+            close_frame,
+            &conn,
+            multiplex,
+            false,
+        ) {
+            let relay_text = encode_relay_frame(&wire, full_to_relay, relay_to_full);
+            if conn.send_raw(&relay_text).is_ok() {
+                traffic.record_sub_sent(sub_id, &relay, &relay_text);
+            }
+        }
+    }
+    closed
+}
+
+#[allow(clippy::too_many_arguments)]
 fn send_envelope(
     bytes: &[u8],
     source: &str,
@@ -120,6 +328,11 @@ fn send_envelope(
     relay_to_full: &Rc<RefCell<HashMap<String, String>>>,
     sub_relays: &Rc<RefCell<HashMap<String, HashSet<String>>>>,
     sub_dedup: &Rc<RefCell<HashMap<String, SubDedup>>>,
+    negentropy: &Rc<RefCell<NegentropySessions>>,
+    multiplexer: &Rc<RefCell<SubscriptionMultiplexer>>,
+    multiplex: bool,
+    policy: &RelayPolicy,
+    traffic: &TrafficStats,
 ) {
     let env: Envelope = match serde_json::from_slice(bytes) {
         Ok(e) => e,
@@ -135,9 +348,51 @@ fn send_envelope(
         if relay.is_empty() {
             continue;
         }
+        let refused = if env.negentropy.is_some() {
+            !policy.can_read(relay)
+        } else {
+            !env.frames.iter().any(|frame| policy_allows_frame(policy, relay, frame))
+        };
+        if refused {
+            warn!("[ConnectionsWorker] relay policy refuses {} from {}", relay, source);
+            continue;
+        }
         let conn = get_conn(relay);
-        for frame in &env.frames {
+        let frames = match env.negentropy.as_ref() {
+            Some(spec) => {
+                sub_relays
+                    .borrow_mut()
+                    .entry(spec.sub_id.clone())
+                    .or_default()
+                    .insert(relay.clone());
+                negentropy.borrow_mut().open(
+                    relay,
+                    spec,
+                    &env.frames,
+                    crate::platform::now_millis(),
+                )
+            }
+            None => env.frames.clone(),
+        };
+        for frame in &frames {
+            if !policy_allows_frame(policy, relay, frame) {
+                warn!(
+                    "[ConnectionsWorker] relay policy refuses frame for {} from {}",
+                    relay, source
+                );
+                continue;
+            }
             if let Some((kind, sub_id)) = relay_frame_state(frame) {
+                if kind == "CLOSE" {
+                    close_negentropy(
+                        negentropy,
+                        Some(relay),
+                        &sub_id,
+                        get_conn,
+                        full_to_relay,
+                        relay_to_full,
+                    );
+                }
                 if kind == "REQ" {
                     sub_relays
                         .borrow_mut()
@@ -146,26 +401,43 @@ fn send_envelope(
                         .insert(relay.clone());
                 }
             }
-            let relay_frame = encode_relay_frame(frame, full_to_relay, relay_to_full);
-            if let Err(e) = conn.send_raw(&relay_frame) {
-                warn!(
-                    "[ConnectionsWorker] send_raw failed for {} from {}: {:?}",
-                    relay, source, e
-                );
+            for wire in multiplex_frame(
+                multiplexer,
+                relay,
+                frame,
+                &conn,
+                multiplex,
+                env.background,
+            ) {
+                let relay_frame = encode_relay_frame(&wire, full_to_relay, relay_to_full);
+                let priority = match FramePriority::of(&relay_frame) {
+                    FramePriority::Normal if env.background => FramePriority::Low,
+                    priority => priority,
+                };
+                match conn.send_raw_with_priority(&relay_frame, priority) {
+                    Ok(()) => record_sub_frame(traffic, relay, frame, &relay_frame),
+                    Err(e) => warn!(
+                        "[ConnectionsWorker] send_raw failed for {} from {}: {:?}",
+                        relay, source, e
+                    ),
+                }
             }
             if let Some((kind, sub_id)) = relay_frame_state(frame) {
                 if kind == "CLOSE" {
+                    // A subscription the budget sweeper already closed has no
+                    // relays left; the app's CLOSE still frees its traffic entry.
                     let should_remove =
                         if let Some(relays) = sub_relays.borrow_mut().get_mut(&sub_id) {
                             relays.remove(relay);
                             relays.is_empty()
                         } else {
-                            false
+                            true
                         };
                     if should_remove {
                         sub_relays.borrow_mut().remove(&sub_id);
                         // Free the cross-relay dedup state for this subscription.
                         sub_dedup.borrow_mut().remove(&sub_id);
+                        traffic.remove_subscription(&sub_id);
                     }
                 }
             }
@@ -176,6 +448,13 @@ fn send_envelope(
 pub struct ConnectionsWorker {
     transport: Arc<dyn RelayTransport>,
     connections: Arc<RwLock<HashMap<String, Arc<RelayConnection>>>>,
+    relay_health: RelayHealth,
+    relay_policy: RelayPolicy,
+    traffic: TrafficStats,
+    auth_policy: AuthPolicy,
+    rate_limits: RateLimits,
+    max_subs_per_relay: usize,
+    multiplex_subscriptions: bool,
 }
 
 pub struct ConnectionsHandle {
@@ -209,9 +488,77 @@ impl ConnectionsWorker {
         Self {
             transport,
             connections: Arc::new(RwLock::new(HashMap::new())),
+            relay_health: RelayHealth::new(),
+            relay_policy: RelayPolicy::default(),
+            traffic: TrafficStats::new(),
+            auth_policy: AuthPolicy::default(),
+            rate_limits: RateLimits::default(),
+            max_subs_per_relay: MAX_SUBS_PER_RELAY,
+            multiplex_subscriptions: true,
         }
     }
 
+    /// Cap on shared REQs per relay; further subscriptions are folded into
+    /// the open ones (see `transport::multiplexer`).
+    pub fn with_max_subscriptions_per_relay(mut self, max: usize) -> Self {
+        self.max_subs_per_relay = max;
+        self
+    }
+
+    /// Turn subscription multiplexing off: every app subscription gets its
+    /// own REQ on each relay.
+    pub fn with_multiplexing(mut self, enabled: bool) -> Self {
+        self.multiplex_subscriptions = enabled;
+        self
+    }
+
+    /// Record relay metrics into `health` (shared with the cache worker's
+    /// relay selection) instead of a private table.
+    pub fn with_relay_health(mut self, health: RelayHealth) -> Self {
+        self.relay_health = health;
+        self
+    }
+
+    pub fn relay_health(&self) -> RelayHealth {
+        self.relay_health.clone()
+    }
+
+    /// Enforce `policy` (normally shared with the cache worker): forbidden
+    /// frames are dropped, the connection cap evicts idle relays, and relays
+    /// a policy update denies are closed by the idle sweeper.
+    pub fn with_relay_policy(mut self, policy: RelayPolicy) -> Self {
+        self.relay_policy = policy;
+        self
+    }
+
+    /// Count traffic and enforce subscription budgets in `traffic`
+    /// (normally the engine's table) instead of a private one.
+    pub fn with_traffic(mut self, traffic: TrafficStats) -> Self {
+        self.traffic = traffic;
+        self
+    }
+
+    pub fn traffic(&self) -> TrafficStats {
+        self.traffic.clone()
+    }
+
+    /// Answer relay AUTH challenges according to `policy` (normally the
+    /// engine's) instead of always signing with the user's key.
+    pub fn with_auth_policy(mut self, policy: AuthPolicy) -> Self {
+        self.auth_policy = policy;
+        self
+    }
+
+    pub fn auth_policy(&self) -> AuthPolicy {
+        self.auth_policy.clone()
+    }
+
+    /// Outgoing per-relay token-bucket limits (see `transport::rate_limit`).
+    pub fn with_rate_limits(mut self, limits: RateLimits) -> Self {
+        self.rate_limits = limits;
+        self
+    }
+
     pub fn run(
         self,
         mut from_parser: Box<dyn WorkerChannel>,
@@ -229,6 +576,13 @@ impl ConnectionsWorker {
         // Cross-relay EVENT dedup: one bounded id ring per (full) subscription id.
         // Entries are created lazily on first EVENT and freed on CLOSE.
         let sub_dedup = Rc::new(RefCell::new(HashMap::<String, SubDedup>::new()));
+        // NIP-77 reconciliations in flight, keyed by (relay, full sub id).
+        let negentropy = Rc::new(RefCell::new(NegentropySessions::new()));
+        // App subscriptions folded into shared REQs, per relay.
+        let multiplexer = Rc::new(RefCell::new(SubscriptionMultiplexer::new(
+            self.max_subs_per_relay,
+        )));
+        let multiplex = self.multiplex_subscriptions;
 
         // Bridge multiple callback clones into the single MessageSender
         let (parser_tx, mut parser_rx) = futures::channel::mpsc::unbounded::<Vec<u8>>();
@@ -272,9 +626,17 @@ impl ConnectionsWorker {
             let connections = self.connections.clone();
             let parser_tx = parser_tx.clone();
             let to_crypto_rc = to_crypto_rc.clone();
+            let full_to_relay_sub_ids = full_to_relay_sub_ids.clone();
             let relay_to_full_sub_ids = relay_to_full_sub_ids.clone();
             let sub_dedup = sub_dedup.clone();
             let parser_batches = parser_batches.clone();
+            let negentropy = negentropy.clone();
+            let relay_health = self.relay_health.clone();
+            let relay_policy = self.relay_policy.clone();
+            let traffic = self.traffic.clone();
+            let auth_policy = self.auth_policy.clone();
+            let rate_limits = self.rate_limits.clone();
+            let multiplexer = multiplexer.clone();
             move |url: &str| {
                 {
                     let map = connections.read().unwrap();
@@ -282,93 +644,168 @@ impl ConnectionsWorker {
                         return conn.clone();
                     }
                 }
+                let max_connections = relay_policy.max_connections();
+                if max_connections > 0 {
+                    evict_idle_connections(&connections, &multiplexer, max_connections - 1);
+                }
 
                 let url_string = url.to_string();
                 let tx_msg = parser_tx.clone();
                 let tx_status = parser_tx.clone();
                 let transport = transport.clone();
                 let to_crypto_messages = to_crypto_rc.clone();
+                let full_to_relay_sub_ids = full_to_relay_sub_ids.clone();
                 let relay_to_full_sub_ids = relay_to_full_sub_ids.clone();
                 let sub_dedup_writer = sub_dedup.clone();
                 let parser_batches = parser_batches.clone();
+                let negentropy_writer = negentropy.clone();
+                let connections_writer = connections.clone();
+                let health_writer = relay_health.clone();
+                let traffic_writer = traffic.clone();
+                let multiplexer_writer = multiplexer.clone();
+                let relay_to_full_out = relay_to_full_sub_ids.clone();
+
+                // Hands one frame of an app subscription to crypto or the parser.
+                let deliver = move |url: &str, full_sub_id: String, msg: &str| {
+                    if full_sub_id.starts_with("n46:") {
+                        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+                        let wm = build_worker_message(&mut fbb, &full_sub_id, url, msg);
+                        fbb.finish(wm, None);
+                        // MessageSender accepts &[u8]: send the finished buffer without copying.
+                        let _ = to_crypto_messages.send(fbb.finished_data());
+                        return;
+                    }
 
-                let out_writer: Rc<dyn Fn(&str, &str, &str)> =
-                    Rc::new(move |url: &str, sub_id: &str, msg: &str| {
-                        let full_sub_id = decode_relay_sub_id(sub_id, &relay_to_full_sub_ids);
-                        if full_sub_id.starts_with("n46:") {
-                            let mut fbb = flatbuffers::FlatBufferBuilder::new();
-                            let wm = build_worker_message(&mut fbb, &full_sub_id, url, msg);
-                            fbb.finish(wm, None);
-                            // MessageSender accepts &[u8]: send the finished buffer without copying.
-                            let _ = to_crypto_messages.send(fbb.finished_data());
+                    // Route for this frame, decided by a single zero-copy scan.
+                    enum Route<'a> {
+                        /// Non-EVENT frame or malformed EVENT: WorkerMessage path.
+                        Control,
+                        /// Well-formed EVENT: batch the raw event-object JSON slice.
+                        Raw(&'a str),
+                    }
+
+                    let scan = scan_relay_frame(msg);
+                    if !scan.as_ref().is_some_and(is_synthetic_frame) {
+                        traffic_writer.record_sub_received(&full_sub_id, url, msg);
+                    }
+                    // Chunked NIP-77 fetches: an intermediate chunk's EOSE
+                    // triggers the next REQ and never reaches the parser.
+                    if scan.as_ref().is_some_and(|scan| scan.kind == "EOSE") {
+                        let next = negentropy_writer.borrow_mut().next_fetch(url, &full_sub_id);
+                        let conn = connections_writer.read().unwrap().get(url).cloned();
+                        if let (Some(next), Some(conn)) = (next, conn) {
+                            let relay_frame = encode_relay_frame(
+                                &next,
+                                &full_to_relay_sub_ids,
+                                &relay_to_full_sub_ids,
+                            );
+                            let _ = conn.send_raw(&relay_frame);
                             return;
                         }
-
-                        // Route for this frame, decided by a single zero-copy scan.
-                        enum Route<'a> {
-                            /// Non-EVENT frame or malformed EVENT: WorkerMessage path.
-                            Control,
-                            /// Well-formed EVENT: batch the raw event-object JSON slice.
-                            Raw(&'a str),
+                    }
+                    let route = match scan {
+                        Some(scan) if scan.kind == "NEG-MSG" || scan.kind == "NEG-ERR" => {
+                            // NIP-77 rounds are answered here and never reach
+                            // the parser; stale frames for closed sessions
+                            // are dropped.
+                            let replies = negentropy_writer.borrow_mut().handle(
+                                url,
+                                &full_sub_id,
+                                msg,
+                                crate::platform::now_millis(),
+                            );
+                            let conn = connections_writer.read().unwrap().get(url).cloned();
+                            if let (Some(replies), Some(conn)) = (replies, conn) {
+                                for reply in replies {
+                                    let relay_frame = encode_relay_frame(
+                                        &reply,
+                                        &full_to_relay_sub_ids,
+                                        &relay_to_full_sub_ids,
+                                    );
+                                    let _ = conn.send_raw(&relay_frame);
+                                }
+                            }
+                            return;
                         }
-
-                        let route = match scan_relay_frame(msg) {
-                            Some(scan) if scan.kind == "EVENT" => {
-                                // Cross-relay dedup: an EVENT frame reaches the parser
-                                // only the first time its (subId, event id) pair is
-                                // seen. Non-EVENT frames and unparseable payloads pass
-                                // through untouched (parser dedup stays as safety net).
-                                if let Some(id) = scanned_event_id(&scan) {
-                                    let mut dedup = sub_dedup_writer.borrow_mut();
-                                    if !dedup.contains_key(&full_sub_id) {
-                                        dedup.insert(full_sub_id.clone(), SubDedup::new());
-                                    }
-                                    let entry = dedup.get_mut(&full_sub_id).unwrap();
-                                    if !entry.mark(id) {
-                                        return;
-                                    }
+                        Some(scan) if scan.kind == "EVENT" => {
+                            // Subscriptions over their traffic budget wait for
+                            // the sweeper's CLOSE; nothing more reaches the app.
+                            if traffic_writer.is_exhausted(&full_sub_id) {
+                                return;
+                            }
+                            // Cross-relay dedup: an EVENT frame reaches the parser
+                            // only the first time its (subId, event id) pair is
+                            // seen. Non-EVENT frames and unparseable payloads pass
+                            // through untouched (parser dedup stays as safety net).
+                            if let Some(id) = scanned_event_id(&scan) {
+                                let mut dedup = sub_dedup_writer.borrow_mut();
+                                if !dedup.contains_key(&full_sub_id) {
+                                    dedup.insert(full_sub_id.clone(), SubDedup::new());
                                 }
-                                match scan.args[1] {
-                                    Some(v) if !v.is_string => Route::Raw(v.raw),
-                                    _ => Route::Control,
+                                let entry = dedup.get_mut(&full_sub_id).unwrap();
+                                if !entry.mark(id) {
+                                    health_writer.record_duplicate(url);
+                                    traffic_writer.record_duplicate(&full_sub_id, url);
+                                    return;
                                 }
                             }
-                            _ => Route::Control,
-                        };
+                            match scan.args[1] {
+                                Some(v) if !v.is_string => Route::Raw(v.raw),
+                                _ => Route::Control,
+                            }
+                        }
+                        _ => Route::Control,
+                    };
+
+                    match route {
+                        Route::Raw(event_json) => {
+                            // Compact envelope: the raw event-object slice goes
+                            // straight into this subscription's batch buffer —
+                            // no FlatBuffer build on the hot path. Flushed by
+                            // the size threshold (here), the sweeper timer, or
+                            // the next control frame for the sub.
+                            let flushed = parser_batches
+                                .borrow_mut()
+                                .add_message(&full_sub_id, event_json.as_bytes());
+                            if let Some(payload) = flushed {
+                                let _ =
+                                    tx_msg.unbounded_send(encode_raw_conn_batch(&payload));
+                            }
+                        }
+                        Route::Control => {
+                            // Control frames (EOSE/CLOSED/OK/AUTH/NOTICE) and
+                            // malformed EVENTs must not sit in a buffer: flush
+                            // this sub's pending events first so ordering is
+                            // preserved, then forward immediately as a bare
+                            // single WorkerMessage.
+                            let mut fbb = flatbuffers::FlatBufferBuilder::new();
+                            let wm = build_worker_message(&mut fbb, &full_sub_id, url, msg);
+                            fbb.finish(wm, None);
+                            let flushed = parser_batches.borrow_mut().flush_sub(&full_sub_id);
+                            if let Some(payload) = flushed {
+                                let _ =
+                                    tx_msg.unbounded_send(encode_raw_conn_batch(&payload));
+                            }
+                            // The mpsc bridge to the parser loop requires an owned Vec.
+                            let _ = tx_msg.unbounded_send(fbb.finished_data().to_vec());
+                        }
+                    }
+                };
 
-                        match route {
-                            Route::Raw(event_json) => {
-                                // Compact envelope: the raw event-object slice goes
-                                // straight into this subscription's batch buffer —
-                                // no FlatBuffer build on the hot path. Flushed by
-                                // the size threshold (here), the sweeper timer, or
-                                // the next control frame for the sub.
-                                let flushed = parser_batches
+                let out_writer: Rc<dyn Fn(&str, &str, &str)> =
+                    Rc::new(move |url: &str, sub_id: &str, msg: &str| {
+                        let full_sub_id = decode_relay_sub_id(sub_id, &relay_to_full_out);
+                        if SubscriptionMultiplexer::is_shared_id(&full_sub_id) {
+                            let routed =
+                                multiplexer_writer
                                     .borrow_mut()
-                                    .add_message(&full_sub_id, event_json.as_bytes());
-                                if let Some(payload) = flushed {
-                                    let _ =
-                                        tx_msg.unbounded_send(encode_raw_conn_batch(&payload));
-                                }
-                            }
-                            Route::Control => {
-                                // Control frames (EOSE/CLOSED/OK/AUTH/NOTICE) and
-                                // malformed EVENTs must not sit in a buffer: flush
-                                // this sub's pending events first so ordering is
-                                // preserved, then forward immediately as a bare
-                                // single WorkerMessage.
-                                let mut fbb = flatbuffers::FlatBufferBuilder::new();
-                                let wm = build_worker_message(&mut fbb, &full_sub_id, url, msg);
-                                fbb.finish(wm, None);
-                                let flushed = parser_batches.borrow_mut().flush_sub(&full_sub_id);
-                                if let Some(payload) = flushed {
-                                    let _ =
-                                        tx_msg.unbounded_send(encode_raw_conn_batch(&payload));
-                                }
-                                // The mpsc bridge to the parser loop requires an owned Vec.
-                                let _ = tx_msg.unbounded_send(fbb.finished_data().to_vec());
+                                    .demux(url, &full_sub_id, msg);
+                            for (member, frame) in routed {
+                                deliver(url, member, &frame);
                             }
+                            return;
                         }
+                        deliver(url, full_sub_id, msg);
                     });
 
                 let status_writer: Rc<dyn Fn(&str, &str)> =
@@ -384,13 +821,17 @@ impl ConnectionsWorker {
                     }
                 }));
 
-                let conn = RelayConnection::new(
+                let conn = RelayConnection::with_health(
                     url_string,
                     transport,
                     out_writer,
                     status_writer,
                     to_crypto_cb,
+                    relay_health.clone(),
                 );
+                conn.set_rate_limits(rate_limits.clone());
+                conn.set_traffic(traffic.clone());
+                conn.set_auth_policy(auth_policy.clone());
 
                 {
                     let mut map = connections.write().unwrap();
@@ -409,6 +850,10 @@ impl ConnectionsWorker {
         let relay_to_full_parser = relay_to_full_sub_ids.clone();
         let sub_relays_parser = sub_relays.clone();
         let sub_dedup_parser = sub_dedup.clone();
+        let negentropy_parser = negentropy.clone();
+        let multiplexer_parser = multiplexer.clone();
+        let policy_parser = self.relay_policy.clone();
+        let traffic_parser = self.traffic.clone();
         spawn_worker(async move {
             info!("[ConnectionsWorker] parser loop started");
             loop {
@@ -429,13 +874,53 @@ impl ConnectionsWorker {
                                 if let Some(raw) = wm.content_as_raw() {
                                     let text = raw.raw();
                                     if !text.is_empty() && !url.is_empty() {
+                                        if !policy_allows_frame(&policy_parser, url, text) {
+                                            warn!(
+                                                "[ConnectionsWorker] relay policy refuses frame for {}",
+                                                url
+                                            );
+                                            continue;
+                                        }
+                                        if let Some((kind, sub_id)) = relay_frame_state(text) {
+                                            if kind == "CLOSE" {
+                                                close_negentropy(
+                                                    &negentropy_parser,
+                                                    Some(url),
+                                                    &sub_id,
+                                                    &get_conn_parser,
+                                                    &full_to_relay_parser,
+                                                    &relay_to_full_parser,
+                                                );
+                                            }
+                                        }
                                         let conn = get_conn_parser(url);
-                                        let relay_text = encode_relay_frame(
+                                        let wires = multiplex_frame(
+                                            &multiplexer_parser,
+                                            url,
                                             text,
-                                            &full_to_relay_parser,
-                                            &relay_to_full_parser,
+                                            &conn,
+                                            multiplex,
+                                            false,
                                         );
-                                        if conn.send_raw(&relay_text).is_ok() {
+                                        let mut sent = true;
+                                        for wire in wires {
+                                            let relay_text = encode_relay_frame(
+                                                &wire,
+                                                &full_to_relay_parser,
+                                                &relay_to_full_parser,
+                                            );
+                                            if conn.send_raw(&relay_text).is_ok() {
+                                                record_sub_frame(
+                                                    &traffic_parser,
+                                                    url,
+                                                    text,
+                                                    &relay_text,
+                                                );
+                                            } else {
+                                                sent = false;
+                                            }
+                                        }
+                                        if sent {
                                             if let Some((kind, sub_id)) = relay_frame_state(text) {
                                                 if kind == "CLOSE" {
                                                     let should_remove = {
@@ -447,7 +932,7 @@ impl ConnectionsWorker {
                                                             relays.remove(url);
                                                             relays.is_empty()
                                                         } else {
-                                                            false
+                                                            true
                                                         }
                                                     };
                                                     if should_remove {
@@ -457,6 +942,7 @@ impl ConnectionsWorker {
                                                         sub_dedup_parser
                                                             .borrow_mut()
                                                             .remove(&sub_id);
+                                                        traffic_parser.remove_subscription(&sub_id);
                                                     }
                                                 }
                                             }
@@ -464,21 +950,21 @@ impl ConnectionsWorker {
                                     } else if !text.is_empty() {
                                         if let Some((kind, sub_id)) = relay_frame_state(text) {
                                             if kind == "CLOSE" {
-                                                let relays = sub_relays_parser
-                                                    .borrow_mut()
-                                                    .remove(&sub_id)
-                                                    .unwrap_or_default();
-                                                // Free the cross-relay dedup state for this subscription.
-                                                sub_dedup_parser.borrow_mut().remove(&sub_id);
-                                                for relay in relays {
-                                                    let conn = get_conn_parser(&relay);
-                                                    let relay_text = encode_relay_frame(
-                                                        text,
-                                                        &full_to_relay_parser,
-                                                        &relay_to_full_parser,
-                                                    );
-                                                    let _ = conn.send_raw(&relay_text);
-                                                }
+                                                close_subscription(
+                                                    &sub_id,
+                                                    text,
+                                                    &get_conn_parser,
+                                                    &full_to_relay_parser,
+                                                    &relay_to_full_parser,
+                                                    &sub_relays_parser,
+                                                    &sub_dedup_parser,
+                                                    &negentropy_parser,
+                                                    &multiplexer_parser,
+                                                    multiplex,
+                                                    &policy_parser,
+                                                    &traffic_parser,
+                                                );
+                                                traffic_parser.remove_subscription(&sub_id);
                                             }
                                         }
                                     }
@@ -516,7 +1002,7 @@ impl ConnectionsWorker {
                                     });
                                     let frame = serde_json::json!(["EVENT", event_json]);
                                     if let Ok(text) = serde_json::to_string(&frame) {
-                                        if !url.is_empty() {
+                                        if !url.is_empty() && policy_parser.can_write(url) {
                                             let conn = get_conn_parser(url);
                                             let _ = conn.send_raw(&text);
                                         }
@@ -532,6 +1018,7 @@ impl ConnectionsWorker {
                                                 let _ = conn.close();
                                                 let mut map = connections_parser.write().unwrap();
                                                 map.remove(url);
+                                                multiplexer_parser.borrow_mut().remove_relay(url);
                                             }
                                         }
                                         _ => {}
@@ -547,12 +1034,172 @@ impl ConnectionsWorker {
             info!("[ConnectionsWorker] parser loop exiting");
         });
 
+        // Multiplex sweeper: shared REQs opened since the last tick go out
+        // together, and shared REQs a member left are narrowed.
+        {
+            let get_conn_mux = get_or_create_connection.clone();
+            let full_to_relay_mux = full_to_relay_sub_ids.clone();
+            let relay_to_full_mux = relay_to_full_sub_ids.clone();
+            let multiplexer_mux = multiplexer.clone();
+            let policy_mux = self.relay_policy.clone();
+            let traffic_mux = self.traffic.clone();
+            spawn_worker(async move {
+                loop {
+                    crate::platform::sleep(MULTIPLEX_SWEEP_MS).await;
+                    let frames = multiplexer_mux.borrow_mut().flush();
+                    for shared in frames {
+                        if !policy_mux.can_read(&shared.relay) {
+                            continue;
+                        }
+                        let conn = get_conn_mux(&shared.relay);
+                        let relay_frame =
+                            encode_relay_frame(&shared.frame, &full_to_relay_mux, &relay_to_full_mux);
+                        if conn.send_raw_with_priority(&relay_frame, shared.priority).is_ok() {
+                            traffic_mux.record_shared_sent(
+                                &shared.members,
+                                &shared.relay,
+                                &relay_frame,
+                            );
+                        }
+                    }
+                }
+            });
+        }
+
+        // NIP-77 sweeper: relays that never answer a NEG-OPEN get the
+        // envelope's original REQ frames instead.
+        {
+            let get_conn_neg = get_or_create_connection.clone();
+            let full_to_relay_neg = full_to_relay_sub_ids.clone();
+            let relay_to_full_neg = relay_to_full_sub_ids.clone();
+            let negentropy_sweep = negentropy.clone();
+            spawn_worker(async move {
+                loop {
+                    crate::platform::sleep(NEGENTROPY_SWEEP_MS).await;
+                    let expired = negentropy_sweep
+                        .borrow_mut()
+                        .expire(crate::platform::now_millis());
+                    for (relay, frames) in expired {
+                        let conn = get_conn_neg(&relay);
+                        for frame in frames {
+                            let relay_frame =
+                                encode_relay_frame(&frame, &full_to_relay_neg, &relay_to_full_neg);
+                            let _ = conn.send_raw(&relay_frame);
+                        }
+                    }
+                }
+            });
+        }
+
+        // Idle sweeper: relays left without subscriptions or pending publishes
+        // are disconnected; they reconnect on their next frame. Relays the
+        // policy no longer allows are dropped, and a lowered connection cap
+        // is enforced here.
+        {
+            let connections_idle = self.connections.clone();
+            let multiplexer_idle = multiplexer.clone();
+            let policy_idle = self.relay_policy.clone();
+            spawn_worker(async move {
+                loop {
+                    crate::platform::sleep(IDLE_SWEEP_MS).await;
+                    let now = crate::platform::now_millis();
+                    let connections: Vec<(String, Arc<RelayConnection>)> = connections_idle
+                        .read()
+                        .unwrap()
+                        .iter()
+                        .map(|(url, conn)| (url.clone(), conn.clone()))
+                        .collect();
+                    for (url, conn) in connections {
+                        if !policy_idle.allows(&url) {
+                            info!(relay = %url, "[ConnectionsWorker] closing relay denied by policy");
+                            let _ = conn.close();
+                            connections_idle.write().unwrap().remove(&url);
+                            multiplexer_idle.borrow_mut().remove_relay(&url);
+                            continue;
+                        }
+                        conn.close_if_idle(now);
+                    }
+                    let max_connections = policy_idle.max_connections();
+                    if max_connections > 0 {
+                        evict_idle_connections(&connections_idle, &multiplexer_idle, max_connections);
+                    }
+                }
+            });
+        }
+
+        // Budget sweeper: subscriptions that crossed their traffic budget are
+        // closed on every relay and the app gets a `budget-exceeded:` CLOSED
+        // per relay.
+        {
+            let get_conn_budget = get_or_create_connection.clone();
+            let full_to_relay_budget = full_to_relay_sub_ids.clone();
+            let relay_to_full_budget = relay_to_full_sub_ids.clone();
+            let sub_relays_budget = sub_relays.clone();
+            let sub_dedup_budget = sub_dedup.clone();
+            let negentropy_budget = negentropy.clone();
+            let multiplexer_budget = multiplexer.clone();
+            let policy_budget = self.relay_policy.clone();
+            let traffic_budget = self.traffic.clone();
+            spawn_worker(async move {
+                loop {
+                    crate::platform::sleep(TRAFFIC_SWEEP_MS).await;
+                    for sub_id in traffic_budget.take_exhausted() {
+                        info!(sub_id = %sub_id, "[ConnectionsWorker] closing subscription over its traffic budget");
+                        let close_frame = serde_json::json!(["CLOSE", sub_id]).to_string();
+                        let relays = close_subscription(
+                            &sub_id,
+                            &close_frame,
+                            &get_conn_budget,
+                            &full_to_relay_budget,
+                            &relay_to_full_budget,
+                            &sub_relays_budget,
+                            &sub_dedup_budget,
+                            &negentropy_budget,
+                            &multiplexer_budget,
+                            multiplex,
+                            &policy_budget,
+                            &traffic_budget,
+                        );
+                        let reason = format!(
+                            "{} subscription traffic budget used up",
+                            BUDGET_EXCEEDED_PREFIX
+                        );
+                        let closed = serde_json::json!(["CLOSED", sub_id, reason]).to_string();
+                        for relay in relays {
+                            get_conn_budget(&relay).notify(&sub_id, &closed);
+                        }
+                    }
+                }
+            });
+        }
+
+        // Auth sweeper: challenges parked for the app (`AuthMode::Ask`) are
+        // signed or declined once it answers or the ask times out.
+        {
+            let connections_auth = self.connections.clone();
+            spawn_worker(async move {
+                loop {
+                    crate::platform::sleep(AUTH_SWEEP_MS).await;
+                    let now = crate::platform::now_millis();
+                    let connections: Vec<Arc<RelayConnection>> =
+                        connections_auth.read().unwrap().values().cloned().collect();
+                    for conn in connections {
+                        conn.poll_auth(now);
+                    }
+                }
+            });
+        }
+
         // Loop for envelopes from cache (e.g. REQ frames)
         let get_conn_cache = get_or_create_connection.clone();
         let full_to_relay_cache = full_to_relay_sub_ids.clone();
         let relay_to_full_cache = relay_to_full_sub_ids.clone();
         let sub_relays_cache = sub_relays.clone();
         let sub_dedup_cache = sub_dedup.clone();
+        let negentropy_cache = negentropy.clone();
+        let multiplexer_cache = multiplexer.clone();
+        let policy_cache = self.relay_policy.clone();
+        let traffic_cache = self.traffic.clone();
         spawn_worker(async move {
             info!("[ConnectionsWorker] cache loop started");
             loop {
@@ -566,6 +1213,11 @@ impl ConnectionsWorker {
                             &relay_to_full_cache,
                             &sub_relays_cache,
                             &sub_dedup_cache,
+                            &negentropy_cache,
+                            &multiplexer_cache,
+                            multiplex,
+                            &policy_cache,
+                            &traffic_cache,
                         );
                     }
                     Err(_) => break,
@@ -581,6 +1233,10 @@ impl ConnectionsWorker {
         let relay_to_full_crypto = relay_to_full_sub_ids.clone();
         let sub_relays_crypto = sub_relays.clone();
         let sub_dedup_crypto = sub_dedup.clone();
+        let negentropy_crypto = negentropy.clone();
+        let multiplexer_crypto = multiplexer.clone();
+        let policy_crypto = self.relay_policy.clone();
+        let traffic_crypto = self.traffic.clone();
         spawn_worker(async move {
             info!("[ConnectionsWorker] crypto loop started");
             loop {
@@ -597,6 +1253,11 @@ impl ConnectionsWorker {
                                     &relay_to_full_crypto,
                                     &sub_relays_crypto,
                                     &sub_dedup_crypto,
+                                    &negentropy_crypto,
+                                    &multiplexer_crypto,
+                                    multiplex,
+                                    &policy_crypto,
+                                    &traffic_crypto,
                                 );
                                 continue;
                             }
@@ -835,6 +1496,11 @@ mod tests {
         fbb.finished_data().to_vec()
     }
 
+    /// Lets the multiplex sweeper send pending shared REQs.
+    async fn multiplex_tick() {
+        tokio::time::sleep(std::time::Duration::from_millis(3 * MULTIPLEX_SWEEP_MS)).await;
+    }
+
     async fn setup() -> (
         Arc<MockRelayTransport>,
         ConnectionsHandle,
@@ -969,6 +1635,7 @@ mod tests {
 					});
 					let bytes = serde_json::to_vec(&envelope).unwrap();
 					cache_test.send(&bytes).await.unwrap();
+					multiplex_tick().await;
 					tokio::task::yield_now().await;
 					tokio::task::yield_now().await;
 					tokio::task::yield_now().await;
@@ -979,13 +1646,104 @@ mod tests {
 						"connect was not called"
 					);
 					assert!(
-						calls.iter().any(|c| matches!(c, Call::Send(url, frame) if url == "wss://r" && frame == r#"["REQ","s1",{}]"#)),
+						calls.iter().any(|c| matches!(c, Call::Send(url, frame) if url == "wss://r" && frame == r#"["REQ","mx:1",{}]"#)),
 						"send was not called with correct frame"
 					);
 				})
 				.await;
     }
 
+    fn negentropy_envelope(items: &[(u64, String)]) -> Vec<u8> {
+        let envelope = serde_json::json!({
+            "relays": ["wss://r"],
+            "frames": [r#"["REQ","s1",{"kinds":[1],"limit":5}]"#],
+            "negentropy": { "sub_id": "s1", "filter": { "kinds": [1] }, "items": items },
+        });
+        serde_json::to_vec(&envelope).unwrap()
+    }
+
+    fn sent_frames(transport: &MockRelayTransport) -> Vec<String> {
+        transport
+            .calls()
+            .into_iter()
+            .filter_map(|c| match c {
+                Call::Send(url, frame) if url == "wss://r" => Some(frame),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_negentropy_envelope_fetches_only_missing_ids() {
+        use negentropy::{Id, Negentropy, NegentropyStorageVector};
+
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let (transport, _worker, _parser_test, _parser_out_test, cache_test, _crypto_test) =
+                    setup().await;
+
+                let have = hex::encode([1u8; 32]);
+                let missing = hex::encode([2u8; 32]);
+                cache_test
+                    .send(&negentropy_envelope(&[(10, have.clone())]))
+                    .await
+                    .unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+                let sent = sent_frames(&transport);
+                assert_eq!(sent.len(), 1, "only NEG-OPEN expected, got {:?}", sent);
+                let open: serde_json::Value = serde_json::from_str(&sent[0]).unwrap();
+                assert_eq!(open[0], "NEG-OPEN");
+                assert_eq!(open[2], serde_json::json!({ "kinds": [1] }));
+
+                let mut storage = NegentropyStorageVector::new();
+                storage.insert(10, Id::from_byte_array([1u8; 32])).unwrap();
+                storage.insert(20, Id::from_byte_array([2u8; 32])).unwrap();
+                storage.seal().unwrap();
+                let mut relay = Negentropy::owned(storage, 0).unwrap();
+                let reply = relay
+                    .reconcile(&hex::decode(open[3].as_str().unwrap()).unwrap())
+                    .unwrap();
+                transport.invoke_message_callback(
+                    "wss://r",
+                    serde_json::json!(["NEG-MSG", "s1", hex::encode(reply)]).to_string(),
+                );
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+                let sent = sent_frames(&transport);
+                assert_eq!(sent[1], r#"["NEG-CLOSE","s1"]"#);
+                let req: serde_json::Value = serde_json::from_str(&sent[2]).unwrap();
+                assert_eq!(req[0], "REQ");
+                assert_eq!(req[2], serde_json::json!({ "ids": [missing] }));
+                assert_eq!(req[3], serde_json::json!({ "kinds": [1], "limit": 0 }));
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_negentropy_neg_err_falls_back_to_req() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let (transport, _worker, _parser_test, _parser_out_test, cache_test, _crypto_test) =
+                    setup().await;
+
+                cache_test.send(&negentropy_envelope(&[])).await.unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                transport.invoke_message_callback(
+                    "wss://r",
+                    r#"["NEG-ERR","s1","blocked: negentropy disabled"]"#.to_string(),
+                );
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+                let sent = sent_frames(&transport);
+                assert_eq!(sent.len(), 2);
+                assert_eq!(sent[1], r#"["REQ","s1",{"kinds":[1],"limit":5}]"#);
+            })
+            .await;
+    }
+
     #[tokio::test]
     async fn test_parser_close_fans_out_to_subscription_relays() {
         let local = LocalSet::new();
@@ -1000,6 +1758,7 @@ mod tests {
 				});
 				let bytes = serde_json::to_vec(&envelope).unwrap();
 				cache_test.send(&bytes).await.unwrap();
+				multiplex_tick().await;
 				tokio::task::yield_now().await;
 				tokio::task::yield_now().await;
 				tokio::task::yield_now().await;
@@ -1014,13 +1773,13 @@ mod tests {
 				assert!(
 					calls
 						.iter()
-						.any(|c| matches!(c, Call::Send(url, frame) if url == "wss://r1" && frame == r#"["CLOSE","s1"]"#)),
+						.any(|c| matches!(c, Call::Send(url, frame) if url == "wss://r1" && frame == r#"["CLOSE","mx:1"]"#)),
 					"r1 did not receive CLOSE"
 				);
 				assert!(
 					calls
 						.iter()
-						.any(|c| matches!(c, Call::Send(url, frame) if url == "wss://r2" && frame == r#"["CLOSE","s1"]"#)),
+						.any(|c| matches!(c, Call::Send(url, frame) if url == "wss://r2" && frame == r#"["CLOSE","mx:2"]"#)),
 					"r2 did not receive CLOSE"
 				);
 			})
@@ -1067,7 +1826,7 @@ mod tests {
                         .filter(|call| matches!(
                             call,
                             Call::Send(url, frame)
-                                if url == "wss://r1" && frame == r#"["CLOSE","s1"]"#
+                                if url == "wss://r1" && frame == r#"["CLOSE","mx:1"]"#
                         ))
                         .count(),
                     1
@@ -1075,7 +1834,7 @@ mod tests {
                 assert!(!calls.iter().any(|call| matches!(
                     call,
                     Call::Send(url, frame)
-                        if url == "wss://r2" && frame == r#"["CLOSE","s1"]"#
+                        if url == "wss://r2" && frame == r#"["CLOSE","mx:2"]"#
                 )));
 
                 // Full cleanup should now fan out only to the remaining relay.
@@ -1092,7 +1851,7 @@ mod tests {
                         .filter(|call| matches!(
                             call,
                             Call::Send(url, frame)
-                                if url == "wss://r1" && frame == r#"["CLOSE","s1"]"#
+                                if url == "wss://r1" && frame == r#"["CLOSE","mx:1"]"#
                         ))
                         .count(),
                     1,
@@ -1104,7 +1863,7 @@ mod tests {
                         .filter(|call| matches!(
                             call,
                             Call::Send(url, frame)
-                                if url == "wss://r2" && frame == r#"["CLOSE","s1"]"#
+                                if url == "wss://r2" && frame == r#"["CLOSE","mx:2"]"#
                         ))
                         .count(),
                     1,
@@ -1141,13 +1900,14 @@ mod tests {
                 let (transport, handle, _parser_test, _parser_out_test, cache_test, _crypto_test) =
                     setup().await;
 
-                let req_frame = r#"["REQ","s1",{}]"#;
                 let envelope = serde_json::json!({
                     "relays": ["wss://r"],
-                    "frames": [req_frame]
+                    "frames": [r#"["REQ","s1",{}]"#]
                 });
+                let req_frame = r#"["REQ","mx:1",{}]"#;
                 let bytes = serde_json::to_vec(&envelope).unwrap();
                 cache_test.send(&bytes).await.unwrap();
+                multiplex_tick().await;
                 tokio::task::yield_now().await;
                 tokio::task::yield_now().await;
                 tokio::task::yield_now().await;
@@ -1441,6 +2201,7 @@ mod tests {
 					});
 					let bytes = serde_json::to_vec(&envelope).unwrap();
 					cache_test.send(&bytes).await.unwrap();
+					multiplex_tick().await;
 					tokio::task::yield_now().await;
 					tokio::task::yield_now().await;
 					tokio::task::yield_now().await;
@@ -1467,6 +2228,7 @@ mod tests {
 					});
 					let bytes2 = serde_json::to_vec(&envelope2).unwrap();
 					cache_test.send(&bytes2).await.unwrap();
+					multiplex_tick().await;
 					tokio::task::yield_now().await;
 					tokio::task::yield_now().await;
 
@@ -1475,7 +2237,7 @@ mod tests {
 					// but send should still work
 					let calls_after = transport.calls();
 					assert!(
-						calls_after.iter().any(|c| matches!(c, Call::Send(url, frame) if url == "wss://r1" && frame == r#"["REQ","s2",{}]"#)),
+						calls_after.iter().any(|c| matches!(c, Call::Send(url, frame) if url == "wss://r1" && frame == r#"["REQ","mx:2",{}]"#)),
 						"send was not called with new frame after reconnect"
 					);
 				})
@@ -1820,6 +2582,341 @@ mod tests {
             .await;
     }
 
+    #[tokio::test]
+    async fn test_traffic_is_attributed_and_budget_closes_subscription() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let (cache_test, cache_worker) = TokioWorkerChannel::new_pair();
+                let (_parser_test, parser_worker) = TokioWorkerChannel::new_pair();
+                let (parser_out_worker, mut parser_out_test) = TokioWorkerChannel::new_pair();
+                let (_crypto_test, crypto_worker) = TokioWorkerChannel::new_pair();
+                let crypto_sender = crypto_worker.clone_sender();
+                let transport = Arc::new(MockRelayTransport::new());
+                let traffic = TrafficStats::new();
+                traffic.set_budget(
+                    "s1",
+                    crate::traffic::TrafficBudget {
+                        max_events: Some(2),
+                        ..Default::default()
+                    },
+                );
+                let _handle = ConnectionsWorker::new(transport.clone())
+                    .with_traffic(traffic.clone())
+                    .run(
+                        Box::new(parser_worker),
+                        parser_out_worker.clone_sender(),
+                        Box::new(cache_worker),
+                        Box::new(crypto_worker),
+                        crypto_sender,
+                    );
+
+                let envelope = serde_json::json!({
+                    "relays": ["wss://r1", "wss://r2"],
+                    "frames": [r#"["REQ","s1",{}]"#]
+                });
+                cache_test.send(&serde_json::to_vec(&envelope).unwrap()).await.unwrap();
+                // The multiplex sweeper sends the REQs; wait for both under load.
+                for _ in 0..100 {
+                    if traffic
+                        .subscription("s1")
+                        .is_some_and(|sub| sub.counters.reqs_sent == 2)
+                    {
+                        break;
+                    }
+                    multiplex_tick().await;
+                }
+
+                let first = "01".repeat(32);
+                transport.invoke_message_callback("wss://r1", event_frame("s1", &first));
+                transport.invoke_message_callback("wss://r2", event_frame("s1", &first));
+                transport.invoke_message_callback("wss://r1", r#"["EOSE","s1"]"#.to_string());
+
+                let sub = traffic.subscription("s1").unwrap();
+                assert_eq!(sub.counters.reqs_sent, 2);
+                assert_eq!(sub.counters.events, 2);
+                assert_eq!(sub.counters.duplicates, 1);
+                assert_eq!(sub.counters.frames_in, 3);
+                assert_eq!(sub.relays["wss://r2"].duplicates, 1);
+                assert!(!sub.exhausted);
+                assert_eq!(traffic.relay("wss://r1").unwrap().reqs_sent, 1);
+
+                // The third EVENT crosses the budget: it is dropped and the
+                // sweeper closes the subscription on both relays.
+                transport.invoke_message_callback("wss://r2", event_frame("s1", &"02".repeat(32)));
+                assert!(traffic.is_exhausted("s1"));
+                tokio::time::sleep(std::time::Duration::from_millis(2 * TRAFFIC_SWEEP_MS)).await;
+
+                let closes: Vec<String> = transport
+                    .calls()
+                    .into_iter()
+                    .filter_map(|call| match call {
+                        Call::Send(url, frame) if frame.starts_with(r#"["CLOSE""#) => Some(url),
+                        _ => None,
+                    })
+                    .collect();
+                assert_eq!(closes.len(), 2, "{:?}", closes);
+
+                let mut pending = VecDeque::new();
+                while let Ok(Ok(bytes)) = tokio::time::timeout(
+                    std::time::Duration::from_millis(20),
+                    parser_out_test.recv(),
+                )
+                .await
+                {
+                    unwrap_parser_payload(&bytes, &mut pending);
+                }
+                let mut budget_closed = 0;
+                for frame in pending {
+                    match frame {
+                        ParserFrame::Raw(event) => {
+                            assert!(!event.contains(&"02".repeat(32)), "over-budget EVENT forwarded")
+                        }
+                        ParserFrame::Wm(bytes) => {
+                            let wm = flatbuffers::root::<fb::WorkerMessage>(&bytes).unwrap();
+                            if let Some(cs) = wm.content_as_connection_status() {
+                                if cs.status() == "CLOSED"
+                                    && cs.message().unwrap_or("").contains(BUDGET_EXCEEDED_PREFIX)
+                                {
+                                    budget_closed += 1;
+                                }
+                            }
+                        }
+                    }
+                }
+                assert_eq!(budget_closed, 2);
+                assert!(traffic.subscription("s1").is_some());
+
+                // The app's own CLOSE then drops the entry, and late frames
+                // from the relays do not bring it back.
+                let envelope = serde_json::json!({
+                    "relays": ["wss://r1", "wss://r2"],
+                    "frames": [r#"["CLOSE","s1"]"#]
+                });
+                cache_test.send(&serde_json::to_vec(&envelope).unwrap()).await.unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                assert!(traffic.subscription("s1").is_none());
+                transport.invoke_message_callback("wss://r1", event_frame("s1", &"03".repeat(32)));
+                assert!(traffic.subscription("s1").is_none());
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_multiplexing_opt_out_sends_one_req_per_subscription() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let (cache_test, cache_worker) = TokioWorkerChannel::new_pair();
+                let (_parser_test, parser_worker) = TokioWorkerChannel::new_pair();
+                let (parser_out_worker, _parser_out_test) = TokioWorkerChannel::new_pair();
+                let (_crypto_test, crypto_worker) = TokioWorkerChannel::new_pair();
+                let crypto_sender = crypto_worker.clone_sender();
+                let transport = Arc::new(MockRelayTransport::new());
+                let _handle = ConnectionsWorker::new(transport.clone())
+                    .with_multiplexing(false)
+                    .run(
+                        Box::new(parser_worker),
+                        parser_out_worker.clone_sender(),
+                        Box::new(cache_worker),
+                        Box::new(crypto_worker),
+                        crypto_sender,
+                    );
+
+                for sub_id in ["s1", "s2"] {
+                    let frame = serde_json::json!(["REQ", sub_id, {"kinds": [0]}]).to_string();
+                    let envelope = serde_json::json!({ "relays": ["wss://r"], "frames": [frame] });
+                    cache_test.send(&serde_json::to_vec(&envelope).unwrap()).await.unwrap();
+                }
+                multiplex_tick().await;
+
+                assert_eq!(
+                    sent_frames(&transport),
+                    vec![
+                        r#"["REQ","s1",{"kinds":[0]}]"#.to_string(),
+                        r#"["REQ","s2",{"kinds":[0]}]"#.to_string(),
+                    ]
+                );
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_relay_policy_modes_and_connection_cap() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let (cache_test, cache_worker) = TokioWorkerChannel::new_pair();
+                let (_parser_test, parser_worker) = TokioWorkerChannel::new_pair();
+                let (parser_out_worker, _parser_out_test) = TokioWorkerChannel::new_pair();
+                let (_crypto_test, crypto_worker) = TokioWorkerChannel::new_pair();
+                let crypto_sender = crypto_worker.clone_sender();
+                let transport = Arc::new(MockRelayTransport::new());
+                let policy = RelayPolicy::new(crate::relay_policy::RelayPolicyRules {
+                    max_connections: 2,
+                    modes: HashMap::from([(
+                        "wss://write-only".to_string(),
+                        crate::relay_policy::RelayMode::Write,
+                    )]),
+                    ..Default::default()
+                });
+                let handle = ConnectionsWorker::new(transport.clone())
+                    .with_relay_policy(policy)
+                    .run(
+                        Box::new(parser_worker),
+                        parser_out_worker.clone_sender(),
+                        Box::new(cache_worker),
+                        Box::new(crypto_worker),
+                        crypto_sender,
+                    );
+
+                let send = |relay: &str, frame: &str| {
+                    serde_json::to_vec(&serde_json::json!({ "relays": [relay], "frames": [frame] }))
+                        .unwrap()
+                };
+                let open_relays = || {
+                    let mut relays: Vec<String> =
+                        handle.connections.read().unwrap().keys().cloned().collect();
+                    relays.sort();
+                    relays
+                };
+
+                // Write-only and denied relays never get a REQ (or a socket).
+                cache_test.send(&send("wss://write-only", r#"["REQ","s0",{}]"#)).await.unwrap();
+                cache_test
+                    .send(&send("wss://wheat.happytavern.co", r#"["REQ","s0",{}]"#))
+                    .await
+                    .unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                assert!(transport.calls().is_empty());
+
+                // Busy relays are kept even past the cap.
+                cache_test.send(&send("wss://a", r#"["REQ","s1",{}]"#)).await.unwrap();
+                cache_test.send(&send("wss://b", r#"["REQ","s2",{}]"#)).await.unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                cache_test.send(&send("wss://c", r#"["REQ","s3",{}]"#)).await.unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                assert_eq!(open_relays(), ["wss://a", "wss://b", "wss://c"]);
+
+                // Once idle, the least recently used relay makes room.
+                cache_test.send(&send("wss://a", r#"["CLOSE","s1"]"#)).await.unwrap();
+                cache_test.send(&send("wss://b", r#"["CLOSE","s2"]"#)).await.unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                cache_test.send(&send("wss://d", r#"["REQ","s4",{}]"#)).await.unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                assert_eq!(open_relays(), ["wss://c", "wss://d"]);
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_compatible_subscriptions_share_one_req() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let (transport, _worker, parser_test, mut parser_out_test, cache_test, _crypto_test) =
+                    setup().await;
+
+                for (sub_id, author) in [("s1", "alice"), ("s2", "bob")] {
+                    let frame = serde_json::json!(["REQ", sub_id, {"kinds": [0], "authors": [author]}]);
+                    let envelope = serde_json::json!({
+                        "relays": ["wss://r"],
+                        "frames": [frame.to_string()]
+                    });
+                    cache_test.send(&serde_json::to_vec(&envelope).unwrap()).await.unwrap();
+                }
+                multiplex_tick().await;
+
+                let sent = sent_frames(&transport);
+                assert_eq!(sent.len(), 1, "subscriptions opened together share one REQ");
+                let shared: serde_json::Value = serde_json::from_str(&sent[0]).unwrap();
+                assert_eq!(shared[1], "mx:1");
+                assert_eq!(shared[2]["authors"], serde_json::json!(["alice", "bob"]));
+
+                // Events come back per member, by filter.
+                transport.invoke_message_callback(
+                    "wss://r",
+                    r#"["EVENT","mx:1",{"id":"ab","pubkey":"bob","kind":0,"content":"","tags":[],"created_at":1,"sig":"s"}]"#
+                        .to_string(),
+                );
+                let sub_id = loop {
+                    let bytes = tokio::time::timeout(
+                        std::time::Duration::from_millis(500),
+                        parser_out_test.recv(),
+                    )
+                    .await
+                    .expect("timed out waiting for EVENT batch")
+                    .unwrap();
+                    match decode_conn_batch(&bytes) {
+                        Some(batch) if batch.raw_events => break batch.frames[0].0.clone(),
+                        _ => continue,
+                    }
+                };
+                assert_eq!(sub_id, "s2");
+
+                // A leaving member narrows the shared REQ to live events.
+                transport.invoke_message_callback("wss://r", r#"["EOSE","mx:1"]"#.to_string());
+                parser_test
+                    .send(&build_raw_worker_message("wss://r", r#"["CLOSE","s1"]"#))
+                    .await
+                    .unwrap();
+                multiplex_tick().await;
+                let sent = sent_frames(&transport);
+                assert_eq!(sent.len(), 2);
+                assert_eq!(sent[1], r#"["REQ","mx:1",{"authors":["bob"],"kinds":[0],"limit":0}]"#);
+
+                parser_test
+                    .send(&build_raw_worker_message("wss://r", r#"["CLOSE","s2"]"#))
+                    .await
+                    .unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                assert_eq!(sent_frames(&transport).last().unwrap(), r#"["CLOSE","mx:1"]"#);
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_background_shared_req_goes_out_in_the_low_lane() {
+        let local = LocalSet::new();
+        local
+            .run_until(async {
+                let (transport, worker, _parser_test, _parser_out_test, cache_test, _crypto_test) =
+                    setup().await;
+                transport.set_connect_result(Err(TransportError::Other("not yet".to_string())));
+
+                // Queued first, but a prefetch never rides on the feed's shared REQ.
+                let subs = [("warm", "alice", true), ("feed", "bob", false)];
+                for (sub_id, author, background) in subs {
+                    let filter = serde_json::json!({"kinds": [0], "authors": [author]});
+                    let frame = serde_json::json!(["REQ", sub_id, filter]);
+                    let envelope = serde_json::json!({
+                        "relays": ["wss://r"],
+                        "frames": [frame.to_string()],
+                        "background": background
+                    });
+                    cache_test.send(&serde_json::to_vec(&envelope).unwrap()).await.unwrap();
+                }
+                multiplex_tick().await;
+                assert!(sent_frames(&transport).is_empty());
+
+                transport.set_connect_result(Ok(()));
+                worker.wake_all();
+                for _ in 0..8 {
+                    tokio::task::yield_now().await;
+                }
+
+                let authors: Vec<serde_json::Value> = sent_frames(&transport)
+                    .iter()
+                    .map(|frame| serde_json::from_str::<serde_json::Value>(frame).unwrap())
+                    .filter(|frame| frame[0] == "REQ")
+                    .map(|frame| frame[2]["authors"].clone())
+                    .collect();
+                let expected = [serde_json::json!(["bob"]), serde_json::json!(["alice"])];
+                assert_eq!(authors[..2], expected);
+            })
+            .await;
+    }
+
     #[tokio::test]
     async fn test_auth_event_full_flow() {
         let local = LocalSet::new();
@@ -1834,6 +2931,7 @@ mod tests {
 				});
 				let bytes = serde_json::to_vec(&envelope).unwrap();
 				cache_test.send(&bytes).await.unwrap();
+				multiplex_tick().await;
 
 				// Let workers run and connection establish
 				tokio::task::yield_now().await;
@@ -1906,7 +3004,7 @@ mod tests {
 
 				// Verify original REQ frame was sent
 				let req_sent = calls.iter().any(|c| {
-					matches!(c, Call::Send(url, frame) if url == "wss://r" && frame == r#"["REQ","s1",{}]"#)
+					matches!(c, Call::Send(url, frame) if url == "wss://r" && frame == r#"["REQ","mx:1",{}]"#)
 				});
 				assert!(req_sent, "expected original REQ frame to be sent");
 			})
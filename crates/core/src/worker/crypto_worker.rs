@@ -1,3 +1,4 @@
+use crate::auth_policy::AuthIdentity;
 use crate::channel::{ChannelError, MessageSender, WorkerChannel};
 #[cfg(feature = "crypto")]
 use crate::crypto::signers::nip46::{Nip46Config, Nip46Signer};
@@ -11,6 +12,7 @@ use crate::traits::Signer;
 use crate::types::nostr::{Event, Template};
 use futures::channel::mpsc;
 use futures::SinkExt;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{info, warn};
 
@@ -76,6 +78,56 @@ fn auth_payload_to_template(payload: &str) -> Result<(String, String), String> {
     Ok((template, relay.to_string()))
 }
 
+/// Identity an auth payload asks to sign with; the user's signer when absent.
+fn auth_payload_identity(payload: &str) -> AuthIdentity {
+    serde_json::from_str::<serde_json::Value>(payload)
+        .ok()
+        .and_then(|parsed| serde_json::from_value(parsed["identity"].clone()).ok())
+        .unwrap_or_default()
+}
+
+/// Signer for an AUTH event. Throwaway keys are generated on first use and
+/// reused for the relay until the worker stops.
+#[cfg(feature = "crypto")]
+fn auth_signer(
+    identity: AuthIdentity,
+    relay: &str,
+    active: &ActiveSigner,
+    ephemeral: &std::cell::RefCell<HashMap<String, ActiveSigner>>,
+) -> Result<ActiveSigner, String> {
+    match identity {
+        AuthIdentity::User => Ok(active.clone()),
+        AuthIdentity::Key { secret_key } => PrivateKeySigner::new(&secret_key)
+            .map(|signer| ActiveSigner::Pk(std::rc::Rc::new(signer)))
+            .map_err(|e| e.to_string()),
+        AuthIdentity::Ephemeral => {
+            let key = crate::utils::normalize_relay_url(relay);
+            if let Some(signer) = ephemeral.borrow().get(&key) {
+                return Ok(signer.clone());
+            }
+            let keys = crate::types::Keys::generate();
+            let signer = PrivateKeySigner::new(&keys.secret_key.to_hex())
+                .map(|signer| ActiveSigner::Pk(std::rc::Rc::new(signer)))
+                .map_err(|e| e.to_string())?;
+            ephemeral.borrow_mut().insert(key, signer.clone());
+            Ok(signer)
+        }
+    }
+}
+
+#[cfg(not(feature = "crypto"))]
+fn auth_signer(
+    identity: AuthIdentity,
+    _relay: &str,
+    active: &ActiveSigner,
+    _ephemeral: &std::cell::RefCell<HashMap<String, ActiveSigner>>,
+) -> Result<ActiveSigner, String> {
+    match identity {
+        AuthIdentity::User => Ok(active.clone()),
+        _ => Err("per-relay auth keys require the crypto feature".to_string()),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // ActiveSigner: enum that holds whichever signer is currently active.
 // Cloning the enum clones the *reference* (Rc/Arc) so the borrow on the
@@ -947,6 +999,7 @@ impl CryptoWorker {
         let to_connections_arc_connections = to_connections_arc.clone();
         spawn_worker(async move {
             info!("[CryptoWorker] connections listener started");
+            let ephemeral_auth_signers = std::cell::RefCell::new(HashMap::new());
             loop {
                 match from_connections.recv().await {
                     Ok(bytes) => {
@@ -989,10 +1042,23 @@ impl CryptoWorker {
                                         request_id,
                                         "[CryptoWorker][AUTH] Signing NIP-42 auth event"
                                     );
-                                    signer.sign_event(&template).await.map(|signed| {
-                                        serde_json::json!({"event": signed, "relay": relay_url})
-                                            .to_string()
-                                    })
+                                    match auth_signer(
+                                        auth_payload_identity(payload),
+                                        &relay_url,
+                                        &signer,
+                                        &ephemeral_auth_signers,
+                                    ) {
+                                        Ok(signer) => {
+                                            signer.sign_event(&template).await.map(|signed| {
+                                                serde_json::json!({
+                                                    "event": signed,
+                                                    "relay": relay_url
+                                                })
+                                                .to_string()
+                                            })
+                                        }
+                                        Err(e) => Err(e),
+                                    }
                                 }
                                 Err(e) => Err(e),
                             },
@@ -1341,6 +1407,19 @@ mod new_tests {
                 ["challenge", "challenge123"]
             ])
         );
+        assert_eq!(super::auth_payload_identity(&payload), AuthIdentity::User);
+
+        let throwaway = serde_json::json!({
+            "challenge": "challenge123",
+            "relay": "wss://relay.example",
+            "created_at": 12345,
+            "identity": {"type": "ephemeral"}
+        })
+        .to_string();
+        assert_eq!(
+            super::auth_payload_identity(&throwaway),
+            AuthIdentity::Ephemeral
+        );
     }
 
     #[tokio::test]
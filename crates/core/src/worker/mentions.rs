@@ -0,0 +1,727 @@
+//! MentionResolver for subscriptions opened with `resolve_mentions`.
+//!
+//! Every NIP-27 reference in a delivered event's parsed content (npub,
+//! nprofile, note, nevent, naddr) names a target: a profile, an event or an
+//! address. Targets nobody resolved yet are queued across all subscriptions
+//! and taken in batches, so references arriving together share one lookup.
+//! A batch is its own internal subscription: it asks the cache first and,
+//! at the cache's EOCE, asks the relays (plus the references' relay hints)
+//! for whatever the cache missed. Each target found becomes one MentionData,
+//! sent to every subscription waiting for it and kept for later references.
+//! Profiles and addresses are replaceable: until their batch finishes, a
+//! newer version replaces the one sent and is sent again, while older ones
+//! are ignored. A batch that found one on the relays therefore runs to its
+//! deadline. Targets still unresolved at the batch deadline count as misses
+//! and are not asked for again for a while.
+
+use crate::generated::nostr::fb;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// References arriving within this window share a batch.
+pub const MENTION_BATCH_DELAY_MS: u64 = 50;
+/// A full batch is taken without waiting for the delay.
+pub const MAX_MENTION_BATCH: usize = 100;
+/// A batch finishes this long after its last phase opened, whatever it
+/// found by then.
+pub const MENTION_FETCH_DEADLINE_MS: u64 = 3_000;
+/// A target that wasn't found is not looked up again for this long.
+pub const MENTION_MISS_TTL_MS: u64 = 300_000;
+/// Resolved targets kept for later references; the cache starts over when
+/// it fills up.
+const MAX_RESOLVED_MENTIONS: usize = 4_096;
+/// Batch subscriptions are named `mentions:<n>`, skipping ids in use.
+const BATCH_PREFIX: &str = "mentions:";
+
+/// What a reference points at, keyed like its NostrData `id`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MentionTarget {
+    /// A kind 0 profile, by pubkey (npub, nprofile).
+    Profile(String),
+    /// An event, by id (note, nevent).
+    Event(String),
+    /// An addressable event, by `kind:pubkey:d` (naddr).
+    Address(String),
+}
+
+impl MentionTarget {
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Profile(id) | Self::Event(id) | Self::Address(id) => id,
+        }
+    }
+
+    fn replaceable(&self) -> bool {
+        !matches!(self, Self::Event(_))
+    }
+}
+
+/// A reference from parsed content, with its relay hints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mention {
+    pub target: MentionTarget,
+    pub relays: Vec<String>,
+}
+
+/// References in a serialized ParsedEvent WorkerMessage, once per target,
+/// in order of appearance.
+pub fn mentions(worker_message: &[u8]) -> Vec<Mention> {
+    let Some(parsed) = flatbuffers::root::<fb::WorkerMessage>(worker_message)
+        .ok()
+        .and_then(|msg| msg.content_as_parsed_event())
+    else {
+        return Vec::new();
+    };
+
+    let mut seen = FxHashSet::default();
+    let mut found = Vec::new();
+    for block in crate::utils::content_blocks(&parsed) {
+        let Some(data) = block.data_as_nostr_data() else {
+            continue;
+        };
+        let id = data.id().to_string();
+        let target = match block.type_() {
+            "npub" | "nprofile" => MentionTarget::Profile(id),
+            "note" | "nevent" => MentionTarget::Event(id),
+            "naddr" => MentionTarget::Address(id),
+            _ => continue,
+        };
+        if target.id().is_empty() || !seen.insert(target.clone()) {
+            continue;
+        }
+        let relays = data
+            .relays()
+            .map(|r| r.iter().map(str::to_string).collect())
+            .unwrap_or_default();
+        found.push(Mention { target, relays });
+    }
+    found
+}
+
+/// Targets a serialized ParsedEvent WorkerMessage resolves, with the
+/// event itself.
+fn resolves<'a>(output: &'a [u8]) -> Option<(fb::ParsedEvent<'a>, Vec<MentionTarget>)> {
+    let event = flatbuffers::root::<fb::WorkerMessage>(output)
+        .ok()?
+        .content_as_parsed_event()?;
+    let mut targets = vec![MentionTarget::Event(event.id().to_string())];
+    if event.kind() == 0 {
+        targets.push(MentionTarget::Profile(event.pubkey().to_string()));
+    }
+    // Replaceable kinds are addressed with an empty `d`.
+    if matches!(event.kind(), 0 | 3 | 10000..=19999 | 30000..=39999) {
+        let d = event
+            .tags()
+            .iter()
+            .filter_map(|t| t.items())
+            .find(|t| t.len() >= 2 && t.get(0) == "d")
+            .map_or("", |t| t.get(1));
+        targets.push(MentionTarget::Address(format!(
+            "{}:{}:{}",
+            event.kind(),
+            event.pubkey(),
+            d
+        )));
+    }
+    Some((event, targets))
+}
+
+fn mention_data(target: &MentionTarget, event: &fb::ParsedEvent<'_>) -> fb::MentionDataT {
+    let mut data = fb::MentionDataT {
+        id: target.id().to_string(),
+        ..Default::default()
+    };
+    match target {
+        MentionTarget::Profile(_) => {
+            if let Some(profile) = event.parsed_as_kind_0_parsed() {
+                data.name = profile
+                    .display_name()
+                    .filter(|n| !n.is_empty())
+                    .or(profile.name())
+                    .map(str::to_string);
+                data.picture = profile.picture().map(str::to_string);
+            }
+        }
+        MentionTarget::Event(_) | MentionTarget::Address(_) => {
+            data.event = Some(Box::new(event.unpack()));
+        }
+    }
+    data
+}
+
+/// Lookups for `targets`: one request for all profiles, one for all events
+/// and one per address. With `hints`, the relay phase also asks each
+/// target's hinted relays.
+fn requests(
+    targets: &[&MentionTarget],
+    hints: Option<&FxHashMap<MentionTarget, Vec<String>>>,
+) -> Vec<fb::RequestT> {
+    let (cache_only, no_cache) = (hints.is_none(), hints.is_some());
+    let base = fb::RequestT {
+        cache_only,
+        no_cache,
+        ..Default::default()
+    };
+    let profile = |pubkeys: Vec<String>| fb::RequestT {
+        kinds: Some(vec![0]),
+        authors: Some(pubkeys),
+        ..base.clone()
+    };
+    let event = |ids: Vec<String>| fb::RequestT {
+        ids: Some(ids),
+        ..base.clone()
+    };
+    let address = |coord: &str| {
+        let mut parts = coord.splitn(3, ':');
+        let kind = parts.next()?.parse::<u16>().ok()?;
+        let pubkey = parts.next()?.to_string();
+        let d = parts.next().unwrap_or("").to_string();
+        Some(fb::RequestT {
+            kinds: Some(vec![kind]),
+            authors: Some(vec![pubkey]),
+            tags: Some(vec![fb::StringVecT {
+                items: Some(vec!["#d".to_string(), d]),
+            }]),
+            ..base.clone()
+        })
+    };
+
+    let mut out = Vec::new();
+    let (mut pubkeys, mut ids) = (Vec::new(), Vec::new());
+    for target in targets {
+        match target {
+            MentionTarget::Profile(pk) => pubkeys.push(pk.clone()),
+            MentionTarget::Event(id) => ids.push(id.clone()),
+            MentionTarget::Address(coord) => out.extend(address(coord)),
+        }
+    }
+    if !pubkeys.is_empty() {
+        out.push(profile(pubkeys));
+    }
+    if !ids.is_empty() {
+        out.push(event(ids));
+    }
+
+    // Each hinted target again, pinned to its hints.
+    for target in targets {
+        let Some(relays) = hints.and_then(|h| h.get(*target)).filter(|r| !r.is_empty()) else {
+            continue;
+        };
+        let pinned = match target {
+            MentionTarget::Profile(pk) => Some(profile(vec![pk.clone()])),
+            MentionTarget::Event(id) => Some(event(vec![id.clone()])),
+            MentionTarget::Address(coord) => address(coord),
+        };
+        out.extend(pinned.map(|r| fb::RequestT {
+            relays: Some(relays.clone()),
+            ..r
+        }));
+    }
+    out
+}
+
+struct Batch {
+    /// Targets the batch hasn't found yet.
+    targets: FxHashSet<MentionTarget>,
+    /// Replaceable targets found in the current phase, with the
+    /// `created_at` of the version sent.
+    newest: FxHashMap<MentionTarget, u32>,
+    opened_at: u64,
+    /// The relay phase was requested.
+    fetching: bool,
+}
+
+impl Batch {
+    /// Nothing is missing and no newer version can turn up.
+    fn done(&self, now: u64) -> bool {
+        (self.targets.is_empty() && self.newest.is_empty())
+            || now.saturating_sub(self.opened_at) >= MENTION_FETCH_DEADLINE_MS
+    }
+}
+
+#[derive(Default)]
+pub struct MentionResolver {
+    resolved: FxHashMap<MentionTarget, fb::MentionDataT>,
+    /// Targets not found, by when their batch gave up.
+    misses: FxHashMap<MentionTarget, u64>,
+    /// Subscriptions waiting for a target, while it is queued or in a batch.
+    waiters: FxHashMap<MentionTarget, FxHashSet<String>>,
+    hints: FxHashMap<MentionTarget, Vec<String>>,
+    queue: Vec<MentionTarget>,
+    queued_at: u64,
+    batches: FxHashMap<String, Batch>,
+    /// Subscription ids of the batches, until their subscription closed.
+    batch_ids: FxHashSet<String>,
+    opened: usize,
+}
+
+impl MentionResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_batch(&self, sub_id: &str) -> bool {
+        self.batch_ids.contains(sub_id)
+    }
+
+    /// The batch's subscription was released; its id may be reused.
+    pub fn closed(&mut self, sub_id: &str) {
+        self.batch_ids.remove(sub_id);
+    }
+
+    /// Register `sub_id`'s interest in `mentions`. Returns what is already
+    /// resolved; the rest is delivered by `offer` once a batch finds it.
+    pub fn request(
+        &mut self,
+        sub_id: &str,
+        mentions: Vec<Mention>,
+        now: u64,
+    ) -> Vec<fb::MentionDataT> {
+        let mut ready = Vec::new();
+        for Mention { target, relays } in mentions {
+            if let Some(data) = self.resolved.get(&target) {
+                ready.push(data.clone());
+                // A batch still looking for a newer version sends it too.
+                if let Some(waiters) = self.waiters.get_mut(&target) {
+                    waiters.insert(sub_id.to_string());
+                }
+                continue;
+            }
+            if self
+                .misses
+                .get(&target)
+                .is_some_and(|at| now.saturating_sub(*at) < MENTION_MISS_TTL_MS)
+            {
+                continue;
+            }
+
+            let hints = self.hints.entry(target.clone()).or_default();
+            for relay in relays {
+                if !hints.contains(&relay) {
+                    hints.push(relay);
+                }
+            }
+            let waiters = self.waiters.entry(target.clone()).or_default();
+            // Already queued or in a batch: just wait for it too.
+            if waiters.is_empty() {
+                if self.queue.is_empty() {
+                    self.queued_at = now;
+                }
+                self.queue.push(target);
+            }
+            waiters.insert(sub_id.to_string());
+        }
+        ready
+    }
+
+    /// Open the next batch once the queue waited long enough or filled a
+    /// batch: its subscription id, one `in_use` doesn't know, and cache
+    /// lookups.
+    pub fn take_batch(
+        &mut self,
+        now: u64,
+        in_use: impl Fn(&str) -> bool,
+    ) -> Option<(String, Vec<fb::RequestT>)> {
+        let due = now.saturating_sub(self.queued_at) >= MENTION_BATCH_DELAY_MS
+            || self.queue.len() >= MAX_MENTION_BATCH;
+        if self.queue.is_empty() || !due {
+            return None;
+        }
+        let rest = self
+            .queue
+            .split_off(self.queue.len().min(MAX_MENTION_BATCH));
+        let targets = std::mem::replace(&mut self.queue, rest);
+        self.queued_at = now;
+
+        let sub_id = loop {
+            let sub_id = format!("{}{}", BATCH_PREFIX, self.opened);
+            self.opened += 1;
+            if !in_use(&sub_id) && !self.batch_ids.contains(&sub_id) {
+                break sub_id;
+            }
+        };
+        self.batch_ids.insert(sub_id.clone());
+        let lookups = requests(&targets.iter().collect::<Vec<_>>(), None);
+        self.batches.insert(
+            sub_id.clone(),
+            Batch {
+                targets: targets.into_iter().collect(),
+                newest: FxHashMap::default(),
+                opened_at: now,
+                fetching: false,
+            },
+        );
+        Some((sub_id, lookups))
+    }
+
+    /// The cache finished a batch's lookups: the relay fetches for what it
+    /// missed, once. Empty when there is nothing (left) to fetch. What the
+    /// cache found is final, as the relays aren't asked for it.
+    pub fn on_eoce(&mut self, sub_id: &str, now: u64) -> Vec<fb::RequestT> {
+        let Some(batch) = self.batches.get_mut(sub_id).filter(|b| !b.fetching) else {
+            return Vec::new();
+        };
+        for (target, _) in batch.newest.drain() {
+            self.waiters.remove(&target);
+        }
+        batch.fetching = true;
+        batch.opened_at = now;
+        let targets: Vec<_> = batch.targets.iter().collect();
+        requests(&targets, Some(&self.hints))
+    }
+
+    /// An event the batch `sub_id` received. Returns each target it
+    /// resolves, or resolves to a newer version, with the subscriptions
+    /// waiting for it.
+    pub fn offer(
+        &mut self,
+        sub_id: &str,
+        output: &[u8],
+    ) -> Vec<(fb::MentionDataT, FxHashSet<String>)> {
+        let Some(batch) = self.batches.get_mut(sub_id) else {
+            return Vec::new();
+        };
+        let Some((event, targets)) = resolves(output) else {
+            return Vec::new();
+        };
+
+        let mut delivered = Vec::new();
+        for target in targets {
+            let found = batch.targets.remove(&target);
+            let newer = batch
+                .newest
+                .get(&target)
+                .is_some_and(|at| event.created_at() > *at);
+            if !found && !newer {
+                continue;
+            }
+            let data = mention_data(&target, &event);
+            if self.resolved.len() >= MAX_RESOLVED_MENTIONS {
+                self.resolved.clear();
+            }
+            self.resolved.insert(target.clone(), data.clone());
+            self.hints.remove(&target);
+            // Replaceable targets keep their waiters for newer versions.
+            let waiters = if target.replaceable() {
+                batch.newest.insert(target.clone(), event.created_at());
+                self.waiters.get(&target).cloned()
+            } else {
+                self.waiters.remove(&target)
+            };
+            delivered.push((data, waiters.unwrap_or_default()));
+        }
+        delivered
+    }
+
+    /// Close batches that found everything or ran past the deadline,
+    /// recording what they missed. Returns their subscription ids, to be
+    /// released and then `closed`.
+    pub fn finished(&mut self, now: u64) -> Vec<String> {
+        let done: Vec<String> = self
+            .batches
+            .iter()
+            .filter(|(_, b)| b.done(now))
+            .map(|(id, _)| id.clone())
+            .collect();
+        if done.is_empty() {
+            return done;
+        }
+
+        for id in &done {
+            let Some(batch) = self.batches.remove(id) else {
+                continue;
+            };
+            for target in batch.newest.into_keys() {
+                self.waiters.remove(&target);
+            }
+            for target in batch.targets {
+                self.waiters.remove(&target);
+                self.hints.remove(&target);
+                self.misses.insert(target, now);
+            }
+        }
+        self.misses
+            .retain(|_, at| now.saturating_sub(*at) < MENTION_MISS_TTL_MS);
+        done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flatbuffers::FlatBufferBuilder;
+
+    const PK: &str = "aa";
+    const NOTE: &str = "bb";
+    const ADDR: &str = "30023:cc:post";
+
+    fn nostr_block(type_: &str, id: &str, relays: &[&str]) -> fb::ContentBlockT {
+        fb::ContentBlockT {
+            type_: type_.to_string(),
+            text: format!("nostr:{type_}1..."),
+            data: fb::ContentDataT::NostrData(Box::new(fb::NostrDataT {
+                id: id.to_string(),
+                entity: format!("{type_}1..."),
+                relays: Some(relays.iter().map(|r| r.to_string()).collect()),
+                ..Default::default()
+            })),
+        }
+    }
+
+    fn message(event: fb::ParsedEventT) -> Vec<u8> {
+        let mut builder = FlatBufferBuilder::new();
+        let wm = fb::WorkerMessageT {
+            type_: fb::MessageType::ParsedNostrEvent,
+            content: fb::MessageT::ParsedEvent(Box::new(event)),
+            ..Default::default()
+        }
+        .pack(&mut builder);
+        builder.finish(wm, None);
+        builder.finished_data().to_vec()
+    }
+
+    fn note(content: Vec<fb::ContentBlockT>) -> Vec<u8> {
+        message(fb::ParsedEventT {
+            id: "mentioning".to_string(),
+            pubkey: "author".to_string(),
+            kind: 1,
+            parsed: fb::ParsedDataT::Kind1Parsed(Box::new(fb::Kind1ParsedT {
+                parsed_content: content,
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+
+    fn profile(pubkey: &str, name: Option<&str>, display_name: Option<&str>) -> Vec<u8> {
+        message(fb::ParsedEventT {
+            id: format!("{pubkey}-profile"),
+            pubkey: pubkey.to_string(),
+            kind: 0,
+            parsed: fb::ParsedDataT::Kind0Parsed(Box::new(fb::Kind0ParsedT {
+                name: name.map(str::to_string),
+                display_name: display_name.map(str::to_string),
+                picture: Some("https://example.com/a.png".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+
+    fn event(id: &str, kind: u16, pubkey: &str, d: Option<&str>) -> Vec<u8> {
+        message(fb::ParsedEventT {
+            id: id.to_string(),
+            pubkey: pubkey.to_string(),
+            kind,
+            tags: d
+                .map(|d| fb::StringVecT {
+                    items: Some(vec!["d".to_string(), d.to_string()]),
+                })
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    fn mention(target: MentionTarget, relays: &[&str]) -> Mention {
+        Mention {
+            target,
+            relays: relays.iter().map(|r| r.to_string()).collect(),
+        }
+    }
+
+    fn subs(ids: &[&str]) -> FxHashSet<String> {
+        ids.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn free(_: &str) -> bool {
+        false
+    }
+
+    fn dated(output: Vec<u8>, created_at: u32) -> Vec<u8> {
+        let wm = flatbuffers::root::<fb::WorkerMessage>(&output).unwrap();
+        let mut event = wm.content_as_parsed_event().unwrap().unpack();
+        event.created_at = created_at;
+        message(event)
+    }
+
+    #[test]
+    fn reads_references_once_in_order() {
+        let found = mentions(&note(vec![
+            nostr_block("nevent", NOTE, &["wss://hint.example"]),
+            fb::ContentBlockT {
+                type_: "text".to_string(),
+                text: " and ".to_string(),
+                ..Default::default()
+            },
+            nostr_block("npub", PK, &[]),
+            nostr_block("note", NOTE, &[]),
+            nostr_block("naddr", ADDR, &[]),
+        ]));
+        assert_eq!(
+            found,
+            vec![
+                mention(MentionTarget::Event(NOTE.into()), &["wss://hint.example"]),
+                mention(MentionTarget::Profile(PK.into()), &[]),
+                mention(MentionTarget::Address(ADDR.into()), &[]),
+            ]
+        );
+        assert!(mentions(&profile(PK, None, None)).is_empty());
+    }
+
+    #[test]
+    fn batches_coalesce_and_ask_cache_then_relays() {
+        let mut resolver = MentionResolver::new();
+        let profile_ref = mention(MentionTarget::Profile(PK.into()), &[]);
+        let note_ref = mention(MentionTarget::Event(NOTE.into()), &["wss://hint.example"]);
+        assert!(resolver
+            .request("a", vec![profile_ref.clone(), note_ref.clone()], 0)
+            .is_empty());
+        assert!(resolver.request("b", vec![note_ref], 10).is_empty());
+
+        // Waits out the delay, then takes both targets in one batch.
+        assert!(resolver.take_batch(MENTION_BATCH_DELAY_MS - 1, free).is_none());
+        let (batch, lookups) = resolver.take_batch(MENTION_BATCH_DELAY_MS, free).unwrap();
+        assert_eq!(batch, "mentions:0");
+        assert!(resolver.is_batch(&batch));
+        assert_eq!(lookups.len(), 2);
+        assert!(lookups.iter().all(|r| r.cache_only && r.relays.is_none()));
+        assert_eq!(lookups[0].kinds, Some(vec![0]));
+        assert_eq!(lookups[0].authors, Some(vec![PK.to_string()]));
+        assert_eq!(lookups[1].ids, Some(vec![NOTE.to_string()]));
+
+        // A target already in a batch isn't queued again.
+        assert!(resolver.request("c", vec![profile_ref], 60).is_empty());
+        assert!(resolver.take_batch(1_000, free).is_none());
+
+        // The cache had the profile; the relays are asked for the note,
+        // also on its hinted relay.
+        let delivered = resolver.offer(&batch, &profile(PK, Some("alice"), Some("")));
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0].0.id, PK);
+        assert_eq!(delivered[0].0.name.as_deref(), Some("alice"));
+        assert_eq!(delivered[0].1, subs(&["a", "c"]));
+
+        let fetches = resolver.on_eoce(&batch, 100);
+        assert_eq!(fetches.len(), 2);
+        assert!(fetches.iter().all(|r| r.no_cache && !r.cache_only));
+        assert_eq!(fetches[0].ids, Some(vec![NOTE.to_string()]));
+        assert_eq!(
+            fetches[1].relays,
+            Some(vec!["wss://hint.example".to_string()])
+        );
+        // The relay phase's own EOCE asks for nothing more.
+        assert!(resolver.on_eoce(&batch, 120).is_empty());
+
+        let delivered = resolver.offer(&batch, &event(NOTE, 1, "author", None));
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0].0.event.as_ref().unwrap().id, NOTE);
+        assert_eq!(delivered[0].1, subs(&["a", "b"]));
+        assert_eq!(resolver.finished(130), vec![batch]);
+
+        // Later references are answered from what was resolved.
+        let ready = resolver.request(
+            "d",
+            vec![mention(MentionTarget::Profile(PK.into()), &[])],
+            200,
+        );
+        assert_eq!(ready.len(), 1);
+        assert_eq!(
+            ready[0].picture.as_deref(),
+            Some("https://example.com/a.png")
+        );
+        assert!(resolver.take_batch(1_000, free).is_none());
+    }
+
+    #[test]
+    fn resolves_addresses_and_remembers_misses() {
+        let mut resolver = MentionResolver::new();
+        let addr_ref = mention(MentionTarget::Address(ADDR.into()), &[]);
+        let gone = mention(MentionTarget::Event("gone".into()), &[]);
+        resolver.request("a", vec![addr_ref, gone.clone()], 0);
+        let (batch, lookups) = resolver.take_batch(MENTION_BATCH_DELAY_MS, free).unwrap();
+        let by_address = &lookups[0];
+        assert_eq!(by_address.kinds, Some(vec![30023]));
+        assert_eq!(by_address.authors, Some(vec!["cc".to_string()]));
+        assert_eq!(
+            by_address.tags.as_ref().unwrap()[0].items,
+            Some(vec!["#d".to_string(), "post".to_string()])
+        );
+
+        // Another address of the same author doesn't match.
+        assert!(resolver
+            .offer(&batch, &event("other", 30023, "cc", Some("draft")))
+            .is_empty());
+        let delivered = resolver.offer(&batch, &event("post-id", 30023, "cc", Some("post")));
+        assert_eq!(delivered[0].0.id, ADDR);
+        assert_eq!(delivered[0].0.event.as_ref().unwrap().id, "post-id");
+
+        resolver.on_eoce(&batch, 100);
+        assert!(resolver
+            .finished(100 + MENTION_FETCH_DEADLINE_MS - 1)
+            .is_empty());
+        assert_eq!(
+            resolver.finished(100 + MENTION_FETCH_DEADLINE_MS),
+            vec![batch]
+        );
+
+        // The miss isn't looked up again until its TTL ran out.
+        let now = 100 + MENTION_FETCH_DEADLINE_MS;
+        resolver.request("b", vec![gone.clone()], now + 1);
+        assert!(resolver.take_batch(now + 1_000, free).is_none());
+        resolver.request("b", vec![gone], now + MENTION_MISS_TTL_MS);
+        assert!(resolver
+            .take_batch(now + MENTION_MISS_TTL_MS + MENTION_BATCH_DELAY_MS, free)
+            .is_some());
+    }
+
+    #[test]
+    fn batch_ids_skip_ids_in_use() {
+        let mut resolver = MentionResolver::new();
+        resolver.request("a", vec![mention(MentionTarget::Event(NOTE.into()), &[])], 0);
+        let (batch, _) = resolver
+            .take_batch(MENTION_BATCH_DELAY_MS, |id| id == "mentions:0")
+            .unwrap();
+        assert_eq!(batch, "mentions:1");
+        // A user subscription named like a batch isn't one.
+        assert!(!resolver.is_batch("mentions:0"));
+        assert!(resolver.is_batch(&batch));
+
+        resolver.offer(&batch, &event(NOTE, 1, "author", None));
+        assert_eq!(resolver.finished(100), vec![batch.clone()]);
+        // Events still in flight go to the batch until it is closed.
+        assert!(resolver.is_batch(&batch));
+        resolver.closed(&batch);
+        assert!(!resolver.is_batch(&batch));
+    }
+
+    #[test]
+    fn replaceable_targets_keep_the_newest_version() {
+        let mut resolver = MentionResolver::new();
+        let profile_ref = mention(MentionTarget::Profile(PK.into()), &[]);
+        resolver.request("a", vec![profile_ref.clone()], 0);
+        let (batch, _) = resolver.take_batch(MENTION_BATCH_DELAY_MS, free).unwrap();
+        // The cache had nothing; the relays answer with several versions.
+        assert_eq!(resolver.on_eoce(&batch, 100).len(), 1);
+
+        let delivered = resolver.offer(&batch, &dated(profile(PK, Some("new"), None), 20));
+        assert_eq!(delivered[0].0.name.as_deref(), Some("new"));
+        assert!(resolver
+            .offer(&batch, &dated(profile(PK, Some("old"), None), 10))
+            .is_empty());
+
+        // Later references get the version so far and any newer one.
+        let ready = resolver.request("b", vec![profile_ref.clone()], 150);
+        assert_eq!(ready[0].name.as_deref(), Some("new"));
+        let delivered = resolver.offer(&batch, &dated(profile(PK, Some("newest"), None), 30));
+        assert_eq!(delivered[0].0.name.as_deref(), Some("newest"));
+        assert_eq!(delivered[0].1, subs(&["a", "b"]));
+
+        // Another relay may still hold a newer one until the deadline.
+        assert!(resolver.finished(200).is_empty());
+        let deadline = 100 + MENTION_FETCH_DEADLINE_MS;
+        assert_eq!(resolver.finished(deadline), vec![batch]);
+        let ready = resolver.request("c", vec![profile_ref], deadline + 1);
+        assert_eq!(ready[0].name.as_deref(), Some("newest"));
+        assert!(resolver.take_batch(deadline + 1_000, free).is_none());
+    }
+}
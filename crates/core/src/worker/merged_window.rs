@@ -0,0 +1,320 @@
+//! MergedWindow for subscriptions opened with `merged_window: true`.
+//!
+//! A subscription with `limit: 50` fanned out to several relays plus the
+//! cache can otherwise deliver up to `50 * (relays + 1)` events in arrival
+//! order. In merged-window mode the parser worker holds every event output
+//! of the subscription until the window closes, then emits the newest
+//! `limit` events across all sources in one stable order.
+//!
+//! Window lifecycle (per subscription):
+//! - Buffering: event outputs are held; non-event outputs (counters, proof
+//!   results, ...) pass straight through.
+//! - Close: once the cache reported EOCE and every relay the subscription
+//!   was sent to reported EOSE (or the cache alone for cache-only
+//!   subscriptions), or once the deadline expires. Held events are sorted
+//!   by `created_at` desc with an ascending id tie-break (NIP-01 order) and
+//!   truncated to the limit.
+//! - Closed: events created after the window opened are live and pass
+//!   through. Older stragglers from relays that missed the deadline are
+//!   emitted while fewer than `limit` events went out, and count towards
+//!   it; once the window is full, only those that sort at or above its
+//!   oldest emitted event still go out.
+
+use crate::generated::nostr::fb;
+use rustc_hash::FxHashSet;
+
+/// Close the window after this long even if the cache or every relay is
+/// still silent.
+pub const MERGED_WINDOW_DEADLINE_MS: u64 = 3_000;
+
+/// Sort key of an event output: (created_at, id).
+type EventKey = (i64, String);
+
+/// Extract the sort key from a serialized ParsedEvent/NostrEvent WorkerMessage.
+fn event_key(output: &[u8]) -> Option<EventKey> {
+    let wm = flatbuffers::root::<fb::WorkerMessage>(output).ok()?;
+    match wm.content_type() {
+        fb::Message::ParsedEvent => {
+            let ev = wm.content_as_parsed_event()?;
+            Some((ev.created_at() as i64, ev.id().to_string()))
+        }
+        fb::Message::NostrEvent => {
+            let ev = wm.content_as_nostr_event()?;
+            Some((ev.created_at() as i64, ev.id().to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// NIP-01 order: `created_at` desc with an ascending id tie-break.
+fn nip01_order(a: &EventKey, b: &EventKey) -> std::cmp::Ordering {
+    b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1))
+}
+
+fn sorts_after(key: &EventKey, last: &EventKey) -> bool {
+    nip01_order(key, last) == std::cmp::Ordering::Greater
+}
+
+pub struct MergedWindow {
+    /// Maximum number of events in the window; `None` merges without truncating.
+    limit: Option<usize>,
+    opened_at: u64,
+    /// Cache-only subscriptions never see a relay EOSE.
+    needs_relay_eose: bool,
+    cache_done: bool,
+    /// Relays the subscription's REQ went to, and those that sent EOSE.
+    relays: FxHashSet<String>,
+    relays_eosed: FxHashSet<String>,
+    closed: bool,
+    held: Vec<(EventKey, Vec<u8>)>,
+    /// Whether the EOCE marker arrived while buffering and still has to be
+    /// emitted after the merged events.
+    eoce_pending: bool,
+    /// Events forwarded from the window's stored phase, merged or late.
+    emitted: usize,
+    /// Oldest of those in NIP-01 order; full windows still take stragglers
+    /// that sort at or above it.
+    last: Option<EventKey>,
+}
+
+impl MergedWindow {
+    pub fn new(limit: Option<usize>, needs_relay_eose: bool, now: u64) -> Self {
+        Self {
+            limit,
+            opened_at: now,
+            needs_relay_eose,
+            cache_done: false,
+            relays: FxHashSet::default(),
+            relays_eosed: FxHashSet::default(),
+            closed: false,
+            held: Vec::new(),
+            eoce_pending: false,
+            emitted: 0,
+            last: None,
+        }
+    }
+
+    /// Offer a pipeline output. Returns the bytes to forward now, or `None`
+    /// when the output is held (buffering) or falls outside the window.
+    pub fn offer(&mut self, output: Vec<u8>) -> Option<Vec<u8>> {
+        let Some(key) = event_key(&output) else {
+            return Some(output);
+        };
+        if !self.closed {
+            self.held.push((key, output));
+            return None;
+        }
+        if key.0 >= (self.opened_at / 1000) as i64 {
+            return Some(output);
+        }
+        let inside = self.last.as_ref().is_some_and(|last| !sorts_after(&key, last));
+        if !inside {
+            if self.limit.is_some_and(|limit| self.emitted >= limit) {
+                return None;
+            }
+            self.last = Some(key);
+        }
+        self.emitted += 1;
+        Some(output)
+    }
+
+    /// Record that the subscription's REQ went out to `relay`.
+    pub fn on_subscribed(&mut self, relay: &str) {
+        if !self.closed && !relay.is_empty() {
+            self.relays.insert(relay.to_string());
+        }
+    }
+
+    /// Record EOCE. Returns `None` once the window is closed (the marker goes
+    /// out as usual), otherwise whether the window is ready to close; the
+    /// marker is held until it does.
+    pub fn on_eoce(&mut self) -> Option<bool> {
+        if self.closed {
+            return None;
+        }
+        self.cache_done = true;
+        self.eoce_pending = true;
+        Some(self.ready())
+    }
+
+    /// Record an EOSE from `relay`. Returns true when the window is ready
+    /// to close.
+    pub fn on_eose(&mut self, relay: &str) -> bool {
+        if self.closed {
+            return false;
+        }
+        self.relays.insert(relay.to_string());
+        self.relays_eosed.insert(relay.to_string());
+        self.ready()
+    }
+
+    fn ready(&self) -> bool {
+        self.cache_done
+            && (!self.needs_relay_eose
+                || (!self.relays_eosed.is_empty() && self.relays_eosed.len() == self.relays.len()))
+    }
+
+    pub fn expired(&self, now: u64) -> bool {
+        !self.closed && now.saturating_sub(self.opened_at) >= MERGED_WINDOW_DEADLINE_MS
+    }
+
+    /// Close the window: returns the merged, truncated events and whether the
+    /// held EOCE marker must follow them.
+    pub fn close(&mut self) -> (Vec<Vec<u8>>, bool) {
+        if self.closed {
+            return (Vec::new(), false);
+        }
+        self.closed = true;
+        let mut held = std::mem::take(&mut self.held);
+        held.sort_by(|a, b| nip01_order(&a.0, &b.0));
+        held.dedup_by(|a, b| a.0 .1 == b.0 .1);
+        if let Some(limit) = self.limit {
+            held.truncate(limit);
+        }
+        self.emitted = held.len();
+        self.last = held.last().map(|(key, _)| key.clone());
+        let eoce = std::mem::take(&mut self.eoce_pending);
+        (held.into_iter().map(|(_, bytes)| bytes).collect(), eoce)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flatbuffers::FlatBufferBuilder;
+
+    fn event(id: &str, created_at: i32) -> Vec<u8> {
+        let mut builder = FlatBufferBuilder::new();
+        let id = builder.create_string(id);
+        let pubkey = builder.create_string("pk");
+        let content = builder.create_string("");
+        let sig = builder.create_string("sig");
+        let tags = builder.create_vector::<flatbuffers::WIPOffset<fb::StringVec>>(&[]);
+        let ev = fb::NostrEvent::create(
+            &mut builder,
+            &fb::NostrEventArgs {
+                id: Some(id),
+                pubkey: Some(pubkey),
+                kind: 1,
+                content: Some(content),
+                tags: Some(tags),
+                created_at,
+                sig: Some(sig),
+            },
+        );
+        let wm = fb::WorkerMessage::create(
+            &mut builder,
+            &fb::WorkerMessageArgs {
+                sub_id: None,
+                url: None,
+                type_: fb::MessageType::NostrEvent,
+                content_type: fb::Message::NostrEvent,
+                content: Some(ev.as_union_value()),
+            },
+        );
+        builder.finish(wm, None);
+        builder.finished_data().to_vec()
+    }
+
+    /// Opening time of the test windows: 1000s, after every test event.
+    const NOW: u64 = 1_000_000;
+
+    fn ids(outputs: &[Vec<u8>]) -> Vec<String> {
+        outputs.iter().map(|o| event_key(o).unwrap().1).collect()
+    }
+
+    #[test]
+    fn close_merges_sorts_and_truncates() {
+        let mut window = MergedWindow::new(Some(3), true, NOW);
+        for (id, at) in [("c", 10), ("a", 30), ("e", 20), ("b", 20), ("d", 5)] {
+            assert!(window.offer(event(id, at)).is_none());
+        }
+        assert_eq!(window.on_eoce(), Some(false));
+        assert!(window.on_eose("wss://a"));
+        let (outputs, eoce) = window.close();
+        assert_eq!(ids(&outputs), vec!["a", "b", "e"]);
+        assert!(eoce);
+    }
+
+    #[test]
+    fn waits_for_every_relay_the_req_went_to() {
+        let mut window = MergedWindow::new(Some(3), true, NOW);
+        window.on_subscribed("wss://a");
+        window.on_subscribed("wss://b");
+        assert_eq!(window.on_eoce(), Some(false));
+        assert!(!window.on_eose("wss://a"));
+        assert!(!window.on_eose("wss://a"));
+        assert!(window.on_eose("wss://b"));
+    }
+
+    #[test]
+    fn full_windows_only_take_stragglers_inside_them() {
+        let mut window = MergedWindow::new(Some(2), true, NOW);
+        window.offer(event("a", 30));
+        window.offer(event("b", 20));
+        window.close();
+        assert!(window.offer(event("old", 10)).is_none());
+        assert!(window.offer(event("newer", 25)).is_some());
+        // Ties on created_at sort by id.
+        assert!(window.offer(event("aa", 20)).is_some());
+        assert!(window.offer(event("c", 20)).is_none());
+    }
+
+    #[test]
+    fn stragglers_fill_an_unfilled_window() {
+        let mut window = MergedWindow::new(Some(3), true, NOW);
+        window.offer(event("a", 30));
+        window.close();
+        assert!(window.offer(event("b", 10)).is_some());
+        assert!(window.offer(event("c", 40)).is_some());
+        assert!(window.offer(event("d", 1)).is_none());
+        // b is now the oldest emitted event.
+        assert!(window.offer(event("e", 15)).is_some());
+    }
+
+    #[test]
+    fn live_events_pass_after_close() {
+        let mut window = MergedWindow::new(Some(1), true, NOW);
+        window.offer(event("a", 30));
+        window.close();
+        let live = (NOW / 1000) as i32;
+        assert!(window.offer(event("live1", live)).is_some());
+        assert!(window.offer(event("live2", live + 5)).is_some());
+    }
+
+    #[test]
+    fn cache_only_closes_on_eoce_and_deadline_expires() {
+        let mut window = MergedWindow::new(None, false, 100);
+        assert_eq!(window.on_eoce(), Some(true));
+        assert!(!window.expired(100 + MERGED_WINDOW_DEADLINE_MS - 1));
+        assert!(window.expired(100 + MERGED_WINDOW_DEADLINE_MS));
+        window.close();
+        assert!(!window.expired(100 + MERGED_WINDOW_DEADLINE_MS));
+        assert_eq!(window.on_eoce(), None);
+    }
+
+    #[test]
+    fn non_event_outputs_pass_through() {
+        let mut window = MergedWindow::new(Some(1), true, NOW);
+        let mut builder = FlatBufferBuilder::new();
+        let subscription_id = builder.create_string("");
+        let eoce = fb::Eoce::create(
+            &mut builder,
+            &fb::EoceArgs {
+                subscription_id: Some(subscription_id),
+            },
+        );
+        let wm = fb::WorkerMessage::create(
+            &mut builder,
+            &fb::WorkerMessageArgs {
+                sub_id: None,
+                url: None,
+                type_: fb::MessageType::Eoce,
+                content_type: fb::Message::Eoce,
+                content: Some(eoce.as_union_value()),
+            },
+        );
+        builder.finish(wm, None);
+        assert!(window.offer(builder.finished_data().to_vec()).is_some());
+    }
+}
@@ -7,4 +7,10 @@ pub mod connections_worker;
 #[cfg(feature = "crypto")]
 pub mod crypto_worker;
 #[cfg(feature = "parser")]
+pub mod mentions;
+#[cfg(feature = "parser")]
+pub mod merged_window;
+#[cfg(feature = "parser")]
 pub mod parser_worker;
+#[cfg(feature = "parser")]
+pub mod thread;
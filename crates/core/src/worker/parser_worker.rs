@@ -1,13 +1,21 @@
 use crate::cache_input;
 use crate::channel::{MessageSender, WorkerChannel};
 use crate::generated::nostr::fb;
+use crate::link_preview::{self, LinkPreview, LinkPreviews};
+use crate::nip05::{self, Nip05Lookups};
 use crate::network::{publish::PublishManager, subscription::SubscriptionManager};
 use crate::nostr_error::{NostrError, NostrResult};
 use crate::parser::Parser;
 use crate::pipeline::Pipeline;
+use crate::platform::now_millis;
+use crate::proxy::HttpClients;
 use crate::spawn::spawn_worker;
+use crate::traffic::BUDGET_EXCEEDED_PREFIX;
 use crate::types::{network::Request, nostr::Template};
 use crate::worker::batch_buffer::BatchBufferManager;
+use crate::worker::mentions::{self, Mention, MentionResolver};
+use crate::worker::merged_window::MergedWindow;
+use crate::worker::thread::ThreadResolver;
 // The tagged framing helpers live in batch_buffer (shared with the
 // connections worker); re-export so existing paths keep working.
 pub use crate::worker::batch_buffer::{decode_conn_batch, decode_tagged, decode_tagged_batch, encode_tagged};
@@ -16,9 +24,9 @@ use futures::channel::mpsc;
 use futures::lock::Mutex;
 use futures::{FutureExt, SinkExt, StreamExt};
 use rustc_hash::{FxHashMap, FxHashSet};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex as StdMutex, RwLock};
-use tracing::{info, info_span, warn, Span};
+use tracing::{debug, info, info_span, warn, Span};
 
 // Tunables
 const MAX_INFLIGHT: usize = 24;
@@ -31,6 +39,12 @@ const SHARD_CAP: usize = BATCH_SIZE * 4;
 const SLOW_SHARDS: usize = 2;
 /// How often the parser checks parser→main batch buffers for timeout flushes.
 const MAIN_BATCH_SWEEP_MS: u64 = 4;
+/// Link preview page fetches in flight at once.
+const MAX_CONCURRENT_LINK_PREVIEWS: usize = 4;
+/// NIP-05 lookups in flight at once; each domain is also rate limited.
+const MAX_CONCURRENT_NIP05_LOOKUPS: usize = 4;
+/// How often new link previews and NIP-05 lookups are sent to the cache worker.
+const LINK_PREVIEW_FLUSH_MS: u64 = 30_000;
 
 struct Sub {
     pipeline: Arc<Mutex<Pipeline>>,
@@ -39,6 +53,24 @@ struct Sub {
     closed_relays: FxHashSet<String>,
     publish_id: Option<String>,
     forced_shard: Option<usize>,
+    /// Set for `merged_window` subscriptions: holds event outputs until
+    /// EOSE/deadline and emits the newest `limit` across all sources.
+    window: Option<StdMutex<MergedWindow>>,
+    /// Fetch previews for unresolved links in this subscription's events.
+    link_previews: bool,
+    /// Set for `thread` subscriptions and the fetch rounds they open.
+    thread: Option<ThreadLink>,
+    /// Resolve the NIP-27 references in this subscription's events.
+    resolve_mentions: bool,
+    /// Look up unchecked NIP-05 identifiers of this subscription's profiles.
+    verify_nip05: bool,
+}
+
+#[derive(Clone)]
+struct ThreadLink {
+    resolver: Arc<StdMutex<ThreadResolver>>,
+    /// The subscription the thread's events and trees are emitted under.
+    sub_id: String,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -52,6 +84,7 @@ enum ShardSource {
 
 type ShardTask = (String, Arc<Vec<u8>>, ShardSource, Span);
 type DispatchTask = (usize, ShardTask);
+type Nip05Waiters = FxHashMap<String, Vec<(String, Vec<u8>)>>;
 
 pub struct ParserWorker {
     to_cache: Arc<dyn MessageSender>,
@@ -63,6 +96,27 @@ pub struct ParserWorker {
     slow_rr: AtomicUsize,
     /// Per-subscription batch buffers for the parser→main channel.
     main_batches: StdMutex<BatchBufferManager>,
+    /// Page URL -> subscriptions waiting for its link preview.
+    preview_waiters: StdMutex<FxHashMap<String, FxHashSet<String>>>,
+    preview_requests: mpsc::UnboundedSender<String>,
+    /// Receiving end of `preview_requests`, taken by the fetch task in `run()`.
+    preview_queue: StdMutex<Option<mpsc::UnboundedReceiver<String>>>,
+    /// NIP-05 identifier -> profiles emitted unverified, as (sub_id, message).
+    nip05_waiters: StdMutex<Nip05Waiters>,
+    nip05_requests: mpsc::UnboundedSender<String>,
+    nip05_queue: StdMutex<Option<mpsc::UnboundedReceiver<String>>>,
+    /// NIP-27 references of `resolve_mentions` subscriptions, looked up in
+    /// batches shared by all of them.
+    mentions: StdMutex<MentionResolver>,
+    /// The engine's HTTP clients, for link previews and NIP-05 lookups.
+    http: HttpClients,
+    /// Fetched link previews, persisted through the cache worker.
+    link_previews: LinkPreviews,
+    /// The engine's NIP-05 lookups, shared with the parser.
+    nip05: Nip05Lookups,
+    /// Earliest deadline of an unfinished thread round, `u64::MAX` when
+    /// none is pending.
+    thread_deadline: AtomicU64,
 }
 
 impl ParserWorker {
@@ -72,8 +126,12 @@ impl ParserWorker {
         to_connections: Arc<dyn MessageSender>,
         to_main: Box<dyn MessageSender>,
     ) -> Self {
+        let http = parser.http();
+        let nip05 = parser.nip05();
         let publish_manager = PublishManager::new(parser.clone());
         let subscription_manager = SubscriptionManager::new(parser.clone());
+        let (preview_requests, preview_queue) = mpsc::unbounded();
+        let (nip05_requests, nip05_queue) = mpsc::unbounded();
         Self {
             to_cache,
             to_connections,
@@ -83,6 +141,17 @@ impl ParserWorker {
             subscriptions: Arc::new(RwLock::new(FxHashMap::default())),
             slow_rr: AtomicUsize::new(0),
             main_batches: StdMutex::new(BatchBufferManager::new()),
+            preview_waiters: StdMutex::new(FxHashMap::default()),
+            preview_requests,
+            preview_queue: StdMutex::new(Some(preview_queue)),
+            nip05_waiters: StdMutex::new(FxHashMap::default()),
+            nip05_requests,
+            nip05_queue: StdMutex::new(Some(nip05_queue)),
+            mentions: StdMutex::new(MentionResolver::new()),
+            http,
+            link_previews: LinkPreviews::default(),
+            nip05,
+            thread_deadline: AtomicU64::new(u64::MAX),
         }
     }
 
@@ -154,10 +223,62 @@ impl ParserWorker {
         spawn_worker(async move {
             loop {
                 crate::platform::sleep(MAIN_BATCH_SWEEP_MS).await;
+                this_sweep.release_expired_windows();
+                this_sweep.advance_due_threads();
+                this_sweep.dispatch_mentions().await;
                 this_sweep.flush_timed_out_batches();
             }
         });
 
+        // Link preview fetches for `link_previews` subscriptions, and the
+        // periodic hand-off of new previews to the cache worker.
+        if let Some(queue) = this.preview_queue.lock().ok().and_then(|mut q| q.take()) {
+            let this_previews = this.clone();
+            spawn_worker(async move {
+                queue
+                    .for_each_concurrent(MAX_CONCURRENT_LINK_PREVIEWS, |url| {
+                        let this = this_previews.clone();
+                        async move {
+                            let result = this.link_previews.fetch(&this.http, &url).await;
+                            this.deliver_link_preview(&url, result);
+                        }
+                    })
+                    .await;
+            });
+        }
+        // NIP-05 lookups for profiles emitted before their identifier was
+        // checked.
+        if let Some(queue) = this.nip05_queue.lock().ok().and_then(|mut q| q.take()) {
+            let this_nip05 = this.clone();
+            spawn_worker(async move {
+                queue
+                    .for_each_concurrent(MAX_CONCURRENT_NIP05_LOOKUPS, |identifier| {
+                        let this = this_nip05.clone();
+                        async move {
+                            if let Err(e) = this.nip05.lookup(&this.http, &identifier).await {
+                                debug!("NIP-05 lookup for {} failed: {}", identifier, e);
+                            }
+                            this.deliver_nip05(&identifier);
+                        }
+                    })
+                    .await;
+            });
+        }
+        let this_persist = this.clone();
+        spawn_worker(async move {
+            loop {
+                crate::platform::sleep(LINK_PREVIEW_FLUSH_MS).await;
+                if this_persist.link_previews.take_dirty() {
+                    let snapshot = this_persist.link_previews.snapshot();
+                    this_persist.persist_snapshot(link_preview::CACHE_SUB_ID, snapshot);
+                }
+                if this_persist.nip05.take_dirty() {
+                    let snapshot = this_persist.nip05.snapshot();
+                    this_persist.persist_snapshot(nip05::CACHE_SUB_ID, snapshot);
+                }
+            }
+        });
+
         // Sharded executors
         let subs = this.subscriptions.clone();
         let mut shard_senders = Vec::with_capacity(NUM_SHARDS);
@@ -397,6 +518,18 @@ impl ParserWorker {
                                     warn!("Invalid cache response: Missing sub_id");
                                     continue;
                                 }
+                                if sid == link_preview::CACHE_SUB_ID {
+                                    if let Some(snapshot) = resp.payload() {
+                                        this_ingress.link_previews.restore(snapshot.bytes());
+                                    }
+                                    continue;
+                                }
+                                if sid == nip05::CACHE_SUB_ID {
+                                    if let Some(snapshot) = resp.payload() {
+                                        this_ingress.nip05.restore(snapshot.bytes());
+                                    }
+                                    continue;
+                                }
 
                                 let (shard_idx, is_slow_lane) = {
                                     let forced = subs
@@ -523,7 +656,7 @@ impl ParserWorker {
                 Ok(Some(output)) => {
                     // Buffered; flushed by the batch size/time thresholds so
                     // live events don't cost one postMessage each.
-                    self.send_output_to_main(&sid, &output);
+                    self.send_event_output(&sid, output);
                 }
                 Ok(None) => {}
                 Err(e) => {
@@ -582,12 +715,18 @@ impl ParserWorker {
                         };
 
                         for output in flushed_outputs {
-                            self.send_output_to_main(&sid, &output);
+                            self.send_event_output(&sid, output);
+                        }
+                        if self.with_window(&sid, |w| w.on_eose(url)) == Some(true) {
+                            self.release_window(&sid);
                         }
 
-                        let status_bytes = serialize_connection_status(url, "EOSE", "");
-                        self.send_output_to_main(&sid, &status_bytes);
-                        self.flush_main(&sid);
+                        // Mention batches finish on their deadline instead.
+                        if !self.on_thread_marker(&sid, true) && !self.is_mention_batch(&sid) {
+                            let status_bytes = serialize_connection_status(url, "EOSE", "");
+                            self.send_output_to_main(&sid, &status_bytes);
+                            self.flush_main(&sid);
+                        }
                         let should_close_relay = if let Ok(mut w) = self.subscriptions.write() {
                             if let Some(sub) = w.get_mut(&sid) {
                                 sub.eosed = true;
@@ -614,8 +753,22 @@ impl ParserWorker {
                             }
                         }
                     }
+                    "CLOSED"
+                        if reason
+                            .trim_start_matches('"')
+                            .starts_with(BUDGET_EXCEEDED_PREFIX) =>
+                    {
+                        // The connections worker closed this subscription
+                        // over its traffic budget; the app needs to know.
+                        let status_bytes = serialize_connection_status(url, "CLOSED", reason);
+                        self.send_output_to_main(&sid, &status_bytes);
+                        self.flush_main(&sid);
+                    }
                     "CLOSED" => {}
                     accepted => {
+                        if accepted == "SUBSCRIBED" {
+                            self.with_window(&sid, |w| w.on_subscribed(url));
+                        }
                         let batch_sub_id = if let Ok(guard) = self.subscriptions.read() {
                             if let Some(sub) = guard.get(&sid) {
                                 sub.publish_id.clone().unwrap_or_else(|| sid.clone())
@@ -667,12 +820,29 @@ impl ParserWorker {
                 };
 
                 for output in flushed_outputs {
-                    self.send_output_to_main(&sid, &output);
+                    self.send_event_output(&sid, output);
+                }
+                if self.on_thread_marker(&sid, false) {
+                    return;
+                }
+                if self.is_mention_batch(&sid) {
+                    self.fetch_missed_mentions(&sid);
+                    return;
                 }
 
-                let eoce_bytes = serialize_eoce();
-                self.send_output_to_main(&sid, &eoce_bytes);
-                self.flush_main(&sid);
+                match self.with_window(&sid, MergedWindow::on_eoce).flatten() {
+                    Some(true) => {
+                        self.release_window(&sid);
+                        self.flush_main(&sid);
+                    }
+                    // The window holds the marker until it closes.
+                    Some(false) => {}
+                    None => {
+                        let eoce_bytes = serialize_eoce();
+                        self.send_output_to_main(&sid, &eoce_bytes);
+                        self.flush_main(&sid);
+                    }
+                }
             }
             fb::Message::Raw => {
                 let Some(raw) = wm.content_as_raw() else {
@@ -690,7 +860,7 @@ impl ParserWorker {
                     Ok(Some(output)) => {
                         // Buffered; flushed by the batch size/time thresholds so
                         // live events don't cost one postMessage each.
-                        self.send_output_to_main(&sid, &output);
+                        self.send_event_output(&sid, output);
                     }
                     Ok(None) => {}
                     Err(e) => {
@@ -705,8 +875,8 @@ impl ParserWorker {
                     .await
                 {
                     Ok(outputs) => {
-                        for output in outputs.iter() {
-                            self.send_output_to_main(&sid, output);
+                        for output in outputs {
+                            self.send_event_output(&sid, output);
                         }
                     }
                     Err(e) => {
@@ -723,7 +893,7 @@ impl ParserWorker {
                     Ok(Some(output)) => {
                         // Buffered; flushed by the batch size/time thresholds so
                         // live events don't cost one postMessage each.
-                        self.send_output_to_main(&sid, &output);
+                        self.send_event_output(&sid, output);
                     }
                     Ok(None) => {}
                     Err(e) => {
@@ -738,7 +908,7 @@ impl ParserWorker {
     pub async fn open_subscription(
         &self,
         subscription_id: String,
-        requests: Vec<fb::RequestT>,
+        mut requests: Vec<fb::RequestT>,
         config: fb::SubscriptionConfigT,
     ) -> NostrResult<()> {
         if self
@@ -750,8 +920,35 @@ impl ParserWorker {
             return Ok(());
         }
 
+        // Thread subscriptions run the resolver's first round instead; the
+        // given requests only contribute relay hints for the focus event.
+        let thread = config.thread.as_deref().filter(|id| !id.is_empty()).map(|focus| {
+            let hints = requests
+                .iter()
+                .flat_map(|r| r.relays.iter().flatten().cloned())
+                .collect();
+            let mut resolver = ThreadResolver::new(focus.to_string(), hints);
+            requests = resolver.next_round();
+            resolver.open_round(subscription_id.clone(), now_millis());
+            ThreadLink {
+                resolver: Arc::new(StdMutex::new(resolver)),
+                sub_id: subscription_id.clone(),
+            }
+        });
+
         let parsed_requests: Vec<Request> = requests.iter().map(request_from_t).collect();
 
+        // The window spans every filter: its size is the sum of their limits,
+        // and any unlimited filter leaves it untruncated.
+        let window = config.merged_window.then(|| {
+            let limit = parsed_requests
+                .iter()
+                .map(|r| r.limit.filter(|l| *l > 0).map(|l| l as usize))
+                .sum::<Option<usize>>();
+            let needs_relay_eose = !parsed_requests.iter().all(|r| r.cache_only);
+            StdMutex::new(MergedWindow::new(limit, needs_relay_eose, now_millis()))
+        });
+
         let mut config_builder = FlatBufferBuilder::new();
         let config_offset = config.pack(&mut config_builder);
         config_builder.finish(config_offset, None);
@@ -808,6 +1005,11 @@ impl ParserWorker {
                     closed_relays: FxHashSet::default(),
                     publish_id: None,
                     forced_shard,
+                    window,
+                    link_previews: config.link_previews,
+                    thread,
+                    resolve_mentions: config.resolve_mentions,
+                    verify_nip05: config.verify_nip05,
                 },
             );
         } else {
@@ -818,35 +1020,39 @@ impl ParserWorker {
             return Ok(());
         }
 
-        {
-            let mut builder = FlatBufferBuilder::new();
-            let sid = builder.create_string(&subscription_id);
-            let req_offsets: Vec<_> = requests.iter().map(|r| r.pack(&mut builder)).collect();
-            let req_vec = if req_offsets.is_empty() {
-                None
-            } else {
-                Some(builder.create_vector(&req_offsets))
-            };
+        self.send_cache_request(&subscription_id, &requests, !config.close_on_eose);
 
-            let cache_req = fb::CacheRequest::create(
-                &mut builder,
-                &fb::CacheRequestArgs {
-                    sub_id: Some(sid),
-                    requests: req_vec,
-                    event: None,
-                    parsed_event: None,
-                    relays: None,
-                    close: false,
-                    keep_mesh_watch: !config.close_on_eose,
-                },
-            );
+        Ok(())
+    }
 
-            builder.finish(cache_req, None);
-            let bytes = cache_input::frame(cache_input::TAG_REQUEST, builder.finished_data());
-            let _ = self.to_cache.send(&bytes);
-        }
+    /// Ask the cache worker to serve `requests` for `sub_id`, cache first and
+    /// then relays.
+    fn send_cache_request(&self, sub_id: &str, requests: &[fb::RequestT], keep_mesh_watch: bool) {
+        let mut builder = FlatBufferBuilder::new();
+        let sid = builder.create_string(sub_id);
+        let req_offsets: Vec<_> = requests.iter().map(|r| r.pack(&mut builder)).collect();
+        let req_vec = if req_offsets.is_empty() {
+            None
+        } else {
+            Some(builder.create_vector(&req_offsets))
+        };
 
-        Ok(())
+        let cache_req = fb::CacheRequest::create(
+            &mut builder,
+            &fb::CacheRequestArgs {
+                sub_id: Some(sid),
+                requests: req_vec,
+                event: None,
+                parsed_event: None,
+                relays: None,
+                close: false,
+                keep_mesh_watch,
+            },
+        );
+
+        builder.finish(cache_req, None);
+        let bytes = cache_input::frame(cache_input::TAG_REQUEST, builder.finished_data());
+        let _ = self.to_cache.send(&bytes);
     }
 
     fn send_close_to_connections(&self, subscription_id: &str, relay_url: Option<&str>) {
@@ -882,10 +1088,35 @@ impl ParserWorker {
     }
 
     pub async fn close_subscription(&self, subscription_id: String) -> NostrResult<()> {
-        self.send_close_to_connections(&subscription_id, None);
+        // A thread takes the fetch rounds it opened along with it.
+        let rounds: Vec<String> = self
+            .thread_link(&subscription_id)
+            .filter(|link| link.sub_id == subscription_id)
+            .and_then(|link| {
+                let resolver = link.resolver.lock().ok()?;
+                Some(
+                    resolver
+                        .round_ids()
+                        .filter(|id| *id != subscription_id)
+                        .map(str::to_string)
+                        .collect(),
+                )
+            })
+            .unwrap_or_default();
+
+        for subscription_id in std::iter::once(subscription_id).chain(rounds) {
+            self.release_subscription(&subscription_id);
+        }
+
+        Ok(())
+    }
+
+    /// Close `subscription_id` on the relays and the cache and drop it.
+    fn release_subscription(&self, subscription_id: &str) {
+        self.send_close_to_connections(subscription_id, None);
 
         let mut builder = FlatBufferBuilder::new();
-        let sid = builder.create_string(&subscription_id);
+        let sid = builder.create_string(subscription_id);
         let close = fb::CacheRequest::create(
             &mut builder,
             &fb::CacheRequestArgs {
@@ -899,14 +1130,12 @@ impl ParserWorker {
         let _ = self.to_cache.send(&framed);
 
         if let Ok(mut w) = self.subscriptions.write() {
-            w.remove(&subscription_id);
+            w.remove(subscription_id);
         }
 
         // Flush any buffered events so nothing is left sitting in the batch
         // buffer for a subscription that no longer exists.
-        self.flush_main(&subscription_id);
-
-        Ok(())
+        self.flush_main(subscription_id);
     }
 
     async fn inject_optimistic_event(&self, sub_id: &str, event_json: &str) -> NostrResult<()> {
@@ -973,12 +1202,14 @@ impl ParserWorker {
             publish_id, default_relays, optimistic_subids
         );
 
-        let event = self
+        let events = self
             .publish_manager
             .publish_event(publish_id.clone(), template)
             .await?;
 
-        if !optimistic_subids.is_empty() {
+        // Optimistic updates show the first event: the event itself, or the
+        // sender's own gift wrap, the only one this signer can unwrap.
+        if let Some(event) = events.first().filter(|_| !optimistic_subids.is_empty()) {
             let event_json = event.to_json();
             for sub_id in &optimistic_subids {
                 if let Err(e) = self.inject_optimistic_event(sub_id, &event_json).await {
@@ -989,68 +1220,75 @@ impl ParserWorker {
             }
         }
 
-        let event_id = event.id.to_string();
-        info!("publish_event: event signed successfully, id={}", event_id);
-        if let Ok(mut w) = self.subscriptions.write() {
-            w.insert(
-                event_id.clone(),
-                Sub {
-                    pipeline: Arc::new(Mutex::new(Pipeline::new(vec![], "".to_string()).unwrap())),
-                    eosed: false,
-                    close_on_eose: false,
-                    closed_relays: FxHashSet::default(),
-                    publish_id: Some(publish_id.clone()),
-                    forced_shard: None,
-                },
-            );
-        } else {
-            warn!(
-                "Subscriptions lock poisoned while publishing {}",
-                publish_id
-            );
-        }
-
-        {
-            let mut builder = FlatBufferBuilder::new();
-            let sid = builder.create_string(&event_id);
-            let fb_event = event.build_flatbuffer(&mut builder);
-
-            let relay_offsets: Vec<_> = default_relays
-                .iter()
-                .map(|r| builder.create_string(r))
-                .collect();
-            let relay_vec = if relay_offsets.is_empty() {
-                None
+        for event in &events {
+            let event_id = event.id.to_string();
+            info!("publish_event: event signed successfully, id={}", event_id);
+            if let Ok(mut w) = self.subscriptions.write() {
+                w.insert(
+                    event_id.clone(),
+                    Sub {
+                        pipeline: Arc::new(Mutex::new(Pipeline::new(vec![], "".to_string()).unwrap())),
+                        eosed: false,
+                        close_on_eose: false,
+                        closed_relays: FxHashSet::default(),
+                        publish_id: Some(publish_id.clone()),
+                        forced_shard: None,
+                        window: None,
+                        link_previews: false,
+                        thread: None,
+                        resolve_mentions: false,
+                        verify_nip05: false,
+                    },
+                );
             } else {
-                Some(builder.create_vector(&relay_offsets))
-            };
+                warn!(
+                    "Subscriptions lock poisoned while publishing {}",
+                    publish_id
+                );
+            }
 
-            let cache_req = fb::CacheRequest::create(
-                &mut builder,
-                &fb::CacheRequestArgs {
-                    sub_id: Some(sid),
-                    requests: None,
-                    event: Some(fb_event),
-                    parsed_event: None,
-                    relays: relay_vec,
-                    close: false,
-                    keep_mesh_watch: true,
-                },
-            );
+            {
+                let mut builder = FlatBufferBuilder::new();
+                let sid = builder.create_string(&event_id);
+                let fb_event = event.build_flatbuffer(&mut builder);
+
+                let relay_offsets: Vec<_> = default_relays
+                    .iter()
+                    .map(|r| builder.create_string(r))
+                    .collect();
+                let relay_vec = if relay_offsets.is_empty() {
+                    None
+                } else {
+                    Some(builder.create_vector(&relay_offsets))
+                };
 
-            builder.finish(cache_req, None);
-            let bytes = cache_input::frame(cache_input::TAG_REQUEST, builder.finished_data());
+                let cache_req = fb::CacheRequest::create(
+                    &mut builder,
+                    &fb::CacheRequestArgs {
+                        sub_id: Some(sid),
+                        requests: None,
+                        event: Some(fb_event),
+                        parsed_event: None,
+                        relays: relay_vec,
+                        close: false,
+                        keep_mesh_watch: true,
+                    },
+                );
 
-            info!(
-                "publish_event: sending CacheRequest to cache, event_id={}, bytes={}",
-                event_id,
-                bytes.len()
-            );
+                builder.finish(cache_req, None);
+                let bytes = cache_input::frame(cache_input::TAG_REQUEST, builder.finished_data());
 
-            if let Err(e) = self.to_cache.send(&bytes) {
-                warn!("publish_event: failed to send to cache: {}", e);
-            } else {
-                info!("publish_event: CacheRequest sent to cache successfully");
+                info!(
+                    "publish_event: sending CacheRequest to cache, event_id={}, bytes={}",
+                    event_id,
+                    bytes.len()
+                );
+
+                if let Err(e) = self.to_cache.send(&bytes) {
+                    warn!("publish_event: failed to send to cache: {}", e);
+                } else {
+                    info!("publish_event: CacheRequest sent to cache successfully");
+                }
             }
         }
 
@@ -1119,104 +1357,556 @@ impl ParserWorker {
         }
     }
 
-    /// Flush every per-subscription batch whose oldest frame exceeded the
-    /// batch timeout. Driven periodically by the sweep task in `run()`.
-    fn flush_timed_out_batches(&self) {
-        let payloads = self.lock_main_batches().drain_timed_out();
-        for payload in payloads {
-            let _ = self.to_main.send(&payload);
+    /// Forward an event output to main, through the subscription's merged
+    /// window when it has one.
+    fn send_event_output(&self, sub_id: &str, output: Vec<u8>) {
+        // Mention batches are internal: their events only resolve mentions.
+        if self.is_mention_batch(sub_id) {
+            self.deliver_mentions(sub_id, &output);
+            return;
+        }
+        // Thread rounds emit under the thread's subscription; once the thread
+        // completed, each new reply also sends a delta tree.
+        let thread = self.thread_link(sub_id);
+        let thread_changed = thread.as_ref().is_some_and(|link| {
+            link.resolver
+                .lock()
+                .map(|mut r| r.offer(&output) && r.is_live())
+                .unwrap_or(false)
+        });
+        let sub_id = thread.as_ref().map_or(sub_id, |link| link.sub_id.as_str());
+        // Profiles served from storage carry the verdict they were stored with.
+        let output = self.nip05.refresh_verified(&output).unwrap_or(output);
+        let links = self
+            .wants_link_previews(sub_id)
+            .then(|| link_preview::unresolved_urls(&output));
+        let mentioned = self
+            .wants_mentions(sub_id)
+            .then(|| mentions::mentions(&output));
+        let unchecked = self
+            .wants_nip05(sub_id)
+            .then(|| self.nip05.unchecked_identifier(&output))
+            .flatten()
+            .map(|(_, identifier)| (identifier, output.clone()));
+        let mut output = Some(output);
+        let forwarded = self
+            .with_window(sub_id, |w| output.take().and_then(|o| w.offer(o)))
+            .unwrap_or_else(|| output.take());
+        if let Some(output) = forwarded {
+            self.send_output_to_main(sub_id, &output);
+        }
+        if let Some(urls) = links {
+            self.request_link_previews(sub_id, urls);
+        }
+        if let Some(found) = mentioned {
+            self.request_mentions(sub_id, found);
+        }
+        if let Some((identifier, output)) = unchecked {
+            self.request_nip05(sub_id, identifier, output);
+        }
+        if let (Some(link), true) = (thread.as_ref(), thread_changed) {
+            self.advance_thread(link, false);
         }
     }
-}
 
+    fn thread_link(&self, sub_id: &str) -> Option<ThreadLink> {
+        let guard = self.subscriptions.read().ok()?;
+        guard.get(sub_id)?.thread.clone()
+    }
 
-fn request_from_t(rt: &fb::RequestT) -> Request {
-    Request {
-        ids: rt.ids.clone().unwrap_or_default(),
-        authors: rt.authors.clone().unwrap_or_default(),
-        kinds: rt
-            .kinds
-            .clone()
-            .unwrap_or_default()
-            .into_iter()
-            .map(|k| k as i32)
-            .collect(),
-        tags: {
-            let mut map = FxHashMap::default();
-            if let Some(ref tags) = rt.tags {
-                for sv in tags {
-                    if let Some(ref items) = sv.items {
-                        if items.len() >= 2 {
-                            map.insert(items[0].clone(), items[1..].to_vec());
-                        }
-                    }
-                }
+    /// Record a thread round's EOSE/EOCE and advance the thread. Returns
+    /// true for the rounds the resolver opened, whose markers stay internal.
+    fn on_thread_marker(&self, sub_id: &str, eose: bool) -> bool {
+        self.advance_due_threads();
+        let Some(link) = self.thread_link(sub_id) else {
+            return false;
+        };
+        if let Ok(mut resolver) = link.resolver.lock() {
+            if eose {
+                resolver.on_eose(sub_id);
+            } else {
+                resolver.on_eoce(sub_id);
             }
-            map
-        },
-        since: if rt.since != 0 { Some(rt.since) } else { None },
-        until: if rt.until != 0 { Some(rt.until) } else { None },
-        limit: if rt.limit != 0 { Some(rt.limit) } else { None },
-        search: rt.search.clone(),
-        relays: rt.relays.clone().unwrap_or_default(),
-        cache_first: rt.cache_first,
-        no_cache: rt.no_cache,
-        max_relays: rt.max_relays as u32,
-        cache_only: rt.cache_only,
-        mesh_only: rt.mesh_only,
+        }
+        self.advance_thread(&link, true);
+        self.flush_main(&link.sub_id);
+        link.sub_id != sub_id
     }
-}
 
-fn template_from_t(tt: &fb::TemplateT) -> Template {
-    Template {
-        kind: tt.kind,
-        content: tt.content.clone(),
-        tags: tt.tags.iter().filter_map(|sv| sv.items.clone()).collect(),
-        created_at: tt.created_at as u64,
+    /// Open a round for whatever the thread learned about and emit its tree
+    /// when due (see `ThreadResolver::update`).
+    fn advance_thread(&self, link: &ThreadLink, boundary: bool) {
+        let now = now_millis();
+        let (round, tree) = {
+            let Ok(mut resolver) = link.resolver.lock() else {
+                warn!("Thread resolver lock poisoned for {}", link.sub_id);
+                return;
+            };
+            let requests = resolver.next_round();
+            let round = (!requests.is_empty()).then(|| {
+                let round_id = format!("{}:thread:{}", link.sub_id, resolver.round_ids().count());
+                resolver.open_round(round_id.clone(), now);
+                (round_id, requests)
+            });
+            if let Some(deadline) = resolver.next_deadline(now) {
+                self.thread_deadline.fetch_min(deadline, Ordering::Relaxed);
+            }
+            (round, resolver.update(now, boundary))
+        };
+        if let Some((round_id, requests)) = round {
+            self.open_thread_round(link, round_id, requests);
+        }
+        if let Some(tree) = tree {
+            self.send_output_to_main(&link.sub_id, &serialize_thread_tree(&tree));
+            self.flush_main(&link.sub_id);
+        }
     }
-}
 
-fn serialize_connection_status(relay_url: &str, status: &str, message: &str) -> Vec<u8> {
-    let mut builder = FlatBufferBuilder::new();
+    /// A thread round is a one-shot subscription sharing the thread's
+    /// pipeline, so events seen by an earlier round are deduplicated.
+    fn open_thread_round(&self, link: &ThreadLink, round_id: String, requests: Vec<fb::RequestT>) {
+        {
+            let Ok(mut subs) = self.subscriptions.write() else {
+                warn!("Subscriptions lock poisoned while opening {}", round_id);
+                return;
+            };
+            // The thread was closed meanwhile.
+            let Some(thread) = subs.get(&link.sub_id) else {
+                return;
+            };
+            let round = Sub {
+                pipeline: Arc::clone(&thread.pipeline),
+                eosed: false,
+                close_on_eose: true,
+                closed_relays: FxHashSet::default(),
+                publish_id: None,
+                forced_shard: thread.forced_shard,
+                window: None,
+                link_previews: thread.link_previews,
+                thread: Some(link.clone()),
+                resolve_mentions: thread.resolve_mentions,
+                verify_nip05: thread.verify_nip05,
+            };
+            subs.insert(round_id.clone(), round);
+        }
+        self.send_cache_request(&round_id, &requests, false);
+    }
 
-    let relay_url_offset = builder.create_string(relay_url);
-    let status_offset = builder.create_string(status);
-    let message_offset = builder.create_string(message);
+    /// Re-check the pending threads once a round ran past its deadline, so a
+    /// thread whose relays stay silent still completes. Runs on every marker
+    /// and sweep, but only scans the threads when the deadline is due.
+    fn advance_due_threads(&self) {
+        if now_millis() < self.thread_deadline.load(Ordering::Relaxed) {
+            return;
+        }
+        // Advancing re-arms the deadline for the rounds still open.
+        self.thread_deadline.store(u64::MAX, Ordering::Relaxed);
+        self.advance_pending_threads();
+    }
 
-    let conn_status_args = fb::ConnectionStatusArgs {
-        relay_url: Some(relay_url_offset),
-        status: Some(status_offset),
-        message: Some(message_offset),
-    };
-    let conn_status_offset = fb::ConnectionStatus::create(&mut builder, &conn_status_args);
+    /// Re-check threads that have not completed yet: rounds past their
+    /// deadline count as finished.
+    fn advance_pending_threads(&self) {
+        let pending: Vec<ThreadLink> = match self.subscriptions.read() {
+            Ok(guard) => guard
+                .iter()
+                .filter_map(|(sid, sub)| sub.thread.as_ref().filter(|link| link.sub_id == *sid))
+                .filter(|link| {
+                    link.resolver
+                        .lock()
+                        .map(|r| !r.is_complete())
+                        .unwrap_or(false)
+                })
+                .cloned()
+                .collect(),
+            Err(_) => return,
+        };
+        for link in pending {
+            self.advance_thread(&link, false);
+        }
+    }
 
-    let message_args = fb::WorkerMessageArgs {
-        sub_id: None,
-        url: None,
-        type_: fb::MessageType::ConnectionStatus,
-        content_type: fb::Message::ConnectionStatus,
-        content: Some(conn_status_offset.as_union_value()),
-    };
-    let root = fb::WorkerMessage::create(&mut builder, &message_args);
-    builder.finish(root, None);
+    fn wants_link_previews(&self, sub_id: &str) -> bool {
+        self.subscriptions
+            .read()
+            .ok()
+            .and_then(|guard| guard.get(sub_id).map(|sub| sub.link_previews))
+            .unwrap_or(false)
+    }
 
-    builder.finished_data().to_vec()
-}
+    /// Send cached previews for `urls` right away and queue fetches for the
+    /// rest; `sub_id` receives each one as it resolves.
+    fn request_link_previews(&self, sub_id: &str, urls: Vec<String>) {
+        for url in urls {
+            if let Some(preview) = self.link_previews.cached(&url) {
+                if !preview.is_empty() {
+                    self.send_output_to_main(sub_id, &serialize_link_preview(&preview));
+                }
+                continue;
+            }
+            let first = match self.preview_waiters.lock() {
+                Ok(mut waiters) => {
+                    let subs = waiters.entry(url.clone()).or_default();
+                    subs.insert(sub_id.to_string());
+                    subs.len() == 1
+                }
+                Err(_) => {
+                    warn!("Link preview waiters lock poisoned");
+                    return;
+                }
+            };
+            if first {
+                let _ = self.preview_requests.unbounded_send(url);
+            }
+        }
+    }
 
-fn serialize_eoce() -> Vec<u8> {
-    let mut builder = FlatBufferBuilder::new();
+    /// Emit a fetched preview to the still-open subscriptions that wait for it.
+    fn deliver_link_preview(&self, url: &str, result: Result<LinkPreview, String>) {
+        let waiters = self
+            .preview_waiters
+            .lock()
+            .ok()
+            .and_then(|mut waiters| waiters.remove(url))
+            .unwrap_or_default();
+        let preview = match result {
+            Ok(preview) if !preview.is_empty() => preview,
+            Ok(_) => return,
+            Err(e) => {
+                info!("Link preview for {} failed: {}", url, e);
+                return;
+            }
+        };
+        let open: Vec<String> = match self.subscriptions.read() {
+            Ok(guard) => waiters
+                .into_iter()
+                .filter(|sid| guard.contains_key(sid))
+                .collect(),
+            Err(_) => return,
+        };
+        let bytes = serialize_link_preview(&preview);
+        for sid in open {
+            self.send_output_to_main(&sid, &bytes);
+            self.flush_main(&sid);
+        }
+    }
 
-    let subscription_id = builder.create_string("");
-    let eoce_args = fb::EoceArgs {
-        subscription_id: Some(subscription_id),
-    };
-    let eoce_offset = fb::Eoce::create(&mut builder, &eoce_args);
+    fn wants_nip05(&self, sub_id: &str) -> bool {
+        self.subscriptions
+            .read()
+            .ok()
+            .and_then(|guard| guard.get(sub_id).map(|sub| sub.verify_nip05))
+            .unwrap_or(false)
+    }
 
-    let message_args = fb::WorkerMessageArgs {
-        sub_id: None,
-        url: None,
-        type_: fb::MessageType::Eoce,
-        content_type: fb::Message::Eoce,
+    /// Queue a lookup for a profile emitted with an unchecked `nip05`; it
+    /// is sent again to `sub_id` if the identifier verifies.
+    fn request_nip05(&self, sub_id: &str, identifier: String, message: Vec<u8>) {
+        let first = match self.nip05_waiters.lock() {
+            Ok(mut waiters) => {
+                let pending = waiters.entry(identifier.clone()).or_default();
+                pending.push((sub_id.to_string(), message));
+                pending.len() == 1
+            }
+            Err(_) => {
+                warn!("NIP-05 waiters lock poisoned");
+                return;
+            }
+        };
+        if first {
+            let _ = self.nip05_requests.unbounded_send(identifier);
+        }
+    }
+
+    /// Re-emit the profiles waiting on `identifier` whose verified flag
+    /// changed with the lookup, to the subscriptions still open.
+    fn deliver_nip05(&self, identifier: &str) {
+        let waiters = self
+            .nip05_waiters
+            .lock()
+            .ok()
+            .and_then(|mut waiters| waiters.remove(identifier))
+            .unwrap_or_default();
+        for (sid, message) in waiters {
+            let open = self
+                .subscriptions
+                .read()
+                .map(|guard| guard.contains_key(&sid))
+                .unwrap_or(false);
+            if !open {
+                continue;
+            }
+            if let Some(updated) = self.nip05.refresh_verified(&message) {
+                self.send_output_to_main(&sid, &updated);
+                self.flush_main(&sid);
+            }
+        }
+    }
+
+    fn wants_mentions(&self, sub_id: &str) -> bool {
+        self.subscriptions
+            .read()
+            .ok()
+            .and_then(|guard| guard.get(sub_id).map(|sub| sub.resolve_mentions))
+            .unwrap_or(false)
+    }
+
+    fn is_mention_batch(&self, sub_id: &str) -> bool {
+        self.mentions
+            .lock()
+            .map(|resolver| resolver.is_batch(sub_id))
+            .unwrap_or(false)
+    }
+
+    /// Send already resolved mentions right away and queue lookups for the
+    /// rest; `sub_id` receives each one as its batch finds it.
+    fn request_mentions(&self, sub_id: &str, found: Vec<Mention>) {
+        if found.is_empty() {
+            return;
+        }
+        let ready = match self.mentions.lock() {
+            Ok(mut resolver) => resolver.request(sub_id, found, now_millis()),
+            Err(_) => {
+                warn!("Mention resolver lock poisoned");
+                return;
+            }
+        };
+        for data in ready {
+            self.send_output_to_main(sub_id, &serialize_mention(&data));
+        }
+    }
+
+    /// Emit what a mention batch's event resolves to the still-open
+    /// subscriptions that wait for it.
+    fn deliver_mentions(&self, batch_id: &str, output: &[u8]) {
+        let delivered = match self.mentions.lock() {
+            Ok(mut resolver) => resolver.offer(batch_id, output),
+            Err(_) => return,
+        };
+        for (data, waiters) in delivered {
+            let open: Vec<String> = match self.subscriptions.read() {
+                Ok(guard) => waiters
+                    .into_iter()
+                    .filter(|sid| guard.contains_key(sid))
+                    .collect(),
+                Err(_) => return,
+            };
+            let bytes = serialize_mention(&data);
+            for sid in open {
+                self.send_output_to_main(&sid, &bytes);
+                self.flush_main(&sid);
+            }
+        }
+    }
+
+    /// A mention batch's cache lookups finished: ask the relays for what
+    /// the cache missed, under the same subscription.
+    fn fetch_missed_mentions(&self, batch_id: &str) {
+        let requests = match self.mentions.lock() {
+            Ok(mut resolver) => resolver.on_eoce(batch_id, now_millis()),
+            Err(_) => return,
+        };
+        if !requests.is_empty() {
+            self.send_cache_request(batch_id, &requests, false);
+        }
+    }
+
+    /// Release finished mention batches and open the next one when due.
+    async fn dispatch_mentions(&self) {
+        let now = now_millis();
+        let in_use = |sub_id: &str| {
+            self.subscriptions
+                .read()
+                .map_or(true, |guard| guard.contains_key(sub_id))
+        };
+        let (finished, next) = match self.mentions.lock() {
+            Ok(mut resolver) => (resolver.finished(now), resolver.take_batch(now, in_use)),
+            Err(_) => return,
+        };
+        for batch_id in finished {
+            self.release_subscription(&batch_id);
+            if let Ok(mut resolver) = self.mentions.lock() {
+                resolver.closed(&batch_id);
+            }
+        }
+        if let Some((batch_id, requests)) = next {
+            let config = fb::SubscriptionConfigT {
+                close_on_eose: true,
+                ..Default::default()
+            };
+            if let Err(e) = self.open_subscription(batch_id, requests, config).await {
+                warn!("Opening mention batch failed: {:?}", e);
+            }
+        }
+    }
+
+    /// Hand a cache snapshot (link previews, NIP-05 lookups) to the cache
+    /// worker for persistence under its reserved `sub_id`.
+    fn persist_snapshot(&self, sub_id: &str, snapshot: Vec<u8>) {
+        let snapshot = String::from_utf8(snapshot).unwrap_or_default();
+        let mut builder = FlatBufferBuilder::new();
+        let sid = builder.create_string(sub_id);
+        let raw_str = builder.create_string(&snapshot);
+        let raw = fb::Raw::create(&mut builder, &fb::RawArgs { raw: Some(raw_str) });
+        let wm = fb::WorkerMessage::create(
+            &mut builder,
+            &fb::WorkerMessageArgs {
+                sub_id: Some(sid),
+                url: None,
+                type_: fb::MessageType::Raw,
+                content_type: fb::Message::Raw,
+                content: Some(raw.as_union_value()),
+            },
+        );
+        builder.finish(wm, None);
+        let bytes = cache_input::frame(cache_input::TAG_PERSIST, builder.finished_data());
+        let _ = self.to_cache.send(&bytes);
+    }
+
+    /// Run `f` on the subscription's merged window; `None` when it has none.
+    fn with_window<R>(&self, sub_id: &str, f: impl FnOnce(&mut MergedWindow) -> R) -> Option<R> {
+        let guard = self.subscriptions.read().ok()?;
+        let window = guard.get(sub_id)?.window.as_ref()?;
+        let mut window = window.lock().ok()?;
+        Some(f(&mut window))
+    }
+
+    /// Close the subscription's merged window and buffer the merged events,
+    /// followed by the EOCE marker when the window held it back.
+    fn release_window(&self, sub_id: &str) {
+        let Some((outputs, eoce)) = self.with_window(sub_id, MergedWindow::close) else {
+            return;
+        };
+        for output in outputs {
+            self.send_output_to_main(sub_id, &output);
+        }
+        if eoce {
+            self.send_output_to_main(sub_id, &serialize_eoce());
+        }
+    }
+
+    /// Close merged windows whose deadline passed before EOSE/EOCE arrived.
+    fn release_expired_windows(&self) {
+        let now = now_millis();
+        let expired: Vec<String> = match self.subscriptions.read() {
+            Ok(guard) => guard
+                .iter()
+                .filter(|(_, sub)| {
+                    sub.window
+                        .as_ref()
+                        .and_then(|w| w.lock().ok().map(|w| w.expired(now)))
+                        .unwrap_or(false)
+                })
+                .map(|(sid, _)| sid.clone())
+                .collect(),
+            Err(_) => return,
+        };
+        for sid in expired {
+            self.release_window(&sid);
+            self.flush_main(&sid);
+        }
+    }
+
+    /// Flush every per-subscription batch whose oldest frame exceeded the
+    /// batch timeout. Driven periodically by the sweep task in `run()`.
+    fn flush_timed_out_batches(&self) {
+        let payloads = self.lock_main_batches().drain_timed_out();
+        for payload in payloads {
+            let _ = self.to_main.send(&payload);
+        }
+    }
+}
+
+
+fn request_from_t(rt: &fb::RequestT) -> Request {
+    Request {
+        ids: rt.ids.clone().unwrap_or_default(),
+        authors: rt.authors.clone().unwrap_or_default(),
+        kinds: rt
+            .kinds
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|k| k as i32)
+            .collect(),
+        tags: {
+            let mut map = FxHashMap::default();
+            if let Some(ref tags) = rt.tags {
+                for sv in tags {
+                    if let Some(ref items) = sv.items {
+                        if items.len() >= 2 {
+                            map.insert(items[0].clone(), items[1..].to_vec());
+                        }
+                    }
+                }
+            }
+            map
+        },
+        since: if rt.since != 0 { Some(rt.since) } else { None },
+        until: if rt.until != 0 { Some(rt.until) } else { None },
+        limit: if rt.limit != 0 { Some(rt.limit) } else { None },
+        search: rt.search.clone(),
+        relays: rt.relays.clone().unwrap_or_default(),
+        cache_first: rt.cache_first,
+        no_cache: rt.no_cache,
+        max_relays: rt.max_relays as u32,
+        cache_only: rt.cache_only,
+        mesh_only: rt.mesh_only,
+        negentropy: rt.negentropy,
+        background: rt.background,
+    }
+}
+
+fn template_from_t(tt: &fb::TemplateT) -> Template {
+    Template {
+        kind: tt.kind,
+        content: tt.content.clone(),
+        tags: tt.tags.iter().filter_map(|sv| sv.items.clone()).collect(),
+        created_at: tt.created_at as u64,
+    }
+}
+
+fn serialize_connection_status(relay_url: &str, status: &str, message: &str) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+
+    let relay_url_offset = builder.create_string(relay_url);
+    let status_offset = builder.create_string(status);
+    let message_offset = builder.create_string(message);
+
+    let conn_status_args = fb::ConnectionStatusArgs {
+        relay_url: Some(relay_url_offset),
+        status: Some(status_offset),
+        message: Some(message_offset),
+    };
+    let conn_status_offset = fb::ConnectionStatus::create(&mut builder, &conn_status_args);
+
+    let message_args = fb::WorkerMessageArgs {
+        sub_id: None,
+        url: None,
+        type_: fb::MessageType::ConnectionStatus,
+        content_type: fb::Message::ConnectionStatus,
+        content: Some(conn_status_offset.as_union_value()),
+    };
+    let root = fb::WorkerMessage::create(&mut builder, &message_args);
+    builder.finish(root, None);
+
+    builder.finished_data().to_vec()
+}
+
+fn serialize_eoce() -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+
+    let subscription_id = builder.create_string("");
+    let eoce_args = fb::EoceArgs {
+        subscription_id: Some(subscription_id),
+    };
+    let eoce_offset = fb::Eoce::create(&mut builder, &eoce_args);
+
+    let message_args = fb::WorkerMessageArgs {
+        sub_id: None,
+        url: None,
+        type_: fb::MessageType::Eoce,
+        content_type: fb::Message::Eoce,
         content: Some(eoce_offset.as_union_value()),
     };
     let root = fb::WorkerMessage::create(&mut builder, &message_args);
@@ -1225,6 +1915,68 @@ fn serialize_eoce() -> Vec<u8> {
     builder.finished_data().to_vec()
 }
 
+fn serialize_link_preview(preview: &LinkPreview) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+
+    let url = builder.create_string(&preview.url);
+    let title = preview.title.as_deref().map(|t| builder.create_string(t));
+    let description = preview.description.as_deref().map(|d| builder.create_string(d));
+    let image = preview.image.as_deref().map(|i| builder.create_string(i));
+    let preview_offset = fb::LinkPreviewData::create(
+        &mut builder,
+        &fb::LinkPreviewDataArgs {
+            url: Some(url),
+            title,
+            description,
+            image,
+        },
+    );
+
+    let message_args = fb::WorkerMessageArgs {
+        sub_id: None,
+        url: None,
+        type_: fb::MessageType::LinkPreview,
+        content_type: fb::Message::LinkPreviewData,
+        content: Some(preview_offset.as_union_value()),
+    };
+    let root = fb::WorkerMessage::create(&mut builder, &message_args);
+    builder.finish(root, None);
+
+    builder.finished_data().to_vec()
+}
+
+fn serialize_thread_tree(tree: &fb::ThreadTreeT) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+    let tree_offset = tree.pack(&mut builder);
+    let message_args = fb::WorkerMessageArgs {
+        sub_id: None,
+        url: None,
+        type_: fb::MessageType::ThreadTree,
+        content_type: fb::Message::ThreadTree,
+        content: Some(tree_offset.as_union_value()),
+    };
+    let root = fb::WorkerMessage::create(&mut builder, &message_args);
+    builder.finish(root, None);
+
+    builder.finished_data().to_vec()
+}
+
+fn serialize_mention(mention: &fb::MentionDataT) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+    let mention_offset = mention.pack(&mut builder);
+    let message_args = fb::WorkerMessageArgs {
+        sub_id: None,
+        url: None,
+        type_: fb::MessageType::Mention,
+        content_type: fb::Message::MentionData,
+        content: Some(mention_offset.as_union_value()),
+    };
+    let root = fb::WorkerMessage::create(&mut builder, &message_args);
+    builder.finish(root, None);
+
+    builder.finished_data().to_vec()
+}
+
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
     use super::*;
@@ -1320,10 +2072,14 @@ mod tests {
     // Helper: Build an EOSE WorkerMessage (ConnectionStatus type)
     // The URL must be in the top-level WorkerMessage.url field for the parser to extract it
     fn build_eose_worker_message(sub_id: &str, url: &str) -> Vec<u8> {
+        build_status_worker_message(sub_id, url, "EOSE")
+    }
+
+    fn build_status_worker_message(sub_id: &str, url: &str, status: &str) -> Vec<u8> {
         let mut builder = FlatBufferBuilder::new();
         let sid = builder.create_string(sub_id);
         let url_off = builder.create_string(url);
-        let status_str = builder.create_string("EOSE");
+        let status_str = builder.create_string(status);
         let message_str = builder.create_string("");
 
         let conn_args = fb::ConnectionStatusArgs {
@@ -1665,6 +2421,523 @@ mod tests {
             .await;
     }
 
+    #[tokio::test]
+    async fn test_merged_window_emits_newest_limit_across_sources() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let (mut to_main_ch, from_parser_ch) = FuturesWorkerChannel::new_pair();
+                let (to_cache_tx, _to_cache_rx) = TokioWorkerChannel::new_pair();
+                let (to_connections_tx, _to_connections_rx) = TokioWorkerChannel::new_pair();
+
+                let parser = Arc::new(Parser::new(None));
+                let worker = ParserWorker::new(
+                    parser,
+                    Arc::from(to_cache_tx.clone_sender()),
+                    Arc::from(to_connections_tx.clone_sender()),
+                    from_parser_ch.clone_sender(),
+                );
+
+                worker
+                    .open_subscription(
+                        "merged".to_string(),
+                        vec![fb::RequestT {
+                            limit: 2,
+                            ..Default::default()
+                        }],
+                        fb::SubscriptionConfigT {
+                            merged_window: true,
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                    .unwrap();
+
+                let relay_event = |id: &str, created_at: u32| {
+                    let event = format!(
+                        r#"["EVENT","merged",{{"id":"{}","pubkey":"{}","created_at":{},"kind":1,"tags":[],"content":"","sig":"{}"}}]"#,
+                        id.repeat(64),
+                        "22".repeat(32),
+                        created_at,
+                        "33".repeat(64)
+                    );
+                    build_raw_worker_message("merged", "wss://relay.example.com", &event)
+                };
+
+                for relay in ["wss://relay.example.com", "wss://slow.example.com"] {
+                    worker
+                        .handle_message_single(
+                            "merged".to_string(),
+                            Arc::new(build_status_worker_message("merged", relay, "SUBSCRIBED")),
+                            ShardSource::Network,
+                        )
+                        .await;
+                }
+                for (id, created_at) in [("a", 100), ("c", 300), ("b", 200)] {
+                    worker
+                        .handle_message_single(
+                            "merged".to_string(),
+                            Arc::new(relay_event(id, created_at)),
+                            ShardSource::Network,
+                        )
+                        .await;
+                }
+                worker
+                    .handle_message_single(
+                        "merged".to_string(),
+                        Arc::new(serialize_eoce()),
+                        ShardSource::Cache,
+                    )
+                    .await;
+
+                // Nothing leaves the window before every relay reports EOSE.
+                worker
+                    .handle_message_single(
+                        "merged".to_string(),
+                        Arc::new(build_eose_worker_message("merged", "wss://relay.example.com")),
+                        ShardSource::Network,
+                    )
+                    .await;
+                let mut statuses = 0;
+                while let Ok(Ok(bytes)) = tokio::time::timeout(
+                    tokio::time::Duration::from_millis(20),
+                    to_main_ch.recv(),
+                )
+                .await
+                {
+                    for (_, data) in decode_tagged_batch(&bytes) {
+                        let wm = flatbuffers::root::<fb::WorkerMessage>(&data).unwrap();
+                        assert_eq!(wm.content_type(), fb::Message::ConnectionStatus);
+                        statuses += 1;
+                    }
+                }
+                assert_eq!(statuses, 3, "two SUBSCRIBED and one EOSE status");
+
+                worker
+                    .handle_message_single(
+                        "merged".to_string(),
+                        Arc::new(build_eose_worker_message("merged", "wss://slow.example.com")),
+                        ShardSource::Network,
+                    )
+                    .await;
+
+                let main_bytes = to_main_ch.recv().await.unwrap();
+                let frames = decode_tagged_batch(&main_bytes);
+                let types: Vec<fb::Message> = frames
+                    .iter()
+                    .map(|(_, data)| flatbuffers::root::<fb::WorkerMessage>(data).unwrap().content_type())
+                    .collect();
+                assert_eq!(
+                    types,
+                    vec![
+                        fb::Message::ParsedEvent,
+                        fb::Message::ParsedEvent,
+                        fb::Message::Eoce,
+                        fb::Message::ConnectionStatus
+                    ]
+                );
+                let ids: Vec<String> = frames[..2]
+                    .iter()
+                    .map(|(_, data)| {
+                        let wm = flatbuffers::root::<fb::WorkerMessage>(data).unwrap();
+                        wm.content_as_parsed_event().unwrap().id().to_string()
+                    })
+                    .collect();
+                assert_eq!(ids, vec!["c".repeat(64), "b".repeat(64)]);
+
+                // The window is full: only stragglers that sort inside it
+                // still go out. Events created since it opened are live.
+                let live = (now_millis() / 1000) as u32;
+                for (id, created_at) in [("d", 50), ("f", 250), ("e", live)] {
+                    worker
+                        .handle_message_single(
+                            "merged".to_string(),
+                            Arc::new(relay_event(id, created_at)),
+                            ShardSource::Network,
+                        )
+                        .await;
+                }
+                worker.flush_main("merged");
+                let main_bytes = to_main_ch.recv().await.unwrap();
+                let ids: Vec<String> = decode_tagged_batch(&main_bytes)
+                    .iter()
+                    .map(|(_, data)| {
+                        let wm = flatbuffers::root::<fb::WorkerMessage>(data).unwrap();
+                        wm.content_as_parsed_event().unwrap().id().to_string()
+                    })
+                    .collect();
+                assert_eq!(ids, vec!["f".repeat(64), "e".repeat(64)]);
+            })
+            .await;
+    }
+
+    /// Skips the persists of parsed events on the way.
+    async fn next_cache_request(rx: &mut TokioWorkerChannel) -> (String, Vec<fb::RequestT>) {
+        loop {
+            let bytes = rx.recv().await.unwrap();
+            let (tag, inner) = cache_input::split(&bytes).unwrap();
+            if tag == cache_input::TAG_REQUEST {
+                let req = flatbuffers::root::<fb::CacheRequest>(inner).unwrap().unpack();
+                return (req.sub_id, req.requests.unwrap_or_default());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_thread_fetches_ancestors_in_rounds_and_emits_tree() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let (mut to_main_ch, from_parser_ch) = FuturesWorkerChannel::new_pair();
+                let (to_cache_tx, mut to_cache_rx) = TokioWorkerChannel::new_pair();
+                let (to_connections_tx, _to_connections_rx) = TokioWorkerChannel::new_pair();
+
+                let parser = Arc::new(Parser::new(None));
+                let worker = ParserWorker::new(
+                    parser,
+                    Arc::from(to_cache_tx.clone_sender()),
+                    Arc::from(to_connections_tx.clone_sender()),
+                    from_parser_ch.clone_sender(),
+                );
+
+                let root = "a".repeat(64);
+                let focus = "f".repeat(64);
+                let round = "thread:thread:1";
+                worker
+                    .open_subscription(
+                        "thread".to_string(),
+                        vec![fb::RequestT {
+                            relays: Some(vec!["wss://hint.example.com".to_string()]),
+                            ..Default::default()
+                        }],
+                        fb::SubscriptionConfigT {
+                            thread: Some(focus.clone()),
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                    .unwrap();
+
+                let (sid, requests) = next_cache_request(&mut to_cache_rx).await;
+                assert_eq!(sid, "thread");
+                assert_eq!(requests[0].ids, Some(vec![focus.clone()]));
+                assert_eq!(
+                    requests[0].relays,
+                    Some(vec!["wss://hint.example.com".to_string()])
+                );
+
+                let relay_event = |sid: &str, id: &str, created_at: u32, tags: &str| {
+                    let event = format!(
+                        r#"["EVENT","{}",{{"id":"{}","pubkey":"{}","created_at":{},"kind":1,"tags":{},"content":"","sig":"{}"}}]"#,
+                        sid,
+                        id,
+                        "22".repeat(32),
+                        created_at,
+                        tags,
+                        "33".repeat(64)
+                    );
+                    build_raw_worker_message(sid, "wss://relay.example.com", &event)
+                };
+                let last_tree = |frames: &[(String, Vec<u8>)]| {
+                    frames.iter().rev().find_map(|(_, data)| {
+                        let wm = flatbuffers::root::<fb::WorkerMessage>(data).unwrap();
+                        wm.content_as_thread_tree().map(|t| t.unpack())
+                    })
+                };
+
+                let focus_tags = format!(r#"[["e","{}","wss://root.example.com","root"]]"#, root);
+                worker
+                    .handle_message_single(
+                        "thread".to_string(),
+                        Arc::new(relay_event("thread", &focus, 200, &focus_tags)),
+                        ShardSource::Network,
+                    )
+                    .await;
+                worker
+                    .handle_message_single(
+                        "thread".to_string(),
+                        Arc::new(serialize_eoce()),
+                        ShardSource::Cache,
+                    )
+                    .await;
+
+                // The focus names its root: a second round asks for it, on
+                // the hinted relay too, and for the root's replies.
+                let (sid, requests) = next_cache_request(&mut to_cache_rx).await;
+                assert_eq!(sid, round);
+                assert_eq!(requests[0].ids, Some(vec![root.clone()]));
+                assert_eq!(
+                    requests[0].relays,
+                    Some(vec!["wss://root.example.com".to_string()])
+                );
+
+                let frames = decode_tagged_batch(&to_main_ch.recv().await.unwrap());
+                let tree = last_tree(&frames).unwrap();
+                assert_eq!(tree.root, root);
+                assert!(!tree.complete);
+                assert!(tree.nodes[0].missing);
+                assert_eq!(tree.nodes[1].parent.as_deref(), Some(root.as_str()));
+
+                worker
+                    .handle_message_single(
+                        round.to_string(),
+                        Arc::new(relay_event(round, &root, 100, "[]")),
+                        ShardSource::Network,
+                    )
+                    .await;
+                for (sid, marker) in [
+                    (round, serialize_eoce()),
+                    (round, build_eose_worker_message(round, "wss://relay.example.com")),
+                    ("thread", build_eose_worker_message("thread", "wss://relay.example.com")),
+                ] {
+                    worker
+                        .handle_message_single(sid.to_string(), Arc::new(marker), ShardSource::Network)
+                        .await;
+                }
+
+                // Round events and trees arrive under the thread's sub id;
+                // the round's own EOCE/EOSE never reach the app.
+                let mut frames = Vec::new();
+                while let Ok(Some(bytes)) = tokio::time::timeout(
+                    tokio::time::Duration::from_millis(20),
+                    to_main_ch.recv(),
+                )
+                .await
+                .map(|r| r.ok())
+                {
+                    frames.extend(decode_tagged_batch(&bytes));
+                }
+                assert!(frames.iter().all(|(sid, _)| sid == "thread"));
+                let types: Vec<fb::Message> = frames
+                    .iter()
+                    .map(|(_, data)| flatbuffers::root::<fb::WorkerMessage>(data).unwrap().content_type())
+                    .collect();
+                assert_eq!(
+                    types,
+                    vec![
+                        fb::Message::Eoce,
+                        fb::Message::ParsedEvent,
+                        fb::Message::ThreadTree,
+                        fb::Message::ThreadTree,
+                        fb::Message::ConnectionStatus
+                    ]
+                );
+                let tree = last_tree(&frames).unwrap();
+                assert!(tree.complete);
+                let shape: Vec<_> = tree
+                    .nodes
+                    .iter()
+                    .map(|n| (n.id.clone(), n.depth, n.missing))
+                    .collect();
+                assert_eq!(shape, vec![(root.clone(), 0, false), (focus.clone(), 1, false)]);
+
+                worker.close_subscription("thread".to_string()).await.unwrap();
+                assert!(worker.subscriptions.read().unwrap().is_empty());
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_mentions_resolve_in_shared_batches() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let (mut to_main_ch, from_parser_ch) = FuturesWorkerChannel::new_pair();
+                let (to_cache_tx, mut to_cache_rx) = TokioWorkerChannel::new_pair();
+                let (to_connections_tx, _to_connections_rx) = TokioWorkerChannel::new_pair();
+
+                let parser = Arc::new(Parser::new(None));
+                let worker = ParserWorker::new(
+                    parser,
+                    Arc::from(to_cache_tx.clone_sender()),
+                    Arc::from(to_connections_tx.clone_sender()),
+                    from_parser_ch.clone_sender(),
+                );
+
+                // NIP-19 test vector.
+                let npub = "npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg";
+                let pubkey = "7e7e9c42a91bfef19fa929e5fda1b72e0ebc1a4c1141673e2794234d86addf4e";
+                let batch = "mentions:0";
+                worker
+                    .open_subscription(
+                        "feed".to_string(),
+                        vec![fb::RequestT::default()],
+                        fb::SubscriptionConfigT {
+                            resolve_mentions: true,
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(next_cache_request(&mut to_cache_rx).await.0, "feed");
+
+                let relay_event = |sid: &str, id: &str, kind: u16, pubkey: &str, content: &str| {
+                    let event = format!(
+                        r#"["EVENT","{}",{{"id":"{}","pubkey":"{}","created_at":100,"kind":{},"tags":[],"content":"{}","sig":"{}"}}]"#,
+                        sid,
+                        id,
+                        pubkey,
+                        kind,
+                        content,
+                        "33".repeat(64)
+                    );
+                    build_raw_worker_message(sid, "wss://relay.example.com", &event)
+                };
+                let note = format!("gm nostr:{}", npub);
+                worker
+                    .handle_message_single(
+                        "feed".to_string(),
+                        Arc::new(relay_event("feed", &"a".repeat(64), 1, &"22".repeat(32), &note)),
+                        ShardSource::Network,
+                    )
+                    .await;
+
+                // Once the batch delay passed, the profile is looked up in
+                // the cache first...
+                tokio::time::sleep(tokio::time::Duration::from_millis(
+                    mentions::MENTION_BATCH_DELAY_MS,
+                ))
+                .await;
+                worker.dispatch_mentions().await;
+                let (sid, requests) = next_cache_request(&mut to_cache_rx).await;
+                assert_eq!(sid, batch);
+                assert!(requests[0].cache_only);
+                assert_eq!(requests[0].kinds, Some(vec![0]));
+                assert_eq!(requests[0].authors, Some(vec![pubkey.to_string()]));
+
+                // ...and fetched from relays when the cache has none.
+                worker
+                    .handle_message_single(batch.to_string(), Arc::new(serialize_eoce()), ShardSource::Cache)
+                    .await;
+                let (sid, requests) = next_cache_request(&mut to_cache_rx).await;
+                assert_eq!(sid, batch);
+                assert!(requests[0].no_cache);
+
+                let profile = r#"{\"name\":\"alice\",\"display_name\":\"Alice\"}"#;
+                for (marker, source) in [
+                    (relay_event(batch, &"b".repeat(64), 0, pubkey, profile), ShardSource::Network),
+                    (build_eose_worker_message(batch, "wss://relay.example.com"), ShardSource::Network),
+                ] {
+                    worker
+                        .handle_message_single(batch.to_string(), Arc::new(marker), source)
+                        .await;
+                }
+
+                // The batch's events and markers stay internal; the feed gets
+                // its note, then the mention.
+                let frames = decode_tagged_batch(&to_main_ch.recv().await.unwrap());
+                assert!(frames.iter().all(|(sid, _)| sid == "feed"));
+                let types: Vec<fb::Message> = frames
+                    .iter()
+                    .map(|(_, data)| flatbuffers::root::<fb::WorkerMessage>(data).unwrap().content_type())
+                    .collect();
+                assert_eq!(types, vec![fb::Message::ParsedEvent, fb::Message::MentionData]);
+                let mention = flatbuffers::root::<fb::WorkerMessage>(&frames[1].1)
+                    .unwrap()
+                    .content_as_mention_data()
+                    .unwrap()
+                    .unpack();
+                assert_eq!(mention.id, pubkey);
+                assert_eq!(mention.name.as_deref(), Some("Alice"));
+
+                // Other relays may still send a newer profile: the batch
+                // stays open until its deadline.
+                worker.dispatch_mentions().await;
+                assert!(worker.subscriptions.read().unwrap().contains_key(batch));
+
+                // Later references are answered without another lookup.
+                worker
+                    .handle_message_single(
+                        "feed".to_string(),
+                        Arc::new(relay_event("feed", &"c".repeat(64), 1, &"22".repeat(32), &note)),
+                        ShardSource::Network,
+                    )
+                    .await;
+                worker.flush_main("feed");
+                let frames = decode_tagged_batch(&to_main_ch.recv().await.unwrap());
+                assert_eq!(frames.len(), 2);
+                assert!(flatbuffers::root::<fb::WorkerMessage>(&frames[1].1)
+                    .unwrap()
+                    .content_as_mention_data()
+                    .is_some());
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_nip05_lookups_only_for_opted_in_subscriptions() {
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let (_to_main_ch, from_parser_ch) = FuturesWorkerChannel::new_pair();
+                let (to_cache_tx, mut to_cache_rx) = TokioWorkerChannel::new_pair();
+                let (to_connections_tx, _to_connections_rx) = TokioWorkerChannel::new_pair();
+
+                let parser = Arc::new(Parser::new(None));
+                let worker = ParserWorker::new(
+                    parser,
+                    Arc::from(to_cache_tx.clone_sender()),
+                    Arc::from(to_connections_tx.clone_sender()),
+                    from_parser_ch.clone_sender(),
+                );
+                for (sid, verify_nip05) in [("plain", false), ("verified", true)] {
+                    worker
+                        .open_subscription(
+                            sid.to_string(),
+                            vec![fb::RequestT::default()],
+                            fb::SubscriptionConfigT {
+                                verify_nip05,
+                                ..Default::default()
+                            },
+                        )
+                        .await
+                        .unwrap();
+                    assert_eq!(next_cache_request(&mut to_cache_rx).await.0, sid);
+                }
+
+                let profile = |sid: &str| {
+                    let event = format!(
+                        r#"["EVENT","{}",{{"id":"{}","pubkey":"{}","created_at":100,"kind":0,"tags":[],"content":"{}","sig":"{}"}}]"#,
+                        sid,
+                        "a".repeat(64),
+                        "22".repeat(32),
+                        r#"{\"name\":\"bob\",\"nip05\":\"bob@example.com\"}"#,
+                        "33".repeat(64)
+                    );
+                    build_raw_worker_message(sid, "wss://relay.example.com", &event)
+                };
+                let waiting = || {
+                    worker
+                        .nip05_waiters
+                        .lock()
+                        .unwrap()
+                        .get("bob@example.com")
+                        .map(|waiters| {
+                            waiters.iter().map(|(sid, _)| sid.clone()).collect::<Vec<_>>()
+                        })
+                };
+
+                worker
+                    .handle_message_single(
+                        "plain".to_string(),
+                        Arc::new(profile("plain")),
+                        ShardSource::Network,
+                    )
+                    .await;
+                assert_eq!(waiting(), None);
+
+                worker
+                    .handle_message_single(
+                        "verified".to_string(),
+                        Arc::new(profile("verified")),
+                        ShardSource::Network,
+                    )
+                    .await;
+                assert_eq!(waiting(), Some(vec!["verified".to_string()]));
+            })
+            .await;
+    }
+
     #[cfg(feature = "crypto")]
     #[tokio::test]
     async fn test_optimistic_publish_matches_subscription_subset_id() {
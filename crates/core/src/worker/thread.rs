@@ -0,0 +1,805 @@
+//! ThreadResolver for subscriptions opened with `thread: <event id>`.
+//!
+//! The parser worker fetches the thread around the focus event in rounds.
+//! Round 0 asks for the focus event and its replies; each later round asks
+//! for what the events seen so far point at but nobody requested yet: the
+//! root and every named parent by id (also on the relays their tags hint
+//! at), the root's replies once the root is known, and the `e`-tagged
+//! replies of every other fetched event, which catches positional replies
+//! that never name the root. Every round is its
+//! own relay subscription, so a follow-up REQ never replaces one still in
+//! flight; their events are emitted under the thread's subscription id.
+//!
+//! Thread position comes from the tags:
+//! - NIP-10: `e` tags marked `root`/`reply`. Without markers the deprecated
+//!   positional scheme applies (first = root, last = parent); `mention`
+//!   tags never count.
+//! - NIP-22 (kind 1111): `E` is the root, `e` the parent.
+//!
+//! The tree is ordered depth-first, siblings oldest first. A parent that is
+//! named but not (yet) fetched becomes a `missing` placeholder under the
+//! root, so its replies keep their place. While rounds are in flight the
+//! tree is emitted at round boundaries (EOCE/EOSE); once every round
+//! finished (cache done and a relay EOSE, or the deadline) it is emitted
+//! with `complete`. After that every change is emitted as a `delta` tree
+//! holding only the nodes that are new or moved; a new root re-sends the
+//! full tree.
+
+use crate::generated::nostr::fb;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// A round counts as finished this long after it opened, even if the cache
+/// or every relay is still silent.
+pub const THREAD_ROUND_DEADLINE_MS: u64 = 3_000;
+/// No rounds are opened past this; a longer chain of unknown parents stays
+/// as placeholders.
+pub const MAX_THREAD_ROUNDS: usize = 16;
+/// `limit` of each reply query.
+const MAX_THREAD_REPLIES: i32 = 500;
+
+/// An event id named by a tag, with the tag's relay hint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventRef {
+    pub id: String,
+    pub relays: Vec<String>,
+}
+
+/// Where an event sits in its thread, as its tags tell it.
+#[derive(Debug, Default, PartialEq)]
+pub struct ThreadRefs {
+    pub root: Option<EventRef>,
+    pub parent: Option<EventRef>,
+}
+
+fn marker(tag: &[String]) -> Option<&str> {
+    tag.get(3).map(String::as_str)
+}
+
+fn event_ref(tag: &[String]) -> EventRef {
+    EventRef {
+        id: tag[1].clone(),
+        relays: tag
+            .get(2)
+            .filter(|r| !r.is_empty())
+            .cloned()
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// Root and parent of an event: NIP-22 `E`/`e` for kind 1111, NIP-10 `e`
+/// tags (marked, else positional) for every other kind.
+pub fn thread_refs(kind: u16, tags: &[Vec<String>]) -> ThreadRefs {
+    let refs = |name: &'static str| {
+        tags.iter()
+            .filter(move |t| t.len() >= 2 && t[0] == name && !t[1].is_empty())
+    };
+    if kind == 1111 {
+        return ThreadRefs {
+            root: refs("E").next().map(|t| event_ref(t)),
+            parent: refs("e").next().map(|t| event_ref(t)),
+        };
+    }
+
+    if refs("e").any(|t| matches!(marker(t), Some("root" | "reply"))) {
+        let root = refs("e")
+            .find(|t| marker(t) == Some("root"))
+            .map(|t| event_ref(t));
+        let parent = refs("e")
+            .find(|t| marker(t) == Some("reply"))
+            .map(|t| event_ref(t))
+            .or_else(|| root.clone());
+        ThreadRefs { root, parent }
+    } else {
+        let positional: Vec<_> = refs("e").filter(|t| marker(t) != Some("mention")).collect();
+        ThreadRefs {
+            root: positional.first().map(|t| event_ref(t)),
+            parent: positional.last().map(|t| event_ref(t)),
+        }
+    }
+}
+
+/// (id, kind, created_at, tags) of a serialized ParsedEvent/NostrEvent
+/// WorkerMessage.
+fn event_fields(output: &[u8]) -> Option<(String, u16, u32, Vec<Vec<String>>)> {
+    let wm = flatbuffers::root::<fb::WorkerMessage>(output).ok()?;
+    let tags = |tags: flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<fb::StringVec<'_>>>| {
+        tags.iter()
+            .map(|t| {
+                t.items()
+                    .map(|items| items.iter().map(str::to_string).collect())
+                    .unwrap_or_default()
+            })
+            .collect()
+    };
+    match wm.content_type() {
+        fb::Message::ParsedEvent => {
+            let ev = wm.content_as_parsed_event()?;
+            Some((
+                ev.id().to_string(),
+                ev.kind(),
+                ev.created_at(),
+                tags(ev.tags()),
+            ))
+        }
+        fb::Message::NostrEvent => {
+            let ev = wm.content_as_nostr_event()?;
+            let created_at = ev.created_at().max(0) as u32;
+            Some((ev.id().to_string(), ev.kind(), created_at, tags(ev.tags())))
+        }
+        _ => None,
+    }
+}
+
+fn replies(ids: &[String], tag: &str, kinds: Vec<u16>) -> fb::RequestT {
+    let items = std::iter::once(tag.to_string()).chain(ids.iter().cloned());
+    fb::RequestT {
+        kinds: Some(kinds),
+        tags: Some(vec![fb::StringVecT {
+            items: Some(items.collect()),
+        }]),
+        limit: MAX_THREAD_REPLIES,
+        ..Default::default()
+    }
+}
+
+struct Node {
+    root: Option<String>,
+    parent: Option<String>,
+    created_at: u32,
+}
+
+struct Round {
+    sub_id: String,
+    opened_at: u64,
+    cache_done: bool,
+    relay_eosed: bool,
+}
+
+impl Round {
+    fn deadline(&self) -> u64 {
+        self.opened_at + THREAD_ROUND_DEADLINE_MS
+    }
+
+    fn finished(&self, now: u64) -> bool {
+        (self.cache_done && self.relay_eosed) || now >= self.deadline()
+    }
+}
+
+/// (parent, depth, missing) of an emitted node.
+type Placement = (Option<String>, u16, bool);
+
+pub struct ThreadResolver {
+    focus: String,
+    nodes: FxHashMap<String, Node>,
+    /// Ids named as a root or parent, with every relay hint seen for them.
+    referenced: FxHashMap<String, Vec<String>>,
+    requested_ids: FxHashSet<String>,
+    requested_replies: FxHashSet<String>,
+    rounds: Vec<Round>,
+    /// The tree changed since it was last emitted.
+    dirty: bool,
+    /// `complete` flag of the last emitted tree.
+    complete: bool,
+    /// Set once the thread completed: from then on every change is emitted.
+    live: bool,
+    /// Root and node placements of the emitted trees, to cut deltas from.
+    emitted_root: String,
+    emitted: FxHashMap<String, Placement>,
+}
+
+impl ThreadResolver {
+    /// `relays` are hints for the focus event itself.
+    pub fn new(focus: String, relays: Vec<String>) -> Self {
+        let mut referenced = FxHashMap::default();
+        referenced.insert(focus.clone(), relays);
+        Self {
+            focus,
+            nodes: FxHashMap::default(),
+            referenced,
+            requested_ids: FxHashSet::default(),
+            requested_replies: FxHashSet::default(),
+            rounds: Vec::new(),
+            dirty: false,
+            complete: false,
+            live: false,
+            emitted_root: String::new(),
+            emitted: FxHashMap::default(),
+        }
+    }
+
+    pub fn focus(&self) -> &str {
+        &self.focus
+    }
+
+    /// Thread root: the focus event's root, else the top of its parent
+    /// chain as far as it was fetched.
+    pub fn root(&self) -> String {
+        let mut id = self.focus.clone();
+        let mut seen = FxHashSet::default();
+        while let Some(node) = self.nodes.get(&id) {
+            if !seen.insert(id.clone()) {
+                break;
+            }
+            match node.root.as_ref().or(node.parent.as_ref()) {
+                Some(up) => id = up.clone(),
+                None => break,
+            }
+        }
+        id
+    }
+
+    /// Requests for what is known but not fetched yet; empty when there is
+    /// nothing new or the round cap is reached.
+    pub fn next_round(&mut self) -> Vec<fb::RequestT> {
+        if self.rounds.len() >= MAX_THREAD_ROUNDS {
+            return Vec::new();
+        }
+        let mut requests = Vec::new();
+
+        let mut ids: Vec<String> = self
+            .referenced
+            .keys()
+            .filter(|id| !self.nodes.contains_key(*id) && !self.requested_ids.contains(*id))
+            .cloned()
+            .collect();
+        ids.sort();
+        for id in &ids {
+            self.requested_ids.insert(id.clone());
+            let hints = &self.referenced[id];
+            if !hints.is_empty() {
+                requests.push(fb::RequestT {
+                    ids: Some(vec![id.clone()]),
+                    relays: Some(hints.clone()),
+                    cache_first: true,
+                    ..Default::default()
+                });
+            }
+        }
+        if !ids.is_empty() {
+            requests.push(fb::RequestT {
+                ids: Some(ids),
+                cache_first: true,
+                ..Default::default()
+            });
+        }
+
+        for id in [self.focus.clone(), self.root()] {
+            if self.requested_replies.insert(id.clone()) {
+                let id = std::slice::from_ref(&id);
+                requests.push(replies(id, "#e", vec![1, 1111]));
+                requests.push(replies(id, "#E", vec![1111]));
+            }
+        }
+        // NIP-22 comments and marked replies name the root, so only
+        // positional kind 1 replies can hide below the other events.
+        let mut deeper: Vec<String> = self
+            .nodes
+            .keys()
+            .filter(|id| !self.requested_replies.contains(*id))
+            .cloned()
+            .collect();
+        if !deeper.is_empty() {
+            deeper.sort();
+            self.requested_replies.extend(deeper.iter().cloned());
+            requests.push(replies(&deeper, "#e", vec![1]));
+        }
+        requests
+    }
+
+    pub fn open_round(&mut self, sub_id: String, now: u64) {
+        self.rounds.push(Round {
+            sub_id,
+            opened_at: now,
+            cache_done: false,
+            relay_eosed: false,
+        });
+    }
+
+    pub fn round_ids(&self) -> impl Iterator<Item = &str> {
+        self.rounds.iter().map(|r| r.sub_id.as_str())
+    }
+
+    pub fn on_eoce(&mut self, sub_id: &str) {
+        if let Some(round) = self.rounds.iter_mut().find(|r| r.sub_id == sub_id) {
+            round.cache_done = true;
+        }
+    }
+
+    pub fn on_eose(&mut self, sub_id: &str) {
+        if let Some(round) = self.rounds.iter_mut().find(|r| r.sub_id == sub_id) {
+            round.relay_eosed = true;
+        }
+    }
+
+    /// Every round finished or ran past the deadline.
+    pub fn settled(&self, now: u64) -> bool {
+        self.rounds.iter().all(|r| r.finished(now))
+    }
+
+    /// When the next unfinished round runs out, if any is left at `now`.
+    pub fn next_deadline(&self, now: u64) -> Option<u64> {
+        self.rounds
+            .iter()
+            .filter(|r| !r.finished(now))
+            .map(Round::deadline)
+            .min()
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.live
+    }
+
+    /// Whether the last emitted tree was complete.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Take an event output of the thread's subscription or one of its
+    /// rounds. Returns whether it joined the tree; events that neither are
+    /// wanted nor point into the thread (e.g. `mention`s) are ignored.
+    pub fn offer(&mut self, output: &[u8]) -> bool {
+        let Some((id, kind, created_at, tags)) = event_fields(output) else {
+            return false;
+        };
+        if self.nodes.contains_key(&id) {
+            return false;
+        }
+        let refs = thread_refs(kind, &tags);
+        let in_thread =
+            |r: &EventRef| self.nodes.contains_key(&r.id) || self.referenced.contains_key(&r.id);
+        let wanted = self.referenced.contains_key(&id)
+            || refs.root.as_ref().is_some_and(in_thread)
+            || refs.parent.as_ref().is_some_and(in_thread);
+        if !wanted {
+            return false;
+        }
+
+        for r in [&refs.root, &refs.parent].into_iter().flatten() {
+            if r.id == id {
+                continue;
+            }
+            let hints = self.referenced.entry(r.id.clone()).or_default();
+            for relay in &r.relays {
+                if !hints.contains(relay) {
+                    hints.push(relay.clone());
+                }
+            }
+        }
+        let not_self = |r: EventRef| (r.id != id).then_some(r.id);
+        self.nodes.insert(
+            id.clone(),
+            Node {
+                root: refs.root.and_then(not_self),
+                parent: refs.parent.and_then(not_self),
+                created_at,
+            },
+        );
+        self.dirty = true;
+        true
+    }
+
+    /// The tree to emit, if any: at round boundaries when it changed, once
+    /// the thread completes, and on every change while live, as a delta
+    /// unless the root moved.
+    pub fn update(&mut self, now: u64, boundary: bool) -> Option<fb::ThreadTreeT> {
+        let complete = self.settled(now);
+        if complete == self.complete && !(self.dirty && (boundary || self.live)) {
+            return None;
+        }
+        let flipped = complete != self.complete;
+        let delta = self.live;
+        self.complete = complete;
+        self.live |= complete;
+        self.dirty = false;
+
+        let mut tree = self.tree();
+        let delta = delta && tree.root == self.emitted_root;
+        if !delta {
+            self.emitted.clear();
+            self.emitted_root = tree.root.clone();
+        }
+        tree.nodes.retain(|n| {
+            let placement = (n.parent.clone(), n.depth, n.missing);
+            self.emitted.insert(n.id.clone(), placement.clone()) != Some(placement) || !delta
+        });
+        if delta && tree.nodes.is_empty() && !flipped {
+            return None;
+        }
+        tree.delta = delta;
+        Some(tree)
+    }
+
+    pub fn tree(&self) -> fb::ThreadTreeT {
+        let root = self.root();
+
+        // Effective parent of every node but the root; unknown parents
+        // become placeholders hung under the root.
+        let mut parent_of: FxHashMap<&str, &str> = FxHashMap::default();
+        let mut created: FxHashMap<&str, u32> = FxHashMap::default();
+        for (id, node) in &self.nodes {
+            created.insert(id, node.created_at);
+            if *id == root {
+                continue;
+            }
+            let parent = node
+                .parent
+                .as_deref()
+                .or(node.root.as_deref())
+                .unwrap_or(&root);
+            parent_of.insert(id, parent);
+        }
+        for (id, node) in &self.nodes {
+            if *id == root {
+                continue;
+            }
+            let parent = parent_of[id.as_str()];
+            if parent != root && !self.nodes.contains_key(parent) {
+                parent_of.insert(parent, &root);
+                let at = created.entry(parent).or_insert(u32::MAX);
+                *at = (*at).min(node.created_at);
+            }
+        }
+
+        let mut children: FxHashMap<&str, Vec<&str>> = FxHashMap::default();
+        for (id, parent) in &parent_of {
+            children.entry(parent).or_default().push(id);
+        }
+        let order = |id: &&str| (created.get(id).copied().unwrap_or(0), id.to_string());
+        for siblings in children.values_mut() {
+            siblings.sort_by_key(order);
+        }
+
+        let mut nodes = Vec::with_capacity(parent_of.len() + 1);
+        let mut visited: FxHashSet<&str> = FxHashSet::default();
+        let fetched = &self.nodes;
+        walk_tree(&root, None, 0, &children, fetched, &mut visited, &mut nodes);
+        // Parent cycles in malformed tags never reach the root: hang them
+        // under it rather than dropping them.
+        let mut stranded: Vec<&str> = parent_of.keys().copied().collect();
+        stranded.sort_by_key(order);
+        for id in stranded {
+            walk_tree(
+                id,
+                Some(&root),
+                1,
+                &children,
+                fetched,
+                &mut visited,
+                &mut nodes,
+            );
+        }
+
+        fb::ThreadTreeT {
+            focus: self.focus.clone(),
+            root,
+            nodes,
+            complete: self.complete,
+            delta: false,
+        }
+    }
+}
+
+/// Appends `start` and its unvisited descendants depth-first.
+fn walk_tree<'a>(
+    start: &'a str,
+    parent: Option<&'a str>,
+    depth: u16,
+    children: &FxHashMap<&'a str, Vec<&'a str>>,
+    fetched: &FxHashMap<String, Node>,
+    visited: &mut FxHashSet<&'a str>,
+    nodes: &mut Vec<fb::ThreadNodeT>,
+) {
+    let mut stack = vec![(start, parent, depth)];
+    while let Some((id, parent, depth)) = stack.pop() {
+        if !visited.insert(id) {
+            continue;
+        }
+        nodes.push(fb::ThreadNodeT {
+            id: id.to_string(),
+            parent: parent.map(str::to_string),
+            depth,
+            missing: !fetched.contains_key(id),
+        });
+        if let Some(kids) = children.get(id) {
+            for kid in kids.iter().rev() {
+                stack.push((kid, Some(id), depth.saturating_add(1)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flatbuffers::FlatBufferBuilder;
+
+    fn tag(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn event(id: &str, kind: u16, created_at: i32, tags: &[Vec<String>]) -> Vec<u8> {
+        let mut builder = FlatBufferBuilder::new();
+        let tag_offsets: Vec<_> = tags
+            .iter()
+            .map(|t| {
+                let items: Vec<_> = t.iter().map(|s| builder.create_string(s)).collect();
+                let items = builder.create_vector(&items);
+                fb::StringVec::create(&mut builder, &fb::StringVecArgs { items: Some(items) })
+            })
+            .collect();
+        let tags = builder.create_vector(&tag_offsets);
+        let id = builder.create_string(id);
+        let pubkey = builder.create_string("pk");
+        let content = builder.create_string("");
+        let sig = builder.create_string("sig");
+        let ev = fb::NostrEvent::create(
+            &mut builder,
+            &fb::NostrEventArgs {
+                id: Some(id),
+                pubkey: Some(pubkey),
+                kind,
+                content: Some(content),
+                tags: Some(tags),
+                created_at,
+                sig: Some(sig),
+            },
+        );
+        let wm = fb::WorkerMessage::create(
+            &mut builder,
+            &fb::WorkerMessageArgs {
+                sub_id: None,
+                url: None,
+                type_: fb::MessageType::NostrEvent,
+                content_type: fb::Message::NostrEvent,
+                content: Some(ev.as_union_value()),
+            },
+        );
+        builder.finish(wm, None);
+        builder.finished_data().to_vec()
+    }
+
+    /// (id, parent, depth, missing) per node, in tree order.
+    fn shape(tree: &fb::ThreadTreeT) -> Vec<(String, Option<String>, u16, bool)> {
+        tree.nodes
+            .iter()
+            .map(|n| (n.id.clone(), n.parent.clone(), n.depth, n.missing))
+            .collect()
+    }
+
+    fn node(
+        id: &str,
+        parent: Option<&str>,
+        depth: u16,
+        missing: bool,
+    ) -> (String, Option<String>, u16, bool) {
+        (id.to_string(), parent.map(str::to_string), depth, missing)
+    }
+
+    #[test]
+    fn reads_marked_positional_and_nip22_refs() {
+        let marked = thread_refs(
+            1,
+            &[
+                tag(&["e", "root", "wss://r", "root"]),
+                tag(&["e", "quoted", "", "mention"]),
+                tag(&["e", "parent", "", "reply"]),
+            ],
+        );
+        assert_eq!(marked.root.as_ref().unwrap().id, "root");
+        assert_eq!(marked.root.unwrap().relays, vec!["wss://r"]);
+        assert_eq!(marked.parent.unwrap().id, "parent");
+
+        // A direct reply to the root only marks the root.
+        let direct = thread_refs(1, &[tag(&["e", "root", "", "root"])]);
+        assert_eq!(direct.parent.unwrap().id, "root");
+
+        let positional = thread_refs(
+            1,
+            &[
+                tag(&["e", "root"]),
+                tag(&["e", "middle"]),
+                tag(&["e", "parent"]),
+            ],
+        );
+        assert_eq!(positional.root.unwrap().id, "root");
+        assert_eq!(positional.parent.unwrap().id, "parent");
+
+        let comment = thread_refs(
+            1111,
+            &[
+                tag(&["E", "root", "wss://r"]),
+                tag(&["e", "parent"]),
+                tag(&["k", "1111"]),
+            ],
+        );
+        assert_eq!(comment.root.unwrap().id, "root");
+        assert_eq!(comment.parent.unwrap().id, "parent");
+
+        assert_eq!(thread_refs(1, &[tag(&["p", "pk"])]), ThreadRefs::default());
+    }
+
+    #[test]
+    fn rounds_ask_for_ancestors_and_root_replies_once() {
+        let mut thread = ThreadResolver::new("focus".into(), vec!["wss://hint".into()]);
+        let first = thread.next_round();
+        // Pinned to the hint, then unpinned, then replies by `e` and `E`.
+        assert_eq!(first.len(), 4);
+        assert_eq!(
+            first[0].relays.as_deref(),
+            Some(&["wss://hint".to_string()][..])
+        );
+        assert_eq!(first[1].ids.as_deref(), Some(&["focus".to_string()][..]));
+        assert_eq!(
+            first[2].tags.as_ref().unwrap()[0].items.as_deref(),
+            Some(&["#e".to_string(), "focus".to_string()][..])
+        );
+        thread.open_round("t".into(), 0);
+
+        thread.offer(&event(
+            "focus",
+            1,
+            30,
+            &[
+                tag(&["e", "root", "", "root"]),
+                tag(&["e", "parent", "wss://p", "reply"]),
+            ],
+        ));
+        let second = thread.next_round();
+        let ids: Vec<_> = second.iter().filter_map(|r| r.ids.clone()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                vec!["parent".to_string()],
+                vec!["parent".to_string(), "root".to_string()]
+            ]
+        );
+        let reply_anchors: Vec<_> = second
+            .iter()
+            .filter_map(|r| r.tags.as_ref())
+            .map(|t| t[0].items.as_ref().unwrap()[1].clone())
+            .collect();
+        assert_eq!(reply_anchors, vec!["root", "root"]);
+
+        assert!(thread.next_round().is_empty());
+    }
+
+    #[test]
+    fn rounds_ask_for_replies_below_the_root() {
+        let mut thread = ThreadResolver::new("focus".into(), Vec::new());
+        thread.next_round();
+        let root = tag(&["e", "root", "", "root"]);
+        thread.offer(&event("focus", 1, 30, std::slice::from_ref(&root)));
+        thread.offer(&event("root", 1, 10, &[]));
+        thread.offer(&event(
+            "middle",
+            1,
+            40,
+            &[root.clone(), tag(&["e", "focus", "", "reply"])],
+        ));
+        let round = thread.next_round();
+        let deeper = round.last().unwrap();
+        assert_eq!(deeper.kinds.as_deref(), Some(&[1][..]));
+        assert_eq!(
+            deeper.tags.as_ref().unwrap()[0].items.as_deref(),
+            Some(&["#e".to_string(), "middle".to_string()][..])
+        );
+
+        // A positional reply that only names its parent.
+        assert!(thread.offer(&event("deep", 1, 50, &[tag(&["e", "middle"])])));
+        assert_eq!(
+            shape(&thread.tree()).last(),
+            Some(&node("deep", Some("middle"), 3, false))
+        );
+        assert_eq!(thread.next_round().len(), 1);
+    }
+
+    #[test]
+    fn builds_ordered_tree_with_placeholders() {
+        let mut thread = ThreadResolver::new("focus".into(), Vec::new());
+        let root = tag(&["e", "root", "", "root"]);
+        thread.offer(&event(
+            "focus",
+            1,
+            30,
+            &[root.clone(), tag(&["e", "gone", "", "reply"])],
+        ));
+        thread.offer(&event("root", 1, 10, &[]));
+        thread.offer(&event(
+            "late",
+            1,
+            50,
+            &[root.clone(), tag(&["e", "root", "", "reply"])],
+        ));
+        thread.offer(&event("early", 1, 20, std::slice::from_ref(&root)));
+        // Positional reply to the focus, and a NIP-22 comment under it.
+        thread.offer(&event(
+            "child",
+            1,
+            40,
+            &[tag(&["e", "root"]), tag(&["e", "focus"])],
+        ));
+        thread.offer(&event(
+            "comment",
+            1111,
+            45,
+            &[tag(&["E", "root"]), tag(&["e", "child"])],
+        ));
+        // Mentions are not replies.
+        assert!(!thread.offer(&event(
+            "quote",
+            1,
+            60,
+            &[tag(&["e", "focus", "", "mention"])]
+        )));
+
+        let tree = thread.tree();
+        assert_eq!(tree.root, "root");
+        assert_eq!(
+            shape(&tree),
+            vec![
+                node("root", None, 0, false),
+                node("early", Some("root"), 1, false),
+                node("gone", Some("root"), 1, true),
+                node("focus", Some("gone"), 2, false),
+                node("child", Some("focus"), 3, false),
+                node("comment", Some("child"), 4, false),
+                node("late", Some("root"), 1, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_root_is_a_placeholder() {
+        let mut thread = ThreadResolver::new("focus".into(), Vec::new());
+        thread.offer(&event(
+            "focus",
+            1111,
+            5,
+            &[tag(&["E", "root"]), tag(&["e", "root"])],
+        ));
+        let tree = thread.tree();
+        assert_eq!(
+            shape(&tree),
+            vec![
+                node("root", None, 0, true),
+                node("focus", Some("root"), 1, false)
+            ]
+        );
+    }
+
+    #[test]
+    fn emits_at_boundaries_then_on_every_change() {
+        let mut thread = ThreadResolver::new("focus".into(), Vec::new());
+        thread.next_round();
+        thread.open_round("t".into(), 0);
+        thread.offer(&event("focus", 1, 1, &[]));
+        assert!(thread.update(10, false).is_none());
+
+        thread.on_eoce("t");
+        let tree = thread.update(10, true).unwrap();
+        assert!(!tree.complete);
+        assert!(thread.update(10, true).is_none());
+
+        thread.on_eose("t");
+        assert!(thread.update(20, false).unwrap().complete);
+        assert!(thread.is_live());
+
+        // Live changes only carry what is new.
+        thread.offer(&event("reply", 1, 2, &[tag(&["e", "focus"])]));
+        let tree = thread.update(30, false).unwrap();
+        assert!(tree.delta);
+        assert_eq!(shape(&tree), vec![node("reply", Some("focus"), 1, false)]);
+        assert!(thread.update(40, false).is_none());
+
+        // A round that never answers still finishes at the deadline.
+        thread.open_round("t:1".into(), 100);
+        assert!(!thread.update(200, false).unwrap().complete);
+        assert!(
+            thread
+                .update(100 + THREAD_ROUND_DEADLINE_MS, false)
+                .unwrap()
+                .complete
+        );
+    }
+}
@@ -1,9 +1,14 @@
+// Each test binary uses a different subset of these helpers.
+#![allow(dead_code)]
+
 use async_trait::async_trait;
 use nipworker_core::traits::{
     RelayTransport, Signer, SignerError, Storage, StorageError, TransportError, TransportStatus,
 };
-use nipworker_core::types::nostr::{Filter, Template};
+use nipworker_core::types::nostr::Filter;
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 use std::sync::{Arc, Mutex, RwLock};
 
 // ============================================================================
@@ -17,10 +22,13 @@ pub enum TransportCall {
     Send(String, String),
 }
 
+type MessageCallbacks = HashMap<String, Box<dyn Fn(String)>>;
+type StatusCallbacks = HashMap<String, Box<dyn Fn(TransportStatus)>>;
+
 pub struct MockRelayTransport {
     calls: Arc<Mutex<Vec<TransportCall>>>,
-    message_callbacks: Arc<RwLock<HashMap<String, Box<dyn Fn(String)>>>>,
-    status_callbacks: Arc<RwLock<HashMap<String, Box<dyn Fn(TransportStatus)>>>>,
+    message_callbacks: Rc<RefCell<MessageCallbacks>>,
+    status_callbacks: Rc<RefCell<StatusCallbacks>>,
     connect_result: Arc<RwLock<Result<(), TransportError>>>,
     send_fail_count: Arc<Mutex<usize>>,
     closed_urls: Arc<Mutex<HashSet<String>>>,
@@ -30,8 +38,8 @@ impl MockRelayTransport {
     pub fn new() -> Self {
         Self {
             calls: Arc::new(Mutex::new(Vec::new())),
-            message_callbacks: Arc::new(RwLock::new(HashMap::new())),
-            status_callbacks: Arc::new(RwLock::new(HashMap::new())),
+            message_callbacks: Rc::new(RefCell::new(HashMap::new())),
+            status_callbacks: Rc::new(RefCell::new(HashMap::new())),
             connect_result: Arc::new(RwLock::new(Ok(()))),
             send_fail_count: Arc::new(Mutex::new(0)),
             closed_urls: Arc::new(Mutex::new(HashSet::new())),
@@ -47,7 +55,7 @@ impl MockRelayTransport {
     }
 
     pub fn invoke_message_callback(&self, url: &str, msg: String) {
-        let cbs = self.message_callbacks.read().unwrap();
+        let cbs = self.message_callbacks.borrow();
         if let Some(cb) = cbs.get(url) {
             cb(msg);
         }
@@ -57,7 +65,7 @@ impl MockRelayTransport {
         if matches!(status, TransportStatus::Closed { .. }) {
             self.closed_urls.lock().unwrap().insert(url.to_string());
         }
-        let cbs = self.status_callbacks.read().unwrap();
+        let cbs = self.status_callbacks.borrow();
         if let Some(cb) = cbs.get(url) {
             cb(status);
         }
@@ -120,15 +128,13 @@ impl RelayTransport for MockRelayTransport {
 
     fn on_message(&self, url: &str, callback: Box<dyn Fn(String)>) {
         self.message_callbacks
-            .write()
-            .unwrap()
+            .borrow_mut()
             .insert(url.to_string(), callback);
     }
 
     fn on_status(&self, url: &str, callback: Box<dyn Fn(TransportStatus)>) {
         self.status_callbacks
-            .write()
-            .unwrap()
+            .borrow_mut()
             .insert(url.to_string(), callback);
     }
 }
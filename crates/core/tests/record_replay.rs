@@ -0,0 +1,136 @@
+// The engine takes `Arc<dyn RelayTransport>`, but transports are
+// single-threaded (`?Send`) by design.
+#![allow(clippy::arc_with_non_send_sync)]
+
+mod common;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use nipworker_core::generated::nostr::fb;
+use nipworker_core::service::engine::NostrEngine;
+use nipworker_core::transport::recording::{Recording, RecordingTransport, ReplayTransport};
+use nipworker_core::types::network::Request;
+use std::time::Duration;
+use tokio::task::LocalSet;
+
+const PUBKEY: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+const SIGNATURE: &str = "0000000000000000000000000000000000000000000000000000000000000002";
+const EVENT_ID: &str = "0000000000000000000000000000000000000000000000000000000000000003";
+
+/// Drain the sink until `sub_id` reports EOSE; returns the event ids seen.
+async fn collect_until_eose(
+    sink: &mut futures::channel::mpsc::Receiver<(String, Vec<u8>)>,
+    sub_id: &str,
+) -> Option<Vec<String>> {
+    let mut ids = Vec::new();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+    while tokio::time::Instant::now() < deadline {
+        let Ok(Some((sid, bytes))) =
+            tokio::time::timeout(Duration::from_millis(200), sink.next()).await
+        else {
+            continue;
+        };
+        if sid != sub_id {
+            continue;
+        }
+        let wm = flatbuffers::root::<fb::WorkerMessage>(&bytes).expect("valid WorkerMessage");
+        match wm.content_type() {
+            fb::Message::ParsedEvent => {
+                ids.push(wm.content_as_parsed_event().unwrap().id().to_string());
+            }
+            fb::Message::ConnectionStatus
+                if wm.content_as_connection_status().unwrap().status() == "EOSE" =>
+            {
+                return Some(ids);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[tokio::test]
+async fn test_recorded_session_replays_offline() {
+    let path = std::env::temp_dir().join(format!(
+        "nipworker-record-replay-{}.jsonl",
+        std::process::id()
+    ));
+
+    // Capture a live session against the scripted mock relay.
+    let local = LocalSet::new();
+    local
+        .run_until(async {
+            let mock = Arc::new(common::MockRelayTransport::new());
+            let recorder = Arc::new(RecordingTransport::to_file(mock.clone(), &path).unwrap());
+            let storage = Arc::new(common::MockStorage::new());
+            let (event_sink_tx, mut event_sink_rx) =
+                futures::channel::mpsc::channel::<(String, Vec<u8>)>(100);
+            let engine = NostrEngine::new(recorder.clone(), storage, event_sink_tx);
+
+            let request = Request {
+                relays: vec!["wss://r".to_string()],
+                kinds: vec![1],
+                since: Some(1_000),
+                ..Default::default()
+            };
+            engine
+                .subscribe("feed".to_string(), vec![request])
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(200)).await;
+
+            let (_, req) = mock
+                .get_sent_frames()
+                .into_iter()
+                .find(|(_, frame)| frame.contains("REQ"))
+                .expect("REQ sent");
+            let wire_sub: serde_json::Value = serde_json::from_str(&req).unwrap();
+            let wire_sub = wire_sub[1].as_str().unwrap().to_string();
+            let event_json =
+                common::make_event_json(EVENT_ID, PUBKEY, 1, "recorded", 1234567890, SIGNATURE);
+            mock.invoke_message_callback(
+                "wss://r",
+                format!(r#"["EVENT","{}",{}]"#, wire_sub, event_json),
+            );
+            mock.invoke_message_callback("wss://r", format!(r#"["EOSE","{}"]"#, wire_sub));
+
+            let ids = collect_until_eose(&mut event_sink_rx, "feed")
+                .await
+                .expect("live EOSE");
+            assert_eq!(ids, vec![EVENT_ID.to_string()]);
+            assert_eq!(Recording::load(&path).unwrap(), recorder.recording());
+        })
+        .await;
+
+    // Replay the capture offline under a different subscription id and a
+    // clock-derived `since` that no longer matches the recording.
+    let recording = Recording::load(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    let local = LocalSet::new();
+    local
+        .run_until(async {
+            let replay = Arc::new(ReplayTransport::new(recording));
+            let storage = Arc::new(common::MockStorage::new());
+            let (event_sink_tx, mut event_sink_rx) =
+                futures::channel::mpsc::channel::<(String, Vec<u8>)>(100);
+            let engine = NostrEngine::new(replay.clone(), storage, event_sink_tx);
+
+            let request = Request {
+                relays: vec!["wss://r".to_string()],
+                kinds: vec![1],
+                since: Some(2_000),
+                ..Default::default()
+            };
+            engine
+                .subscribe("feed-replayed".to_string(), vec![request])
+                .await
+                .unwrap();
+
+            let ids = collect_until_eose(&mut event_sink_rx, "feed-replayed")
+                .await
+                .expect("replayed EOSE");
+            assert_eq!(ids, vec![EVENT_ID.to_string()]);
+            assert!(replay.unmatched().is_empty(), "{:?}", replay.unmatched());
+        })
+        .await;
+}
@@ -33,6 +33,21 @@ void *nipworker_init_with_options(
 	const char *indexer_relays,
 	bool mesh_enabled
 );
+/*
+ * transport_json: relay socket settings, e.g.
+ * {"keepalive_timeout_secs":30,"proxy":"127.0.0.1:9050","proxy_scope":"onion"}.
+ * proxy_scope is "all" (default), "onion" or an array of relay URLs; the
+ * proxy also carries the engine's HTTP lookups.
+ */
+void *nipworker_init_with_transport(
+	nipworker_callback callback,
+	void *userdata,
+	const char *storage_path,
+	const char *default_relays,
+	const char *indexer_relays,
+	bool mesh_enabled,
+	const char *transport_json
+);
 /*
  * Process-wide engine registry used by native mobile wrappers. The first
  * acquire creates the engine; subsequent clients receive the same handle and
@@ -48,6 +63,15 @@ void *nipworker_shared_acquire(
 	const char *indexer_relays,
 	bool mesh_enabled
 );
+void *nipworker_shared_acquire_with_transport(
+	nipworker_callback callback,
+	void *userdata,
+	const char *storage_path,
+	const char *default_relays,
+	const char *indexer_relays,
+	bool mesh_enabled,
+	const char *transport_json
+);
 void nipworker_shared_release(
 	void *handle,
 	nipworker_callback callback,
@@ -62,6 +86,17 @@ void *nipworker_shared_process_acquire(
 void nipworker_shared_process_release(void);
 
 void nipworker_wake(void *handle);
+/* JSON array of relay health scores; free with nipworker_free_bytes. */
+uint8_t *nipworker_relay_health(void *handle, size_t *out_length);
+bool nipworker_set_relay_policy(void *handle, const char *rules_json);
+bool nipworker_set_auth_policy(void *handle, const char *rules_json);
+/* decision_json: "deny" or {"allow":{"type":"user"|"ephemeral"}}. */
+bool nipworker_resolve_auth(
+	void *handle,
+	const char *relay,
+	const char *decision_json,
+	bool remember
+);
 void nipworker_handle_message(void *handle, const uint8_t *bytes, size_t length);
 bool nipworker_subscribe_message(void *handle, const uint8_t *bytes, size_t length);
 bool nipworker_publish_message(void *handle, const uint8_t *bytes, size_t length);
@@ -30,16 +30,48 @@ public final class CashuData extends Table {
   public String token() { int o = __offset(4); return o != 0 ? __string(o + bb_pos) : null; }
   public ByteBuffer tokenAsByteBuffer() { return __vector_as_bytebuffer(4, 1); }
   public ByteBuffer tokenInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 4, 1); }
+  public String mint() { int o = __offset(6); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer mintAsByteBuffer() { return __vector_as_bytebuffer(6, 1); }
+  public ByteBuffer mintInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 6, 1); }
+  public String unit() { int o = __offset(8); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer unitAsByteBuffer() { return __vector_as_bytebuffer(8, 1); }
+  public ByteBuffer unitInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 8, 1); }
+  public String memo() { int o = __offset(10); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer memoAsByteBuffer() { return __vector_as_bytebuffer(10, 1); }
+  public ByteBuffer memoInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 10, 1); }
+  public long amount() { int o = __offset(12); return o != 0 ? bb.getLong(o + bb_pos) : 0L; }
+  public nostr.fb.Proof proofs(int j) { return proofs(new nostr.fb.Proof(), j); }
+  public nostr.fb.Proof proofs(nostr.fb.Proof obj, int j) { int o = __offset(14); return o != 0 ? obj.__assign(__indirect(__vector(o) + j * 4), bb) : null; }
+  public int proofsLength() { int o = __offset(14); return o != 0 ? __vector_len(o) : 0; }
+  public nostr.fb.Proof.Vector proofsVector() { return proofsVector(new nostr.fb.Proof.Vector()); }
+  public nostr.fb.Proof.Vector proofsVector(nostr.fb.Proof.Vector obj) { int o = __offset(14); return o != 0 ? obj.__assign(__vector(o), 4, bb) : null; }
 
   public static int createCashuData(FlatBufferBuilder builder,
-      int tokenOffset) {
-    builder.startTable(1);
+      int tokenOffset,
+      int mintOffset,
+      int unitOffset,
+      int memoOffset,
+      long amount,
+      int proofsOffset) {
+    builder.startTable(6);
+    CashuData.addAmount(builder, amount);
+    CashuData.addProofs(builder, proofsOffset);
+    CashuData.addMemo(builder, memoOffset);
+    CashuData.addUnit(builder, unitOffset);
+    CashuData.addMint(builder, mintOffset);
     CashuData.addToken(builder, tokenOffset);
     return CashuData.endCashuData(builder);
   }
 
-  public static void startCashuData(FlatBufferBuilder builder) { builder.startTable(1); }
+  public static void startCashuData(FlatBufferBuilder builder) { builder.startTable(6); }
   public static void addToken(FlatBufferBuilder builder, int tokenOffset) { builder.addOffset(0, tokenOffset, 0); }
+  public static void addMint(FlatBufferBuilder builder, int mintOffset) { builder.addOffset(1, mintOffset, 0); }
+  public static void addUnit(FlatBufferBuilder builder, int unitOffset) { builder.addOffset(2, unitOffset, 0); }
+  public static void addMemo(FlatBufferBuilder builder, int memoOffset) { builder.addOffset(3, memoOffset, 0); }
+  public static void addAmount(FlatBufferBuilder builder, long amount) { builder.addLong(4, amount, 0L); }
+  public static void addProofs(FlatBufferBuilder builder, int proofsOffset) { builder.addOffset(5, proofsOffset, 0); }
+  public static int createProofsVector(FlatBufferBuilder builder, int[] data) { builder.startVector(4, data.length, 4); for (int i = data.length - 1; i >= 0; i--) builder.addOffset(data[i]); return builder.endVector(); }
+  public static void startProofsVector(FlatBufferBuilder builder, int numElems) { builder.startVector(4, numElems, 4); }
   public static int endCashuData(FlatBufferBuilder builder) {
     int o = builder.endTable();
     builder.required(o, 4);  // token
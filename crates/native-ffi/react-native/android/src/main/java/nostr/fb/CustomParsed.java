@@ -0,0 +1,68 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package nostr.fb;
+
+import com.google.flatbuffers.BaseVector;
+import com.google.flatbuffers.BooleanVector;
+import com.google.flatbuffers.ByteVector;
+import com.google.flatbuffers.Constants;
+import com.google.flatbuffers.DoubleVector;
+import com.google.flatbuffers.FlatBufferBuilder;
+import com.google.flatbuffers.FloatVector;
+import com.google.flatbuffers.IntVector;
+import com.google.flatbuffers.LongVector;
+import com.google.flatbuffers.ShortVector;
+import com.google.flatbuffers.StringVector;
+import com.google.flatbuffers.Struct;
+import com.google.flatbuffers.Table;
+import com.google.flatbuffers.UnionVector;
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+
+@SuppressWarnings("unused")
+public final class CustomParsed extends Table {
+  public static void ValidateVersion() { Constants.FLATBUFFERS_25_2_10(); }
+  public static CustomParsed getRootAsCustomParsed(ByteBuffer _bb) { return getRootAsCustomParsed(_bb, new CustomParsed()); }
+  public static CustomParsed getRootAsCustomParsed(ByteBuffer _bb, CustomParsed obj) { _bb.order(ByteOrder.LITTLE_ENDIAN); return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __reset(_i, _bb); }
+  public CustomParsed __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public String schemaId() { int o = __offset(4); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer schemaIdAsByteBuffer() { return __vector_as_bytebuffer(4, 1); }
+  public ByteBuffer schemaIdInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 4, 1); }
+  public int payload(int j) { int o = __offset(6); return o != 0 ? bb.get(__vector(o) + j * 1) & 0xFF : 0; }
+  public int payloadLength() { int o = __offset(6); return o != 0 ? __vector_len(o) : 0; }
+  public ByteVector payloadVector() { return payloadVector(new ByteVector()); }
+  public ByteVector payloadVector(ByteVector obj) { int o = __offset(6); return o != 0 ? obj.__assign(__vector(o), bb) : null; }
+  public ByteBuffer payloadAsByteBuffer() { return __vector_as_bytebuffer(6, 1); }
+  public ByteBuffer payloadInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 6, 1); }
+
+  public static int createCustomParsed(FlatBufferBuilder builder,
+      int schemaIdOffset,
+      int payloadOffset) {
+    builder.startTable(2);
+    CustomParsed.addPayload(builder, payloadOffset);
+    CustomParsed.addSchemaId(builder, schemaIdOffset);
+    return CustomParsed.endCustomParsed(builder);
+  }
+
+  public static void startCustomParsed(FlatBufferBuilder builder) { builder.startTable(2); }
+  public static void addSchemaId(FlatBufferBuilder builder, int schemaIdOffset) { builder.addOffset(0, schemaIdOffset, 0); }
+  public static void addPayload(FlatBufferBuilder builder, int payloadOffset) { builder.addOffset(1, payloadOffset, 0); }
+  public static int createPayloadVector(FlatBufferBuilder builder, byte[] data) { return builder.createByteVector(data); }
+  public static int createPayloadVector(FlatBufferBuilder builder, ByteBuffer data) { return builder.createByteVector(data); }
+  public static void startPayloadVector(FlatBufferBuilder builder, int numElems) { builder.startVector(1, numElems, 1); }
+  public static int endCustomParsed(FlatBufferBuilder builder) {
+    int o = builder.endTable();
+    builder.required(o, 4);  // schema_id
+    return o;
+  }
+
+  public static final class Vector extends BaseVector {
+    public Vector __assign(int _vector, int _element_size, ByteBuffer _bb) { __reset(_vector, _element_size, _bb); return this; }
+
+    public CustomParsed get(int j) { return get(new CustomParsed(), j); }
+    public CustomParsed get(CustomParsed obj, int j) {  return obj.__assign(__indirect(__element(j), bb), bb); }
+  }
+}
+
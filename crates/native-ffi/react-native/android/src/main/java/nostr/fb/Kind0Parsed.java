@@ -87,6 +87,7 @@ public final class Kind0Parsed extends Table {
   public String background() { int o = __offset(42); return o != 0 ? __string(o + bb_pos) : null; }
   public ByteBuffer backgroundAsByteBuffer() { return __vector_as_bytebuffer(42, 1); }
   public ByteBuffer backgroundInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 42, 1); }
+  public boolean nip05Verified() { int o = __offset(44); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
 
   public static int createKind0Parsed(FlatBufferBuilder builder,
       int pubkeyOffset,
@@ -108,8 +109,9 @@ public final class Kind0Parsed extends Table {
       int bioOffset,
       int imageOffset,
       int avatarOffset,
-      int backgroundOffset) {
-    builder.startTable(20);
+      int backgroundOffset,
+      boolean nip05Verified) {
+    builder.startTable(21);
     Kind0Parsed.addBackground(builder, backgroundOffset);
     Kind0Parsed.addAvatar(builder, avatarOffset);
     Kind0Parsed.addImage(builder, imageOffset);
@@ -130,10 +132,11 @@ public final class Kind0Parsed extends Table {
     Kind0Parsed.addDisplayName(builder, displayNameOffset);
     Kind0Parsed.addName(builder, nameOffset);
     Kind0Parsed.addPubkey(builder, pubkeyOffset);
+    Kind0Parsed.addNip05Verified(builder, nip05Verified);
     return Kind0Parsed.endKind0Parsed(builder);
   }
 
-  public static void startKind0Parsed(FlatBufferBuilder builder) { builder.startTable(20); }
+  public static void startKind0Parsed(FlatBufferBuilder builder) { builder.startTable(21); }
   public static void addPubkey(FlatBufferBuilder builder, int pubkeyOffset) { builder.addOffset(0, pubkeyOffset, 0); }
   public static void addName(FlatBufferBuilder builder, int nameOffset) { builder.addOffset(1, nameOffset, 0); }
   public static void addDisplayName(FlatBufferBuilder builder, int displayNameOffset) { builder.addOffset(2, displayNameOffset, 0); }
@@ -154,6 +157,7 @@ public final class Kind0Parsed extends Table {
   public static void addImage(FlatBufferBuilder builder, int imageOffset) { builder.addOffset(17, imageOffset, 0); }
   public static void addAvatar(FlatBufferBuilder builder, int avatarOffset) { builder.addOffset(18, avatarOffset, 0); }
   public static void addBackground(FlatBufferBuilder builder, int backgroundOffset) { builder.addOffset(19, backgroundOffset, 0); }
+  public static void addNip05Verified(FlatBufferBuilder builder, boolean nip05Verified) { builder.addBoolean(20, nip05Verified, false); }
   public static int endKind0Parsed(FlatBufferBuilder builder) {
     int o = builder.endTable();
     return o;
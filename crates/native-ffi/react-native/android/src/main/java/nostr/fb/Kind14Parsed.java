@@ -0,0 +1,125 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package nostr.fb;
+
+import com.google.flatbuffers.BaseVector;
+import com.google.flatbuffers.BooleanVector;
+import com.google.flatbuffers.ByteVector;
+import com.google.flatbuffers.Constants;
+import com.google.flatbuffers.DoubleVector;
+import com.google.flatbuffers.FlatBufferBuilder;
+import com.google.flatbuffers.FloatVector;
+import com.google.flatbuffers.IntVector;
+import com.google.flatbuffers.LongVector;
+import com.google.flatbuffers.ShortVector;
+import com.google.flatbuffers.StringVector;
+import com.google.flatbuffers.Struct;
+import com.google.flatbuffers.Table;
+import com.google.flatbuffers.UnionVector;
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+
+@SuppressWarnings("unused")
+public final class Kind14Parsed extends Table {
+  public static void ValidateVersion() { Constants.FLATBUFFERS_25_2_10(); }
+  public static Kind14Parsed getRootAsKind14Parsed(ByteBuffer _bb) { return getRootAsKind14Parsed(_bb, new Kind14Parsed()); }
+  public static Kind14Parsed getRootAsKind14Parsed(ByteBuffer _bb, Kind14Parsed obj) { _bb.order(ByteOrder.LITTLE_ENDIAN); return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __reset(_i, _bb); }
+  public Kind14Parsed __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public int kind() { int o = __offset(4); return o != 0 ? bb.getShort(o + bb_pos) & 0xFFFF : 0; }
+  public String rumorId() { int o = __offset(6); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer rumorIdAsByteBuffer() { return __vector_as_bytebuffer(6, 1); }
+  public ByteBuffer rumorIdInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 6, 1); }
+  public String sender() { int o = __offset(8); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer senderAsByteBuffer() { return __vector_as_bytebuffer(8, 1); }
+  public ByteBuffer senderInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 8, 1); }
+  public long createdAt() { int o = __offset(10); return o != 0 ? (long)bb.getInt(o + bb_pos) & 0xFFFFFFFFL : 0L; }
+  public String participants(int j) { int o = __offset(12); return o != 0 ? __string(__vector(o) + j * 4) : null; }
+  public int participantsLength() { int o = __offset(12); return o != 0 ? __vector_len(o) : 0; }
+  public StringVector participantsVector() { return participantsVector(new StringVector()); }
+  public StringVector participantsVector(StringVector obj) { int o = __offset(12); return o != 0 ? obj.__assign(__vector(o), 4, bb) : null; }
+  public String chatId() { int o = __offset(14); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer chatIdAsByteBuffer() { return __vector_as_bytebuffer(14, 1); }
+  public ByteBuffer chatIdInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 14, 1); }
+  public String content() { int o = __offset(16); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer contentAsByteBuffer() { return __vector_as_bytebuffer(16, 1); }
+  public ByteBuffer contentInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 16, 1); }
+  public nostr.fb.ContentBlock parsedContent(int j) { return parsedContent(new nostr.fb.ContentBlock(), j); }
+  public nostr.fb.ContentBlock parsedContent(nostr.fb.ContentBlock obj, int j) { int o = __offset(18); return o != 0 ? obj.__assign(__indirect(__vector(o) + j * 4), bb) : null; }
+  public int parsedContentLength() { int o = __offset(18); return o != 0 ? __vector_len(o) : 0; }
+  public nostr.fb.ContentBlock.Vector parsedContentVector() { return parsedContentVector(new nostr.fb.ContentBlock.Vector()); }
+  public nostr.fb.ContentBlock.Vector parsedContentVector(nostr.fb.ContentBlock.Vector obj) { int o = __offset(18); return o != 0 ? obj.__assign(__vector(o), 4, bb) : null; }
+  public String subject() { int o = __offset(20); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer subjectAsByteBuffer() { return __vector_as_bytebuffer(20, 1); }
+  public ByteBuffer subjectInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 20, 1); }
+  public String replyTo() { int o = __offset(22); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer replyToAsByteBuffer() { return __vector_as_bytebuffer(22, 1); }
+  public ByteBuffer replyToInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 22, 1); }
+  public nostr.fb.StringVec tags(int j) { return tags(new nostr.fb.StringVec(), j); }
+  public nostr.fb.StringVec tags(nostr.fb.StringVec obj, int j) { int o = __offset(24); return o != 0 ? obj.__assign(__indirect(__vector(o) + j * 4), bb) : null; }
+  public int tagsLength() { int o = __offset(24); return o != 0 ? __vector_len(o) : 0; }
+  public nostr.fb.StringVec.Vector tagsVector() { return tagsVector(new nostr.fb.StringVec.Vector()); }
+  public nostr.fb.StringVec.Vector tagsVector(nostr.fb.StringVec.Vector obj) { int o = __offset(24); return o != 0 ? obj.__assign(__vector(o), 4, bb) : null; }
+
+  public static int createKind14Parsed(FlatBufferBuilder builder,
+      int kind,
+      int rumorIdOffset,
+      int senderOffset,
+      long createdAt,
+      int participantsOffset,
+      int chatIdOffset,
+      int contentOffset,
+      int parsedContentOffset,
+      int subjectOffset,
+      int replyToOffset,
+      int tagsOffset) {
+    builder.startTable(11);
+    Kind14Parsed.addTags(builder, tagsOffset);
+    Kind14Parsed.addReplyTo(builder, replyToOffset);
+    Kind14Parsed.addSubject(builder, subjectOffset);
+    Kind14Parsed.addParsedContent(builder, parsedContentOffset);
+    Kind14Parsed.addContent(builder, contentOffset);
+    Kind14Parsed.addChatId(builder, chatIdOffset);
+    Kind14Parsed.addParticipants(builder, participantsOffset);
+    Kind14Parsed.addCreatedAt(builder, createdAt);
+    Kind14Parsed.addSender(builder, senderOffset);
+    Kind14Parsed.addRumorId(builder, rumorIdOffset);
+    Kind14Parsed.addKind(builder, kind);
+    return Kind14Parsed.endKind14Parsed(builder);
+  }
+
+  public static void startKind14Parsed(FlatBufferBuilder builder) { builder.startTable(11); }
+  public static void addKind(FlatBufferBuilder builder, int kind) { builder.addShort(0, (short) kind, (short) 0); }
+  public static void addRumorId(FlatBufferBuilder builder, int rumorIdOffset) { builder.addOffset(1, rumorIdOffset, 0); }
+  public static void addSender(FlatBufferBuilder builder, int senderOffset) { builder.addOffset(2, senderOffset, 0); }
+  public static void addCreatedAt(FlatBufferBuilder builder, long createdAt) { builder.addInt(3, (int) createdAt, (int) 0L); }
+  public static void addParticipants(FlatBufferBuilder builder, int participantsOffset) { builder.addOffset(4, participantsOffset, 0); }
+  public static int createParticipantsVector(FlatBufferBuilder builder, int[] data) { builder.startVector(4, data.length, 4); for (int i = data.length - 1; i >= 0; i--) builder.addOffset(data[i]); return builder.endVector(); }
+  public static void startParticipantsVector(FlatBufferBuilder builder, int numElems) { builder.startVector(4, numElems, 4); }
+  public static void addChatId(FlatBufferBuilder builder, int chatIdOffset) { builder.addOffset(5, chatIdOffset, 0); }
+  public static void addContent(FlatBufferBuilder builder, int contentOffset) { builder.addOffset(6, contentOffset, 0); }
+  public static void addParsedContent(FlatBufferBuilder builder, int parsedContentOffset) { builder.addOffset(7, parsedContentOffset, 0); }
+  public static int createParsedContentVector(FlatBufferBuilder builder, int[] data) { builder.startVector(4, data.length, 4); for (int i = data.length - 1; i >= 0; i--) builder.addOffset(data[i]); return builder.endVector(); }
+  public static void startParsedContentVector(FlatBufferBuilder builder, int numElems) { builder.startVector(4, numElems, 4); }
+  public static void addSubject(FlatBufferBuilder builder, int subjectOffset) { builder.addOffset(8, subjectOffset, 0); }
+  public static void addReplyTo(FlatBufferBuilder builder, int replyToOffset) { builder.addOffset(9, replyToOffset, 0); }
+  public static void addTags(FlatBufferBuilder builder, int tagsOffset) { builder.addOffset(10, tagsOffset, 0); }
+  public static int createTagsVector(FlatBufferBuilder builder, int[] data) { builder.startVector(4, data.length, 4); for (int i = data.length - 1; i >= 0; i--) builder.addOffset(data[i]); return builder.endVector(); }
+  public static void startTagsVector(FlatBufferBuilder builder, int numElems) { builder.startVector(4, numElems, 4); }
+  public static int endKind14Parsed(FlatBufferBuilder builder) {
+    int o = builder.endTable();
+    builder.required(o, 6);  // rumor_id
+    builder.required(o, 8);  // sender
+    builder.required(o, 12);  // participants
+    builder.required(o, 14);  // chat_id
+    return o;
+  }
+
+  public static final class Vector extends BaseVector {
+    public Vector __assign(int _vector, int _element_size, ByteBuffer _bb) { __reset(_vector, _element_size, _bb); return this; }
+
+    public Kind14Parsed get(int j) { return get(new Kind14Parsed(), j); }
+    public Kind14Parsed get(Kind14Parsed obj, int j) {  return obj.__assign(__indirect(__element(j), bb), bb); }
+  }
+}
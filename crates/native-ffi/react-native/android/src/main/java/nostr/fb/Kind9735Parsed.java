@@ -56,6 +56,10 @@ public final class Kind9735Parsed extends Table {
   public boolean valid() { int o = __offset(24); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
   public nostr.fb.ZapRequest description() { return description(new nostr.fb.ZapRequest()); }
   public nostr.fb.ZapRequest description(nostr.fb.ZapRequest obj) { int o = __offset(26); return o != 0 ? obj.__assign(__indirect(o + bb_pos), bb) : null; }
+  public String invalidReason() { int o = __offset(28); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer invalidReasonAsByteBuffer() { return __vector_as_bytebuffer(28, 1); }
+  public ByteBuffer invalidReasonInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 28, 1); }
+  public boolean providerVerified() { int o = __offset(30); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
 
   public static int createKind9735Parsed(FlatBufferBuilder builder,
       int idOffset,
@@ -69,9 +73,12 @@ public final class Kind9735Parsed extends Table {
       int eventCoordinateOffset,
       long timestamp,
       boolean valid,
-      int descriptionOffset) {
-    builder.startTable(12);
+      int descriptionOffset,
+      int invalidReasonOffset,
+      boolean providerVerified) {
+    builder.startTable(14);
     Kind9735Parsed.addTimestamp(builder, timestamp);
+    Kind9735Parsed.addInvalidReason(builder, invalidReasonOffset);
     Kind9735Parsed.addDescription(builder, descriptionOffset);
     Kind9735Parsed.addEventCoordinate(builder, eventCoordinateOffset);
     Kind9735Parsed.addEvent(builder, eventOffset);
@@ -82,11 +89,12 @@ public final class Kind9735Parsed extends Table {
     Kind9735Parsed.addContent(builder, contentOffset);
     Kind9735Parsed.addAmount(builder, amount);
     Kind9735Parsed.addId(builder, idOffset);
+    Kind9735Parsed.addProviderVerified(builder, providerVerified);
     Kind9735Parsed.addValid(builder, valid);
     return Kind9735Parsed.endKind9735Parsed(builder);
   }
 
-  public static void startKind9735Parsed(FlatBufferBuilder builder) { builder.startTable(12); }
+  public static void startKind9735Parsed(FlatBufferBuilder builder) { builder.startTable(14); }
   public static void addId(FlatBufferBuilder builder, int idOffset) { builder.addOffset(0, idOffset, 0); }
   public static void addAmount(FlatBufferBuilder builder, int amount) { builder.addInt(1, amount, 0); }
   public static void addContent(FlatBufferBuilder builder, int contentOffset) { builder.addOffset(2, contentOffset, 0); }
@@ -99,6 +107,8 @@ public final class Kind9735Parsed extends Table {
   public static void addTimestamp(FlatBufferBuilder builder, long timestamp) { builder.addLong(9, timestamp, 0L); }
   public static void addValid(FlatBufferBuilder builder, boolean valid) { builder.addBoolean(10, valid, false); }
   public static void addDescription(FlatBufferBuilder builder, int descriptionOffset) { builder.addOffset(11, descriptionOffset, 0); }
+  public static void addInvalidReason(FlatBufferBuilder builder, int invalidReasonOffset) { builder.addOffset(12, invalidReasonOffset, 0); }
+  public static void addProviderVerified(FlatBufferBuilder builder, boolean providerVerified) { builder.addBoolean(13, providerVerified, false); }
   public static int endKind9735Parsed(FlatBufferBuilder builder) {
     int o = builder.endTable();
     builder.required(o, 4);  // id
@@ -30,16 +30,58 @@ public final class LightningData extends Table {
   public String invoice() { int o = __offset(4); return o != 0 ? __string(o + bb_pos) : null; }
   public ByteBuffer invoiceAsByteBuffer() { return __vector_as_bytebuffer(4, 1); }
   public ByteBuffer invoiceInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 4, 1); }
+  public String network() { int o = __offset(6); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer networkAsByteBuffer() { return __vector_as_bytebuffer(6, 1); }
+  public ByteBuffer networkInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 6, 1); }
+  public long amountMsats() { int o = __offset(8); return o != 0 ? bb.getLong(o + bb_pos) : 0L; }
+  public long timestamp() { int o = __offset(10); return o != 0 ? bb.getLong(o + bb_pos) : 0L; }
+  public long expiry() { int o = __offset(12); return o != 0 ? bb.getLong(o + bb_pos) : 0L; }
+  public String description() { int o = __offset(14); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer descriptionAsByteBuffer() { return __vector_as_bytebuffer(14, 1); }
+  public ByteBuffer descriptionInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 14, 1); }
+  public String descriptionHash() { int o = __offset(16); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer descriptionHashAsByteBuffer() { return __vector_as_bytebuffer(16, 1); }
+  public ByteBuffer descriptionHashInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 16, 1); }
+  public String paymentHash() { int o = __offset(18); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer paymentHashAsByteBuffer() { return __vector_as_bytebuffer(18, 1); }
+  public ByteBuffer paymentHashInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 18, 1); }
+  public String payee() { int o = __offset(20); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer payeeAsByteBuffer() { return __vector_as_bytebuffer(20, 1); }
+  public ByteBuffer payeeInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 20, 1); }
 
   public static int createLightningData(FlatBufferBuilder builder,
-      int invoiceOffset) {
-    builder.startTable(1);
+      int invoiceOffset,
+      int networkOffset,
+      long amountMsats,
+      long timestamp,
+      long expiry,
+      int descriptionOffset,
+      int descriptionHashOffset,
+      int paymentHashOffset,
+      int payeeOffset) {
+    builder.startTable(9);
+    LightningData.addExpiry(builder, expiry);
+    LightningData.addTimestamp(builder, timestamp);
+    LightningData.addAmountMsats(builder, amountMsats);
+    LightningData.addPayee(builder, payeeOffset);
+    LightningData.addPaymentHash(builder, paymentHashOffset);
+    LightningData.addDescriptionHash(builder, descriptionHashOffset);
+    LightningData.addDescription(builder, descriptionOffset);
+    LightningData.addNetwork(builder, networkOffset);
     LightningData.addInvoice(builder, invoiceOffset);
     return LightningData.endLightningData(builder);
   }
 
-  public static void startLightningData(FlatBufferBuilder builder) { builder.startTable(1); }
+  public static void startLightningData(FlatBufferBuilder builder) { builder.startTable(9); }
   public static void addInvoice(FlatBufferBuilder builder, int invoiceOffset) { builder.addOffset(0, invoiceOffset, 0); }
+  public static void addNetwork(FlatBufferBuilder builder, int networkOffset) { builder.addOffset(1, networkOffset, 0); }
+  public static void addAmountMsats(FlatBufferBuilder builder, long amountMsats) { builder.addLong(2, amountMsats, 0L); }
+  public static void addTimestamp(FlatBufferBuilder builder, long timestamp) { builder.addLong(3, timestamp, 0L); }
+  public static void addExpiry(FlatBufferBuilder builder, long expiry) { builder.addLong(4, expiry, 0L); }
+  public static void addDescription(FlatBufferBuilder builder, int descriptionOffset) { builder.addOffset(5, descriptionOffset, 0); }
+  public static void addDescriptionHash(FlatBufferBuilder builder, int descriptionHashOffset) { builder.addOffset(6, descriptionHashOffset, 0); }
+  public static void addPaymentHash(FlatBufferBuilder builder, int paymentHashOffset) { builder.addOffset(7, paymentHashOffset, 0); }
+  public static void addPayee(FlatBufferBuilder builder, int payeeOffset) { builder.addOffset(8, payeeOffset, 0); }
   public static int endLightningData(FlatBufferBuilder builder) {
     int o = builder.endTable();
     builder.required(o, 4);  // invoice
@@ -0,0 +1,73 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package nostr.fb;
+
+import com.google.flatbuffers.BaseVector;
+import com.google.flatbuffers.BooleanVector;
+import com.google.flatbuffers.ByteVector;
+import com.google.flatbuffers.Constants;
+import com.google.flatbuffers.DoubleVector;
+import com.google.flatbuffers.FlatBufferBuilder;
+import com.google.flatbuffers.FloatVector;
+import com.google.flatbuffers.IntVector;
+import com.google.flatbuffers.LongVector;
+import com.google.flatbuffers.ShortVector;
+import com.google.flatbuffers.StringVector;
+import com.google.flatbuffers.Struct;
+import com.google.flatbuffers.Table;
+import com.google.flatbuffers.UnionVector;
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+
+@SuppressWarnings("unused")
+public final class MentionData extends Table {
+  public static void ValidateVersion() { Constants.FLATBUFFERS_25_2_10(); }
+  public static MentionData getRootAsMentionData(ByteBuffer _bb) { return getRootAsMentionData(_bb, new MentionData()); }
+  public static MentionData getRootAsMentionData(ByteBuffer _bb, MentionData obj) { _bb.order(ByteOrder.LITTLE_ENDIAN); return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __reset(_i, _bb); }
+  public MentionData __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public String id() { int o = __offset(4); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer idAsByteBuffer() { return __vector_as_bytebuffer(4, 1); }
+  public ByteBuffer idInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 4, 1); }
+  public String name() { int o = __offset(6); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer nameAsByteBuffer() { return __vector_as_bytebuffer(6, 1); }
+  public ByteBuffer nameInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 6, 1); }
+  public String picture() { int o = __offset(8); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer pictureAsByteBuffer() { return __vector_as_bytebuffer(8, 1); }
+  public ByteBuffer pictureInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 8, 1); }
+  public nostr.fb.ParsedEvent event() { return event(new nostr.fb.ParsedEvent()); }
+  public nostr.fb.ParsedEvent event(nostr.fb.ParsedEvent obj) { int o = __offset(10); return o != 0 ? obj.__assign(__indirect(o + bb_pos), bb) : null; }
+
+  public static int createMentionData(FlatBufferBuilder builder,
+      int idOffset,
+      int nameOffset,
+      int pictureOffset,
+      int eventOffset) {
+    builder.startTable(4);
+    MentionData.addEvent(builder, eventOffset);
+    MentionData.addPicture(builder, pictureOffset);
+    MentionData.addName(builder, nameOffset);
+    MentionData.addId(builder, idOffset);
+    return MentionData.endMentionData(builder);
+  }
+
+  public static void startMentionData(FlatBufferBuilder builder) { builder.startTable(4); }
+  public static void addId(FlatBufferBuilder builder, int idOffset) { builder.addOffset(0, idOffset, 0); }
+  public static void addName(FlatBufferBuilder builder, int nameOffset) { builder.addOffset(1, nameOffset, 0); }
+  public static void addPicture(FlatBufferBuilder builder, int pictureOffset) { builder.addOffset(2, pictureOffset, 0); }
+  public static void addEvent(FlatBufferBuilder builder, int eventOffset) { builder.addOffset(3, eventOffset, 0); }
+  public static int endMentionData(FlatBufferBuilder builder) {
+    int o = builder.endTable();
+    builder.required(o, 4);  // id
+    return o;
+  }
+
+  public static final class Vector extends BaseVector {
+    public Vector __assign(int _vector, int _element_size, ByteBuffer _bb) { __reset(_vector, _element_size, _bb); return this; }
+
+    public MentionData get(int j) { return get(new MentionData(), j); }
+    public MentionData get(MentionData obj, int j) {  return obj.__assign(__indirect(__element(j), bb), bb); }
+  }
+}
+
@@ -18,8 +18,11 @@ public final class Message {
   public static final byte Raw = 10;
   public static final byte SetSignerResponse = 11;
   public static final byte AuthUrl = 12;
+  public static final byte LinkPreviewData = 13;
+  public static final byte ThreadTree = 14;
+  public static final byte MentionData = 15;
 
-  public static final String[] names = { "NONE", "ParsedEvent", "NostrEvent", "ConnectionStatus", "CountResponse", "Eoce", "BufferFull", "ValidProofs", "SignedEvent", "Pubkey", "Raw", "SetSignerResponse", "AuthUrl", };
+  public static final String[] names = { "NONE", "ParsedEvent", "NostrEvent", "ConnectionStatus", "CountResponse", "Eoce", "BufferFull", "ValidProofs", "SignedEvent", "Pubkey", "Raw", "SetSignerResponse", "AuthUrl", "LinkPreviewData", "ThreadTree", "MentionData", };
 
   public static String name(int e) { return names[e]; }
 }
@@ -17,5 +17,8 @@ public final class MessageType {
   public static final long NostrEvent = 9;
   public static final long SetSignerResponse = 10;
   public static final long AuthUrl = 11;
+  public static final long LinkPreview = 12;
+  public static final long ThreadTree = 13;
+  public static final long Mention = 14;
 }
 
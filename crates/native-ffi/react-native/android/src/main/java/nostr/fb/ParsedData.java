@@ -31,8 +31,10 @@ public final class ParsedData {
   public static final byte Kind30023Parsed = 23;
   public static final byte ListParsed = 24;
   public static final byte PreGenericParsed = 25;
+  public static final byte Kind14Parsed = 26;
+  public static final byte CustomParsed = 27;
 
-  public static final String[] names = { "NONE", "Kind0Parsed", "Kind1Parsed", "Kind3Parsed", "Kind4Parsed", "Kind6Parsed", "Kind7Parsed", "Kind8Parsed", "Kind17Parsed", "Kind20Parsed", "Kind22Parsed", "Kind1111Parsed", "Kind1311Parsed", "Kind1068Parsed", "Kind1018Parsed", "Kind10002Parsed", "Kind10019Parsed", "Kind17375Parsed", "Kind7374Parsed", "Kind7375Parsed", "Kind7376Parsed", "Kind9321Parsed", "Kind9735Parsed", "Kind30023Parsed", "ListParsed", "PreGenericParsed", };
+  public static final String[] names = { "NONE", "Kind0Parsed", "Kind1Parsed", "Kind3Parsed", "Kind4Parsed", "Kind6Parsed", "Kind7Parsed", "Kind8Parsed", "Kind17Parsed", "Kind20Parsed", "Kind22Parsed", "Kind1111Parsed", "Kind1311Parsed", "Kind1068Parsed", "Kind1018Parsed", "Kind10002Parsed", "Kind10019Parsed", "Kind17375Parsed", "Kind7374Parsed", "Kind7375Parsed", "Kind7376Parsed", "Kind9321Parsed", "Kind9735Parsed", "Kind30023Parsed", "ListParsed", "PreGenericParsed", "Kind14Parsed", "CustomParsed", };
 
   public static String name(int e) { return names[e]; }
 }
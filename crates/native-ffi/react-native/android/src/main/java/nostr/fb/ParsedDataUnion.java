@@ -12,6 +12,7 @@ public final class ParsedDataUnion {
   public static final long Kind6Parsed = 6;
   public static final long Kind7Parsed = 7;
   public static final long Kind8Parsed = 8;
+  public static final long Kind14Parsed = 14;
   public static final long Kind17Parsed = 17;
   public static final long Kind20Parsed = 20;
   public static final long Kind22Parsed = 22;
@@ -31,5 +32,6 @@ public final class ParsedDataUnion {
   public static final long Kind39089Parsed = 39089;
   public static final long ListParsed = 51000;
   public static final long PreGenericParsed = 52000;
+  public static final long CustomParsed = 60000;
 }
 
@@ -51,6 +51,12 @@ public final class ParsedEvent extends Table {
   public int tagsLength() { int o = __offset(20); return o != 0 ? __vector_len(o) : 0; }
   public nostr.fb.StringVec.Vector tagsVector() { return tagsVector(new nostr.fb.StringVec.Vector()); }
   public nostr.fb.StringVec.Vector tagsVector(nostr.fb.StringVec.Vector obj) { int o = __offset(20); return o != 0 ? obj.__assign(__vector(o), 4, bb) : null; }
+  public String nevent() { int o = __offset(22); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer neventAsByteBuffer() { return __vector_as_bytebuffer(22, 1); }
+  public ByteBuffer neventInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 22, 1); }
+  public String naddr() { int o = __offset(24); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer naddrAsByteBuffer() { return __vector_as_bytebuffer(24, 1); }
+  public ByteBuffer naddrInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 24, 1); }
 
   public static int createParsedEvent(FlatBufferBuilder builder,
       int idOffset,
@@ -61,8 +67,12 @@ public final class ParsedEvent extends Table {
       int parsedOffset,
       int requestsOffset,
       int relaysOffset,
-      int tagsOffset) {
-    builder.startTable(9);
+      int tagsOffset,
+      int neventOffset,
+      int naddrOffset) {
+    builder.startTable(11);
+    ParsedEvent.addNaddr(builder, naddrOffset);
+    ParsedEvent.addNevent(builder, neventOffset);
     ParsedEvent.addTags(builder, tagsOffset);
     ParsedEvent.addRelays(builder, relaysOffset);
     ParsedEvent.addRequests(builder, requestsOffset);
@@ -75,7 +85,7 @@ public final class ParsedEvent extends Table {
     return ParsedEvent.endParsedEvent(builder);
   }
 
-  public static void startParsedEvent(FlatBufferBuilder builder) { builder.startTable(9); }
+  public static void startParsedEvent(FlatBufferBuilder builder) { builder.startTable(11); }
   public static void addId(FlatBufferBuilder builder, int idOffset) { builder.addOffset(0, idOffset, 0); }
   public static void addPubkey(FlatBufferBuilder builder, int pubkeyOffset) { builder.addOffset(1, pubkeyOffset, 0); }
   public static void addKind(FlatBufferBuilder builder, int kind) { builder.addShort(2, (short) kind, (short) 0); }
@@ -91,6 +101,8 @@ public final class ParsedEvent extends Table {
   public static void addTags(FlatBufferBuilder builder, int tagsOffset) { builder.addOffset(8, tagsOffset, 0); }
   public static int createTagsVector(FlatBufferBuilder builder, int[] data) { builder.startVector(4, data.length, 4); for (int i = data.length - 1; i >= 0; i--) builder.addOffset(data[i]); return builder.endVector(); }
   public static void startTagsVector(FlatBufferBuilder builder, int numElems) { builder.startVector(4, numElems, 4); }
+  public static void addNevent(FlatBufferBuilder builder, int neventOffset) { builder.addOffset(9, neventOffset, 0); }
+  public static void addNaddr(FlatBufferBuilder builder, int naddrOffset) { builder.addOffset(10, naddrOffset, 0); }
   public static int endParsedEvent(FlatBufferBuilder builder) {
     int o = builder.endTable();
     builder.required(o, 4);  // id
@@ -15,8 +15,9 @@ public final class PipeConfig {
   public static final byte SerializeEventsPipeConfig = 7;
   public static final byte ProofVerificationPipeConfig = 8;
   public static final byte MuteFilterPipeConfig = 9;
+  public static final byte ZapValidationPipeConfig = 10;
 
-  public static final String[] names = { "NONE", "ParsePipeConfig", "KindFilterPipeConfig", "CounterPipeConfig", "NpubLimiterPipeConfig", "ChatLimiterPipeConfig", "SaveToDbPipeConfig", "SerializeEventsPipeConfig", "ProofVerificationPipeConfig", "MuteFilterPipeConfig", };
+  public static final String[] names = { "NONE", "ParsePipeConfig", "KindFilterPipeConfig", "CounterPipeConfig", "NpubLimiterPipeConfig", "ChatLimiterPipeConfig", "SaveToDbPipeConfig", "SerializeEventsPipeConfig", "ProofVerificationPipeConfig", "MuteFilterPipeConfig", "ZapValidationPipeConfig", };
 
   public static String name(int e) { return names[e]; }
 }
@@ -61,6 +61,8 @@ public final class Request extends Table {
   public int maxRelays() { int o = __offset(28); return o != 0 ? bb.getShort(o + bb_pos) & 0xFFFF : 0; }
   public boolean cacheOnly() { int o = __offset(30); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
   public boolean meshOnly() { int o = __offset(32); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
+  public boolean negentropy() { int o = __offset(34); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
+  public boolean background() { int o = __offset(36); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
 
   public static int createRequest(FlatBufferBuilder builder,
       int idsOffset,
@@ -76,8 +78,10 @@ public final class Request extends Table {
       boolean noCache,
       int maxRelays,
       boolean cacheOnly,
-      boolean meshOnly) {
-    builder.startTable(15);
+      boolean meshOnly,
+      boolean negentropy,
+      boolean background) {
+    builder.startTable(17);
     Request.addRelays(builder, relaysOffset);
     Request.addSearch(builder, searchOffset);
     Request.addUntil(builder, until);
@@ -88,6 +92,8 @@ public final class Request extends Table {
     Request.addAuthors(builder, authorsOffset);
     Request.addIds(builder, idsOffset);
     Request.addMaxRelays(builder, maxRelays);
+    Request.addBackground(builder, background);
+    Request.addNegentropy(builder, negentropy);
     Request.addMeshOnly(builder, meshOnly);
     Request.addCacheOnly(builder, cacheOnly);
     Request.addNoCache(builder, noCache);
@@ -95,7 +101,7 @@ public final class Request extends Table {
     return Request.endRequest(builder);
   }
 
-  public static void startRequest(FlatBufferBuilder builder) { builder.startTable(15); }
+  public static void startRequest(FlatBufferBuilder builder) { builder.startTable(17); }
   public static void addIds(FlatBufferBuilder builder, int idsOffset) { builder.addOffset(0, idsOffset, 0); }
   public static int createIdsVector(FlatBufferBuilder builder, int[] data) { builder.startVector(4, data.length, 4); for (int i = data.length - 1; i >= 0; i--) builder.addOffset(data[i]); return builder.endVector(); }
   public static void startIdsVector(FlatBufferBuilder builder, int numElems) { builder.startVector(4, numElems, 4); }
@@ -120,6 +126,8 @@ public final class Request extends Table {
   public static void addMaxRelays(FlatBufferBuilder builder, int maxRelays) { builder.addShort(12, (short) maxRelays, (short) 0); }
   public static void addCacheOnly(FlatBufferBuilder builder, boolean cacheOnly) { builder.addBoolean(13, cacheOnly, false); }
   public static void addMeshOnly(FlatBufferBuilder builder, boolean meshOnly) { builder.addBoolean(14, meshOnly, false); }
+  public static void addNegentropy(FlatBufferBuilder builder, boolean negentropy) { builder.addBoolean(15, negentropy, false); }
+  public static void addBackground(FlatBufferBuilder builder, boolean background) { builder.addBoolean(16, background, false); }
   public static int endRequest(FlatBufferBuilder builder) {
     int o = builder.endTable();
     return o;
@@ -41,6 +41,15 @@ public final class SubscriptionConfig extends Table {
   public ByteBuffer paginationAsByteBuffer() { return __vector_as_bytebuffer(22, 1); }
   public ByteBuffer paginationInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 22, 1); }
   public boolean cacheOnly() { int o = __offset(24); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
+  public boolean mergedWindow() { int o = __offset(26); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
+  public boolean linkPreviews() { int o = __offset(28); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
+  public String thread() { int o = __offset(30); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer threadAsByteBuffer() { return __vector_as_bytebuffer(30, 1); }
+  public ByteBuffer threadInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 30, 1); }
+  public boolean resolveMentions() { int o = __offset(32); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
+  public long budgetBytes() { int o = __offset(34); return o != 0 ? bb.getLong(o + bb_pos) : 0L; }
+  public long budgetEvents() { int o = __offset(36); return o != 0 ? bb.getLong(o + bb_pos) : 0L; }
+  public boolean verifyNip05() { int o = __offset(38); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
 
   public static int createSubscriptionConfig(FlatBufferBuilder builder,
       int pipelineOffset,
@@ -53,13 +62,27 @@ public final class SubscriptionConfig extends Table {
       long bytesPerEvent,
       boolean isSlow,
       int paginationOffset,
-      boolean cacheOnly) {
-    builder.startTable(11);
+      boolean cacheOnly,
+      boolean mergedWindow,
+      boolean linkPreviews,
+      int threadOffset,
+      boolean resolveMentions,
+      long budgetBytes,
+      long budgetEvents,
+      boolean verifyNip05) {
+    builder.startTable(18);
+    SubscriptionConfig.addBudgetEvents(builder, budgetEvents);
+    SubscriptionConfig.addBudgetBytes(builder, budgetBytes);
     SubscriptionConfig.addTimeoutMs(builder, timeoutMs);
+    SubscriptionConfig.addThread(builder, threadOffset);
     SubscriptionConfig.addPagination(builder, paginationOffset);
     SubscriptionConfig.addBytesPerEvent(builder, bytesPerEvent);
     SubscriptionConfig.addMaxEvents(builder, maxEvents);
     SubscriptionConfig.addPipeline(builder, pipelineOffset);
+    SubscriptionConfig.addVerifyNip05(builder, verifyNip05);
+    SubscriptionConfig.addResolveMentions(builder, resolveMentions);
+    SubscriptionConfig.addLinkPreviews(builder, linkPreviews);
+    SubscriptionConfig.addMergedWindow(builder, mergedWindow);
     SubscriptionConfig.addCacheOnly(builder, cacheOnly);
     SubscriptionConfig.addIsSlow(builder, isSlow);
     SubscriptionConfig.addForce(builder, force);
@@ -69,7 +92,7 @@ public final class SubscriptionConfig extends Table {
     return SubscriptionConfig.endSubscriptionConfig(builder);
   }
 
-  public static void startSubscriptionConfig(FlatBufferBuilder builder) { builder.startTable(11); }
+  public static void startSubscriptionConfig(FlatBufferBuilder builder) { builder.startTable(18); }
   public static void addPipeline(FlatBufferBuilder builder, int pipelineOffset) { builder.addOffset(0, pipelineOffset, 0); }
   public static void addCloseOnEose(FlatBufferBuilder builder, boolean closeOnEose) { builder.addBoolean(1, closeOnEose, false); }
   public static void addCacheFirst(FlatBufferBuilder builder, boolean cacheFirst) { builder.addBoolean(2, cacheFirst, true); }
@@ -81,6 +104,13 @@ public final class SubscriptionConfig extends Table {
   public static void addIsSlow(FlatBufferBuilder builder, boolean isSlow) { builder.addBoolean(8, isSlow, false); }
   public static void addPagination(FlatBufferBuilder builder, int paginationOffset) { builder.addOffset(9, paginationOffset, 0); }
   public static void addCacheOnly(FlatBufferBuilder builder, boolean cacheOnly) { builder.addBoolean(10, cacheOnly, false); }
+  public static void addMergedWindow(FlatBufferBuilder builder, boolean mergedWindow) { builder.addBoolean(11, mergedWindow, false); }
+  public static void addLinkPreviews(FlatBufferBuilder builder, boolean linkPreviews) { builder.addBoolean(12, linkPreviews, false); }
+  public static void addThread(FlatBufferBuilder builder, int threadOffset) { builder.addOffset(13, threadOffset, 0); }
+  public static void addResolveMentions(FlatBufferBuilder builder, boolean resolveMentions) { builder.addBoolean(14, resolveMentions, false); }
+  public static void addBudgetBytes(FlatBufferBuilder builder, long budgetBytes) { builder.addLong(15, budgetBytes, 0L); }
+  public static void addBudgetEvents(FlatBufferBuilder builder, long budgetEvents) { builder.addLong(16, budgetEvents, 0L); }
+  public static void addVerifyNip05(FlatBufferBuilder builder, boolean verifyNip05) { builder.addBoolean(17, verifyNip05, false); }
   public static int endSubscriptionConfig(FlatBufferBuilder builder) {
     int o = builder.endTable();
     return o;
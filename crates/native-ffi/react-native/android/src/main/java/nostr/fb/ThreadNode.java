@@ -0,0 +1,69 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package nostr.fb;
+
+import com.google.flatbuffers.BaseVector;
+import com.google.flatbuffers.BooleanVector;
+import com.google.flatbuffers.ByteVector;
+import com.google.flatbuffers.Constants;
+import com.google.flatbuffers.DoubleVector;
+import com.google.flatbuffers.FlatBufferBuilder;
+import com.google.flatbuffers.FloatVector;
+import com.google.flatbuffers.IntVector;
+import com.google.flatbuffers.LongVector;
+import com.google.flatbuffers.ShortVector;
+import com.google.flatbuffers.StringVector;
+import com.google.flatbuffers.Struct;
+import com.google.flatbuffers.Table;
+import com.google.flatbuffers.UnionVector;
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+
+@SuppressWarnings("unused")
+public final class ThreadNode extends Table {
+  public static void ValidateVersion() { Constants.FLATBUFFERS_25_2_10(); }
+  public static ThreadNode getRootAsThreadNode(ByteBuffer _bb) { return getRootAsThreadNode(_bb, new ThreadNode()); }
+  public static ThreadNode getRootAsThreadNode(ByteBuffer _bb, ThreadNode obj) { _bb.order(ByteOrder.LITTLE_ENDIAN); return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __reset(_i, _bb); }
+  public ThreadNode __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public String id() { int o = __offset(4); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer idAsByteBuffer() { return __vector_as_bytebuffer(4, 1); }
+  public ByteBuffer idInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 4, 1); }
+  public String parent() { int o = __offset(6); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer parentAsByteBuffer() { return __vector_as_bytebuffer(6, 1); }
+  public ByteBuffer parentInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 6, 1); }
+  public int depth() { int o = __offset(8); return o != 0 ? bb.getShort(o + bb_pos) & 0xFFFF : 0; }
+  public boolean missing() { int o = __offset(10); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
+
+  public static int createThreadNode(FlatBufferBuilder builder,
+      int idOffset,
+      int parentOffset,
+      int depth,
+      boolean missing) {
+    builder.startTable(4);
+    ThreadNode.addParent(builder, parentOffset);
+    ThreadNode.addId(builder, idOffset);
+    ThreadNode.addDepth(builder, depth);
+    ThreadNode.addMissing(builder, missing);
+    return ThreadNode.endThreadNode(builder);
+  }
+
+  public static void startThreadNode(FlatBufferBuilder builder) { builder.startTable(4); }
+  public static void addId(FlatBufferBuilder builder, int idOffset) { builder.addOffset(0, idOffset, 0); }
+  public static void addParent(FlatBufferBuilder builder, int parentOffset) { builder.addOffset(1, parentOffset, 0); }
+  public static void addDepth(FlatBufferBuilder builder, int depth) { builder.addShort(2, (short) depth, (short) 0); }
+  public static void addMissing(FlatBufferBuilder builder, boolean missing) { builder.addBoolean(3, missing, false); }
+  public static int endThreadNode(FlatBufferBuilder builder) {
+    int o = builder.endTable();
+    builder.required(o, 4);  // id
+    return o;
+  }
+
+  public static final class Vector extends BaseVector {
+    public Vector __assign(int _vector, int _element_size, ByteBuffer _bb) { __reset(_vector, _element_size, _bb); return this; }
+
+    public ThreadNode get(int j) { return get(new ThreadNode(), j); }
+    public ThreadNode get(ThreadNode obj, int j) {  return obj.__assign(__indirect(__element(j), bb), bb); }
+  }
+}
@@ -0,0 +1,81 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package nostr.fb;
+
+import com.google.flatbuffers.BaseVector;
+import com.google.flatbuffers.BooleanVector;
+import com.google.flatbuffers.ByteVector;
+import com.google.flatbuffers.Constants;
+import com.google.flatbuffers.DoubleVector;
+import com.google.flatbuffers.FlatBufferBuilder;
+import com.google.flatbuffers.FloatVector;
+import com.google.flatbuffers.IntVector;
+import com.google.flatbuffers.LongVector;
+import com.google.flatbuffers.ShortVector;
+import com.google.flatbuffers.StringVector;
+import com.google.flatbuffers.Struct;
+import com.google.flatbuffers.Table;
+import com.google.flatbuffers.UnionVector;
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+
+@SuppressWarnings("unused")
+public final class ThreadTree extends Table {
+  public static void ValidateVersion() { Constants.FLATBUFFERS_25_2_10(); }
+  public static ThreadTree getRootAsThreadTree(ByteBuffer _bb) { return getRootAsThreadTree(_bb, new ThreadTree()); }
+  public static ThreadTree getRootAsThreadTree(ByteBuffer _bb, ThreadTree obj) { _bb.order(ByteOrder.LITTLE_ENDIAN); return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __reset(_i, _bb); }
+  public ThreadTree __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public String focus() { int o = __offset(4); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer focusAsByteBuffer() { return __vector_as_bytebuffer(4, 1); }
+  public ByteBuffer focusInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 4, 1); }
+  public String root() { int o = __offset(6); return o != 0 ? __string(o + bb_pos) : null; }
+  public ByteBuffer rootAsByteBuffer() { return __vector_as_bytebuffer(6, 1); }
+  public ByteBuffer rootInByteBuffer(ByteBuffer _bb) { return __vector_in_bytebuffer(_bb, 6, 1); }
+  public nostr.fb.ThreadNode nodes(int j) { return nodes(new nostr.fb.ThreadNode(), j); }
+  public nostr.fb.ThreadNode nodes(nostr.fb.ThreadNode obj, int j) { int o = __offset(8); return o != 0 ? obj.__assign(__indirect(__vector(o) + j * 4), bb) : null; }
+  public int nodesLength() { int o = __offset(8); return o != 0 ? __vector_len(o) : 0; }
+  public nostr.fb.ThreadNode.Vector nodesVector() { return nodesVector(new nostr.fb.ThreadNode.Vector()); }
+  public nostr.fb.ThreadNode.Vector nodesVector(nostr.fb.ThreadNode.Vector obj) { int o = __offset(8); return o != 0 ? obj.__assign(__vector(o), 4, bb) : null; }
+  public boolean complete() { int o = __offset(10); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
+  public boolean delta() { int o = __offset(12); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
+
+  public static int createThreadTree(FlatBufferBuilder builder,
+      int focusOffset,
+      int rootOffset,
+      int nodesOffset,
+      boolean complete,
+      boolean delta) {
+    builder.startTable(5);
+    ThreadTree.addNodes(builder, nodesOffset);
+    ThreadTree.addRoot(builder, rootOffset);
+    ThreadTree.addFocus(builder, focusOffset);
+    ThreadTree.addDelta(builder, delta);
+    ThreadTree.addComplete(builder, complete);
+    return ThreadTree.endThreadTree(builder);
+  }
+
+  public static void startThreadTree(FlatBufferBuilder builder) { builder.startTable(5); }
+  public static void addFocus(FlatBufferBuilder builder, int focusOffset) { builder.addOffset(0, focusOffset, 0); }
+  public static void addRoot(FlatBufferBuilder builder, int rootOffset) { builder.addOffset(1, rootOffset, 0); }
+  public static void addNodes(FlatBufferBuilder builder, int nodesOffset) { builder.addOffset(2, nodesOffset, 0); }
+  public static int createNodesVector(FlatBufferBuilder builder, int[] data) { builder.startVector(4, data.length, 4); for (int i = data.length - 1; i >= 0; i--) builder.addOffset(data[i]); return builder.endVector(); }
+  public static void startNodesVector(FlatBufferBuilder builder, int numElems) { builder.startVector(4, numElems, 4); }
+  public static void addComplete(FlatBufferBuilder builder, boolean complete) { builder.addBoolean(3, complete, false); }
+  public static void addDelta(FlatBufferBuilder builder, boolean delta) { builder.addBoolean(4, delta, false); }
+  public static int endThreadTree(FlatBufferBuilder builder) {
+    int o = builder.endTable();
+    builder.required(o, 4);  // focus
+    builder.required(o, 6);  // root
+    builder.required(o, 8);  // nodes
+    return o;
+  }
+
+  public static final class Vector extends BaseVector {
+    public Vector __assign(int _vector, int _element_size, ByteBuffer _bb) { __reset(_vector, _element_size, _bb); return this; }
+
+    public ThreadTree get(int j) { return get(new ThreadTree(), j); }
+    public ThreadTree get(ThreadTree obj, int j) {  return obj.__assign(__indirect(__element(j), bb), bb); }
+  }
+}
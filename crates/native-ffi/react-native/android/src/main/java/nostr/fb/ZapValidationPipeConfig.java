@@ -0,0 +1,53 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package nostr.fb;
+
+import com.google.flatbuffers.BaseVector;
+import com.google.flatbuffers.BooleanVector;
+import com.google.flatbuffers.ByteVector;
+import com.google.flatbuffers.Constants;
+import com.google.flatbuffers.DoubleVector;
+import com.google.flatbuffers.FlatBufferBuilder;
+import com.google.flatbuffers.FloatVector;
+import com.google.flatbuffers.IntVector;
+import com.google.flatbuffers.LongVector;
+import com.google.flatbuffers.ShortVector;
+import com.google.flatbuffers.StringVector;
+import com.google.flatbuffers.Struct;
+import com.google.flatbuffers.Table;
+import com.google.flatbuffers.UnionVector;
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+
+@SuppressWarnings("unused")
+public final class ZapValidationPipeConfig extends Table {
+  public static void ValidateVersion() { Constants.FLATBUFFERS_25_2_10(); }
+  public static ZapValidationPipeConfig getRootAsZapValidationPipeConfig(ByteBuffer _bb) { return getRootAsZapValidationPipeConfig(_bb, new ZapValidationPipeConfig()); }
+  public static ZapValidationPipeConfig getRootAsZapValidationPipeConfig(ByteBuffer _bb, ZapValidationPipeConfig obj) { _bb.order(ByteOrder.LITTLE_ENDIAN); return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __reset(_i, _bb); }
+  public ZapValidationPipeConfig __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public boolean dropInvalid() { int o = __offset(4); return o != 0 ? 0!=bb.get(o + bb_pos) : false; }
+
+  public static int createZapValidationPipeConfig(FlatBufferBuilder builder,
+      boolean dropInvalid) {
+    builder.startTable(1);
+    ZapValidationPipeConfig.addDropInvalid(builder, dropInvalid);
+    return ZapValidationPipeConfig.endZapValidationPipeConfig(builder);
+  }
+
+  public static void startZapValidationPipeConfig(FlatBufferBuilder builder) { builder.startTable(1); }
+  public static void addDropInvalid(FlatBufferBuilder builder, boolean dropInvalid) { builder.addBoolean(0, dropInvalid, false); }
+  public static int endZapValidationPipeConfig(FlatBufferBuilder builder) {
+    int o = builder.endTable();
+    return o;
+  }
+
+  public static final class Vector extends BaseVector {
+    public Vector __assign(int _vector, int _element_size, ByteBuffer _bb) { __reset(_vector, _element_size, _bb); return this; }
+
+    public ZapValidationPipeConfig get(int j) { return get(new ZapValidationPipeConfig(), j); }
+    public ZapValidationPipeConfig get(ZapValidationPipeConfig obj, int j) {  return obj.__assign(__indirect(__element(j), bb), bb); }
+  }
+}
+
@@ -3,8 +3,13 @@ mod jni;
 mod mesh_ffi;
 
 use futures::StreamExt;
+use nipworker_core::auth_policy::{AuthDecision, AuthPolicy, AuthPolicyRules};
+use nipworker_core::proxy::{ProxyConfig, ProxyScope};
+use nipworker_core::relay_health::RelayHealth;
+use nipworker_core::relay_policy::{RelayPolicy, RelayPolicyRules};
 use nipworker_core::service::engine::NostrEngine;
 use nipworker_core::storage::{NostrDbStorage, PersistentNostrDbStorage};
+use nipworker_core::transport::types::RelayConfig;
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
 use std::ffi::{c_char, c_void, CStr};
@@ -19,6 +24,7 @@ use tokio::runtime::Builder;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::task::LocalSet;
 
+mod socks;
 pub mod storage;
 pub mod transport;
 
@@ -80,6 +86,13 @@ pub extern "C" fn nipworker_set_log_level(level: *const c_char) {
     NATIVE_LOG_LEVEL.store(parsed, Ordering::Relaxed);
 }
 
+fn c_string_arg(value: *const c_char) -> String {
+    if value.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(value) }.to_string_lossy().to_string()
+}
+
 fn split_relay_csv(value: *const c_char) -> Vec<String> {
     if value.is_null() {
         return Vec::new();
@@ -92,6 +105,49 @@ fn split_relay_csv(value: *const c_char) -> Vec<String> {
         .collect()
 }
 
+/// Relay socket settings from the `transport_json` argument of the
+/// `*_with_transport` initializers, e.g. `{"keepalive_timeout_secs":30}`.
+/// `proxy` is a SOCKS5 address (`host:port` or `socks5h://host:port`, e.g. a
+/// local Tor daemon) used by this engine's relay sockets and HTTP lookups;
+/// `proxy_scope` is `"all"` (default), `"onion"` or an array of relay URLs,
+/// and `.onion` hosts always use it. Null, invalid or missing fields keep
+/// the `RelayConfig` defaults.
+fn relay_config_from_json(transport_json: *const c_char) -> RelayConfig {
+    let mut config = RelayConfig::default();
+    let json = c_string_arg(transport_json);
+    if json.trim().is_empty() {
+        return config;
+    }
+    let value: serde_json::Value = match serde_json::from_str(&json) {
+        Ok(value) => value,
+        Err(e) => {
+            log::error!("Invalid transport config: {}", e);
+            return config;
+        }
+    };
+    if let Some(secs) = value["keepalive_timeout_secs"].as_u64().filter(|secs| *secs > 0) {
+        config.keepalive_timeout = std::time::Duration::from_secs(secs);
+    }
+    if let Some(addr) = value["proxy"].as_str().filter(|addr| !addr.trim().is_empty()) {
+        let scope = match &value["proxy_scope"] {
+            serde_json::Value::String(scope) if scope == "onion" => ProxyScope::Onion,
+            serde_json::Value::Array(relays) => ProxyScope::Relays(
+                relays
+                    .iter()
+                    .filter_map(|relay| relay.as_str())
+                    .map(|relay| relay.trim().to_string())
+                    .collect(),
+            ),
+            _ => ProxyScope::All,
+        };
+        config.proxy = ProxyConfig::parse(addr, scope);
+        if config.proxy.is_none() {
+            log::error!("Ignoring invalid proxy address {}", addr);
+        }
+    }
+    config
+}
+
 fn fallback_relays(relays: Vec<String>, fallback: &[&str]) -> Vec<String> {
     if relays.is_empty() {
         fallback.iter().map(|s| s.to_string()).collect()
@@ -289,6 +345,10 @@ struct NipworkerState {
     subscriptions: Arc<Mutex<NativeSubscriptionStore>>,
     mesh_tx: Option<tokio::sync::mpsc::UnboundedSender<mesh_ffi::MeshCommand>>,
     engine_thread: Option<thread::JoinHandle<()>>,
+    /// Filled in by the engine thread once the engine exists.
+    relay_health: Arc<Mutex<Option<RelayHealth>>>,
+    relay_policy: Arc<Mutex<Option<RelayPolicy>>>,
+    auth_policy: Arc<Mutex<Option<AuthPolicy>>>,
 }
 
 /// Runtime-independent owner for a pinned subscription buffer. Unlike the
@@ -620,6 +680,29 @@ pub extern "C" fn nipworker_init_with_options(
     default_relays: *const c_char,
     indexer_relays: *const c_char,
     mesh_enabled: bool,
+) -> *mut c_void {
+    nipworker_init_with_transport(
+        callback,
+        userdata,
+        storage_path,
+        default_relays,
+        indexer_relays,
+        mesh_enabled,
+        std::ptr::null(),
+    )
+}
+
+/// `nipworker_init_with_options` plus relay socket settings as JSON (see
+/// `relay_config_from_json`); null keeps the defaults.
+#[no_mangle]
+pub extern "C" fn nipworker_init_with_transport(
+    callback: extern "C" fn(*mut c_void, *const u8, usize),
+    userdata: *mut c_void,
+    storage_path: *const c_char,
+    default_relays: *const c_char,
+    indexer_relays: *const c_char,
+    mesh_enabled: bool,
+    transport_json: *const c_char,
 ) -> *mut c_void {
     // Initialize tracing subscriber for native builds
     #[cfg(target_vendor = "apple")]
@@ -709,6 +792,8 @@ pub extern "C" fn nipworker_init_with_options(
     };
     let default_relays = split_relay_csv(default_relays);
     let indexer_relays = split_relay_csv(indexer_relays);
+    let relay_config = relay_config_from_json(transport_json);
+    let http_proxy = relay_config.proxy.clone();
 
     // Set panic hook so Rust panics are visible instead of silent thread death
     std::panic::set_hook(Box::new(|info| {
@@ -732,6 +817,12 @@ pub extern "C" fn nipworker_init_with_options(
     let callback_subscriptions = subscriptions.clone();
     let callback_cmd_tx = cmd_tx.clone();
     let callback_alive = alive.clone();
+    let relay_health = Arc::new(Mutex::new(None));
+    let engine_relay_health = relay_health.clone();
+    let relay_policy = Arc::new(Mutex::new(None));
+    let engine_relay_policy = relay_policy.clone();
+    let auth_policy = Arc::new(Mutex::new(None));
+    let engine_auth_policy = auth_policy.clone();
 
     // Spawn engine thread
     let engine_thread = thread::Builder::new()
@@ -769,7 +860,7 @@ pub extern "C" fn nipworker_init_with_options(
                 };
             let engine = if let Some(mesh_rx) = mesh_rx {
                 let (engine, mesh_endpoint) = NostrEngine::new_threaded_with_mesh(
-                    || Arc::new(NativeTransport::new()),
+                    move || Arc::new(NativeTransport::with_config(relay_config)),
                     client_storage_factory,
                     move || {
                     let storage = new_named_core_storage(
@@ -791,11 +882,21 @@ pub extern "C" fn nipworker_init_with_options(
                 Arc::new(engine)
             } else {
                 Arc::new(NostrEngine::new_threaded(
-                    || Arc::new(NativeTransport::new()),
+                    move || Arc::new(NativeTransport::with_config(relay_config)),
                     client_storage_factory,
                     async_event_tx,
                 ))
             };
+            engine.http().set_proxy(http_proxy);
+            if let Ok(mut slot) = engine_relay_health.lock() {
+                *slot = Some(engine.relay_health());
+            }
+            if let Ok(mut slot) = engine_relay_policy.lock() {
+                *slot = Some(engine.relay_policy());
+            }
+            if let Ok(mut slot) = engine_auth_policy.lock() {
+                *slot = Some(engine.auth_policy());
+            }
 
             // Bridge async events to the native transport callback. The
             // callback receives an owned allocation and may adopt it directly
@@ -911,6 +1012,9 @@ pub extern "C" fn nipworker_init_with_options(
             subscriptions,
             mesh_tx,
             engine_thread: Some(engine_thread),
+            relay_health,
+            relay_policy,
+            auth_policy,
         }),
     });
     Box::into_raw(handle) as *mut c_void
@@ -929,6 +1033,29 @@ pub extern "C" fn nipworker_shared_acquire(
     default_relays: *const c_char,
     indexer_relays: *const c_char,
     mesh_enabled: bool,
+) -> *mut c_void {
+    nipworker_shared_acquire_with_transport(
+        callback,
+        userdata,
+        storage_path,
+        default_relays,
+        indexer_relays,
+        mesh_enabled,
+        std::ptr::null(),
+    )
+}
+
+/// `nipworker_shared_acquire` with relay socket settings for the engine it
+/// creates; like the other arguments they are ignored once it is running.
+#[no_mangle]
+pub extern "C" fn nipworker_shared_acquire_with_transport(
+    callback: NativeCallback,
+    userdata: *mut c_void,
+    storage_path: *const c_char,
+    default_relays: *const c_char,
+    indexer_relays: *const c_char,
+    mesh_enabled: bool,
+    transport_json: *const c_char,
 ) -> *mut c_void {
     let Ok(_lifecycle) = SHARED_ENGINE_LIFECYCLE.lock() else {
         return std::ptr::null_mut();
@@ -982,13 +1109,14 @@ pub extern "C" fn nipworker_shared_acquire(
     );
     drop(state);
 
-    let handle = nipworker_init_with_options(
+    let handle = nipworker_init_with_transport(
         shared_engine_callback,
         std::ptr::null_mut(),
         storage_path,
         default_relays,
         indexer_relays,
         mesh_enabled,
+        transport_json,
     );
     let Ok(mut state) = mutex.lock() else {
         if !handle.is_null() {
@@ -1273,6 +1401,144 @@ pub unsafe extern "C" fn nipworker_wake(handle: *mut c_void) {
     }
 }
 
+/// Relay health scores as a JSON array (best first), for settings screens to
+/// flag dead or slow relays. Returns null before the engine is up; free the
+/// result with `nipworker_free_bytes`.
+#[no_mangle]
+pub unsafe extern "C" fn nipworker_relay_health(
+    handle: *mut c_void,
+    out_len: *mut usize,
+) -> *mut u8 {
+    if !out_len.is_null() {
+        unsafe { *out_len = 0 };
+    }
+    if handle.is_null() || out_len.is_null() {
+        return std::ptr::null_mut();
+    }
+    let handle = unsafe { &*(handle as *mut NipworkerHandle) };
+    let health = match handle.state.lock() {
+        Ok(state) if !state.destroyed => state
+            .relay_health
+            .lock()
+            .ok()
+            .and_then(|slot| slot.clone()),
+        _ => None,
+    };
+    let Some(health) = health else {
+        return std::ptr::null_mut();
+    };
+    let Ok(json) = serde_json::to_vec(&health.scores()) else {
+        return std::ptr::null_mut();
+    };
+    let bytes = json.into_boxed_slice();
+    unsafe { *out_len = bytes.len() };
+    Box::into_raw(bytes) as *mut u8
+}
+
+/// Replaces the engine's relay policy with `rules_json` (a JSON
+/// `RelayPolicyRules` object; missing fields take their defaults). Returns
+/// false when the JSON is invalid or the engine is not running yet.
+#[no_mangle]
+pub unsafe extern "C" fn nipworker_set_relay_policy(
+    handle: *mut c_void,
+    rules_json: *const c_char,
+) -> bool {
+    if handle.is_null() || rules_json.is_null() {
+        return false;
+    }
+    let handle = unsafe { &*(handle as *mut NipworkerHandle) };
+    let json = unsafe { CStr::from_ptr(rules_json) }.to_string_lossy();
+    let rules: RelayPolicyRules = match serde_json::from_str(&json) {
+        Ok(rules) => rules,
+        Err(e) => {
+            log::error!("Invalid relay policy: {}", e);
+            return false;
+        }
+    };
+    let policy = match handle.state.lock() {
+        Ok(state) if !state.destroyed => state
+            .relay_policy
+            .lock()
+            .ok()
+            .and_then(|slot| slot.clone()),
+        _ => None,
+    };
+    match policy {
+        Some(policy) => {
+            policy.set(rules);
+            true
+        }
+        None => false,
+    }
+}
+
+fn running_auth_policy(handle: &NipworkerHandle) -> Option<AuthPolicy> {
+    match handle.state.lock() {
+        Ok(state) if !state.destroyed => state
+            .auth_policy
+            .lock()
+            .ok()
+            .and_then(|slot| slot.clone()),
+        _ => None,
+    }
+}
+
+/// Replaces the engine's NIP-42 auth policy with `rules_json` (a JSON
+/// `AuthPolicyRules` object; missing fields take their defaults). Returns
+/// false when the JSON is invalid or the engine is not running yet.
+#[no_mangle]
+pub unsafe extern "C" fn nipworker_set_auth_policy(
+    handle: *mut c_void,
+    rules_json: *const c_char,
+) -> bool {
+    if handle.is_null() || rules_json.is_null() {
+        return false;
+    }
+    let handle = unsafe { &*(handle as *mut NipworkerHandle) };
+    let json = unsafe { CStr::from_ptr(rules_json) }.to_string_lossy();
+    let rules: AuthPolicyRules = match serde_json::from_str(&json) {
+        Ok(rules) => rules,
+        Err(e) => {
+            log::error!("Invalid auth policy: {}", e);
+            return false;
+        }
+    };
+    match running_auth_policy(handle) {
+        Some(policy) => {
+            policy.set(rules);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Answers a relay's `auth-pending` challenge with `decision_json`
+/// (`"deny"` or `{"allow":{"type":"user"|"ephemeral"}}`). With `remember`,
+/// later challenges from the relay get the same answer. Returns false when
+/// the JSON is invalid or no challenge from `relay` was waiting.
+#[no_mangle]
+pub unsafe extern "C" fn nipworker_resolve_auth(
+    handle: *mut c_void,
+    relay: *const c_char,
+    decision_json: *const c_char,
+    remember: bool,
+) -> bool {
+    if handle.is_null() || relay.is_null() || decision_json.is_null() {
+        return false;
+    }
+    let handle = unsafe { &*(handle as *mut NipworkerHandle) };
+    let relay = unsafe { CStr::from_ptr(relay) }.to_string_lossy();
+    let json = unsafe { CStr::from_ptr(decision_json) }.to_string_lossy();
+    let decision: AuthDecision = match serde_json::from_str(&json) {
+        Ok(decision) => decision,
+        Err(e) => {
+            log::error!("Invalid auth decision: {}", e);
+            return false;
+        }
+    };
+    running_auth_policy(handle).is_some_and(|policy| policy.resolve(&relay, decision, remember))
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nipworker_handle_message(handle: *mut c_void, ptr: *const u8, len: usize) {
     if handle.is_null() || ptr.is_null() {
@@ -1746,9 +2012,9 @@ mod tests {
         nipworker_shared_acquire, nipworker_shared_process_acquire,
         nipworker_shared_process_release, nipworker_shared_release, nipworker_subscription_pin,
         nipworker_subscription_pin_release, nipworker_subscription_try_reset,
-        parse_native_log_level, shared_engine, AppendOutcome, BatchOutcome, CallbackAction,
-        EngineCommand, NativeSubscription, NativeSubscriptionStore, NipworkerHandle,
-        NipworkerState, LOG_LEVEL_DEBUG, LOG_LEVEL_ERROR, LOG_LEVEL_INFO, LOG_LEVEL_TRACE,
+        parse_native_log_level, relay_config_from_json, shared_engine, AppendOutcome,
+        BatchOutcome, CallbackAction, EngineCommand, NativeSubscription, NativeSubscriptionStore,
+        NipworkerHandle, NipworkerState, ProxyScope, RelayConfig, LOG_LEVEL_DEBUG, LOG_LEVEL_ERROR, LOG_LEVEL_INFO, LOG_LEVEL_TRACE,
         LOG_LEVEL_WARN, SHARED_ENGINE_INITIALIZATIONS,
     };
     use std::ffi::{c_void, CString};
@@ -1772,6 +2038,23 @@ mod tests {
         assert_eq!(parse_native_log_level("invalid"), LOG_LEVEL_ERROR);
     }
 
+    #[test]
+    fn transport_json_configures_keepalive_and_a_per_engine_proxy() {
+        let json = CString::new(
+            r#"{"keepalive_timeout_secs":20,"proxy":"socks5h://127.0.0.1:9050","proxy_scope":["wss://nos.lol"]}"#,
+        )
+        .unwrap();
+        let config = relay_config_from_json(json.as_ptr());
+        assert_eq!(config.keepalive_timeout, Duration::from_secs(20));
+        let proxy = config.proxy.unwrap();
+        assert_eq!((proxy.host.as_str(), proxy.port), ("127.0.0.1", 9050));
+        assert_eq!(proxy.scope, ProxyScope::Relays(vec!["wss://nos.lol".to_string()]));
+
+        let defaults = relay_config_from_json(std::ptr::null());
+        assert_eq!(defaults.keepalive_timeout, RelayConfig::default().keepalive_timeout);
+        assert!(defaults.proxy.is_none());
+    }
+
     fn wake_ids(outcome: &BatchOutcome) -> Vec<String> {
         outcome
             .actions
@@ -1803,6 +2086,9 @@ mod tests {
                 subscriptions: Arc::new(Mutex::new(NativeSubscriptionStore::new())),
                 mesh_tx: None,
                 engine_thread: None,
+                relay_health: Arc::new(Mutex::new(None)),
+                relay_policy: Arc::new(Mutex::new(None)),
+                auth_policy: Arc::new(Mutex::new(None)),
             }),
         });
         Box::into_raw(handle) as *mut c_void
@@ -1826,6 +2112,9 @@ mod tests {
                 subscriptions: Arc::new(Mutex::new(NativeSubscriptionStore::new())),
                 mesh_tx: None,
                 engine_thread: None,
+                relay_health: Arc::new(Mutex::new(None)),
+                relay_policy: Arc::new(Mutex::new(None)),
+                auth_policy: Arc::new(Mutex::new(None)),
             }),
         });
         let handle = Box::into_raw(handle) as *mut c_void;
@@ -1847,6 +2136,9 @@ mod tests {
                 subscriptions: Arc::new(Mutex::new(NativeSubscriptionStore::new())),
                 mesh_tx: None,
                 engine_thread: None,
+                relay_health: Arc::new(Mutex::new(None)),
+                relay_policy: Arc::new(Mutex::new(None)),
+                auth_policy: Arc::new(Mutex::new(None)),
             }),
         });
         let handle = Box::into_raw(handle) as *mut c_void;
@@ -2463,4 +2755,88 @@ mod tests {
         let _ = unsafe { Box::from_raw(third_callbacks) };
         let _ = unsafe { Box::from_raw(fourth_callbacks) };
     }
+
+    #[tokio::test]
+    #[allow(clippy::arc_with_non_send_sync)]
+    async fn threaded_engine_round_trips_against_test_relay() {
+        use futures::StreamExt;
+        use nipworker_core::generated::nostr::fb;
+        use nipworker_core::service::engine::NostrEngine;
+        use nipworker_core::types::network::Request;
+
+        let relay = nipworker_testrelay::TestRelay::start(Default::default())
+            .await
+            .unwrap();
+        for (id, kind) in [('a', 1), ('b', 1), ('c', 7)] {
+            relay
+                .publish(serde_json::json!({
+                    "id": id.to_string().repeat(64),
+                    "pubkey": "1".repeat(64),
+                    "kind": kind,
+                    "created_at": 10,
+                    "tags": [],
+                    "content": "",
+                    "sig": "00".repeat(64),
+                }))
+                .unwrap();
+        }
+        let url = relay.url();
+
+        let ids = tokio::task::LocalSet::new()
+            .run_until(async move {
+                let (event_tx, mut event_rx) = futures::channel::mpsc::channel(100);
+                let engine = NostrEngine::new_threaded(
+                    || Arc::new(super::NativeTransport::new()),
+                    || {
+                        Arc::new(super::new_named_core_storage(
+                            "testrelay-e2e",
+                            1024 * 1024,
+                            vec![],
+                            vec![],
+                        ))
+                    },
+                    event_tx,
+                );
+                let request = Request {
+                    relays: vec![url],
+                    kinds: vec![1],
+                    ..Default::default()
+                };
+                engine
+                    .subscribe("e2e".to_string(), vec![request])
+                    .await
+                    .unwrap();
+
+                let mut ids = Vec::new();
+                let deadline = Instant::now() + Duration::from_secs(10);
+                loop {
+                    assert!(Instant::now() < deadline, "no EOSE; got {:?}", ids);
+                    let Ok(Some((sub_id, bytes))) =
+                        tokio::time::timeout(Duration::from_millis(200), event_rx.next()).await
+                    else {
+                        continue;
+                    };
+                    if sub_id != "e2e" {
+                        continue;
+                    }
+                    let message = flatbuffers::root::<fb::WorkerMessage>(&bytes).unwrap();
+                    match message.content_type() {
+                        fb::Message::ParsedEvent => {
+                            ids.push(message.content_as_parsed_event().unwrap().id().to_string());
+                        }
+                        fb::Message::ConnectionStatus
+                            if message.content_as_connection_status().unwrap().status() == "EOSE" =>
+                        {
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                ids.sort();
+                ids
+            })
+            .await;
+        assert_eq!(ids, vec!["a".repeat(64), "b".repeat(64)]);
+        assert!(relay.received().iter().any(|frame| frame.starts_with(r#"["REQ""#)));
+    }
 }
@@ -0,0 +1,173 @@
+//! Minimal SOCKS5 client (RFC 1928, no-auth CONNECT) for relay sockets.
+//!
+//! Hostnames are sent to the proxy unresolved so lookups happen on the proxy
+//! side (what Tor needs for `.onion` and what keeps DNS from leaking).
+
+use nipworker_core::proxy::ProxyConfig;
+use std::io::{Error, ErrorKind, Result};
+use std::net::IpAddr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+const VERSION: u8 = 0x05;
+const NO_AUTH: u8 = 0x00;
+const CMD_CONNECT: u8 = 0x01;
+const ATYP_IPV4: u8 = 0x01;
+const ATYP_DOMAIN: u8 = 0x03;
+const ATYP_IPV6: u8 = 0x04;
+
+/// Opens a TCP stream to `host:port` tunnelled through `proxy`.
+pub async fn connect(proxy: &ProxyConfig, host: &str, port: u16) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect((proxy.host.as_str(), proxy.port)).await?;
+
+    stream.write_all(&[VERSION, 1, NO_AUTH]).await?;
+    let mut choice = [0u8; 2];
+    stream.read_exact(&mut choice).await?;
+    if choice != [VERSION, NO_AUTH] {
+        return Err(Error::new(
+            ErrorKind::PermissionDenied,
+            "SOCKS5 proxy requires authentication",
+        ));
+    }
+
+    stream.write_all(&connect_request(host, port)?).await?;
+
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[0] != VERSION {
+        return Err(Error::new(ErrorKind::InvalidData, "not a SOCKS5 proxy"));
+    }
+    if reply[1] != 0 {
+        return Err(Error::new(ErrorKind::ConnectionRefused, reply_error(reply[1])));
+    }
+    // Skip the bound address; the stream is ready once it is consumed.
+    let bound_len = match reply[3] {
+        ATYP_IPV4 => 4,
+        ATYP_IPV6 => 16,
+        ATYP_DOMAIN => stream.read_u8().await? as usize,
+        other => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown SOCKS5 address type {}", other),
+            ))
+        }
+    };
+    let mut bound = vec![0u8; bound_len + 2];
+    stream.read_exact(&mut bound).await?;
+    Ok(stream)
+}
+
+fn connect_request(host: &str, port: u16) -> Result<Vec<u8>> {
+    let mut request = vec![VERSION, CMD_CONNECT, 0x00];
+    match host.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(ATYP_IPV4);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(ATYP_IPV6);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            let name = host.as_bytes();
+            if name.is_empty() || name.len() > 255 {
+                return Err(Error::new(ErrorKind::InvalidInput, "invalid SOCKS5 hostname"));
+            }
+            request.push(ATYP_DOMAIN);
+            request.push(name.len() as u8);
+            request.extend_from_slice(name);
+        }
+    }
+    request.extend_from_slice(&port.to_be_bytes());
+    Ok(request)
+}
+
+fn reply_error(code: u8) -> &'static str {
+    match code {
+        0x01 => "SOCKS5 general failure",
+        0x02 => "SOCKS5 connection not allowed",
+        0x03 => "SOCKS5 network unreachable",
+        0x04 => "SOCKS5 host unreachable",
+        0x05 => "SOCKS5 connection refused",
+        0x06 => "SOCKS5 TTL expired",
+        0x07 => "SOCKS5 command not supported",
+        0x08 => "SOCKS5 address type not supported",
+        _ => "SOCKS5 request failed",
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use nipworker_core::proxy::ProxyScope;
+    use tokio::net::TcpListener;
+
+    /// Local SOCKS5 stand-in: accepts one client, reports the requested
+    /// (host, port) and splices the tunnel to `upstream`.
+    pub(crate) async fn socks_stand_in(
+        upstream: std::net::SocketAddr,
+    ) -> (ProxyConfig, tokio::sync::oneshot::Receiver<(String, u16)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (target_tx, target_rx) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let (mut client, _) = listener.accept().await.unwrap();
+            let mut greeting = [0u8; 3];
+            client.read_exact(&mut greeting).await.unwrap();
+            assert_eq!(greeting, [VERSION, 1, NO_AUTH]);
+            client.write_all(&[VERSION, NO_AUTH]).await.unwrap();
+
+            let mut head = [0u8; 4];
+            client.read_exact(&mut head).await.unwrap();
+            assert_eq!(head[..3], [VERSION, CMD_CONNECT, 0]);
+            // Only hostnames are accepted: the client must not resolve them.
+            assert_eq!(head[3], ATYP_DOMAIN);
+            let len = client.read_u8().await.unwrap() as usize;
+            let mut name = vec![0u8; len];
+            client.read_exact(&mut name).await.unwrap();
+            let port = client.read_u16().await.unwrap();
+            let _ = target_tx.send((String::from_utf8(name).unwrap(), port));
+
+            client
+                .write_all(&[VERSION, 0, 0, ATYP_IPV4, 127, 0, 0, 1, 0, 0])
+                .await
+                .unwrap();
+            let mut upstream = TcpStream::connect(upstream).await.unwrap();
+            let _ = tokio::io::copy_bidirectional(&mut client, &mut upstream).await;
+        });
+        let proxy = ProxyConfig {
+            host: addr.ip().to_string(),
+            port: addr.port(),
+            scope: ProxyScope::All,
+        };
+        (proxy, target_rx)
+    }
+
+    #[test]
+    fn ip_literals_use_address_types() {
+        assert_eq!(
+            connect_request("10.0.0.1", 80).unwrap(),
+            [VERSION, CMD_CONNECT, 0, ATYP_IPV4, 10, 0, 0, 1, 0, 80]
+        );
+        assert_eq!(connect_request("[::1]", 443).unwrap()[3], ATYP_IPV6);
+        assert!(connect_request("", 443).is_err());
+    }
+
+    #[tokio::test]
+    async fn tunnels_by_hostname_through_the_proxy() {
+        let upstream = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let upstream_addr = upstream.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut conn, _) = upstream.accept().await.unwrap();
+            conn.write_all(b"hello").await.unwrap();
+        });
+        let (proxy, target) = socks_stand_in(upstream_addr).await;
+
+        let mut stream = connect(&proxy, "relay.example.onion", 80).await.unwrap();
+        let mut greeting = [0u8; 5];
+        stream.read_exact(&mut greeting).await.unwrap();
+
+        assert_eq!(&greeting, b"hello");
+        assert_eq!(target.await.unwrap(), ("relay.example.onion".to_string(), 80));
+    }
+}
@@ -1,18 +1,22 @@
 use futures::channel::mpsc;
 use futures::{SinkExt, StreamExt};
+use nipworker_core::proxy::ProxyConfig;
 use nipworker_core::traits::{RelayTransport, TransportError, TransportStatus};
+use nipworker_core::transport::types::RelayConfig;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpStream;
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::Connector;
 
 struct WsHandle {
-    write: mpsc::UnboundedSender<String>,
+    write: mpsc::UnboundedSender<Message>,
     close_tx: tokio::sync::mpsc::UnboundedSender<()>,
 }
 
@@ -21,10 +25,18 @@ pub struct NativeTransport {
     message_callbacks: Rc<RefCell<HashMap<String, Box<dyn Fn(String)>>>>,
     status_callbacks: Rc<RefCell<HashMap<String, Box<dyn Fn(TransportStatus)>>>>,
     tls_config: Arc<rustls::ClientConfig>,
+    keepalive_timeout: Duration,
+    proxy: Option<ProxyConfig>,
 }
 
 impl NativeTransport {
     pub fn new() -> Self {
+        Self::with_config(RelayConfig::default())
+    }
+
+    /// Uses `config.keepalive_timeout` for ping/pong dead-connection detection
+    /// and routes relays matched by `config.proxy` through that SOCKS5 proxy.
+    pub fn with_config(config: RelayConfig) -> Self {
         let _ = rustls::crypto::ring::default_provider().install_default();
         let mut root_store = rustls::RootCertStore::empty();
         root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
@@ -39,10 +51,32 @@ impl NativeTransport {
             message_callbacks: Rc::new(RefCell::new(HashMap::new())),
             status_callbacks: Rc::new(RefCell::new(HashMap::new())),
             tls_config,
+            keepalive_timeout: config.keepalive_timeout,
+            proxy: config.proxy,
         }
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+enum Keepalive {
+    Wait,
+    Ping,
+    Dead,
+}
+
+/// Keepalive step for a socket silent for `idle`, with a ping outstanding
+/// for `ping_age` (if any). After half of `timeout` without inbound frames
+/// a ping goes out; a socket that stays silent for the other half is dead.
+fn keepalive_step(idle: Duration, ping_age: Option<Duration>, timeout: Duration) -> Keepalive {
+    let half = timeout / 2;
+    match ping_age {
+        Some(age) if age >= half => Keepalive::Dead,
+        Some(_) => Keepalive::Wait,
+        None if idle >= half => Keepalive::Ping,
+        None => Keepalive::Wait,
+    }
+}
+
 /// Parse host and port from a WebSocket URL.
 fn parse_host_port(url_str: &str) -> Result<(String, u16), TransportError> {
     let parsed = url::Url::parse(url_str)
@@ -64,10 +98,15 @@ const TLS_WS_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(8);
 async fn open_websocket(
     url: &str,
     tls_config: Arc<rustls::ClientConfig>,
+    proxy: Option<&ProxyConfig>,
 ) -> Result<
     tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<TcpStream>>,
     TransportError,
 > {
+    if let Some(proxy) = proxy.filter(|proxy| proxy.applies_to(url)) {
+        return open_websocket_via_proxy(url, tls_config, proxy).await;
+    }
+
     // Use manual path with pre-built TLS config to avoid blocking the LocalSet
     // with repeated ClientConfig construction (expensive with webpki-roots).
     log::info!(
@@ -214,15 +253,68 @@ async fn open_websocket(
     )))
 }
 
+/// Open a WebSocket through a SOCKS5 proxy. No local DNS lookup happens:
+/// the hostname goes to the proxy as-is, and TLS still verifies against it.
+async fn open_websocket_via_proxy(
+    url: &str,
+    tls_config: Arc<rustls::ClientConfig>,
+    proxy: &ProxyConfig,
+) -> Result<
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<TcpStream>>,
+    TransportError,
+> {
+    let (host, port) = parse_host_port(url)?;
+    log::info!("Connecting to {} through SOCKS5 proxy {}:{}", url, proxy.host, proxy.port);
+
+    let stream = match tokio::time::timeout(TCP_TIMEOUT, crate::socks::connect(proxy, &host, port)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) => {
+            return Err(TransportError::Other(format!(
+                "SOCKS5 connect to {} failed: {}",
+                url, e
+            )))
+        }
+        Err(_) => {
+            return Err(TransportError::Other(format!(
+                "SOCKS5 connect to {} timed out",
+                url
+            )))
+        }
+    };
+
+    let request = url
+        .into_client_request()
+        .map_err(|e| TransportError::Other(format!("Invalid WebSocket request: {}", e)))?;
+    match tokio::time::timeout(
+        TLS_WS_TIMEOUT,
+        tokio_tungstenite::client_async_tls_with_config(
+            request,
+            stream,
+            None::<WebSocketConfig>,
+            Some(Connector::Rustls(tls_config)),
+        ),
+    )
+    .await
+    {
+        Ok(Ok((ws_stream, _))) => Ok(ws_stream),
+        Ok(Err(e)) => Err(TransportError::Other(format!(
+            "TLS/WebSocket handshake to {} failed: {}",
+            url, e
+        ))),
+        Err(_) => Err(TransportError::Other(format!(
+            "TLS/WebSocket handshake to {} timed out",
+            url
+        ))),
+    }
+}
+
 #[async_trait::async_trait(?Send)]
 impl RelayTransport for NativeTransport {
     async fn connect(&self, url: &str) -> Result<(), TransportError> {
-        use tokio_tungstenite::tungstenite::Message;
-
-        let ws_stream = open_websocket(url, self.tls_config.clone()).await?;
+        let ws_stream = open_websocket(url, self.tls_config.clone(), self.proxy.as_ref()).await?;
 
         let (mut write, mut read) = ws_stream.split();
-        let (tx, mut rx) = mpsc::unbounded::<String>();
+        let (tx, mut rx) = mpsc::unbounded::<Message>();
         let (close_tx, mut close_rx) = tokio::sync::mpsc::unbounded_channel::<()>();
 
         let url_writer = url.to_string();
@@ -235,7 +327,7 @@ impl RelayTransport for NativeTransport {
         let status_cbs_writer = status_cbs.clone();
         tokio::task::spawn_local(async move {
             while let Some(msg) = rx.next().await {
-                if write.send(Message::Text(msg)).await.is_err() {
+                if write.send(msg).await.is_err() {
                     break;
                 }
             }
@@ -247,25 +339,66 @@ impl RelayTransport for NativeTransport {
             }
         });
 
-        // Reader task
+        // Reader task, which also runs the keepalive: half-open sockets
+        // (common on mobile networks) never error, they just go quiet.
         let status_cbs_reader = status_cbs.clone();
+        let connections_reader = self.connections.clone();
+        let ping_tx = tx.clone();
+        let keepalive_timeout = self.keepalive_timeout;
         tokio::task::spawn_local(async move {
+            let mut ticker = tokio::time::interval((keepalive_timeout / 4).max(Duration::from_secs(1)));
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut last_rx = tokio::time::Instant::now();
+            let mut ping_sent: Option<tokio::time::Instant> = None;
+            let mut dead = false;
             loop {
                 tokio::select! {
                     msg = read.next() => {
                         match msg {
-                            Some(Ok(Message::Text(text))) => {
-                                if let Some(cb) = msg_cbs.borrow().get(&url_reader) {
-                                    cb(text);
+                            Some(Ok(msg)) => {
+                                // Any inbound frame (pong included) proves liveness.
+                                last_rx = tokio::time::Instant::now();
+                                ping_sent = None;
+                                if let Message::Text(text) = msg {
+                                    if let Some(cb) = msg_cbs.borrow().get(&url_reader) {
+                                        cb(text);
+                                    }
                                 }
                             }
-                            Some(Ok(_)) => {}
                             Some(Err(_)) | None => break,
                         }
                     }
+                    _ = ticker.tick() => {
+                        let ping_age = ping_sent.map(|sent| sent.elapsed());
+                        match keepalive_step(last_rx.elapsed(), ping_age, keepalive_timeout) {
+                            Keepalive::Wait => {}
+                            Keepalive::Ping => {
+                                if ping_tx.unbounded_send(Message::Ping(Vec::new())).is_err() {
+                                    break;
+                                }
+                                ping_sent = Some(tokio::time::Instant::now());
+                            }
+                            Keepalive::Dead => {
+                                dead = true;
+                                break;
+                            }
+                        }
+                    }
                     _ = close_rx.recv() => break,
                 }
             }
+            if dead {
+                log::warn!("Relay {} missed its keepalive pong; dropping connection", url_reader);
+                // Dropping the handle ends the writer task; its Closed report
+                // is ignored once the connection has seen Failed.
+                connections_reader.borrow_mut().remove(&url_reader);
+                if let Some(cb) = status_cbs_reader.borrow().get(&url_reader) {
+                    cb(TransportStatus::Failed {
+                        url: url_reader.clone(),
+                    });
+                }
+                return;
+            }
             if let Some(cb) = status_cbs_reader.borrow().get(&url_reader) {
                 cb(TransportStatus::Closed {
                     url: url_reader.clone(),
@@ -304,7 +437,7 @@ impl RelayTransport for NativeTransport {
         if let Some(handle) = self.connections.borrow().get(url) {
             handle
                 .write
-                .unbounded_send(frame)
+                .unbounded_send(Message::Text(frame))
                 .map_err(|e| TransportError::Other(format!("Send failed: {}", e)))
         } else {
             Err(TransportError::Other(format!("Not connected to {}", url)))
@@ -323,3 +456,170 @@ impl RelayTransport for NativeTransport {
             .insert(url.to_string(), callback);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(60);
+
+    #[tokio::test]
+    async fn proxied_relays_connect_without_local_dns() {
+        let server = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = server.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            ws.send(Message::Text("[\"NOTICE\",\"hi\"]".to_string()))
+                .await
+                .unwrap();
+        });
+        let (mut proxy, target) = crate::socks::tests::socks_stand_in(server_addr).await;
+        proxy.scope = nipworker_core::proxy::ProxyScope::Onion;
+        let transport = NativeTransport::new();
+
+        // `.onion` names never resolve locally, so this only works via the proxy.
+        let url = "ws://relay.example.onion";
+        let mut ws = open_websocket(url, transport.tls_config.clone(), Some(&proxy))
+            .await
+            .unwrap();
+
+        let frame = ws.next().await.unwrap().unwrap();
+        assert_eq!(frame, Message::Text("[\"NOTICE\",\"hi\"]".to_string()));
+        assert_eq!(
+            target.await.unwrap(),
+            ("relay.example.onion".to_string(), 80)
+        );
+    }
+
+    #[tokio::test]
+    async fn relays_frames_and_reports_dropped_connections() {
+        let relay = nipworker_testrelay::TestRelay::start(Default::default())
+            .await
+            .unwrap();
+        relay
+            .publish(serde_json::json!({
+                "id": "a".repeat(64),
+                "pubkey": "1".repeat(64),
+                "kind": 1,
+                "created_at": 10,
+                "tags": [],
+                "content": "hello",
+                "sig": "00".repeat(64),
+            }))
+            .unwrap();
+        let url = relay.url();
+
+        tokio::task::LocalSet::new()
+            .run_until(async {
+                let transport = NativeTransport::new();
+                let (msg_tx, mut msg_rx) = mpsc::unbounded::<String>();
+                let (status_tx, mut status_rx) = mpsc::unbounded::<TransportStatus>();
+                transport.on_message(&url, Box::new(move |m| drop(msg_tx.unbounded_send(m))));
+                transport.on_status(&url, Box::new(move |s| drop(status_tx.unbounded_send(s))));
+
+                transport.connect(&url).await.unwrap();
+                assert!(matches!(
+                    status_rx.next().await,
+                    Some(TransportStatus::Connected { .. })
+                ));
+                transport
+                    .send(&url, r#"["REQ","s",{"kinds":[1]}]"#.to_string())
+                    .await
+                    .unwrap();
+                let event: serde_json::Value =
+                    serde_json::from_str(&msg_rx.next().await.unwrap()).unwrap();
+                assert_eq!(event[2]["content"], "hello");
+                assert_eq!(msg_rx.next().await.unwrap(), r#"["EOSE","s"]"#);
+
+                relay.drop_connections();
+                let status = tokio::time::timeout(Duration::from_secs(5), status_rx.next())
+                    .await
+                    .unwrap();
+                assert!(matches!(status, Some(TransportStatus::Closed { .. })));
+            })
+            .await;
+    }
+
+    /// A relay that completes the handshake and then either answers pings
+    /// (by reading, which makes tungstenite queue the pong) or goes silent.
+    async fn keepalive_relay(answers_pings: bool) -> String {
+        let server = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", server.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = server.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            if answers_pings {
+                while let Some(Ok(_)) = ws.next().await {}
+            } else {
+                std::future::pending::<()>().await;
+            }
+            drop(ws);
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn sockets_without_a_pong_are_dropped_after_the_deadline() {
+        let silent = keepalive_relay(false).await;
+        let responsive = keepalive_relay(true).await;
+
+        tokio::task::LocalSet::new()
+            .run_until(async {
+                let transport = NativeTransport::with_config(RelayConfig {
+                    keepalive_timeout: Duration::from_secs(2),
+                    ..Default::default()
+                });
+                let (status_tx, mut status_rx) = mpsc::unbounded::<TransportStatus>();
+                for url in [&silent, &responsive] {
+                    let status_tx = status_tx.clone();
+                    transport.on_status(url, Box::new(move |s| drop(status_tx.unbounded_send(s))));
+                    transport.connect(url).await.unwrap();
+                }
+
+                let started = tokio::time::Instant::now();
+                let mut statuses = Vec::new();
+                let failed = loop {
+                    let status = tokio::time::timeout(Duration::from_secs(5), status_rx.next())
+                        .await
+                        .expect("silent socket outlived its pong deadline")
+                        .unwrap();
+                    match status {
+                        TransportStatus::Failed { url } => break url,
+                        other => statuses.push(other),
+                    }
+                };
+                assert_eq!(failed, silent);
+                // One timeout to ping, half of it again for the pong (plus a tick).
+                assert!(started.elapsed() >= Duration::from_secs(1));
+                assert!(matches!(
+                    statuses.as_slice(),
+                    [TransportStatus::Connected { .. }, TransportStatus::Connected { .. }]
+                ));
+
+                // The relay that answered its ping keeps its socket.
+                tokio::time::sleep(Duration::from_secs(2)).await;
+                while let Ok(status) = status_rx.try_recv() {
+                    assert!(
+                        !matches!(&status, TransportStatus::Failed { url } | TransportStatus::Closed { url } if *url == responsive),
+                        "responsive relay was dropped: {:?}",
+                        status
+                    );
+                }
+                transport
+                    .send(&responsive, r#"["REQ","s",{}]"#.to_string())
+                    .await
+                    .unwrap();
+            })
+            .await;
+    }
+
+    #[test]
+    fn keepalive_pings_quiet_sockets_then_declares_them_dead() {
+        let secs = Duration::from_secs;
+        assert_eq!(keepalive_step(secs(10), None, TIMEOUT), Keepalive::Wait);
+        assert_eq!(keepalive_step(secs(30), None, TIMEOUT), Keepalive::Ping);
+        assert_eq!(keepalive_step(secs(40), Some(secs(10)), TIMEOUT), Keepalive::Wait);
+        assert_eq!(keepalive_step(secs(60), Some(secs(30)), TIMEOUT), Keepalive::Dead);
+    }
+}
@@ -0,0 +1,206 @@
+//! In-process NIP-01 relay for tests.
+//!
+//! `TestRelay` is a tokio WebSocket server on `127.0.0.1` backed by an
+//! in-memory `Store`. It speaks real relay semantics instead of scripted
+//! replies:
+//! - NIP-01: EVENT/REQ/CLOSE, stored results then EOSE, live fan-out, OK and
+//!   CLOSED with machine-readable prefixes, replaceable/addressable/ephemeral
+//!   kinds.
+//! - NIP-09: kind 5 deletions by `e` and `a` tag, author-checked.
+//! - NIP-42: with `RelayConfig::auth_required` every connection receives a
+//!   challenge and REQ/COUNT/EVENT are refused until a valid kind 22242 AUTH.
+//! - NIP-45: COUNT.
+//!
+//! `Faults` (latency, connection drops, rate limits, refused handshakes,
+//! delayed EOSE) can be changed while the relay runs, so transport and engine
+//! tests can exercise recovery paths against a real socket.
+//!
+//! Event signatures are not verified; the e2e suites sign with placeholder
+//! keys.
+
+mod session;
+pub mod store;
+
+use serde_json::Value;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use store::{Store, StoreOutcome, StoredEvent};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+/// Static relay behavior, fixed at start.
+#[derive(Debug, Clone, Default)]
+pub struct RelayConfig {
+    /// NIP-42: refuse reads and writes until the connection authenticated.
+    pub auth_required: bool,
+    /// Cap applied to every filter `limit` (and to filters without one).
+    pub max_limit: Option<usize>,
+}
+
+/// Fixed-window limit on frames a single connection may send.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max_frames: u32,
+    pub per_ms: u64,
+}
+
+/// Misbehavior injected into every connection; changeable at runtime.
+#[derive(Debug, Clone, Default)]
+pub struct Faults {
+    /// Delay before every frame the relay sends.
+    pub latency_ms: u64,
+    /// Delay between the stored results of a REQ and its EOSE.
+    pub eose_delay_ms: u64,
+    /// Drop the socket without a close frame after sending this many frames.
+    pub drop_after_frames: Option<usize>,
+    /// Answer frames over the limit with `rate-limited:` OK/CLOSED/NOTICE.
+    pub rate_limit: Option<RateLimit>,
+    /// Drop new TCP connections before the WebSocket handshake.
+    pub refuse_connections: bool,
+}
+
+/// State shared by the accept loop, every session and the test handle.
+pub(crate) struct Shared {
+    pub(crate) config: RelayConfig,
+    pub(crate) store: Mutex<Store>,
+    pub(crate) faults: Mutex<Faults>,
+    pub(crate) live: broadcast::Sender<StoredEvent>,
+    /// Bumped by `drop_connections`; sessions exit when it changes.
+    pub(crate) kick: tokio::sync::watch::Sender<u64>,
+    pub(crate) received: Mutex<Vec<String>>,
+    pub(crate) open_connections: AtomicUsize,
+    pub(crate) total_connections: AtomicUsize,
+    pub(crate) challenges: AtomicU64,
+}
+
+impl Shared {
+    pub(crate) fn faults(&self) -> Faults {
+        self.faults.lock().map(|f| f.clone()).unwrap_or_default()
+    }
+}
+
+pub struct TestRelay {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+    sessions: Arc<Mutex<Vec<JoinHandle<()>>>>,
+    accept_task: JoinHandle<()>,
+}
+
+impl TestRelay {
+    /// Bind an ephemeral port on 127.0.0.1 and start accepting connections.
+    /// Must be called from within a tokio runtime.
+    pub async fn start(config: RelayConfig) -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let (live, _) = broadcast::channel(1024);
+        let (kick, _) = tokio::sync::watch::channel(0);
+        let shared = Arc::new(Shared {
+            config,
+            store: Mutex::new(Store::default()),
+            faults: Mutex::new(Faults::default()),
+            live,
+            kick,
+            received: Mutex::new(Vec::new()),
+            open_connections: AtomicUsize::new(0),
+            total_connections: AtomicUsize::new(0),
+            challenges: AtomicU64::new(0),
+        });
+        let sessions: Arc<Mutex<Vec<JoinHandle<()>>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let accept_shared = shared.clone();
+        let accept_sessions = sessions.clone();
+        let accept_task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                if accept_shared.faults().refuse_connections {
+                    drop(stream);
+                    continue;
+                }
+                let handle = tokio::spawn(session::run(stream, accept_shared.clone()));
+                if let Ok(mut sessions) = accept_sessions.lock() {
+                    sessions.retain(|h| !h.is_finished());
+                    sessions.push(handle);
+                }
+            }
+        });
+
+        Ok(Self {
+            addr,
+            shared,
+            sessions,
+            accept_task,
+        })
+    }
+
+    /// `ws://127.0.0.1:<port>`
+    pub fn url(&self) -> String {
+        format!("ws://{}", self.addr)
+    }
+
+    pub fn set_faults(&self, faults: Faults) {
+        if let Ok(mut f) = self.shared.faults.lock() {
+            *f = faults;
+        }
+    }
+
+    /// Store an event as if another client published it; live subscriptions
+    /// receive it.
+    pub fn publish(&self, event: Value) -> Result<StoreOutcome, String> {
+        let event = StoredEvent::parse(&event)?;
+        let outcome = self
+            .shared
+            .store
+            .lock()
+            .map_err(|_| "store poisoned".to_string())?
+            .insert(event.clone());
+        if matches!(outcome, StoreOutcome::Stored | StoreOutcome::Ephemeral) {
+            let _ = self.shared.live.send(event);
+        }
+        Ok(outcome)
+    }
+
+    /// Stored events, in insertion order.
+    pub fn events(&self) -> Vec<Value> {
+        self.shared
+            .store
+            .lock()
+            .map(|s| s.events().into_iter().map(|e| e.json).collect())
+            .unwrap_or_default()
+    }
+
+    /// Every frame clients sent, in arrival order.
+    pub fn received(&self) -> Vec<String> {
+        self.shared
+            .received
+            .lock()
+            .map(|r| r.clone())
+            .unwrap_or_default()
+    }
+
+    /// Currently open WebSocket sessions.
+    pub fn open_connections(&self) -> usize {
+        self.shared.open_connections.load(Ordering::SeqCst)
+    }
+
+    /// WebSocket sessions accepted since start.
+    pub fn total_connections(&self) -> usize {
+        self.shared.total_connections.load(Ordering::SeqCst)
+    }
+
+    /// Abruptly close every open session (no close frame).
+    pub fn drop_connections(&self) {
+        self.shared.kick.send_modify(|generation| *generation += 1);
+    }
+}
+
+impl Drop for TestRelay {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+        if let Ok(sessions) = self.sessions.lock() {
+            for session in sessions.iter() {
+                session.abort();
+            }
+        }
+    }
+}
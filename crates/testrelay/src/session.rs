@@ -0,0 +1,303 @@
+//! One client WebSocket session.
+
+use crate::store::{filter_matches, StoreOutcome, StoredEvent};
+use crate::{Faults, Shared};
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio::sync::broadcast::error::RecvError;
+use tokio_tungstenite::tungstenite::Message;
+
+type Filters = Vec<Map<String, Value>>;
+
+/// Frames to send for one client frame; EOSE waits for `Faults::eose_delay_ms`.
+enum Outgoing {
+    Frame(String),
+    Eose(String),
+}
+
+struct Session {
+    shared: Arc<Shared>,
+    subscriptions: HashMap<String, Filters>,
+    challenge: Option<String>,
+    authed: Vec<String>,
+    window_start: Instant,
+    window_frames: u32,
+}
+
+impl Session {
+    fn authorized(&self) -> bool {
+        !self.shared.config.auth_required || !self.authed.is_empty()
+    }
+
+    /// Fixed-window rate limit over frames received from the client.
+    fn rate_limited(&mut self, faults: &Faults) -> bool {
+        let Some(limit) = faults.rate_limit else {
+            return false;
+        };
+        if self.window_start.elapsed() >= Duration::from_millis(limit.per_ms) {
+            self.window_start = Instant::now();
+            self.window_frames = 0;
+        }
+        self.window_frames += 1;
+        self.window_frames > limit.max_frames
+    }
+
+    fn parse_filters(&self, frame: &[Value]) -> Filters {
+        frame
+            .iter()
+            .skip(2)
+            .filter_map(Value::as_object)
+            .map(|filter| {
+                let mut filter = filter.clone();
+                if let Some(max) = self.shared.config.max_limit {
+                    let limit = filter
+                        .get("limit")
+                        .and_then(Value::as_u64)
+                        .map_or(max, |l| (l as usize).min(max));
+                    filter.insert("limit".to_string(), json!(limit));
+                }
+                filter
+            })
+            .collect()
+    }
+
+    fn handle(&mut self, text: &str, faults: &Faults) -> Vec<Outgoing> {
+        let frame = match serde_json::from_str::<Value>(text) {
+            Ok(Value::Array(frame)) => frame,
+            _ => return vec![notice("invalid: frame is not a JSON array")],
+        };
+        let verb = frame.first().and_then(Value::as_str).unwrap_or("");
+        let key = frame
+            .get(1)
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string();
+
+        if self.rate_limited(faults) {
+            let reason = "rate-limited: slow down";
+            return vec![match verb {
+                "EVENT" | "AUTH" => ok(event_id(&frame), false, reason),
+                "REQ" | "COUNT" => closed(&key, reason),
+                _ => notice(reason),
+            }];
+        }
+
+        match verb {
+            "EVENT" => vec![self.handle_event(&frame)],
+            "REQ" => self.handle_req(&key, &frame),
+            "CLOSE" => {
+                self.subscriptions.remove(&key);
+                Vec::new()
+            }
+            "COUNT" => {
+                if !self.authorized() {
+                    return vec![closed(&key, "auth-required: authenticate first")];
+                }
+                let filters = self.parse_filters(&frame);
+                let count = self
+                    .shared
+                    .store
+                    .lock()
+                    .map(|s| s.count(&filters))
+                    .unwrap_or(0);
+                vec![Outgoing::Frame(
+                    json!(["COUNT", key, { "count": count }]).to_string(),
+                )]
+            }
+            "AUTH" => vec![self.handle_auth(&frame)],
+            other => vec![notice(&format!("unsupported: {}", other))],
+        }
+    }
+
+    fn handle_event(&mut self, frame: &[Value]) -> Outgoing {
+        let id = event_id(frame);
+        let event = match frame.get(1).map(StoredEvent::parse) {
+            Some(Ok(event)) => event,
+            Some(Err(reason)) => return ok(id, false, &format!("invalid: {}", reason)),
+            None => return ok(id, false, "invalid: missing event"),
+        };
+        if !self.authorized() {
+            return ok(id, false, "auth-required: authenticate first");
+        }
+        let outcome = match self.shared.store.lock() {
+            Ok(mut store) => store.insert(event.clone()),
+            Err(_) => return ok(id, false, "error: store poisoned"),
+        };
+        match outcome {
+            StoreOutcome::Stored | StoreOutcome::Ephemeral => {
+                let _ = self.shared.live.send(event);
+                ok(id, true, "")
+            }
+            StoreOutcome::Duplicate => ok(id, true, "duplicate: already have this event"),
+            StoreOutcome::Superseded => ok(id, true, "duplicate: have a newer version"),
+            StoreOutcome::Deleted => ok(id, false, "blocked: event was deleted"),
+        }
+    }
+
+    fn handle_req(&mut self, sub_id: &str, frame: &[Value]) -> Vec<Outgoing> {
+        if sub_id.is_empty() {
+            return vec![notice("invalid: missing subscription id")];
+        }
+        if !self.authorized() {
+            return vec![closed(sub_id, "auth-required: authenticate first")];
+        }
+        let filters = self.parse_filters(frame);
+        let stored = self
+            .shared
+            .store
+            .lock()
+            .map(|s| s.query(&filters))
+            .unwrap_or_default();
+        self.subscriptions.insert(sub_id.to_string(), filters);
+        let mut out: Vec<Outgoing> = stored
+            .into_iter()
+            .map(|e| Outgoing::Frame(json!(["EVENT", sub_id, e.json]).to_string()))
+            .collect();
+        out.push(Outgoing::Eose(json!(["EOSE", sub_id]).to_string()));
+        out
+    }
+
+    /// NIP-42: a kind 22242 event carrying this connection's challenge.
+    fn handle_auth(&mut self, frame: &[Value]) -> Outgoing {
+        let id = event_id(frame);
+        let event = match frame.get(1).map(StoredEvent::parse) {
+            Some(Ok(event)) => event,
+            _ => return ok(id, false, "invalid: malformed auth event"),
+        };
+        let tag = |name: &str| {
+            event
+                .tags
+                .iter()
+                .find(|t| t.first().map(String::as_str) == Some(name))
+                .and_then(|t| t.get(1).cloned())
+        };
+        if event.kind != 22242 {
+            return ok(id, false, "invalid: auth event must be kind 22242");
+        }
+        if self.challenge.is_none() || tag("challenge") != self.challenge {
+            return ok(id, false, "invalid: challenge mismatch");
+        }
+        if tag("relay").is_none() {
+            return ok(id, false, "invalid: missing relay tag");
+        }
+        self.authed.push(event.pubkey);
+        ok(id, true, "")
+    }
+
+    /// Live fan-out of a newly stored event to matching subscriptions.
+    fn live_frames(&self, event: &StoredEvent) -> Vec<Outgoing> {
+        if !self.authorized() {
+            return Vec::new();
+        }
+        self.subscriptions
+            .iter()
+            .filter(|(_, filters)| filters.iter().any(|f| filter_matches(f, event)))
+            .map(|(sub_id, _)| Outgoing::Frame(json!(["EVENT", sub_id, event.json]).to_string()))
+            .collect()
+    }
+}
+
+fn event_id(frame: &[Value]) -> String {
+    frame
+        .get(1)
+        .and_then(|e| e.get("id"))
+        .and_then(Value::as_str)
+        .unwrap_or("")
+        .to_string()
+}
+
+fn ok(id: String, accepted: bool, message: &str) -> Outgoing {
+    Outgoing::Frame(json!(["OK", id, accepted, message]).to_string())
+}
+
+fn closed(sub_id: &str, message: &str) -> Outgoing {
+    Outgoing::Frame(json!(["CLOSED", sub_id, message]).to_string())
+}
+
+fn notice(message: &str) -> Outgoing {
+    Outgoing::Frame(json!(["NOTICE", message]).to_string())
+}
+
+pub(crate) async fn run(stream: TcpStream, shared: Arc<Shared>) {
+    let Ok(mut ws) = tokio_tungstenite::accept_async(stream).await else {
+        return;
+    };
+    shared.open_connections.fetch_add(1, Ordering::SeqCst);
+    shared.total_connections.fetch_add(1, Ordering::SeqCst);
+
+    let mut live = shared.live.subscribe();
+    let mut kick = shared.kick.subscribe();
+    let mut session = Session {
+        challenge: None,
+        subscriptions: HashMap::new(),
+        authed: Vec::new(),
+        window_start: Instant::now(),
+        window_frames: 0,
+        shared: shared.clone(),
+    };
+    let mut sent = 0usize;
+
+    let mut pending = Vec::new();
+    if shared.config.auth_required {
+        let n = shared.challenges.fetch_add(1, Ordering::SeqCst);
+        let challenge = format!("challenge-{}", n);
+        pending.push(Outgoing::Frame(json!(["AUTH", challenge]).to_string()));
+        session.challenge = Some(challenge);
+    }
+
+    'session: loop {
+        let faults = shared.faults();
+        for outgoing in pending.drain(..) {
+            let frame = match outgoing {
+                Outgoing::Frame(frame) => frame,
+                Outgoing::Eose(frame) => {
+                    if faults.eose_delay_ms > 0 {
+                        tokio::time::sleep(Duration::from_millis(faults.eose_delay_ms)).await;
+                    }
+                    frame
+                }
+            };
+            if faults.latency_ms > 0 {
+                tokio::time::sleep(Duration::from_millis(faults.latency_ms)).await;
+            }
+            if ws.send(Message::Text(frame)).await.is_err() {
+                break 'session;
+            }
+            sent += 1;
+            if faults.drop_after_frames.is_some_and(|n| sent >= n) {
+                break 'session;
+            }
+        }
+
+        tokio::select! {
+            message = ws.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    if let Ok(mut received) = shared.received.lock() {
+                        received.push(text.clone());
+                    }
+                    pending = session.handle(&text, &faults);
+                }
+                Some(Ok(Message::Ping(payload))) => {
+                    if ws.send(Message::Pong(payload)).await.is_err() {
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                Some(Ok(_)) => {}
+            },
+            event = live.recv() => match event {
+                Ok(event) => pending = session.live_frames(&event),
+                Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => break,
+            },
+            _ = kick.changed() => break,
+        }
+    }
+
+    shared.open_connections.fetch_sub(1, Ordering::SeqCst);
+}
@@ -0,0 +1,424 @@
+//! In-memory event store with NIP-01 filter semantics and NIP-09 deletions.
+
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+/// A validated event: the raw JSON object plus the fields filters look at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredEvent {
+    pub id: String,
+    pub pubkey: String,
+    pub kind: u64,
+    pub created_at: u64,
+    pub tags: Vec<Vec<String>>,
+    pub json: Value,
+}
+
+impl StoredEvent {
+    /// Validate the shape of an EVENT payload. Signatures are not checked:
+    /// the e2e suites sign with fixed placeholder keys.
+    pub fn parse(value: &Value) -> Result<Self, String> {
+        let obj = value.as_object().ok_or("event is not an object")?;
+        let hex64 = |field: &str| -> Result<String, String> {
+            let s = obj
+                .get(field)
+                .and_then(Value::as_str)
+                .ok_or(format!("missing {}", field))?;
+            if s.len() != 64 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(format!("bad {}", field));
+            }
+            Ok(s.to_ascii_lowercase())
+        };
+        let id = hex64("id")?;
+        let pubkey = hex64("pubkey")?;
+        let kind = obj
+            .get("kind")
+            .and_then(Value::as_u64)
+            .ok_or("missing kind")?;
+        let created_at = obj
+            .get("created_at")
+            .and_then(Value::as_u64)
+            .ok_or("missing created_at")?;
+        obj.get("content")
+            .and_then(Value::as_str)
+            .ok_or("missing content")?;
+        obj.get("sig")
+            .and_then(Value::as_str)
+            .ok_or("missing sig")?;
+        let tags = obj
+            .get("tags")
+            .and_then(Value::as_array)
+            .ok_or("missing tags")?
+            .iter()
+            .map(|tag| {
+                tag.as_array()
+                    .map(|items| {
+                        items
+                            .iter()
+                            .filter_map(|i| i.as_str().map(str::to_string))
+                            .collect()
+                    })
+                    .ok_or("tag is not an array".to_string())
+            })
+            .collect::<Result<Vec<Vec<String>>, String>>()?;
+        Ok(Self {
+            id,
+            pubkey,
+            kind,
+            created_at,
+            tags,
+            json: value.clone(),
+        })
+    }
+
+    fn tag_values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.tags
+            .iter()
+            .filter(move |t| t.first().map(String::as_str) == Some(name))
+            .filter_map(|t| t.get(1).map(String::as_str))
+    }
+
+    fn d_tag(&self) -> &str {
+        self.tag_values("d").next().unwrap_or("")
+    }
+
+    pub fn is_ephemeral(&self) -> bool {
+        (20000..30000).contains(&self.kind)
+    }
+
+    fn is_replaceable(&self) -> bool {
+        self.kind == 0 || self.kind == 3 || (10000..20000).contains(&self.kind)
+    }
+
+    fn is_addressable(&self) -> bool {
+        (30000..40000).contains(&self.kind)
+    }
+
+    fn coordinate(&self) -> String {
+        format!("{}:{}:{}", self.kind, self.pubkey, self.d_tag())
+    }
+
+    /// True when `self` replaces `other` under NIP-01 replaceable rules:
+    /// newer wins, equal timestamps keep the lowest id.
+    fn supersedes(&self, other: &StoredEvent) -> bool {
+        self.created_at > other.created_at
+            || (self.created_at == other.created_at && self.id < other.id)
+    }
+}
+
+/// Does `event` match a single NIP-01 filter object?
+pub fn filter_matches(filter: &Map<String, Value>, event: &StoredEvent) -> bool {
+    let strings = |key: &str| -> Option<Vec<&str>> {
+        filter
+            .get(key)
+            .and_then(Value::as_array)
+            .map(|v| v.iter().filter_map(Value::as_str).collect())
+    };
+    if let Some(ids) = strings("ids") {
+        if !ids.contains(&event.id.as_str()) {
+            return false;
+        }
+    }
+    if let Some(authors) = strings("authors") {
+        if !authors.contains(&event.pubkey.as_str()) {
+            return false;
+        }
+    }
+    if let Some(kinds) = filter.get("kinds").and_then(Value::as_array) {
+        if !kinds.iter().any(|k| k.as_u64() == Some(event.kind)) {
+            return false;
+        }
+    }
+    if let Some(since) = filter.get("since").and_then(Value::as_u64) {
+        if event.created_at < since {
+            return false;
+        }
+    }
+    if let Some(until) = filter.get("until").and_then(Value::as_u64) {
+        if event.created_at > until {
+            return false;
+        }
+    }
+    for (key, values) in filter {
+        let Some(tag) = key.strip_prefix('#') else {
+            continue;
+        };
+        let wanted: Vec<&str> = values
+            .as_array()
+            .map(|v| v.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        if !event.tag_values(tag).any(|v| wanted.contains(&v)) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Outcome of storing an event, mapped onto the OK message by the session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StoreOutcome {
+    Stored,
+    /// Ephemeral events are broadcast but never stored.
+    Ephemeral,
+    Duplicate,
+    /// A newer replaceable/addressable version is already stored.
+    Superseded,
+    /// A NIP-09 deletion already covers this event.
+    Deleted,
+}
+
+#[derive(Default)]
+pub struct Store {
+    events: Vec<StoredEvent>,
+    seen_ids: HashSet<String>,
+    /// Ids deleted by kind 5 events, with the deleting pubkey.
+    deleted_ids: HashSet<(String, String)>,
+    /// Coordinates deleted by kind 5 `a` tags, with the deletion timestamp.
+    deleted_coordinates: Vec<(String, u64)>,
+}
+
+impl Store {
+    pub fn insert(&mut self, event: StoredEvent) -> StoreOutcome {
+        if self
+            .deleted_ids
+            .contains(&(event.id.clone(), event.pubkey.clone()))
+        {
+            return StoreOutcome::Deleted;
+        }
+        if self.seen_ids.contains(&event.id) {
+            return StoreOutcome::Duplicate;
+        }
+        if (event.is_replaceable() || event.is_addressable())
+            && self
+                .deleted_coordinates
+                .iter()
+                .any(|(c, at)| *c == event.coordinate() && event.created_at <= *at)
+        {
+            return StoreOutcome::Deleted;
+        }
+        if event.is_ephemeral() {
+            return StoreOutcome::Ephemeral;
+        }
+        if event.is_replaceable() || event.is_addressable() {
+            let coordinate = event.coordinate();
+            if let Some(pos) = self.events.iter().position(|e| {
+                (e.is_replaceable() || e.is_addressable()) && e.coordinate() == coordinate
+            }) {
+                if !event.supersedes(&self.events[pos]) {
+                    return StoreOutcome::Superseded;
+                }
+                self.events.remove(pos);
+            }
+        }
+        if event.kind == 5 {
+            self.apply_deletion(&event);
+        }
+        self.seen_ids.insert(event.id.clone());
+        self.events.push(event);
+        StoreOutcome::Stored
+    }
+
+    /// NIP-09: remove referenced events authored by the deletion's author.
+    fn apply_deletion(&mut self, deletion: &StoredEvent) {
+        let ids: Vec<String> = deletion.tag_values("e").map(str::to_string).collect();
+        let coordinates: Vec<String> = deletion.tag_values("a").map(str::to_string).collect();
+        for id in ids {
+            self.deleted_ids.insert((id, deletion.pubkey.clone()));
+        }
+        for coordinate in coordinates {
+            // Only the author of the coordinate may delete it.
+            if coordinate.split(':').nth(1) == Some(deletion.pubkey.as_str()) {
+                self.deleted_coordinates
+                    .push((coordinate, deletion.created_at));
+            }
+        }
+        let deleted_ids = &self.deleted_ids;
+        let deleted_coordinates = &self.deleted_coordinates;
+        self.events.retain(|e| {
+            e.kind == 5
+                || !(deleted_ids.contains(&(e.id.clone(), e.pubkey.clone()))
+                    || deleted_coordinates
+                        .iter()
+                        .any(|(c, at)| *c == e.coordinate() && e.created_at <= *at))
+        });
+    }
+
+    /// Stored events matching any filter: each filter contributes its newest
+    /// `limit` events; the union is returned newest first, id ascending on ties.
+    pub fn query(&self, filters: &[Map<String, Value>]) -> Vec<StoredEvent> {
+        let mut out: Vec<StoredEvent> = Vec::new();
+        let mut taken = HashSet::new();
+        for filter in filters {
+            let mut matching: Vec<&StoredEvent> = self
+                .events
+                .iter()
+                .filter(|e| filter_matches(filter, e))
+                .collect();
+            sort_newest_first(&mut matching);
+            let limit = filter
+                .get("limit")
+                .and_then(Value::as_u64)
+                .map_or(usize::MAX, |l| l as usize);
+            for event in matching.into_iter().take(limit) {
+                if taken.insert(event.id.clone()) {
+                    out.push(event.clone());
+                }
+            }
+        }
+        let mut refs: Vec<&StoredEvent> = out.iter().collect();
+        sort_newest_first(&mut refs);
+        refs.into_iter().cloned().collect()
+    }
+
+    /// NIP-45: number of stored events matching any filter (limits ignored).
+    pub fn count(&self, filters: &[Map<String, Value>]) -> usize {
+        self.events
+            .iter()
+            .filter(|e| filters.iter().any(|f| filter_matches(f, e)))
+            .count()
+    }
+
+    pub fn events(&self) -> Vec<StoredEvent> {
+        self.events.clone()
+    }
+}
+
+fn sort_newest_first(events: &mut [&StoredEvent]) {
+    events.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(id: char, pubkey: char, kind: u64, created_at: u64, tags: Value) -> StoredEvent {
+        StoredEvent::parse(&json!({
+            "id": id.to_string().repeat(64),
+            "pubkey": pubkey.to_string().repeat(64),
+            "kind": kind,
+            "created_at": created_at,
+            "tags": tags,
+            "content": "",
+            "sig": "00".repeat(64),
+        }))
+        .unwrap()
+    }
+
+    fn filter(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn query_applies_filters_limit_and_order() {
+        let mut store = Store::default();
+        store.insert(event('a', '1', 1, 10, json!([["t", "nostr"]])));
+        store.insert(event('b', '1', 1, 30, json!([])));
+        store.insert(event('c', '2', 1, 20, json!([["t", "nostr"]])));
+        store.insert(event('d', '1', 7, 40, json!([])));
+
+        let ids = |events: Vec<StoredEvent>| -> Vec<char> {
+            events
+                .iter()
+                .map(|e| e.id.chars().next().unwrap())
+                .collect()
+        };
+        assert_eq!(
+            ids(store.query(&[filter(json!({"kinds": [1], "limit": 2}))])),
+            vec!['b', 'c']
+        );
+        assert_eq!(
+            ids(store.query(&[filter(json!({"#t": ["nostr"]}))])),
+            vec!['c', 'a']
+        );
+        assert_eq!(
+            ids(store.query(&[filter(json!({"authors": ["1".repeat(64)], "since": 20}))])),
+            vec!['d', 'b']
+        );
+        assert_eq!(
+            ids(store.query(&[
+                filter(json!({"kinds": [7]})),
+                filter(json!({"ids": ["a".repeat(64)]}))
+            ])),
+            vec!['d', 'a']
+        );
+        assert_eq!(store.count(&[filter(json!({"kinds": [1], "limit": 1}))]), 3);
+    }
+
+    #[test]
+    fn replaceable_events_keep_the_newest_version() {
+        let mut store = Store::default();
+        assert_eq!(
+            store.insert(event('a', '1', 0, 10, json!([]))),
+            StoreOutcome::Stored
+        );
+        assert_eq!(
+            store.insert(event('b', '1', 0, 5, json!([]))),
+            StoreOutcome::Superseded
+        );
+        assert_eq!(
+            store.insert(event('c', '1', 0, 20, json!([]))),
+            StoreOutcome::Stored
+        );
+        assert_eq!(
+            store.insert(event('c', '1', 0, 20, json!([]))),
+            StoreOutcome::Duplicate
+        );
+        assert_eq!(
+            store.insert(event('d', '1', 30023, 1, json!([["d", "x"]]))),
+            StoreOutcome::Stored
+        );
+        assert_eq!(
+            store.insert(event('e', '1', 30023, 1, json!([["d", "y"]]))),
+            StoreOutcome::Stored
+        );
+        assert_eq!(
+            store.insert(event('f', '1', 20001, 1, json!([]))),
+            StoreOutcome::Ephemeral
+        );
+        assert_eq!(store.events().len(), 3);
+    }
+
+    #[test]
+    fn deletions_only_apply_to_the_authors_events() {
+        let mut store = Store::default();
+        store.insert(event('a', '1', 1, 10, json!([])));
+        store.insert(event('b', '2', 1, 10, json!([])));
+        store.insert(event('c', '1', 30023, 10, json!([["d", "x"]])));
+        let coordinate = format!("30023:{}:x", "1".repeat(64));
+        store.insert(event(
+            'd',
+            '1',
+            5,
+            20,
+            json!([
+                ["e", "a".repeat(64)],
+                ["e", "b".repeat(64)],
+                ["a", coordinate]
+            ]),
+        ));
+        let kinds: Vec<(char, u64)> = store
+            .events()
+            .iter()
+            .map(|e| (e.id.chars().next().unwrap(), e.kind))
+            .collect();
+        assert_eq!(kinds, vec![('b', 1), ('d', 5)]);
+        // Re-publishing a deleted event or an older address version is refused.
+        assert_eq!(
+            store.insert(event('a', '1', 1, 10, json!([]))),
+            StoreOutcome::Deleted
+        );
+        assert_eq!(
+            store.insert(event('e', '1', 30023, 15, json!([["d", "x"]]))),
+            StoreOutcome::Deleted
+        );
+        assert_eq!(
+            store.insert(event('f', '1', 30023, 25, json!([["d", "x"]]))),
+            StoreOutcome::Stored
+        );
+    }
+}
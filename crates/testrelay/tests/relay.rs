@@ -0,0 +1,157 @@
+use futures::{SinkExt, StreamExt};
+use nipworker_testrelay::{Faults, RateLimit, RelayConfig, TestRelay};
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+fn event(id: char, kind: u64, created_at: u64, tags: Value) -> Value {
+    json!({
+        "id": id.to_string().repeat(64),
+        "pubkey": "1".repeat(64),
+        "kind": kind,
+        "created_at": created_at,
+        "tags": tags,
+        "content": "",
+        "sig": "00".repeat(64),
+    })
+}
+
+async fn connect(relay: &TestRelay) -> Client {
+    tokio_tungstenite::connect_async(relay.url())
+        .await
+        .unwrap()
+        .0
+}
+
+async fn send(client: &mut Client, frame: Value) {
+    client.send(Message::Text(frame.to_string())).await.unwrap();
+}
+
+/// Next text frame, or None if the socket closed or stayed silent for 2s.
+async fn recv(client: &mut Client) -> Option<Value> {
+    loop {
+        match tokio::time::timeout(Duration::from_secs(2), client.next()).await {
+            Ok(Some(Ok(Message::Text(text)))) => return serde_json::from_str(&text).ok(),
+            Ok(Some(Ok(_))) => continue,
+            _ => return None,
+        }
+    }
+}
+
+#[tokio::test]
+async fn req_returns_stored_then_eose_then_live() {
+    let relay = TestRelay::start(RelayConfig::default()).await.unwrap();
+    relay.publish(event('a', 1, 10, json!([]))).unwrap();
+    relay.publish(event('b', 1, 20, json!([]))).unwrap();
+    relay.publish(event('c', 7, 30, json!([]))).unwrap();
+
+    let mut client = connect(&relay).await;
+    send(&mut client, json!(["REQ", "s", {"kinds": [1]}])).await;
+    assert_eq!(recv(&mut client).await.unwrap()[2]["id"], "b".repeat(64));
+    assert_eq!(recv(&mut client).await.unwrap()[2]["id"], "a".repeat(64));
+    assert_eq!(recv(&mut client).await.unwrap(), json!(["EOSE", "s"]));
+
+    send(&mut client, json!(["EVENT", event('d', 1, 40, json!([]))])).await;
+    let mut frames = [
+        recv(&mut client).await.unwrap(),
+        recv(&mut client).await.unwrap(),
+    ];
+    frames.sort_by_key(|f| f[0].as_str().unwrap().to_string());
+    assert_eq!(frames[0][2]["id"], "d".repeat(64));
+    assert_eq!(frames[1], json!(["OK", "d".repeat(64), true, ""]));
+
+    send(&mut client, json!(["EVENT", event('d', 1, 40, json!([]))])).await;
+    let duplicate = recv(&mut client).await.unwrap();
+    assert!(duplicate[3].as_str().unwrap().starts_with("duplicate:"));
+
+    send(&mut client, json!(["COUNT", "c", {"kinds": [1]}])).await;
+    assert_eq!(
+        recv(&mut client).await.unwrap(),
+        json!(["COUNT", "c", {"count": 3}])
+    );
+}
+
+#[tokio::test]
+async fn deletion_removes_and_blocks_events() {
+    let relay = TestRelay::start(RelayConfig::default()).await.unwrap();
+    let mut client = connect(&relay).await;
+    send(&mut client, json!(["EVENT", event('a', 1, 10, json!([]))])).await;
+    assert_eq!(recv(&mut client).await.unwrap()[2], true);
+    let deletion = event('f', 5, 11, json!([["e", "a".repeat(64)]]));
+    send(&mut client, json!(["EVENT", deletion])).await;
+    assert_eq!(recv(&mut client).await.unwrap()[2], true);
+
+    send(&mut client, json!(["EVENT", event('a', 1, 10, json!([]))])).await;
+    let blocked = recv(&mut client).await.unwrap();
+    assert_eq!(blocked[2], false);
+    assert!(blocked[3].as_str().unwrap().starts_with("blocked:"));
+    assert!(relay.events().iter().all(|e| e["kind"] != 1));
+}
+
+#[tokio::test]
+async fn auth_gates_requests_until_challenge_answered() {
+    let relay = TestRelay::start(RelayConfig {
+        auth_required: true,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let mut client = connect(&relay).await;
+    let challenge = recv(&mut client).await.unwrap();
+    assert_eq!(challenge[0], "AUTH");
+
+    send(&mut client, json!(["REQ", "s", {}])).await;
+    let closed = recv(&mut client).await.unwrap();
+    assert_eq!(closed[0], "CLOSED");
+    assert!(closed[2].as_str().unwrap().starts_with("auth-required:"));
+
+    let auth = event(
+        'e',
+        22242,
+        10,
+        json!([["relay", relay.url()], ["challenge", challenge[1]]]),
+    );
+    send(&mut client, json!(["AUTH", auth])).await;
+    assert_eq!(recv(&mut client).await.unwrap()[2], true);
+
+    send(&mut client, json!(["REQ", "s", {}])).await;
+    assert_eq!(recv(&mut client).await.unwrap(), json!(["EOSE", "s"]));
+}
+
+#[tokio::test]
+async fn faults_rate_limit_and_drop_connections() {
+    let relay = TestRelay::start(RelayConfig::default()).await.unwrap();
+    relay.set_faults(Faults {
+        rate_limit: Some(RateLimit {
+            max_frames: 1,
+            per_ms: 60_000,
+        }),
+        ..Default::default()
+    });
+    let mut client = connect(&relay).await;
+    send(&mut client, json!(["REQ", "a", {}])).await;
+    assert_eq!(recv(&mut client).await.unwrap(), json!(["EOSE", "a"]));
+    send(&mut client, json!(["REQ", "b", {}])).await;
+    let closed = recv(&mut client).await.unwrap();
+    assert_eq!(closed[0], "CLOSED");
+    assert!(closed[2].as_str().unwrap().starts_with("rate-limited:"));
+
+    relay.set_faults(Faults {
+        drop_after_frames: Some(1),
+        ..Default::default()
+    });
+    let mut dropped = connect(&relay).await;
+    send(&mut dropped, json!(["REQ", "a", {}])).await;
+    assert_eq!(recv(&mut dropped).await.unwrap(), json!(["EOSE", "a"]));
+    assert!(recv(&mut dropped).await.is_none());
+
+    relay.set_faults(Faults::default());
+    relay.drop_connections();
+    assert!(recv(&mut client).await.is_none());
+    assert_eq!(relay.open_connections(), 0);
+    assert_eq!(relay.total_connections(), 2);
+}
@@ -755,7 +755,14 @@ export class NostrManager extends BaseBackend {
 			options.bytesPerEvent,
 			options.isSlow,
 			options.pagination ? this.textEncoder.encode(options.pagination) : null,
-			options.cacheOnly
+			options.cacheOnly,
+			options.mergedWindow,
+			options.linkPreviews,
+			options.thread ? this.textEncoder.encode(options.thread) : null,
+			options.resolveMentions,
+			BigInt(options.trafficBudget?.maxBytes ?? 0),
+			BigInt(options.trafficBudget?.maxEvents ?? 0),
+			options.verifyNip05 ?? false
 		);
 
 		const subscribeT = new SubscribeT(
@@ -778,7 +785,9 @@ export class NostrManager extends BaseBackend {
 						r.noCache,
 						r.maxRelays,
 						options.cacheOnly,
-						r.meshOnly
+						r.meshOnly,
+						r.negentropy,
+						r.background
 					)
 			),
 			optionsT
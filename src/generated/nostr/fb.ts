@@ -23,6 +23,7 @@ export { ContentData } from './fb/content-data.js';
 export { Coordinate, CoordinateT } from './fb/coordinate.js';
 export { CountResponse, CountResponseT } from './fb/count-response.js';
 export { CounterPipeConfig, CounterPipeConfigT } from './fb/counter-pipe-config.js';
+export { CustomParsed, CustomParsedT } from './fb/custom-parsed.js';
 export { DLEQProof, DLEQProofT } from './fb/dleqproof.js';
 export { Emoji, EmojiT } from './fb/emoji.js';
 export { EmojiData, EmojiDataT } from './fb/emoji-data.js';
@@ -41,6 +42,7 @@ export { Kind1018Parsed, Kind1018ParsedT } from './fb/kind1018-parsed.js';
 export { Kind1068Parsed, Kind1068ParsedT } from './fb/kind1068-parsed.js';
 export { Kind1111Parsed, Kind1111ParsedT } from './fb/kind1111-parsed.js';
 export { Kind1311Parsed, Kind1311ParsedT } from './fb/kind1311-parsed.js';
+export { Kind14Parsed, Kind14ParsedT } from './fb/kind14-parsed.js';
 export { Kind17375Parsed, Kind17375ParsedT } from './fb/kind17375-parsed.js';
 export { Kind17Parsed, Kind17ParsedT } from './fb/kind17-parsed.js';
 export { Kind1Parsed, Kind1ParsedT } from './fb/kind1-parsed.js';
@@ -68,6 +70,7 @@ export { MainContent } from './fb/main-content.js';
 export { MainMessage, MainMessageT } from './fb/main-message.js';
 export { MediaGroupData, MediaGroupDataT } from './fb/media-group-data.js';
 export { MediaItem, MediaItemT } from './fb/media-item.js';
+export { MentionData, MentionDataT } from './fb/mention-data.js';
 export { Message } from './fb/message.js';
 export { MessageType } from './fb/message-type.js';
 export { MintInfo, MintInfoT } from './fb/mint-info.js';
@@ -120,6 +123,8 @@ export { SubscribeKind } from './fb/subscribe-kind.js';
 export { SubscriptionConfig, SubscriptionConfigT } from './fb/subscription-config.js';
 export { Tag, TagT } from './fb/tag.js';
 export { Template, TemplateT } from './fb/template.js';
+export { ThreadNode, ThreadNodeT } from './fb/thread-node.js';
+export { ThreadTree, ThreadTreeT } from './fb/thread-tree.js';
 export { Unsubscribe, UnsubscribeT } from './fb/unsubscribe.js';
 export { ValidProofs, ValidProofsT } from './fb/valid-proofs.js';
 export { VideoData, VideoDataT } from './fb/video-data.js';
@@ -128,3 +133,4 @@ export { Witness } from './fb/witness.js';
 export { WitnessString, WitnessStringT } from './fb/witness-string.js';
 export { WorkerMessage, WorkerMessageT } from './fb/worker-message.js';
 export { ZapRequest, ZapRequestT } from './fb/zap-request.js';
+export { ZapValidationPipeConfig, ZapValidationPipeConfigT } from './fb/zap-validation-pipe-config.js';
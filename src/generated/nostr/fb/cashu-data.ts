@@ -4,6 +4,7 @@
 
 import * as flatbuffers from 'flatbuffers';
 
+import { Proof, ProofT } from '../../nostr/fb/proof.js';
 
 
 export class CashuData implements flatbuffers.IUnpackableObject<CashuDataT> {
@@ -31,49 +32,146 @@ token(optionalEncoding?:any):string|Uint8Array|null {
   return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
 }
 
+mint():string|null
+mint(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+mint(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+unit():string|null
+unit(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+unit(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+memo():string|null
+memo(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+memo(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 10);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+amount():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 12);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+proofs(index: number, obj?:Proof):Proof|null {
+  const offset = this.bb!.__offset(this.bb_pos, 14);
+  return offset ? (obj || new Proof()).__init(this.bb!.__indirect(this.bb!.__vector(this.bb_pos + offset) + index * 4), this.bb!) : null;
+}
+
+proofsLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 14);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
 static startCashuData(builder:flatbuffers.Builder) {
-  builder.startObject(1);
+  builder.startObject(6);
 }
 
 static addToken(builder:flatbuffers.Builder, tokenOffset:flatbuffers.Offset) {
   builder.addFieldOffset(0, tokenOffset, 0);
 }
 
+static addMint(builder:flatbuffers.Builder, mintOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, mintOffset, 0);
+}
+
+static addUnit(builder:flatbuffers.Builder, unitOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(2, unitOffset, 0);
+}
+
+static addMemo(builder:flatbuffers.Builder, memoOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(3, memoOffset, 0);
+}
+
+static addAmount(builder:flatbuffers.Builder, amount:bigint) {
+  builder.addFieldInt64(4, amount, BigInt('0'));
+}
+
+static addProofs(builder:flatbuffers.Builder, proofsOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(5, proofsOffset, 0);
+}
+
+static createProofsVector(builder:flatbuffers.Builder, data:flatbuffers.Offset[]):flatbuffers.Offset {
+  builder.startVector(4, data.length, 4);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addOffset(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startProofsVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(4, numElems, 4);
+}
+
 static endCashuData(builder:flatbuffers.Builder):flatbuffers.Offset {
   const offset = builder.endObject();
   builder.requiredField(offset, 4) // token
   return offset;
 }
 
-static createCashuData(builder:flatbuffers.Builder, tokenOffset:flatbuffers.Offset):flatbuffers.Offset {
+static createCashuData(builder:flatbuffers.Builder, tokenOffset:flatbuffers.Offset, mintOffset:flatbuffers.Offset, unitOffset:flatbuffers.Offset, memoOffset:flatbuffers.Offset, amount:bigint, proofsOffset:flatbuffers.Offset):flatbuffers.Offset {
   CashuData.startCashuData(builder);
   CashuData.addToken(builder, tokenOffset);
+  CashuData.addMint(builder, mintOffset);
+  CashuData.addUnit(builder, unitOffset);
+  CashuData.addMemo(builder, memoOffset);
+  CashuData.addAmount(builder, amount);
+  CashuData.addProofs(builder, proofsOffset);
   return CashuData.endCashuData(builder);
 }
 
 unpack(): CashuDataT {
   return new CashuDataT(
-    this.token()
+    this.token(),
+    this.mint(),
+    this.unit(),
+    this.memo(),
+    this.amount(),
+    this.bb!.createObjList<Proof, ProofT>(this.proofs.bind(this), this.proofsLength())
   );
 }
 
 
 unpackTo(_o: CashuDataT): void {
   _o.token = this.token();
+  _o.mint = this.mint();
+  _o.unit = this.unit();
+  _o.memo = this.memo();
+  _o.amount = this.amount();
+  _o.proofs = this.bb!.createObjList<Proof, ProofT>(this.proofs.bind(this), this.proofsLength());
 }
 }
 
 export class CashuDataT implements flatbuffers.IGeneratedObject {
 constructor(
-  public token: string|Uint8Array|null = null
+  public token: string|Uint8Array|null = null,
+  public mint: string|Uint8Array|null = null,
+  public unit: string|Uint8Array|null = null,
+  public memo: string|Uint8Array|null = null,
+  public amount: bigint = BigInt('0'),
+  public proofs: (ProofT)[] = []
 ){}
 
 
 pack(builder:flatbuffers.Builder): flatbuffers.Offset {
   const token = (this.token !== null ? builder.createString(this.token!) : 0);
+  const mint = (this.mint !== null ? builder.createString(this.mint!) : 0);
+  const unit = (this.unit !== null ? builder.createString(this.unit!) : 0);
+  const memo = (this.memo !== null ? builder.createString(this.memo!) : 0);
+  const proofs = CashuData.createProofsVector(builder, builder.createObjectOffsetList(this.proofs));
 
   return CashuData.createCashuData(builder,
-    token
+    token,
+    mint,
+    unit,
+    memo,
+    this.amount,
+    proofs
   );
 }
 }
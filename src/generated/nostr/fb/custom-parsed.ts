@@ -0,0 +1,116 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+/* eslint-disable @typescript-eslint/no-unused-vars, @typescript-eslint/no-explicit-any, @typescript-eslint/no-non-null-assertion */
+
+import * as flatbuffers from 'flatbuffers';
+
+
+
+export class CustomParsed implements flatbuffers.IUnpackableObject<CustomParsedT> {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):CustomParsed {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsCustomParsed(bb:flatbuffers.ByteBuffer, obj?:CustomParsed):CustomParsed {
+  return (obj || new CustomParsed()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsCustomParsed(bb:flatbuffers.ByteBuffer, obj?:CustomParsed):CustomParsed {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new CustomParsed()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+schemaId():string|null
+schemaId(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+schemaId(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+payload(index: number):number|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.readUint8(this.bb!.__vector(this.bb_pos + offset) + index) : 0;
+}
+
+payloadLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+payloadArray():Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? new Uint8Array(this.bb!.bytes().buffer, this.bb!.bytes().byteOffset + this.bb!.__vector(this.bb_pos + offset), this.bb!.__vector_len(this.bb_pos + offset)) : null;
+}
+
+static startCustomParsed(builder:flatbuffers.Builder) {
+  builder.startObject(2);
+}
+
+static addSchemaId(builder:flatbuffers.Builder, schemaIdOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(0, schemaIdOffset, 0);
+}
+
+static addPayload(builder:flatbuffers.Builder, payloadOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, payloadOffset, 0);
+}
+
+static createPayloadVector(builder:flatbuffers.Builder, data:number[]|Uint8Array):flatbuffers.Offset {
+  builder.startVector(1, data.length, 1);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addInt8(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startPayloadVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(1, numElems, 1);
+}
+
+static endCustomParsed(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  builder.requiredField(offset, 4) // schema_id
+  return offset;
+}
+
+static createCustomParsed(builder:flatbuffers.Builder, schemaIdOffset:flatbuffers.Offset, payloadOffset:flatbuffers.Offset):flatbuffers.Offset {
+  CustomParsed.startCustomParsed(builder);
+  CustomParsed.addSchemaId(builder, schemaIdOffset);
+  CustomParsed.addPayload(builder, payloadOffset);
+  return CustomParsed.endCustomParsed(builder);
+}
+
+unpack(): CustomParsedT {
+  return new CustomParsedT(
+    this.schemaId(),
+    this.bb!.createScalarList<number>(this.payload.bind(this), this.payloadLength())
+  );
+}
+
+
+unpackTo(_o: CustomParsedT): void {
+  _o.schemaId = this.schemaId();
+  _o.payload = this.bb!.createScalarList<number>(this.payload.bind(this), this.payloadLength());
+}
+}
+
+export class CustomParsedT implements flatbuffers.IGeneratedObject {
+constructor(
+  public schemaId: string|Uint8Array|null = null,
+  public payload: (number)[] = []
+){}
+
+
+pack(builder:flatbuffers.Builder): flatbuffers.Offset {
+  const schemaId = (this.schemaId !== null ? builder.createString(this.schemaId!) : 0);
+  const payload = CustomParsed.createPayloadVector(builder, this.payload);
+
+  return CustomParsed.createCustomParsed(builder,
+    schemaId,
+    payload
+  );
+}
+}
@@ -164,8 +164,13 @@ background(optionalEncoding?:any):string|Uint8Array|null {
   return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
 }
 
+nip05Verified():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 44);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
 static startKind0Parsed(builder:flatbuffers.Builder) {
-  builder.startObject(20);
+  builder.startObject(21);
 }
 
 static addPubkey(builder:flatbuffers.Builder, pubkeyOffset:flatbuffers.Offset) {
@@ -248,12 +253,16 @@ static addBackground(builder:flatbuffers.Builder, backgroundOffset:flatbuffers.O
   builder.addFieldOffset(19, backgroundOffset, 0);
 }
 
+static addNip05Verified(builder:flatbuffers.Builder, nip05Verified:boolean) {
+  builder.addFieldInt8(20, +nip05Verified, +false);
+}
+
 static endKind0Parsed(builder:flatbuffers.Builder):flatbuffers.Offset {
   const offset = builder.endObject();
   return offset;
 }
 
-static createKind0Parsed(builder:flatbuffers.Builder, pubkeyOffset:flatbuffers.Offset, nameOffset:flatbuffers.Offset, displayNameOffset:flatbuffers.Offset, pictureOffset:flatbuffers.Offset, bannerOffset:flatbuffers.Offset, aboutOffset:flatbuffers.Offset, websiteOffset:flatbuffers.Offset, nip05Offset:flatbuffers.Offset, lud06Offset:flatbuffers.Offset, lud16Offset:flatbuffers.Offset, githubOffset:flatbuffers.Offset, twitterOffset:flatbuffers.Offset, mastodonOffset:flatbuffers.Offset, nostrOffset:flatbuffers.Offset, displayNameAltOffset:flatbuffers.Offset, usernameOffset:flatbuffers.Offset, bioOffset:flatbuffers.Offset, imageOffset:flatbuffers.Offset, avatarOffset:flatbuffers.Offset, backgroundOffset:flatbuffers.Offset):flatbuffers.Offset {
+static createKind0Parsed(builder:flatbuffers.Builder, pubkeyOffset:flatbuffers.Offset, nameOffset:flatbuffers.Offset, displayNameOffset:flatbuffers.Offset, pictureOffset:flatbuffers.Offset, bannerOffset:flatbuffers.Offset, aboutOffset:flatbuffers.Offset, websiteOffset:flatbuffers.Offset, nip05Offset:flatbuffers.Offset, lud06Offset:flatbuffers.Offset, lud16Offset:flatbuffers.Offset, githubOffset:flatbuffers.Offset, twitterOffset:flatbuffers.Offset, mastodonOffset:flatbuffers.Offset, nostrOffset:flatbuffers.Offset, displayNameAltOffset:flatbuffers.Offset, usernameOffset:flatbuffers.Offset, bioOffset:flatbuffers.Offset, imageOffset:flatbuffers.Offset, avatarOffset:flatbuffers.Offset, backgroundOffset:flatbuffers.Offset, nip05Verified:boolean):flatbuffers.Offset {
   Kind0Parsed.startKind0Parsed(builder);
   Kind0Parsed.addPubkey(builder, pubkeyOffset);
   Kind0Parsed.addName(builder, nameOffset);
@@ -275,6 +284,7 @@ static createKind0Parsed(builder:flatbuffers.Builder, pubkeyOffset:flatbuffers.O
   Kind0Parsed.addImage(builder, imageOffset);
   Kind0Parsed.addAvatar(builder, avatarOffset);
   Kind0Parsed.addBackground(builder, backgroundOffset);
+  Kind0Parsed.addNip05Verified(builder, nip05Verified);
   return Kind0Parsed.endKind0Parsed(builder);
 }
 
@@ -299,7 +309,8 @@ unpack(): Kind0ParsedT {
     this.bio(),
     this.image(),
     this.avatar(),
-    this.background()
+    this.background(),
+    this.nip05Verified()
   );
 }
 
@@ -325,6 +336,7 @@ unpackTo(_o: Kind0ParsedT): void {
   _o.image = this.image();
   _o.avatar = this.avatar();
   _o.background = this.background();
+  _o.nip05Verified = this.nip05Verified();
 }
 }
 
@@ -349,7 +361,8 @@ constructor(
   public bio: string|Uint8Array|null = null,
   public image: string|Uint8Array|null = null,
   public avatar: string|Uint8Array|null = null,
-  public background: string|Uint8Array|null = null
+  public background: string|Uint8Array|null = null,
+  public nip05Verified: boolean = false
 ){}
 
 
@@ -395,7 +408,8 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
     bio,
     image,
     avatar,
-    background
+    background,
+    this.nip05Verified
   );
 }
 }
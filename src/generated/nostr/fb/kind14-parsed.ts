@@ -0,0 +1,295 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+/* eslint-disable @typescript-eslint/no-unused-vars, @typescript-eslint/no-explicit-any, @typescript-eslint/no-non-null-assertion */
+
+import * as flatbuffers from 'flatbuffers';
+
+import { ContentBlock, ContentBlockT } from '../../nostr/fb/content-block.js';
+import { StringVec, StringVecT } from '../../nostr/fb/string-vec.js';
+
+
+export class Kind14Parsed implements flatbuffers.IUnpackableObject<Kind14ParsedT> {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):Kind14Parsed {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsKind14Parsed(bb:flatbuffers.ByteBuffer, obj?:Kind14Parsed):Kind14Parsed {
+  return (obj || new Kind14Parsed()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsKind14Parsed(bb:flatbuffers.ByteBuffer, obj?:Kind14Parsed):Kind14Parsed {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new Kind14Parsed()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+kind():number {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readUint16(this.bb_pos + offset) : 0;
+}
+
+rumorId():string|null
+rumorId(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+rumorId(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+sender():string|null
+sender(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+sender(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+createdAt():number {
+  const offset = this.bb!.__offset(this.bb_pos, 10);
+  return offset ? this.bb!.readUint32(this.bb_pos + offset) : 0;
+}
+
+participants(index: number):string
+participants(index: number,optionalEncoding:flatbuffers.Encoding):string|Uint8Array
+participants(index: number,optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 12);
+  return offset ? this.bb!.__string(this.bb!.__vector(this.bb_pos + offset) + index * 4, optionalEncoding) : null;
+}
+
+participantsLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 12);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+chatId():string|null
+chatId(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+chatId(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 14);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+content():string|null
+content(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+content(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 16);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+parsedContent(index: number, obj?:ContentBlock):ContentBlock|null {
+  const offset = this.bb!.__offset(this.bb_pos, 18);
+  return offset ? (obj || new ContentBlock()).__init(this.bb!.__indirect(this.bb!.__vector(this.bb_pos + offset) + index * 4), this.bb!) : null;
+}
+
+parsedContentLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 18);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+subject():string|null
+subject(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+subject(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 20);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+replyTo():string|null
+replyTo(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+replyTo(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 22);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+tags(index: number, obj?:StringVec):StringVec|null {
+  const offset = this.bb!.__offset(this.bb_pos, 24);
+  return offset ? (obj || new StringVec()).__init(this.bb!.__indirect(this.bb!.__vector(this.bb_pos + offset) + index * 4), this.bb!) : null;
+}
+
+tagsLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 24);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+static startKind14Parsed(builder:flatbuffers.Builder) {
+  builder.startObject(11);
+}
+
+static addKind(builder:flatbuffers.Builder, kind:number) {
+  builder.addFieldInt16(0, kind, 0);
+}
+
+static addRumorId(builder:flatbuffers.Builder, rumorIdOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, rumorIdOffset, 0);
+}
+
+static addSender(builder:flatbuffers.Builder, senderOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(2, senderOffset, 0);
+}
+
+static addCreatedAt(builder:flatbuffers.Builder, createdAt:number) {
+  builder.addFieldInt32(3, createdAt, 0);
+}
+
+static addParticipants(builder:flatbuffers.Builder, participantsOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(4, participantsOffset, 0);
+}
+
+static createParticipantsVector(builder:flatbuffers.Builder, data:flatbuffers.Offset[]):flatbuffers.Offset {
+  builder.startVector(4, data.length, 4);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addOffset(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startParticipantsVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(4, numElems, 4);
+}
+
+static addChatId(builder:flatbuffers.Builder, chatIdOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(5, chatIdOffset, 0);
+}
+
+static addContent(builder:flatbuffers.Builder, contentOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(6, contentOffset, 0);
+}
+
+static addParsedContent(builder:flatbuffers.Builder, parsedContentOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(7, parsedContentOffset, 0);
+}
+
+static createParsedContentVector(builder:flatbuffers.Builder, data:flatbuffers.Offset[]):flatbuffers.Offset {
+  builder.startVector(4, data.length, 4);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addOffset(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startParsedContentVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(4, numElems, 4);
+}
+
+static addSubject(builder:flatbuffers.Builder, subjectOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(8, subjectOffset, 0);
+}
+
+static addReplyTo(builder:flatbuffers.Builder, replyToOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(9, replyToOffset, 0);
+}
+
+static addTags(builder:flatbuffers.Builder, tagsOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(10, tagsOffset, 0);
+}
+
+static createTagsVector(builder:flatbuffers.Builder, data:flatbuffers.Offset[]):flatbuffers.Offset {
+  builder.startVector(4, data.length, 4);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addOffset(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startTagsVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(4, numElems, 4);
+}
+
+static endKind14Parsed(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  builder.requiredField(offset, 6) // rumor_id
+  builder.requiredField(offset, 8) // sender
+  builder.requiredField(offset, 12) // participants
+  builder.requiredField(offset, 14) // chat_id
+  return offset;
+}
+
+static createKind14Parsed(builder:flatbuffers.Builder, kind:number, rumorIdOffset:flatbuffers.Offset, senderOffset:flatbuffers.Offset, createdAt:number, participantsOffset:flatbuffers.Offset, chatIdOffset:flatbuffers.Offset, contentOffset:flatbuffers.Offset, parsedContentOffset:flatbuffers.Offset, subjectOffset:flatbuffers.Offset, replyToOffset:flatbuffers.Offset, tagsOffset:flatbuffers.Offset):flatbuffers.Offset {
+  Kind14Parsed.startKind14Parsed(builder);
+  Kind14Parsed.addKind(builder, kind);
+  Kind14Parsed.addRumorId(builder, rumorIdOffset);
+  Kind14Parsed.addSender(builder, senderOffset);
+  Kind14Parsed.addCreatedAt(builder, createdAt);
+  Kind14Parsed.addParticipants(builder, participantsOffset);
+  Kind14Parsed.addChatId(builder, chatIdOffset);
+  Kind14Parsed.addContent(builder, contentOffset);
+  Kind14Parsed.addParsedContent(builder, parsedContentOffset);
+  Kind14Parsed.addSubject(builder, subjectOffset);
+  Kind14Parsed.addReplyTo(builder, replyToOffset);
+  Kind14Parsed.addTags(builder, tagsOffset);
+  return Kind14Parsed.endKind14Parsed(builder);
+}
+
+unpack(): Kind14ParsedT {
+  return new Kind14ParsedT(
+    this.kind(),
+    this.rumorId(),
+    this.sender(),
+    this.createdAt(),
+    this.bb!.createScalarList<string>(this.participants.bind(this), this.participantsLength()),
+    this.chatId(),
+    this.content(),
+    this.bb!.createObjList<ContentBlock, ContentBlockT>(this.parsedContent.bind(this), this.parsedContentLength()),
+    this.subject(),
+    this.replyTo(),
+    this.bb!.createObjList<StringVec, StringVecT>(this.tags.bind(this), this.tagsLength())
+  );
+}
+
+
+unpackTo(_o: Kind14ParsedT): void {
+  _o.kind = this.kind();
+  _o.rumorId = this.rumorId();
+  _o.sender = this.sender();
+  _o.createdAt = this.createdAt();
+  _o.participants = this.bb!.createScalarList<string>(this.participants.bind(this), this.participantsLength());
+  _o.chatId = this.chatId();
+  _o.content = this.content();
+  _o.parsedContent = this.bb!.createObjList<ContentBlock, ContentBlockT>(this.parsedContent.bind(this), this.parsedContentLength());
+  _o.subject = this.subject();
+  _o.replyTo = this.replyTo();
+  _o.tags = this.bb!.createObjList<StringVec, StringVecT>(this.tags.bind(this), this.tagsLength());
+}
+}
+
+export class Kind14ParsedT implements flatbuffers.IGeneratedObject {
+constructor(
+  public kind: number = 0,
+  public rumorId: string|Uint8Array|null = null,
+  public sender: string|Uint8Array|null = null,
+  public createdAt: number = 0,
+  public participants: (string)[] = [],
+  public chatId: string|Uint8Array|null = null,
+  public content: string|Uint8Array|null = null,
+  public parsedContent: (ContentBlockT)[] = [],
+  public subject: string|Uint8Array|null = null,
+  public replyTo: string|Uint8Array|null = null,
+  public tags: (StringVecT)[] = []
+){}
+
+
+pack(builder:flatbuffers.Builder): flatbuffers.Offset {
+  const rumorId = (this.rumorId !== null ? builder.createString(this.rumorId!) : 0);
+  const sender = (this.sender !== null ? builder.createString(this.sender!) : 0);
+  const participants = Kind14Parsed.createParticipantsVector(builder, builder.createObjectOffsetList(this.participants));
+  const chatId = (this.chatId !== null ? builder.createString(this.chatId!) : 0);
+  const content = (this.content !== null ? builder.createString(this.content!) : 0);
+  const parsedContent = Kind14Parsed.createParsedContentVector(builder, builder.createObjectOffsetList(this.parsedContent));
+  const subject = (this.subject !== null ? builder.createString(this.subject!) : 0);
+  const replyTo = (this.replyTo !== null ? builder.createString(this.replyTo!) : 0);
+  const tags = Kind14Parsed.createTagsVector(builder, builder.createObjectOffsetList(this.tags));
+
+  return Kind14Parsed.createKind14Parsed(builder,
+    this.kind,
+    rumorId,
+    sender,
+    this.createdAt,
+    participants,
+    chatId,
+    content,
+    parsedContent,
+    subject,
+    replyTo,
+    tags
+  );
+}
+}
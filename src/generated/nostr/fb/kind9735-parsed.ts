@@ -101,8 +101,20 @@ description(obj?:ZapRequest):ZapRequest|null {
   return offset ? (obj || new ZapRequest()).__init(this.bb!.__indirect(this.bb_pos + offset), this.bb!) : null;
 }
 
+invalidReason():string|null
+invalidReason(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+invalidReason(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 28);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+providerVerified():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 30);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
 static startKind9735Parsed(builder:flatbuffers.Builder) {
-  builder.startObject(12);
+  builder.startObject(14);
 }
 
 static addId(builder:flatbuffers.Builder, idOffset:flatbuffers.Offset) {
@@ -153,6 +165,14 @@ static addDescription(builder:flatbuffers.Builder, descriptionOffset:flatbuffers
   builder.addFieldOffset(11, descriptionOffset, 0);
 }
 
+static addInvalidReason(builder:flatbuffers.Builder, invalidReasonOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(12, invalidReasonOffset, 0);
+}
+
+static addProviderVerified(builder:flatbuffers.Builder, providerVerified:boolean) {
+  builder.addFieldInt8(13, +providerVerified, +false);
+}
+
 static endKind9735Parsed(builder:flatbuffers.Builder):flatbuffers.Offset {
   const offset = builder.endObject();
   builder.requiredField(offset, 4) // id
@@ -178,7 +198,9 @@ unpack(): Kind9735ParsedT {
     this.eventCoordinate(),
     this.timestamp(),
     this.valid(),
-    (this.description() !== null ? this.description()!.unpack() : null)
+    (this.description() !== null ? this.description()!.unpack() : null),
+    this.invalidReason(),
+    this.providerVerified()
   );
 }
 
@@ -196,6 +218,8 @@ unpackTo(_o: Kind9735ParsedT): void {
   _o.timestamp = this.timestamp();
   _o.valid = this.valid();
   _o.description = (this.description() !== null ? this.description()!.unpack() : null);
+  _o.invalidReason = this.invalidReason();
+  _o.providerVerified = this.providerVerified();
 }
 }
 
@@ -212,7 +236,9 @@ constructor(
   public eventCoordinate: string|Uint8Array|null = null,
   public timestamp: bigint = BigInt('0'),
   public valid: boolean = false,
-  public description: ZapRequestT|null = null
+  public description: ZapRequestT|null = null,
+  public invalidReason: string|Uint8Array|null = null,
+  public providerVerified: boolean = false
 ){}
 
 
@@ -226,6 +252,7 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
   const event = (this.event !== null ? builder.createString(this.event!) : 0);
   const eventCoordinate = (this.eventCoordinate !== null ? builder.createString(this.eventCoordinate!) : 0);
   const description = (this.description !== null ? this.description!.pack(builder) : 0);
+  const invalidReason = (this.invalidReason !== null ? builder.createString(this.invalidReason!) : 0);
 
   Kind9735Parsed.startKind9735Parsed(builder);
   Kind9735Parsed.addId(builder, id);
@@ -240,6 +267,8 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
   Kind9735Parsed.addTimestamp(builder, this.timestamp);
   Kind9735Parsed.addValid(builder, this.valid);
   Kind9735Parsed.addDescription(builder, description);
+  Kind9735Parsed.addInvalidReason(builder, invalidReason);
+  Kind9735Parsed.addProviderVerified(builder, this.providerVerified);
 
   return Kind9735Parsed.endKind9735Parsed(builder);
 }
@@ -31,49 +31,176 @@ invoice(optionalEncoding?:any):string|Uint8Array|null {
   return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
 }
 
+network():string|null
+network(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+network(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+amountMsats():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+timestamp():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 10);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+expiry():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 12);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+description():string|null
+description(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+description(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 14);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+descriptionHash():string|null
+descriptionHash(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+descriptionHash(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 16);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+paymentHash():string|null
+paymentHash(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+paymentHash(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 18);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+payee():string|null
+payee(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+payee(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 20);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
 static startLightningData(builder:flatbuffers.Builder) {
-  builder.startObject(1);
+  builder.startObject(9);
 }
 
 static addInvoice(builder:flatbuffers.Builder, invoiceOffset:flatbuffers.Offset) {
   builder.addFieldOffset(0, invoiceOffset, 0);
 }
 
+static addNetwork(builder:flatbuffers.Builder, networkOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, networkOffset, 0);
+}
+
+static addAmountMsats(builder:flatbuffers.Builder, amountMsats:bigint) {
+  builder.addFieldInt64(2, amountMsats, BigInt('0'));
+}
+
+static addTimestamp(builder:flatbuffers.Builder, timestamp:bigint) {
+  builder.addFieldInt64(3, timestamp, BigInt('0'));
+}
+
+static addExpiry(builder:flatbuffers.Builder, expiry:bigint) {
+  builder.addFieldInt64(4, expiry, BigInt('0'));
+}
+
+static addDescription(builder:flatbuffers.Builder, descriptionOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(5, descriptionOffset, 0);
+}
+
+static addDescriptionHash(builder:flatbuffers.Builder, descriptionHashOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(6, descriptionHashOffset, 0);
+}
+
+static addPaymentHash(builder:flatbuffers.Builder, paymentHashOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(7, paymentHashOffset, 0);
+}
+
+static addPayee(builder:flatbuffers.Builder, payeeOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(8, payeeOffset, 0);
+}
+
 static endLightningData(builder:flatbuffers.Builder):flatbuffers.Offset {
   const offset = builder.endObject();
   builder.requiredField(offset, 4) // invoice
   return offset;
 }
 
-static createLightningData(builder:flatbuffers.Builder, invoiceOffset:flatbuffers.Offset):flatbuffers.Offset {
+static createLightningData(builder:flatbuffers.Builder, invoiceOffset:flatbuffers.Offset, networkOffset:flatbuffers.Offset, amountMsats:bigint, timestamp:bigint, expiry:bigint, descriptionOffset:flatbuffers.Offset, descriptionHashOffset:flatbuffers.Offset, paymentHashOffset:flatbuffers.Offset, payeeOffset:flatbuffers.Offset):flatbuffers.Offset {
   LightningData.startLightningData(builder);
   LightningData.addInvoice(builder, invoiceOffset);
+  LightningData.addNetwork(builder, networkOffset);
+  LightningData.addAmountMsats(builder, amountMsats);
+  LightningData.addTimestamp(builder, timestamp);
+  LightningData.addExpiry(builder, expiry);
+  LightningData.addDescription(builder, descriptionOffset);
+  LightningData.addDescriptionHash(builder, descriptionHashOffset);
+  LightningData.addPaymentHash(builder, paymentHashOffset);
+  LightningData.addPayee(builder, payeeOffset);
   return LightningData.endLightningData(builder);
 }
 
 unpack(): LightningDataT {
   return new LightningDataT(
-    this.invoice()
+    this.invoice(),
+    this.network(),
+    this.amountMsats(),
+    this.timestamp(),
+    this.expiry(),
+    this.description(),
+    this.descriptionHash(),
+    this.paymentHash(),
+    this.payee()
   );
 }
 
 
 unpackTo(_o: LightningDataT): void {
   _o.invoice = this.invoice();
+  _o.network = this.network();
+  _o.amountMsats = this.amountMsats();
+  _o.timestamp = this.timestamp();
+  _o.expiry = this.expiry();
+  _o.description = this.description();
+  _o.descriptionHash = this.descriptionHash();
+  _o.paymentHash = this.paymentHash();
+  _o.payee = this.payee();
 }
 }
 
 export class LightningDataT implements flatbuffers.IGeneratedObject {
 constructor(
-  public invoice: string|Uint8Array|null = null
+  public invoice: string|Uint8Array|null = null,
+  public network: string|Uint8Array|null = null,
+  public amountMsats: bigint = BigInt('0'),
+  public timestamp: bigint = BigInt('0'),
+  public expiry: bigint = BigInt('0'),
+  public description: string|Uint8Array|null = null,
+  public descriptionHash: string|Uint8Array|null = null,
+  public paymentHash: string|Uint8Array|null = null,
+  public payee: string|Uint8Array|null = null
 ){}
 
 
 pack(builder:flatbuffers.Builder): flatbuffers.Offset {
   const invoice = (this.invoice !== null ? builder.createString(this.invoice!) : 0);
+  const network = (this.network !== null ? builder.createString(this.network!) : 0);
+  const description = (this.description !== null ? builder.createString(this.description!) : 0);
+  const descriptionHash = (this.descriptionHash !== null ? builder.createString(this.descriptionHash!) : 0);
+  const paymentHash = (this.paymentHash !== null ? builder.createString(this.paymentHash!) : 0);
+  const payee = (this.payee !== null ? builder.createString(this.payee!) : 0);
 
   return LightningData.createLightningData(builder,
-    invoice
+    invoice,
+    network,
+    this.amountMsats,
+    this.timestamp,
+    this.expiry,
+    description,
+    descriptionHash,
+    paymentHash,
+    payee
   );
 }
 }
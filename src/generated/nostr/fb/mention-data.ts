@@ -0,0 +1,129 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+/* eslint-disable @typescript-eslint/no-unused-vars, @typescript-eslint/no-explicit-any, @typescript-eslint/no-non-null-assertion */
+
+import * as flatbuffers from 'flatbuffers';
+
+import { ParsedEvent, ParsedEventT } from '../../nostr/fb/parsed-event.js';
+
+
+export class MentionData implements flatbuffers.IUnpackableObject<MentionDataT> {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):MentionData {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsMentionData(bb:flatbuffers.ByteBuffer, obj?:MentionData):MentionData {
+  return (obj || new MentionData()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsMentionData(bb:flatbuffers.ByteBuffer, obj?:MentionData):MentionData {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new MentionData()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+id():string|null
+id(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+id(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+name():string|null
+name(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+name(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+picture():string|null
+picture(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+picture(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+event(obj?:ParsedEvent):ParsedEvent|null {
+  const offset = this.bb!.__offset(this.bb_pos, 10);
+  return offset ? (obj || new ParsedEvent()).__init(this.bb!.__indirect(this.bb_pos + offset), this.bb!) : null;
+}
+
+static startMentionData(builder:flatbuffers.Builder) {
+  builder.startObject(4);
+}
+
+static addId(builder:flatbuffers.Builder, idOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(0, idOffset, 0);
+}
+
+static addName(builder:flatbuffers.Builder, nameOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, nameOffset, 0);
+}
+
+static addPicture(builder:flatbuffers.Builder, pictureOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(2, pictureOffset, 0);
+}
+
+static addEvent(builder:flatbuffers.Builder, eventOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(3, eventOffset, 0);
+}
+
+static endMentionData(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  builder.requiredField(offset, 4) // id
+  return offset;
+}
+
+static createMentionData(builder:flatbuffers.Builder, idOffset:flatbuffers.Offset, nameOffset:flatbuffers.Offset, pictureOffset:flatbuffers.Offset, eventOffset:flatbuffers.Offset):flatbuffers.Offset {
+  MentionData.startMentionData(builder);
+  MentionData.addId(builder, idOffset);
+  MentionData.addName(builder, nameOffset);
+  MentionData.addPicture(builder, pictureOffset);
+  MentionData.addEvent(builder, eventOffset);
+  return MentionData.endMentionData(builder);
+}
+
+unpack(): MentionDataT {
+  return new MentionDataT(
+    this.id(),
+    this.name(),
+    this.picture(),
+    (this.event() !== null ? this.event()!.unpack() : null)
+  );
+}
+
+
+unpackTo(_o: MentionDataT): void {
+  _o.id = this.id();
+  _o.name = this.name();
+  _o.picture = this.picture();
+  _o.event = (this.event() !== null ? this.event()!.unpack() : null);
+}
+}
+
+export class MentionDataT implements flatbuffers.IGeneratedObject {
+constructor(
+  public id: string|Uint8Array|null = null,
+  public name: string|Uint8Array|null = null,
+  public picture: string|Uint8Array|null = null,
+  public event: ParsedEventT|null = null
+){}
+
+
+pack(builder:flatbuffers.Builder): flatbuffers.Offset {
+  const id = (this.id !== null ? builder.createString(this.id!) : 0);
+  const name = (this.name !== null ? builder.createString(this.name!) : 0);
+  const picture = (this.picture !== null ? builder.createString(this.picture!) : 0);
+  const event = (this.event !== null ? this.event!.pack(builder) : 0);
+
+  return MentionData.createMentionData(builder,
+    id,
+    name,
+    picture,
+    event
+  );
+}
+}
@@ -14,5 +14,8 @@ export enum MessageType {
   Raw = 8,
   NostrEvent = 9,
   SetSignerResponse = 10,
-  AuthUrl = 11
+  AuthUrl = 11,
+  LinkPreview = 12,
+  ThreadTree = 13,
+  Mention = 14
 }
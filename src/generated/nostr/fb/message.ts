@@ -7,12 +7,15 @@ import { BufferFull, BufferFullT } from '../../nostr/fb/buffer-full.js';
 import { ConnectionStatus, ConnectionStatusT } from '../../nostr/fb/connection-status.js';
 import { CountResponse, CountResponseT } from '../../nostr/fb/count-response.js';
 import { Eoce, EoceT } from '../../nostr/fb/eoce.js';
+import { LinkPreviewData, LinkPreviewDataT } from '../../nostr/fb/link-preview-data.js';
+import { MentionData, MentionDataT } from '../../nostr/fb/mention-data.js';
 import { NostrEvent, NostrEventT } from '../../nostr/fb/nostr-event.js';
 import { ParsedEvent, ParsedEventT } from '../../nostr/fb/parsed-event.js';
 import { Pubkey, PubkeyT } from '../../nostr/fb/pubkey.js';
 import { Raw, RawT } from '../../nostr/fb/raw.js';
 import { SetSignerResponse, SetSignerResponseT } from '../../nostr/fb/set-signer-response.js';
 import { SignedEvent, SignedEventT } from '../../nostr/fb/signed-event.js';
+import { ThreadTree, ThreadTreeT } from '../../nostr/fb/thread-tree.js';
 import { ValidProofs, ValidProofsT } from '../../nostr/fb/valid-proofs.js';
 
 
@@ -29,13 +32,16 @@ export enum Message {
   Pubkey = 9,
   Raw = 10,
   SetSignerResponse = 11,
-  AuthUrl = 12
+  AuthUrl = 12,
+  LinkPreviewData = 13,
+  ThreadTree = 14,
+  MentionData = 15
 }
 
 export function unionToMessage(
   type: Message,
-  accessor: (obj:AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ValidProofs) => AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ValidProofs|null
-): AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ValidProofs|null {
+  accessor: (obj:AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|LinkPreviewData|MentionData|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ThreadTree|ValidProofs) => AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|LinkPreviewData|MentionData|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ThreadTree|ValidProofs|null
+): AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|LinkPreviewData|MentionData|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ThreadTree|ValidProofs|null {
   switch(Message[type]) {
     case 'NONE': return null; 
     case 'ParsedEvent': return accessor(new ParsedEvent())! as ParsedEvent;
@@ -50,15 +56,18 @@ export function unionToMessage(
     case 'Raw': return accessor(new Raw())! as Raw;
     case 'SetSignerResponse': return accessor(new SetSignerResponse())! as SetSignerResponse;
     case 'AuthUrl': return accessor(new AuthUrl())! as AuthUrl;
+    case 'LinkPreviewData': return accessor(new LinkPreviewData())! as LinkPreviewData;
+    case 'ThreadTree': return accessor(new ThreadTree())! as ThreadTree;
+    case 'MentionData': return accessor(new MentionData())! as MentionData;
     default: return null;
   }
 }
 
 export function unionListToMessage(
   type: Message, 
-  accessor: (index: number, obj:AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ValidProofs) => AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ValidProofs|null,
+  accessor: (index: number, obj:AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|LinkPreviewData|MentionData|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ThreadTree|ValidProofs) => AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|LinkPreviewData|MentionData|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ThreadTree|ValidProofs|null,
   index: number
-): AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ValidProofs|null {
+): AuthUrl|BufferFull|ConnectionStatus|CountResponse|Eoce|LinkPreviewData|MentionData|NostrEvent|ParsedEvent|Pubkey|Raw|SetSignerResponse|SignedEvent|ThreadTree|ValidProofs|null {
   switch(Message[type]) {
     case 'NONE': return null; 
     case 'ParsedEvent': return accessor(index, new ParsedEvent())! as ParsedEvent;
@@ -73,6 +82,9 @@ export function unionListToMessage(
     case 'Raw': return accessor(index, new Raw())! as Raw;
     case 'SetSignerResponse': return accessor(index, new SetSignerResponse())! as SetSignerResponse;
     case 'AuthUrl': return accessor(index, new AuthUrl())! as AuthUrl;
+    case 'LinkPreviewData': return accessor(index, new LinkPreviewData())! as LinkPreviewData;
+    case 'ThreadTree': return accessor(index, new ThreadTree())! as ThreadTree;
+    case 'MentionData': return accessor(index, new MentionData())! as MentionData;
     default: return null;
   }
 }
@@ -10,6 +10,7 @@ export enum ParsedDataUnion {
   Kind6Parsed = 6,
   Kind7Parsed = 7,
   Kind8Parsed = 8,
+  Kind14Parsed = 14,
   Kind17Parsed = 17,
   Kind20Parsed = 20,
   Kind22Parsed = 22,
@@ -28,5 +29,6 @@ export enum ParsedDataUnion {
   Kind30023Parsed = 30023,
   Kind39089Parsed = 39089,
   ListParsed = 51000,
-  PreGenericParsed = 52000
+  PreGenericParsed = 52000,
+  CustomParsed = 60000
 }
@@ -2,6 +2,7 @@
 
 /* eslint-disable @typescript-eslint/no-unused-vars, @typescript-eslint/no-explicit-any, @typescript-eslint/no-non-null-assertion */
 
+import { CustomParsed, CustomParsedT } from '../../nostr/fb/custom-parsed.js';
 import { Kind0Parsed, Kind0ParsedT } from '../../nostr/fb/kind0-parsed.js';
 import { Kind10002Parsed, Kind10002ParsedT } from '../../nostr/fb/kind10002-parsed.js';
 import { Kind10019Parsed, Kind10019ParsedT } from '../../nostr/fb/kind10019-parsed.js';
@@ -9,6 +10,7 @@ import { Kind1018Parsed, Kind1018ParsedT } from '../../nostr/fb/kind1018-parsed.
 import { Kind1068Parsed, Kind1068ParsedT } from '../../nostr/fb/kind1068-parsed.js';
 import { Kind1111Parsed, Kind1111ParsedT } from '../../nostr/fb/kind1111-parsed.js';
 import { Kind1311Parsed, Kind1311ParsedT } from '../../nostr/fb/kind1311-parsed.js';
+import { Kind14Parsed, Kind14ParsedT } from '../../nostr/fb/kind14-parsed.js';
 import { Kind17375Parsed, Kind17375ParsedT } from '../../nostr/fb/kind17375-parsed.js';
 import { Kind17Parsed, Kind17ParsedT } from '../../nostr/fb/kind17-parsed.js';
 import { Kind1Parsed, Kind1ParsedT } from '../../nostr/fb/kind1-parsed.js';
@@ -55,13 +57,15 @@ export enum ParsedData {
   Kind9735Parsed = 22,
   Kind30023Parsed = 23,
   ListParsed = 24,
-  PreGenericParsed = 25
+  PreGenericParsed = 25,
+  Kind14Parsed = 26,
+  CustomParsed = 27
 }
 
 export function unionToParsedData(
   type: ParsedData,
-  accessor: (obj:Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed) => Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed|null
-): Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed|null {
+  accessor: (obj:CustomParsed|Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind14Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed) => CustomParsed|Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind14Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed|null
+): CustomParsed|Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind14Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed|null {
   switch(ParsedData[type]) {
     case 'NONE': return null; 
     case 'Kind0Parsed': return accessor(new Kind0Parsed())! as Kind0Parsed;
@@ -89,15 +93,17 @@ export function unionToParsedData(
     case 'Kind30023Parsed': return accessor(new Kind30023Parsed())! as Kind30023Parsed;
     case 'ListParsed': return accessor(new ListParsed())! as ListParsed;
     case 'PreGenericParsed': return accessor(new PreGenericParsed())! as PreGenericParsed;
+    case 'Kind14Parsed': return accessor(new Kind14Parsed())! as Kind14Parsed;
+    case 'CustomParsed': return accessor(new CustomParsed())! as CustomParsed;
     default: return null;
   }
 }
 
 export function unionListToParsedData(
   type: ParsedData, 
-  accessor: (index: number, obj:Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed) => Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed|null, 
+  accessor: (index: number, obj:CustomParsed|Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind14Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed) => CustomParsed|Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind14Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed|null, 
   index: number
-): Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed|null {
+): CustomParsed|Kind0Parsed|Kind10002Parsed|Kind10019Parsed|Kind1018Parsed|Kind1068Parsed|Kind1111Parsed|Kind1311Parsed|Kind14Parsed|Kind17375Parsed|Kind17Parsed|Kind1Parsed|Kind20Parsed|Kind22Parsed|Kind30023Parsed|Kind3Parsed|Kind4Parsed|Kind6Parsed|Kind7374Parsed|Kind7375Parsed|Kind7376Parsed|Kind7Parsed|Kind8Parsed|Kind9321Parsed|Kind9735Parsed|ListParsed|PreGenericParsed|null {
   switch(ParsedData[type]) {
     case 'NONE': return null; 
     case 'Kind0Parsed': return accessor(index, new Kind0Parsed())! as Kind0Parsed;
@@ -125,6 +131,8 @@ export function unionListToParsedData(
     case 'Kind30023Parsed': return accessor(index, new Kind30023Parsed())! as Kind30023Parsed;
     case 'ListParsed': return accessor(index, new ListParsed())! as ListParsed;
     case 'PreGenericParsed': return accessor(index, new PreGenericParsed())! as PreGenericParsed;
+    case 'Kind14Parsed': return accessor(index, new Kind14Parsed())! as Kind14Parsed;
+    case 'CustomParsed': return accessor(index, new CustomParsed())! as CustomParsed;
     default: return null;
   }
 }
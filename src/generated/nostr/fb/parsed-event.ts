@@ -4,6 +4,7 @@
 
 import * as flatbuffers from 'flatbuffers';
 
+import { CustomParsed, CustomParsedT } from '../../nostr/fb/custom-parsed.js';
 import { Kind0Parsed, Kind0ParsedT } from '../../nostr/fb/kind0-parsed.js';
 import { Kind10002Parsed, Kind10002ParsedT } from '../../nostr/fb/kind10002-parsed.js';
 import { Kind10019Parsed, Kind10019ParsedT } from '../../nostr/fb/kind10019-parsed.js';
@@ -11,6 +12,7 @@ import { Kind1018Parsed, Kind1018ParsedT } from '../../nostr/fb/kind1018-parsed.
 import { Kind1068Parsed, Kind1068ParsedT } from '../../nostr/fb/kind1068-parsed.js';
 import { Kind1111Parsed, Kind1111ParsedT } from '../../nostr/fb/kind1111-parsed.js';
 import { Kind1311Parsed, Kind1311ParsedT } from '../../nostr/fb/kind1311-parsed.js';
+import { Kind14Parsed, Kind14ParsedT } from '../../nostr/fb/kind14-parsed.js';
 import { Kind17375Parsed, Kind17375ParsedT } from '../../nostr/fb/kind17375-parsed.js';
 import { Kind17Parsed, Kind17ParsedT } from '../../nostr/fb/kind17-parsed.js';
 import { Kind1Parsed, Kind1ParsedT } from '../../nostr/fb/kind1-parsed.js';
@@ -118,8 +120,22 @@ tagsLength():number {
   return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
 }
 
+nevent():string|null
+nevent(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+nevent(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 22);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+naddr():string|null
+naddr(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+naddr(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 24);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
 static startParsedEvent(builder:flatbuffers.Builder) {
-  builder.startObject(9);
+  builder.startObject(11);
 }
 
 static addId(builder:flatbuffers.Builder, idOffset:flatbuffers.Offset) {
@@ -194,6 +210,14 @@ static startTagsVector(builder:flatbuffers.Builder, numElems:number) {
   builder.startVector(4, numElems, 4);
 }
 
+static addNevent(builder:flatbuffers.Builder, neventOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(9, neventOffset, 0);
+}
+
+static addNaddr(builder:flatbuffers.Builder, naddrOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(10, naddrOffset, 0);
+}
+
 static endParsedEvent(builder:flatbuffers.Builder):flatbuffers.Offset {
   const offset = builder.endObject();
   builder.requiredField(offset, 4) // id
@@ -202,7 +226,7 @@ static endParsedEvent(builder:flatbuffers.Builder):flatbuffers.Offset {
   return offset;
 }
 
-static createParsedEvent(builder:flatbuffers.Builder, idOffset:flatbuffers.Offset, pubkeyOffset:flatbuffers.Offset, kind:number, createdAt:number, parsedType:ParsedData, parsedOffset:flatbuffers.Offset, requestsOffset:flatbuffers.Offset, relaysOffset:flatbuffers.Offset, tagsOffset:flatbuffers.Offset):flatbuffers.Offset {
+static createParsedEvent(builder:flatbuffers.Builder, idOffset:flatbuffers.Offset, pubkeyOffset:flatbuffers.Offset, kind:number, createdAt:number, parsedType:ParsedData, parsedOffset:flatbuffers.Offset, requestsOffset:flatbuffers.Offset, relaysOffset:flatbuffers.Offset, tagsOffset:flatbuffers.Offset, neventOffset:flatbuffers.Offset, naddrOffset:flatbuffers.Offset):flatbuffers.Offset {
   ParsedEvent.startParsedEvent(builder);
   ParsedEvent.addId(builder, idOffset);
   ParsedEvent.addPubkey(builder, pubkeyOffset);
@@ -213,6 +237,8 @@ static createParsedEvent(builder:flatbuffers.Builder, idOffset:flatbuffers.Offse
   ParsedEvent.addRequests(builder, requestsOffset);
   ParsedEvent.addRelays(builder, relaysOffset);
   ParsedEvent.addTags(builder, tagsOffset);
+  ParsedEvent.addNevent(builder, neventOffset);
+  ParsedEvent.addNaddr(builder, naddrOffset);
   return ParsedEvent.endParsedEvent(builder);
 }
 
@@ -230,7 +256,9 @@ unpack(): ParsedEventT {
   })(),
     this.bb!.createObjList<Request, RequestT>(this.requests.bind(this), this.requestsLength()),
     this.bb!.createScalarList<string>(this.relays.bind(this), this.relaysLength()),
-    this.bb!.createObjList<StringVec, StringVecT>(this.tags.bind(this), this.tagsLength())
+    this.bb!.createObjList<StringVec, StringVecT>(this.tags.bind(this), this.tagsLength()),
+    this.nevent(),
+    this.naddr()
   );
 }
 
@@ -249,6 +277,8 @@ unpackTo(_o: ParsedEventT): void {
   _o.requests = this.bb!.createObjList<Request, RequestT>(this.requests.bind(this), this.requestsLength());
   _o.relays = this.bb!.createScalarList<string>(this.relays.bind(this), this.relaysLength());
   _o.tags = this.bb!.createObjList<StringVec, StringVecT>(this.tags.bind(this), this.tagsLength());
+  _o.nevent = this.nevent();
+  _o.naddr = this.naddr();
 }
 }
 
@@ -259,10 +289,12 @@ constructor(
   public kind: number = 0,
   public createdAt: number = 0,
   public parsedType: ParsedData = ParsedData.NONE,
-  public parsed: Kind0ParsedT|Kind10002ParsedT|Kind10019ParsedT|Kind1018ParsedT|Kind1068ParsedT|Kind1111ParsedT|Kind1311ParsedT|Kind17375ParsedT|Kind17ParsedT|Kind1ParsedT|Kind20ParsedT|Kind22ParsedT|Kind30023ParsedT|Kind3ParsedT|Kind4ParsedT|Kind6ParsedT|Kind7374ParsedT|Kind7375ParsedT|Kind7376ParsedT|Kind7ParsedT|Kind8ParsedT|Kind9321ParsedT|Kind9735ParsedT|ListParsedT|PreGenericParsedT|null = null,
+  public parsed: CustomParsedT|Kind0ParsedT|Kind10002ParsedT|Kind10019ParsedT|Kind1018ParsedT|Kind1068ParsedT|Kind1111ParsedT|Kind1311ParsedT|Kind14ParsedT|Kind17375ParsedT|Kind17ParsedT|Kind1ParsedT|Kind20ParsedT|Kind22ParsedT|Kind30023ParsedT|Kind3ParsedT|Kind4ParsedT|Kind6ParsedT|Kind7374ParsedT|Kind7375ParsedT|Kind7376ParsedT|Kind7ParsedT|Kind8ParsedT|Kind9321ParsedT|Kind9735ParsedT|ListParsedT|PreGenericParsedT|null = null,
   public requests: (RequestT)[] = [],
   public relays: (string)[] = [],
-  public tags: (StringVecT)[] = []
+  public tags: (StringVecT)[] = [],
+  public nevent: string|Uint8Array|null = null,
+  public naddr: string|Uint8Array|null = null
 ){}
 
 
@@ -273,6 +305,8 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
   const requests = ParsedEvent.createRequestsVector(builder, builder.createObjectOffsetList(this.requests));
   const relays = ParsedEvent.createRelaysVector(builder, builder.createObjectOffsetList(this.relays));
   const tags = ParsedEvent.createTagsVector(builder, builder.createObjectOffsetList(this.tags));
+  const nevent = (this.nevent !== null ? builder.createString(this.nevent!) : 0);
+  const naddr = (this.naddr !== null ? builder.createString(this.naddr!) : 0);
 
   return ParsedEvent.createParsedEvent(builder,
     id,
@@ -283,7 +317,9 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
     parsed,
     requests,
     relays,
-    tags
+    tags,
+    nevent,
+    naddr
   );
 }
 }
@@ -11,6 +11,7 @@ import { ParsePipeConfig, ParsePipeConfigT } from '../../nostr/fb/parse-pipe-con
 import { ProofVerificationPipeConfig, ProofVerificationPipeConfigT } from '../../nostr/fb/proof-verification-pipe-config.js';
 import { SaveToDbPipeConfig, SaveToDbPipeConfigT } from '../../nostr/fb/save-to-db-pipe-config.js';
 import { SerializeEventsPipeConfig, SerializeEventsPipeConfigT } from '../../nostr/fb/serialize-events-pipe-config.js';
+import { ZapValidationPipeConfig, ZapValidationPipeConfigT } from '../../nostr/fb/zap-validation-pipe-config.js';
 
 
 export enum PipeConfig {
@@ -23,13 +24,14 @@ export enum PipeConfig {
   SaveToDbPipeConfig = 6,
   SerializeEventsPipeConfig = 7,
   ProofVerificationPipeConfig = 8,
-  MuteFilterPipeConfig = 9
+  MuteFilterPipeConfig = 9,
+  ZapValidationPipeConfig = 10
 }
 
 export function unionToPipeConfig(
   type: PipeConfig,
-  accessor: (obj:ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig) => ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig|null
-): ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig|null {
+  accessor: (obj:ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig|ZapValidationPipeConfig) => ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig|ZapValidationPipeConfig|null
+): ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig|ZapValidationPipeConfig|null {
   switch(PipeConfig[type]) {
     case 'NONE': return null; 
     case 'ParsePipeConfig': return accessor(new ParsePipeConfig())! as ParsePipeConfig;
@@ -41,15 +43,16 @@ export function unionToPipeConfig(
     case 'SerializeEventsPipeConfig': return accessor(new SerializeEventsPipeConfig())! as SerializeEventsPipeConfig;
     case 'ProofVerificationPipeConfig': return accessor(new ProofVerificationPipeConfig())! as ProofVerificationPipeConfig;
     case 'MuteFilterPipeConfig': return accessor(new MuteFilterPipeConfig())! as MuteFilterPipeConfig;
+    case 'ZapValidationPipeConfig': return accessor(new ZapValidationPipeConfig())! as ZapValidationPipeConfig;
     default: return null;
   }
 }
 
 export function unionListToPipeConfig(
   type: PipeConfig, 
-  accessor: (index: number, obj:ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig) => ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig|null, 
+  accessor: (index: number, obj:ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig|ZapValidationPipeConfig) => ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig|ZapValidationPipeConfig|null, 
   index: number
-): ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig|null {
+): ChatLimiterPipeConfig|CounterPipeConfig|KindFilterPipeConfig|MuteFilterPipeConfig|NpubLimiterPipeConfig|ParsePipeConfig|ProofVerificationPipeConfig|SaveToDbPipeConfig|SerializeEventsPipeConfig|ZapValidationPipeConfig|null {
   switch(PipeConfig[type]) {
     case 'NONE': return null; 
     case 'ParsePipeConfig': return accessor(index, new ParsePipeConfig())! as ParsePipeConfig;
@@ -61,6 +64,7 @@ export function unionListToPipeConfig(
     case 'SerializeEventsPipeConfig': return accessor(index, new SerializeEventsPipeConfig())! as SerializeEventsPipeConfig;
     case 'ProofVerificationPipeConfig': return accessor(index, new ProofVerificationPipeConfig())! as ProofVerificationPipeConfig;
     case 'MuteFilterPipeConfig': return accessor(index, new MuteFilterPipeConfig())! as MuteFilterPipeConfig;
+    case 'ZapValidationPipeConfig': return accessor(index, new ZapValidationPipeConfig())! as ZapValidationPipeConfig;
     default: return null;
   }
 }
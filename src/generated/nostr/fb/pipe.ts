@@ -14,6 +14,7 @@ import { PipeConfig, unionToPipeConfig, unionListToPipeConfig } from '../../nost
 import { ProofVerificationPipeConfig, ProofVerificationPipeConfigT } from '../../nostr/fb/proof-verification-pipe-config.js';
 import { SaveToDbPipeConfig, SaveToDbPipeConfigT } from '../../nostr/fb/save-to-db-pipe-config.js';
 import { SerializeEventsPipeConfig, SerializeEventsPipeConfigT } from '../../nostr/fb/serialize-events-pipe-config.js';
+import { ZapValidationPipeConfig, ZapValidationPipeConfigT } from '../../nostr/fb/zap-validation-pipe-config.js';
 
 
 export class Pipe implements flatbuffers.IUnpackableObject<PipeT> {
@@ -94,7 +95,7 @@ unpackTo(_o: PipeT): void {
 export class PipeT implements flatbuffers.IGeneratedObject {
 constructor(
   public configType: PipeConfig = PipeConfig.NONE,
-  public config: ChatLimiterPipeConfigT|CounterPipeConfigT|KindFilterPipeConfigT|MuteFilterPipeConfigT|NpubLimiterPipeConfigT|ParsePipeConfigT|ProofVerificationPipeConfigT|SaveToDbPipeConfigT|SerializeEventsPipeConfigT|null = null
+  public config: ChatLimiterPipeConfigT|CounterPipeConfigT|KindFilterPipeConfigT|MuteFilterPipeConfigT|NpubLimiterPipeConfigT|ParsePipeConfigT|ProofVerificationPipeConfigT|SaveToDbPipeConfigT|SerializeEventsPipeConfigT|ZapValidationPipeConfigT|null = null
 ){}
 
 
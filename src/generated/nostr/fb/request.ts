@@ -133,8 +133,18 @@ meshOnly():boolean {
   return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
 }
 
+negentropy():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 34);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+background():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 36);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
 static startRequest(builder:flatbuffers.Builder) {
-  builder.startObject(15);
+  builder.startObject(17);
 }
 
 static addIds(builder:flatbuffers.Builder, idsOffset:flatbuffers.Offset) {
@@ -258,12 +268,20 @@ static addMeshOnly(builder:flatbuffers.Builder, meshOnly:boolean) {
   builder.addFieldInt8(14, +meshOnly, +false);
 }
 
+static addNegentropy(builder:flatbuffers.Builder, negentropy:boolean) {
+  builder.addFieldInt8(15, +negentropy, +false);
+}
+
+static addBackground(builder:flatbuffers.Builder, background:boolean) {
+  builder.addFieldInt8(16, +background, +false);
+}
+
 static endRequest(builder:flatbuffers.Builder):flatbuffers.Offset {
   const offset = builder.endObject();
   return offset;
 }
 
-static createRequest(builder:flatbuffers.Builder, idsOffset:flatbuffers.Offset, authorsOffset:flatbuffers.Offset, kindsOffset:flatbuffers.Offset, tagsOffset:flatbuffers.Offset, limit:number, since:number, until:number, searchOffset:flatbuffers.Offset, relaysOffset:flatbuffers.Offset, cacheFirst:boolean, noCache:boolean, maxRelays:number, cacheOnly:boolean, meshOnly:boolean):flatbuffers.Offset {
+static createRequest(builder:flatbuffers.Builder, idsOffset:flatbuffers.Offset, authorsOffset:flatbuffers.Offset, kindsOffset:flatbuffers.Offset, tagsOffset:flatbuffers.Offset, limit:number, since:number, until:number, searchOffset:flatbuffers.Offset, relaysOffset:flatbuffers.Offset, cacheFirst:boolean, noCache:boolean, maxRelays:number, cacheOnly:boolean, meshOnly:boolean, negentropy:boolean, background:boolean):flatbuffers.Offset {
   Request.startRequest(builder);
   Request.addIds(builder, idsOffset);
   Request.addAuthors(builder, authorsOffset);
@@ -279,6 +297,8 @@ static createRequest(builder:flatbuffers.Builder, idsOffset:flatbuffers.Offset,
   Request.addMaxRelays(builder, maxRelays);
   Request.addCacheOnly(builder, cacheOnly);
   Request.addMeshOnly(builder, meshOnly);
+  Request.addNegentropy(builder, negentropy);
+  Request.addBackground(builder, background);
   return Request.endRequest(builder);
 }
 
@@ -297,7 +317,9 @@ unpack(): RequestT {
     this.noCache(),
     this.maxRelays(),
     this.cacheOnly(),
-    this.meshOnly()
+    this.meshOnly(),
+    this.negentropy(),
+    this.background()
   );
 }
 
@@ -317,6 +339,8 @@ unpackTo(_o: RequestT): void {
   _o.maxRelays = this.maxRelays();
   _o.cacheOnly = this.cacheOnly();
   _o.meshOnly = this.meshOnly();
+  _o.negentropy = this.negentropy();
+  _o.background = this.background();
 }
 }
 
@@ -335,7 +359,9 @@ constructor(
   public noCache: boolean = false,
   public maxRelays: number = 0,
   public cacheOnly: boolean = false,
-  public meshOnly: boolean = false
+  public meshOnly: boolean = false,
+  public negentropy: boolean = false,
+  public background: boolean = false
 ){}
 
 
@@ -361,7 +387,9 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
     this.noCache,
     this.maxRelays,
     this.cacheOnly,
-    this.meshOnly
+    this.meshOnly,
+    this.negentropy,
+    this.background
   );
 }
 }
@@ -82,8 +82,45 @@ cacheOnly():boolean {
   return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
 }
 
+mergedWindow():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 26);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+linkPreviews():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 28);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+thread():string|null
+thread(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+thread(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 30);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+resolveMentions():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 32);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+budgetBytes():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 34);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+budgetEvents():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 36);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+verifyNip05():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 38);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
 static startSubscriptionConfig(builder:flatbuffers.Builder) {
-  builder.startObject(11);
+  builder.startObject(18);
 }
 
 static addPipeline(builder:flatbuffers.Builder, pipelineOffset:flatbuffers.Offset) {
@@ -130,12 +167,40 @@ static addCacheOnly(builder:flatbuffers.Builder, cacheOnly:boolean) {
   builder.addFieldInt8(10, +cacheOnly, +false);
 }
 
+static addMergedWindow(builder:flatbuffers.Builder, mergedWindow:boolean) {
+  builder.addFieldInt8(11, +mergedWindow, +false);
+}
+
+static addLinkPreviews(builder:flatbuffers.Builder, linkPreviews:boolean) {
+  builder.addFieldInt8(12, +linkPreviews, +false);
+}
+
+static addThread(builder:flatbuffers.Builder, threadOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(13, threadOffset, 0);
+}
+
+static addResolveMentions(builder:flatbuffers.Builder, resolveMentions:boolean) {
+  builder.addFieldInt8(14, +resolveMentions, +false);
+}
+
+static addBudgetBytes(builder:flatbuffers.Builder, budgetBytes:bigint) {
+  builder.addFieldInt64(15, budgetBytes, BigInt('0'));
+}
+
+static addBudgetEvents(builder:flatbuffers.Builder, budgetEvents:bigint) {
+  builder.addFieldInt64(16, budgetEvents, BigInt('0'));
+}
+
+static addVerifyNip05(builder:flatbuffers.Builder, verifyNip05:boolean) {
+  builder.addFieldInt8(17, +verifyNip05, +false);
+}
+
 static endSubscriptionConfig(builder:flatbuffers.Builder):flatbuffers.Offset {
   const offset = builder.endObject();
   return offset;
 }
 
-static createSubscriptionConfig(builder:flatbuffers.Builder, pipelineOffset:flatbuffers.Offset, closeOnEose:boolean, cacheFirst:boolean, timeoutMs:bigint, maxEvents:number, skipCache:boolean, force:boolean, bytesPerEvent:number, isSlow:boolean, paginationOffset:flatbuffers.Offset, cacheOnly:boolean):flatbuffers.Offset {
+static createSubscriptionConfig(builder:flatbuffers.Builder, pipelineOffset:flatbuffers.Offset, closeOnEose:boolean, cacheFirst:boolean, timeoutMs:bigint, maxEvents:number, skipCache:boolean, force:boolean, bytesPerEvent:number, isSlow:boolean, paginationOffset:flatbuffers.Offset, cacheOnly:boolean, mergedWindow:boolean, linkPreviews:boolean, threadOffset:flatbuffers.Offset, resolveMentions:boolean, budgetBytes:bigint, budgetEvents:bigint, verifyNip05:boolean):flatbuffers.Offset {
   SubscriptionConfig.startSubscriptionConfig(builder);
   SubscriptionConfig.addPipeline(builder, pipelineOffset);
   SubscriptionConfig.addCloseOnEose(builder, closeOnEose);
@@ -148,6 +213,13 @@ static createSubscriptionConfig(builder:flatbuffers.Builder, pipelineOffset:flat
   SubscriptionConfig.addIsSlow(builder, isSlow);
   SubscriptionConfig.addPagination(builder, paginationOffset);
   SubscriptionConfig.addCacheOnly(builder, cacheOnly);
+  SubscriptionConfig.addMergedWindow(builder, mergedWindow);
+  SubscriptionConfig.addLinkPreviews(builder, linkPreviews);
+  SubscriptionConfig.addThread(builder, threadOffset);
+  SubscriptionConfig.addResolveMentions(builder, resolveMentions);
+  SubscriptionConfig.addBudgetBytes(builder, budgetBytes);
+  SubscriptionConfig.addBudgetEvents(builder, budgetEvents);
+  SubscriptionConfig.addVerifyNip05(builder, verifyNip05);
   return SubscriptionConfig.endSubscriptionConfig(builder);
 }
 
@@ -163,7 +235,14 @@ unpack(): SubscriptionConfigT {
     this.bytesPerEvent(),
     this.isSlow(),
     this.pagination(),
-    this.cacheOnly()
+    this.cacheOnly(),
+    this.mergedWindow(),
+    this.linkPreviews(),
+    this.thread(),
+    this.resolveMentions(),
+    this.budgetBytes(),
+    this.budgetEvents(),
+    this.verifyNip05()
   );
 }
 
@@ -180,6 +259,13 @@ unpackTo(_o: SubscriptionConfigT): void {
   _o.isSlow = this.isSlow();
   _o.pagination = this.pagination();
   _o.cacheOnly = this.cacheOnly();
+  _o.mergedWindow = this.mergedWindow();
+  _o.linkPreviews = this.linkPreviews();
+  _o.thread = this.thread();
+  _o.resolveMentions = this.resolveMentions();
+  _o.budgetBytes = this.budgetBytes();
+  _o.budgetEvents = this.budgetEvents();
+  _o.verifyNip05 = this.verifyNip05();
 }
 }
 
@@ -195,13 +281,21 @@ constructor(
   public bytesPerEvent: number = 0,
   public isSlow: boolean = false,
   public pagination: string|Uint8Array|null = null,
-  public cacheOnly: boolean = false
+  public cacheOnly: boolean = false,
+  public mergedWindow: boolean = false,
+  public linkPreviews: boolean = false,
+  public thread: string|Uint8Array|null = null,
+  public resolveMentions: boolean = false,
+  public budgetBytes: bigint = BigInt('0'),
+  public budgetEvents: bigint = BigInt('0'),
+  public verifyNip05: boolean = false
 ){}
 
 
 pack(builder:flatbuffers.Builder): flatbuffers.Offset {
   const pipeline = (this.pipeline !== null ? this.pipeline!.pack(builder) : 0);
   const pagination = (this.pagination !== null ? builder.createString(this.pagination!) : 0);
+  const thread = (this.thread !== null ? builder.createString(this.thread!) : 0);
 
   return SubscriptionConfig.createSubscriptionConfig(builder,
     pipeline,
@@ -214,7 +308,14 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
     this.bytesPerEvent,
     this.isSlow,
     pagination,
-    this.cacheOnly
+    this.cacheOnly,
+    this.mergedWindow,
+    this.linkPreviews,
+    thread,
+    this.resolveMentions,
+    this.budgetBytes,
+    this.budgetEvents,
+    this.verifyNip05
   );
 }
 }
@@ -0,0 +1,124 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+/* eslint-disable @typescript-eslint/no-unused-vars, @typescript-eslint/no-explicit-any, @typescript-eslint/no-non-null-assertion */
+
+import * as flatbuffers from 'flatbuffers';
+
+
+
+export class ThreadNode implements flatbuffers.IUnpackableObject<ThreadNodeT> {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):ThreadNode {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsThreadNode(bb:flatbuffers.ByteBuffer, obj?:ThreadNode):ThreadNode {
+  return (obj || new ThreadNode()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsThreadNode(bb:flatbuffers.ByteBuffer, obj?:ThreadNode):ThreadNode {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new ThreadNode()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+id():string|null
+id(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+id(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+parent():string|null
+parent(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+parent(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+depth():number {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.readUint16(this.bb_pos + offset) : 0;
+}
+
+missing():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 10);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+static startThreadNode(builder:flatbuffers.Builder) {
+  builder.startObject(4);
+}
+
+static addId(builder:flatbuffers.Builder, idOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(0, idOffset, 0);
+}
+
+static addParent(builder:flatbuffers.Builder, parentOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, parentOffset, 0);
+}
+
+static addDepth(builder:flatbuffers.Builder, depth:number) {
+  builder.addFieldInt16(2, depth, 0);
+}
+
+static addMissing(builder:flatbuffers.Builder, missing:boolean) {
+  builder.addFieldInt8(3, +missing, +false);
+}
+
+static endThreadNode(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  builder.requiredField(offset, 4) // id
+  return offset;
+}
+
+static createThreadNode(builder:flatbuffers.Builder, idOffset:flatbuffers.Offset, parentOffset:flatbuffers.Offset, depth:number, missing:boolean):flatbuffers.Offset {
+  ThreadNode.startThreadNode(builder);
+  ThreadNode.addId(builder, idOffset);
+  ThreadNode.addParent(builder, parentOffset);
+  ThreadNode.addDepth(builder, depth);
+  ThreadNode.addMissing(builder, missing);
+  return ThreadNode.endThreadNode(builder);
+}
+
+unpack(): ThreadNodeT {
+  return new ThreadNodeT(
+    this.id(),
+    this.parent(),
+    this.depth(),
+    this.missing()
+  );
+}
+
+
+unpackTo(_o: ThreadNodeT): void {
+  _o.id = this.id();
+  _o.parent = this.parent();
+  _o.depth = this.depth();
+  _o.missing = this.missing();
+}
+}
+
+export class ThreadNodeT implements flatbuffers.IGeneratedObject {
+constructor(
+  public id: string|Uint8Array|null = null,
+  public parent: string|Uint8Array|null = null,
+  public depth: number = 0,
+  public missing: boolean = false
+){}
+
+
+pack(builder:flatbuffers.Builder): flatbuffers.Offset {
+  const id = (this.id !== null ? builder.createString(this.id!) : 0);
+  const parent = (this.parent !== null ? builder.createString(this.parent!) : 0);
+
+  return ThreadNode.createThreadNode(builder,
+    id,
+    parent,
+    this.depth,
+    this.missing
+  );
+}
+}
@@ -0,0 +1,159 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+/* eslint-disable @typescript-eslint/no-unused-vars, @typescript-eslint/no-explicit-any, @typescript-eslint/no-non-null-assertion */
+
+import * as flatbuffers from 'flatbuffers';
+
+import { ThreadNode, ThreadNodeT } from '../../nostr/fb/thread-node.js';
+
+
+export class ThreadTree implements flatbuffers.IUnpackableObject<ThreadTreeT> {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):ThreadTree {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsThreadTree(bb:flatbuffers.ByteBuffer, obj?:ThreadTree):ThreadTree {
+  return (obj || new ThreadTree()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsThreadTree(bb:flatbuffers.ByteBuffer, obj?:ThreadTree):ThreadTree {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new ThreadTree()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+focus():string|null
+focus(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+focus(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+root():string|null
+root(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+root(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+nodes(index: number, obj?:ThreadNode):ThreadNode|null {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? (obj || new ThreadNode()).__init(this.bb!.__indirect(this.bb!.__vector(this.bb_pos + offset) + index * 4), this.bb!) : null;
+}
+
+nodesLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+complete():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 10);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+delta():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 12);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+static startThreadTree(builder:flatbuffers.Builder) {
+  builder.startObject(5);
+}
+
+static addFocus(builder:flatbuffers.Builder, focusOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(0, focusOffset, 0);
+}
+
+static addRoot(builder:flatbuffers.Builder, rootOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, rootOffset, 0);
+}
+
+static addNodes(builder:flatbuffers.Builder, nodesOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(2, nodesOffset, 0);
+}
+
+static createNodesVector(builder:flatbuffers.Builder, data:flatbuffers.Offset[]):flatbuffers.Offset {
+  builder.startVector(4, data.length, 4);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addOffset(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startNodesVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(4, numElems, 4);
+}
+
+static addComplete(builder:flatbuffers.Builder, complete:boolean) {
+  builder.addFieldInt8(3, +complete, +false);
+}
+
+static addDelta(builder:flatbuffers.Builder, delta:boolean) {
+  builder.addFieldInt8(4, +delta, +false);
+}
+
+static endThreadTree(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  builder.requiredField(offset, 4) // focus
+  builder.requiredField(offset, 6) // root
+  builder.requiredField(offset, 8) // nodes
+  return offset;
+}
+
+static createThreadTree(builder:flatbuffers.Builder, focusOffset:flatbuffers.Offset, rootOffset:flatbuffers.Offset, nodesOffset:flatbuffers.Offset, complete:boolean, delta:boolean):flatbuffers.Offset {
+  ThreadTree.startThreadTree(builder);
+  ThreadTree.addFocus(builder, focusOffset);
+  ThreadTree.addRoot(builder, rootOffset);
+  ThreadTree.addNodes(builder, nodesOffset);
+  ThreadTree.addComplete(builder, complete);
+  ThreadTree.addDelta(builder, delta);
+  return ThreadTree.endThreadTree(builder);
+}
+
+unpack(): ThreadTreeT {
+  return new ThreadTreeT(
+    this.focus(),
+    this.root(),
+    this.bb!.createObjList<ThreadNode, ThreadNodeT>(this.nodes.bind(this), this.nodesLength()),
+    this.complete(),
+    this.delta()
+  );
+}
+
+
+unpackTo(_o: ThreadTreeT): void {
+  _o.focus = this.focus();
+  _o.root = this.root();
+  _o.nodes = this.bb!.createObjList<ThreadNode, ThreadNodeT>(this.nodes.bind(this), this.nodesLength());
+  _o.complete = this.complete();
+  _o.delta = this.delta();
+}
+}
+
+export class ThreadTreeT implements flatbuffers.IGeneratedObject {
+constructor(
+  public focus: string|Uint8Array|null = null,
+  public root: string|Uint8Array|null = null,
+  public nodes: (ThreadNodeT)[] = [],
+  public complete: boolean = false,
+  public delta: boolean = false
+){}
+
+
+pack(builder:flatbuffers.Builder): flatbuffers.Offset {
+  const focus = (this.focus !== null ? builder.createString(this.focus!) : 0);
+  const root = (this.root !== null ? builder.createString(this.root!) : 0);
+  const nodes = ThreadTree.createNodesVector(builder, builder.createObjectOffsetList(this.nodes));
+
+  return ThreadTree.createThreadTree(builder,
+    focus,
+    root,
+    nodes,
+    this.complete,
+    this.delta
+  );
+}
+}
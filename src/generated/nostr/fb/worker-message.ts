@@ -9,6 +9,8 @@ import { BufferFull, BufferFullT } from '../../nostr/fb/buffer-full.js';
 import { ConnectionStatus, ConnectionStatusT } from '../../nostr/fb/connection-status.js';
 import { CountResponse, CountResponseT } from '../../nostr/fb/count-response.js';
 import { Eoce, EoceT } from '../../nostr/fb/eoce.js';
+import { LinkPreviewData, LinkPreviewDataT } from '../../nostr/fb/link-preview-data.js';
+import { MentionData, MentionDataT } from '../../nostr/fb/mention-data.js';
 import { Message, unionToMessage, unionListToMessage } from '../../nostr/fb/message.js';
 import { MessageType } from '../../nostr/fb/message-type.js';
 import { NostrEvent, NostrEventT } from '../../nostr/fb/nostr-event.js';
@@ -17,6 +19,7 @@ import { Pubkey, PubkeyT } from '../../nostr/fb/pubkey.js';
 import { Raw, RawT } from '../../nostr/fb/raw.js';
 import { SetSignerResponse, SetSignerResponseT } from '../../nostr/fb/set-signer-response.js';
 import { SignedEvent, SignedEventT } from '../../nostr/fb/signed-event.js';
+import { ThreadTree, ThreadTreeT } from '../../nostr/fb/thread-tree.js';
 import { ValidProofs, ValidProofsT } from '../../nostr/fb/valid-proofs.js';
 
 
@@ -148,7 +151,7 @@ constructor(
   public url: string|Uint8Array|null = null,
   public type: MessageType = MessageType.ParsedNostrEvent,
   public contentType: Message = Message.NONE,
-  public content: AuthUrlT|BufferFullT|ConnectionStatusT|CountResponseT|EoceT|NostrEventT|ParsedEventT|PubkeyT|RawT|SetSignerResponseT|SignedEventT|ValidProofsT|null = null
+  public content: AuthUrlT|BufferFullT|ConnectionStatusT|CountResponseT|EoceT|LinkPreviewDataT|MentionDataT|NostrEventT|ParsedEventT|PubkeyT|RawT|SetSignerResponseT|SignedEventT|ThreadTreeT|ValidProofsT|null = null
 ){}
 
 
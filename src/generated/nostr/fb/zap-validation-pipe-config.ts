@@ -0,0 +1,74 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+/* eslint-disable @typescript-eslint/no-unused-vars, @typescript-eslint/no-explicit-any, @typescript-eslint/no-non-null-assertion */
+
+import * as flatbuffers from 'flatbuffers';
+
+
+
+export class ZapValidationPipeConfig implements flatbuffers.IUnpackableObject<ZapValidationPipeConfigT> {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):ZapValidationPipeConfig {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsZapValidationPipeConfig(bb:flatbuffers.ByteBuffer, obj?:ZapValidationPipeConfig):ZapValidationPipeConfig {
+  return (obj || new ZapValidationPipeConfig()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsZapValidationPipeConfig(bb:flatbuffers.ByteBuffer, obj?:ZapValidationPipeConfig):ZapValidationPipeConfig {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new ZapValidationPipeConfig()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+dropInvalid():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+static startZapValidationPipeConfig(builder:flatbuffers.Builder) {
+  builder.startObject(1);
+}
+
+static addDropInvalid(builder:flatbuffers.Builder, dropInvalid:boolean) {
+  builder.addFieldInt8(0, +dropInvalid, +false);
+}
+
+static endZapValidationPipeConfig(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createZapValidationPipeConfig(builder:flatbuffers.Builder, dropInvalid:boolean):flatbuffers.Offset {
+  ZapValidationPipeConfig.startZapValidationPipeConfig(builder);
+  ZapValidationPipeConfig.addDropInvalid(builder, dropInvalid);
+  return ZapValidationPipeConfig.endZapValidationPipeConfig(builder);
+}
+
+unpack(): ZapValidationPipeConfigT {
+  return new ZapValidationPipeConfigT(
+    this.dropInvalid()
+  );
+}
+
+
+unpackTo(_o: ZapValidationPipeConfigT): void {
+  _o.dropInvalid = this.dropInvalid();
+}
+}
+
+export class ZapValidationPipeConfigT implements flatbuffers.IGeneratedObject {
+constructor(
+  public dropInvalid: boolean = false
+){}
+
+
+pack(builder:flatbuffers.Builder): flatbuffers.Offset {
+  return ZapValidationPipeConfig.createZapValidationPipeConfig(builder,
+    this.dropInvalid
+  );
+}
+}
@@ -30,6 +30,8 @@ import {
 	Kind1311Parsed,
 	ContentBlock,
 	LinkPreviewData,
+	ThreadTree,
+	MentionData,
 	ContentData,
 	CashuData,
 	LightningData,
@@ -41,7 +43,8 @@ import {
 	EmojiData,
 	NostrEvent,
 	ListParsed,
-	PreGenericParsed
+	PreGenericParsed,
+	CustomParsed
 } from 'src/generated/nostr/fb';
 import { ImageData as FbImageData } from 'src/generated/nostr/fb';
 import { ParsedData } from 'src/generated/nostr/fb/parsed-data';
@@ -82,6 +85,25 @@ export function isValidProofs(msg: WorkerMessage): ValidProofs | null {
 
 export const asValidProofs = isValidProofs;
 
+/** A preview resolved after the event that linked `url()` was delivered (`linkPreviews` subscriptions). */
+export function isLinkPreview(msg: WorkerMessage): LinkPreviewData | null {
+	if (msg.contentType() !== Message.LinkPreviewData) return null;
+	return msg.content(new LinkPreviewData()) ?? null;
+}
+
+/** The thread around the focus event, re-sent as it fills in, then as `delta` updates once complete
+ * (`thread` subscriptions). */
+export function isThreadTree(msg: WorkerMessage): ThreadTree | null {
+	if (msg.contentType() !== Message.ThreadTree) return null;
+	return msg.content(new ThreadTree()) ?? null;
+}
+
+/** A `nostr:` reference resolved to a display name or an embedded event (`resolveMentions` subscriptions). */
+export function isMention(msg: WorkerMessage): MentionData | null {
+	if (msg.contentType() !== Message.MentionData) return null;
+	return msg.content(new MentionData()) ?? null;
+}
+
 // ---- Generic ParsedEvent --------
 export function isParsedEvent(msg: WorkerMessage): ParsedEvent | null {
 	if (msg.contentType() !== Message.ParsedEvent) return null;
@@ -152,6 +174,8 @@ function parsedKind<T>(msg: WorkerMessage, kind: ParsedData): T | null {
 			return ev.parsed(new ListParsed()) as T | null;
 		case ParsedData.PreGenericParsed:
 			return ev.parsed(new PreGenericParsed()) as T | null;
+		case ParsedData.CustomParsed:
+			return ev.parsed(new CustomParsed()) as T | null;
 		default:
 			return null;
 	}
@@ -327,6 +351,19 @@ export function asPreGeneric(ev: ParsedEvent): PreGenericParsed | null {
 	return ev.parsed(new PreGenericParsed()) ?? null;
 }
 
+/**
+ * Payload from a kind parser registered in Rust. Check `schemaId()` before
+ * decoding `payloadArray()` with the app's own generated reader.
+ */
+export function isCustom(msg: WorkerMessage): CustomParsed | null {
+	return parsedKind<CustomParsed>(msg, ParsedData.CustomParsed);
+}
+
+export function asCustom(ev: ParsedEvent): CustomParsed | null {
+	if (ev.parsedType() !== ParsedData.CustomParsed) return null;
+	return ev.parsed(new CustomParsed()) ?? null;
+}
+
 export function asCodeData(block: ContentBlock): CodeData | null {
 	if (block.dataType() !== ContentData.CodeData) return null;
 	return block.data(new CodeData()) ?? null;
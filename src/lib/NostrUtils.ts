@@ -4,12 +4,15 @@
  * This module provides:
  * - d-tag generation (slug or random)
  * - a-pointer computation (kind:pubkey_hex:d)
- * - naddr-like computation (human-readable, non-bech32 placeholder)
+ * - NIP-19 naddr encoding/decoding (bech32, via nostr-tools)
+ * - naddr-like computation (human-readable, non-bech32)
  *
  * Notes:
  * - naddr-like is NOT a real bech32-encoded naddr. It’s a convenient string to
  *   carry the same tuple ("kind:pubkey:d") plus optional relays for UI/links.
- * - If/when you wire a proper NIP-19 encoder, replace naddr-like with a true naddr.
+ * - Parsed events already carry a bech32 naddr (`ParsedEvent.naddr()`, with relay
+ *   hints) for addressable kinds; `Kind30023Parsed.naddr()` is the plain
+ *   "kind:pubkey:d" a-pointer.
  */
 
 import { nip19 } from 'nostr-tools';
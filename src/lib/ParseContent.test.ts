@@ -4,6 +4,7 @@ import * as flatbuffers from 'flatbuffers';
 import { ContentBlock } from 'src/generated/nostr/fb/content-block';
 import { ContentData } from 'src/generated/nostr/fb/content-data';
 import { LightningDataT } from 'src/generated/nostr/fb/lightning-data';
+import { CashuDataT } from 'src/generated/nostr/fb/cashu-data';
 
 import { asLightningData } from './NarrowTypes';
 import { parseContent } from './ParseContent';
@@ -42,6 +43,22 @@ const invalidZeroRScalar = [
 	'h2ursdae8g6twvus8g6rfwvs8qun0dfjkxaq9qrsgqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq',
 	'qqqqqqqqqqqqqqqqq9us6v52vjjsrvywa6rt52cm9r9zqt8r2t7mlcwspyetp5h2tztugpc4vhg3'
 ].join('');
+// NUT-00 test vectors
+const cashuV3 = [
+	'cashuAeyJ0b2tlbiI6W3sibWludCI6Imh0dHBzOi8vODMzMy5zcGFjZTozMzM4IiwicHJvb2ZzIjpbeyJh',
+	'bW91bnQiOjIsImlkIjoiMDA5YTFmMjkzMjUzZTQxZSIsInNlY3JldCI6IjQwNzkxNWJjMjEyYmU2MWE3',
+	'N2UzZTZkMmFlYjRjNzI3OTgwYmRhNTFjZDA2YTZhZmMyOWUyODYxNzY4YTc4MzciLCJDIjoiMDJiYzkw',
+	'OTc5OTdkODFhZmIyY2M3MzQ2YjVlNDM0NWE5MzQ2YmQyYTUwNmViNzk1ODU5OGE3MmYwY2Y4NTE2M2Vh',
+	'In0seyJhbW91bnQiOjgsImlkIjoiMDA5YTFmMjkzMjUzZTQxZSIsInNlY3JldCI6ImZlMTUxMDkzMTRl',
+	'NjFkNzc1NmIwZjhlZTBmMjNhNjI0YWNhYTNmNGUwNDJmNjE0MzNjNzI4YzcwNTdiOTMxYmUiLCJDIjoi',
+	'MDI5ZThlNTA1MGI4OTBhN2Q2YzA5NjhkYjE2YmMxZDVkNWZhMDQwZWExZGUyODRmNmVjNjlkNjEyOTlm',
+	'NjcxMDU5In1dfV0sInVuaXQiOiJzYXQiLCJtZW1vIjoiVGhhbmsgeW91IHZlcnkgbXVjaC4ifQ'
+].join('');
+const cashuV4 = [
+	'cashuBpGF0gaJhaUgArSaMTR9YJmFwgaNhYQFhc3hAOWE2ZGJiODQ3YmQyMzJiYTc2ZGIwZGYxOTcyMTZi',
+	'MjlkM2I4Y2MxNDU1M2NkMjc4MjdmYzFjYzk0MmZlZGI0ZWFjWCEDhhhUP_trhpXfStS6vN6So0qWvc2X3O4N',
+	'fM-Y1HISZ5JhZGlUaGFuayB5b3VhbXVodHRwOi8vbG9jYWxob3N0OjMzMzhhdWNzYXQ='
+].join('');
 
 describe('parseContent Lightning invoices', () => {
 	it('parses a bare BOLT11 invoice', async () => {
@@ -54,6 +71,23 @@ describe('parseContent Lightning invoices', () => {
 		expect(decode((block?.data as LightningDataT).invoice)).toBe(validBolt11);
 	});
 
+	it('decodes the invoice fields', async () => {
+		const [block] = await parseContent(validDescriptionHashBolt11);
+		const lightning = block?.data as LightningDataT;
+
+		expect(decode(lightning.network)).toBe('bitcoin');
+		expect(lightning.amountMsats).toBe(2_000_000_000n);
+		expect(lightning.timestamp).toBe(1_496_314_658n);
+		expect(lightning.expiry).toBe(3600n);
+		expect(lightning.description).toBeNull();
+		expect(decode(lightning.descriptionHash)).toBe(
+			'3925b6f67e2c340036ed12093dd44e0368df1b6ea26c53dbe4811f58fd5db8c1'
+		);
+		expect(decode(lightning.paymentHash)).toBe(
+			'0001020304050607080900010203040506070809000102030405060708090102'
+		);
+	});
+
 	it('parses a lightning URI and exposes the invoice without the scheme', async () => {
 		const invoice = validBolt11.toUpperCase();
 		const uri = `LIGHTNING:${invoice}`;
@@ -127,3 +161,45 @@ describe('parseContent Lightning invoices', () => {
 		expect(blocks.map((block) => decode(block.text)).join('')).toBe(content);
 	});
 });
+
+describe('parseContent Cashu tokens', () => {
+	it('decodes a V3 token', async () => {
+		const blocks = await parseContent(`Here you go ${cashuV3}`);
+		const block = blocks.find((candidate) => candidate.dataType === ContentData.CashuData);
+		const cashu = block?.data as CashuDataT;
+
+		expect(decode(block?.text ?? null)).toBe(cashuV3);
+		expect(decode(cashu.mint)).toBe('https://8333.space:3338');
+		expect(decode(cashu.unit)).toBe('sat');
+		expect(decode(cashu.memo)).toBe('Thank you very much.');
+		expect(cashu.amount).toBe(10n);
+		expect(cashu.proofs.map((proof) => decode(proof.id))).toEqual([
+			'009a1f293253e41e',
+			'009a1f293253e41e'
+		]);
+	});
+
+	it('decodes a V4 token', async () => {
+		const [block] = await parseContent(cashuV4);
+		const cashu = block?.data as CashuDataT;
+
+		expect(block?.dataType).toBe(ContentData.CashuData);
+		expect(decode(cashu.mint)).toBe('http://localhost:3338');
+		expect(decode(cashu.memo)).toBe('Thank you');
+		expect(cashu.amount).toBe(1n);
+		expect(decode(cashu.proofs[0]!.id)).toBe('00ad268c4d1f5826');
+		expect(decode(cashu.proofs[0]!.c)).toBe(
+			'038618543ffb6b8695df4ad4babcde92a34a96bdcd97dcee0d7ccf98d472126792'
+		);
+	});
+
+	it.each(['cashuAnotatoken', cashuV4.slice(0, -8)])(
+		'leaves an undecodable token as text: %s',
+		async (content) => {
+			const [block] = await parseContent(content);
+
+			expect(block?.dataType).toBe(ContentData.NONE);
+			expect(decode(block?.type ?? null)).toBe('text');
+		}
+	);
+});
@@ -12,6 +12,10 @@ import { MediaItemT } from 'src/generated/nostr/fb/media-item';
 import { LinkPreviewDataT } from 'src/generated/nostr/fb/link-preview-data';
 import { NostrDataT } from 'src/generated/nostr/fb/nostr-data';
 import { LightningDataT } from 'src/generated/nostr/fb/lightning-data';
+import { ProofT } from 'src/generated/nostr/fb/proof';
+import { DLEQProofT } from 'src/generated/nostr/fb/dleqproof';
+import { Witness } from 'src/generated/nostr/fb/witness';
+import { WitnessStringT } from 'src/generated/nostr/fb/witness-string';
 
 type MatchProcessor = (
 	match: RegExpExecArray
@@ -32,17 +36,26 @@ function bech32PolymodStep(checksum: number, value: number): number {
 	return next;
 }
 
-function isValidBolt11Hrp(hrp: string): boolean {
+const bolt11Networks: Record<string, string> = {
+	bcrt: 'regtest',
+	tbs: 'signet',
+	tb: 'testnet',
+	bc: 'bitcoin'
+};
+
+// Network and amount in msats (0n when the payer chooses) from a BOLT11 HRP.
+function parseBolt11Hrp(hrp: string): { network: string; amountMsats: bigint } | null {
 	const networkAndAmount = hrp.slice(2);
-	if (!hrp.startsWith('ln')) return false;
+	if (!hrp.startsWith('ln')) return null;
 
-	const network = ['bcrt', 'tbs', 'tb', 'bc'].find((candidate) =>
+	const prefix = ['bcrt', 'tbs', 'tb', 'bc'].find((candidate) =>
 		networkAndAmount.startsWith(candidate)
 	);
-	if (!network) return false;
+	if (!prefix) return null;
+	const network = bolt11Networks[prefix]!;
 
-	const amount = networkAndAmount.slice(network.length);
-	if (amount.length === 0) return true;
+	const amount = networkAndAmount.slice(prefix.length);
+	if (amount.length === 0) return { network, amountMsats: 0n };
 
 	const suffix = amount[amount.length - 1];
 	const multipliers: Record<string, bigint> = {
@@ -53,107 +66,395 @@ function isValidBolt11Hrp(hrp: string): boolean {
 	};
 	const multiplier = suffix && multipliers[suffix] ? multipliers[suffix] : 1_000_000_000_000n;
 	const digits = suffix && multipliers[suffix] ? amount.slice(0, -1) : amount;
-	if (!/^[1-9][0-9]*$/.test(digits)) return false;
+	if (!/^[1-9][0-9]*$/.test(digits)) return null;
 
 	let rawAmount = 0n;
 	const maxRawAmount = ((1n << 64n) - 1n) / multiplier;
 	for (const digit of digits) {
 		rawAmount = rawAmount * 10n + BigInt(digit);
-		if (rawAmount > maxRawAmount) return false;
+		if (rawAmount > maxRawAmount) return null;
 	}
 
-	return (rawAmount * multiplier) % 10n === 0n;
+	// One msat is ten pico-bitcoin; finer amounts are not payable.
+	const picoBtc = rawAmount * multiplier;
+	if (picoBtc % 10n !== 0n) return null;
+	return { network, amountMsats: picoBtc / 10n };
 }
 
-function isValidSecp256k1Scalar(bytes: number[]): boolean {
+function isValidSecp256k1Scalar(bytes: Uint8Array): boolean {
 	let scalar = 0n;
 	for (const byte of bytes) scalar = (scalar << 8n) | BigInt(byte);
 	return scalar > 0n && scalar < secp256k1Order;
 }
 
-// Recognize a checksum-valid, structurally well-formed BOLT11 envelope. Payment
-// code must still verify its signature, features, expiry, and payment policy.
-function isWellFormedBolt11(invoice: string): boolean {
-	if (!/^[\x00-\x7f]+$/.test(invoice)) return false;
-	if (invoice !== invoice.toLowerCase() && invoice !== invoice.toUpperCase()) return false;
+// Regroup 5-bit words into bytes, rejecting non-zero padding bits.
+function wordsToBytes(words: number[]): Uint8Array | null {
+	let accumulator = 0;
+	let bits = 0;
+	const bytes: number[] = [];
+	for (const word of words) {
+		accumulator = (accumulator << 5) | word;
+		bits += 5;
+		if (bits >= 8) {
+			bits -= 8;
+			bytes.push((accumulator >>> bits) & 0xff);
+			accumulator &= (1 << bits) - 1;
+		}
+	}
+	if (bits >= 5 || accumulator !== 0) return null;
+	return new Uint8Array(bytes);
+}
+
+const toHex = (bytes: Uint8Array): string =>
+	Array.from(bytes, (byte) => byte.toString(16).padStart(2, '0')).join('');
+
+// Decode a checksum-valid, structurally well-formed BOLT11 invoice. The payee
+// is only filled from an `n` field here; the Rust parser recovers it from the
+// signature. Payment code must still verify features, expiry, and policy.
+function decodeBolt11(invoice: string): LightningDataT | null {
+	if (!/^[\x00-\x7f]+$/.test(invoice)) return null;
+	if (invoice !== invoice.toLowerCase() && invoice !== invoice.toUpperCase()) return null;
 
 	const normalized = invoice.toLowerCase();
 	const separator = normalized.lastIndexOf('1');
-	if (separator <= 0 || separator === normalized.length - 1) return false;
+	if (separator <= 0 || separator === normalized.length - 1) return null;
 
 	const hrp = normalized.slice(0, separator);
 	const data = normalized.slice(separator + 1);
-	if (!isValidBolt11Hrp(hrp)) return false;
-	if (data.length < 117) return false;
+	const hrpInfo = parseBolt11Hrp(hrp);
+	if (!hrpInfo) return null;
+	if (data.length < 117) return null;
 
+	const words: number[] = [];
 	let checksum = 1;
 	for (const char of hrp) checksum = bech32PolymodStep(checksum, char.charCodeAt(0) >>> 5);
 	checksum = bech32PolymodStep(checksum, 0);
 	for (const char of hrp) checksum = bech32PolymodStep(checksum, char.charCodeAt(0) & 31);
 	for (const char of data) {
 		const value = bech32Charset.indexOf(char);
-		if (value === -1) return false;
+		if (value === -1) return null;
+		words.push(value);
 		checksum = bech32PolymodStep(checksum, value);
 	}
-	if (checksum !== 1) return false;
+	if (checksum !== 1) return null;
 
-	const taggedEnd = data.length - 6 - 104;
-	if (taggedEnd < 7) return false;
+	const taggedEnd = words.length - 6 - 104;
+	if (taggedEnd < 7) return null;
 
-	let index = 7;
+	let timestamp = 0n;
+	for (const word of words.slice(0, 7)) timestamp = (timestamp << 5n) | BigInt(word);
+
+	let expiry = 3600n;
+	let description: string | null = null;
+	let descriptionHash: string | null = null;
+	let paymentHash: string | null = null;
+	let payee: string | null = null;
 	let paymentHashes = 0;
 	let descriptions = 0;
+	let index = 7;
 	while (index < taggedEnd) {
-		if (index + 3 > taggedEnd) return false;
-		const tag = bech32Charset.indexOf(data[index]!);
-		const lengthHigh = bech32Charset.indexOf(data[index + 1]!);
-		const lengthLow = bech32Charset.indexOf(data[index + 2]!);
-		if (tag === -1 || lengthHigh === -1 || lengthLow === -1) return false;
-
-		const fieldLength = (lengthHigh << 5) | lengthLow;
+		if (index + 3 > taggedEnd) return null;
+		const tag = words[index]!;
+		const fieldLength = (words[index + 1]! << 5) | words[index + 2]!;
 		index += 3;
 		const fieldEnd = index + fieldLength;
-		if (fieldEnd > taggedEnd) return false;
+		if (fieldEnd > taggedEnd) return null;
+		const field = words.slice(index, fieldEnd);
+		index = fieldEnd;
 
-		if (tag === 1) {
-			if (fieldLength !== 52 || (bech32Charset.indexOf(data[fieldEnd - 1]!) & 15) !== 0) {
-				return false;
+		if (tag === 1 || tag === 23) {
+			const hash = fieldLength === 52 ? wordsToBytes(field) : null;
+			if (!hash) return null;
+			if (tag === 1) {
+				paymentHash = toHex(hash);
+				paymentHashes++;
+			} else {
+				descriptionHash = toHex(hash);
+				descriptions++;
 			}
-			paymentHashes++;
 		} else if (tag === 13) {
-			descriptions++;
-		} else if (tag === 23) {
-			if (fieldLength !== 52 || (bech32Charset.indexOf(data[fieldEnd - 1]!) & 15) !== 0) {
-				return false;
+			const bytes = wordsToBytes(field);
+			if (!bytes) return null;
+			try {
+				description = new TextDecoder('utf-8', { fatal: true }).decode(bytes);
+			} catch {
+				return null;
 			}
 			descriptions++;
+		} else if (tag === 6) {
+			if (fieldLength > 12) return null;
+			expiry = field.reduce((value, word) => (value << 5n) | BigInt(word), 0n);
+		} else if (tag === 19) {
+			const node = fieldLength === 53 ? wordsToBytes(field) : null;
+			if (!node) return null;
+			payee = toHex(node);
 		}
-		index = fieldEnd;
 	}
-	if (paymentHashes !== 1 || descriptions !== 1) return false;
+	if (paymentHashes !== 1 || descriptions !== 1) return null;
+
+	// The final 104 data words before the checksum encode a 65-byte compact
+	// signature. Its last byte is the recovery id and must be 0..=3.
+	const signature = wordsToBytes(words.slice(taggedEnd, -6));
+	if (
+		!signature ||
+		signature.length !== 65 ||
+		signature[64]! > 3 ||
+		!isValidSecp256k1Scalar(signature.slice(0, 32)) ||
+		!isValidSecp256k1Scalar(signature.slice(32, 64))
+	) {
+		return null;
+	}
 
-	let accumulator = 0;
-	let bits = 0;
-	const signatureBytes: number[] = [];
-	for (const char of data.slice(taggedEnd, -6)) {
-		const value = bech32Charset.indexOf(char);
-		if (value === -1) return false;
-		accumulator = (accumulator << 5) | value;
-		bits += 5;
-		if (bits >= 8) {
-			bits -= 8;
-			const decoded = (accumulator >>> bits) & 0xff;
-			signatureBytes.push(decoded);
-			accumulator &= (1 << bits) - 1;
+	const encode = (value: string | null) => (value === null ? null : textEncoder.encode(value));
+	return new LightningDataT(
+		textEncoder.encode(invoice),
+		textEncoder.encode(hrpInfo.network),
+		hrpInfo.amountMsats,
+		timestamp,
+		expiry,
+		encode(description),
+		encode(descriptionHash),
+		encode(paymentHash),
+		encode(payee)
+	);
+}
+
+type CborValue = bigint | Uint8Array | string | boolean | null | CborValue[] | Map<string, CborValue>;
+
+const maxUint64 = (1n << 64n) - 1n;
+
+// Standard or URL-safe base64, padding optional.
+function base64ToBytes(data: string): Uint8Array | null {
+	const normalized = data.replace(/=+$/, '').replace(/-/g, '+').replace(/_/g, '/');
+	if (!/^[A-Za-z0-9+/]*$/.test(normalized) || normalized.length % 4 === 1) return null;
+	try {
+		const binary = atob(normalized + '='.repeat((4 - (normalized.length % 4)) % 4));
+		return Uint8Array.from(binary, (char) => char.charCodeAt(0));
+	} catch {
+		return null;
+	}
+}
+
+// Definite-length CBOR as used by V4 Cashu tokens. Negative integers,
+// floats, indefinite lengths and non-text map keys are rejected.
+function readCbor(bytes: Uint8Array): CborValue | undefined {
+	let pos = 0;
+	const take = (length: number): Uint8Array | undefined => {
+		if (length > bytes.length - pos) return undefined;
+		pos += length;
+		return bytes.subarray(pos - length, pos);
+	};
+
+	const read = (depth: number): CborValue | undefined => {
+		if (depth > 16) return undefined;
+		const initial = take(1)?.[0];
+		if (initial === undefined) return undefined;
+		const info = initial & 0x1f;
+		let argument: bigint;
+		if (info < 24) {
+			argument = BigInt(info);
+		} else if (info <= 27) {
+			const raw = take(1 << (info - 24));
+			if (!raw) return undefined;
+			argument = raw.reduce((value, byte) => (value << 8n) | BigInt(byte), 0n);
+		} else {
+			return undefined;
+		}
+		// Lengths never exceed the remaining input
+		const length = argument > BigInt(bytes.length - pos) ? Infinity : Number(argument);
+
+		switch (initial >> 5) {
+			case 0:
+				return argument;
+			case 2:
+				return take(length);
+			case 3: {
+				const text = take(length);
+				if (!text) return undefined;
+				try {
+					return new TextDecoder('utf-8', { fatal: true }).decode(text);
+				} catch {
+					return undefined;
+				}
+			}
+			case 4: {
+				if (length === Infinity) return undefined;
+				const items: CborValue[] = [];
+				for (let i = 0; i < length; i++) {
+					const item = read(depth + 1);
+					if (item === undefined) return undefined;
+					items.push(item);
+				}
+				return items;
+			}
+			case 5: {
+				if (length === Infinity) return undefined;
+				const entries = new Map<string, CborValue>();
+				for (let i = 0; i < length; i++) {
+					const key = read(depth + 1);
+					const value = read(depth + 1);
+					if (typeof key !== 'string' || value === undefined) return undefined;
+					entries.set(key, value);
+				}
+				return entries;
+			}
+			case 6:
+				return read(depth + 1);
+			case 7:
+				if (info === 20) return false;
+				if (info === 21) return true;
+				if (info === 22 || info === 23) return null;
+				return undefined;
 		}
+		return undefined;
+	};
+
+	const value = read(0);
+	return pos === bytes.length ? value : undefined;
+}
+
+const cborMap = (value: CborValue | undefined): Map<string, CborValue> | null =>
+	value instanceof Map ? value : null;
+const cborBytes = (value: CborValue | undefined): Uint8Array | null =>
+	value instanceof Uint8Array ? value : null;
+const cborText = (value: CborValue | undefined): string | null =>
+	typeof value === 'string' ? value : null;
+
+function cashuProof(
+	amount: bigint,
+	id: string,
+	secret: string,
+	c: string,
+	dleq: DLEQProofT | null,
+	witness: string | null
+): ProofT {
+	return new ProofT(
+		amount,
+		textEncoder.encode(id),
+		textEncoder.encode(secret),
+		textEncoder.encode(c),
+		dleq,
+		witness === null ? Witness.NONE : Witness.WitnessString,
+		witness === null ? null : new WitnessStringT(textEncoder.encode(witness))
+	);
+}
+
+// V3: {"token":[{"mint","proofs":[...]}],"unit","memo"}. Tokens spanning
+// several mints are not decoded.
+function decodeCashuV3(
+	body: Uint8Array
+): { mint: string; unit: string | null; memo: string | null; proofs: ProofT[] } | null {
+	let json: any;
+	try {
+		json = JSON.parse(new TextDecoder('utf-8', { fatal: true }).decode(body));
+	} catch {
+		return null;
 	}
+	const entries = json?.token;
+	if (!Array.isArray(entries) || entries.length !== 1) return null;
+	const entry = entries[0];
+	if (typeof entry?.mint !== 'string' || !Array.isArray(entry.proofs)) return null;
+
+	const proofs: ProofT[] = [];
+	for (const proof of entry.proofs) {
+		const { amount, id, secret, C: c, dleq, witness } = proof ?? {};
+		if (
+			!Number.isSafeInteger(amount) ||
+			amount < 0 ||
+			typeof id !== 'string' ||
+			typeof secret !== 'string' ||
+			typeof c !== 'string' ||
+			(witness != null && typeof witness !== 'string')
+		) {
+			return null;
+		}
+		let dleqProof: DLEQProofT | null = null;
+		if (dleq != null) {
+			if (typeof dleq.e !== 'string' || typeof dleq.s !== 'string') return null;
+			const r = typeof dleq.r === 'string' ? textEncoder.encode(dleq.r) : null;
+			dleqProof = new DLEQProofT(textEncoder.encode(dleq.e), textEncoder.encode(dleq.s), r);
+		}
+		proofs.push(cashuProof(BigInt(amount), id, secret, c, dleqProof, witness ?? null));
+	}
+
+	return {
+		mint: entry.mint,
+		unit: typeof json.unit === 'string' ? json.unit : null,
+		memo: typeof json.memo === 'string' ? json.memo : null,
+		proofs
+	};
+}
+
+// V4: {"m": mint, "u": unit, "d": memo, "t": [{"i": keyset id, "p": [...]}]}
+// with keyset ids, C and DLEQ values as raw bytes.
+function decodeCashuV4(
+	body: Uint8Array
+): { mint: string; unit: string | null; memo: string | null; proofs: ProofT[] } | null {
+	const token = cborMap(readCbor(body));
+	const mint = cborText(token?.get('m'));
+	const entries = token?.get('t');
+	if (!token || mint === null || !Array.isArray(entries)) return null;
+
+	const proofs: ProofT[] = [];
+	for (const entry of entries) {
+		const id = cborBytes(cborMap(entry)?.get('i'));
+		const entryProofs = cborMap(entry)?.get('p');
+		if (!id || !Array.isArray(entryProofs)) return null;
+		for (const value of entryProofs) {
+			const proof = cborMap(value);
+			const amount = proof?.get('a');
+			const secret = cborText(proof?.get('s'));
+			const c = cborBytes(proof?.get('c'));
+			if (!proof || typeof amount !== 'bigint' || secret === null || !c) return null;
+
+			let dleqProof: DLEQProofT | null = null;
+			if (proof.has('d')) {
+				const dleq = cborMap(proof.get('d'));
+				const e = cborBytes(dleq?.get('e'));
+				const s = cborBytes(dleq?.get('s'));
+				if (!e || !s) return null;
+				const r = cborBytes(dleq?.get('r'));
+				dleqProof = new DLEQProofT(
+					textEncoder.encode(toHex(e)),
+					textEncoder.encode(toHex(s)),
+					r ? textEncoder.encode(toHex(r)) : null
+				);
+			}
+			proofs.push(
+				cashuProof(amount, toHex(id), secret, toHex(c), dleqProof, cborText(proof.get('w')))
+			);
+		}
+	}
+
+	return {
+		mint,
+		unit: cborText(token.get('u')),
+		memo: cborText(token.get('d')),
+		proofs
+	};
+}
 
-	return (
-		signatureBytes.length === 65 &&
-		bits === 0 &&
-		signatureBytes[64]! <= 3 &&
-		isValidSecp256k1Scalar(signatureBytes.slice(0, 32)) &&
-		isValidSecp256k1Scalar(signatureBytes.slice(32, 64))
+// Decodes a cashuA (V3) or cashuB (V4) token. Whether its proofs are
+// unspent is only known to the mint.
+function decodeCashu(token: string): CashuDataT | null {
+	const body = base64ToBytes(token.slice(6));
+	if (!body) return null;
+	const decoded = token.startsWith('cashuA')
+		? decodeCashuV3(body)
+		: token.startsWith('cashuB')
+			? decodeCashuV4(body)
+			: null;
+	if (!decoded || decoded.mint.length === 0 || decoded.proofs.length === 0) return null;
+
+	let amount = decoded.proofs.reduce((sum, proof) => sum + proof.amount, 0n);
+	if (amount > maxUint64) amount = maxUint64;
+	return new CashuDataT(
+		textEncoder.encode(token),
+		textEncoder.encode(decoded.mint),
+		textEncoder.encode(decoded.unit ?? 'sat'),
+		decoded.memo === null ? null : textEncoder.encode(decoded.memo),
+		amount,
+		decoded.proofs
 	);
 }
 
@@ -203,24 +504,29 @@ export async function parseContent(content: string): Promise<ContentBlockT[]> {
 		);
 	};
 
-	const cashuBlock = (token: string): ContentBlockT =>
-		new ContentBlockT(
+	const cashuBlock = (token: string): ContentBlockT | null => {
+		const cashu = decodeCashu(token);
+		if (!cashu) return null;
+
+		return new ContentBlockT(
 			textEncoder.encode('cashu'),
 			textEncoder.encode(token),
 			ContentData.CashuData,
-			new CashuDataT(textEncoder.encode(token))
+			cashu
 		);
+	};
 
 	const lightningBlock = (text: string): ContentBlockT | null => {
 		const invoice = text.slice(0, 10).toLowerCase() === 'lightning:' ? text.slice(10) : text;
 
-		if (!isWellFormedBolt11(invoice)) return null;
+		const lightning = decodeBolt11(invoice);
+		if (!lightning) return null;
 
 		return new ContentBlockT(
 			textEncoder.encode('lightning'),
 			textEncoder.encode(text),
 			ContentData.LightningData,
-			new LightningDataT(textEncoder.encode(invoice))
+			lightning
 		);
 	};
 
@@ -320,7 +626,7 @@ export async function parseContent(content: string): Promise<ContentBlockT[]> {
 		},
 		{
 			type: 'cashu',
-			regex: /(cashuA[A-Za-z0-9_-]+)/g,
+			regex: /(cashu[AB][A-Za-z0-9_-]+={0,2})/g,
 			processMatch: (match) => cashuBlock(match[0])
 		},
 		{
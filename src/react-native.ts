@@ -534,7 +534,14 @@ export class ReactNativeManager extends BaseBackend {
 			options.bytesPerEvent,
 			options.isSlow,
 			options.pagination ? this.textEncoder.encode(options.pagination) : null,
-			options.cacheOnly
+			options.cacheOnly,
+			options.mergedWindow,
+			options.linkPreviews,
+			options.thread ? this.textEncoder.encode(options.thread) : null,
+			options.resolveMentions,
+			BigInt(options.trafficBudget?.maxBytes ?? 0),
+			BigInt(options.trafficBudget?.maxEvents ?? 0),
+			options.verifyNip05 ?? false
 		);
 		const subscribeT = new SubscribeT(
 			this.textEncoder.encode(subId),
@@ -556,7 +563,9 @@ export class ReactNativeManager extends BaseBackend {
 						r.noCache,
 						r.maxRelays,
 						options.cacheOnly,
-						r.meshOnly
+						r.meshOnly,
+						r.negentropy,
+						r.background
 					)
 			),
 			optionsT
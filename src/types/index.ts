@@ -40,6 +40,10 @@ export type RequestObject = {
 	maxRelays?: number;
 	/** Only return events learned through the nearby Bluetooth mesh. */
 	meshOnly?: boolean;
+	/** Reconcile with relays via NIP-77 and fetch only events missing from the cache. */
+	negentropy?: boolean;
+	/** Background prefetch: relays get this REQ after foreground subscriptions and publishes. */
+	background?: boolean;
 };
 
 // export type PipeConfig = {
@@ -68,6 +72,32 @@ export type SubscriptionConfig = {
 	pagination?: string;
 	/** If true, only return events from cache without hitting the network. */
 	cacheOnly?: boolean;
+	/** Hold cache and relay results until EOSE (or a deadline), then emit the newest `limit`
+	 * events merged across all sources; later events only if they fall inside that window. */
+	mergedWindow?: boolean;
+	/** Fetch OpenGraph previews for links in parsed content; each resolved preview arrives as a
+	 * `LinkPreviewData` message keyed by URL. Off by default: fetching contacts the linked sites. */
+	linkPreviews?: boolean;
+	/** Resolve the thread around this event id instead of running the filters: its root,
+	 * ancestors and replies are fetched in rounds and `ThreadTree` messages follow as the tree fills in.
+	 * Relays listed on the requests are used as hints for the focus event. */
+	thread?: string;
+	/** Look up the profiles and events that `nostr:` references in parsed content point at, in the
+	 * cache and then on relays, batched across subscriptions. Each resolved reference arrives as a
+	 * `MentionData` message keyed by the reference's `NostrData.id`. */
+	resolveMentions?: boolean;
+	/** Close the subscription on every relay once it has moved `maxBytes` (in and out) or received
+	 * `maxEvents` EVENT frames, duplicates included; the app then gets a `budget-exceeded:` CLOSED.
+	 * For data-saver modes on metered connections. */
+	trafficBudget?: TrafficBudget;
+	/** Look up the NIP-05 identifiers of parsed profiles that have no cached verdict and re-emit
+	 * each profile once `nip05Verified` changes. Off by default: lookups contact the domains. */
+	verifyNip05?: boolean;
+};
+
+export type TrafficBudget = {
+	maxBytes?: number;
+	maxEvents?: number;
 };
 
 export type ProxyConfig = {
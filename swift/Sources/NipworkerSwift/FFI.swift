@@ -30,6 +30,17 @@ func nipworker_shared_acquire(
     _ meshEnabled: Bool
 ) -> UnsafeMutableRawPointer?
 
+@_silgen_name("nipworker_shared_acquire_with_transport")
+func nipworker_shared_acquire_with_transport(
+    _ callback: @convention(c) (UnsafeMutableRawPointer?, UnsafePointer<UInt8>?, Int) -> Void,
+    _ userdata: UnsafeMutableRawPointer?,
+    _ storagePath: UnsafePointer<Int8>?,
+    _ defaultRelays: UnsafePointer<Int8>?,
+    _ indexerRelays: UnsafePointer<Int8>?,
+    _ meshEnabled: Bool,
+    _ transportJSON: UnsafePointer<Int8>?
+) -> UnsafeMutableRawPointer?
+
 @_silgen_name("nipworker_shared_release")
 func nipworker_shared_release(
     _ handle: UnsafeMutableRawPointer?,
@@ -81,6 +92,12 @@ func nipworker_set_private_key(
 @_silgen_name("nipworker_deinit")
 func nipworker_deinit(_ handle: UnsafeMutableRawPointer?)
 
+@_silgen_name("nipworker_relay_health")
+func nipworker_relay_health(
+    _ handle: UnsafeMutableRawPointer?,
+    _ outputLength: UnsafeMutablePointer<Int>?
+) -> UnsafeMutablePointer<UInt8>?
+
 @_silgen_name("nipworker_free_bytes")
 func nipworker_free_bytes(_ ptr: UnsafeMutablePointer<UInt8>?, _ len: Int)
 
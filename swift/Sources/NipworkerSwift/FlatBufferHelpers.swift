@@ -42,7 +42,9 @@ func buildSubscribeMessage(
             noCache: req.noCache ?? false,
             maxRelays: req.maxRelays ?? 0,
             cacheOnly: options.cacheOnly,
-            meshOnly: req.meshOnly ?? false
+            meshOnly: req.meshOnly ?? false,
+            negentropy: req.negentropy ?? false,
+            background: req.background ?? false
         )
     }
 
@@ -99,6 +101,12 @@ func buildSubscribeMessage(
                 &builder,
                 maxProofs: maxProofs
             )
+        case .zapValidation(let dropInvalid):
+            configType = .zapvalidationpipeconfig
+            configOffset = nostr_fb_ZapValidationPipeConfig.createZapValidationPipeConfig(
+                &builder,
+                dropInvalid: dropInvalid
+            )
         }
         return nostr_fb_Pipe.createPipe(&builder, configType: configType, configOffset: configOffset)
     }) ?? [
@@ -125,7 +133,14 @@ func buildSubscribeMessage(
         bytesPerEvent: options.bytesPerEvent,
         isSlow: options.isSlow,
         paginationOffset: options.pagination.map { builder.create(string: $0) } ?? Offset(),
-        cacheOnly: options.cacheOnly
+        cacheOnly: options.cacheOnly,
+        mergedWindow: options.mergedWindow,
+        linkPreviews: options.linkPreviews,
+        threadOffset: options.thread.map { builder.create(string: $0) } ?? Offset(),
+        resolveMentions: options.resolveMentions,
+        budgetBytes: options.trafficBudget?.maxBytes ?? 0,
+        budgetEvents: options.trafficBudget?.maxEvents ?? 0,
+        verifyNip05: options.verifyNip05
     )
 
     let subIdOffset = builder.create(string: subId)
@@ -184,8 +184,9 @@ public enum nostr_fb_PipeConfig: UInt8, UnionEnum {
   case serializeeventspipeconfig = 7
   case proofverificationpipeconfig = 8
   case mutefilterpipeconfig = 9
+  case zapvalidationpipeconfig = 10
 
-  public static var max: nostr_fb_PipeConfig { return .mutefilterpipeconfig }
+  public static var max: nostr_fb_PipeConfig { return .zapvalidationpipeconfig }
   public static var min: nostr_fb_PipeConfig { return .none_ }
 }
 
@@ -240,8 +241,11 @@ public enum nostr_fb_MessageType: UInt32, Enum, Verifiable {
   case nostrevent = 9
   case setsignerresponse = 10
   case authurl = 11
+  case linkpreview = 12
+  case threadtree = 13
+  case mention = 14
 
-  public static var max: nostr_fb_MessageType { return .authurl }
+  public static var max: nostr_fb_MessageType { return .mention }
   public static var min: nostr_fb_MessageType { return .parsednostrevent }
 }
 
@@ -257,6 +261,7 @@ public enum nostr_fb_ParsedDataUnion: UInt32, Enum, Verifiable {
   case kind6parsed = 6
   case kind7parsed = 7
   case kind8parsed = 8
+  case kind14parsed = 14
   case kind17parsed = 17
   case kind20parsed = 20
   case kind22parsed = 22
@@ -276,8 +281,9 @@ public enum nostr_fb_ParsedDataUnion: UInt32, Enum, Verifiable {
   case kind39089parsed = 39089
   case listparsed = 51000
   case pregenericparsed = 52000
+  case customparsed = 60000
 
-  public static var max: nostr_fb_ParsedDataUnion { return .pregenericparsed }
+  public static var max: nostr_fb_ParsedDataUnion { return .customparsed }
   public static var min: nostr_fb_ParsedDataUnion { return .kind0parsed }
 }
 
@@ -317,8 +323,10 @@ public enum nostr_fb_ParsedData: UInt8, UnionEnum {
   case kind30023parsed = 23
   case listparsed = 24
   case pregenericparsed = 25
+  case kind14parsed = 26
+  case customparsed = 27
 
-  public static var max: nostr_fb_ParsedData { return .pregenericparsed }
+  public static var max: nostr_fb_ParsedData { return .customparsed }
   public static var min: nostr_fb_ParsedData { return .none_ }
 }
 
@@ -345,8 +353,11 @@ public enum nostr_fb_Message: UInt8, UnionEnum {
   case raw = 10
   case setsignerresponse = 11
   case authurl = 12
+  case linkpreviewdata = 13
+  case threadtree = 14
+  case mentiondata = 15
 
-  public static var max: nostr_fb_Message { return .authurl }
+  public static var max: nostr_fb_Message { return .mentiondata }
   public static var min: nostr_fb_Message { return .none_ }
 }
 
@@ -511,27 +522,62 @@ public struct nostr_fb_CashuData: FlatBufferObject, Verifiable {
 
   private enum VTOFFSET: VOffset {
     case token = 4
+    case mint = 6
+    case unit = 8
+    case memo = 10
+    case amount = 12
+    case proofs = 14
     var v: Int32 { Int32(self.rawValue) }
     var p: VOffset { self.rawValue }
   }
 
   public var token: String! { let o = _accessor.offset(VTOFFSET.token.v); return _accessor.string(at: o) }
   public var tokenSegmentArray: [UInt8]! { return _accessor.getVector(at: VTOFFSET.token.v) }
-  public static func startCashuData(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 1) }
+  public var mint: String? { let o = _accessor.offset(VTOFFSET.mint.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var mintSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.mint.v) }
+  public var unit: String? { let o = _accessor.offset(VTOFFSET.unit.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var unitSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.unit.v) }
+  public var memo: String? { let o = _accessor.offset(VTOFFSET.memo.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var memoSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.memo.v) }
+  public var amount: UInt64 { let o = _accessor.offset(VTOFFSET.amount.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt64.self, at: o) }
+  public var hasProofs: Bool { let o = _accessor.offset(VTOFFSET.proofs.v); return o == 0 ? false : true }
+  public var proofsCount: Int32 { let o = _accessor.offset(VTOFFSET.proofs.v); return o == 0 ? 0 : _accessor.vector(count: o) }
+  public func proofs(at index: Int32) -> nostr_fb_Proof? { let o = _accessor.offset(VTOFFSET.proofs.v); return o == 0 ? nil : nostr_fb_Proof(_accessor.bb, o: _accessor.indirect(_accessor.vector(at: o) + index * 4)) }
+  public static func startCashuData(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 6) }
   public static func add(token: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: token, at: VTOFFSET.token.p) }
+  public static func add(mint: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: mint, at: VTOFFSET.mint.p) }
+  public static func add(unit: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: unit, at: VTOFFSET.unit.p) }
+  public static func add(memo: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: memo, at: VTOFFSET.memo.p) }
+  public static func add(amount: UInt64, _ fbb: inout FlatBufferBuilder) { fbb.add(element: amount, def: 0, at: VTOFFSET.amount.p) }
+  public static func addVectorOf(proofs: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: proofs, at: VTOFFSET.proofs.p) }
   public static func endCashuData(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); fbb.require(table: end, fields: [4]); return end }
   public static func createCashuData(
     _ fbb: inout FlatBufferBuilder,
-    tokenOffset token: Offset
+    tokenOffset token: Offset,
+    mintOffset mint: Offset = Offset(),
+    unitOffset unit: Offset = Offset(),
+    memoOffset memo: Offset = Offset(),
+    amount: UInt64 = 0,
+    proofsVectorOffset proofs: Offset = Offset()
   ) -> Offset {
     let __start = nostr_fb_CashuData.startCashuData(&fbb)
     nostr_fb_CashuData.add(token: token, &fbb)
+    nostr_fb_CashuData.add(mint: mint, &fbb)
+    nostr_fb_CashuData.add(unit: unit, &fbb)
+    nostr_fb_CashuData.add(memo: memo, &fbb)
+    nostr_fb_CashuData.add(amount: amount, &fbb)
+    nostr_fb_CashuData.addVectorOf(proofs: proofs, &fbb)
     return nostr_fb_CashuData.endCashuData(&fbb, start: __start)
   }
 
   public static func verify<T>(_ verifier: inout Verifier, at position: Int, of type: T.Type) throws where T: Verifiable {
     var _v = try verifier.visitTable(at: position)
     try _v.visit(field: VTOFFSET.token.p, fieldName: "token", required: true, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.mint.p, fieldName: "mint", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.unit.p, fieldName: "unit", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.memo.p, fieldName: "memo", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.amount.p, fieldName: "amount", required: false, type: UInt64.self)
+    try _v.visit(field: VTOFFSET.proofs.p, fieldName: "proofs", required: false, type: ForwardOffset<Vector<ForwardOffset<nostr_fb_Proof>, nostr_fb_Proof>>.self)
     _v.finish()
   }
 }
@@ -547,27 +593,80 @@ public struct nostr_fb_LightningData: FlatBufferObject, Verifiable {
 
   private enum VTOFFSET: VOffset {
     case invoice = 4
+    case network = 6
+    case amountMsats = 8
+    case timestamp = 10
+    case expiry = 12
+    case description = 14
+    case descriptionHash = 16
+    case paymentHash = 18
+    case payee = 20
     var v: Int32 { Int32(self.rawValue) }
     var p: VOffset { self.rawValue }
   }
 
   public var invoice: String! { let o = _accessor.offset(VTOFFSET.invoice.v); return _accessor.string(at: o) }
   public var invoiceSegmentArray: [UInt8]! { return _accessor.getVector(at: VTOFFSET.invoice.v) }
-  public static func startLightningData(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 1) }
+  public var network: String? { let o = _accessor.offset(VTOFFSET.network.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var networkSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.network.v) }
+  public var amountMsats: UInt64 { let o = _accessor.offset(VTOFFSET.amountMsats.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt64.self, at: o) }
+  public var timestamp: UInt64 { let o = _accessor.offset(VTOFFSET.timestamp.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt64.self, at: o) }
+  public var expiry: UInt64 { let o = _accessor.offset(VTOFFSET.expiry.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt64.self, at: o) }
+  public var description: String? { let o = _accessor.offset(VTOFFSET.description.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var descriptionSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.description.v) }
+  public var descriptionHash: String? { let o = _accessor.offset(VTOFFSET.descriptionHash.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var descriptionHashSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.descriptionHash.v) }
+  public var paymentHash: String? { let o = _accessor.offset(VTOFFSET.paymentHash.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var paymentHashSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.paymentHash.v) }
+  public var payee: String? { let o = _accessor.offset(VTOFFSET.payee.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var payeeSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.payee.v) }
+  public static func startLightningData(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 9) }
   public static func add(invoice: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: invoice, at: VTOFFSET.invoice.p) }
+  public static func add(network: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: network, at: VTOFFSET.network.p) }
+  public static func add(amountMsats: UInt64, _ fbb: inout FlatBufferBuilder) { fbb.add(element: amountMsats, def: 0, at: VTOFFSET.amountMsats.p) }
+  public static func add(timestamp: UInt64, _ fbb: inout FlatBufferBuilder) { fbb.add(element: timestamp, def: 0, at: VTOFFSET.timestamp.p) }
+  public static func add(expiry: UInt64, _ fbb: inout FlatBufferBuilder) { fbb.add(element: expiry, def: 0, at: VTOFFSET.expiry.p) }
+  public static func add(description: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: description, at: VTOFFSET.description.p) }
+  public static func add(descriptionHash: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: descriptionHash, at: VTOFFSET.descriptionHash.p) }
+  public static func add(paymentHash: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: paymentHash, at: VTOFFSET.paymentHash.p) }
+  public static func add(payee: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: payee, at: VTOFFSET.payee.p) }
   public static func endLightningData(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); fbb.require(table: end, fields: [4]); return end }
   public static func createLightningData(
     _ fbb: inout FlatBufferBuilder,
-    invoiceOffset invoice: Offset
+    invoiceOffset invoice: Offset,
+    networkOffset network: Offset = Offset(),
+    amountMsats: UInt64 = 0,
+    timestamp: UInt64 = 0,
+    expiry: UInt64 = 0,
+    descriptionOffset description: Offset = Offset(),
+    descriptionHashOffset descriptionHash: Offset = Offset(),
+    paymentHashOffset paymentHash: Offset = Offset(),
+    payeeOffset payee: Offset = Offset()
   ) -> Offset {
     let __start = nostr_fb_LightningData.startLightningData(&fbb)
     nostr_fb_LightningData.add(invoice: invoice, &fbb)
+    nostr_fb_LightningData.add(network: network, &fbb)
+    nostr_fb_LightningData.add(amountMsats: amountMsats, &fbb)
+    nostr_fb_LightningData.add(timestamp: timestamp, &fbb)
+    nostr_fb_LightningData.add(expiry: expiry, &fbb)
+    nostr_fb_LightningData.add(description: description, &fbb)
+    nostr_fb_LightningData.add(descriptionHash: descriptionHash, &fbb)
+    nostr_fb_LightningData.add(paymentHash: paymentHash, &fbb)
+    nostr_fb_LightningData.add(payee: payee, &fbb)
     return nostr_fb_LightningData.endLightningData(&fbb, start: __start)
   }
 
   public static func verify<T>(_ verifier: inout Verifier, at position: Int, of type: T.Type) throws where T: Verifiable {
     var _v = try verifier.visitTable(at: position)
     try _v.visit(field: VTOFFSET.invoice.p, fieldName: "invoice", required: true, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.network.p, fieldName: "network", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.amountMsats.p, fieldName: "amountMsats", required: false, type: UInt64.self)
+    try _v.visit(field: VTOFFSET.timestamp.p, fieldName: "timestamp", required: false, type: UInt64.self)
+    try _v.visit(field: VTOFFSET.expiry.p, fieldName: "expiry", required: false, type: UInt64.self)
+    try _v.visit(field: VTOFFSET.description.p, fieldName: "description", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.descriptionHash.p, fieldName: "descriptionHash", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.paymentHash.p, fieldName: "paymentHash", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.payee.p, fieldName: "payee", required: false, type: ForwardOffset<String>.self)
     _v.finish()
   }
 }
@@ -1960,6 +2059,8 @@ public struct nostr_fb_Request: FlatBufferObject, Verifiable {
     case maxRelays = 28
     case cacheOnly = 30
     case meshOnly = 32
+    case negentropy = 34
+    case background = 36
     var v: Int32 { Int32(self.rawValue) }
     var p: VOffset { self.rawValue }
   }
@@ -1990,7 +2091,9 @@ public struct nostr_fb_Request: FlatBufferObject, Verifiable {
   public var maxRelays: UInt16 { let o = _accessor.offset(VTOFFSET.maxRelays.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt16.self, at: o) }
   public var cacheOnly: Bool { let o = _accessor.offset(VTOFFSET.cacheOnly.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
   public var meshOnly: Bool { let o = _accessor.offset(VTOFFSET.meshOnly.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
-  public static func startRequest(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 15) }
+  public var negentropy: Bool { let o = _accessor.offset(VTOFFSET.negentropy.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public var background: Bool { let o = _accessor.offset(VTOFFSET.background.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public static func startRequest(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 17) }
   public static func addVectorOf(ids: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: ids, at: VTOFFSET.ids.p) }
   public static func addVectorOf(authors: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: authors, at: VTOFFSET.authors.p) }
   public static func addVectorOf(kinds: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: kinds, at: VTOFFSET.kinds.p) }
@@ -2009,6 +2112,10 @@ public struct nostr_fb_Request: FlatBufferObject, Verifiable {
    at: VTOFFSET.cacheOnly.p) }
   public static func add(meshOnly: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: meshOnly, def: false,
    at: VTOFFSET.meshOnly.p) }
+  public static func add(negentropy: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: negentropy, def: false,
+   at: VTOFFSET.negentropy.p) }
+  public static func add(background: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: background, def: false,
+   at: VTOFFSET.background.p) }
   public static func endRequest(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); return end }
   public static func createRequest(
     _ fbb: inout FlatBufferBuilder,
@@ -2025,7 +2132,9 @@ public struct nostr_fb_Request: FlatBufferObject, Verifiable {
     noCache: Bool = false,
     maxRelays: UInt16 = 0,
     cacheOnly: Bool = false,
-    meshOnly: Bool = false
+    meshOnly: Bool = false,
+    negentropy: Bool = false,
+    background: Bool = false
   ) -> Offset {
     let __start = nostr_fb_Request.startRequest(&fbb)
     nostr_fb_Request.addVectorOf(ids: ids, &fbb)
@@ -2042,6 +2151,8 @@ public struct nostr_fb_Request: FlatBufferObject, Verifiable {
     nostr_fb_Request.add(maxRelays: maxRelays, &fbb)
     nostr_fb_Request.add(cacheOnly: cacheOnly, &fbb)
     nostr_fb_Request.add(meshOnly: meshOnly, &fbb)
+    nostr_fb_Request.add(negentropy: negentropy, &fbb)
+    nostr_fb_Request.add(background: background, &fbb)
     return nostr_fb_Request.endRequest(&fbb, start: __start)
   }
 
@@ -2061,6 +2172,8 @@ public struct nostr_fb_Request: FlatBufferObject, Verifiable {
     try _v.visit(field: VTOFFSET.maxRelays.p, fieldName: "maxRelays", required: false, type: UInt16.self)
     try _v.visit(field: VTOFFSET.cacheOnly.p, fieldName: "cacheOnly", required: false, type: Bool.self)
     try _v.visit(field: VTOFFSET.meshOnly.p, fieldName: "meshOnly", required: false, type: Bool.self)
+    try _v.visit(field: VTOFFSET.negentropy.p, fieldName: "negentropy", required: false, type: Bool.self)
+    try _v.visit(field: VTOFFSET.background.p, fieldName: "background", required: false, type: Bool.self)
     _v.finish()
   }
 }
@@ -2649,6 +2762,42 @@ public struct nostr_fb_MuteFilterPipeConfig: FlatBufferObject, Verifiable {
   }
 }
 
+public struct nostr_fb_ZapValidationPipeConfig: FlatBufferObject, Verifiable {
+
+  static func validateVersion() { FlatBuffersVersion_25_2_10() }
+  public var __buffer: ByteBuffer! { return _accessor.bb }
+  private var _accessor: Table
+
+  private init(_ t: Table) { _accessor = t }
+  public init(_ bb: ByteBuffer, o: Int32) { _accessor = Table(bb: bb, position: o) }
+
+  private enum VTOFFSET: VOffset {
+    case dropInvalid = 4
+    var v: Int32 { Int32(self.rawValue) }
+    var p: VOffset { self.rawValue }
+  }
+
+  public var dropInvalid: Bool { let o = _accessor.offset(VTOFFSET.dropInvalid.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public static func startZapValidationPipeConfig(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 1) }
+  public static func add(dropInvalid: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: dropInvalid, def: false,
+   at: VTOFFSET.dropInvalid.p) }
+  public static func endZapValidationPipeConfig(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); return end }
+  public static func createZapValidationPipeConfig(
+    _ fbb: inout FlatBufferBuilder,
+    dropInvalid: Bool = false
+  ) -> Offset {
+    let __start = nostr_fb_ZapValidationPipeConfig.startZapValidationPipeConfig(&fbb)
+    nostr_fb_ZapValidationPipeConfig.add(dropInvalid: dropInvalid, &fbb)
+    return nostr_fb_ZapValidationPipeConfig.endZapValidationPipeConfig(&fbb, start: __start)
+  }
+
+  public static func verify<T>(_ verifier: inout Verifier, at position: Int, of type: T.Type) throws where T: Verifiable {
+    var _v = try verifier.visitTable(at: position)
+    try _v.visit(field: VTOFFSET.dropInvalid.p, fieldName: "dropInvalid", required: false, type: Bool.self)
+    _v.finish()
+  }
+}
+
 public struct nostr_fb_Pipe: FlatBufferObject, Verifiable {
 
   static func validateVersion() { FlatBuffersVersion_25_2_10() }
@@ -2706,6 +2855,8 @@ public struct nostr_fb_Pipe: FlatBufferObject, Verifiable {
         try ForwardOffset<nostr_fb_ProofVerificationPipeConfig>.verify(&verifier, at: pos, of: nostr_fb_ProofVerificationPipeConfig.self)
       case .mutefilterpipeconfig:
         try ForwardOffset<nostr_fb_MuteFilterPipeConfig>.verify(&verifier, at: pos, of: nostr_fb_MuteFilterPipeConfig.self)
+      case .zapvalidationpipeconfig:
+        try ForwardOffset<nostr_fb_ZapValidationPipeConfig>.verify(&verifier, at: pos, of: nostr_fb_ZapValidationPipeConfig.self)
       }
     })
     _v.finish()
@@ -2826,6 +2977,13 @@ public struct nostr_fb_SubscriptionConfig: FlatBufferObject, Verifiable {
     case isSlow = 20
     case pagination = 22
     case cacheOnly = 24
+    case mergedWindow = 26
+    case linkPreviews = 28
+    case thread = 30
+    case resolveMentions = 32
+    case budgetBytes = 34
+    case budgetEvents = 36
+    case verifyNip05 = 38
     var v: Int32 { Int32(self.rawValue) }
     var p: VOffset { self.rawValue }
   }
@@ -2842,7 +3000,15 @@ public struct nostr_fb_SubscriptionConfig: FlatBufferObject, Verifiable {
   public var pagination: String? { let o = _accessor.offset(VTOFFSET.pagination.v); return o == 0 ? nil : _accessor.string(at: o) }
   public var paginationSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.pagination.v) }
   public var cacheOnly: Bool { let o = _accessor.offset(VTOFFSET.cacheOnly.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
-  public static func startSubscriptionConfig(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 11) }
+  public var mergedWindow: Bool { let o = _accessor.offset(VTOFFSET.mergedWindow.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public var linkPreviews: Bool { let o = _accessor.offset(VTOFFSET.linkPreviews.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public var thread: String? { let o = _accessor.offset(VTOFFSET.thread.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var threadSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.thread.v) }
+  public var resolveMentions: Bool { let o = _accessor.offset(VTOFFSET.resolveMentions.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public var budgetBytes: UInt64 { let o = _accessor.offset(VTOFFSET.budgetBytes.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt64.self, at: o) }
+  public var budgetEvents: UInt64 { let o = _accessor.offset(VTOFFSET.budgetEvents.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt64.self, at: o) }
+  public var verifyNip05: Bool { let o = _accessor.offset(VTOFFSET.verifyNip05.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public static func startSubscriptionConfig(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 18) }
   public static func add(pipeline: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: pipeline, at: VTOFFSET.pipeline.p) }
   public static func add(closeOnEose: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: closeOnEose, def: false,
    at: VTOFFSET.closeOnEose.p) }
@@ -2860,6 +3026,17 @@ public struct nostr_fb_SubscriptionConfig: FlatBufferObject, Verifiable {
   public static func add(pagination: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: pagination, at: VTOFFSET.pagination.p) }
   public static func add(cacheOnly: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: cacheOnly, def: false,
    at: VTOFFSET.cacheOnly.p) }
+  public static func add(mergedWindow: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: mergedWindow, def: false,
+   at: VTOFFSET.mergedWindow.p) }
+  public static func add(linkPreviews: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: linkPreviews, def: false,
+   at: VTOFFSET.linkPreviews.p) }
+  public static func add(thread: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: thread, at: VTOFFSET.thread.p) }
+  public static func add(resolveMentions: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: resolveMentions, def: false,
+   at: VTOFFSET.resolveMentions.p) }
+  public static func add(budgetBytes: UInt64, _ fbb: inout FlatBufferBuilder) { fbb.add(element: budgetBytes, def: 0, at: VTOFFSET.budgetBytes.p) }
+  public static func add(budgetEvents: UInt64, _ fbb: inout FlatBufferBuilder) { fbb.add(element: budgetEvents, def: 0, at: VTOFFSET.budgetEvents.p) }
+  public static func add(verifyNip05: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: verifyNip05, def: false,
+   at: VTOFFSET.verifyNip05.p) }
   public static func endSubscriptionConfig(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); return end }
   public static func createSubscriptionConfig(
     _ fbb: inout FlatBufferBuilder,
@@ -2873,7 +3050,14 @@ public struct nostr_fb_SubscriptionConfig: FlatBufferObject, Verifiable {
     bytesPerEvent: UInt32 = 0,
     isSlow: Bool = false,
     paginationOffset pagination: Offset = Offset(),
-    cacheOnly: Bool = false
+    cacheOnly: Bool = false,
+    mergedWindow: Bool = false,
+    linkPreviews: Bool = false,
+    threadOffset thread: Offset = Offset(),
+    resolveMentions: Bool = false,
+    budgetBytes: UInt64 = 0,
+    budgetEvents: UInt64 = 0,
+    verifyNip05: Bool = false
   ) -> Offset {
     let __start = nostr_fb_SubscriptionConfig.startSubscriptionConfig(&fbb)
     nostr_fb_SubscriptionConfig.add(pipeline: pipeline, &fbb)
@@ -2887,6 +3071,13 @@ public struct nostr_fb_SubscriptionConfig: FlatBufferObject, Verifiable {
     nostr_fb_SubscriptionConfig.add(isSlow: isSlow, &fbb)
     nostr_fb_SubscriptionConfig.add(pagination: pagination, &fbb)
     nostr_fb_SubscriptionConfig.add(cacheOnly: cacheOnly, &fbb)
+    nostr_fb_SubscriptionConfig.add(mergedWindow: mergedWindow, &fbb)
+    nostr_fb_SubscriptionConfig.add(linkPreviews: linkPreviews, &fbb)
+    nostr_fb_SubscriptionConfig.add(thread: thread, &fbb)
+    nostr_fb_SubscriptionConfig.add(resolveMentions: resolveMentions, &fbb)
+    nostr_fb_SubscriptionConfig.add(budgetBytes: budgetBytes, &fbb)
+    nostr_fb_SubscriptionConfig.add(budgetEvents: budgetEvents, &fbb)
+    nostr_fb_SubscriptionConfig.add(verifyNip05: verifyNip05, &fbb)
     return nostr_fb_SubscriptionConfig.endSubscriptionConfig(&fbb, start: __start)
   }
 
@@ -2903,6 +3094,13 @@ public struct nostr_fb_SubscriptionConfig: FlatBufferObject, Verifiable {
     try _v.visit(field: VTOFFSET.isSlow.p, fieldName: "isSlow", required: false, type: Bool.self)
     try _v.visit(field: VTOFFSET.pagination.p, fieldName: "pagination", required: false, type: ForwardOffset<String>.self)
     try _v.visit(field: VTOFFSET.cacheOnly.p, fieldName: "cacheOnly", required: false, type: Bool.self)
+    try _v.visit(field: VTOFFSET.mergedWindow.p, fieldName: "mergedWindow", required: false, type: Bool.self)
+    try _v.visit(field: VTOFFSET.linkPreviews.p, fieldName: "linkPreviews", required: false, type: Bool.self)
+    try _v.visit(field: VTOFFSET.thread.p, fieldName: "thread", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.resolveMentions.p, fieldName: "resolveMentions", required: false, type: Bool.self)
+    try _v.visit(field: VTOFFSET.budgetBytes.p, fieldName: "budgetBytes", required: false, type: UInt64.self)
+    try _v.visit(field: VTOFFSET.budgetEvents.p, fieldName: "budgetEvents", required: false, type: UInt64.self)
+    try _v.visit(field: VTOFFSET.verifyNip05.p, fieldName: "verifyNip05", required: false, type: Bool.self)
     _v.finish()
   }
 }
@@ -3250,6 +3448,7 @@ public struct nostr_fb_Kind0Parsed: FlatBufferObject, Verifiable {
     case image = 38
     case avatar = 40
     case background = 42
+    case nip05Verified = 44
     var v: Int32 { Int32(self.rawValue) }
     var p: VOffset { self.rawValue }
   }
@@ -3294,7 +3493,8 @@ public struct nostr_fb_Kind0Parsed: FlatBufferObject, Verifiable {
   public var avatarSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.avatar.v) }
   public var background: String? { let o = _accessor.offset(VTOFFSET.background.v); return o == 0 ? nil : _accessor.string(at: o) }
   public var backgroundSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.background.v) }
-  public static func startKind0Parsed(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 20) }
+  public var nip05Verified: Bool { let o = _accessor.offset(VTOFFSET.nip05Verified.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public static func startKind0Parsed(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 21) }
   public static func add(pubkey: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: pubkey, at: VTOFFSET.pubkey.p) }
   public static func add(name: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: name, at: VTOFFSET.name.p) }
   public static func add(displayName: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: displayName, at: VTOFFSET.displayName.p) }
@@ -3315,6 +3515,8 @@ public struct nostr_fb_Kind0Parsed: FlatBufferObject, Verifiable {
   public static func add(image: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: image, at: VTOFFSET.image.p) }
   public static func add(avatar: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: avatar, at: VTOFFSET.avatar.p) }
   public static func add(background: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: background, at: VTOFFSET.background.p) }
+  public static func add(nip05Verified: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: nip05Verified, def: false,
+   at: VTOFFSET.nip05Verified.p) }
   public static func endKind0Parsed(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); return end }
   public static func createKind0Parsed(
     _ fbb: inout FlatBufferBuilder,
@@ -3337,7 +3539,8 @@ public struct nostr_fb_Kind0Parsed: FlatBufferObject, Verifiable {
     bioOffset bio: Offset = Offset(),
     imageOffset image: Offset = Offset(),
     avatarOffset avatar: Offset = Offset(),
-    backgroundOffset background: Offset = Offset()
+    backgroundOffset background: Offset = Offset(),
+    nip05Verified: Bool = false
   ) -> Offset {
     let __start = nostr_fb_Kind0Parsed.startKind0Parsed(&fbb)
     nostr_fb_Kind0Parsed.add(pubkey: pubkey, &fbb)
@@ -3360,6 +3563,7 @@ public struct nostr_fb_Kind0Parsed: FlatBufferObject, Verifiable {
     nostr_fb_Kind0Parsed.add(image: image, &fbb)
     nostr_fb_Kind0Parsed.add(avatar: avatar, &fbb)
     nostr_fb_Kind0Parsed.add(background: background, &fbb)
+    nostr_fb_Kind0Parsed.add(nip05Verified: nip05Verified, &fbb)
     return nostr_fb_Kind0Parsed.endKind0Parsed(&fbb, start: __start)
   }
 
@@ -3385,6 +3589,7 @@ public struct nostr_fb_Kind0Parsed: FlatBufferObject, Verifiable {
     try _v.visit(field: VTOFFSET.image.p, fieldName: "image", required: false, type: ForwardOffset<String>.self)
     try _v.visit(field: VTOFFSET.avatar.p, fieldName: "avatar", required: false, type: ForwardOffset<String>.self)
     try _v.visit(field: VTOFFSET.background.p, fieldName: "background", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.nip05Verified.p, fieldName: "nip05Verified", required: false, type: Bool.self)
     _v.finish()
   }
 }
@@ -3769,6 +3974,113 @@ public struct nostr_fb_Kind8Parsed: FlatBufferObject, Verifiable {
   }
 }
 
+public struct nostr_fb_Kind14Parsed: FlatBufferObject, Verifiable {
+
+  static func validateVersion() { FlatBuffersVersion_25_2_10() }
+  public var __buffer: ByteBuffer! { return _accessor.bb }
+  private var _accessor: Table
+
+  private init(_ t: Table) { _accessor = t }
+  public init(_ bb: ByteBuffer, o: Int32) { _accessor = Table(bb: bb, position: o) }
+
+  private enum VTOFFSET: VOffset {
+    case kind = 4
+    case rumorId = 6
+    case sender = 8
+    case createdAt = 10
+    case participants = 12
+    case chatId = 14
+    case content = 16
+    case parsedContent = 18
+    case subject = 20
+    case replyTo = 22
+    case tags = 24
+    var v: Int32 { Int32(self.rawValue) }
+    var p: VOffset { self.rawValue }
+  }
+
+  public var kind: UInt16 { let o = _accessor.offset(VTOFFSET.kind.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt16.self, at: o) }
+  public var rumorId: String! { let o = _accessor.offset(VTOFFSET.rumorId.v); return _accessor.string(at: o) }
+  public var rumorIdSegmentArray: [UInt8]! { return _accessor.getVector(at: VTOFFSET.rumorId.v) }
+  public var sender: String! { let o = _accessor.offset(VTOFFSET.sender.v); return _accessor.string(at: o) }
+  public var senderSegmentArray: [UInt8]! { return _accessor.getVector(at: VTOFFSET.sender.v) }
+  public var createdAt: UInt32 { let o = _accessor.offset(VTOFFSET.createdAt.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt32.self, at: o) }
+  public var hasParticipants: Bool { let o = _accessor.offset(VTOFFSET.participants.v); return o == 0 ? false : true }
+  public var participantsCount: Int32 { let o = _accessor.offset(VTOFFSET.participants.v); return o == 0 ? 0 : _accessor.vector(count: o) }
+  public func participants(at index: Int32) -> String? { let o = _accessor.offset(VTOFFSET.participants.v); return o == 0 ? nil : _accessor.directString(at: _accessor.vector(at: o) + index * 4) }
+  public var chatId: String! { let o = _accessor.offset(VTOFFSET.chatId.v); return _accessor.string(at: o) }
+  public var chatIdSegmentArray: [UInt8]! { return _accessor.getVector(at: VTOFFSET.chatId.v) }
+  public var content: String? { let o = _accessor.offset(VTOFFSET.content.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var contentSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.content.v) }
+  public var hasParsedContent: Bool { let o = _accessor.offset(VTOFFSET.parsedContent.v); return o == 0 ? false : true }
+  public var parsedContentCount: Int32 { let o = _accessor.offset(VTOFFSET.parsedContent.v); return o == 0 ? 0 : _accessor.vector(count: o) }
+  public func parsedContent(at index: Int32) -> nostr_fb_ContentBlock? { let o = _accessor.offset(VTOFFSET.parsedContent.v); return o == 0 ? nil : nostr_fb_ContentBlock(_accessor.bb, o: _accessor.indirect(_accessor.vector(at: o) + index * 4)) }
+  public var subject: String? { let o = _accessor.offset(VTOFFSET.subject.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var subjectSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.subject.v) }
+  public var replyTo: String? { let o = _accessor.offset(VTOFFSET.replyTo.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var replyToSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.replyTo.v) }
+  public var hasTags: Bool { let o = _accessor.offset(VTOFFSET.tags.v); return o == 0 ? false : true }
+  public var tagsCount: Int32 { let o = _accessor.offset(VTOFFSET.tags.v); return o == 0 ? 0 : _accessor.vector(count: o) }
+  public func tags(at index: Int32) -> nostr_fb_StringVec? { let o = _accessor.offset(VTOFFSET.tags.v); return o == 0 ? nil : nostr_fb_StringVec(_accessor.bb, o: _accessor.indirect(_accessor.vector(at: o) + index * 4)) }
+  public static func startKind14Parsed(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 11) }
+  public static func add(kind: UInt16, _ fbb: inout FlatBufferBuilder) { fbb.add(element: kind, def: 0, at: VTOFFSET.kind.p) }
+  public static func add(rumorId: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: rumorId, at: VTOFFSET.rumorId.p) }
+  public static func add(sender: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: sender, at: VTOFFSET.sender.p) }
+  public static func add(createdAt: UInt32, _ fbb: inout FlatBufferBuilder) { fbb.add(element: createdAt, def: 0, at: VTOFFSET.createdAt.p) }
+  public static func addVectorOf(participants: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: participants, at: VTOFFSET.participants.p) }
+  public static func add(chatId: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: chatId, at: VTOFFSET.chatId.p) }
+  public static func add(content: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: content, at: VTOFFSET.content.p) }
+  public static func addVectorOf(parsedContent: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: parsedContent, at: VTOFFSET.parsedContent.p) }
+  public static func add(subject: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: subject, at: VTOFFSET.subject.p) }
+  public static func add(replyTo: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: replyTo, at: VTOFFSET.replyTo.p) }
+  public static func addVectorOf(tags: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: tags, at: VTOFFSET.tags.p) }
+  public static func endKind14Parsed(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); fbb.require(table: end, fields: [6, 8, 12, 14]); return end }
+  public static func createKind14Parsed(
+    _ fbb: inout FlatBufferBuilder,
+    kind: UInt16 = 0,
+    rumorIdOffset rumorId: Offset,
+    senderOffset sender: Offset,
+    createdAt: UInt32 = 0,
+    participantsVectorOffset participants: Offset,
+    chatIdOffset chatId: Offset,
+    contentOffset content: Offset = Offset(),
+    parsedContentVectorOffset parsedContent: Offset = Offset(),
+    subjectOffset subject: Offset = Offset(),
+    replyToOffset replyTo: Offset = Offset(),
+    tagsVectorOffset tags: Offset = Offset()
+  ) -> Offset {
+    let __start = nostr_fb_Kind14Parsed.startKind14Parsed(&fbb)
+    nostr_fb_Kind14Parsed.add(kind: kind, &fbb)
+    nostr_fb_Kind14Parsed.add(rumorId: rumorId, &fbb)
+    nostr_fb_Kind14Parsed.add(sender: sender, &fbb)
+    nostr_fb_Kind14Parsed.add(createdAt: createdAt, &fbb)
+    nostr_fb_Kind14Parsed.addVectorOf(participants: participants, &fbb)
+    nostr_fb_Kind14Parsed.add(chatId: chatId, &fbb)
+    nostr_fb_Kind14Parsed.add(content: content, &fbb)
+    nostr_fb_Kind14Parsed.addVectorOf(parsedContent: parsedContent, &fbb)
+    nostr_fb_Kind14Parsed.add(subject: subject, &fbb)
+    nostr_fb_Kind14Parsed.add(replyTo: replyTo, &fbb)
+    nostr_fb_Kind14Parsed.addVectorOf(tags: tags, &fbb)
+    return nostr_fb_Kind14Parsed.endKind14Parsed(&fbb, start: __start)
+  }
+
+  public static func verify<T>(_ verifier: inout Verifier, at position: Int, of type: T.Type) throws where T: Verifiable {
+    var _v = try verifier.visitTable(at: position)
+    try _v.visit(field: VTOFFSET.kind.p, fieldName: "kind", required: false, type: UInt16.self)
+    try _v.visit(field: VTOFFSET.rumorId.p, fieldName: "rumorId", required: true, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.sender.p, fieldName: "sender", required: true, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.createdAt.p, fieldName: "createdAt", required: false, type: UInt32.self)
+    try _v.visit(field: VTOFFSET.participants.p, fieldName: "participants", required: true, type: ForwardOffset<Vector<ForwardOffset<String>, String>>.self)
+    try _v.visit(field: VTOFFSET.chatId.p, fieldName: "chatId", required: true, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.content.p, fieldName: "content", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.parsedContent.p, fieldName: "parsedContent", required: false, type: ForwardOffset<Vector<ForwardOffset<nostr_fb_ContentBlock>, nostr_fb_ContentBlock>>.self)
+    try _v.visit(field: VTOFFSET.subject.p, fieldName: "subject", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.replyTo.p, fieldName: "replyTo", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.tags.p, fieldName: "tags", required: false, type: ForwardOffset<Vector<ForwardOffset<nostr_fb_StringVec>, nostr_fb_StringVec>>.self)
+    _v.finish()
+  }
+}
+
 public struct nostr_fb_Kind17Parsed: FlatBufferObject, Verifiable {
 
   static func validateVersion() { FlatBuffersVersion_25_2_10() }
@@ -4301,6 +4613,8 @@ public struct nostr_fb_Kind9735Parsed: FlatBufferObject, Verifiable {
     case timestamp = 22
     case valid = 24
     case description = 26
+    case invalidReason = 28
+    case providerVerified = 30
     var v: Int32 { Int32(self.rawValue) }
     var p: VOffset { self.rawValue }
   }
@@ -4325,7 +4639,10 @@ public struct nostr_fb_Kind9735Parsed: FlatBufferObject, Verifiable {
   public var timestamp: UInt64 { let o = _accessor.offset(VTOFFSET.timestamp.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt64.self, at: o) }
   public var valid: Bool { let o = _accessor.offset(VTOFFSET.valid.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
   public var description: nostr_fb_ZapRequest! { let o = _accessor.offset(VTOFFSET.description.v); return nostr_fb_ZapRequest(_accessor.bb, o: _accessor.indirect(o + _accessor.position)) }
-  public static func startKind9735Parsed(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 12) }
+  public var invalidReason: String? { let o = _accessor.offset(VTOFFSET.invalidReason.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var invalidReasonSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.invalidReason.v) }
+  public var providerVerified: Bool { let o = _accessor.offset(VTOFFSET.providerVerified.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public static func startKind9735Parsed(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 14) }
   public static func add(id: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: id, at: VTOFFSET.id.p) }
   public static func add(amount: Int32, _ fbb: inout FlatBufferBuilder) { fbb.add(element: amount, def: 0, at: VTOFFSET.amount.p) }
   public static func add(content: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: content, at: VTOFFSET.content.p) }
@@ -4339,6 +4656,9 @@ public struct nostr_fb_Kind9735Parsed: FlatBufferObject, Verifiable {
   public static func add(valid: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: valid, def: false,
    at: VTOFFSET.valid.p) }
   public static func add(description: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: description, at: VTOFFSET.description.p) }
+  public static func add(invalidReason: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: invalidReason, at: VTOFFSET.invalidReason.p) }
+  public static func add(providerVerified: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: providerVerified, def: false,
+   at: VTOFFSET.providerVerified.p) }
   public static func endKind9735Parsed(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); fbb.require(table: end, fields: [4, 8, 10, 14, 16, 26]); return end }
   public static func createKind9735Parsed(
     _ fbb: inout FlatBufferBuilder,
@@ -4353,7 +4673,9 @@ public struct nostr_fb_Kind9735Parsed: FlatBufferObject, Verifiable {
     eventCoordinateOffset eventCoordinate: Offset = Offset(),
     timestamp: UInt64 = 0,
     valid: Bool = false,
-    descriptionOffset description: Offset
+    descriptionOffset description: Offset,
+    invalidReasonOffset invalidReason: Offset = Offset(),
+    providerVerified: Bool = false
   ) -> Offset {
     let __start = nostr_fb_Kind9735Parsed.startKind9735Parsed(&fbb)
     nostr_fb_Kind9735Parsed.add(id: id, &fbb)
@@ -4368,6 +4690,8 @@ public struct nostr_fb_Kind9735Parsed: FlatBufferObject, Verifiable {
     nostr_fb_Kind9735Parsed.add(timestamp: timestamp, &fbb)
     nostr_fb_Kind9735Parsed.add(valid: valid, &fbb)
     nostr_fb_Kind9735Parsed.add(description: description, &fbb)
+    nostr_fb_Kind9735Parsed.add(invalidReason: invalidReason, &fbb)
+    nostr_fb_Kind9735Parsed.add(providerVerified: providerVerified, &fbb)
     return nostr_fb_Kind9735Parsed.endKind9735Parsed(&fbb, start: __start)
   }
 
@@ -4385,6 +4709,8 @@ public struct nostr_fb_Kind9735Parsed: FlatBufferObject, Verifiable {
     try _v.visit(field: VTOFFSET.timestamp.p, fieldName: "timestamp", required: false, type: UInt64.self)
     try _v.visit(field: VTOFFSET.valid.p, fieldName: "valid", required: false, type: Bool.self)
     try _v.visit(field: VTOFFSET.description.p, fieldName: "description", required: true, type: ForwardOffset<nostr_fb_ZapRequest>.self)
+    try _v.visit(field: VTOFFSET.invalidReason.p, fieldName: "invalidReason", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.providerVerified.p, fieldName: "providerVerified", required: false, type: Bool.self)
     _v.finish()
   }
 }
@@ -5903,6 +6229,51 @@ public struct nostr_fb_Kind1018Parsed: FlatBufferObject, Verifiable {
   }
 }
 
+public struct nostr_fb_CustomParsed: FlatBufferObject, Verifiable {
+
+  static func validateVersion() { FlatBuffersVersion_25_2_10() }
+  public var __buffer: ByteBuffer! { return _accessor.bb }
+  private var _accessor: Table
+
+  private init(_ t: Table) { _accessor = t }
+  public init(_ bb: ByteBuffer, o: Int32) { _accessor = Table(bb: bb, position: o) }
+
+  private enum VTOFFSET: VOffset {
+    case schemaId = 4
+    case payload = 6
+    var v: Int32 { Int32(self.rawValue) }
+    var p: VOffset { self.rawValue }
+  }
+
+  public var schemaId: String! { let o = _accessor.offset(VTOFFSET.schemaId.v); return _accessor.string(at: o) }
+  public var schemaIdSegmentArray: [UInt8]! { return _accessor.getVector(at: VTOFFSET.schemaId.v) }
+  public var hasPayload: Bool { let o = _accessor.offset(VTOFFSET.payload.v); return o == 0 ? false : true }
+  public var payloadCount: Int32 { let o = _accessor.offset(VTOFFSET.payload.v); return o == 0 ? 0 : _accessor.vector(count: o) }
+  public func payload(at index: Int32) -> UInt8 { let o = _accessor.offset(VTOFFSET.payload.v); return o == 0 ? 0 : _accessor.directRead(of: UInt8.self, offset: _accessor.vector(at: o) + index * 1) }
+  public var payload: [UInt8] { return _accessor.getVector(at: VTOFFSET.payload.v) ?? [] }
+  public static func startCustomParsed(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 2) }
+  public static func add(schemaId: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: schemaId, at: VTOFFSET.schemaId.p) }
+  public static func addVectorOf(payload: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: payload, at: VTOFFSET.payload.p) }
+  public static func endCustomParsed(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); fbb.require(table: end, fields: [4]); return end }
+  public static func createCustomParsed(
+    _ fbb: inout FlatBufferBuilder,
+    schemaIdOffset schemaId: Offset,
+    payloadVectorOffset payload: Offset = Offset()
+  ) -> Offset {
+    let __start = nostr_fb_CustomParsed.startCustomParsed(&fbb)
+    nostr_fb_CustomParsed.add(schemaId: schemaId, &fbb)
+    nostr_fb_CustomParsed.addVectorOf(payload: payload, &fbb)
+    return nostr_fb_CustomParsed.endCustomParsed(&fbb, start: __start)
+  }
+
+  public static func verify<T>(_ verifier: inout Verifier, at position: Int, of type: T.Type) throws where T: Verifiable {
+    var _v = try verifier.visitTable(at: position)
+    try _v.visit(field: VTOFFSET.schemaId.p, fieldName: "schemaId", required: true, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.payload.p, fieldName: "payload", required: false, type: ForwardOffset<Vector<UInt8, UInt8>>.self)
+    _v.finish()
+  }
+}
+
 public struct nostr_fb_ParsedEvent: FlatBufferObject, Verifiable {
 
   static func validateVersion() { FlatBuffersVersion_25_2_10() }
@@ -5922,6 +6293,8 @@ public struct nostr_fb_ParsedEvent: FlatBufferObject, Verifiable {
     case requests = 16
     case relays = 18
     case tags = 20
+    case nevent = 22
+    case naddr = 24
     var v: Int32 { Int32(self.rawValue) }
     var p: VOffset { self.rawValue }
   }
@@ -5943,7 +6316,11 @@ public struct nostr_fb_ParsedEvent: FlatBufferObject, Verifiable {
   public var hasTags: Bool { let o = _accessor.offset(VTOFFSET.tags.v); return o == 0 ? false : true }
   public var tagsCount: Int32 { let o = _accessor.offset(VTOFFSET.tags.v); return o == 0 ? 0 : _accessor.vector(count: o) }
   public func tags(at index: Int32) -> nostr_fb_StringVec? { let o = _accessor.offset(VTOFFSET.tags.v); return o == 0 ? nil : nostr_fb_StringVec(_accessor.bb, o: _accessor.indirect(_accessor.vector(at: o) + index * 4)) }
-  public static func startParsedEvent(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 9) }
+  public var nevent: String? { let o = _accessor.offset(VTOFFSET.nevent.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var neventSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.nevent.v) }
+  public var naddr: String? { let o = _accessor.offset(VTOFFSET.naddr.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var naddrSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.naddr.v) }
+  public static func startParsedEvent(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 11) }
   public static func add(id: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: id, at: VTOFFSET.id.p) }
   public static func add(pubkey: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: pubkey, at: VTOFFSET.pubkey.p) }
   public static func add(kind: UInt16, _ fbb: inout FlatBufferBuilder) { fbb.add(element: kind, def: 0, at: VTOFFSET.kind.p) }
@@ -5953,6 +6330,8 @@ public struct nostr_fb_ParsedEvent: FlatBufferObject, Verifiable {
   public static func addVectorOf(requests: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: requests, at: VTOFFSET.requests.p) }
   public static func addVectorOf(relays: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: relays, at: VTOFFSET.relays.p) }
   public static func addVectorOf(tags: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: tags, at: VTOFFSET.tags.p) }
+  public static func add(nevent: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: nevent, at: VTOFFSET.nevent.p) }
+  public static func add(naddr: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: naddr, at: VTOFFSET.naddr.p) }
   public static func endParsedEvent(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); fbb.require(table: end, fields: [4, 6, 20]); return end }
   public static func createParsedEvent(
     _ fbb: inout FlatBufferBuilder,
@@ -5964,7 +6343,9 @@ public struct nostr_fb_ParsedEvent: FlatBufferObject, Verifiable {
     parsedOffset parsed: Offset = Offset(),
     requestsVectorOffset requests: Offset = Offset(),
     relaysVectorOffset relays: Offset = Offset(),
-    tagsVectorOffset tags: Offset
+    tagsVectorOffset tags: Offset,
+    neventOffset nevent: Offset = Offset(),
+    naddrOffset naddr: Offset = Offset()
   ) -> Offset {
     let __start = nostr_fb_ParsedEvent.startParsedEvent(&fbb)
     nostr_fb_ParsedEvent.add(id: id, &fbb)
@@ -5976,6 +6357,8 @@ public struct nostr_fb_ParsedEvent: FlatBufferObject, Verifiable {
     nostr_fb_ParsedEvent.addVectorOf(requests: requests, &fbb)
     nostr_fb_ParsedEvent.addVectorOf(relays: relays, &fbb)
     nostr_fb_ParsedEvent.addVectorOf(tags: tags, &fbb)
+    nostr_fb_ParsedEvent.add(nevent: nevent, &fbb)
+    nostr_fb_ParsedEvent.add(naddr: naddr, &fbb)
     return nostr_fb_ParsedEvent.endParsedEvent(&fbb, start: __start)
   }
 
@@ -6039,11 +6422,17 @@ public struct nostr_fb_ParsedEvent: FlatBufferObject, Verifiable {
         try ForwardOffset<nostr_fb_ListParsed>.verify(&verifier, at: pos, of: nostr_fb_ListParsed.self)
       case .pregenericparsed:
         try ForwardOffset<nostr_fb_PreGenericParsed>.verify(&verifier, at: pos, of: nostr_fb_PreGenericParsed.self)
+      case .kind14parsed:
+        try ForwardOffset<nostr_fb_Kind14Parsed>.verify(&verifier, at: pos, of: nostr_fb_Kind14Parsed.self)
+      case .customparsed:
+        try ForwardOffset<nostr_fb_CustomParsed>.verify(&verifier, at: pos, of: nostr_fb_CustomParsed.self)
       }
     })
     try _v.visit(field: VTOFFSET.requests.p, fieldName: "requests", required: false, type: ForwardOffset<Vector<ForwardOffset<nostr_fb_Request>, nostr_fb_Request>>.self)
     try _v.visit(field: VTOFFSET.relays.p, fieldName: "relays", required: false, type: ForwardOffset<Vector<ForwardOffset<String>, String>>.self)
     try _v.visit(field: VTOFFSET.tags.p, fieldName: "tags", required: true, type: ForwardOffset<Vector<ForwardOffset<nostr_fb_StringVec>, nostr_fb_StringVec>>.self)
+    try _v.visit(field: VTOFFSET.nevent.p, fieldName: "nevent", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.naddr.p, fieldName: "naddr", required: false, type: ForwardOffset<String>.self)
     _v.finish()
   }
 }
@@ -6518,6 +6907,183 @@ public struct nostr_fb_AuthUrl: FlatBufferObject, Verifiable {
   }
 }
 
+public struct nostr_fb_ThreadNode: FlatBufferObject, Verifiable {
+
+  static func validateVersion() { FlatBuffersVersion_25_2_10() }
+  public var __buffer: ByteBuffer! { return _accessor.bb }
+  private var _accessor: Table
+
+  private init(_ t: Table) { _accessor = t }
+  public init(_ bb: ByteBuffer, o: Int32) { _accessor = Table(bb: bb, position: o) }
+
+  private enum VTOFFSET: VOffset {
+    case id = 4
+    case parent = 6
+    case depth = 8
+    case missing = 10
+    var v: Int32 { Int32(self.rawValue) }
+    var p: VOffset { self.rawValue }
+  }
+
+  public var id: String! { let o = _accessor.offset(VTOFFSET.id.v); return _accessor.string(at: o) }
+  public var idSegmentArray: [UInt8]! { return _accessor.getVector(at: VTOFFSET.id.v) }
+  public var parent: String? { let o = _accessor.offset(VTOFFSET.parent.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var parentSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.parent.v) }
+  public var depth: UInt16 { let o = _accessor.offset(VTOFFSET.depth.v); return o == 0 ? 0 : _accessor.readBuffer(of: UInt16.self, at: o) }
+  public var missing: Bool { let o = _accessor.offset(VTOFFSET.missing.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public static func startThreadNode(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 4) }
+  public static func add(id: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: id, at: VTOFFSET.id.p) }
+  public static func add(parent: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: parent, at: VTOFFSET.parent.p) }
+  public static func add(depth: UInt16, _ fbb: inout FlatBufferBuilder) { fbb.add(element: depth, def: 0, at: VTOFFSET.depth.p) }
+  public static func add(missing: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: missing, def: false,
+   at: VTOFFSET.missing.p) }
+  public static func endThreadNode(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); fbb.require(table: end, fields: [4]); return end }
+  public static func createThreadNode(
+    _ fbb: inout FlatBufferBuilder,
+    idOffset id: Offset,
+    parentOffset parent: Offset = Offset(),
+    depth: UInt16 = 0,
+    missing: Bool = false
+  ) -> Offset {
+    let __start = nostr_fb_ThreadNode.startThreadNode(&fbb)
+    nostr_fb_ThreadNode.add(id: id, &fbb)
+    nostr_fb_ThreadNode.add(parent: parent, &fbb)
+    nostr_fb_ThreadNode.add(depth: depth, &fbb)
+    nostr_fb_ThreadNode.add(missing: missing, &fbb)
+    return nostr_fb_ThreadNode.endThreadNode(&fbb, start: __start)
+  }
+
+  public static func verify<T>(_ verifier: inout Verifier, at position: Int, of type: T.Type) throws where T: Verifiable {
+    var _v = try verifier.visitTable(at: position)
+    try _v.visit(field: VTOFFSET.id.p, fieldName: "id", required: true, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.parent.p, fieldName: "parent", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.depth.p, fieldName: "depth", required: false, type: UInt16.self)
+    try _v.visit(field: VTOFFSET.missing.p, fieldName: "missing", required: false, type: Bool.self)
+    _v.finish()
+  }
+}
+
+public struct nostr_fb_ThreadTree: FlatBufferObject, Verifiable {
+
+  static func validateVersion() { FlatBuffersVersion_25_2_10() }
+  public var __buffer: ByteBuffer! { return _accessor.bb }
+  private var _accessor: Table
+
+  private init(_ t: Table) { _accessor = t }
+  public init(_ bb: ByteBuffer, o: Int32) { _accessor = Table(bb: bb, position: o) }
+
+  private enum VTOFFSET: VOffset {
+    case focus = 4
+    case root = 6
+    case nodes = 8
+    case complete = 10
+    case delta = 12
+    var v: Int32 { Int32(self.rawValue) }
+    var p: VOffset { self.rawValue }
+  }
+
+  public var focus: String! { let o = _accessor.offset(VTOFFSET.focus.v); return _accessor.string(at: o) }
+  public var focusSegmentArray: [UInt8]! { return _accessor.getVector(at: VTOFFSET.focus.v) }
+  public var root: String! { let o = _accessor.offset(VTOFFSET.root.v); return _accessor.string(at: o) }
+  public var rootSegmentArray: [UInt8]! { return _accessor.getVector(at: VTOFFSET.root.v) }
+  public var hasNodes: Bool { let o = _accessor.offset(VTOFFSET.nodes.v); return o == 0 ? false : true }
+  public var nodesCount: Int32 { let o = _accessor.offset(VTOFFSET.nodes.v); return o == 0 ? 0 : _accessor.vector(count: o) }
+  public func nodes(at index: Int32) -> nostr_fb_ThreadNode? { let o = _accessor.offset(VTOFFSET.nodes.v); return o == 0 ? nil : nostr_fb_ThreadNode(_accessor.bb, o: _accessor.indirect(_accessor.vector(at: o) + index * 4)) }
+  public var complete: Bool { let o = _accessor.offset(VTOFFSET.complete.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public var delta: Bool { let o = _accessor.offset(VTOFFSET.delta.v); return o == 0 ? false : _accessor.readBuffer(of: Bool.self, at: o) }
+  public static func startThreadTree(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 5) }
+  public static func add(focus: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: focus, at: VTOFFSET.focus.p) }
+  public static func add(root: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: root, at: VTOFFSET.root.p) }
+  public static func addVectorOf(nodes: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: nodes, at: VTOFFSET.nodes.p) }
+  public static func add(complete: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: complete, def: false,
+   at: VTOFFSET.complete.p) }
+  public static func add(delta: Bool, _ fbb: inout FlatBufferBuilder) { fbb.add(element: delta, def: false,
+   at: VTOFFSET.delta.p) }
+  public static func endThreadTree(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); fbb.require(table: end, fields: [4, 6, 8]); return end }
+  public static func createThreadTree(
+    _ fbb: inout FlatBufferBuilder,
+    focusOffset focus: Offset,
+    rootOffset root: Offset,
+    nodesVectorOffset nodes: Offset,
+    complete: Bool = false,
+    delta: Bool = false
+  ) -> Offset {
+    let __start = nostr_fb_ThreadTree.startThreadTree(&fbb)
+    nostr_fb_ThreadTree.add(focus: focus, &fbb)
+    nostr_fb_ThreadTree.add(root: root, &fbb)
+    nostr_fb_ThreadTree.addVectorOf(nodes: nodes, &fbb)
+    nostr_fb_ThreadTree.add(complete: complete, &fbb)
+    nostr_fb_ThreadTree.add(delta: delta, &fbb)
+    return nostr_fb_ThreadTree.endThreadTree(&fbb, start: __start)
+  }
+
+  public static func verify<T>(_ verifier: inout Verifier, at position: Int, of type: T.Type) throws where T: Verifiable {
+    var _v = try verifier.visitTable(at: position)
+    try _v.visit(field: VTOFFSET.focus.p, fieldName: "focus", required: true, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.root.p, fieldName: "root", required: true, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.nodes.p, fieldName: "nodes", required: true, type: ForwardOffset<Vector<ForwardOffset<nostr_fb_ThreadNode>, nostr_fb_ThreadNode>>.self)
+    try _v.visit(field: VTOFFSET.complete.p, fieldName: "complete", required: false, type: Bool.self)
+    try _v.visit(field: VTOFFSET.delta.p, fieldName: "delta", required: false, type: Bool.self)
+    _v.finish()
+  }
+}
+
+public struct nostr_fb_MentionData: FlatBufferObject, Verifiable {
+
+  static func validateVersion() { FlatBuffersVersion_25_2_10() }
+  public var __buffer: ByteBuffer! { return _accessor.bb }
+  private var _accessor: Table
+
+  private init(_ t: Table) { _accessor = t }
+  public init(_ bb: ByteBuffer, o: Int32) { _accessor = Table(bb: bb, position: o) }
+
+  private enum VTOFFSET: VOffset {
+    case id = 4
+    case name = 6
+    case picture = 8
+    case event = 10
+    var v: Int32 { Int32(self.rawValue) }
+    var p: VOffset { self.rawValue }
+  }
+
+  public var id: String! { let o = _accessor.offset(VTOFFSET.id.v); return _accessor.string(at: o) }
+  public var idSegmentArray: [UInt8]! { return _accessor.getVector(at: VTOFFSET.id.v) }
+  public var name: String? { let o = _accessor.offset(VTOFFSET.name.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var nameSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.name.v) }
+  public var picture: String? { let o = _accessor.offset(VTOFFSET.picture.v); return o == 0 ? nil : _accessor.string(at: o) }
+  public var pictureSegmentArray: [UInt8]? { return _accessor.getVector(at: VTOFFSET.picture.v) }
+  public var event: nostr_fb_ParsedEvent? { let o = _accessor.offset(VTOFFSET.event.v); return o == 0 ? nil : nostr_fb_ParsedEvent(_accessor.bb, o: _accessor.indirect(o + _accessor.position)) }
+  public static func startMentionData(_ fbb: inout FlatBufferBuilder) -> UOffset { fbb.startTable(with: 4) }
+  public static func add(id: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: id, at: VTOFFSET.id.p) }
+  public static func add(name: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: name, at: VTOFFSET.name.p) }
+  public static func add(picture: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: picture, at: VTOFFSET.picture.p) }
+  public static func add(event: Offset, _ fbb: inout FlatBufferBuilder) { fbb.add(offset: event, at: VTOFFSET.event.p) }
+  public static func endMentionData(_ fbb: inout FlatBufferBuilder, start: UOffset) -> Offset { let end = Offset(offset: fbb.endTable(at: start)); fbb.require(table: end, fields: [4]); return end }
+  public static func createMentionData(
+    _ fbb: inout FlatBufferBuilder,
+    idOffset id: Offset,
+    nameOffset name: Offset = Offset(),
+    pictureOffset picture: Offset = Offset(),
+    eventOffset event: Offset = Offset()
+  ) -> Offset {
+    let __start = nostr_fb_MentionData.startMentionData(&fbb)
+    nostr_fb_MentionData.add(id: id, &fbb)
+    nostr_fb_MentionData.add(name: name, &fbb)
+    nostr_fb_MentionData.add(picture: picture, &fbb)
+    nostr_fb_MentionData.add(event: event, &fbb)
+    return nostr_fb_MentionData.endMentionData(&fbb, start: __start)
+  }
+
+  public static func verify<T>(_ verifier: inout Verifier, at position: Int, of type: T.Type) throws where T: Verifiable {
+    var _v = try verifier.visitTable(at: position)
+    try _v.visit(field: VTOFFSET.id.p, fieldName: "id", required: true, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.name.p, fieldName: "name", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.picture.p, fieldName: "picture", required: false, type: ForwardOffset<String>.self)
+    try _v.visit(field: VTOFFSET.event.p, fieldName: "event", required: false, type: ForwardOffset<nostr_fb_ParsedEvent>.self)
+    _v.finish()
+  }
+}
+
 public struct nostr_fb_WorkerMessage: FlatBufferObject, Verifiable {
 
   static func validateVersion() { FlatBuffersVersion_25_2_10() }
@@ -6601,6 +7167,12 @@ public struct nostr_fb_WorkerMessage: FlatBufferObject, Verifiable {
         try ForwardOffset<nostr_fb_SetSignerResponse>.verify(&verifier, at: pos, of: nostr_fb_SetSignerResponse.self)
       case .authurl:
         try ForwardOffset<nostr_fb_AuthUrl>.verify(&verifier, at: pos, of: nostr_fb_AuthUrl.self)
+      case .linkpreviewdata:
+        try ForwardOffset<nostr_fb_LinkPreviewData>.verify(&verifier, at: pos, of: nostr_fb_LinkPreviewData.self)
+      case .threadtree:
+        try ForwardOffset<nostr_fb_ThreadTree>.verify(&verifier, at: pos, of: nostr_fb_ThreadTree.self)
+      case .mentiondata:
+        try ForwardOffset<nostr_fb_MentionData>.verify(&verifier, at: pos, of: nostr_fb_MentionData.self)
       }
     })
     _v.finish()
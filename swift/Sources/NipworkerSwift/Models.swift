@@ -15,6 +15,10 @@ public struct RequestObject {
     public var noCache: Bool?
     public var maxRelays: UInt16?
     public var meshOnly: Bool?
+    /// Reconcile with relays via NIP-77 and fetch only events missing from the cache.
+    public var negentropy: Bool?
+    /// Background prefetch: relays get this REQ after foreground subscriptions and publishes.
+    public var background: Bool?
 
     public init(
         ids: [String]? = nil,
@@ -29,7 +33,9 @@ public struct RequestObject {
         cacheFirst: Bool? = nil,
         noCache: Bool? = nil,
         maxRelays: UInt16? = nil,
-        meshOnly: Bool? = nil
+        meshOnly: Bool? = nil,
+        negentropy: Bool? = nil,
+        background: Bool? = nil
     ) {
         self.ids = ids
         self.authors = authors
@@ -44,6 +50,8 @@ public struct RequestObject {
         self.noCache = noCache
         self.maxRelays = maxRelays
         self.meshOnly = meshOnly
+        self.negentropy = negentropy
+        self.background = background
     }
 }
 
@@ -57,6 +65,7 @@ public struct PipeConfig {
         case counter(kinds: [UInt16], pubkey: String)
         case npubLimiter(kind: UInt16, limitPerNpub: UInt32, maxTotalNpubs: UInt32)
         case proofVerification(maxProofs: UInt32)
+        case zapValidation(dropInvalid: Bool)
     }
     public var kind: Kind
     public init(_ kind: Kind) { self.kind = kind }
@@ -72,6 +81,14 @@ public struct SubscriptionConfig {
     public var isSlow: Bool
     public var pagination: String?
     public var cacheOnly: Bool
+    public var mergedWindow: Bool
+    public var linkPreviews: Bool
+    public var thread: String?
+    public var resolveMentions: Bool
+    /// Closes the subscription with a `budget-exceeded:` CLOSED once exceeded.
+    public var trafficBudget: TrafficBudget?
+    /// Looks up unchecked NIP-05 identifiers of parsed profiles.
+    public var verifyNip05: Bool
 
     public init(
         pipeline: [PipeConfig]? = nil,
@@ -82,7 +99,13 @@ public struct SubscriptionConfig {
         bytesPerEvent: UInt32 = 3072,
         isSlow: Bool = false,
         pagination: String? = nil,
-        cacheOnly: Bool = false
+        cacheOnly: Bool = false,
+        mergedWindow: Bool = false,
+        linkPreviews: Bool = false,
+        thread: String? = nil,
+        resolveMentions: Bool = false,
+        trafficBudget: TrafficBudget? = nil,
+        verifyNip05: Bool = false
     ) {
         self.pipeline = pipeline
         self.closeOnEose = closeOnEose
@@ -93,6 +116,25 @@ public struct SubscriptionConfig {
         self.isSlow = isSlow
         self.pagination = pagination
         self.cacheOnly = cacheOnly
+        self.mergedWindow = mergedWindow
+        self.linkPreviews = linkPreviews
+        self.thread = thread
+        self.resolveMentions = resolveMentions
+        self.trafficBudget = trafficBudget
+        self.verifyNip05 = verifyNip05
+    }
+}
+
+/// Per-subscription traffic limits; `nil` fields are unlimited.
+public struct TrafficBudget {
+    /// Bytes in and out across all relays.
+    public var maxBytes: UInt64?
+    /// EVENT frames received across all relays, duplicates included.
+    public var maxEvents: UInt64?
+
+    public init(maxBytes: UInt64? = nil, maxEvents: UInt64? = nil) {
+        self.maxBytes = maxBytes
+        self.maxEvents = maxEvents
     }
 }
 
@@ -131,10 +173,51 @@ public struct NostrEvent: Identifiable, Equatable, Sendable {
 public struct NostrManagerConfig {
     public var logLevel: String?
     public var meshBLEEnabled: Bool
+    /// Silence after which a relay socket is pinged and, if it stays silent
+    /// as long again, dropped. Nil keeps the engine default (60s).
+    public var keepaliveTimeout: TimeInterval?
+    /// SOCKS5 proxy (`host:port` or `socks5h://host:port`, e.g. a local Tor
+    /// daemon) for this engine's relay sockets and HTTP lookups.
+    public var proxy: String?
+    public var proxyScope: ProxyScope
 
-    public init(logLevel: String? = nil, meshBLEEnabled: Bool = false) {
+    public enum ProxyScope: Sendable {
+        case all
+        /// Only `.onion` relays; everything else dials directly.
+        case onion
+        /// These relays, plus `.onion` ones.
+        case relays([String])
+    }
+
+    public init(
+        logLevel: String? = nil,
+        meshBLEEnabled: Bool = false,
+        keepaliveTimeout: TimeInterval? = nil,
+        proxy: String? = nil,
+        proxyScope: ProxyScope = .all
+    ) {
         self.logLevel = logLevel
         self.meshBLEEnabled = meshBLEEnabled
+        self.keepaliveTimeout = keepaliveTimeout
+        self.proxy = proxy
+        self.proxyScope = proxyScope
+    }
+
+    var transportJSON: String {
+        var fields: [String: Any] = [:]
+        if let keepaliveTimeout {
+            fields["keepalive_timeout_secs"] = max(1, Int(keepaliveTimeout.rounded()))
+        }
+        if let proxy {
+            fields["proxy"] = proxy
+            switch proxyScope {
+            case .all: fields["proxy_scope"] = "all"
+            case .onion: fields["proxy_scope"] = "onion"
+            case .relays(let relays): fields["proxy_scope"] = relays
+            }
+        }
+        guard let data = try? JSONSerialization.data(withJSONObject: fields) else { return "{}" }
+        return String(decoding: data, as: UTF8.self)
     }
 }
 
@@ -149,6 +232,52 @@ public enum RelayStatus: Equatable, Sendable {
     case closed(String?)
 }
 
+/// Health score for one relay, as tracked by the engine across sessions.
+public struct RelayHealthScore: Decodable, Equatable, Sendable {
+    public var url: String
+    /// 0 (unusable) ... 1 (fast and reliable); 0.5 for relays never tried.
+    public var score: Double
+    public var dead: Bool
+    public var slow: Bool
+    public var connectAttempts: Int
+    public var connectFailures: Int
+    public var disconnects: Int
+    public var connectLatencyMs: Double
+    public var eoseLatencyMs: Double
+    public var okAccepted: Int
+    public var okRejected: Int
+
+    private enum CodingKeys: String, CodingKey {
+        case url, score, dead, slow, stats
+    }
+
+    private enum StatsKeys: String, CodingKey {
+        case connectAttempts = "connect_attempts"
+        case connectFailures = "connect_failures"
+        case disconnects
+        case connectLatencyMs = "connect_latency_ms"
+        case eoseLatencyMs = "eose_latency_ms"
+        case okAccepted = "ok_accepted"
+        case okRejected = "ok_rejected"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        url = try container.decode(String.self, forKey: .url)
+        score = try container.decode(Double.self, forKey: .score)
+        dead = try container.decode(Bool.self, forKey: .dead)
+        slow = try container.decode(Bool.self, forKey: .slow)
+        let stats = try container.nestedContainer(keyedBy: StatsKeys.self, forKey: .stats)
+        connectAttempts = try stats.decode(Int.self, forKey: .connectAttempts)
+        connectFailures = try stats.decode(Int.self, forKey: .connectFailures)
+        disconnects = try stats.decode(Int.self, forKey: .disconnects)
+        connectLatencyMs = try stats.decode(Double.self, forKey: .connectLatencyMs)
+        eoseLatencyMs = try stats.decode(Double.self, forKey: .eoseLatencyMs)
+        okAccepted = try stats.decode(Int.self, forKey: .okAccepted)
+        okRejected = try stats.decode(Int.self, forKey: .okRejected)
+    }
+}
+
 public enum PublishStatus: Equatable, Sendable {
     case pending
     case success(String)
@@ -61,14 +61,17 @@ public actor NostrManager {
 
         (config.logLevel ?? "warn").withCString { nipworker_set_log_level($0) }
 
-        self.handle = nipworker_shared_acquire(
-            nipworkerManagerCallback,
-            self.boxPtr,
-            nil,
-            nil,
-            nil,
-            config.meshBLEEnabled
-        )
+        self.handle = config.transportJSON.withCString { transportJSON in
+            nipworker_shared_acquire_with_transport(
+                nipworkerManagerCallback,
+                self.boxPtr,
+                nil,
+                nil,
+                nil,
+                config.meshBLEEnabled,
+                transportJSON
+            )
+        }
         if self.handle != nil {
             let anchoredHandle = nipworker_shared_process_acquire(
                 nil,
@@ -318,6 +321,18 @@ public actor NostrManager {
         return relayStatuses
     }
 
+    /// Relay health scores, best first. Use `dead` / `slow` to flag relays in
+    /// settings; empty until the engine has started.
+    public func getRelayHealth() -> [RelayHealthScore] {
+        var length = 0
+        guard let pointer = nipworker_relay_health(handle, &length) else {
+            return []
+        }
+        let data = Data(bytes: pointer, count: length)
+        nipworker_free_bytes(pointer, length)
+        return (try? JSONDecoder().decode([RelayHealthScore].self, from: data)) ?? []
+    }
+
     public func getActivePubkey() -> String? {
         return activePubkey
     }
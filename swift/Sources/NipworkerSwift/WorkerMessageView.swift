@@ -142,4 +142,22 @@ public struct WorkerMessageView: Sendable {
         guard contentType == .countresponse else { return nil }
         return message.content(type: nostr_fb_CountResponse.self)
     }
+
+    /// A link preview resolved after the event referencing its URL was delivered.
+    public var linkPreview: nostr_fb_LinkPreviewData? {
+        guard contentType == .linkpreviewdata else { return nil }
+        return message.content(type: nostr_fb_LinkPreviewData.self)
+    }
+
+    /// The thread around a `thread` subscription's focus event, re-sent as it fills in.
+    public var threadTree: nostr_fb_ThreadTree? {
+        guard contentType == .threadtree else { return nil }
+        return message.content(type: nostr_fb_ThreadTree.self)
+    }
+
+    /// A `nostr:` reference resolved for a `resolveMentions` subscription, keyed by `id`.
+    public var mention: nostr_fb_MentionData? {
+        guard contentType == .mentiondata else { return nil }
+        return message.content(type: nostr_fb_MentionData.self)
+    }
 }